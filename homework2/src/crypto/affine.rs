@@ -0,0 +1,186 @@
+use std::error::Error;
+
+use crate::crypto::caesar::CLASSIC_ALPHABET_LEN;
+use crate::crypto::symmetric::{invalid_key_bytes, run_symmetric_cipher, SymmetricCipher};
+use crate::logic::bigint::ChonkerInt;
+use crate::logic::config::Mode;
+use crate::logic::error::OperationError;
+
+// Modulus of the affine cipher over the bytes.
+pub const AFFINE_BYTE_MODULUS: u16 = 256;
+
+// The key of the affine cipher E(x) = (a * x + b) mod m, the decryption D(y) = a^-1 * (y - b) mod m
+// uses the modular inverse of a, which exists only for a coprime to the modulus.
+// More about the idea: https://en.wikipedia.org/wiki/Affine_cipher
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AffineKey {
+    pub a: u16,
+    pub b: u16,
+    pub a_inverse: u16,
+    pub modulus: u16,
+}
+
+impl AffineKey {
+    // Parse the key "a,b" of two whole numbers, both are reduced modulo the modulus, a has to be invertible.
+    pub fn parse(key: &str, modulus: u16) -> Result<AffineKey, OperationError> {
+        let parts: Vec<&str> = key.split(',').map(str::trim).collect();
+        let (a, b) = match parts.as_slice() {
+            [a, b] => match (a.parse::<i128>(), b.parse::<i128>()) {
+                (Ok(a), Ok(b)) => (a, b),
+                _ => return Err(OperationError::new(&format!("the affine key \"{}\" contains a value, which is not a whole number. Correct key: \"a,b\", e.g. \"5,8\".", key))),
+            },
+            _ => return Err(OperationError::new(&format!("the affine key \"{}\" does not consist of two values. Correct key: \"a,b\", e.g. \"5,8\".", key))),
+        };
+
+        let chonky_modulus = ChonkerInt::from(modulus);
+        let chonky_a = ChonkerInt::from(a);
+        let a_inverse = match chonky_a.modinv(&chonky_modulus) {
+            Some(a_inverse) => a_inverse,
+            None => {
+                return Err(OperationError::new(&format!(
+                    "the affine key a = {} is not invertible modulo {}, gcd(a, {}) = {}, thus the decryption is impossible. Correct value of a is coprime to {}.",
+                    a,
+                    modulus,
+                    modulus,
+                    chonky_a.gcd(&chonky_modulus),
+                    modulus
+                )))
+            }
+        };
+
+        Ok(AffineKey {
+            a: a.rem_euclid(i128::from(modulus)) as u16,
+            b: b.rem_euclid(i128::from(modulus)) as u16,
+            a_inverse: a_inverse.to_u128()? as u16,
+            modulus,
+        })
+    }
+
+    // Encrypt the value in the range 0 - (m - 1).
+    fn encrypt(&self, value: u16) -> u16 {
+        (self.a * value + self.b) % self.modulus
+    }
+
+    // Decrypt the value in the range 0 - (m - 1).
+    fn decrypt(&self, value: u16) -> u16 {
+        (self.a_inverse * ((value + self.modulus - self.b) % self.modulus)) % self.modulus
+    }
+}
+
+// The affine cipher over the bytes, the key bytes are a, b and the inverse of a modulo 256.
+pub struct Affine;
+
+impl SymmetricCipher for Affine {
+    fn name(&self) -> &'static str {
+        "affine"
+    }
+
+    fn validate_key(&self, key: &str) -> Result<Vec<u8>, OperationError> {
+        let key = AffineKey::parse(key, AFFINE_BYTE_MODULUS)?;
+
+        Ok(vec![key.a as u8, key.b as u8, key.a_inverse as u8])
+    }
+
+    fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        let key = affine_byte_key(key)?;
+
+        // Encrypt the bytes one by one.
+        Ok(plaintext.iter().map(|byte| key.encrypt(u16::from(*byte)) as u8).collect())
+    }
+
+    fn decrypt(&self, ciphertext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        let key = affine_byte_key(key)?;
+
+        // Decrypt the bytes one by one.
+        Ok(ciphertext.iter().map(|byte| key.decrypt(u16::from(*byte)) as u8).collect())
+    }
+}
+
+// Restore the affine key over the bytes from its key bytes.
+fn affine_byte_key(key: &[u8]) -> Result<AffineKey, OperationError> {
+    match key {
+        [a, b, a_inverse] => Ok(AffineKey {
+            a: u16::from(*a),
+            b: u16::from(*b),
+            a_inverse: u16::from(*a_inverse),
+            modulus: AFFINE_BYTE_MODULUS,
+        }),
+        _ => Err(invalid_key_bytes("affine")),
+    }
+}
+
+// Function to encrypt or decrypt the target string under the affine cipher over the bytes, the ciphertext is in hex.
+pub fn affine(mode: &Mode, target: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    run_symmetric_cipher(&Affine, mode, target, key)
+}
+
+// Function to encrypt or decrypt the target string under the classic affine cipher of the Latin alphabet.
+// Only the ASCII letters are transformed within A-Z or a-z, the case is preserved, other characters pass through unchanged.
+pub fn affine_classic(mode: &Mode, target: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    let key = AffineKey::parse(key, u16::from(CLASSIC_ALPHABET_LEN))?;
+    let transform = match mode {
+        Mode::Encode => AffineKey::encrypt,
+        Mode::Decode => AffineKey::decrypt,
+        _ => return Err(Box::new(OperationError::new("received an incorrect argument for the encryption mode. Correct values: \"encrypt\" or \"decrypt\". (affine_classic)"))),
+    };
+
+    Ok(target
+        .chars()
+        .map(|char| {
+            let alphabet_start = match char {
+                'A'..='Z' => b'A',
+                'a'..='z' => b'a',
+                _ => return char,
+            };
+
+            (alphabet_start + transform(&key, u16::from(char as u8 - alphabet_start)) as u8) as char
+        })
+        .collect())
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::crypto::affine::{affine, affine_classic, AffineKey, AFFINE_BYTE_MODULUS};
+    use crate::crypto::caesar::caesar;
+    use crate::logic::config::Mode;
+
+    // Test the classic affine cipher on the textbook example with a = 5 and b = 8, the case and the spaces are preserved.
+    #[test]
+    fn test_affine_classic() {
+        assert_eq!(affine_classic(&Mode::Encode, "AFFINE CIPHER", "5,8").unwrap(), "IHHWVC SWFRCP");
+        assert_eq!(affine_classic(&Mode::Decode, "IHHWVC SWFRCP", "5,8").unwrap(), "AFFINE CIPHER");
+        assert_eq!(affine_classic(&Mode::Encode, "Affine cipher, 1929!", "-21,34").unwrap(), "Ihhwvc swfrcp, 1929!");
+
+        // The even a and the multiples of 13 are not invertible modulo 26.
+        assert!(affine_classic(&Mode::Encode, "AFFINE", "13,8").unwrap_err().to_string().contains("gcd(a, 26) = 13"));
+        assert!(affine_classic(&Mode::Encode, "AFFINE", "2,8").is_err());
+    }
+
+    // Test the affine cipher over the bytes: the round-trip, a = 1 matches Caesar in hex, the even a is rejected.
+    #[test]
+    fn test_affine_bytes() {
+        let target_original = "TargetText, Целевой текст 🗝";
+        let encryption_result = affine(&Mode::Encode, target_original, "171,42").unwrap();
+        assert_eq!(encryption_result.len(), 2 * target_original.len());
+        assert_eq!(affine(&Mode::Decode, &encryption_result, "171,42").unwrap(), target_original);
+
+        assert_eq!(affine(&Mode::Encode, target_original, "1,123").unwrap(), caesar(&Mode::Encode, &mut String::from(target_original), "123").unwrap());
+
+        match affine(&Mode::Encode, target_original, "2,8") {
+            Ok(_) => panic!("Expected an error for the key a = 2, which is not invertible modulo 256 (test_affine_bytes)"),
+            Err(e) => assert!(e.to_string().contains("a = 2 is not invertible modulo 256, gcd(a, 256) = 2")),
+        }
+    }
+
+    // Test the parsing of the affine key: the values are reduced modulo the modulus, the malformed keys are rejected.
+    #[test]
+    fn test_affine_key_parse() {
+        assert_eq!(AffineKey::parse("5, 8", 26).unwrap(), AffineKey { a: 5, b: 8, a_inverse: 21, modulus: 26 });
+        assert_eq!(AffineKey::parse("-1,300", AFFINE_BYTE_MODULUS).unwrap(), AffineKey { a: 255, b: 44, a_inverse: 255, modulus: 256 });
+
+        for key in ["5", "5,8,1", "", "a,8", "5,"] {
+            assert!(AffineKey::parse(key, 26).is_err());
+        }
+    }
+}
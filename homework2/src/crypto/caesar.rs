@@ -1,27 +1,42 @@
 use std::error::Error;
-use std::str::from_utf8_unchecked;
+use std::io::{Read, Write};
 
+use crate::crypto::symmetric::{decrypt_stream, encrypt_stream, invalid_key_bytes, run_symmetric_cipher, SymmetricCipher};
 use crate::encoding::*;
 use crate::logic::config::Mode;
 use crate::logic::error::OperationError;
 
+// Amount of the letters of the Latin alphabet, the classic mode shifts the letters within it.
+pub const CLASSIC_ALPHABET_LEN: u8 = 26;
+
+// Amount of the best candidates, produced by the Caesar bruteforce by default.
+pub const CAESAR_BRUTEFORCE_TOP: usize = 5;
+
+// Relative frequencies of the letters a-z in the English text.
+pub(crate) const ENGLISH_LETTER_FREQUENCIES: [f64; 26] = [
+    0.082, 0.015, 0.028, 0.043, 0.127, 0.022, 0.020, 0.061, 0.070, 0.0015, 0.0077, 0.040, 0.024,
+    0.067, 0.075, 0.019, 0.00095, 0.060, 0.063, 0.091, 0.028, 0.0098, 0.024, 0.0015, 0.020, 0.00074,
+];
+// Relative frequency of the space in the English text, a word is about 5 letters long.
+const ENGLISH_SPACE_FREQUENCY: f64 = 0.13;
+
+// A candidate of the Caesar bruteforce: the shift, the score of its plaintext and the plaintext itself,
+// the invalid UTF-8 sequences of the plaintext are replaced with the replacement character.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaesarCandidate {
+    pub shift: u8,
+    pub score: f64,
+    pub plaintext: String,
+}
+
 // Check if the Caesar key numeric and shorter than 39 number integer, if so return True, otherwise False.
+// A negative key has a single leading minus/hyphen, the signs in the middle or at the end are denied.
 pub fn check_caesar_key(key: &str) -> bool {
-    let mut char_iter = key.chars();
-
-    // Check if the first character is minus/hyphen or a number.
-    // If so, proceed with further checking.
-    if let Some(char) = char_iter.next() {
-        if !(char == '-' || char.is_numeric()) {
-            return false;
-        }
-    }
-
-    // Check if every other character is numeric.
-    for char in char_iter {
-        if !char.is_numeric() {
-            return false;
-        }
+    // Strip the optional minus/hyphen, the rest has to be a non empty sequence of the decimal digits.
+    let digits = key.strip_prefix('-').unwrap_or(key);
+    if digits.is_empty() || !digits.chars().all(|char| char.is_ascii_digit()) {
+        return false;
     }
 
     // Check the length of the key, if it is too long to handle, deny it.
@@ -32,46 +47,176 @@ pub fn check_caesar_key(key: &str) -> bool {
     true
 }
 
-// Function to encrypt or decrypt the target sting under Caesar cipher.
-pub fn caesar(mode: &Mode, target: &mut str, key: &str) -> Result<String, Box<dyn Error>> {
+// Caesar cipher over the bytes, the key bytes are the single shift.
+pub struct Caesar;
+
+impl SymmetricCipher for Caesar {
+    fn name(&self) -> &'static str {
+        "Caesar"
+    }
+
     // Byte has only 256 variations, considering the algorithm used,
     // there is no need for key number bigger than 256;
     // the euclidean modulus is calculated to account for possible negative entries instead of
     // C-like remainder "%" operation.
-    let key: i128 = key.parse()?;
-    let key = key.rem_euclid(256);
-    let key = key as u8;
+    fn validate_key(&self, key: &str) -> Result<Vec<u8>, OperationError> {
+        // Check Caesar key, only numbers are accepted.
+        if !check_caesar_key(key) {
+            return Err(OperationError::new("Received incorrect key for Caesar processing, only a number value as a key is accepted."));
+        }
+        let key: i128 = match key.parse() {
+            Ok(key) => key,
+            Err(e) => return Err(OperationError::new(&format!("the Caesar key \"{}\" is not a number ({}).", key, e))),
+        };
 
-    match mode {
-        Mode::Encode => {
-            // Convert string to the vector of unsigned one byte integers.
-            let target = unsafe { target.as_bytes_mut() };
+        Ok(vec![key.rem_euclid(256) as u8])
+    }
 
-            // Encrypt vector of bytes one by one.
-            for char in target.iter_mut() {
-                caesar_encrypt_char(char, &key);
-            }
+    fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        let shift = caesar_shift(key)?;
 
-            // Encode the vector of bytes into the hex string.
-            string_hex_encode(target)
+        // Encrypt vector of bytes one by one.
+        let mut encrypted_bytes = plaintext.to_vec();
+        for char in encrypted_bytes.iter_mut() {
+            caesar_encrypt_char(char, &shift);
         }
-        Mode::Decode => {
-            // Convert received hex string into the vector of encrypted one bytes.
-            let mut decoded_string = string_hex_decode(target)?;
 
-            // Decrypt vector of bytes one by one.
-            for char in decoded_string.iter_mut() {
-                caesar_decrypt_char(char, &key);
-            }
+        Ok(encrypted_bytes)
+    }
 
-            let result = unsafe { from_utf8_unchecked(&decoded_string) };
+    fn decrypt(&self, ciphertext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        let shift = caesar_shift(key)?;
 
-            Ok(String::from(result))
+        // Decrypt vector of bytes one by one.
+        let mut decrypted_bytes = ciphertext.to_vec();
+        for char in decrypted_bytes.iter_mut() {
+            caesar_decrypt_char(char, &shift);
         }
-        _ => Err(Box::new(OperationError::new("received an incorrect argument for the encryption mode. Correct values: \"encrypt\" or \"decrypt\". (caesar)"))),
+
+        Ok(decrypted_bytes)
     }
 }
 
+// Take the shift out of the key bytes of Caesar.
+fn caesar_shift(key: &[u8]) -> Result<u8, OperationError> {
+    match key {
+        [shift] => Ok(*shift),
+        _ => Err(invalid_key_bytes("Caesar")),
+    }
+}
+
+// Function to encrypt or decrypt the target sting under Caesar cipher.
+pub fn caesar(mode: &Mode, target: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    run_symmetric_cipher(&Caesar, mode, target, key)
+}
+
+// Function to encrypt the bytes of the reader into the hex ciphertext of the writer under Caesar cipher chunk by chunk,
+// for the inputs too large for the memory. Returns the amount of the written bytes.
+pub fn caesar_encrypt_stream<R: Read, W: Write>(reader: R, writer: W, key: &str) -> Result<u64, Box<dyn Error>> {
+    let shift = caesar_shift(&Caesar.validate_key(key)?)?;
+
+    encrypt_stream(reader, writer, |chunk, _position| {
+        for char in chunk.iter_mut() {
+            caesar_encrypt_char(char, &shift);
+        }
+    })
+}
+
+// Function to decrypt the hex ciphertext of the reader into the bytes of the writer under Caesar cipher chunk by chunk,
+// for the inputs too large for the memory. Returns the amount of the written bytes.
+pub fn caesar_decrypt_stream<R: Read, W: Write>(reader: R, writer: W, key: &str) -> Result<u64, Box<dyn Error>> {
+    let shift = caesar_shift(&Caesar.validate_key(key)?)?;
+
+    decrypt_stream(reader, writer, |chunk, _position| {
+        for char in chunk.iter_mut() {
+            caesar_decrypt_char(char, &shift);
+        }
+    })
+}
+
+// Function to bruteforce the hex ciphertext of Caesar cipher: every shift 0-255 is tried and the candidate plaintexts
+// are ranked by their score from the best to the worst, the candidates with the equal scores are ordered by their shifts.
+pub fn caesar_bruteforce(target: &str) -> Result<Vec<CaesarCandidate>, Box<dyn Error>> {
+    // Convert received hex string into the vector of encrypted one bytes.
+    let decoded_string = string_hex_decode(target)?;
+
+    let mut candidates: Vec<CaesarCandidate> = (0..=u8::MAX)
+        .map(|shift| {
+            let mut decrypted_bytes = decoded_string.clone();
+            for char in decrypted_bytes.iter_mut() {
+                caesar_decrypt_char(char, &shift);
+            }
+
+            CaesarCandidate {
+                shift,
+                score: plaintext_score(&decrypted_bytes),
+                plaintext: String::from_utf8_lossy(&decrypted_bytes).into_owned(),
+            }
+        })
+        .collect();
+
+    // The stable sort keeps the order of the shifts for the equal scores.
+    candidates.sort_by(|first, second| second.score.total_cmp(&first.score));
+
+    Ok(candidates)
+}
+
+// Score the bytes as an English plaintext: the ratio of the printable ASCII characters plus the average frequency
+// of the letters and spaces in the English text. The higher score is the more likely plaintext, the empty text scores 0.
+pub fn plaintext_score(bytes: &[u8]) -> f64 {
+    if bytes.is_empty() {
+        return 0.0;
+    }
+
+    let printable = bytes.iter().filter(|byte| byte.is_ascii_graphic() || byte.is_ascii_whitespace()).count();
+    let frequency: f64 = bytes
+        .iter()
+        .map(|byte| match byte.to_ascii_lowercase() {
+            letter @ b'a'..=b'z' => ENGLISH_LETTER_FREQUENCIES[usize::from(letter - b'a')],
+            b' ' => ENGLISH_SPACE_FREQUENCY,
+            _ => 0.0,
+        })
+        .sum();
+
+    (printable as f64 + frequency) / bytes.len() as f64
+}
+
+// Convert the decrypted bytes into the string of UTF-8 characters, the invalid sequences are produced by an incorrect key.
+// The name of the cipher is a part of the error message.
+pub fn decrypted_bytes_to_string(decrypted_bytes: Vec<u8>, cipher: &str) -> Result<String, Box<dyn Error>> {
+    match String::from_utf8(decrypted_bytes) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(Box::new(OperationError::new(&format!("the decrypted {} message is not a valid UTF-8 text ({}), the key is likely incorrect.", cipher, e.utf8_error())))),
+    }
+}
+
+// Function to encrypt or decrypt the target string under the classic Caesar cipher of the Latin alphabet.
+// Only the ASCII letters are shifted within A-Z or a-z, the case is preserved, other characters pass through unchanged.
+// The key is taken modulo 26 and the result is a plain text instead of hex.
+pub fn caesar_classic(mode: &Mode, target: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    let key: i128 = key.parse()?;
+    let shift = key.rem_euclid(i128::from(CLASSIC_ALPHABET_LEN)) as u8;
+
+    let shift = match mode {
+        Mode::Encode => shift,
+        Mode::Decode => CLASSIC_ALPHABET_LEN - shift,
+        _ => return Err(Box::new(OperationError::new("received an incorrect argument for the encryption mode. Correct values: \"encrypt\" or \"decrypt\". (caesar_classic)"))),
+    };
+
+    Ok(target.chars().map(|char| classic_shift_char(char, shift)).collect())
+}
+
+// Shift the ASCII letter forward within its alphabet A-Z or a-z, other characters are returned unchanged.
+pub fn classic_shift_char(char: char, shift: u8) -> char {
+    let alphabet_start = match char {
+        'A'..='Z' => b'A',
+        'a'..='z' => b'a',
+        _ => return char,
+    };
+
+    (alphabet_start + (char as u8 - alphabet_start + shift % CLASSIC_ALPHABET_LEN) % CLASSIC_ALPHABET_LEN) as char
+}
+
 // Encrypt provided character.
 pub fn caesar_encrypt_char(target_char: &mut u8, key: &u8) {
     // Cast target value from unsigned one byte integer to the two byte signed integer,
@@ -102,9 +247,16 @@ pub fn caesar_decrypt_char(target_char: &mut u8, key: &u8) {
 // Test module.
 #[cfg(test)]
 mod tests {
+    use std::io::{Read, Write};
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
     use crate::crypto::caesar::{
-        caesar, caesar_decrypt_char, caesar_encrypt_char, check_caesar_key,
+        caesar, caesar_bruteforce, caesar_classic, caesar_decrypt_char, caesar_decrypt_stream, caesar_encrypt_char, caesar_encrypt_stream, check_caesar_key, plaintext_score, Caesar,
     };
+    use crate::crypto::symmetric::{SymmetricCipher, STREAM_CHUNK_SIZE};
+    use crate::encoding::string_hex_encode;
     use crate::logic::config::Mode;
 
     // Test Caesar encryption.
@@ -190,6 +342,187 @@ mod tests {
         assert!(!check_caesar_key(&key));
     }
 
+    // Test Caesar key check mechanism on the signs: a single leading minus is accepted, the repeated,
+    // embedded or trailing signs, the lone sign and the non decimal digits are denied, the length bound includes the sign.
+    #[test]
+    fn test_caesar_key_check_signs() {
+        assert!(check_caesar_key("-5"));
+        assert!(check_caesar_key("-0"));
+        assert!(!check_caesar_key("--3"));
+        assert!(!check_caesar_key("5-"));
+        assert!(!check_caesar_key("1-5"));
+        assert!(!check_caesar_key("-"));
+        assert!(!check_caesar_key(""));
+        assert!(!check_caesar_key("+5"));
+        assert!(!check_caesar_key("-\u{0663}"));
+
+        // The longest accepted negative key has 37 digits, the key of 38 digits is too long with its sign.
+        assert!(check_caesar_key(&format!("-{}", "9".repeat(37))));
+        assert!(!check_caesar_key(&format!("-{}", "9".repeat(38))));
+    }
+
+    // Test Caesar decryption with the key, smaller by one: the continuation byte 0xBF of the letter "п" turns into 0xC0,
+    // which is never valid in UTF-8, an error is returned. The correct key still restores the text.
+    #[test]
+    fn test_caesar_decryption_incorrect_key() {
+        let target_original = "Caesar переписка";
+        let mut encryption_result = caesar(&Mode::Encode, &mut String::from(target_original), "100").unwrap();
+
+        match caesar(&Mode::Decode, &mut encryption_result.clone(), "99") {
+            Ok(_) => panic!("Expected an error for the decryption with an incorrect key (test_caesar_decryption_incorrect_key)"),
+            Err(e) => assert!(e.to_string().contains("the decrypted Caesar message is not a valid UTF-8 text")),
+        }
+        assert_eq!(caesar(&Mode::Decode, &mut encryption_result, "100").unwrap(), target_original);
+    }
+
+    // Test the classic Caesar on the textbook example, the round-trip with the punctuation and digits,
+    // the keys, equal modulo 26, and the negative key.
+    #[test]
+    fn test_caesar_classic() {
+        assert_eq!(caesar_classic(&Mode::Encode, "ATTACK AT DAWN", "3").unwrap(), "DWWDFN DW GDZQ");
+        assert_eq!(caesar_classic(&Mode::Decode, "DWWDFN DW GDZQ", "3").unwrap(), "ATTACK AT DAWN");
+
+        let target_original = "Veni, vidi, vici! 47 BC, Zela.";
+        let encryption_result = caesar_classic(&Mode::Encode, target_original, "-1").unwrap();
+        assert_eq!(encryption_result, "Udmh, uhch, uhbh! 47 AB, Ydkz.");
+        assert_eq!(encryption_result, caesar_classic(&Mode::Encode, target_original, "77").unwrap());
+        assert_eq!(caesar_classic(&Mode::Decode, &encryption_result, "-1").unwrap(), target_original);
+
+        // The key 26 and the non ASCII letters leave the text unchanged.
+        assert_eq!(caesar_classic(&Mode::Encode, "Привет, World", "26").unwrap(), "Привет, World");
+        assert!(caesar_classic(&Mode::Encode, "Text", "key").is_err());
+    }
+
+    // Test Caesar bruteforce on the ciphertexts, produced by the tool, the correct shift is ranked first.
+    #[test]
+    fn test_caesar_bruteforce() {
+        let target_original = "Attack at dawn, the enemy is approaching from the north.";
+        for (key, shift) in [("42", 42), ("-3", 253), ("0", 0), ("200", 200)] {
+            let encryption_result = caesar(&Mode::Encode, &mut String::from(target_original), key).unwrap();
+            let candidates = caesar_bruteforce(&encryption_result).unwrap();
+
+            assert_eq!(candidates.len(), 256);
+            assert_eq!(candidates[0].shift, shift);
+            assert_eq!(candidates[0].plaintext, target_original);
+        }
+
+        // The plaintext scores above the same bytes, shifted by one.
+        assert!(plaintext_score(b"hello world") > plaintext_score(b"ifmmp!xpsme"));
+        assert_eq!(plaintext_score(b""), 0.0);
+    }
+
+    // Test Caesar bruteforce on the random binary bytes and the empty ciphertext, the ranked list of all shifts is returned.
+    #[test]
+    fn test_caesar_bruteforce_binary() {
+        let mut rng = StdRng::seed_from_u64(94);
+        let random_bytes: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+
+        for target in [string_hex_encode(&random_bytes).unwrap(), String::new()] {
+            let candidates = caesar_bruteforce(&target).unwrap();
+            assert_eq!(candidates.len(), 256);
+            assert!(candidates.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+            let mut shifts: Vec<u8> = candidates.iter().map(|candidate| candidate.shift).collect();
+            shifts.sort_unstable();
+            assert!(shifts.iter().copied().eq(0..=u8::MAX));
+        }
+    }
+
+    // Test Caesar bruteforce on the incorrect hex ciphertexts.
+    #[test]
+    fn test_caesar_bruteforce_invalid_hex() {
+        for target in ["ZZ", "ABC", "Hello"] {
+            assert!(caesar_bruteforce(target).is_err());
+        }
+    }
+
+    // Test Caesar streaming encryption and decryption of the data of several chunks, the streamed ciphertext is the same as the one
+    // of the whole data, the separators between the pairs of the streamed ciphertext are skipped, the incorrect hex ciphertexts are denied.
+    #[test]
+    fn test_caesar_stream() {
+        let mut rng = StdRng::seed_from_u64(98);
+        for data_len in [0, 1, STREAM_CHUNK_SIZE, 3 * STREAM_CHUNK_SIZE + 17] {
+            let data: Vec<u8> = (0..data_len).map(|_| rng.gen()).collect();
+
+            let mut ciphertext = vec![];
+            let written_len = caesar_encrypt_stream(data.as_slice(), &mut ciphertext, "-1337").unwrap();
+            assert_eq!(written_len, ciphertext.len() as u64);
+            let key = Caesar.validate_key("-1337").unwrap();
+            assert!(ciphertext == string_hex_encode(&Caesar.encrypt(&data, &key).unwrap()).unwrap().into_bytes());
+
+            ciphertext.extend_from_slice(b"\r\n");
+            let mut decrypted_bytes = vec![];
+            assert_eq!(caesar_decrypt_stream(ciphertext.as_slice(), &mut decrypted_bytes, "-1337").unwrap(), data_len as u64);
+            assert!(decrypted_bytes == data);
+        }
+
+        for ciphertext in ["ABC", "A BCD", "ZZ", "AB\nC", "4:B4C"] {
+            assert!(caesar_decrypt_stream(ciphertext.as_bytes(), &mut vec![], "3").is_err());
+        }
+        assert_eq!(caesar_decrypt_stream(&b" \t4B4C\n"[..], &mut vec![], "3").unwrap(), 2);
+        let mut decrypted_bytes = vec![];
+        assert_eq!(caesar_decrypt_stream(&b"4B:4C-4D\n4E"[..], &mut decrypted_bytes, "3").unwrap(), 4);
+        assert_eq!(decrypted_bytes, b"HIJK");
+        let error_message = caesar_decrypt_stream(&b"4B4C4G"[..], &mut vec![], "3").unwrap_err().to_string();
+        assert!(error_message.contains("the character 'G' at position 5 is not a hex symbol"), "{}", error_message);
+        assert!(caesar_encrypt_stream(&b"data"[..], &mut vec![], "three").is_err());
+    }
+
+    // Test the interruption of Caesar streaming encryption and decryption by the failing reader and writer in the middle of the data,
+    // the errors are returned instead of the panics.
+    #[test]
+    fn test_caesar_stream_interruption() {
+        // A reader, which fails after the data.
+        struct FailingReader;
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "the connection is lost"))
+            }
+        }
+
+        // A writer, which fails after the limit of the written bytes.
+        struct FailingWriter {
+            written_len: usize,
+            limit: usize,
+        }
+        impl Write for FailingWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                if self.written_len + buf.len() > self.limit {
+                    return Err(std::io::Error::other("the disk is full"));
+                }
+                self.written_len += buf.len();
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let data = vec![0x5Au8; 3 * STREAM_CHUNK_SIZE];
+        let encryption_error = caesar_encrypt_stream(data.as_slice().chain(FailingReader), &mut vec![], "7").unwrap_err();
+        assert!(encryption_error.to_string().contains("the connection is lost"));
+        let encryption_error = caesar_encrypt_stream(data.as_slice(), FailingWriter { written_len: 0, limit: 3 * STREAM_CHUNK_SIZE }, "7").unwrap_err();
+        assert!(encryption_error.to_string().contains("the disk is full"));
+
+        let mut ciphertext = vec![];
+        caesar_encrypt_stream(data.as_slice(), &mut ciphertext, "7").unwrap();
+        let decryption_error = caesar_decrypt_stream(ciphertext.as_slice().chain(FailingReader), &mut vec![], "7").unwrap_err();
+        assert!(decryption_error.to_string().contains("the connection is lost"));
+        let decryption_error = caesar_decrypt_stream(ciphertext.as_slice(), FailingWriter { written_len: 0, limit: STREAM_CHUNK_SIZE }, "7").unwrap_err();
+        assert!(decryption_error.to_string().contains("the disk is full"));
+    }
+
+    // Test Caesar with the negative key, it equals the positive key, shifted by 256.
+    #[test]
+    fn test_caesar_negative_key() {
+        let mut target = String::from("MammaMia");
+        let encryption_result = caesar(&Mode::Encode, &mut target.clone(), "-133").unwrap();
+
+        assert_eq!(encryption_result, caesar(&Mode::Encode, &mut target, "123").unwrap());
+        assert_eq!(caesar(&Mode::Decode, &mut encryption_result.clone(), "-133").unwrap(), "MammaMia");
+    }
+
     // Test Caesar character encryption.
     #[test]
     fn test_caesar_character_encryption() {
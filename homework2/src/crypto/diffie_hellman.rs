@@ -1,38 +1,287 @@
+use std::collections::HashMap;
 use std::error::Error;
 
 // Import required randomisation items.
 use rand::Rng;
 
+use crate::crypto::sha256::hkdf_sha256;
+use crate::encoding::{string_hex_decode, string_hex_encode};
+use crate::logic::bigint::randomisation::secure_rng;
 use crate::logic::bigint::{BigIntSign, ChonkerInt};
 use crate::logic::error::OperationError;
 
+// The result of the Diffie-Hellman exchange of two participants with all intermediate values: the public value g^a (modulo p)
+// of each participant, the shared key g^(a * b) (modulo p) and the key in hex, derived from the shared key, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DiffieHellmanResult {
-    pub shared_prime: ChonkerInt,
-    pub shared_base: ChonkerInt,
+    pub prime: ChonkerInt,
+    pub base: ChonkerInt,
     pub secret_a: ChonkerInt,
     pub secret_b: ChonkerInt,
-    pub package_from_a_to_b: ChonkerInt,
-    pub package_from_b_to_a: ChonkerInt,
-    pub result_a: ChonkerInt,
-    pub result_b: ChonkerInt,
-    pub success: bool,
+    pub public_a: ChonkerInt,
+    pub public_b: ChonkerInt,
+    pub shared_key: ChonkerInt,
+    pub derived_key: Option<String>,
 }
 
 // Implement default value for DiffieHellmanResult.
 impl Default for DiffieHellmanResult {
     fn default() -> Self {
         DiffieHellmanResult {
-            shared_prime: Default::default(),
-            shared_base: Default::default(),
+            prime: Default::default(),
+            base: Default::default(),
             secret_a: Default::default(),
             secret_b: Default::default(),
-            package_from_a_to_b: Default::default(),
-            package_from_b_to_a: Default::default(),
-            result_a: Default::default(),
-            result_b: Default::default(),
-            success: false,
+            public_a: Default::default(),
+            public_b: Default::default(),
+            shared_key: Default::default(),
+            derived_key: None,
+        }
+    }
+}
+
+impl DiffieHellmanResult {
+    // Recalculate both sides of the exchange from the secrets, true only if the public values, the shared key of each side
+    // and the derived key agree with the stored ones. Malformed values, e.g. the zero prime or the invalid hex, are not verified.
+    pub fn verify(&self) -> bool {
+        if self.secret_a.is_negative() || self.secret_b.is_negative() {
+            return false;
+        }
+
+        self.recalculate().unwrap_or(false)
+    }
+
+    // Compare the stored values with the recalculated ones, the errors of the recalculation are passed to the caller.
+    fn recalculate(&self) -> Result<bool, Box<dyn Error>> {
+        let public_a = self.base.checked_modpow(&self.secret_a, &self.prime)?;
+        let public_b = self.base.checked_modpow(&self.secret_b, &self.prime)?;
+        let shared_key_a = self.public_b.checked_modpow(&self.secret_a, &self.prime)?;
+        let shared_key_b = self.public_a.checked_modpow(&self.secret_b, &self.prime)?;
+        let derived_key_agrees = match &self.derived_key {
+            Some(derived_key) => {
+                let derived_key = string_hex_decode(derived_key)?;
+                df_derive_key(&self.shared_key, &self.prime, derived_key.len())? == derived_key
+            }
+            None => true,
+        };
+
+        Ok(public_a == self.public_a
+            && public_b == self.public_b
+            && shared_key_a == self.shared_key
+            && shared_key_b == self.shared_key
+            && derived_key_agrees)
+    }
+}
+
+// The result of the Diffie-Hellman exchange of any number of participants in a ring: the public value of each participant,
+// the intermediate values, which each participant passes to the next one in the following rounds, and the key of each participant.
+// The intermediate values of a round are indexed by the sending participant, the participant i sends its value to i + 1,
+// the last participant sends it to the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiDiffieHellmanResult {
+    pub shared_prime: ChonkerInt,
+    pub shared_base: ChonkerInt,
+    pub secrets: Vec<ChonkerInt>,
+    pub public_values: Vec<ChonkerInt>,
+    pub intermediate_values: Vec<Vec<ChonkerInt>>,
+    pub results: Vec<ChonkerInt>,
+    pub success: bool,
+}
+
+// The secret exponent, recovered from the public value of the participant with the discrete logarithm search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiscreteLogResult {
+    pub shared_prime: ChonkerInt,
+    pub shared_base: ChonkerInt,
+    pub public_value: ChonkerInt,
+    pub secret: ChonkerInt,
+}
+
+// Argument for the shared prime and the shared base, which requests generation of a safe prime and its primitive root.
+pub const DF_GENERATE_ARGUMENT: &str = "generate";
+// Bit length of the generated safe prime.
+const DF_SAFE_PRIME_BITS: u64 = 128;
+// Default length of the key in bytes, derived from the shared secret.
+pub const DF_DERIVED_KEY_LEN: usize = 32;
+// Context of the key derivation, it binds the derived key to this tool and to the Diffie-Hellman exchange.
+const DF_KDF_INFO: &[u8] = b"enc diffie-hellman derived key";
+// Longest shared prime in bits for the discrete logarithm search, the table of the baby steps holds up to 2^20 values.
+pub const DF_CRACK_MAX_BITS: u64 = 40;
+// Smallest amount of the participants of the Diffie-Hellman exchange.
+const DF_MIN_PARTICIPANTS: usize = 2;
+// Prefix of the names of the MODP groups, the argument for the shared prime, which requests one of the groups.
+pub const DF_GROUP_PREFIX: &str = "group";
+// Generator of every MODP group.
+const DF_GROUP_GENERATOR: u64 = 2;
+
+// A standardised MODP group of RFC 3526: a safe prime of the fixed bit length with the generator 2.
+// More about the groups: https://www.rfc-editor.org/rfc/rfc3526
+pub struct ModpGroup {
+    pub name: &'static str,
+    pub bits: u64,
+    prime_hex: &'static str,
+}
+
+// The MODP groups of RFC 3526, the primes are written in hex, the most significant byte first.
+pub static MODP_GROUPS: [ModpGroup; 4] = [
+    ModpGroup {
+        name: "group5",
+        bits: 1536,
+        prime_hex: "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+            020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+            4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+            EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+            98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+            9ED529077096966D670C354E4ABC9804F1746C08CA237327FFFFFFFFFFFFFFFF",
+    },
+    ModpGroup {
+        name: "group14",
+        bits: 2048,
+        prime_hex: "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+            020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+            4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+            EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+            98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+            9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+            E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718\
+            3995497CEA956AE515D2261898FA051015728E5A8AACAA68FFFFFFFFFFFFFFFF",
+    },
+    ModpGroup {
+        name: "group15",
+        bits: 3072,
+        prime_hex: "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+            020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+            4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+            EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+            98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+            9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+            E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718\
+            3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33\
+            A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7\
+            ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864\
+            D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2\
+            08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A93AD2CAFFFFFFFFFFFFFFFF",
+    },
+    ModpGroup {
+        name: "group16",
+        bits: 4096,
+        prime_hex: "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+            020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+            4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+            EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+            98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+            9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+            E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718\
+            3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33\
+            A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7\
+            ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864\
+            D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2\
+            08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A92108011A723C12A787E6D7\
+            88719A10BDBA5B2699C327186AF4E23C1A946834B6150BDA2583E9CA2AD44CE8\
+            DBBBC2DB04DE8EF92E8EFC141FBECAA6287C59474E6BC05D99B2964FA090C3A2\
+            233BA186515BE7ED1F612970CEE2D7AFB81BDD762170481CD0069127D5B05AA9\
+            93B4EA988D8FDDC186FFB7DC90A6C08F4DF435C934063199FFFFFFFFFFFFFFFF",
+    },
+];
+
+// Implement the parameters of the MODP group.
+impl ModpGroup {
+    // The safe prime of the group.
+    pub fn prime(&self) -> ChonkerInt {
+        match string_hex_decode(self.prime_hex) {
+            Ok(bytes) => ChonkerInt::from_bytes_be(&bytes),
+            Err(error) => panic!("the prime of the MODP group {} is not a correct hex string: {} (ModpGroup::prime)", self.name, error),
         }
     }
+
+    // The generator of the group.
+    pub fn generator(&self) -> ChonkerInt {
+        ChonkerInt::from(DF_GROUP_GENERATOR)
+    }
+}
+
+// Find the MODP group by its name, like "group14".
+pub fn df_modp_group(name: &str) -> Result<&'static ModpGroup, OperationError> {
+    match MODP_GROUPS.iter().find(|group| group.name == name) {
+        Some(group) => Ok(group),
+        None => {
+            let names: Vec<String> = MODP_GROUPS.iter().map(|group| format!("\"{}\"", group.name)).collect();
+            Err(OperationError::new(&format!("the MODP group \"{}\" is unknown. Correct values: {}.", name, names.join(", "))))
+        }
+    }
+}
+
+// A single participant of the Diffie-Hellman exchange, it keeps its own secret and never sees the secret of the peer.
+// Each session of a backend holds its own party, only the public part is sent to the peer.
+#[derive(Clone)]
+pub struct DhParty {
+    shared_prime: ChonkerInt,
+    shared_base: ChonkerInt,
+    secret: ChonkerInt,
+    public_value: ChonkerInt,
+}
+
+// The public part of the Diffie-Hellman participant, it is safe to send it over the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DhPublicKey {
+    pub shared_prime: ChonkerInt,
+    pub shared_base: ChonkerInt,
+    pub public_value: ChonkerInt,
+}
+
+// Implement the operations of the Diffie-Hellman participant.
+impl DhParty {
+    // Create the participant with the validated shared prime and shared base, the secret is generated in the range 1 - (p - 2).
+    pub fn new<R: Rng + ?Sized>(shared_prime: &ChonkerInt, shared_base: &ChonkerInt, rng: &mut R) -> Result<DhParty, OperationError> {
+        validate_df_shared_values(shared_prime, shared_base)?;
+        let secret = df_random_secret(shared_prime, shared_base, rng);
+
+        DhParty::with_validated_secret(shared_prime, shared_base, secret)
+    }
+
+    // Create the participant with the known secret, all parameters are validated.
+    pub fn from_secret(shared_prime: &ChonkerInt, shared_base: &ChonkerInt, secret: &ChonkerInt) -> Result<DhParty, OperationError> {
+        validate_df_parameters(shared_prime, shared_base, std::slice::from_ref(secret))?;
+
+        DhParty::with_validated_secret(shared_prime, shared_base, secret.clone())
+    }
+
+    // Create the participant with the parameters, which were already validated, and calculate its public value g^a (modulo p).
+    fn with_validated_secret(shared_prime: &ChonkerInt, shared_base: &ChonkerInt, secret: ChonkerInt) -> Result<DhParty, OperationError> {
+        let public_value = shared_base.checked_modpow(&secret, shared_prime)?;
+
+        Ok(DhParty {
+            shared_prime: shared_prime.clone(),
+            shared_base: shared_base.clone(),
+            secret,
+            public_value,
+        })
+    }
+
+    // The public value g^a (modulo p), which is sent to the peer.
+    pub fn public_value(&self) -> &ChonkerInt {
+        &self.public_value
+    }
+
+    // The public part of the participant with the shared values, which is sent to the peer.
+    pub fn public_key(&self) -> DhPublicKey {
+        DhPublicKey {
+            shared_prime: self.shared_prime.clone(),
+            shared_base: self.shared_base.clone(),
+            public_value: self.public_value.clone(),
+        }
+    }
+
+    // Calculate the shared key (g^b)^a (modulo p) from the public value of the peer, the public value is validated first.
+    pub fn agree(&self, peer_public: &ChonkerInt) -> Result<ChonkerInt, OperationError> {
+        validate_peer_public(peer_public, &self.shared_prime)?;
+
+        peer_public.checked_modpow(&self.secret, &self.shared_prime)
+    }
 }
 
 struct DiffieHellmanParameters {
@@ -42,156 +291,452 @@ struct DiffieHellmanParameters {
     secret_b: ChonkerInt,
 }
 
-// Diffie-Hellman implementation.
+// Diffie-Hellman implementation, the key of the default length is derived from the shared secret.
 pub fn diffie_hellman(
     shared_prime: Option<String>,
     shared_base: Option<String>,
     secret_a: Option<String>,
     secret_b: Option<String>,
+) -> Result<DiffieHellmanResult, Box<dyn Error>> {
+    diffie_hellman_with_key_length(shared_prime, shared_base, secret_a, secret_b, DF_DERIVED_KEY_LEN)
+}
+
+// Diffie-Hellman implementation, the key of the requested length in bytes is derived from the shared secret by each participant.
+pub fn diffie_hellman_with_key_length(
+    shared_prime: Option<String>,
+    shared_base: Option<String>,
+    secret_a: Option<String>,
+    secret_b: Option<String>,
+    key_length: usize,
 ) -> Result<DiffieHellmanResult, Box<dyn Error>> {
     let parameters = check_df_parameters(&shared_prime, &shared_base, &secret_a, &secret_b)?;
 
-    let package_from_a_to_b = parameters
-        .shared_base
-        .modpow(&parameters.secret_a, &parameters.shared_prime);
-    let package_from_b_to_a = parameters
-        .shared_base
-        .modpow(&parameters.secret_b, &parameters.shared_prime);
-    let result_a = package_from_b_to_a.modpow(&parameters.secret_a, &parameters.shared_prime);
-    let result_b = package_from_a_to_b.modpow(&parameters.secret_b, &parameters.shared_prime);
-    let success = result_a == result_b;
+    // Each participant sees only the public value of the peer.
+    let party_a = DhParty::with_validated_secret(&parameters.shared_prime, &parameters.shared_base, parameters.secret_a)?;
+    let party_b = DhParty::with_validated_secret(&parameters.shared_prime, &parameters.shared_base, parameters.secret_b)?;
+    let public_a = party_a.public_value().clone();
+    let public_b = party_b.public_value().clone();
+    let shared_key = party_a.agree(&public_b)?;
+    if party_b.agree(&public_a)? != shared_key {
+        return Err(Box::new(OperationError::new("the shared keys of the participants A and B of the Diffie-Hellman calculation differ.")));
+    }
+    let derived_key = df_derive_key(&shared_key, &parameters.shared_prime, key_length)?;
 
     Ok(DiffieHellmanResult {
-        shared_prime: parameters.shared_prime,
-        shared_base: parameters.shared_base,
-        secret_a: parameters.secret_a,
-        secret_b: parameters.secret_b,
-        package_from_a_to_b,
-        package_from_b_to_a,
-        result_a,
-        result_b,
+        prime: parameters.shared_prime,
+        base: parameters.shared_base,
+        secret_a: party_a.secret,
+        secret_b: party_b.secret,
+        public_a,
+        public_b,
+        shared_key,
+        derived_key: Some(string_hex_encode(&derived_key)?),
+    })
+}
+
+// Derive the symmetric key of the requested length in bytes from the shared secret with HKDF-SHA-256 of RFC 5869.
+// The shared secret is serialised into the big-endian bytes of the length of the shared prime, padded with the leading zeros,
+// the salt is empty and the info is the fixed context of the tool. HKDF does not mix the length into the output,
+// thus the shorter key is the prefix of the longer key, derived from the same shared secret.
+pub fn df_derive_key(shared_secret: &ChonkerInt, shared_prime: &ChonkerInt, key_length: usize) -> Result<Vec<u8>, OperationError> {
+    let secret_bytes = shared_secret.to_bytes_be();
+    let prime_length = shared_prime.to_bytes_be().len();
+    let mut input_key = vec![0u8; prime_length.saturating_sub(secret_bytes.len())];
+    input_key.extend_from_slice(&secret_bytes);
+
+    hkdf_sha256(&[], &input_key, DF_KDF_INFO, key_length)
+}
+
+// Multi-party Diffie-Hellman implementation. The participants form a ring, each participant sends its public value g^a_i
+// to the next one, in every following round each participant raises the received value to its secret and sends it further.
+// After n - 1 rounds every participant holds g^(a_1 * ... * a_n), the shared key.
+// More about the idea: https://en.wikipedia.org/wiki/Diffie%E2%80%93Hellman_key_exchange#Operation_with_more_than_two_parties
+pub fn diffie_hellman_multi(
+    shared_prime: &ChonkerInt,
+    shared_base: &ChonkerInt,
+    secrets: &[ChonkerInt],
+) -> Result<MultiDiffieHellmanResult, OperationError> {
+    let participants = secrets.len();
+    if participants < DF_MIN_PARTICIPANTS {
+        return Err(OperationError::new(&format!("the multi-party Diffie-Hellman calculation received {} secrets, correct amount is at least {}, one for every participant.", participants, DF_MIN_PARTICIPANTS)));
+    }
+    validate_df_parameters(shared_prime, shared_base, secrets)?;
+
+    let public_values = secrets
+        .iter()
+        .map(|secret| shared_base.checked_modpow(secret, shared_prime))
+        .collect::<Result<Vec<ChonkerInt>, OperationError>>()?;
+
+    // Each participant raises the value, received from the previous participant of the ring, to its secret.
+    // The values of the first round are the public values, the values of the last round are the keys.
+    let mut intermediate_values = Vec::with_capacity(participants - DF_MIN_PARTICIPANTS);
+    let mut received_values = public_values.clone();
+    for round in 1..participants {
+        let raised_values = (0..participants)
+            .map(|index| received_values[(index + participants - 1) % participants].checked_modpow(&secrets[index], shared_prime))
+            .collect::<Result<Vec<ChonkerInt>, OperationError>>()?;
+        let sent_values = std::mem::replace(&mut received_values, raised_values);
+        if round > 1 {
+            intermediate_values.push(sent_values);
+        }
+    }
+
+    let success = received_values.iter().all(|result| *result == received_values[0]);
+
+    Ok(MultiDiffieHellmanResult {
+        shared_prime: shared_prime.clone(),
+        shared_base: shared_base.clone(),
+        secrets: secrets.to_vec(),
+        public_values,
+        intermediate_values,
+        results: received_values,
         success,
     })
 }
 
+// Multi-party Diffie-Hellman calculation with the arguments of the command line, the shared prime, the shared base
+// and each of the secrets are checked or generated, if they are absent, as for the two-party calculation.
+pub fn diffie_hellman_multi_from_strings(
+    shared_prime: Option<String>,
+    shared_base: Option<String>,
+    secrets: &[Option<String>],
+) -> Result<MultiDiffieHellmanResult, Box<dyn Error>> {
+    let mut rng = secure_rng();
+    let (shared_prime, shared_base) = check_df_shared_values(&shared_prime, &shared_base, &mut rng)?;
+    validate_df_shared_values(&shared_prime, &shared_base)?;
+    let secrets = secrets
+        .iter()
+        .enumerate()
+        .map(|(index, secret)| check_df_secret(secret, &shared_prime, &shared_base, &df_participant_name(index), &mut rng))
+        .collect::<Result<Vec<ChonkerInt>, Box<dyn Error>>>()?;
+
+    Ok(diffie_hellman_multi(&shared_prime, &shared_base, &secrets)?)
+}
+
+// Recover the secret exponent of the participant from its public value g^a (modulo p) with the baby-step giant-step search.
+// It demonstrates, why the small shared primes are weak, the search is limited to the primes of DF_CRACK_MAX_BITS bits,
+// since the memory of the search grows as O(sqrt(p)).
+pub fn diffie_hellman_crack(shared_prime: &str, shared_base: &str, public_value: &str) -> Result<DiscreteLogResult, Box<dyn Error>> {
+    let shared_prime = match shared_prime.parse::<ChonkerInt>() {
+        Ok(candidate) if candidate.is_positive() => candidate,
+        Ok(_) => return Err(Box::new(OperationError::new("did no receive a correct shared prime for the discrete logarithm search, the received value is not positive. Correct value is a prime number."))),
+        Err(error) => return Err(Box::new(OperationError::new(&format!("did no receive a correct shared prime for the discrete logarithm search, {}", error)))),
+    };
+    if shared_prime.bit_length() > DF_CRACK_MAX_BITS {
+        return Err(Box::new(OperationError::new(&format!("the shared prime has {} bits, the discrete logarithm search keeps O(sqrt(p)) values in memory and accepts only the primes of at most {} bits. Correct value is a prime number below 2^{}.", shared_prime.bit_length(), DF_CRACK_MAX_BITS, DF_CRACK_MAX_BITS))));
+    }
+    if !shared_prime.is_prime_probabilistic(df_primality_trials(&shared_prime)) {
+        return Err(Box::new(OperationError::new("the shared prime of the discrete logarithm search is not a prime, according to Miller-Rabin primality test. Correct value is a prime number.")));
+    }
+
+    let shared_base = match shared_base.parse::<ChonkerInt>() {
+        Ok(candidate) if candidate > 1u64 && candidate < &shared_prime - 1u64 => candidate,
+        Ok(_) => return Err(Box::new(OperationError::new("the shared base of the discrete logarithm search is out of the range 2 - (p - 2), where p is the shared prime."))),
+        Err(error) => return Err(Box::new(OperationError::new(&format!("did no receive a correct shared base for the discrete logarithm search, {}", error)))),
+    };
+
+    let public_value = match public_value.parse::<ChonkerInt>() {
+        Ok(candidate) if candidate.is_positive() && candidate < shared_prime => candidate,
+        Ok(_) => return Err(Box::new(OperationError::new("the public value of the discrete logarithm search is out of the range 1 - (p - 1), where p is the shared prime."))),
+        Err(error) => return Err(Box::new(OperationError::new(&format!("did no receive a correct public value for the discrete logarithm search, {}", error)))),
+    };
+
+    // The order of the multiplicative group is p - 1, thus the secret is below it.
+    match discrete_log_bsgs(&shared_base, &public_value, &shared_prime, &(&shared_prime - 2u64))? {
+        Some(secret) => Ok(DiscreteLogResult { shared_prime, shared_base, public_value, secret }),
+        None => Err(Box::new(OperationError::new("the public value is not a power of the shared base modulo the shared prime, it is out of the subgroup, generated by the base, no secret produces it."))),
+    }
+}
+
+// Solve the discrete logarithm base^x == target (modulo m) for the smallest x in the range 0 - max_exponent with the baby-step giant-step
+// search. The exponent is split into x = i * s + j with s = floor(sqrt(max_exponent)) + 1: the baby steps base^j for j < s are
+// stored in the table, the giant steps target * base^(-s * i) are looked up in it. Time and memory are O(sqrt(max_exponent)),
+// the caller bounds the max exponent. Returns None, if there is no such exponent, and an error for the negative base, target
+// or max exponent, the non positive modulus, and the max exponent with the table of the baby steps too big for the memory.
+// More about the idea: https://en.wikipedia.org/wiki/Baby-step_giant-step
+pub fn discrete_log_bsgs(base: &ChonkerInt, target: &ChonkerInt, modulus: &ChonkerInt, max_exponent: &ChonkerInt) -> Result<Option<ChonkerInt>, OperationError> {
+    if base.is_negative() || target.is_negative() || !modulus.is_positive() || max_exponent.is_negative() {
+        return Err(OperationError::new("the discrete logarithm search accepts only the non negative base, target and max exponent and the positive modulus."));
+    }
+
+    let base = base % modulus;
+    let steps = &max_exponent.sqrt() + 1u64;
+    let step_count = match steps.to_u128() {
+        Ok(step_count) if step_count <= usize::MAX as u128 => step_count,
+        _ => return Err(OperationError::new(&format!("the max exponent {} of the discrete logarithm search is too big, the table of its baby steps does not fit into the memory.", max_exponent))),
+    };
+
+    // The baby steps, the smallest exponent is kept for the repeated values.
+    let mut baby_steps: HashMap<ChonkerInt, u128> = HashMap::new();
+    let mut baby_step = &ChonkerInt::from(1) % modulus;
+    for exponent in 0..step_count {
+        baby_steps.entry(baby_step.clone()).or_insert(exponent);
+        baby_step = &(&baby_step * &base) % modulus;
+    }
+
+    // The last baby step is base^s, its inverse is the giant step. The base without the inverse has no other powers to find.
+    let giant_step = baby_step.modinv(modulus);
+    let mut giant_value = target % modulus;
+    for giant_index in 0..step_count {
+        if let Some(baby_index) = baby_steps.get(&giant_value) {
+            let exponent = &(&steps * &ChonkerInt::from(giant_index)) + &ChonkerInt::from(*baby_index);
+            return Ok(if exponent <= *max_exponent { Some(exponent) } else { None });
+        }
+
+        giant_value = match &giant_step {
+            Some(giant_step) => &(&giant_value * giant_step) % modulus,
+            None => return Ok(None),
+        };
+    }
+
+    Ok(None)
+}
+
+// Name the participant of the Diffie-Hellman exchange by its index: "A" to "Z", then the numbers from 27.
+pub fn df_participant_name(index: usize) -> String {
+    match index {
+        0..=25 => ((b'A' + index as u8) as char).to_string(),
+        _ => (index + 1).to_string(),
+    }
+}
+
 fn check_df_parameters(
     shared_prime: &Option<String>,
     shared_base: &Option<String>,
     secret_a: &Option<String>,
     secret_b: &Option<String>,
 ) -> Result<DiffieHellmanParameters, Box<dyn Error>> {
-    // Initiate RNG. For possible value length randomisation.
-    let mut rng = rand::thread_rng();
+    // Initiate the cryptographically secure RNG. For possible value length randomisation and the key material.
+    let mut rng = secure_rng();
+
+    // The secrets are generated below the validated shared prime.
+    let (shared_prime, shared_base) = check_df_shared_values(shared_prime, shared_base, &mut rng)?;
+    validate_df_shared_values(&shared_prime, &shared_base)?;
+    let secret_a = check_df_secret(secret_a, &shared_prime, &shared_base, "A", &mut rng)?;
+    let secret_b = check_df_secret(secret_b, &shared_prime, &shared_base, "B", &mut rng)?;
+    validate_df_secrets(&shared_prime, &[secret_a.clone(), secret_b.clone()])?;
+
+    // Generate and return a package of parameters for Diffie-Hellman algorithm calculations.
+    Ok(DiffieHellmanParameters {
+        shared_prime,
+        shared_base,
+        secret_a,
+        secret_b,
+    })
+}
+
+// Check the shared prime and the shared base, generate them, if they are absent or requested with the "generate" argument.
+// The name of the MODP group in place of the shared prime selects the prime and the generator of the group.
+fn check_df_shared_values<R: Rng + ?Sized>(
+    shared_prime: &Option<String>,
+    shared_base: &Option<String>,
+    rng: &mut R,
+) -> Result<(ChonkerInt, ChonkerInt), Box<dyn Error>> {
+    // The group is already checked, the base of the group is its generator.
+    if let Some(name) = shared_prime.as_deref().filter(|value| value.starts_with(DF_GROUP_PREFIX)) {
+        let group = df_modp_group(name)?;
+        return match shared_base {
+            Some(value) if *value != DF_GROUP_GENERATOR.to_string() => Err(Box::new(OperationError::new(&format!("did no receive a correct shared base for the MODP group {} for the Diffie-Hellman calculation. Correct value is the generator of the group {} or \"none\".", group.name, DF_GROUP_GENERATOR)))),
+            _ => Ok((group.prime(), group.generator())),
+        };
+    }
 
     // Check inputs, are they correct/incorrect, are they empty.
     // If they are empty, randomly generate required numbers.
     // Check shared prime.
+    let generate_safe_prime = matches!(shared_prime, Some(value) if value == DF_GENERATE_ARGUMENT);
     let shared_prime = match shared_prime {
+        Some(value) if value == DF_GENERATE_ARGUMENT => {
+            // Generate a safe prime p = 2q + 1, its primitive root is generated or checked with the known factorization of p - 1.
+            ChonkerInt::new_safe_prime(DF_SAFE_PRIME_BITS, rng)
+        }
         Some(value) => {
-            // Check the received value, is it numeric and a prime.
-            match check_parameter_is_numeric(value) {
-                true => {
+            // Check the received value, is it a correct positive number and a prime.
+            match value.parse::<ChonkerInt>() {
+                Ok(candidate) if !candidate.is_negative() => {
                     // Check if the numeric value is prime.
-                    let candidate = ChonkerInt::from(String::from(value));
-
                     // Check the length of the received number, if it is too long, deny the service.
-                    let number_length = candidate.get_vec().len();
+                    let number_length = candidate.digit_count();
 
                     if number_length > 100 {
                         return Err(Box::new(OperationError::new("the received candidate has a length more than 100, primality test will take a significant amount of time, choose a smaller value. Correct value is a prime number with the length under 100.")));
                     }
 
-                    let number_of_trials = if number_length < 25 {
-                        Some(20)
-                    } else if (25..50).contains(&number_length) {
-                        Some(10)
-                    } else if (50..75).contains(&number_length) {
-                        Some(3)
-                    } else { // number_length >= 75 && number_length <= 100
-                        Some(1)
-                    };
-
-                    if candidate.is_prime_probabilistic(number_of_trials) {
+                    if candidate.is_prime_probabilistic(df_primality_trials(&candidate)) {
                         candidate
                     } else {
                         return Err(Box::new(OperationError::new("the received candidate number is not a prime, according to Miller-Rabin primality test. Correct value is a prime number with the length under 100.")));
                     }
                 }
-                false => return Err(Box::new(OperationError::new("did no receive a correct shared prime for the Diffie-Hellman calculation. Correct value is a prime number with the length under 100."))),
+                Ok(_) => return Err(Box::new(OperationError::new("did no receive a correct shared prime for the Diffie-Hellman calculation, the received value is negative. Correct value is a prime number with the length under 100."))),
+                Err(error) => return Err(Box::new(OperationError::new(&format!("did no receive a correct shared prime for the Diffie-Hellman calculation, {}", error)))),
             }
         }
         None => {
             // Generate a random prime of random length in the range of 5-10.
             let random_length: u64 = rng.gen_range(5..=10);
-            ChonkerInt::new_prime(&random_length)
+            ChonkerInt::new_prime_with_rng(&random_length, rng)
         }
     };
 
+    // The safe prime p = 2q + 1 requires the generator of its subgroup of the prime order q in place of the primitive root.
+    let safe_prime = generate_safe_prime || df_safe_prime_subgroup_order(&shared_prime).is_some();
+
     // Check shared base.
     let shared_base = match shared_base {
+        Some(value) if value == DF_GENERATE_ARGUMENT && safe_prime => df_safe_prime_subgroup_generator(&shared_prime, rng),
+        Some(value) if value == DF_GENERATE_ARGUMENT => shared_prime.new_primitive_root_with_rng(rng),
         Some(value) => {
-            match check_parameter_is_numeric(value) {
-                true => {
-                    // Check if the numeric value is a primitive root to the shared base.
-                    let candidate = ChonkerInt::from(String::from(value));
-
-                    match candidate.is_primitive_root(&shared_prime) {
-                        true => candidate,
-                        false => return Err(Box::new(OperationError::new("the received candidate number is not a primitive root to the provided prime, did no receive a correct shared base for the Diffie-Hellman calculation. Correct value is a primitive root to the shared prime."))),
+            match value.parse::<ChonkerInt>() {
+                Ok(candidate) if !candidate.is_negative() => {
+                    // Check if the numeric value is a primitive root to the shared prime. The base of the safe prime
+                    // and the base out of the range 2 - (p - 2) are checked with the validation of the parameters.
+                    if safe_prime || candidate <= 1u64 || candidate >= &shared_prime - 1u64 || candidate.is_primitive_root(&shared_prime) {
+                        candidate
+                    } else {
+                        return Err(Box::new(OperationError::new("the received candidate number is not a primitive root to the provided prime, did no receive a correct shared base for the Diffie-Hellman calculation. Correct value is a primitive root to the shared prime.")));
                     }
                 }
-                false => return Err(Box::new(OperationError::new("did no receive a correct shared base for the Diffie-Hellman calculation. Correct value is a primitive root to the shared prime."))),
+                Ok(_) => return Err(Box::new(OperationError::new("did no receive a correct shared base for the Diffie-Hellman calculation, the received value is negative. Correct value is a primitive root to the shared prime."))),
+                Err(error) => return Err(Box::new(OperationError::new(&format!("did no receive a correct shared base for the Diffie-Hellman calculation, {}", error)))),
             }
         }
+        None if safe_prime => df_safe_prime_subgroup_generator(&shared_prime, rng),
         None => {
             // Generate a random primitive root to the shared prime..
-            shared_prime.new_primitive_root()
+            shared_prime.new_primitive_root_with_rng(rng)
         }
     };
 
-    // Check secret value A.
-    let secret_a = match secret_a {
+    Ok((shared_prime, shared_base))
+}
+
+// Check the secret of the participant, generate it in the range 1 - (p - 2), if it is absent.
+fn check_df_secret<R: Rng + ?Sized>(
+    secret: &Option<String>,
+    shared_prime: &ChonkerInt,
+    shared_base: &ChonkerInt,
+    participant: &str,
+    rng: &mut R,
+) -> Result<ChonkerInt, Box<dyn Error>> {
+    match secret {
         Some(value) => {
-            // Check the received value, is it numeric.
-            match check_parameter_is_numeric(value) {
-                true => {
-                    ChonkerInt::from(String::from(value))
-                }
-                false => return Err(Box::new(OperationError::new("did no receive a correct value for the peer A for the Diffie-Hellman calculation. Correct value is a positive number."))),
+            // Check the received value, is it a correct positive number.
+            match value.parse::<ChonkerInt>() {
+                Ok(candidate) if !candidate.is_negative() => Ok(candidate),
+                Ok(_) => Err(Box::new(OperationError::new(&format!("did no receive a correct value for the peer {} for the Diffie-Hellman calculation, the received value is negative. Correct value is a positive number.", participant)))),
+                Err(error) => Err(Box::new(OperationError::new(&format!("did no receive a correct value for the peer {} for the Diffie-Hellman calculation, {}", participant, error)))),
             }
         }
-        None => {
-            // Generate a random number of random length in the range of 500-1000.
-            let random_length: u64 = rng.gen_range(500..=1000);
-            ChonkerInt::new_rand(&random_length, &BigIntSign::Positive)
+        None => Ok(df_random_secret(shared_prime, shared_base, rng)),
+    }
+}
+
+// Generate the secret in the range 1 - (p - 2), its public value has to pass the validation of the peer public value,
+// e.g. the secret (p - 1) / 2 turns a primitive root into the rejected public value p - 1, such secrets are drawn again.
+fn df_random_secret<R: Rng + ?Sized>(shared_prime: &ChonkerInt, shared_base: &ChonkerInt, rng: &mut R) -> ChonkerInt {
+    loop {
+        let secret = &ChonkerInt::new_rand_below_with_rng(&(shared_prime - 2u64), rng) + 1u64;
+        if validate_peer_public(&shared_base.modpow(&secret, shared_prime), shared_prime).is_ok() {
+            return secret;
         }
-    };
+    }
+}
 
-    // Check secret value B.
-    let secret_b = match secret_b {
-        Some(value) => {
-            // Check the received value, is it numeric.
-            match check_parameter_is_numeric(value) {
-                true => {
-                    ChonkerInt::from(String::from(value))
-                }
-                false => return Err(Box::new(OperationError::new("did no receive a correct value for the peer B for the Diffie-Hellman calculation. Correct value is a positive number."))),
-            }
+// Validate the parameters of the Diffie-Hellman calculation, the shared values first, then the secret of every participant.
+pub fn validate_df_parameters(shared_prime: &ChonkerInt, shared_base: &ChonkerInt, secrets: &[ChonkerInt]) -> Result<(), OperationError> {
+    validate_df_shared_values(shared_prime, shared_base)?;
+    validate_df_secrets(shared_prime, secrets)
+}
+
+// Validate the shared prime and the shared base: the prime has to pass the Miller-Rabin test, the base has to be in the range
+// 2 - (p - 2). The base of the safe prime p = 2q + 1 has to generate the large subgroup of the prime order q:
+// g^2 != 1 and g^q == 1 (modulo p), otherwise the shared key leaks its Legendre symbol or falls into a tiny subgroup.
+fn validate_df_shared_values(shared_prime: &ChonkerInt, shared_base: &ChonkerInt) -> Result<(), OperationError> {
+    if shared_prime.is_negative() || !shared_prime.is_prime_probabilistic(df_primality_trials(shared_prime)) {
+        return Err(OperationError::new("the shared prime of the Diffie-Hellman calculation is not a prime, according to Miller-Rabin primality test. Correct value is a prime number."));
+    }
+
+    if *shared_base <= 1u64 || *shared_base >= shared_prime - 1u64 {
+        return Err(OperationError::new("the shared base of the Diffie-Hellman calculation is out of the range 2 - (p - 2), where p is the shared prime. Correct value is a primitive root to the shared prime."));
+    }
+
+    if let Some(prime_q) = df_safe_prime_subgroup_order(shared_prime) {
+        if shared_base.modpow(&ChonkerInt::from(2), shared_prime).is_one() || !shared_base.modpow(&prime_q, shared_prime).is_one() {
+            return Err(OperationError::new("the shared base of the Diffie-Hellman calculation does not generate the subgroup of the prime order q of the safe prime p = 2q + 1. Correct value is g with g^2 != 1 and g^q == 1 (modulo p)."));
         }
-        None => {
-            // Generate a random number of random length in the range of 500-1000.
-            let random_length: u64 = rng.gen_range(500..=1000);
-            ChonkerInt::new_rand(&random_length, &BigIntSign::Positive)
+    }
+
+    Ok(())
+}
+
+// Validate the secret of every participant, it has to be in the range 1 - (p - 2).
+fn validate_df_secrets(shared_prime: &ChonkerInt, secrets: &[ChonkerInt]) -> Result<(), OperationError> {
+    let largest_secret = shared_prime - 2u64;
+    for (index, secret) in secrets.iter().enumerate() {
+        if *secret < 1u64 || *secret > largest_secret {
+            return Err(OperationError::new(&format!("the secret of the peer {} of the Diffie-Hellman calculation is out of the range 1 - (p - 2), where p is the shared prime. Correct value is a positive number below the shared prime minus 1.", df_participant_name(index))));
         }
-    };
+    }
 
-    // Generate and return a package of parameters for Diffie-Hellman algorithm calculations.
-    Ok(DiffieHellmanParameters {
-        shared_prime,
-        shared_base,
-        secret_a,
-        secret_b,
-    })
+    Ok(())
+}
+
+// Validate the public value, received from the peer, before the shared key is calculated. The values 0, 1 and p - 1
+// and the values out of the range 2 - (p - 2) force the shared key into a tiny predictable set. The public value
+// for the safe prime p = 2q + 1 has to be in the subgroup of the prime order q: v^q == 1 (modulo p).
+pub fn validate_peer_public(value: &ChonkerInt, shared_prime: &ChonkerInt) -> Result<(), OperationError> {
+    let largest_value = shared_prime - 1u64;
+    if value.is_zero() {
+        return Err(OperationError::new("the peer public value is 0, it is not an element of the multiplicative group modulo the shared prime. Correct value is in the range 2 - (p - 2)."));
+    }
+    if value.is_one() {
+        return Err(OperationError::new("the peer public value is 1, it forces the shared key to 1. Correct value is in the range 2 - (p - 2)."));
+    }
+    if *value == largest_value {
+        return Err(OperationError::new("the peer public value is p - 1, the element of the order 2, it forces the shared key to 1 or p - 1. Correct value is in the range 2 - (p - 2)."));
+    }
+    if value.is_negative() || *value > largest_value {
+        return Err(OperationError::new("the peer public value is out of the range 2 - (p - 2), where p is the shared prime. Correct value is in the range 2 - (p - 2)."));
+    }
+
+    if let Some(prime_q) = df_safe_prime_subgroup_order(shared_prime) {
+        if !value.modpow(&prime_q, shared_prime).is_one() {
+            return Err(OperationError::new("the peer public value is not in the subgroup of the prime order q of the safe prime p = 2q + 1, v^q != 1 (modulo p)."));
+        }
+    }
+
+    Ok(())
+}
+
+// The order q of the large subgroup of the safe prime p = 2q + 1, none if the prime is not safe.
+fn df_safe_prime_subgroup_order(shared_prime: &ChonkerInt) -> Option<ChonkerInt> {
+    if *shared_prime < 5u64 || shared_prime.is_even() {
+        return None;
+    }
+
+    let mut prime_q = shared_prime - 1u64;
+    prime_q.halve_in_place();
+    match prime_q.is_prime_probabilistic(df_primality_trials(&prime_q)) {
+        true => Some(prime_q),
+        false => None,
+    }
+}
+
+// Generate the generator of the subgroup of the prime order q of the safe prime p = 2q + 1,
+// the square of a primitive root generates the quadratic residues, the subgroup of the order q.
+fn df_safe_prime_subgroup_generator<R: Rng + ?Sized>(shared_prime: &ChonkerInt, rng: &mut R) -> ChonkerInt {
+    shared_prime.new_safe_prime_primitive_root(rng).modpow(&ChonkerInt::from(2), shared_prime)
+}
+
+// Amount of the Miller-Rabin trials for the prime of the Diffie-Hellman calculation, the longer the prime, the less trials.
+fn df_primality_trials(number: &ChonkerInt) -> Option<u64> {
+    match number.digit_count() {
+        0..=24 => Some(20),
+        25..=49 => Some(10),
+        50..=74 => Some(3),
+        _ => Some(1),
+    }
 }
 
 pub fn check_parameter_is_numeric(parameter: &str) -> bool {
@@ -211,8 +756,12 @@ pub fn check_parameter_is_numeric(parameter: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use crate::crypto::diffie_hellman::{
-        check_df_parameters, check_parameter_is_numeric, diffie_hellman,
+        check_df_parameters, check_parameter_is_numeric, df_derive_key, df_modp_group, df_participant_name, diffie_hellman, diffie_hellman_crack, diffie_hellman_multi,
+        diffie_hellman_multi_from_strings, diffie_hellman_with_key_length, discrete_log_bsgs, validate_df_parameters, validate_peer_public, DhParty, DiffieHellmanResult, DF_CRACK_MAX_BITS, DF_DERIVED_KEY_LEN,
+        DF_GENERATE_ARGUMENT, DF_SAFE_PRIME_BITS, MODP_GROUPS,
     };
+    use crate::encoding::string_hex_encode;
+    use crate::logic::bigint::randomisation::secure_rng;
     use crate::logic::bigint::ChonkerInt;
 
     // Test Diffie-Hellman algorithm.
@@ -221,8 +770,8 @@ mod tests {
         // Correct values.
         let test_shared_prime = "13".to_string();
         let test_shared_base = "7".to_string();
-        let test_secret_a = "12323".to_string();
-        let test_secret_b = "42398472".to_string();
+        let test_secret_a = "5".to_string();
+        let test_secret_b = "8".to_string();
 
         let result = diffie_hellman(
             Some(test_shared_prime.clone()),
@@ -232,29 +781,29 @@ mod tests {
         )
             .unwrap();
         let package_from_a_to_b = ChonkerInt::from(String::from("7")).modpow(
-            &ChonkerInt::from(String::from("12323")),
+            &ChonkerInt::from(String::from("5")),
             &ChonkerInt::from(String::from("13")),
         );
         let package_from_b_to_a = ChonkerInt::from(String::from("7")).modpow(
-            &ChonkerInt::from(String::from("42398472")),
+            &ChonkerInt::from(String::from("8")),
             &ChonkerInt::from(String::from("13")),
         );
         let result_a = package_from_b_to_a.modpow(
-            &ChonkerInt::from(String::from("12323")),
+            &ChonkerInt::from(String::from("5")),
             &ChonkerInt::from(String::from("13")),
         );
         let result_b = package_from_a_to_b.modpow(
-            &ChonkerInt::from(String::from("42398472")),
+            &ChonkerInt::from(String::from("8")),
             &ChonkerInt::from(String::from("13")),
         );
 
         assert_eq!(
             ChonkerInt::from(test_shared_prime),
-            result.shared_prime
+            result.prime
         );
         assert_eq!(
             ChonkerInt::from(test_shared_base),
-            result.shared_base
+            result.base
         );
         assert_eq!(
             ChonkerInt::from(test_secret_a),
@@ -264,17 +813,17 @@ mod tests {
             ChonkerInt::from(test_secret_b),
             result.secret_b
         );
-        assert_eq!(package_from_a_to_b, result.package_from_a_to_b);
-        assert_eq!(package_from_b_to_a, result.package_from_b_to_a);
-        assert_eq!(result_a, result.result_a);
-        assert_eq!(result_b, result.result_b);
-        assert_eq!(true, result.success);
+        assert_eq!(package_from_a_to_b, result.public_a);
+        assert_eq!(package_from_b_to_a, result.public_b);
+        assert_eq!(result_a, result.shared_key);
+        assert_eq!(result_b, result.shared_key);
+        assert_eq!(true, result.verify());
 
         // Incorrect shared prime.
         let test_shared_prime = Some("562457128101735933004861281229980320017117423199759147390620125871795298609636011392770132344949088969751961333591438819671223807833285269283606992239".to_string());
         let test_shared_base = Some("7".to_string());
-        let test_secret_a = Some("12323".to_string());
-        let test_secret_b = Some("42398472".to_string());
+        let test_secret_a = Some("5".to_string());
+        let test_secret_b = Some("8".to_string());
 
         let result = match diffie_hellman(
             test_shared_prime.clone(),
@@ -336,6 +885,22 @@ mod tests {
 
         assert!(!result);
 
+        // Negative secret A.
+        let test_secret_a = Some("-12323".to_string());
+        let test_secret_b = Some("8".to_string());
+
+        let result = match check_df_parameters(
+            &test_shared_prime,
+            &test_shared_base,
+            &test_secret_a,
+            &test_secret_b,
+        ) {
+            Ok(_) => true,
+            Err(_) => false,
+        };
+
+        assert!(!result);
+
         // Random values.
         let test_shared_prime = None;
         let test_shared_base = None;
@@ -361,8 +926,8 @@ mod tests {
         // Correct values.
         let test_shared_prime = Some("13".to_string());
         let test_shared_base = Some("7".to_string());
-        let test_secret_a = Some("12323".to_string());
-        let test_secret_b = Some("42398472".to_string());
+        let test_secret_a = Some("5".to_string());
+        let test_secret_b = Some("8".to_string());
 
         let result = match check_df_parameters(
             &test_shared_prime,
@@ -393,7 +958,7 @@ mod tests {
 
         // Incorrect shared base.
         let test_shared_prime = Some("13".to_string());
-        // let test_shared_base = Some("0"); // Panics, as it should.
+        // let test_shared_base = Some("0"); // Rejected, see test_df_parameter_validation.
         //
         // let result = match check_df_parameters(&test_shared_prime, &test_shared_base, &test_secret_a, &test_secret_b) {
         //     Ok(_) => true,
@@ -453,6 +1018,354 @@ mod tests {
         assert!(result);
     }
 
+    // Test generation of the Diffie-Hellman parameters with a safe prime, requested with the "generate" argument.
+    #[test]
+    fn test_df_safe_prime_parameters() {
+        let generate = Some(DF_GENERATE_ARGUMENT.to_string());
+
+        // Generated safe prime and the generator of its subgroup of the prime order q.
+        let parameters = check_df_parameters(&generate, &generate, &None, &None).unwrap();
+        let prime_q = &(&parameters.shared_prime - 1u64) / &ChonkerInt::from(2);
+        assert_eq!(parameters.shared_prime.bit_length(), DF_SAFE_PRIME_BITS);
+        assert!(parameters.shared_prime.is_prime_probabilistic(None));
+        assert!(prime_q.is_prime_probabilistic(None));
+        assert!(parameters.shared_base.modpow(&prime_q, &parameters.shared_prime).is_one());
+
+        // The base is generated for the safe prime, when it is not provided.
+        let parameters = check_df_parameters(&generate, &None, &None, &None).unwrap();
+        let prime_q = &(&parameters.shared_prime - 1u64) / &ChonkerInt::from(2);
+        assert!(parameters.shared_base.modpow(&prime_q, &parameters.shared_prime).is_one());
+
+        // The provided base is checked against the safe prime, 1 never generates the subgroup.
+        assert!(check_df_parameters(&generate, &Some("1".to_string()), &None, &None).is_err());
+
+        // The provided safe prime 23 = 2 * 11 + 1 receives the generator of the subgroup of the order 11.
+        let parameters = check_df_parameters(&Some("23".to_string()), &generate, &None, &None).unwrap();
+        assert!(parameters.shared_base.modpow(&ChonkerInt::from(11), &ChonkerInt::from(23)).is_one());
+
+        // A primitive root is generated for the provided prime, which is not safe.
+        let parameters = check_df_parameters(&Some("29".to_string()), &generate, &None, &None).unwrap();
+        assert!(parameters.shared_base.is_primitive_root(&ChonkerInt::from(29)));
+
+        // The whole exchange succeeds.
+        let result = diffie_hellman(generate.clone(), generate, None, None).unwrap();
+        assert!(result.verify());
+    }
+
+    // Test the validation of the Diffie-Hellman parameters, every rejected parameter is named in the error.
+    #[test]
+    fn test_df_parameter_validation() {
+        let error_message = |shared_prime: &str, shared_base: &str, secret_a: &str, secret_b: &str| {
+            match diffie_hellman(Some(shared_prime.to_string()), Some(shared_base.to_string()), Some(secret_a.to_string()), Some(secret_b.to_string())) {
+                Ok(_) => panic!("Expected the validation error for the parameters {}, {}, {}, {} (test_df_parameter_validation)", shared_prime, shared_base, secret_a, secret_b),
+                Err(error) => error.to_string(),
+            }
+        };
+
+        // The composite prime.
+        assert!(validate_df_parameters(&ChonkerInt::from(221), &ChonkerInt::from(2), &[ChonkerInt::from(3)]).unwrap_err().to_string().contains("the shared prime"));
+        assert!(error_message("221", "2", "3", "5").contains("is not a prime"));
+
+        // The base out of the range 2 - (p - 2) of the prime 13, which is not safe.
+        for shared_base in ["0", "1", "12", "13", "20"] {
+            assert!(error_message("13", shared_base, "3", "5").contains("the shared base of the Diffie-Hellman calculation is out of the range"));
+        }
+
+        // The secrets out of the range 1 - (p - 2).
+        assert!(error_message("13", "7", "0", "5").contains("the secret of the peer A"));
+        assert!(error_message("13", "7", "3", "12").contains("the secret of the peer B"));
+        assert!(diffie_hellman(Some("13".to_string()), Some("7".to_string()), Some("1".to_string()), Some("11".to_string())).unwrap().verify());
+        let secret_error = diffie_hellman_multi(&ChonkerInt::from(13), &ChonkerInt::from(7), &[ChonkerInt::from(3), ChonkerInt::from(5), ChonkerInt::from(40)]).unwrap_err();
+        assert!(secret_error.to_string().contains("the secret of the peer C"));
+
+        // The primitive root 5 of the safe prime 23 = 2 * 11 + 1 does not generate the subgroup of the order 11, 2 does.
+        assert!(error_message("23", "5", "3", "5").contains("does not generate the subgroup of the prime order q"));
+        assert!(error_message("23", "22", "3", "5").contains("out of the range"));
+        let result = diffie_hellman(Some("23".to_string()), Some("2".to_string()), Some("1".to_string()), Some("21".to_string())).unwrap();
+        assert!(result.verify());
+        assert!(result.shared_key.modpow(&ChonkerInt::from(11), &ChonkerInt::from(23)).is_one());
+
+        // The exchange over the generated 128 bit safe prime with the provided secrets still succeeds.
+        let generate = Some(DF_GENERATE_ARGUMENT.to_string());
+        assert!(diffie_hellman(generate.clone(), generate, Some("12323".to_string()), Some("42398472".to_string())).unwrap().verify());
+    }
+
+    // Test the key derivation from the shared secret: both participants derive the same key, the different shared secrets
+    // give the different keys, the shorter key is the prefix of the longer one, since HKDF does not mix the length in.
+    #[test]
+    fn test_df_derived_key() {
+        let result = diffie_hellman(Some("2147483647".to_string()), Some("7".to_string()), Some("12323".to_string()), Some("42398472".to_string())).unwrap();
+        let shared_prime = ChonkerInt::from(2147483647u64);
+        let key = df_derive_key(&result.shared_key, &shared_prime, DF_DERIVED_KEY_LEN).unwrap();
+        let derived_key = result.derived_key.clone().unwrap();
+        assert_eq!(key.len(), DF_DERIVED_KEY_LEN);
+        assert_eq!(derived_key, string_hex_encode(&key).unwrap());
+        assert!(result.verify());
+
+        // The different shared secret.
+        let other_result = diffie_hellman(Some("2147483647".to_string()), Some("7".to_string()), Some("12324".to_string()), Some("42398472".to_string())).unwrap();
+        assert_ne!(other_result.shared_key, result.shared_key);
+        assert_ne!(other_result.derived_key, result.derived_key);
+
+        // The key of 16 bytes is the prefix of the key of 32 bytes.
+        let short_result = diffie_hellman_with_key_length(Some("2147483647".to_string()), Some("7".to_string()), Some("12323".to_string()), Some("42398472".to_string()), 16).unwrap();
+        let short_derived_key = short_result.derived_key.clone().unwrap();
+        assert_eq!(short_derived_key.len(), 32);
+        assert!(derived_key.starts_with(&short_derived_key));
+        assert!(short_result.verify());
+
+        // The shared secret is padded to the length of the prime, the leading zero bytes change the key.
+        assert_ne!(df_derive_key(&ChonkerInt::from(5), &shared_prime, 16).unwrap(), df_derive_key(&ChonkerInt::from(5), &ChonkerInt::from(13), 16).unwrap());
+        assert!(diffie_hellman_with_key_length(None, None, None, None, 0).is_err());
+    }
+
+    // Test the baby-step giant-step discrete logarithm: the known exponents modulo the primes around 10^10 are recovered,
+    // the target out of the subgroup of the base and the exponent above the bound are not found.
+    #[test]
+    fn test_discrete_log_bsgs() {
+        // 2 is a primitive root to the prime 10000000019, 3 generates only the quadratic residues and 2 is not one of them.
+        let prime = ChonkerInt::from(10000000019u64);
+        let max_exponent = &prime - 2u64;
+        for (base, secret) in [(2u64, 1234567891u64), (2, 10000000017), (2, 0), (7, 987654321), (3, 4999999999)] {
+            let base = ChonkerInt::from(base);
+            let public_value = base.modpow(&ChonkerInt::from(secret), &prime);
+            let recovered_secret = discrete_log_bsgs(&base, &public_value, &prime, &max_exponent).unwrap().unwrap();
+            assert_eq!(base.modpow(&recovered_secret, &prime), public_value);
+            assert!(recovered_secret <= max_exponent);
+        }
+        assert_eq!(discrete_log_bsgs(&ChonkerInt::from(2), &ChonkerInt::from(6560363120u64), &prime, &max_exponent).unwrap(), Some(ChonkerInt::from(1234567891)));
+        assert_eq!(discrete_log_bsgs(&ChonkerInt::from(3), &ChonkerInt::from(2), &prime, &max_exponent).unwrap(), None);
+
+        // The smallest exponent is recovered within the bound, 2^7 = 128.
+        assert_eq!(discrete_log_bsgs(&ChonkerInt::from(2), &ChonkerInt::from(128), &prime, &ChonkerInt::from(7)).unwrap(), Some(ChonkerInt::from(7)));
+        assert_eq!(discrete_log_bsgs(&ChonkerInt::from(2), &ChonkerInt::from(128), &prime, &ChonkerInt::from(6)).unwrap(), None);
+
+        // The max exponent with the table of the baby steps too big for the memory is not searched, the error differs from the missing exponent.
+        let oversized_max_exponent = ChonkerInt::from(10u64).pow(&ChonkerInt::from(80));
+        let error = discrete_log_bsgs(&ChonkerInt::from(2), &ChonkerInt::from(128), &prime, &oversized_max_exponent).unwrap_err();
+        assert!(error.to_string().contains("too big"), "{}", error);
+        assert!(discrete_log_bsgs(&ChonkerInt::from(2), &ChonkerInt::from(128), &ChonkerInt::new(), &max_exponent).is_err());
+
+        // The public value of the exchange over the prime 9500000021 is cracked back into the secret.
+        let result = diffie_hellman(Some("9500000021".to_string()), Some("2".to_string()), Some("7654321987".to_string()), None).unwrap();
+        let crack_result = diffie_hellman_crack("9500000021", "2", &result.public_a.to_string()).unwrap();
+        assert_eq!(crack_result.secret, ChonkerInt::from(7654321987u64));
+
+        // The prime above the bound, the composite prime, the base and the public value out of the range and the value out of the subgroup are rejected.
+        let long_prime = ChonkerInt::from(1099511627791u64);
+        assert!(long_prime.bit_length() > DF_CRACK_MAX_BITS);
+        let errors = [
+            (long_prime.to_string(), "3", "2187", "bits"),
+            ("10000000021".to_string(), "2", "128", "not a prime"),
+            ("10000000019".to_string(), "1", "128", "the shared base"),
+            ("10000000019".to_string(), "2", "10000000019", "the public value"),
+            ("10000000019".to_string(), "3", "2", "not a power of the shared base"),
+        ];
+        for (shared_prime, shared_base, public_value, message) in errors {
+            match diffie_hellman_crack(&shared_prime, shared_base, public_value) {
+                Ok(_) => panic!("Expected the discrete logarithm search to fail for {}, {}, {} (test_discrete_log_bsgs)", shared_prime, shared_base, public_value),
+                Err(error) => assert!(error.to_string().contains(message)),
+            }
+        }
+    }
+
+    // Test the two-phase exchange of the independent participants: two parties agree on the key, the third party
+    // with another secret does not, the parties with the known secrets match the single call exchange.
+    #[test]
+    fn test_dh_party() {
+        let mut rng = secure_rng();
+        let group = df_modp_group("group5").unwrap();
+        let (shared_prime, shared_base) = (group.prime(), group.generator());
+
+        let party_a = DhParty::new(&shared_prime, &shared_base, &mut rng).unwrap();
+        let party_b = DhParty::new(&shared_prime, &shared_base, &mut rng).unwrap();
+        let party_c = DhParty::new(&shared_prime, &shared_base, &mut rng).unwrap();
+        let shared_key = party_a.agree(party_b.public_value()).unwrap();
+        assert_eq!(shared_key, party_b.agree(party_a.public_value()).unwrap());
+        assert_ne!(party_c.agree(party_a.public_value()).unwrap(), shared_key);
+        assert_ne!(party_a.agree(party_c.public_value()).unwrap(), shared_key);
+
+        // The public key holds the shared values and the public value only.
+        let public_key = party_a.public_key();
+        assert_eq!((&public_key.shared_prime, &public_key.shared_base, &public_key.public_value), (&shared_prime, &shared_base, party_a.public_value()));
+
+        // The parties with the known secrets.
+        let (shared_prime, shared_base) = (ChonkerInt::from(2147483647u64), ChonkerInt::from(7));
+        let party_a = DhParty::from_secret(&shared_prime, &shared_base, &ChonkerInt::from(12323)).unwrap();
+        let party_b = DhParty::from_secret(&shared_prime, &shared_base, &ChonkerInt::from(42398472)).unwrap();
+        let result = diffie_hellman(Some("2147483647".to_string()), Some("7".to_string()), Some("12323".to_string()), Some("42398472".to_string())).unwrap();
+        assert_eq!((party_a.public_value(), party_b.public_value()), (&result.public_a, &result.public_b));
+        assert_eq!(party_a.agree(party_b.public_value()).unwrap(), result.shared_key);
+
+        // The parameters are validated.
+        assert!(DhParty::from_secret(&shared_prime, &shared_base, &ChonkerInt::new()).is_err());
+        assert!(DhParty::new(&ChonkerInt::from(221), &shared_base, &mut rng).is_err());
+        assert!(DhParty::new(&shared_prime, &ChonkerInt::from(1), &mut rng).is_err());
+    }
+
+    // Test the verification of the Diffie-Hellman result: the legitimate exchange is verified,
+    // the tampered shared key, public value or derived key and the malformed values are not.
+    #[test]
+    fn test_df_result_verify() {
+        let result = diffie_hellman(Some("2147483647".to_string()), Some("7".to_string()), Some("12323".to_string()), Some("42398472".to_string())).unwrap();
+        assert!(result.verify());
+        assert!(!DiffieHellmanResult::default().verify());
+
+        let mut tampered_result = result.clone();
+        tampered_result.shared_key = &tampered_result.shared_key + 1u64;
+        assert!(!tampered_result.verify());
+
+        let mut tampered_result = result.clone();
+        tampered_result.public_b = ChonkerInt::from(7);
+        assert!(!tampered_result.verify());
+
+        // The derived key is optional, the tampered or malformed derived key fails the verification.
+        let mut tampered_result = result.clone();
+        tampered_result.derived_key = None;
+        assert!(tampered_result.verify());
+        tampered_result.derived_key = Some(String::from("6B1A7E0E43C9F1F2D8B2E6F7C6A29E3D"));
+        assert!(!tampered_result.verify());
+        tampered_result.derived_key = Some(String::from("not hex"));
+        assert!(!tampered_result.verify());
+
+        let mut tampered_result = result;
+        tampered_result.secret_a = ChonkerInt::from(-12323);
+        assert!(!tampered_result.verify());
+    }
+
+    // Test the validation of the peer public value: each forbidden value is rejected with its own message,
+    // the value out of the subgroup of the safe prime is rejected, the legitimate exchange is unaffected.
+    #[test]
+    fn test_validate_peer_public() {
+        let prime = ChonkerInt::from(2147483647u64);
+        let rejected_values = [
+            (ChonkerInt::new(), "the peer public value is 0"),
+            (ChonkerInt::from(1), "the peer public value is 1"),
+            (ChonkerInt::from(2147483646u64), "the peer public value is p - 1"),
+            (ChonkerInt::from(2147483647u64), "out of the range 2 - (p - 2)"),
+            (ChonkerInt::from(9147483647u64), "out of the range 2 - (p - 2)"),
+            (ChonkerInt::from(-5), "out of the range 2 - (p - 2)"),
+        ];
+        for (value, message) in rejected_values.iter() {
+            assert!(validate_peer_public(value, &prime).unwrap_err().to_string().contains(message));
+        }
+        assert!(validate_peer_public(&ChonkerInt::from(2), &prime).is_ok());
+        assert!(validate_peer_public(&ChonkerInt::from(2147483645u64), &prime).is_ok());
+
+        // The safe prime 23 = 2 * 11 + 1: the only element of the order 2 is 22, the primitive root 5 of the order 22
+        // is out of the subgroup of the order 11, the quadratic residue 2 is in it.
+        let safe_prime = ChonkerInt::from(23);
+        assert!(validate_peer_public(&ChonkerInt::from(22), &safe_prime).unwrap_err().to_string().contains("the element of the order 2"));
+        assert!(validate_peer_public(&ChonkerInt::from(5), &safe_prime).unwrap_err().to_string().contains("not in the subgroup of the prime order q"));
+        assert!(validate_peer_public(&ChonkerInt::from(2), &safe_prime).is_ok());
+
+        // The party rejects the forbidden value of the peer, the legitimate exchange still agrees.
+        let party = DhParty::from_secret(&safe_prime, &ChonkerInt::from(2), &ChonkerInt::from(7)).unwrap();
+        assert!(party.agree(&ChonkerInt::from(1)).is_err());
+        assert!(party.agree(&ChonkerInt::from(5)).is_err());
+        let peer = DhParty::from_secret(&safe_prime, &ChonkerInt::from(2), &ChonkerInt::from(9)).unwrap();
+        assert_eq!(party.agree(peer.public_value()).unwrap(), peer.agree(party.public_value()).unwrap());
+        assert!(diffie_hellman(Some("23".to_string()), Some("2".to_string()), Some("7".to_string()), Some("9".to_string())).unwrap().verify());
+
+        // The generated secrets of the prime 13 never give the public value p - 1, the exchange always succeeds.
+        for _attempt in 0..200 {
+            let party = DhParty::new(&ChonkerInt::from(13), &ChonkerInt::from(7), &mut secure_rng()).unwrap();
+            assert!(validate_peer_public(party.public_value(), &ChonkerInt::from(13)).is_ok());
+            let parameters = check_df_parameters(&Some("13".to_string()), &Some("7".to_string()), &None, &None).unwrap();
+            assert!(parameters.secret_a != 6u64 && parameters.secret_b != 6u64);
+            assert!(diffie_hellman(Some("13".to_string()), Some("7".to_string()), None, None).unwrap().verify());
+        }
+
+        // The secret (p - 1) / 2 turns the primitive root 7 of the prime 13 into the public value p - 1, the exchange is rejected.
+        let error = match diffie_hellman(Some("13".to_string()), Some("7".to_string()), Some("6".to_string()), Some("5".to_string())) {
+            Ok(_) => panic!("Expected the exchange with the public value p - 1 to fail (test_validate_peer_public)"),
+            Err(error) => error.to_string(),
+        };
+        assert!(error.contains("the peer public value is p - 1"));
+    }
+
+    // Test the MODP groups of RFC 3526: the primes have the expected bit lengths, the exchange over the group succeeds.
+    #[test]
+    fn test_df_modp_groups() {
+        let expected_bits = [("group5", 1536), ("group14", 2048), ("group15", 3072), ("group16", 4096)];
+        assert_eq!(MODP_GROUPS.len(), expected_bits.len());
+        for (name, bits) in expected_bits.iter() {
+            let group = df_modp_group(name).unwrap();
+            assert_eq!(group.bits, *bits);
+            assert_eq!(group.prime().bit_length(), *bits);
+            assert_eq!(group.generator(), ChonkerInt::from(2));
+            // Every prime starts and ends with 64 set bits, p = 2^n - 2^(n - 64) - 1 + 2^64 * (floor(2^(n - 130) * pi) + k).
+            let low_bits = ChonkerInt::from(u64::MAX);
+            assert_eq!(&group.prime() % &(&low_bits + 1u64), low_bits);
+        }
+        assert!(df_modp_group("group5").unwrap().prime().is_prime_probabilistic(Some(1)));
+
+        // Both sides of the exchange over the group 14 agree, the base of the group is its generator.
+        let group = Some("group14".to_string());
+        let result = diffie_hellman(group.clone(), None, Some("123456789123456789".to_string()), Some("987654321987654321".to_string())).unwrap();
+        assert!(result.verify());
+        assert_eq!(result.prime, df_modp_group("group14").unwrap().prime());
+        assert_eq!(result.base, ChonkerInt::from(2));
+        assert!(diffie_hellman(group.clone(), Some("2".to_string()), Some("3".to_string()), Some("5".to_string())).unwrap().verify());
+        assert!(diffie_hellman(group, Some("3".to_string()), Some("3".to_string()), Some("5".to_string())).is_err());
+
+        // The unknown group lists the correct names.
+        let error = match diffie_hellman(Some("group2".to_string()), None, None, None) {
+            Ok(_) => panic!("Expected an error for the unknown MODP group"),
+            Err(error) => error.to_string(),
+        };
+        assert!(error.contains("\"group5\", \"group14\", \"group15\", \"group16\""));
+    }
+
+    // Test the multi-party Diffie-Hellman exchange: two participants match the two-party exchange,
+    // more participants agree on the single key g^(a_1 * ... * a_n).
+    #[test]
+    fn test_diffie_hellman_multi() {
+        let shared_prime = ChonkerInt::from(13);
+        let shared_base = ChonkerInt::from(7);
+        let two_party_result = diffie_hellman(Some("13".to_string()), Some("7".to_string()), Some("5".to_string()), Some("8".to_string())).unwrap();
+        let multi_result = diffie_hellman_multi(&shared_prime, &shared_base, &[ChonkerInt::from(5), ChonkerInt::from(8)]).unwrap();
+        assert_eq!(multi_result.public_values, vec![two_party_result.public_a, two_party_result.public_b]);
+        assert!(multi_result.intermediate_values.is_empty());
+        assert_eq!(multi_result.results, vec![two_party_result.shared_key.clone(), two_party_result.shared_key]);
+        assert!(multi_result.success);
+
+        // The Mersenne prime 2^31 - 1 with its primitive root 7.
+        let shared_prime = ChonkerInt::from(2147483647u64);
+        let shared_base = ChonkerInt::from(7);
+        for secrets in [vec![12323u64, 42398472, 7919], vec![3, 5, 7, 11, 13]] {
+            let secrets: Vec<ChonkerInt> = secrets.into_iter().map(ChonkerInt::from).collect();
+            let result = diffie_hellman_multi(&shared_prime, &shared_base, &secrets).unwrap();
+            let secrets_product = secrets.iter().fold(ChonkerInt::from(1), |product, secret| &product * secret);
+            let shared_key = shared_base.modpow(&secrets_product, &shared_prime);
+
+            assert_eq!(result.public_values.len(), secrets.len());
+            assert_eq!(result.intermediate_values.len(), secrets.len() - 2);
+            assert_eq!(result.results, vec![shared_key; secrets.len()]);
+            assert!(result.success);
+
+            // The first intermediate value of the participant B is the public value of A raised to the secret of B.
+            assert_eq!(result.public_values[0], shared_base.modpow(&secrets[0], &shared_prime));
+            assert_eq!(result.intermediate_values[0][1], result.public_values[0].modpow(&secrets[1], &shared_prime));
+        }
+
+        // The random parameters and secrets of 3 participants agree on the key.
+        let result = diffie_hellman_multi_from_strings(None, None, &[None, Some("12345".to_string()), None]).unwrap();
+        assert_eq!(result.secrets[1], ChonkerInt::from(12345));
+        assert!(result.success);
+
+        // A single secret, no secrets and the incorrect secrets are rejected.
+        let single_error = diffie_hellman_multi(&shared_prime, &shared_base, &[ChonkerInt::from(12323)]).unwrap_err();
+        assert!(single_error.to_string().contains("at least 2"));
+        assert!(diffie_hellman_multi(&shared_prime, &shared_base, &[]).is_err());
+        assert!(diffie_hellman_multi_from_strings(Some("13".to_string()), Some("7".to_string()), &[Some("12323".to_string())]).is_err());
+        let secret_error = diffie_hellman_multi_from_strings(None, None, &[None, None, Some("-5".to_string())]).unwrap_err();
+        assert!(secret_error.to_string().contains("peer C"));
+
+        // The participants are named by the letters, then by the numbers.
+        assert_eq!((df_participant_name(0), df_participant_name(25), df_participant_name(26)), ("A".to_string(), "Z".to_string(), "27".to_string()));
+    }
+
     // Test check of the Diffie-Hellman parameter, is it numeric.
     #[test]
     fn test_df_parameter_numeric_check() {
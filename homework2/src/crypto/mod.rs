@@ -1,11 +1,29 @@
+// Module for the common interface of the symmetric ciphers over the bytes.
+pub mod symmetric;
+
 // Module for Caesar cipher calculations.
 pub mod caesar;
 
 // Module for Vigenere cipher calculations.
 pub mod vigenere;
 
+// Module for repeating-key XOR cipher calculations.
+pub mod xor;
+
+// Module for one-time pad cipher calculations.
+pub mod otp;
+
+// Module for affine cipher calculations.
+pub mod affine;
+
+// Module for rail fence transposition cipher calculations.
+pub mod rail_fence;
+
 // Module for RSA cipher calculations.
 pub mod rsa;
 
 // Module for Diffie-Hellman key exchange calculations.
 pub mod diffie_hellman;
+
+// Module for SHA-256 hash calculations.
+pub mod sha256;
@@ -0,0 +1,100 @@
+use std::error::Error;
+
+// Import required randomisation items.
+use rand::Rng;
+
+use crate::crypto::caesar::decrypted_bytes_to_string;
+use crate::crypto::xor::xor_bytes;
+use crate::encoding::*;
+use crate::logic::bigint::randomisation::secure_rng;
+use crate::logic::error::OperationError;
+
+// The result of the one-time pad encryption: the ciphertext and the generated key, both in hex.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OtpResult {
+    pub ciphertext: String,
+    pub key: String,
+}
+
+// Function to encrypt the target bytes under the one-time pad, the key is generated by the cryptographically secure
+// random number generator, it is exactly as long as the target and must never be reused.
+pub fn otp_encrypt(target: &[u8]) -> Result<OtpResult, Box<dyn Error>> {
+    otp_encrypt_with_rng(target, &mut secure_rng())
+}
+
+// Function to encrypt the target bytes under the one-time pad with the key from the provided random number generator.
+pub fn otp_encrypt_with_rng<R: Rng + ?Sized>(target: &[u8], rng: &mut R) -> Result<OtpResult, Box<dyn Error>> {
+    let mut key = vec![0u8; target.len()];
+    rng.fill(&mut key[..]);
+
+    Ok(OtpResult {
+        ciphertext: string_hex_encode(&xor_bytes(target, &key))?,
+        key: string_hex_encode(&key)?,
+    })
+}
+
+// Function to decrypt the hex ciphertext of the one-time pad with the hex key, the key must be exactly as long as the ciphertext.
+pub fn otp_decrypt(ciphertext: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    let decoded_ciphertext = string_hex_decode(ciphertext)?;
+    let decoded_key = string_hex_decode(key)?;
+    if decoded_ciphertext.len() != decoded_key.len() {
+        return Err(Box::new(OperationError::new(&format!("the one-time pad key of {} bytes differs in length from the ciphertext of {} bytes, the key must be exactly as long as the ciphertext. (otp_decrypt)", decoded_key.len(), decoded_ciphertext.len()))));
+    }
+
+    decrypted_bytes_to_string(xor_bytes(&decoded_ciphertext, &decoded_key), "one-time pad")
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::crypto::otp::{otp_decrypt, otp_encrypt, otp_encrypt_with_rng};
+
+    // Test the one-time pad encryption and decryption, the key and the ciphertext are as long as the bytes of the target.
+    #[test]
+    fn test_otp_encryption_and_decryption() {
+        let target_original = "TargetText, Целевой текст 🗝";
+        let result = otp_encrypt(target_original.as_bytes()).unwrap();
+
+        assert_eq!(result.ciphertext.len(), 2 * target_original.len());
+        assert_eq!(result.key.len(), result.ciphertext.len());
+        assert_eq!(otp_decrypt(&result.ciphertext, &result.key).unwrap(), target_original);
+
+        // The known key: "hi" ^ 0x0102.
+        assert_eq!(otp_decrypt("696B", "0102").unwrap(), "hi");
+
+        // The same random number generator produces the same key.
+        let seeded_result = otp_encrypt_with_rng(target_original.as_bytes(), &mut StdRng::seed_from_u64(42)).unwrap();
+        assert_eq!(seeded_result, otp_encrypt_with_rng(target_original.as_bytes(), &mut StdRng::seed_from_u64(42)).unwrap());
+        assert_eq!(otp_encrypt(b"").unwrap().key, "");
+    }
+
+    // Test the one-time pad decryption with the key of the different length, it is rejected.
+    #[test]
+    fn test_otp_length_mismatch() {
+        let result = otp_encrypt(b"hello").unwrap();
+
+        for key in [&result.key[2..], "0102030405060708", ""] {
+            match otp_decrypt(&result.ciphertext, key) {
+                Ok(_) => panic!("Expected an error for the key of the different length (test_otp_length_mismatch)"),
+                Err(e) => assert!(e.to_string().contains("differs in length from the ciphertext")),
+            }
+        }
+    }
+
+    // Test the one-time pad encryption of the same target twice, the keys and the ciphertexts differ.
+    // The chance of the same random key of 32 bytes is 2^-256.
+    #[test]
+    fn test_otp_random_keys() {
+        let target = "The same plaintext of 32 bytes!!";
+        let first_result = otp_encrypt(target.as_bytes()).unwrap();
+        let second_result = otp_encrypt(target.as_bytes()).unwrap();
+
+        assert_ne!(first_result.key, second_result.key);
+        assert_ne!(first_result.ciphertext, second_result.ciphertext);
+        assert_ne!(first_result.ciphertext, first_result.key);
+    }
+}
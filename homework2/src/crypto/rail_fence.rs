@@ -0,0 +1,145 @@
+use std::convert::TryInto;
+use std::error::Error;
+
+use crate::crypto::symmetric::{invalid_key_bytes, run_symmetric_cipher, SymmetricCipher};
+use crate::logic::config::Mode;
+use crate::logic::error::OperationError;
+
+// Smallest amount of the rails, a single rail leaves the text unchanged.
+const RAIL_FENCE_MIN_RAILS: usize = 2;
+
+// Parse the key of the rail fence cipher, the amount of the rails is a whole number of at least 2.
+pub fn parse_rail_count(key: &str) -> Result<usize, OperationError> {
+    match key.parse::<usize>() {
+        Ok(rails) if rails >= RAIL_FENCE_MIN_RAILS => Ok(rails),
+        Ok(rails) => Err(OperationError::new(&format!("the rail fence key {} is too small, a single rail or none leave the text unchanged. Correct key is a whole number of at least {} rails.", rails, RAIL_FENCE_MIN_RAILS))),
+        Err(_) => Err(OperationError::new(&format!("the rail fence key \"{}\" is not a whole number. Correct key is a whole number of at least {} rails.", key, RAIL_FENCE_MIN_RAILS))),
+    }
+}
+
+// The rail fence transposition cipher over the bytes, the bytes are written in the zig-zag over the rails and read rail by rail.
+// The key bytes are the amount of the rails in the big-endian order.
+// More about the idea: https://en.wikipedia.org/wiki/Rail_fence_cipher
+pub struct RailFence;
+
+impl SymmetricCipher for RailFence {
+    fn name(&self) -> &'static str {
+        "rail fence"
+    }
+
+    fn validate_key(&self, key: &str) -> Result<Vec<u8>, OperationError> {
+        Ok(parse_rail_count(key)?.to_be_bytes().to_vec())
+    }
+
+    fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        // Read the bytes in the order of the rails.
+        Ok(rail_fence_order(plaintext.len(), rail_count(key)?).into_iter().map(|index| plaintext[index]).collect())
+    }
+
+    fn decrypt(&self, ciphertext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        // Put every byte back to its position in the zig-zag.
+        let mut decrypted_bytes = vec![0u8; ciphertext.len()];
+        for (byte, index) in ciphertext.iter().zip(rail_fence_order(ciphertext.len(), rail_count(key)?)) {
+            decrypted_bytes[index] = *byte;
+        }
+
+        Ok(decrypted_bytes)
+    }
+}
+
+// Take the amount of the rails out of the key bytes of the rail fence cipher.
+fn rail_count(key: &[u8]) -> Result<usize, OperationError> {
+    match key.try_into() {
+        Ok(rails) => Ok(usize::from_be_bytes(rails)),
+        Err(_) => Err(invalid_key_bytes("rail fence")),
+    }
+}
+
+// Function to encrypt or decrypt the target string under the rail fence transposition cipher over the bytes, the ciphertext is in hex.
+pub fn rail_fence(mode: &Mode, target: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    run_symmetric_cipher(&RailFence, mode, target, key)
+}
+
+// The positions of the text of the given length in the order of the ciphertext: the positions of the first rail,
+// then the second one and so on. The zig-zag repeats every 2 * (rails - 1) positions, the rails beyond the length are empty,
+// thus the amount of the rails is bounded by the length, which also keeps the cycle from the overflow.
+fn rail_fence_order(length: usize, rails: usize) -> Vec<usize> {
+    let rails = rails.min(length.max(2));
+    let cycle = 2 * (rails - 1);
+    let rail = |index: usize| {
+        let position = index % cycle;
+        position.min(cycle - position)
+    };
+
+    // The stable sort keeps the positions of every rail in the order of the text.
+    let mut order: Vec<usize> = (0..length).collect();
+    order.sort_by_key(|index| rail(*index));
+
+    order
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::crypto::rail_fence::{parse_rail_count, rail_fence};
+    use crate::encoding::{string_hex_decode, string_hex_encode};
+    use crate::logic::config::Mode;
+
+    // Test the rail fence cipher on the classic example with 3 rails.
+    #[test]
+    fn test_rail_fence_known_answer() {
+        let encryption_result = rail_fence(&Mode::Encode, "WEAREDISCOVEREDFLEEATONCE", "3").unwrap();
+
+        assert_eq!(string_hex_decode(&encryption_result).unwrap(), b"WECRLTEERDSOEEFEAOCAIVDEN");
+        assert_eq!(rail_fence(&Mode::Decode, &encryption_result, "3").unwrap(), "WEAREDISCOVEREDFLEEATONCE");
+    }
+
+    // Test the degenerate cases: the text of 2 bytes, the rails more than the bytes, the empty text.
+    #[test]
+    fn test_rail_fence_degenerate() {
+        let identity = |target: &str| string_hex_encode(target.as_bytes()).unwrap();
+
+        assert_eq!(rail_fence(&Mode::Encode, "hi", "2").unwrap(), identity("hi"));
+        assert_eq!(rail_fence(&Mode::Encode, "abc", "2").unwrap(), identity("acb"));
+        assert_eq!(rail_fence(&Mode::Encode, "hello", "5").unwrap(), identity("hello"));
+        assert_eq!(rail_fence(&Mode::Encode, "hello", "100").unwrap(), identity("hello"));
+        assert_eq!(rail_fence(&Mode::Decode, &identity("hello"), "100").unwrap(), "hello");
+        assert_eq!(rail_fence(&Mode::Encode, "", "3").unwrap(), "");
+
+        // The cycle of the huge amounts of the rails does not overflow.
+        for rails in [usize::MAX, (1usize << (usize::BITS - 1)) + 1] {
+            assert_eq!(rail_fence(&Mode::Encode, "hello", &rails.to_string()).unwrap(), identity("hello"));
+            assert_eq!(rail_fence(&Mode::Decode, &identity("hello"), &rails.to_string()).unwrap(), "hello");
+        }
+    }
+
+    // Test the round-trips at the random lengths and amounts of the rails, including the multi-byte characters.
+    #[test]
+    fn test_rail_fence_random_round_trips() {
+        let mut rng = StdRng::seed_from_u64(93);
+        let alphabet: Vec<char> = "ABCxyz 0123,!ЦелкийКлюч🗝".chars().collect();
+
+        for _attempt in 0..200 {
+            let target: String = (0..rng.gen_range(0..64)).map(|_| alphabet[rng.gen_range(0..alphabet.len())]).collect();
+            let key = rng.gen_range(2..80usize).to_string();
+
+            let encryption_result = rail_fence(&Mode::Encode, &target, &key).unwrap();
+            assert_eq!(encryption_result.len(), 2 * target.len());
+            assert_eq!(rail_fence(&Mode::Decode, &encryption_result, &key).unwrap(), target);
+        }
+    }
+
+    // Test the parsing of the rail fence key: a single rail, no rails and the non numeric keys are rejected.
+    #[test]
+    fn test_rail_fence_key() {
+        assert_eq!(parse_rail_count("2").unwrap(), 2);
+        assert!(parse_rail_count("1").unwrap_err().to_string().contains("at least 2 rails"));
+        for key in ["0", "-3", "three", "", "2.5"] {
+            assert!(parse_rail_count(key).is_err());
+        }
+        assert!(rail_fence(&Mode::Encode, "hello", "1").is_err());
+    }
+}
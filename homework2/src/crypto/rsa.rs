@@ -1,42 +1,411 @@
 use std::error::Error;
-use std::str::from_utf8_unchecked;
-use std::sync::mpsc;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+
+use rand::Rng;
 
-use crate::crypto::diffie_hellman::check_parameter_is_numeric;
 use crate::crypto::rsa::threadpool::ThreadPool;
-use crate::encoding::{string_hex_decode, string_hex_encode};
+use crate::crypto::sha256::{sha256, SHA256_DIGEST_LEN};
+use crate::encoding::{decode_ciphertext, string_hex_decode, string_hex_encode, Encoding};
+use crate::logic::bigint::randomisation::secure_rng;
 use crate::logic::bigint::{BigIntSign, ChonkerInt};
-use crate::logic::config::Mode;
+use crate::logic::cancellation::CancellationToken;
 use crate::logic::error::OperationError;
 
-mod threadpool;
+pub(crate) mod threadpool;
 
-#[derive(Debug, PartialEq, Eq)]
+pub mod keyfile;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RsaKeyPair {
     pub public_key_n: ChonkerInt,
     pub public_key_e: ChonkerInt,
     pub private_key_d: ChonkerInt,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub crt: Option<RsaCrtComponents>,
+}
+
+// The public key of the RSA key pair, loaded from the public key file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsaPublicKey {
+    pub public_key_n: ChonkerInt,
+    pub public_key_e: ChonkerInt,
+}
+
+// The private key of the RSA key pair with the optional CRT components, loaded from the private key file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsaPrivateKey {
+    pub public_key_n: ChonkerInt,
+    pub private_key_d: ChonkerInt,
+    pub crt: Option<RsaCrtComponents>,
+}
+
+// Implement methods on the private key struct.
+impl RsaPrivateKey {
+    // Derive the public exponent from the CRT components as the inverse of the private exponent modulo lambda(n),
+    // it is the public exponent of the key pair or differs from it by a multiple of lambda(n), which is the same exponent
+    // for the numbers coprime to the modulus. Without the CRT components the public exponent is unknown.
+    pub fn public_exponent(&self) -> Option<ChonkerInt> {
+        let crt = self.crt.as_ref()?;
+        let lambda_n = (&crt.prime_p - 1u64).lcm(&(&crt.prime_q - 1u64));
+
+        self.private_key_d.modinv(&lambda_n)
+    }
+}
+
+// The components of the private key for the decryption with the Chinese remainder theorem:
+// the primes p and q, the exponents d_p = d mod (p-1), d_q = d mod (q-1) and the coefficient q_inv = q^-1 mod p.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RsaCrtComponents {
+    pub prime_p: ChonkerInt,
+    pub prime_q: ChonkerInt,
+    pub d_p: ChonkerInt,
+    pub d_q: ChonkerInt,
+    pub q_inv: ChonkerInt,
+}
+
+// Implement methods on the CRT components struct.
+impl RsaCrtComponents {
+    // Calculate the CRT components from the primes and the private exponent,
+    // if the primes are equal, the coefficient does not exist and there are no components.
+    pub fn new(prime_p: &ChonkerInt, prime_q: &ChonkerInt, private_key_d: &ChonkerInt) -> Option<RsaCrtComponents> {
+        let q_inv = prime_q.modinv(prime_p)?;
+
+        Some(RsaCrtComponents {
+            prime_p: prime_p.clone(),
+            prime_q: prime_q.clone(),
+            d_p: private_key_d.rem_euclid(&(prime_p - 1u64)),
+            d_q: private_key_d.rem_euclid(&(prime_q - 1u64)),
+            q_inv,
+        })
+    }
 }
 
 // An enumeration of results for the main thread from a worker.
 pub enum TaskResult {
     Success(BruteforceResult),
+    Progress(BruteforceProgress),
     Terminate(OperationError),
 }
 
+// A progress report of a bruteforce worker: the factor candidate it is checking,
+// the amount of the candidates it has tested and the time since the start of the bruteforce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BruteforceProgress {
+    pub worker: usize,
+    pub current_candidate: ChonkerInt,
+    pub candidates_tested: u64,
+    pub elapsed: Duration,
+}
+
+// Callback receiving the progress reports of the bruteforce workers in the main thread,
+// thus it may borrow the state of the caller, e.g. the writer of the progress.
+pub type BruteforceProgressCallback<'a> = Box<dyn FnMut(BruteforceProgress) + 'a>;
+
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BruteforceResult {
     pub prime_q: ChonkerInt,
     pub prime_p: ChonkerInt,
     pub public_key_n: ChonkerInt,
     pub public_key_e: ChonkerInt,
     pub private_key_d: ChonkerInt,
+    pub crt: Option<RsaCrtComponents>,
+}
+
+// Implement methods on the bruteforce result struct.
+impl BruteforceResult {
+    // Describe the bruteforced key pair in one line, as RsaKeyPair::summary() does.
+    pub fn summary(&self) -> String {
+        rsa_key_summary(&self.public_key_n, &self.public_key_e)
+    }
+}
+
+// Calculate the fingerprint of the public key: the first bytes of the SHA-256 digest of the big-endian bytes of the modulus
+// and the public exponent, each prefixed with its 4 byte length, thus the different splits of the same bytes differ.
+// The bytes are rendered as the colon separated uppercase hex pairs, e.g. "3F:A0:...".
+fn rsa_key_fingerprint(key_modulus: &ChonkerInt, key_exponent: &ChonkerInt) -> String {
+    let mut key_bytes = Vec::new();
+    for value in [key_modulus, key_exponent] {
+        let value_bytes = value.to_bytes_be();
+        key_bytes.extend_from_slice(&(value_bytes.len() as u32).to_be_bytes());
+        key_bytes.extend_from_slice(&value_bytes);
+    }
+
+    sha256(&key_bytes)[..KEY_FINGERPRINT_LEN]
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<String>>()
+        .join(":")
+}
+
+// Describe the public key in one line: the bit length of the modulus, the public exponent and the fingerprint.
+fn rsa_key_summary(key_modulus: &ChonkerInt, key_exponent: &ChonkerInt) -> String {
+    format!("{} bit modulus, public exponent {}, fingerprint {}", key_modulus.bit_length(), key_exponent, rsa_key_fingerprint(key_modulus, key_exponent))
+}
+
+// An enumeration of the strategies for the RSA modulus bruteforce: the trial division of the ranges by the threads,
+// which works with the short moduli, or the Fermat's factorization, which works with the moduli of any length,
+// if the primes are close to each other, or the Wiener's attack, which works with the moduli of any length,
+// if the private exponent is small.
+#[derive(Debug, PartialEq, Eq)]
+pub enum BruteforceStrategy {
+    TrialDivision,
+    Fermat,
+    Wiener,
+}
+
+// Implement methods on the RSA key pair struct.
+impl RsaKeyPair {
+    // Calculate the short fingerprint of the public key to tell the key pairs apart, see rsa_key_fingerprint().
+    pub fn fingerprint(&self) -> String {
+        rsa_key_fingerprint(&self.public_key_n, &self.public_key_e)
+    }
+
+    // Describe the key pair in one line: the bit length of the modulus, the public exponent and the fingerprint.
+    pub fn summary(&self) -> String {
+        rsa_key_summary(&self.public_key_n, &self.public_key_e)
+    }
+
+    // Construct the key pair from the known primes and the public exponent, e.g. for the textbook examples and the test vectors.
+    // The primes must be distinct primes and the exponent must be a coprime to lambda(n), thus to phi(n) too.
+    // The private exponent is the inverse of the public exponent modulo lambda(n), as in the random key generation.
+    pub fn from_primes(prime_p: &ChonkerInt, prime_q: &ChonkerInt, public_key_e: &ChonkerInt) -> Result<RsaKeyPair, OperationError> {
+        let big_one = ChonkerInt::from(1);
+
+        for prime in [prime_p, prime_q] {
+            if !prime.is_positive() || !prime.is_prime_probabilistic(Some(VALIDATION_PRIMALITY_TRIALS)) {
+                return Err(OperationError::new(&format!("failed to construct the RSA key pair, {} is not a prime.", prime)));
+            }
+        }
+        if prime_p == prime_q {
+            return Err(OperationError::new(&format!("failed to construct the RSA key pair, the primes p and q are both {}, correct values are distinct primes.", prime_p)));
+        }
+
+        // Generate result of Carmichael's totient function, lambda(n) = lcm(p-1, q-1).
+        let lambda_n = (prime_p - &big_one).lcm(&(prime_q - &big_one));
+        let private_key_d = match public_key_e.modinv(&lambda_n) {
+            Some(value) if *public_key_e > big_one => value,
+            _ => return Err(OperationError::new(&format!("failed to construct the RSA key pair, the public exponent {} is not a coprime to lambda(n) = {}, correct value is bigger than 1 and a coprime to lambda(n).", public_key_e, lambda_n))),
+        };
+
+        let key_pair = RsaKeyPair {
+            public_key_n: prime_p * prime_q,
+            public_key_e: public_key_e.clone(),
+            crt: RsaCrtComponents::new(prime_p, prime_q, &private_key_d),
+            private_key_d,
+        };
+        key_pair.validate()?;
+
+        Ok(key_pair)
+    }
+
+    // Check the consistency of the key pair: n > 1, e > 1 is odd, 0 < d < n. The public exponent is not bounded by the modulus,
+    // since the fixed exponent 65537 is bigger than the small moduli.
+    // With the CRT components n = p * q, the primes pass the probabilistic primality test,
+    // e * d = 1 (mod lambda(n)) and the CRT components match the private exponent.
+    // Without them a random number is encrypted and decrypted with the key pair.
+    pub fn validate(&self) -> Result<(), OperationError> {
+        let big_one = ChonkerInt::from(1);
+        let (key_n, key_e, key_d) = (&self.public_key_n, &self.public_key_e, &self.private_key_d);
+
+        if *key_n <= big_one {
+            return Err(OperationError::new("the RSA key pair is inconsistent, the modulus must be bigger than 1."));
+        }
+        if *key_e <= big_one || key_e.is_even() {
+            return Err(OperationError::new("the RSA key pair is inconsistent, the public exponent must be an odd number bigger than 1."));
+        }
+        if !key_d.is_positive() || key_d >= key_n {
+            return Err(OperationError::new("the RSA key pair is inconsistent, the private exponent must be in the range 0 < d < n."));
+        }
+
+        match &self.crt {
+            Some(crt) => {
+                if &crt.prime_p * &crt.prime_q != *key_n {
+                    return Err(OperationError::new("the RSA key pair is inconsistent, the modulus is not the product of its primes."));
+                }
+                if !crt.prime_p.is_prime_probabilistic(Some(VALIDATION_PRIMALITY_TRIALS)) || !crt.prime_q.is_prime_probabilistic(Some(VALIDATION_PRIMALITY_TRIALS)) {
+                    return Err(OperationError::new("the RSA key pair is inconsistent, the factors of the modulus are not primes."));
+                }
+
+                let lambda_n = (&crt.prime_p - &big_one).lcm(&(&crt.prime_q - &big_one));
+                if (key_e * key_d).rem_euclid(&lambda_n) != big_one {
+                    return Err(OperationError::new("the RSA key pair is inconsistent, the private exponent is not the inverse of the public exponent modulo lambda(n)."));
+                }
+                if RsaCrtComponents::new(&crt.prime_p, &crt.prime_q, key_d).as_ref() != Some(crt) {
+                    return Err(OperationError::new("the RSA key pair is inconsistent, the CRT components do not match the private exponent."));
+                }
+            }
+            None => {
+                // The primes are unknown, check that the decryption restores a random number 1 < m < n.
+                let test_value = match *key_n > ChonkerInt::from(3) {
+                    true => ChonkerInt::new_rand_range_value_with_rng(&ChonkerInt::from(2), key_n, &BigIntSign::Positive, &mut secure_rng()),
+                    false => big_one.clone(),
+                };
+                if test_value.checked_modpow(key_e, key_n)?.checked_modpow(key_d, key_n)? != test_value {
+                    return Err(OperationError::new("the RSA key pair is inconsistent, the decryption of a random number with the key pair does not restore it."));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// A typed request of an RSA operation for the entry point: the encryption of the message with the public key,
+// the decryption of the ciphertext with the private key, the same for the raw bytes of any data or streamed from the input file into the output file,
+// the generation of the key pair with the modulus of the bit size or from the known primes and the public exponent,
+// the decryption blinds the private key operations against the timing attacks only if requested, the blinding is off by default,
+// thus the backends, which decrypt the ciphertexts of the remote clients, have to request it themselves,
+// the bruteforce of the public key with the optional thread count, or the recovery of the message from its two ciphertexts
+// with the same modulus and the coprime public exponents.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RsaRequest {
+    Encrypt {
+        message: String,
+        public: RsaPublicKey,
+        padding: RsaPadding,
+        encoding: Encoding,
+    },
+    Decrypt {
+        ciphertext: String,
+        private: RsaPrivateKey,
+        padding: RsaPadding,
+        blinding: bool,
+        encoding: Option<Encoding>,
+    },
+    EncryptBytes {
+        data: Vec<u8>,
+        public: RsaPublicKey,
+        padding: RsaPadding,
+    },
+    DecryptBytes {
+        ciphertext: Vec<u8>,
+        private: RsaPrivateKey,
+        padding: RsaPadding,
+        blinding: bool,
+    },
+    EncryptStream {
+        input: String,
+        output: String,
+        public: RsaPublicKey,
+        padding: RsaPadding,
+    },
+    DecryptStream {
+        input: String,
+        output: String,
+        private: RsaPrivateKey,
+        padding: RsaPadding,
+        blinding: bool,
+    },
+    Generate {
+        bits: u64,
+        options: RsaKeygenOptions,
+    },
+    GenerateFromPrimes {
+        prime_p: ChonkerInt,
+        prime_q: ChonkerInt,
+        public_key_e: ChonkerInt,
+    },
+    Bruteforce {
+        public: RsaPublicKey,
+        threads: Option<usize>,
+        strategy: BruteforceStrategy,
+    },
+    CommonModulusAttack {
+        first_ciphertext: String,
+        second_ciphertext: String,
+        first_exponent: ChonkerInt,
+        second_exponent: ChonkerInt,
+        key_modulus: ChonkerInt,
+    },
+}
+
+// An enumeration of the choices of the public exponent for the RSA key generation:
+// the usual fixed exponent 65537 or a random exponent from the range 1 < e < lambda(n).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExponentChoice {
+    Fixed65537,
+    Random,
+}
+
+// Options of the RSA key generation, by default the public exponent is 65537.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RsaKeygenOptions {
+    pub exponent: ExponentChoice,
+}
+
+// Implement default value for RsaKeygenOptions.
+impl Default for RsaKeygenOptions {
+    fn default() -> Self {
+        RsaKeygenOptions {
+            exponent: ExponentChoice::Fixed65537,
+        }
+    }
+}
+
+// An enumeration of the paddings of the RSA encryption: the block mode of this tool with the PKCS#7 padding,
+// the PKCS#1 v1.5 encryption padding with the random padding string or the OAEP padding with the random seed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RsaPadding {
+    Raw,
+    Pkcs1v15,
+    Oaep(OaepParams),
+}
+
+// An enumeration of the hash functions for the OAEP padding and its mask generation function MGF1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OaepHash {
+    Sha256,
+}
+
+// Implement methods on the OAEP hash function enumeration.
+impl OaepHash {
+    // Calculate the digest of the data.
+    fn digest(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            OaepHash::Sha256 => sha256(data).to_vec(),
+        }
+    }
+
+    // Get the length of the digest in bytes.
+    fn output_len(&self) -> usize {
+        match self {
+            OaepHash::Sha256 => SHA256_DIGEST_LEN,
+        }
+    }
+}
+
+// Parameters of the OAEP padding: the hash function and the label, which is bound to the ciphertext.
+// By default SHA-256 is used with an empty label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OaepParams {
+    pub hash: OaepHash,
+    pub label: Vec<u8>,
+}
+
+// Implement default value for OaepParams.
+impl Default for OaepParams {
+    fn default() -> Self {
+        OaepParams {
+            hash: OaepHash::Sha256,
+            label: vec![],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum RsaResult {
     KeyPair(RsaKeyPair),
     StringResult(String),
+    BytesResult(Vec<u8>),
+    FileResult { path: String, written_bytes: u64 },
     BruteforceRSAResult(BruteforceResult),
 }
 
@@ -48,244 +417,273 @@ impl Default for RsaResult {
 }
 
 // Constants for RSA block encryption/decryption/bruteforce.
-const BLOCK_SIZE: u8 = 16;
-const BLOCK_DELIMITER: i8 = 0b11111111_u8 as i8;
-const BLOCK_PADDING: u128 = 0b10010000_u8 as u128;
+// The block size is derived from the modulus, see rsa_block_size(),
+// but the block of the padded message is at most 255 bytes, so that the padding length fits into a byte.
+const MAX_PADDED_BLOCK_SIZE: usize = 255;
+// Smallest length of the random padding string of the PKCS#1 v1.5 encryption padding.
+const PKCS1_MIN_PADDING_LEN: usize = 8;
 // No assigned value for the extended ASCII.
 const BRUTEFORCE_THREAD_COUNT: usize = 8;
-
+// Amount of iterations of the Fermat's factorization for the RSA modulus bruteforce.
+const FERMAT_MAX_ITERATIONS: u64 = 100_000;
+// Amount of the bytes of the SHA-256 digest in the fingerprint of the RSA public key.
+const KEY_FINGERPRINT_LEN: usize = 16;
+// Amount of the factor candidates tested by a bruteforce worker between the progress reports.
+const BRUTEFORCE_PROGRESS_INTERVAL: u64 = 1000;
+// Interval between the checks of the cancellation by the main thread, while it waits for the bruteforce workers.
+const BRUTEFORCE_CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(50);
+// The fixed public exponent of the RSA key generation, the fifth Fermat prime 2^16 + 1.
+const FIXED_PUBLIC_EXPONENT: u64 = 65537;
+// Bit size of the generated key modulus by default.
+pub const MODULUS_BIT_SIZE: u64 = 160;
+// Smallest accepted bit size of the key modulus, each of the primes has at least 2 bits.
+const MIN_MODULUS_BIT_SIZE: u64 = 4;
+// Bounds of the bit size of the key modulus, requested for the key generation through the entry point.
+pub const MIN_KEY_SIZE: u64 = 128;
+pub const MAX_KEY_SIZE: u64 = 4096;
+// Amount of the Miller-Rabin trials for the primes of the validated key pairs.
+const VALIDATION_PRIMALITY_TRIALS: u64 = 8;
+// Smallest accepted exponent of the encryption and the decryption, the exponent 1 leaves the message unchanged.
+const MIN_KEY_EXPONENT: u64 = 3;
+
+// Perform the requested RSA operation: encrypt the message with the public key, decrypt the ciphertext
+// with the private key, generate a new key pair or bruteforce the private key from the public key.
+// The requests are created from the command line arguments by logic::config::rsa_request_from_args().
 pub fn rsa(
-    mode: &Mode,
-    target: Option<String>,
-    key_exponent: Option<String>,
-    key_modulus: Option<String>,
-    thread_count: Option<String>,
+    request: RsaRequest,
+    progress_callback: Option<BruteforceProgressCallback>,
+    cancellation: &CancellationToken,
 ) -> Result<RsaResult, Box<dyn std::error::Error>> {
-    let encryption_decryption_clojure =
-        |mode: Mode| -> Result<RsaResult, Box<dyn std::error::Error>> {
-            let key_exponent = match key_exponent {
-                Some(value) => value,
-                None => return Err(Box::new(OperationError::new("did not receive a value for the public/private key exponent for the RSA encryption/decryption. Correct value is a positive number."))),
-            };
+    match request {
+        RsaRequest::Encrypt { message, public, padding, encoding } => {
+            let encryption_result = encoding.encode(&rsa_encrypt_bytes(message.as_bytes(), &public.public_key_e, &public.public_key_n, &padding)?)?;
 
-            let key_modulus = match key_modulus {
-                Some(value) => value,
-                None => return Err(Box::new(OperationError::new("did not receive a value for the key modulus for the RSA encryption/decryption. Correct value is a positive composite number."))),
-            };
+            Ok(RsaResult::StringResult(encryption_result))
+        }
+        RsaRequest::Decrypt { ciphertext, private, padding, blinding, encoding } => {
+            let blinding = rsa_blinding_exponent(&private, blinding)?;
+            let ciphertext = decode_ciphertext(&ciphertext, encoding)?;
+            let decryption_result = rsa_decrypt_bytes_to_string(&ciphertext, &private.private_key_d, &private.public_key_n, private.crt.as_ref(), blinding.as_ref(), &padding)?;
 
-            // Check if the key exponent and modulus are numeric.
-            if let false = check_parameter_is_numeric(&key_modulus) {
-                return Err(Box::new(OperationError::new("did not receive a correct value for the public/private key exponent for the RSA encryption/decryption. Correct value is a positive number.")));
-            };
+            Ok(RsaResult::StringResult(decryption_result))
+        }
+        RsaRequest::EncryptBytes { data, public, padding } => {
+            let encryption_result = rsa_encrypt_bytes(&data, &public.public_key_e, &public.public_key_n, &padding)?;
 
-            if let false = check_parameter_is_numeric(&key_modulus) {
-                return Err(Box::new(OperationError::new("did not receive a correct value for the key modulus for the RSA encryption/decryption. Correct value is a positive composite number.")));
-            };
+            Ok(RsaResult::BytesResult(encryption_result))
+        }
+        RsaRequest::DecryptBytes { ciphertext, private, padding, blinding } => {
+            let blinding = rsa_blinding_exponent(&private, blinding)?;
+            let decryption_result = rsa_decrypt_bytes(&ciphertext, &private.private_key_d, &private.public_key_n, private.crt.as_ref(), blinding.as_ref(), &padding)?;
 
-            // Convert the exponent and the modulus into the BigInts.
-            let key_exponent = ChonkerInt::from(String::from(&key_exponent));
-            let key_modulus = ChonkerInt::from(String::from(&key_modulus));
+            Ok(RsaResult::BytesResult(decryption_result))
+        }
+        RsaRequest::EncryptStream { input, output, public, padding } => {
+            let (reader, writer) = open_rsa_stream_files(&input, &output, "encryption")?;
+            let written_bytes = rsa_encrypt_stream(reader, writer, &public.public_key_e, &public.public_key_n, &padding)?;
 
-            // Check if the key modulus is a composite number.
-            if let true = key_modulus.is_prime_probabilistic(Some(1)) {
-                return Err(Box::new(OperationError::new("did not receive a correct value for the key modulus for the RSA encryption/decryption. Correct value is a positive composite number.")));
-            };
+            Ok(RsaResult::FileResult { path: output, written_bytes })
+        }
+        RsaRequest::DecryptStream { input, output, private, padding, blinding } => {
+            let blinding = rsa_blinding_exponent(&private, blinding)?;
+            let (reader, writer) = open_rsa_stream_files(&input, &output, "decryption")?;
+            let written_bytes = rsa_decrypt_stream(reader, writer, &private.private_key_d, &private.public_key_n, private.crt.as_ref(), blinding.as_ref(), &padding)?;
+
+            Ok(RsaResult::FileResult { path: output, written_bytes })
+        }
+        RsaRequest::Generate { bits, options } => rsa_key_generation(bits, &options, cancellation),
+        RsaRequest::GenerateFromPrimes { prime_p, prime_q, public_key_e } => {
+            Ok(RsaResult::KeyPair(RsaKeyPair::from_primes(&prime_p, &prime_q, &public_key_e)?))
+        }
+        RsaRequest::Bruteforce { public, threads, strategy } => {
+            rsa_bruteforce(&public.public_key_e, &public.public_key_n, threads, &strategy, progress_callback, cancellation)
+        }
+        RsaRequest::CommonModulusAttack { first_ciphertext, second_ciphertext, first_exponent, second_exponent, key_modulus } => {
+            let recovered_bytes = rsa_common_modulus_attack(&string_hex_decode(&first_ciphertext)?, &string_hex_decode(&second_ciphertext)?, &first_exponent, &second_exponent, &key_modulus)?;
 
-            // Check if the modulus is longer than the encryption/decryption block value.
-            // The check is preformed only for encryption or decryption requests,
-            // for bruteforcing the value is reuqired to be equal or below length 10.
-            if (key_modulus.get_vec().len() <= 39) && (mode == Mode::Encode || mode == Mode::Decode) {
-                return Err(Box::new(OperationError::new("did not receive a correct value for the key modulus for the RSA encryption/decryption. Correct value is a positive composite number with at least length of 40 or bigger.")));
+            // The recovered text is shown as it is, the other data in hex.
+            match String::from_utf8(recovered_bytes) {
+                Ok(recovered_message) => Ok(RsaResult::StringResult(recovered_message)),
+                Err(e) => Ok(RsaResult::BytesResult(e.into_bytes())),
             }
+        }
+    }
+}
 
-            // Parameter for encryption or decryption.
-            let mut unwrap_target = "".to_string();
+// Determine the public exponent for the blinding of the decryption with the private key, if the blinding is requested.
+fn rsa_blinding_exponent(private: &RsaPrivateKey, blinding: bool) -> Result<Option<ChonkerInt>, OperationError> {
+    if !blinding {
+        return Ok(None);
+    }
 
-            // Parameter for bruteforcing.
-            let mut unwrap_thread_count = None;
+    match private.public_exponent() {
+        Some(public_key_e) => Ok(Some(public_key_e)),
+        None => Err(OperationError::new("the blinding of the RSA decryption requires the public exponent, which is derived from the primes of the private key, the private key does not contain them.")),
+    }
+}
 
-            // Based on mode unwrap specific key parameters.
-            if mode == Mode::Encode || mode == Mode::Decode {
-                unwrap_target = match target {
-                    Some(value) => value,
-                    None => return Err(Box::new(OperationError::new("did not receive a string for for the RSA encryption/decryption. Correct value is a string."))),
-                };
-            } else if mode == Mode::Bruteforce {
-                unwrap_thread_count = match thread_count {
-                    Some(thread_count) => {
-                        // Check if the thread count parameter is numeric.
-                        if let false = check_parameter_is_numeric(&thread_count) {
-                            return Err(Box::new(OperationError::new("did not receive a correct value for the thread count for the RSA bruteforcing. Correct value is a positive number in the range 1-64. It can be omitted, or \"none\" can be written instead to use the default value of 8.")));
-                        };
-
-                        // Check if the thread count is too long.
-                        if thread_count.len() > 2 {
-                            return Err(Box::new(OperationError::new("did not receive a correct value for the thread count for the RSA bruteforcing. Correct value is a positive number in the range 1-64. It can be omitted, or \"none\" can be written instead to use the default value of 8.")));
-                        };
-
-                        let parsed_count: usize = thread_count.parse()?;
-
-                        Some(parsed_count)
-                    }
-                    None => None,
-                };
-            }
+// Generate a random RSA key pair with the modulus of the requested bit size,
+// with the cryptographically secure random number generator.
+fn rsa_key_generation(bits: u64, options: &RsaKeygenOptions, cancellation: &CancellationToken) -> Result<RsaResult, Box<dyn std::error::Error>> {
+    rsa_key_generation_with_rng(bits, &mut secure_rng(), options, cancellation)
+}
 
+// Generate a random RSA key pair with the modulus of the requested bit size and the provided random number generator,
+// the same seeded generator produces the same key pair, e.g. with StdRng::seed_from_u64().
+// The modulus is a product of two primes of bits / 2 bits, thus its bit length is bits or bits - 1.
+// The public exponent is chosen according to the options. The generation stops with an error, once the token is cancelled.
+pub fn rsa_key_generation_with_rng<R: Rng + ?Sized>(
+    bits: u64,
+    rng: &mut R,
+    options: &RsaKeygenOptions,
+    cancellation: &CancellationToken,
+) -> Result<RsaResult, Box<dyn std::error::Error>> {
+    if bits < MIN_MODULUS_BIT_SIZE {
+        return Err(Box::new(OperationError::new(&format!("the requested bit size {} of the RSA modulus is too small, correct value is at least {} bits.", bits, MIN_MODULUS_BIT_SIZE))));
+    }
 
-            // Encrypt, decrypt or bruteforce the target string.
-            match mode {
-                Mode::Encode => {
-                    let encryption_result = rsa_encrypt(&unwrap_target, &key_exponent, &key_modulus)?;
+    let big_one = ChonkerInt::from(1);
+    let fixed_exponent = ChonkerInt::from(FIXED_PUBLIC_EXPONENT);
 
-                    Ok(RsaResult::StringResult(encryption_result))
-                }
-                Mode::Decode => {
-                    let decryption_result = rsa_decrypt(&unwrap_target, &key_exponent, &key_modulus)?;
+    let (prime_q, prime_p, lambda_n, exponent_e) = loop {
+        let prime_q = ChonkerInt::new_prime_bits_until(bits - bits / 2, rng, cancellation)?;
+        let mut prime_p = ChonkerInt::new_prime_bits_until(bits / 2, rng, cancellation)?;
 
-                    Ok(RsaResult::StringResult(decryption_result))
-                }
-                Mode::Bruteforce => {
-                    let bruteforce_result = rsa_bruteforce(&key_exponent, &key_modulus, unwrap_thread_count)?;
+        // Regenerate one of the primes to ensure that are distinct.
+        while prime_q == prime_p {
+            prime_p = ChonkerInt::new_prime_bits_until(bits / 2, rng, cancellation)?;
+        }
 
-                    Ok(bruteforce_result)
+        // Generate result of Carmichael's totient function, lambda(n) = lcm(p-1, q-1).
+        // It is used instead of the Euler's totient function phi(n) = (p-1)(q-1), since lambda(n) divides phi(n),
+        // which results in a smaller, but still valid private exponent.
+        let lambda_n = (&prime_p - &big_one).lcm(&(&prime_q - &big_one));
+
+        // Exponent e is a part of the public key. e should not be a factor of n, should be a coprime to lambda(n).
+        match options.exponent {
+            // The fixed exponent 65537 is the usual choice, it does not undermine secrecy,
+            // but it increases efficiency of the encryption. If it is not a coprime to lambda(n), regenerate the primes.
+            ExponentChoice::Fixed65537 => {
+                if fixed_exponent == prime_q || fixed_exponent == prime_p || !fixed_exponent.is_coprime(&lambda_n) {
+                    continue;
                 }
-                _ => Err(Box::new(OperationError::new(
-                    "error in RSA logic, incorrect handling of mode",
-                ))),
+
+                break (prime_q, prime_p, lambda_n, fixed_exponent.clone());
             }
-        };
+            // Generate the exponent e form the range 1 < e < lambda(n).
+            ExponentChoice::Random => {
+                let mut exponent_e;
+                loop {
+                    if cancellation.is_cancelled() {
+                        return Err(Box::new(OperationError::new_cancelled("the generation of the RSA key pair was cancelled.")));
+                    }
 
-    // Determine what to do: encrypt, decrypt or generate a new key pair.
-    match mode {
-        Mode::Encode => encryption_decryption_clojure(Mode::Encode),
-        Mode::Decode => encryption_decryption_clojure(Mode::Decode),
-        Mode::Generate => rsa_key_generation(),
-        Mode::Bruteforce => encryption_decryption_clojure(Mode::Bruteforce),
-    }
-}
+                    exponent_e = ChonkerInt::new_rand_range_value_with_rng(&big_one, &lambda_n, &BigIntSign::Positive, rng);
 
-// Generate a random RSA key pair.
-fn rsa_key_generation() -> Result<RsaResult, Box<dyn std::error::Error>> {
-    let big_one = ChonkerInt::from(1);
-    let prime_q = ChonkerInt::new_prime(&25);
-    let mut prime_p = ChonkerInt::new_prime(&21);
+                    if exponent_e == prime_q || exponent_e == prime_p || (!exponent_e.is_coprime(&lambda_n)) {
+                        continue;
+                    }
+                    break;
+                }
 
-    // Regenerate one of the primes to ensure that are distinct.
-    while prime_q == prime_p {
-        prime_p = ChonkerInt::new_prime(&10);
-    }
+                break (prime_q, prime_p, lambda_n, exponent_e);
+            }
+        }
+    };
 
     // Generate the modulus n, a product of two previously randomly generated primes.
     // Modulus n is a part of the public key.
     let modulus_n = &prime_q * &prime_p;
 
-    // Generate result of Euler's totient function, phi(n) = (p-1)(q-1)
-    let phi_n = &(&prime_p - &big_one) * &(&prime_q - &big_one);
-
-    // Generate the exponent e form the range 1 < e < phi(n).
-    // Exponent e is a part of the public key. e should not be a factor of n,
-    // should be a coprime to phi(n).
-    // Note: very often a predetermined value of e = 65537 is used, it does not undermine secrecy,
-    // but it increases efficiency of the algorithm.
-    // Repeat the public and private exponent generation if the resulting private exponent is negative.
-    let mut exponent_e;
-    let mut private_key_d;
-    loop {
-        loop {
-            exponent_e = ChonkerInt::new_rand_range_value(&big_one, &phi_n, &BigIntSign::Positive);
-
-            if exponent_e == prime_q || exponent_e == prime_p || (!exponent_e.is_coprime(&phi_n)) {
-                continue;
-            }
-            break;
-        }
-
-        // Generate the modular multiplicative inverse d of e,
-        // a private key with the extended Euclidean algorithm.
-        private_key_d = exponent_e.egcd(&phi_n).self_x;
+    // Generate the modular multiplicative inverse d of e,
+    // a private key with the extended Euclidean algorithm.
+    let private_key_d = match exponent_e.modinv(&lambda_n) {
+        Some(value) => value,
+        None => return Err(Box::new(OperationError::new("failed to generate the RSA private exponent, the public exponent is not a coprime to the euler's totient."))),
+    };
 
-        if *private_key_d.get_sign() != BigIntSign::Negative {
-            break;
-        }
-    }
+    // Keep the primes for the faster decryption with the Chinese remainder theorem.
+    let crt = RsaCrtComponents::new(&prime_p, &prime_q, &private_key_d);
 
-    Ok(RsaResult::KeyPair(RsaKeyPair {
+    let key_pair = RsaKeyPair {
         public_key_n: modulus_n,
         public_key_e: exponent_e,
         private_key_d,
-    }))
+        crt,
+    };
+    key_pair.validate()?;
+
+    Ok(RsaResult::KeyPair(key_pair))
 }
 
-// Encrypt the message with a public exponent and a modulus.
+// Encrypt the message with a public exponent and a modulus, the ciphertext is encoded into the hex string.
 fn rsa_encrypt(
     target: &str,
     key_exponent: &ChonkerInt,
     key_modulus: &ChonkerInt,
+    padding: &RsaPadding,
 ) -> Result<String, Box<dyn Error>> {
-    // Convert string to the vector of unsigned one byte integers.
-    let target = target.as_bytes();
-    let mut big_int: u128 = 0;
-
-    // Define a vector for encrypted bytes and remainder.
-    // The target message is exactly split into 16 byte blocks,
-    // if there is a remainder, store it and operate on it separately.
-    let mut result_vector: Vec<i8> = vec![];
-    let target_chunks = target.chunks_exact(BLOCK_SIZE as usize);
-    let remainder = target_chunks.remainder();
-
-    let byte_shift_counter = 8;
-
-    // Loop over the first chunk, store each 8 bits/1 byte of the chunk sequence in a 16 byte unsigned integer.
-    // Afterwards, turn the 16 byte integer into the BigInt and proceed with the modpow operation on it,
-    // to get the encrypted block.
-    // Access the BigInt's vector of decimal digits after encryption and copy them to the resulting vector,
-    // delimit each BigInt's vector, corresponding to different chunks of the message.
-    for chunk in target_chunks {
-        // Store the bytes in the 16 byte unsigned integer.
-        for integer in chunk {
-            big_int <<= byte_shift_counter;
-            big_int |= *integer as u128;
-        }
+    string_hex_encode(&rsa_encrypt_bytes(target.as_bytes(), key_exponent, key_modulus, padding)?)
+}
 
-        // Encrypt the produced BigInt.
-        // Extract the vector of decimal digits from the BigInt and store it in the result vector with delimiters.
-        let encrypted_bigint = ChonkerInt::from(big_int).modpow(key_exponent, key_modulus);
-        let mut encrypted_bigint_vec = Vec::from(encrypted_bigint.get_vec());
-        result_vector.append(&mut encrypted_bigint_vec);
-        result_vector.push(BLOCK_DELIMITER);
+// Encrypt the bytes of any data with a public exponent and a modulus with the requested padding.
+pub fn rsa_encrypt_bytes(
+    target: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    padding: &RsaPadding,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    // Reject the keys, which can not produce a decryptable ciphertext.
+    check_rsa_key(key_exponent, key_modulus, true)?;
+
+    match padding {
+        RsaPadding::Raw => rsa_encrypt_raw(target, key_exponent, key_modulus),
+        RsaPadding::Pkcs1v15 => rsa_encrypt_pkcs1v15(target, key_exponent, key_modulus, &mut secure_rng()),
+        RsaPadding::Oaep(params) => rsa_encrypt_oaep(target, key_exponent, key_modulus, params, &mut secure_rng()),
     }
+}
 
-    // Check if there is a remainder, if it exists, repeate the process above,
-    // but add the predefined padding bytes to the unsigned 16 byte integer at the end.
-    if !remainder.is_empty() {
-        let remainder_len = remainder.len();
-        let padding_len = BLOCK_SIZE as usize - remainder_len;
-
-        // Store the remaining bytes.
-        for integer in remainder {
-            big_int <<= byte_shift_counter;
-            big_int |= *integer as u128;
-        }
-
-        // Add padding values.
-        for _iteration in 0..padding_len {
-            big_int <<= byte_shift_counter;
-            big_int |= BLOCK_PADDING;
-        }
+// Encrypt the bytes with a public exponent and a modulus.
+// The message is padded with the PKCS#7 padding: N bytes of value N, 1 <= N <= block size, are always appended,
+// and split into the blocks, each block is encrypted and stored as a fixed width field of the modulus byte length.
+fn rsa_encrypt_raw(
+    target: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let block_size = rsa_padded_block_size(key_modulus)?;
+    let field_width = rsa_field_width(key_modulus);
+
+    // Append the padding, the padded message is exactly split into the blocks.
+    let padding_len = block_size - target.len() % block_size;
+    let mut padded_target = target.to_vec();
+    padded_target.resize(target.len() + padding_len, padding_len as u8);
+
+    // Turn the bytes of each block into the BigInt and proceed with the modpow operation on it,
+    // to get the encrypted block. Store the encrypted block with the leading zero bytes in the fixed width field.
+    let mut ciphertext = Vec::with_capacity(padded_target.len() / block_size * field_width);
+    for block in padded_target.chunks_exact(block_size) {
+        rsa_encrypt_field(block, key_exponent, key_modulus, field_width, &mut ciphertext)?;
+    }
 
-        // Encrypt the produced BigInt.
-        let encrypted_bigint = ChonkerInt::from(big_int).modpow(key_exponent, key_modulus);
+    Ok(ciphertext)
+}
 
-        // Extract the vector of decimal digits from the BigInt and store it in the result vector.
-        let mut encrypted_bigint_vec = Vec::from(encrypted_bigint.get_vec());
-        result_vector.append(&mut encrypted_bigint_vec);
-    }
+// Encrypt the encoded block, which is below the modulus, and append it with the leading zero bytes
+// as the fixed width field to the ciphertext.
+fn rsa_encrypt_field(
+    encoded_block: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    field_width: usize,
+    ciphertext: &mut Vec<u8>,
+) -> Result<(), OperationError> {
+    let encrypted_block = ChonkerInt::from_bytes_be(encoded_block).checked_modpow(key_exponent, key_modulus)?.to_bytes_be();
 
-    // Change the type of the bytes in the result vector from signed to the unsigned bytes.
-    let result_vector: Vec<u8> = result_vector.iter().map(|int| *int as u8).collect();
+    ciphertext.resize(ciphertext.len() + field_width - encrypted_block.len(), 0);
+    ciphertext.extend_from_slice(&encrypted_block);
 
-    // Encode the vector of bytes into the hex string and return the result.
-    string_hex_encode(&result_vector)
+    Ok(())
 }
 
 // Decrypt the message with a private exponent and a modulus.
@@ -293,198 +691,832 @@ fn rsa_decrypt(
     target: &str,
     key_exponent: &ChonkerInt,
     key_modulus: &ChonkerInt,
+    padding: &RsaPadding,
 ) -> Result<String, Box<dyn Error>> {
-    // Convert received hex string into the vector of encrypted one bytes.
-    // Split the vector of encrypted bytes into separate vectors of predefined chunks base on the delimiters.
-    let decoded_string = string_hex_decode(target)?;
-    let mut decrypted_bigint_vec: Vec<u8> = vec![];
-    let decoded_string_iterator = decoded_string.split(|int| *int == BLOCK_DELIMITER as u8);
-
-    // Define the 16 byte integer where result of encrypted chunk/number decryption.
-    // Define the mutable byte shift to the left and immutable one to the right.
-    let mut big_unsigned_integer;
-    let mut left_byte_shift_counter = 0u8;
-    let right_byte_shift_counter = 120u8;
+    rsa_decrypt_with_crt(target, key_exponent, key_modulus, None, None, padding)
+}
 
-    // Decrypt the number from the vector of encrypted decimal digits.
-    // For each retrieved big integer, split the 16 byte unsigned integer of fused bytes
-    // into separate 1 byte unsigned integers and store the result in the final vector of bytes.
-    // If the predefined padding value is detected, stop the loops.
-    'outer: for bigint in decoded_string_iterator {
-        big_unsigned_integer = ChonkerInt::from(bigint)
-            .modpow(key_exponent, key_modulus)
-            .to_digit();
+// Decrypt the message with a key pair, the CRT components of the key pair are used for the faster decryption,
+// if they are absent, the message is decrypted with the private exponent and the modulus.
+fn rsa_decrypt_crt(
+    target: &str,
+    key_pair: &RsaKeyPair,
+    padding: &RsaPadding,
+) -> Result<String, Box<dyn Error>> {
+    rsa_decrypt_with_crt(target, &key_pair.private_key_d, &key_pair.public_key_n, key_pair.crt.as_ref(), None, padding)
+}
 
-        for iteration in 0..BLOCK_SIZE {
-            let mut big_unsigned_integer_copy = big_unsigned_integer;
+// Decrypt the message with a private exponent and a modulus, and the optional CRT components.
+// The ciphertext is the hex string, the decrypted bytes must be a valid UTF-8 text.
+fn rsa_decrypt_with_crt(
+    target: &str,
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+    blinding: Option<&ChonkerInt>,
+    padding: &RsaPadding,
+) -> Result<String, Box<dyn Error>> {
+    // Convert received hex string into the vector of encrypted bytes and decrypt them.
+    rsa_decrypt_bytes_to_string(&string_hex_decode(target)?, key_exponent, key_modulus, crt, blinding, padding)
+}
 
-            // Extract the target byte and store it in the unsigned 1 byte integer.
-            big_unsigned_integer_copy <<= left_byte_shift_counter;
-            big_unsigned_integer_copy >>= right_byte_shift_counter;
+// Decrypt the bytes of the ciphertext of a text with a private exponent and a modulus, and the optional CRT components,
+// the decrypted bytes must be a valid UTF-8 text.
+fn rsa_decrypt_bytes_to_string(
+    ciphertext: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+    blinding: Option<&ChonkerInt>,
+    padding: &RsaPadding,
+) -> Result<String, Box<dyn Error>> {
+    let decrypted_bytes = rsa_decrypt_bytes(ciphertext, key_exponent, key_modulus, crt, blinding, padding)?;
 
-            let original_byte = big_unsigned_integer_copy as u8;
+    // Convert the vector of unsigned byte integers into the string of UTF-8 characters,
+    // the invalid sequences are produced by an incorrect key or by the encrypted binary data.
+    match String::from_utf8(decrypted_bytes) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(Box::new(OperationError::new(&format!("the decrypted RSA message is not a valid UTF-8 text ({}), the key is incorrect or the message is binary data, which is decrypted with the \"--binary\" option.", e.utf8_error())))),
+    }
+}
 
-            // Check if the value equals the predefined padding value, if it does, exit both loops.
-            if original_byte == BLOCK_PADDING as u8 {
-                break 'outer;
-            }
+// Decrypt the bytes of any data with a private exponent and a modulus, and the optional CRT components,
+// with the requested padding. With the public exponent of the blinding each private key operation is blinded.
+pub fn rsa_decrypt_bytes(
+    ciphertext: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+    blinding: Option<&ChonkerInt>,
+    padding: &RsaPadding,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    // Reject the keys, which can not restore a message.
+    check_rsa_key(key_exponent, key_modulus, false)?;
+
+    match padding {
+        RsaPadding::Raw => rsa_decrypt_raw(ciphertext, key_exponent, key_modulus, crt, blinding),
+        RsaPadding::Pkcs1v15 => rsa_decrypt_pkcs1v15(ciphertext, key_exponent, key_modulus, crt, blinding),
+        RsaPadding::Oaep(params) => rsa_decrypt_oaep(ciphertext, key_exponent, key_modulus, crt, blinding, params),
+    }
+}
 
-            // Store the extracted byte in the result vector.
-            decrypted_bigint_vec.push(original_byte);
+// Encrypt the data of the reader into the writer with a public exponent and a modulus with the requested padding.
+// The data is read by the message blocks of the padding, each block is encrypted and written at once,
+// thus the memory use does not depend on the length of the data. The ciphertext is the same as the one of rsa_encrypt_bytes(),
+// the last partial or empty block receives the padding. Returns the amount of the written bytes.
+pub fn rsa_encrypt_stream<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    padding: &RsaPadding,
+) -> Result<u64, Box<dyn Error>> {
+    // Reject the keys, which can not produce a decryptable ciphertext.
+    check_rsa_key(key_exponent, key_modulus, true)?;
+
+    let field_width = rsa_field_width(key_modulus);
+    let block_size = rsa_message_block_size(key_modulus, padding)?;
+    let mut rng = secure_rng();
+    let mut block = vec![0u8; block_size];
+    let mut written_len = 0u64;
+    let mut first_block = true;
 
-            // Prevent subtraction from unsigned 0 at the last iteration.
-            if iteration == BLOCK_SIZE - 1 {
-                break;
+    loop {
+        let block_len = read_block(&mut reader, &mut block)?;
+        let ciphertext = match padding {
+            // The full blocks of the block mode are encrypted as they are, the PKCS#7 padding is appended to the last block.
+            RsaPadding::Raw if block_len == block_size => {
+                let mut ciphertext = Vec::with_capacity(field_width);
+                rsa_encrypt_field(&block, key_exponent, key_modulus, field_width, &mut ciphertext)?;
+                ciphertext
             }
+            RsaPadding::Raw => rsa_encrypt_raw(&block[..block_len], key_exponent, key_modulus)?,
+            // The empty block is encrypted only for the empty data.
+            _ if block_len == 0 && !first_block => break,
+            RsaPadding::Pkcs1v15 => rsa_encrypt_pkcs1v15(&block[..block_len], key_exponent, key_modulus, &mut rng)?,
+            RsaPadding::Oaep(params) => rsa_encrypt_oaep(&block[..block_len], key_exponent, key_modulus, params, &mut rng)?,
+        };
 
-            // Increase the shift to the left, so to clean the bit sequence to the left of the target byte
-            // before shifting it to the rightmost position.
-            left_byte_shift_counter += 8;
-        }
+        writer.write_all(&ciphertext)?;
+        written_len += ciphertext.len() as u64;
+        first_block = false;
 
-        // Reset the left shift parameter for each new big integer.
-        left_byte_shift_counter = 0u8;
+        if block_len < block_size {
+            break;
+        }
     }
-    // Convert the vector of unsigned byte integers into the string of UTF-8 characters
-    // without checks for the validity of the unicode sequences.
-    // Wrap the resulting string and return it.
-    let result = unsafe { from_utf8_unchecked(&decrypted_bigint_vec) };
+    writer.flush()?;
 
-    Ok(String::from(result))
+    Ok(written_len)
 }
 
-// Bruteforce the provided RSA modulus, if successful,
-// return calculated primes and new generated exponents for the RSA key pair.
-fn rsa_bruteforce(
+// Decrypt the ciphertext of the reader into the writer with a private exponent and a modulus, and the optional CRT components,
+// with the requested padding. The ciphertext is read by the fields of the modulus byte length, each field is decrypted
+// and written at once, the block mode of this tool holds back the last field, until it is known to carry the padding.
+// The private key operations are blinded with the public exponent of the blinding. Returns the amount of the written bytes.
+pub fn rsa_decrypt_stream<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
     key_exponent: &ChonkerInt,
     key_modulus: &ChonkerInt,
-    thread_count: Option<usize>,
-) -> Result<RsaResult, Box<dyn std::error::Error>> {
-    // Check the length of the target modulus for bruteforce.
-    // The bruteforcing is fast with the length equal to or below 10 digits, at 12 digits it starts to take 1 minute,
-    // the longer it gets, the more time it takes to bruteforce.
-    if key_modulus.get_vec().len() > 10 {
-        return Err(Box::new(OperationError::new("the requested RSA modulus for bruteforce is longer than 10, after 10 the operation starts taking noticeable amount of time, e.g. it takes about 0.5 min for 12 digit modulus.")));
-    }
+    crt: Option<&RsaCrtComponents>,
+    blinding: Option<&ChonkerInt>,
+    padding: &RsaPadding,
+) -> Result<u64, Box<dyn Error>> {
+    // Reject the keys, which can not restore a message.
+    check_rsa_key(key_exponent, key_modulus, false)?;
+
+    let field_width = rsa_field_width(key_modulus);
+    let mut field = vec![0u8; field_width];
+    let mut held_field: Option<Vec<u8>> = None;
+    let mut written_len = 0u64;
+    let mut field_count = 0u64;
 
-    // Check the thread count parameter, if it is empty/none, use a default thread count,
-    // if it is present, check if it fits into the preset boundary.
-    let bruteforce_thread_count = match thread_count {
-        None => BRUTEFORCE_THREAD_COUNT,
-        Some(thread_count) => {
-            // Check for the requested thread count.
-            if thread_count > 64 || thread_count == 0 {
-                return Err(Box::new(OperationError::new("the requested thread count for brute forcing exceeds 64 or is equal to 0, the amount requested must be a positive number below or equal 64.")));
-            }
-            thread_count
+    loop {
+        let field_len = read_block(&mut reader, &mut field)?;
+        if field_len == 0 {
+            break;
         }
-    };
+        if field_len < field_width {
+            return Err(Box::new(OperationError::new(&format!("failed to decrypt the RSA ciphertext, its length {} is not a positive multiple of the modulus byte length {}, the ciphertext or the key is incorrect.", field_count * field_width as u64 + field_len as u64, field_width))));
+        }
+        field_count += 1;
+
+        let decrypted_bytes = match padding {
+            RsaPadding::Raw => match held_field.replace(field.clone()) {
+                Some(previous_field) => {
+                    let block_size = rsa_padded_block_size(key_modulus)?;
+                    let mut decrypted_bytes = Vec::with_capacity(block_size);
+                    rsa_decrypt_raw_field(&previous_field, key_exponent, key_modulus, crt, blinding, block_size, &mut decrypted_bytes)?;
+                    decrypted_bytes
+                }
+                None => continue,
+            },
+            RsaPadding::Pkcs1v15 => rsa_decrypt_pkcs1v15(&field, key_exponent, key_modulus, crt, blinding)?,
+            RsaPadding::Oaep(params) => rsa_decrypt_oaep(&field, key_exponent, key_modulus, crt, blinding, params)?,
+        };
 
-    // Initialize the thread pool and take a half of the modulus' length.
-    let thread_pool = ThreadPool::new(bruteforce_thread_count);
-    let key_modulus_half_length = key_modulus.get_vec().len() / 2;
+        writer.write_all(&decrypted_bytes)?;
+        written_len += decrypted_bytes.len() as u64;
+    }
 
-    // Define the ceiling for values to not over-calculate,
-    // based on it define starting points for all threads/workers.
-    let mut ceiling_limit: Vec<u8> = vec![0; key_modulus_half_length];
-    ceiling_limit.fill(9);
-    let increment_count_bigint = &ChonkerInt::from(ceiling_limit.as_slice())
-        / &ChonkerInt::from(BRUTEFORCE_THREAD_COUNT as u64);
+    // The padding is always present, thus there is at least one field.
+    if field_count == 0 {
+        return Err(Box::new(OperationError::new("failed to decrypt the RSA ciphertext, it is empty, the ciphertext or the key is incorrect.")));
+    }
 
-    // A starting point for the first worker that takes the job.
-    let start_prime_point = ChonkerInt::from(3);
+    // The last field of the block mode carries the padding, which is checked and removed.
+    if let Some(last_field) = held_field {
+        let decrypted_bytes = rsa_decrypt_raw(&last_field, key_exponent, key_modulus, crt, blinding)?;
+        writer.write_all(&decrypted_bytes)?;
+        written_len += decrypted_bytes.len() as u64;
+    }
+    writer.flush()?;
 
-    // Clojure defining the tasks, executed by the workers.
-    let task_clojure = |starting_point: ChonkerInt,
-                        key_exponent: ChonkerInt,
-                        key_modulus: ChonkerInt,
-                        worker_sender: mpsc::Sender<TaskResult>| {
-        // Factor the target starting with the given starting point.
-        let prime_factors = key_modulus.factor_rsa_modulus(&starting_point);
+    Ok(written_len)
+}
 
-        // If the vector length is more than two,
-        // then the target is not a product of two primes.
-        if prime_factors.len() > 2 {
-            let _sent_task_result = worker_sender.send(TaskResult::Terminate(OperationError::new("The target RSA modulus for bruteforce is incorrect, it must be a product of two primes. THe received target had more than 2 factors.")));
-            return;
+// Open the input file for reading and create or truncate the output file of the RSA streaming, both are buffered.
+fn open_rsa_stream_files(input: &str, output: &str, operation: &str) -> Result<(BufReader<File>, BufWriter<File>), OperationError> {
+    let input_file = match File::open(input) {
+        Ok(file) => file,
+        Err(e) => return Err(OperationError::new(&format!("failed to open the input file \"{}\" for the RSA {}: {}.", input, operation, e))),
+    };
+    let output_file = match File::create(output) {
+        Ok(file) => file,
+        Err(e) => return Err(OperationError::new(&format!("failed to create the output file \"{}\" for the RSA {}: {}.", output, operation, e))),
+    };
+
+    Ok((BufReader::new(input_file), BufWriter::new(output_file)))
+}
+
+// Read the reader into the buffer, until the buffer is full or the reader is exhausted, returns the amount of the read bytes.
+pub(crate) fn read_block<R: Read>(reader: &mut R, buffer: &mut [u8]) -> Result<usize, std::io::Error> {
+    let mut read_len = 0;
+    while read_len < buffer.len() {
+        match reader.read(&mut buffer[read_len..]) {
+            Ok(0) => break,
+            Ok(len) => read_len += len,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
         }
+    }
 
-        // If the vector length is less than two,
-        // then the thread did not find suitable factors in the given range.
-        // End the operation of the thread.
-        if prime_factors.len() < 2 {
-            return;
+    Ok(read_len)
+}
+
+// Check the exponent and the modulus of the encryption or the decryption, before they are used.
+// The modulus is odd and long enough to be a product of two primes, the exponent is at least 3 and below the modulus,
+// the public exponent is odd, as an even exponent is never coprime with lambda(n) and can not be inverted.
+fn check_rsa_key(key_exponent: &ChonkerInt, key_modulus: &ChonkerInt, is_public_exponent: bool) -> Result<(), OperationError> {
+    if key_modulus.bit_length() < MIN_MODULUS_BIT_SIZE {
+        return Err(OperationError::new(&format!("the RSA modulus {} is too short to be a product of two primes, correct value is at least {} bits long.", key_modulus, MIN_MODULUS_BIT_SIZE)));
+    }
+
+    if key_modulus.is_even() {
+        return Err(OperationError::new(&format!("the RSA modulus {} is even, correct value is a product of two odd primes.", key_modulus)));
+    }
+
+    if *key_exponent < ChonkerInt::from(MIN_KEY_EXPONENT) {
+        return Err(OperationError::new(&format!("the RSA exponent {} is too small, correct value is at least {}.", key_exponent, MIN_KEY_EXPONENT)));
+    }
+
+    if is_public_exponent && key_exponent.is_even() {
+        return Err(OperationError::new(&format!("the RSA public exponent {} is even, correct value is an odd number, coprime with lambda(n).", key_exponent)));
+    }
+
+    if key_exponent >= key_modulus {
+        return Err(OperationError::new(&format!("the RSA exponent {} is not below the modulus {}, correct value is smaller than the modulus.", key_exponent, key_modulus)));
+    }
+
+    Ok(())
+}
+
+// Decrypt the bytes with a private exponent and a modulus, the ciphertext consists of the fixed width fields
+// of the encrypted blocks. The PKCS#7 padding is checked and removed, the malformed padding is rejected.
+fn rsa_decrypt_raw(
+    ciphertext: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+    blinding: Option<&ChonkerInt>,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let block_size = rsa_padded_block_size(key_modulus)?;
+    let field_width = rsa_field_width(key_modulus);
+
+    // The padding is always present, thus there is at least one block.
+    if ciphertext.is_empty() || ciphertext.len() % field_width != 0 {
+        return Err(Box::new(OperationError::new(&format!("failed to decrypt the RSA ciphertext, its length {} is not a positive multiple of the modulus byte length {}, the ciphertext or the key is incorrect.", ciphertext.len(), field_width))));
+    }
+
+    let mut decrypted_bytes = Vec::with_capacity(ciphertext.len() / field_width * block_size);
+    for field in ciphertext.chunks_exact(field_width) {
+        rsa_decrypt_raw_field(field, key_exponent, key_modulus, crt, blinding, block_size, &mut decrypted_bytes)?;
+    }
+    remove_raw_padding(&mut decrypted_bytes, block_size)?;
+
+    Ok(decrypted_bytes)
+}
+
+// Check and remove the padding of the block mode of this tool: the last byte N, 1 <= N <= block size, and N bytes of value N.
+fn remove_raw_padding(decrypted_bytes: &mut Vec<u8>, block_size: usize) -> Result<(), OperationError> {
+    let malformed_padding = || OperationError::new("failed to decrypt the RSA ciphertext, the padding of the message is malformed, the ciphertext or the key is incorrect.");
+    let padding_len = *decrypted_bytes.last().unwrap_or(&0) as usize;
+    if padding_len == 0 || padding_len > block_size {
+        return Err(malformed_padding());
+    }
+
+    let message_len = decrypted_bytes.len() - padding_len;
+    if decrypted_bytes[message_len..].iter().any(|byte| *byte as usize != padding_len) {
+        return Err(malformed_padding());
+    }
+    decrypted_bytes.truncate(message_len);
+
+    Ok(())
+}
+
+// Decrypt a single field of the block mode of this tool and append the block of block_size bytes to the decrypted bytes,
+// the padding is not checked.
+fn rsa_decrypt_raw_field(
+    field: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+    blinding: Option<&ChonkerInt>,
+    block_size: usize,
+    decrypted_bytes: &mut Vec<u8>,
+) -> Result<(), Box<dyn Error>> {
+    let encrypted_block = ChonkerInt::from_bytes_be(field);
+    if encrypted_block >= *key_modulus {
+        return Err(Box::new(OperationError::new("failed to decrypt the RSA block, the encrypted number is not below the modulus, the ciphertext or the key is incorrect.")));
+    }
+
+    let decrypted_block = rsa_private_operation(&encrypted_block, key_exponent, key_modulus, crt, blinding)?;
+    push_raw_block(&decrypted_block, block_size, decrypted_bytes)?;
+
+    Ok(())
+}
+
+// Append the decrypted number to the decrypted bytes as the block of block_size bytes with the leading zero bytes.
+fn push_raw_block(decrypted_block: &ChonkerInt, block_size: usize, decrypted_bytes: &mut Vec<u8>) -> Result<(), OperationError> {
+    // Check if the decrypted number fits into the block, if not, the ciphertext or the key is incorrect.
+    let block = decrypted_block.to_bytes_be();
+    if block.len() > block_size {
+        return Err(OperationError::new(&format!("failed to decrypt the RSA block, the decrypted number does not fit into the block of {} bytes, the ciphertext or the key is incorrect.", block_size)));
+    }
+
+    // Restore the leading zero bytes of the block.
+    decrypted_bytes.resize(decrypted_bytes.len() + block_size - block.len(), 0);
+    decrypted_bytes.extend_from_slice(&block);
+
+    Ok(())
+}
+
+// Raise the encrypted block to the private exponent modulo the modulus, with the public exponent of the blinding
+// the block is blinded with a new random factor of the secure generator.
+fn rsa_private_operation(
+    encrypted_block: &ChonkerInt,
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+    blinding: Option<&ChonkerInt>,
+) -> Result<ChonkerInt, OperationError> {
+    match blinding {
+        Some(public_key_e) => rsa_blinded_private_operation(encrypted_block, key_exponent, key_modulus, crt, public_key_e, &mut secure_rng()),
+        None => rsa_unblinded_private_operation(encrypted_block, key_exponent, key_modulus, crt),
+    }
+}
+
+// Raise the blinded block to the private exponent modulo the modulus and remove the blinding from the result:
+// c' = c * r^e mod n, m' = c'^d = m * r mod n, m = m' * r^-1 mod n. The random factor r is coprime to the modulus
+// and is generated for every block, thus the time of the exponentiation does not depend on the ciphertext,
+// which is chosen by the attacker of the timing side channel.
+// More about the idea: https://en.wikipedia.org/wiki/Blinding_(cryptography)
+fn rsa_blinded_private_operation<R: Rng + ?Sized>(
+    encrypted_block: &ChonkerInt,
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+    public_key_e: &ChonkerInt,
+    rng: &mut R,
+) -> Result<ChonkerInt, OperationError> {
+    let (blinding_factor, blinding_inverse) = rsa_blinding_factor(key_modulus, rng)?;
+
+    let blinded_block = (encrypted_block * &blinding_factor.checked_modpow(public_key_e, key_modulus)?).rem_euclid(key_modulus);
+    let blinded_message = rsa_unblinded_private_operation(&blinded_block, key_exponent, key_modulus, crt)?;
+
+    Ok((&blinded_message * &blinding_inverse).rem_euclid(key_modulus))
+}
+
+// Generate the random blinding factor 1 < r < n, which is coprime to the modulus, and its inverse modulo the modulus.
+fn rsa_blinding_factor<R: Rng + ?Sized>(key_modulus: &ChonkerInt, rng: &mut R) -> Result<(ChonkerInt, ChonkerInt), OperationError> {
+    if *key_modulus <= ChonkerInt::from(3) {
+        return Err(OperationError::new(&format!("the RSA modulus {} is too small for the blinding, correct value is bigger than 3.", key_modulus)));
+    }
+
+    // The factors, which are not coprime to the modulus, reveal its prime and are practically never generated.
+    loop {
+        let blinding_factor = ChonkerInt::new_rand_range_value_with_rng(&ChonkerInt::from(2), &(key_modulus - 1u64), &BigIntSign::Positive, rng);
+        if let Some(blinding_inverse) = blinding_factor.modinv(key_modulus) {
+            return Ok((blinding_factor, blinding_inverse));
         }
+    }
+}
 
-        let prime_p = prime_factors[0].clone();
-        let prime_q = prime_factors[1].clone();
-        let big_one = ChonkerInt::from(1);
+// Raise the encrypted block to the private exponent modulo the modulus. With the CRT components,
+// the block is raised to d_p modulo p and to d_q modulo q, and the results are combined with the Garner's formula:
+// m = m_q + q * (q_inv * (m_p - m_q) mod p). The exponentiations with the half sized moduli are about 4 times faster.
+fn rsa_unblinded_private_operation(
+    encrypted_block: &ChonkerInt,
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+) -> Result<ChonkerInt, OperationError> {
+    let crt = match crt {
+        Some(crt) => crt,
+        None => return encrypted_block.checked_modpow(key_exponent, key_modulus),
+    };
 
-        // Generate result of Euler's totient function, phi(n) = (p-1)(q-1)
-        let phi_n = &(&prime_p - &big_one) * &(&prime_q - &big_one);
+    let message_p = encrypted_block.rem_euclid(&crt.prime_p).checked_modpow(&crt.d_p, &crt.prime_p)?;
+    let message_q = encrypted_block.rem_euclid(&crt.prime_q).checked_modpow(&crt.d_q, &crt.prime_q)?;
+    let h = (&crt.q_inv * &(&message_p - &message_q)).rem_euclid(&crt.prime_p);
 
-        // Check if the provided public exponent is coprime to the phi(n).
-        if !key_exponent.is_coprime(&phi_n) {
-            let _sent_task_result = worker_sender.send(TaskResult::Terminate(OperationError::new("The target RSA public exponent for bruteforce is incorrect, it must be a coprime to the euler's totient of the bruteforced primes.")));
-            return;
+    Ok(&message_q + &(&h * &crt.prime_q))
+}
+
+// Encrypt the bytes with a public exponent and a modulus with the PKCS#1 v1.5 encryption padding.
+// The message is split into the blocks of at most k - 11 bytes, where k is the byte length of the modulus,
+// each block is encoded as 0x00 || 0x02 || at least 8 nonzero random bytes || 0x00 || block of k bytes,
+// encrypted and stored as a fixed width field of k bytes. The empty message is encrypted as a single empty block.
+fn rsa_encrypt_pkcs1v15<R: Rng + ?Sized>(
+    target: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    rng: &mut R,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let field_width = rsa_field_width(key_modulus);
+    if field_width < PKCS1_MIN_PADDING_LEN + 4 {
+        return Err(Box::new(OperationError::new(&format!("the RSA modulus {} is too small for the PKCS#1 v1.5 padding, it must be at least {} bytes long.", key_modulus, PKCS1_MIN_PADDING_LEN + 4))));
+    }
+    let block_size = field_width - PKCS1_MIN_PADDING_LEN - 3;
+
+    let mut ciphertext = Vec::with_capacity((target.len() / block_size + 1) * field_width);
+    let empty_block: [&[u8]; 1] = [&[]];
+    let blocks: Vec<&[u8]> = if target.is_empty() { empty_block.to_vec() } else { target.chunks(block_size).collect() };
+
+    for block in blocks {
+        // Build the encoded message: the header, the nonzero random padding string, the separator and the block.
+        let mut encoded_message = Vec::with_capacity(field_width);
+        encoded_message.extend_from_slice(&[0x00, 0x02]);
+        for _ in 0..field_width - block.len() - 3 {
+            encoded_message.push(rng.gen_range(1..=255));
+        }
+        encoded_message.push(0x00);
+        encoded_message.extend_from_slice(block);
+
+        // The leading zero byte ensures that the encoded message is below the modulus.
+        rsa_encrypt_field(&encoded_message, key_exponent, key_modulus, field_width, &mut ciphertext)?;
+    }
+
+    Ok(ciphertext)
+}
+
+// Decrypt the bytes with a private exponent and a modulus with the PKCS#1 v1.5 encryption padding,
+// the structure of each decrypted block is validated and stripped, the malformed padding is rejected.
+fn rsa_decrypt_pkcs1v15(
+    ciphertext: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+    blinding: Option<&ChonkerInt>,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let field_width = rsa_field_width(key_modulus);
+    if ciphertext.is_empty() || ciphertext.len() % field_width != 0 {
+        return Err(Box::new(OperationError::new(&format!("failed to decrypt the RSA ciphertext, its length {} is not a positive multiple of the modulus byte length {}, the ciphertext or the key is incorrect.", ciphertext.len(), field_width))));
+    }
+
+    let malformed_padding = || Box::new(OperationError::new("failed to decrypt the RSA ciphertext, the PKCS#1 v1.5 padding of the message is malformed, the ciphertext or the key is incorrect."));
+    let mut decrypted_bytes = vec![];
+    for field in ciphertext.chunks_exact(field_width) {
+        let encrypted_block = ChonkerInt::from_bytes_be(field);
+        if encrypted_block >= *key_modulus {
+            return Err(Box::new(OperationError::new("failed to decrypt the RSA block, the encrypted number is not below the modulus, the ciphertext or the key is incorrect.")));
+        }
+
+        // Restore the leading zero bytes of the encoded message, the first one is always zero.
+        let block = rsa_private_operation(&encrypted_block, key_exponent, key_modulus, crt, blinding)?.to_bytes_be();
+        let mut encoded_message = vec![0u8; field_width - block.len()];
+        encoded_message.extend_from_slice(&block);
+
+        // Check the header 0x00 0x02, the separator after at least 8 bytes of the padding string.
+        if encoded_message[0] != 0x00 || encoded_message[1] != 0x02 {
+            return Err(malformed_padding());
+        }
+        let separator = match encoded_message[2..].iter().position(|byte| *byte == 0x00) {
+            Some(position) if position >= PKCS1_MIN_PADDING_LEN => position + 2,
+            _ => return Err(malformed_padding()),
+        };
+
+        decrypted_bytes.extend_from_slice(&encoded_message[separator + 1..]);
+    }
+
+    Ok(decrypted_bytes)
+}
+
+// Encrypt the bytes with a public exponent and a modulus with the OAEP padding of PKCS#1 v2.2.
+// The message is split into the blocks of at most k - 2 * hLen - 2 bytes, where k is the byte length of the modulus
+// and hLen is the length of the digest, each block is encoded with a fresh random seed, encrypted and stored
+// as a fixed width field of k bytes. The empty message is encrypted as a single empty block.
+fn rsa_encrypt_oaep<R: Rng + ?Sized>(
+    target: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    params: &OaepParams,
+    rng: &mut R,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let field_width = rsa_field_width(key_modulus);
+    let hash_len = params.hash.output_len();
+    if field_width < 2 * hash_len + 3 {
+        return Err(Box::new(OperationError::new(&format!("the RSA modulus {} is too small for the OAEP padding, it must be at least {} bytes long.", key_modulus, 2 * hash_len + 3))));
+    }
+    let block_size = field_width - 2 * hash_len - 2;
+
+    let mut ciphertext = Vec::with_capacity((target.len() / block_size + 1) * field_width);
+    let empty_block: [&[u8]; 1] = [&[]];
+    let blocks: Vec<&[u8]> = if target.is_empty() { empty_block.to_vec() } else { target.chunks(block_size).collect() };
+
+    let mut seed = vec![0u8; hash_len];
+    for block in blocks {
+        rng.fill_bytes(&mut seed);
+        let encoded_message = oaep_encode(block, field_width, params, &seed);
+
+        // The leading zero byte ensures that the encoded message is below the modulus.
+        rsa_encrypt_field(&encoded_message, key_exponent, key_modulus, field_width, &mut ciphertext)?;
+    }
+
+    Ok(ciphertext)
+}
+
+// Decrypt the bytes with a private exponent and a modulus with the OAEP padding of PKCS#1 v2.2.
+// Any failure of the decryption is reported with the same error, so that the failed checks are not distinguished.
+fn rsa_decrypt_oaep(
+    ciphertext: &[u8],
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    crt: Option<&RsaCrtComponents>,
+    blinding: Option<&ChonkerInt>,
+    params: &OaepParams,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let decryption_error = || Box::new(OperationError::new("failed to decrypt the RSA ciphertext with the OAEP padding, the ciphertext, the key or the label is incorrect."));
+    let field_width = rsa_field_width(key_modulus);
+    if field_width < 2 * params.hash.output_len() + 3 || ciphertext.is_empty() || ciphertext.len() % field_width != 0 {
+        return Err(decryption_error());
+    }
+
+    let mut decrypted_bytes = vec![];
+    for field in ciphertext.chunks_exact(field_width) {
+        let encrypted_block = ChonkerInt::from_bytes_be(field);
+        if encrypted_block >= *key_modulus {
+            return Err(decryption_error());
+        }
+
+        // Restore the leading zero bytes of the encoded message.
+        let block = rsa_private_operation(&encrypted_block, key_exponent, key_modulus, crt, blinding).map_err(|_| decryption_error())?.to_bytes_be();
+        let mut encoded_message = vec![0u8; field_width - block.len()];
+        encoded_message.extend_from_slice(&block);
+
+        match oaep_decode(&encoded_message, params) {
+            Some(message) => decrypted_bytes.extend_from_slice(&message),
+            None => return Err(decryption_error()),
+        }
+    }
+
+    Ok(decrypted_bytes)
+}
+
+// Encode the message block with the OAEP padding into the encoded message of the requested length:
+// 0x00 || seed xor MGF1(masked DB) || DB xor MGF1(seed), where DB = lHash || zero bytes || 0x01 || message.
+fn oaep_encode(block: &[u8], encoded_len: usize, params: &OaepParams, seed: &[u8]) -> Vec<u8> {
+    let hash_len = params.hash.output_len();
+
+    let mut data_block = params.hash.digest(&params.label);
+    data_block.resize(encoded_len - hash_len - 1 - block.len() - 1, 0x00);
+    data_block.push(0x01);
+    data_block.extend_from_slice(block);
+
+    let data_block_mask = mgf1(&params.hash, seed, data_block.len());
+    let masked_data_block: Vec<u8> = data_block.iter().zip(data_block_mask.iter()).map(|(byte, mask)| byte ^ mask).collect();
+    let seed_mask = mgf1(&params.hash, &masked_data_block, hash_len);
+
+    let mut encoded_message = Vec::with_capacity(encoded_len);
+    encoded_message.push(0x00);
+    encoded_message.extend(seed.iter().zip(seed_mask.iter()).map(|(byte, mask)| byte ^ mask));
+    encoded_message.extend_from_slice(&masked_data_block);
+    encoded_message
+}
+
+// Decode the message block from the encoded message with the OAEP padding.
+// The leading zero byte, the label digest and the 0x01 separator are checked without the early exits,
+// so that the time of the check does not depend on which of them failed.
+fn oaep_decode(encoded_message: &[u8], params: &OaepParams) -> Option<Vec<u8>> {
+    let hash_len = params.hash.output_len();
+    let (masked_seed, masked_data_block) = encoded_message[1..].split_at(hash_len);
+
+    let seed_mask = mgf1(&params.hash, masked_data_block, hash_len);
+    let seed: Vec<u8> = masked_seed.iter().zip(seed_mask.iter()).map(|(byte, mask)| byte ^ mask).collect();
+    let data_block_mask = mgf1(&params.hash, &seed, masked_data_block.len());
+    let data_block: Vec<u8> = masked_data_block.iter().zip(data_block_mask.iter()).map(|(byte, mask)| byte ^ mask).collect();
+
+    // Accumulate the differences of the leading byte and the label digest.
+    let label_hash = params.hash.digest(&params.label);
+    let mut invalid = encoded_message[0];
+    for (byte, expected_byte) in data_block.iter().zip(label_hash.iter()) {
+        invalid |= byte ^ expected_byte;
+    }
+
+    // Find the first nonzero byte after the label digest, it must be the 0x01 separator.
+    let mut looking_for_separator = 1u8;
+    let mut separator_index = 0usize;
+    for (index, byte) in data_block.iter().enumerate().skip(hash_len) {
+        let is_zero = (*byte == 0x00) as u8;
+        let is_separator = (*byte == 0x01) as u8;
+        let found = looking_for_separator & is_separator;
+        separator_index |= index * found as usize;
+        invalid |= looking_for_separator & (is_zero ^ 1) & (is_separator ^ 1);
+        looking_for_separator &= found ^ 1;
+    }
+    invalid |= looking_for_separator;
+
+    if invalid != 0 {
+        return None;
+    }
+
+    Some(data_block[separator_index + 1..].to_vec())
+}
+
+// Generate the mask of the requested length with the mask generation function MGF1:
+// the concatenation of the digests of the seed with the 4 byte big endian counter.
+fn mgf1(hash: &OaepHash, seed: &[u8], mask_len: usize) -> Vec<u8> {
+    let mut mask = Vec::with_capacity(mask_len + hash.output_len());
+    let mut counter = 0u32;
+    while mask.len() < mask_len {
+        let mut hash_input = seed.to_vec();
+        hash_input.extend_from_slice(&counter.to_be_bytes());
+        mask.extend_from_slice(&hash.digest(&hash_input));
+        counter += 1;
+    }
+
+    mask.truncate(mask_len);
+    mask
+}
+
+// Calculate the size of the padded message block in bytes, the PKCS#7 padding length has to fit into a byte,
+// thus the block is at most 255 bytes long, even if the modulus allows longer blocks.
+fn rsa_padded_block_size(key_modulus: &ChonkerInt) -> Result<usize, OperationError> {
+    Ok(rsa_block_size(key_modulus)?.min(MAX_PADDED_BLOCK_SIZE))
+}
+
+// Calculate the size of the message block of the padding in bytes, the most bytes of the message,
+// which are encrypted into a single field.
+fn rsa_message_block_size(key_modulus: &ChonkerInt, padding: &RsaPadding) -> Result<usize, OperationError> {
+    let field_width = rsa_field_width(key_modulus);
+
+    match padding {
+        RsaPadding::Raw => rsa_padded_block_size(key_modulus),
+        RsaPadding::Pkcs1v15 if field_width < PKCS1_MIN_PADDING_LEN + 4 => Err(OperationError::new(&format!("the RSA modulus {} is too small for the PKCS#1 v1.5 padding, it must be at least {} bytes long.", key_modulus, PKCS1_MIN_PADDING_LEN + 4))),
+        RsaPadding::Pkcs1v15 => Ok(field_width - PKCS1_MIN_PADDING_LEN - 3),
+        RsaPadding::Oaep(params) if field_width < 2 * params.hash.output_len() + 3 => Err(OperationError::new(&format!("the RSA modulus {} is too small for the OAEP padding, it must be at least {} bytes long.", key_modulus, 2 * params.hash.output_len() + 3))),
+        RsaPadding::Oaep(params) => Ok(field_width - 2 * params.hash.output_len() - 2),
+    }
+}
+
+// Calculate the width of the ciphertext field of an encrypted block, the byte length of the modulus.
+fn rsa_field_width(key_modulus: &ChonkerInt) -> usize {
+    ((key_modulus.bit_length() + 7) / 8) as usize
+}
+
+// Calculate the size of the RSA block in bytes for the modulus, the largest k with 2^(8 * k) < n,
+// thus every block of k bytes is a number below the modulus.
+fn rsa_block_size(key_modulus: &ChonkerInt) -> Result<usize, OperationError> {
+    let block_size = (key_modulus.bit_length().saturating_sub(1) / 8) as usize;
+
+    if block_size == 0 {
+        return Err(OperationError::new(&format!("the RSA modulus {} is too small for the encryption/decryption, it must be bigger than 256 to fit a block of at least 1 byte.", key_modulus)));
+    }
+
+    Ok(block_size)
+}
+
+// Bruteforce the provided RSA modulus with the requested strategy, if successful,
+// return calculated primes and new generated exponents for the RSA key pair.
+fn rsa_bruteforce(
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+    thread_count: Option<usize>,
+    strategy: &BruteforceStrategy,
+    mut progress_callback: Option<BruteforceProgressCallback>,
+    cancellation: &CancellationToken,
+) -> Result<RsaResult, Box<dyn std::error::Error>> {
+    if cancellation.is_cancelled() {
+        return Err(Box::new(OperationError::new_cancelled("the RSA bruteforce was cancelled before the start.")));
+    }
+
+    // The Fermat's factorization does not depend on the length of the modulus, only on the distance between the primes.
+    if *strategy == BruteforceStrategy::Fermat {
+        return match key_modulus.factor_fermat(FERMAT_MAX_ITERATIONS) {
+            Some((prime_p, prime_q)) => {
+                let bruteforce_result = bruteforce_result_from_primes(prime_p, prime_q, key_exponent, key_modulus)?;
+                Ok(RsaResult::BruteforceRSAResult(bruteforce_result))
+            }
+            None => Err(Box::new(OperationError::new(&format!("the Fermat's factorization did not find the factors of the RSA modulus in {} iterations, the primes are not close to each other.", FERMAT_MAX_ITERATIONS)))),
+        };
+    }
+
+    // The Wiener's attack does not depend on the length of the modulus, only on the size of the private exponent.
+    if *strategy == BruteforceStrategy::Wiener {
+        return match wiener_attack(key_exponent, key_modulus) {
+            Some((prime_p, prime_q)) => {
+                let bruteforce_result = bruteforce_result_from_primes(prime_p, prime_q, key_exponent, key_modulus)?;
+                Ok(RsaResult::BruteforceRSAResult(bruteforce_result))
+            }
+            None => Err(Box::new(OperationError::new("the RSA public key is not vulnerable to the Wiener's attack, none of the convergents of e/n gives the factors of the modulus, the private exponent is not small enough."))),
+        };
+    }
+
+    // Check the length of the target modulus for bruteforce.
+    // The bruteforcing is fast with the length equal to or below 10 digits, at 12 digits it starts to take 1 minute,
+    // the longer it gets, the more time it takes to bruteforce. The longer moduli are accepted with a deadline,
+    // which bounds the time of the bruteforce.
+    if key_modulus.digit_count() > 10 && cancellation.deadline().is_none() {
+        return Err(Box::new(OperationError::new("the requested RSA modulus for bruteforce is longer than 10, after 10 the operation starts taking noticeable amount of time, e.g. it takes about 0.5 min for 12 digit modulus. Longer moduli are accepted with a timeout.")));
+    }
+
+    // Check the thread count parameter, if it is empty/none, use a default thread count,
+    // if it is present, check if it fits into the preset boundary.
+    let bruteforce_thread_count = match thread_count {
+        None => BRUTEFORCE_THREAD_COUNT,
+        Some(thread_count) => {
+            // Check for the requested thread count.
+            if thread_count > 64 || thread_count == 0 {
+                return Err(Box::new(OperationError::new("the requested thread count for brute forcing exceeds 64 or is equal to 0, the amount requested must be a positive number below or equal 64.")));
+            }
+            thread_count
         }
+    };
+
+    // Take a half of the modulus' length.
+    let key_modulus_half_length = key_modulus.digit_count() / 2;
+
+    // Define the ceiling for values to not over-calculate,
+    // based on it define starting points for all threads/workers.
+    let mut ceiling_limit: Vec<u8> = vec![0; key_modulus_half_length];
+    ceiling_limit.fill(9);
+    let starting_points = bruteforce_starting_points(&ChonkerInt::from(ceiling_limit.as_slice()), bruteforce_thread_count);
+
+    // Initialize the thread pool with a thread per starting point,
+    // and the flag, which stops the workers once any of them finds the factors.
+    let thread_pool = ThreadPool::new(starting_points.len());
+    let bruteforce_finished = Arc::new(AtomicBool::new(false));
 
-        // Generate the private exponent.
-        let private_key_d;
-        private_key_d = key_exponent.egcd(&phi_n).self_x;
+    // The workers report the progress only if there is a callback to receive it.
+    let progress_interval = match progress_callback {
+        Some(_) => BRUTEFORCE_PROGRESS_INTERVAL,
+        None => u64::MAX,
+    };
+    let bruteforce_start = Instant::now();
+
+    // Clojure defining the tasks, executed by the workers.
+    let task_clojure = move |worker: usize,
+                             starting_point: ChonkerInt,
+                             key_exponent: ChonkerInt,
+                             key_modulus: ChonkerInt,
+                             worker_sender: mpsc::Sender<TaskResult>,
+                             bruteforce_finished: Arc<AtomicBool>| {
+        // Factor the target starting with the given starting point, until another worker finishes,
+        // periodically send the progress of the worker to the main thread.
+        let prime_factors = key_modulus.factor_rsa_modulus_with_progress(&starting_point, &bruteforce_finished, progress_interval, |current_candidate, candidates_tested| {
+            let _sent_task_result = worker_sender.send(TaskResult::Progress(BruteforceProgress {
+                worker,
+                current_candidate: current_candidate.clone(),
+                candidates_tested,
+                elapsed: bruteforce_start.elapsed(),
+            }));
+        });
+
+        // If the vector length is more than two,
+        // then the target is not a product of two primes.
+        if prime_factors.len() > 2 {
+            let _sent_task_result = worker_sender.send(TaskResult::Terminate(OperationError::new("The target RSA modulus for bruteforce is incorrect, it must be a product of two primes. THe received target had more than 2 factors.")));
+            return;
+        }
 
-        // Check if the produced private exponent is negative.
-        // If it is stop the thread and the whole pool.
-        if *private_key_d.get_sign() == BigIntSign::Negative {
-            let _sent_task_result = worker_sender.send(TaskResult::Terminate(OperationError::new("The produced private exponent from bruteforce is negative, thus either input parameters are incorrect or there is an error in the algorithm.")));
+        // If the vector length is less than two,
+        // then the thread did not find suitable factors in the given range.
+        // End the operation of the thread.
+        if prime_factors.len() < 2 {
             return;
         }
 
+        let prime_p = prime_factors[0].clone();
+        let prime_q = prime_factors[1].clone();
+
         // Package calculated results and send them off to the main thread.
-        let bruteforce_result = BruteforceResult {
-            prime_q,
-            prime_p,
-            public_key_n: key_modulus,
-            public_key_e: key_exponent,
-            private_key_d,
+        // If the private exponent does not exist, stop the thread and the whole pool.
+        let task_result = match bruteforce_result_from_primes(prime_p, prime_q, &key_exponent, &key_modulus) {
+            Ok(bruteforce_result) => TaskResult::Success(bruteforce_result),
+            Err(bruteforce_error) => TaskResult::Terminate(bruteforce_error),
         };
 
-        let _sent_task_result = worker_sender.send(TaskResult::Success(bruteforce_result));
+        let _sent_task_result = worker_sender.send(task_result);
     };
 
-    let big_two = ChonkerInt::from(2);
-    let big_one = ChonkerInt::from(1);
-    let big_zero = ChonkerInt::new();
-
     // Create a channel, share the receiver among workers/threads,
     // while the sender part will be utilised by the main thread
     // to listen for the worker results.
     let (worker_sender, main_receiver) = mpsc::channel();
 
     // Generate the set amount of threads and send them the appropriate task with set starting points.
-    for thread in 0..BRUTEFORCE_THREAD_COUNT {
-        let mut starting_point =
-            &start_prime_point + &(&increment_count_bigint * &ChonkerInt::from(thread as u64));
-
-        // Check if the starting point is odd.
-        // If it is, increment by 1.
-        // Prime numbers are odd with an exception for 2.
-        if &starting_point % &big_two == big_zero {
-            starting_point = &starting_point + &big_one;
-        }
-
+    for (worker, starting_point) in starting_points.into_iter().enumerate() {
         let key_exponent = (*key_exponent).clone();
         let key_modulus = (*key_modulus).clone();
         let worker_sender = worker_sender.clone();
+        let bruteforce_finished = Arc::clone(&bruteforce_finished);
 
         // Create a new worker with the task.
         thread_pool.execute(move || {
-            task_clojure(starting_point, key_exponent, key_modulus, worker_sender);
+            task_clojure(worker, starting_point, key_exponent, key_modulus, worker_sender, bruteforce_finished);
         });
     }
 
-    // Listen for the signals from the threads/workers.
-    let received_result = main_receiver.recv()?;
+    // Drop the sender of the main thread, so that the channel is closed, if all of the workers finish without a result.
+    drop(worker_sender);
+
+    // Listen for the signals from the threads/workers, forward the progress reports to the callback
+    // until a worker sends the result. Check the cancellation before each signal and periodically, while there are none.
+    let received_result = loop {
+        if cancellation.is_cancelled() {
+            break Err(OperationError::new_cancelled("the RSA bruteforce was cancelled before the factors of the modulus were found."));
+        }
+
+        match main_receiver.recv_timeout(BRUTEFORCE_CANCELLATION_POLL_INTERVAL) {
+            Ok(TaskResult::Progress(bruteforce_progress)) => {
+                if let Some(progress_callback) = &mut progress_callback {
+                    progress_callback(bruteforce_progress);
+                }
+            }
+            Ok(task_result) => break Ok(task_result),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                break Err(OperationError::new("the RSA bruteforce workers finished without finding the factors of the modulus."));
+            }
+        }
+    };
+
+    // Stop the other workers and wait for them to exit.
+    // The progress reports left in the channel are not forwarded, the callback is not called after the bruteforce.
+    bruteforce_finished.store(true, Ordering::SeqCst);
+    thread_pool.shutdown();
+    let received_result = received_result?;
 
     // Check the received result from a worker/thread.
     match received_result {
@@ -526,239 +1558,1412 @@ fn rsa_bruteforce(
             Ok(RsaResult::BruteforceRSAResult(bruteforce_result))
         }
         TaskResult::Terminate(bruteforce_error) => Err(Box::new(bruteforce_error)),
+        TaskResult::Progress(_) => unreachable!("the progress reports are handled while listening to the workers (rsa_bruteforce)"),
     }
 }
 
-// Test module.
-#[cfg(test)]
-mod tests {
-    use crate::crypto::rsa::{
-        rsa, rsa_bruteforce, rsa_decrypt, rsa_encrypt, rsa_key_generation, RsaResult,
-    };
-    use crate::logic::bigint::{BigIntSign, ChonkerInt};
-    use crate::logic::config::Mode;
+// Split the range of the factor candidates 3 - ceiling into the equal consecutive parts, one per thread,
+// and return the odd starting points of the parts, the remainder of the division goes to the last part.
+// If there are fewer candidates than threads, the whole range is given to a single thread.
+fn bruteforce_starting_points(ceiling_limit: &ChonkerInt, thread_count: usize) -> Vec<ChonkerInt> {
+    let start_prime_point = ChonkerInt::from(3);
+    if *ceiling_limit <= start_prime_point {
+        return vec![start_prime_point];
+    }
 
-    // Test RSA handling of incorrect input data.
-    #[test]
-    fn test_rsa_incorrect_input_handling() {
-        let mode = Mode::Encode;
-        let mut target_message: Option<String> = None;
-        let mut exponent = Some("45145151".to_string());
-        let mut modulus = Some("578851612".to_string());
-        let mut thread_count = None;
+    let increment_count_bigint = &(ceiling_limit - &start_prime_point) / &ChonkerInt::from(thread_count);
+    if increment_count_bigint.is_zero() {
+        return vec![start_prime_point];
+    }
 
-        // Test for the absence of the target message string during encryption.
-        match rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
-            Ok(_) => panic!("somehow generated a result, while the error for the absence of the target message string during encryption was desired (test_rsa_incorrect_input_handling)"),
-            Err(e) => println!("Target related error: {}", e),
+    (0..thread_count)
+        .map(|thread| {
+            let starting_point = &start_prime_point + &(&increment_count_bigint * &ChonkerInt::from(thread));
+
+            // Check if the starting point is even.
+            // If it is, increment by 1.
+            // Prime numbers are odd with an exception for 2.
+            if starting_point.is_even() {
+                &starting_point + 1u64
+            } else {
+                starting_point
+            }
+        })
+        .collect()
+}
+
+// Recover the message from its two ciphertexts of the block mode of this tool, encrypted with the same modulus
+// and the coprime public exponents, without the private key. The Bézout coefficients a * e1 + b * e2 = 1 give
+// c1^a * c2^b = m^(a * e1 + b * e2) = m (mod n), one of the coefficients is negative, thus its ciphertext is inverted first.
+// The randomised paddings encrypt the different blocks each time, thus only the block mode is attacked.
+// More about the idea: https://en.wikipedia.org/wiki/RSA_(cryptosystem)#Attacks_against_plain_RSA
+pub fn rsa_common_modulus_attack(
+    first_ciphertext: &[u8],
+    second_ciphertext: &[u8],
+    first_exponent: &ChonkerInt,
+    second_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+) -> Result<Vec<u8>, OperationError> {
+    if !first_exponent.is_positive() || !second_exponent.is_positive() {
+        return Err(OperationError::new("failed to recover the RSA message with the common modulus attack, the public exponents must be positive numbers."));
+    }
+
+    let bezout = first_exponent.egcd(second_exponent);
+    if !bezout.gcd.is_one() {
+        return Err(OperationError::new(&format!("failed to recover the RSA message with the common modulus attack, the public exponents {} and {} are not coprime, their GCD is {}.", first_exponent, second_exponent, bezout.gcd)));
+    }
+
+    let block_size = rsa_padded_block_size(key_modulus)?;
+    let field_width = rsa_field_width(key_modulus);
+    if first_ciphertext.len() != second_ciphertext.len() || first_ciphertext.is_empty() || first_ciphertext.len() % field_width != 0 {
+        return Err(OperationError::new(&format!("failed to recover the RSA message with the common modulus attack, the ciphertexts of {} and {} bytes are not the equal positive multiples of the modulus byte length {}, they are not the ciphertexts of the same message.", first_ciphertext.len(), second_ciphertext.len(), field_width)));
+    }
+
+    let mut decrypted_bytes = Vec::with_capacity(first_ciphertext.len() / field_width * block_size);
+    for (first_field, second_field) in first_ciphertext.chunks_exact(field_width).zip(second_ciphertext.chunks_exact(field_width)) {
+        let first_part = common_modulus_power(&ChonkerInt::from_bytes_be(first_field), &bezout.x, key_modulus)?;
+        let second_part = common_modulus_power(&ChonkerInt::from_bytes_be(second_field), &bezout.y, key_modulus)?;
+        push_raw_block(&(&first_part * &second_part).rem_euclid(key_modulus), block_size, &mut decrypted_bytes)?;
+    }
+    remove_raw_padding(&mut decrypted_bytes, block_size)?;
+
+    Ok(decrypted_bytes)
+}
+
+// Raise the encrypted block to the Bézout coefficient modulo the modulus, the negative coefficient raises the inverse of the block.
+fn common_modulus_power(encrypted_block: &ChonkerInt, coefficient: &ChonkerInt, key_modulus: &ChonkerInt) -> Result<ChonkerInt, OperationError> {
+    if encrypted_block >= key_modulus {
+        return Err(OperationError::new("failed to recover the RSA message with the common modulus attack, the encrypted number is not below the modulus, the ciphertext or the modulus is incorrect."));
+    }
+
+    if !coefficient.is_negative() {
+        return encrypted_block.checked_modpow(coefficient, key_modulus);
+    }
+
+    match encrypted_block.modinv(key_modulus) {
+        Some(inverse_block) => inverse_block.checked_modpow(&coefficient.abs(), key_modulus),
+        None => Err(OperationError::new("failed to recover the RSA message with the common modulus attack, the encrypted number is not invertible modulo the modulus, the ciphertext or the modulus is incorrect.")),
+    }
+}
+
+// Recover the primes of the RSA modulus with the Wiener's attack, which works with the private exponents d < n^(1/4) / 3.
+// Since e * d - k * phi(n) = 1, the fraction k/d is one of the convergents of the continued fraction of e/n.
+// Every convergent gives the candidate phi(n) = (e * d - 1) / k, and the primes are the integer roots
+// of x^2 - (n - phi(n) + 1) * x + n = 0. Returns None, if no convergent gives the primes, the key is not vulnerable.
+// More about the idea: https://en.wikipedia.org/wiki/Wiener%27s_attack
+fn wiener_attack(key_exponent: &ChonkerInt, key_modulus: &ChonkerInt) -> Option<(ChonkerInt, ChonkerInt)> {
+    for (k, d) in key_exponent.convergents(key_modulus) {
+        if k.is_zero() {
+            continue;
         }
 
-        target_message = Some("A correct test string.".to_string());
-        exponent = None;
+        // The candidate phi(n) must be an exact quotient.
+        let (phi_n, remainder) = (&(key_exponent * &d) - 1u64).div_rem(&k);
+        if !remainder.is_zero() {
+            continue;
+        }
 
-        // Test for the absence of the public exponent string during encryption.
-        match rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
-            Ok(_) => panic!("somehow generated a result, while the error for the absence of the public exponent string during encryption was desired (test_rsa_incorrect_input_handling)"),
-            Err(e) => println!("Public exponent related error: {}", e),
+        // The sum of the primes p + q = n - phi(n) + 1, the difference of the primes is the root of the discriminant (p + q)^2 - 4n.
+        let prime_sum = &(key_modulus - &phi_n) + 1u64;
+        let discriminant = &(&prime_sum * &prime_sum) - &(key_modulus * 4u64);
+        if let Some(prime_difference) = discriminant.sqrt_exact() {
+            let mut prime_p = &prime_sum - &prime_difference;
+            let mut prime_q = &prime_sum + &prime_difference;
+            if prime_p.is_even() && prime_p.is_positive() {
+                prime_p.halve_in_place();
+                prime_q.halve_in_place();
+
+                if &prime_p * &prime_q == *key_modulus && !prime_p.is_one() {
+                    return Some((prime_p, prime_q));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// Package the primes of the bruteforced RSA modulus with the private exponent, calculated from them.
+fn bruteforce_result_from_primes(
+    prime_p: ChonkerInt,
+    prime_q: ChonkerInt,
+    key_exponent: &ChonkerInt,
+    key_modulus: &ChonkerInt,
+) -> Result<BruteforceResult, OperationError> {
+    let big_one = ChonkerInt::from(1);
+
+    // Generate result of Euler's totient function, phi(n) = (p-1)(q-1)
+    let phi_n = &(&prime_p - &big_one) * &(&prime_q - &big_one);
+
+    // Generate the private exponent, the modular multiplicative inverse of the public exponent.
+    // If it does not exist, the provided public exponent is not coprime to the phi(n).
+    let private_key_d = match key_exponent.modinv(&phi_n) {
+        Some(value) => value,
+        None => return Err(OperationError::new("The target RSA public exponent for bruteforce is incorrect, it must be a coprime to the euler's totient of the bruteforced primes.")),
+    };
+
+    let crt = RsaCrtComponents::new(&prime_p, &prime_q, &private_key_d);
+
+    // Check the found key pair, before it is reported.
+    RsaKeyPair {
+        public_key_n: (*key_modulus).clone(),
+        public_key_e: (*key_exponent).clone(),
+        private_key_d: private_key_d.clone(),
+        crt: crt.clone(),
+    }
+    .validate()?;
+
+    Ok(BruteforceResult {
+        prime_q,
+        prime_p,
+        public_key_n: (*key_modulus).clone(),
+        public_key_e: (*key_exponent).clone(),
+        private_key_d,
+        crt,
+    })
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::{Read, Write};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use crate::crypto::rsa::{
+        bruteforce_starting_points, rsa, rsa_common_modulus_attack, rsa_block_size, rsa_bruteforce, rsa_decrypt, rsa_decrypt_bytes, rsa_decrypt_stream, rsa_encrypt_stream, rsa_message_block_size, rsa_decrypt_raw, rsa_decrypt_oaep, rsa_decrypt_pkcs1v15, rsa_encrypt_bytes, rsa_encrypt_raw, rsa_encrypt_oaep, rsa_encrypt_pkcs1v15, rsa_field_width, rsa_private_operation, rsa_decrypt_crt, bruteforce_result_from_primes, mgf1, oaep_decode, oaep_encode, rsa_padded_block_size, rsa_encrypt, rsa_blinded_private_operation, rsa_blinding_factor, rsa_key_generation, rsa_key_generation_with_rng, BruteforceProgress, BruteforceResult, BruteforceStrategy,
+        ExponentChoice, OaepHash, OaepParams, RsaCrtComponents, RsaKeyPair, RsaKeygenOptions, RsaPadding, RsaPrivateKey, RsaPublicKey, RsaRequest, RsaResult, KEY_FINGERPRINT_LEN, MODULUS_BIT_SIZE,
+    };
+    use crate::encoding::{string_hex_encode, Encoding, HexCase};
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
+    use crate::logic::cancellation::CancellationToken;
+    use crate::logic::config::{rsa_from_strings, rsa_request_from_args, Mode, RsaArgs};
+    use crate::logic::error::OperationError;
+
+    // Test RSA handling of incorrect input data through the deprecated entry point with the string arguments.
+    #[test]
+    #[allow(deprecated)]
+    fn test_rsa_incorrect_input_handling() {
+        let mode = Mode::Encode;
+        let mut target_message: Option<String> = None;
+        let mut exponent = Some("45145151".to_string());
+        let mut modulus = Some("578851612".to_string());
+        let mut thread_count = None;
+
+        // Test for the absence of the target message string during encryption.
+        match rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
+            Ok(_) => panic!("somehow generated a result, while the error for the absence of the target message string during encryption was desired (test_rsa_incorrect_input_handling)"),
+            Err(e) => println!("Target related error: {}", e),
+        }
+
+        target_message = Some("A correct test string.".to_string());
+        exponent = None;
+
+        // Test for the absence of the public exponent string during encryption.
+        match rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
+            Ok(_) => panic!("somehow generated a result, while the error for the absence of the public exponent string during encryption was desired (test_rsa_incorrect_input_handling)"),
+            Err(e) => println!("Public exponent related error: {}", e),
+        }
+
+        let mut exponent = Some("ABCDE".to_string());
+
+        // Test for the incorrectness of the public exponent string during encryption.
+        match rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
+            Ok(_) => panic!("somehow generated a result, while the error for the incorrectness of the public exponent string during encryption was desired (test_rsa_incorrect_input_handling)"),
+            Err(e) => println!("Public exponent related error: {}", e),
+        }
+
+        exponent = Some("45145151".to_string());
+        modulus = None;
+
+        // Test for the absence of the modulus string during encryption.
+        match rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
+            Ok(_) => panic!("somehow generated a result, while the error for the absence of the modulus string during encryption was desired (test_rsa_incorrect_input_handling)"),
+            Err(e) => println!("Modulus related error: {}", e),
+        }
+
+        modulus = Some("ABCDE".to_string());
+
+        // Test for the incorrectness of the modulus string during encryption.
+        match rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
+            Ok(_) => panic!("somehow generated a result, while the error for the incorrectness of the modulus string during encryption was desired (test_rsa_incorrect_input_handling)"),
+            Err(e) => println!("Modulus related error: {}", e),
+        }
+
+        modulus = Some("57885161".to_string());
+
+        // Test for the modulus being a composite number during encryption.
+        match rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
+            Ok(_) => panic!("somehow generated a result, while the error for the modulus being a composite number during encryption was desired (test_rsa_incorrect_input_handling)"),
+            Err(e) => println!("Modulus related error: {}", e),
+        }
+
+        modulus = Some("221".to_string());
+
+        // Test for the modulus being too short, the block of the modulus is shorter than 1 byte during encryption.
+        match rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
+            Ok(_) => panic!("somehow generated a result, while the error for the modulus being too short, the block of the modulus is shorter than 1 byte during encryption was desired (test_rsa_incorrect_input_handling)"),
+            Err(e) => println!("Modulus related error: {}", e),
+        }
+
+        thread_count = Some("65".to_string());
+
+        // Test for the thread count being too big, being over 64.
+        match rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
+            Ok(_) => panic!("somehow generated a result, while the error for the thread count being too big, being over 64 was desired (test_rsa_incorrect_input_handling)"),
+            Err(e) => println!("Thread count related error: {}", e),
+        }
+
+        thread_count = Some("0".to_string());
+
+        // Test for the thread count being zero.
+        match rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
+            Ok(_) => panic!("somehow generated a result, while the error for the thread count being zero was desired (test_rsa_incorrect_input_handling)"),
+            Err(e) => println!("Thread count related error: {}", e),
+        }
+    }
+
+    // Test RSA handling of correct input data through the deprecated entry point with the string arguments.
+    #[test]
+    #[allow(deprecated)]
+    fn test_rsa_correct_input_handling() {
+        let mut mode = Mode::Generate;
+        let mut target_message: Option<String> = None;
+        let mut exponent = Some("random".to_string());
+        let mut modulus = Some("192".to_string());
+        let mut thread_count = None;
+
+        // Test random RSA keypair generation with absent or incorrect data besides the mode, the exponent choice and the key size.
+        let _keypair = rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+
+        target_message = Some("Test string.".to_string());
+        exponent = None;
+        modulus = None;
+
+        // Test random RSA keypair generation with absent or incorrect data besides the mode..
+        let _keypair = rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+
+        // A key pair that was produced separately by the rsa_key_generation() function.
+        // Randomly generated RSA public/private key modulus n: 441982524952231918609144409818894577105184461
+        // Randomly generated RSA public key exponent e: 6119931580888508280272762765
+        // Randomly generated RSA private key exponent d: 3257209244777795983999918284178604218550597
+
+        mode = Mode::Encode;
+        exponent = Some("6119931580888508280272762765".to_string());
+        modulus = Some("441982524952231918609144409818894577105184461".to_string());
+
+        // Test the target string encryption with correct parameters. Print out the encrypted string, it is also encoded in hexadecimal format.
+        let encryption_result = rsa_from_strings(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+
+        let encrypted_string = match encryption_result {
+            RsaResult::KeyPair(_) => panic!("somehow generated a random RSA key pair, while the encrypted string was desired (test_rsa_correct_input_handling)"),
+            RsaResult::StringResult(encrypted_string) => {
+                println!("The encrypted string: {}", encrypted_string);
+                encrypted_string
+            }
+            RsaResult::BytesResult(_) | RsaResult::FileResult { .. } => panic!("somehow generated the bytes, while the encrypted string was desired (test_rsa_correct_input_handling)"),
+            RsaResult::BruteforceRSAResult(_) => panic!("somehow generated an RSA bruteforce result, while the encrypted string was desired (test_rsa_correct_input_handling)"),
+        };
+
+        mode = Mode::Decode;
+        exponent = Some("3257209244777795983999918284178604218550597".to_string());
+
+        // Result of the encryption of the "Test string." with previous parameters.
+        // let encrypted_string = Some("0109020000060607080608020405030409090304010309000708090108070900050901080503010803");
+
+        // Test the target string decryption with correct parameters. Print out the decrypted string and comapre it with the original.
+        let decryption_result = rsa_from_strings(&mode, Some(encrypted_string.clone()), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+
+        let decrypted_string = match decryption_result {
+            RsaResult::KeyPair(_) => panic!("somehow generated a random RSA key pair, while the decrypted string was desired (test_rsa_correct_input_handling)"),
+            RsaResult::StringResult(decrypted_string) => {
+                println!("The decrypted string: {}", decrypted_string);
+                decrypted_string
+            }
+            RsaResult::BytesResult(_) | RsaResult::FileResult { .. } => panic!("somehow generated the bytes, while the decrypted string was desired (test_rsa_correct_input_handling)"),
+            RsaResult::BruteforceRSAResult(_) => panic!("somehow generated an RSA bruteforce result, while the encrypted string was desired (test_rsa_correct_input_handling)"),
+        };
+
+        // Check the original string with the decrypted string.
+        assert_eq!(target_message.unwrap(), decrypted_string);
+
+        // Test requests for bruteforcing.
+        mode = Mode::Bruteforce;
+        exponent = Some("85".to_string());
+        modulus = Some("268970693".to_string());
+        thread_count = None; // Rely on default 8 threads/workers.
+
+        let target_modulus = ChonkerInt::from(268970693);
+        let target_public_exponent = ChonkerInt::from(85);
+        let private_key_comparison = ChonkerInt::from(88590349);
+
+        let bruteforce_result = rsa_from_strings(&mode, Some(encrypted_string.clone()), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+
+        let rsa_package = match bruteforce_result {
+            RsaResult::BruteforceRSAResult(rsa_result) => rsa_result,
+            _ => panic!(
+                "error in the algorithm, did not compute a bruteforce result (test_rsa_bruteforce)"
+            ),
+        };
+
+        println!("Bruteforce result package: {:?}", rsa_package);
+        println!("Bruteforce prime p: {}", rsa_package.prime_p);
+        println!("Bruteforce prime q: {}", rsa_package.prime_q);
+
+        assert_eq!(rsa_package.public_key_n, target_modulus);
+        assert_eq!(rsa_package.public_key_e, target_public_exponent);
+        assert_eq!(rsa_package.private_key_d, private_key_comparison);
+
+        // Repeat the bruteforcing request with a custom amount of threads.
+        thread_count = Some("24".to_string()); // Rely on default 8 threads/workers.
+        let bruteforce_result = rsa_from_strings(&mode, Some(encrypted_string.clone()), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+
+        let rsa_package = match bruteforce_result {
+            RsaResult::BruteforceRSAResult(rsa_result) => rsa_result,
+            _ => panic!(
+                "error in the algorithm, did not compute a bruteforce result (test_rsa_bruteforce)"
+            ),
+        };
+
+        println!("Bruteforce result package: {:?}", rsa_package);
+        println!("Bruteforce prime p: {}", rsa_package.prime_p);
+        println!("Bruteforce prime q: {}", rsa_package.prime_q);
+
+        // let private_key_comparison = ChonkerInt::from(2485);
+        let private_key_comparison = ChonkerInt::from(88590349);
+
+        assert_eq!(rsa_package.public_key_n, target_modulus);
+        assert_eq!(rsa_package.public_key_e, target_public_exponent);
+        assert_eq!(rsa_package.private_key_d, private_key_comparison);
+    }
+
+    // Test the routing of the typed RSA requests by the entry point: the encryption and the decryption produce the strings,
+    // the key generation produces a key pair and the bruteforce produces the bruteforce result.
+    #[test]
+    fn test_rsa_request_routing() {
+        let key_modulus = ChonkerInt::from(String::from("441982524952231918609144409818894577105184461"));
+        let public_key_e = ChonkerInt::from(String::from("6119931580888508280272762765"));
+        let private_key_d = ChonkerInt::from(String::from("3257209244777795983999918284178604218550597"));
+        let target_string = "String for the RSA request routing.";
+
+        let encrypt_request = RsaRequest::Encrypt {
+            message: target_string.to_string(),
+            public: RsaPublicKey { public_key_n: key_modulus.clone(), public_key_e: public_key_e.clone() },
+            padding: RsaPadding::Raw,
+            encoding: Encoding::Hex(HexCase::Upper),
+        };
+        let ciphertext = match rsa(encrypt_request, None, &CancellationToken::new()).unwrap() {
+            RsaResult::StringResult(ciphertext) => ciphertext,
+            _ => panic!("the encryption request did not produce the encrypted string (test_rsa_request_routing)"),
+        };
+        assert_eq!(ciphertext, rsa_encrypt(target_string, &public_key_e, &key_modulus, &RsaPadding::Raw).unwrap());
+
+        let decrypt_request = RsaRequest::Decrypt {
+            ciphertext,
+            private: RsaPrivateKey { public_key_n: key_modulus.clone(), private_key_d: private_key_d.clone(), crt: None },
+            padding: RsaPadding::Raw,
+            blinding: false,
+            encoding: None,
+        };
+        assert_eq!(rsa(decrypt_request, None, &CancellationToken::new()).unwrap(), RsaResult::StringResult(target_string.to_string()));
+
+        // The base64 ciphertext is decrypted with the selected encoding, the default hex encoding denies it.
+        let encrypt_request = RsaRequest::Encrypt {
+            message: target_string.to_string(),
+            public: RsaPublicKey { public_key_n: key_modulus.clone(), public_key_e: public_key_e.clone() },
+            padding: RsaPadding::Raw,
+            encoding: Encoding::Base64,
+        };
+        let ciphertext = match rsa(encrypt_request, None, &CancellationToken::new()).unwrap() {
+            RsaResult::StringResult(ciphertext) => ciphertext,
+            _ => panic!("the encryption request did not produce the encrypted string (test_rsa_request_routing)"),
+        };
+        for encoding in [Some(Encoding::Base64), None] {
+            let decrypt_request = RsaRequest::Decrypt {
+                ciphertext: ciphertext.clone(),
+                private: RsaPrivateKey { public_key_n: key_modulus.clone(), private_key_d: private_key_d.clone(), crt: None },
+                padding: RsaPadding::Raw,
+                blinding: false,
+                encoding,
+            };
+            match encoding {
+                Some(_) => assert_eq!(rsa(decrypt_request, None, &CancellationToken::new()).unwrap(), RsaResult::StringResult(target_string.to_string())),
+                None => assert!(rsa(decrypt_request, None, &CancellationToken::new()).is_err()),
+            }
+        }
+
+        let generate_request = RsaRequest::Generate { bits: 192, options: RsaKeygenOptions::default() };
+        match rsa(generate_request, None, &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => assert!(key_pair.public_key_n.bit_length() >= 191),
+            _ => panic!("the key generation request did not produce a key pair (test_rsa_request_routing)"),
+        }
+
+        let bruteforce_request = RsaRequest::Bruteforce {
+            public: RsaPublicKey { public_key_n: ChonkerInt::from(268970693), public_key_e: ChonkerInt::from(85) },
+            threads: Some(2),
+            strategy: BruteforceStrategy::TrialDivision,
+        };
+        match rsa(bruteforce_request, None, &CancellationToken::new()).unwrap() {
+            RsaResult::BruteforceRSAResult(bruteforce_result) => assert_eq!(bruteforce_result.private_key_d, ChonkerInt::from(88590349)),
+            _ => panic!("the bruteforce request did not produce a bruteforce result (test_rsa_request_routing)"),
+        }
+
+        // The bruteforce request with an unsupported thread count reaches the bruteforce and is rejected there.
+        let bruteforce_request = RsaRequest::Bruteforce {
+            public: RsaPublicKey { public_key_n: ChonkerInt::from(268970693), public_key_e: ChonkerInt::from(85) },
+            threads: Some(65),
+            strategy: BruteforceStrategy::TrialDivision,
+        };
+        assert!(rsa(bruteforce_request, None, &CancellationToken::new()).unwrap_err().to_string().contains("thread count"));
+    }
+
+    // Test the validation of the exponent and the modulus in the RSA encryption and decryption,
+    // each rejected key is reported with the failed check.
+    #[test]
+    fn test_rsa_encryption_and_decryption_key_checks() {
+        let key_modulus = ChonkerInt::from(String::from("441982524952231918609144409818894577105184461"));
+        let public_key_e = ChonkerInt::from(String::from("6119931580888508280272762765"));
+        let private_key_d = ChonkerInt::from(String::from("3257209244777795983999918284178604218550597"));
+        let target_string = "String for the RSA key checks.";
+
+        // The even exponent was accepted before and produced a ciphertext, which never decrypts.
+        let even_exponent = &public_key_e + &ChonkerInt::from(1);
+        let encryption_error = rsa_encrypt(target_string, &even_exponent, &key_modulus, &RsaPadding::Raw).unwrap_err();
+        assert!(encryption_error.to_string().contains("public exponent") && encryption_error.to_string().contains("is even"));
+
+        let even_modulus = &key_modulus + &ChonkerInt::from(1);
+        for (key_exponent, modulus, expected_error) in [
+            (ChonkerInt::from(1), &key_modulus, "is too small"),
+            (ChonkerInt::from(2), &key_modulus, "is too small"),
+            (ChonkerInt::from(0), &key_modulus, "is too small"),
+            (&key_modulus + &ChonkerInt::from(2), &key_modulus, "is not below the modulus"),
+            (key_modulus.clone(), &key_modulus, "is not below the modulus"),
+            (public_key_e.clone(), &even_modulus, "modulus"),
+            (ChonkerInt::from(3), &ChonkerInt::from(7), "is too short"),
+        ] {
+            let encryption_error = rsa_encrypt(target_string, &key_exponent, modulus, &RsaPadding::Raw).unwrap_err();
+            assert!(encryption_error.to_string().contains(expected_error), "unexpected error: {} (test_rsa_encryption_and_decryption_key_checks)", encryption_error);
+        }
+        assert!(rsa_encrypt(target_string, &public_key_e, &even_modulus, &RsaPadding::Raw).unwrap_err().to_string().contains("is even"));
+
+        // The decryption checks the private exponent and the modulus in the same way, the padding does not matter.
+        let ciphertext = rsa_encrypt(target_string, &public_key_e, &key_modulus, &RsaPadding::Raw).unwrap();
+        for padding in [RsaPadding::Raw, RsaPadding::Pkcs1v15] {
+            assert!(rsa_decrypt(&ciphertext, &ChonkerInt::from(1), &key_modulus, &padding).unwrap_err().to_string().contains("is too small"));
+            assert!(rsa_decrypt(&ciphertext, &key_modulus, &key_modulus, &padding).unwrap_err().to_string().contains("is not below the modulus"));
+            assert!(rsa_decrypt(&ciphertext, &private_key_d, &even_modulus, &padding).unwrap_err().to_string().contains("is even"));
+        }
+        assert_eq!(rsa_decrypt(&ciphertext, &private_key_d, &key_modulus, &RsaPadding::Raw).unwrap(), target_string);
+    }
+
+    // Test RSA encryption and decryption of the binary data with every byte value with all paddings,
+    // and the rejection of the invalid UTF-8 texts by the decryption of the strings.
+    #[test]
+    fn test_rsa_encryption_and_decryption_binary() {
+        let key_pair = match rsa_key_generation_with_rng(768, &mut StdRng::seed_from_u64(70), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_encryption_and_decryption_binary)"),
+        };
+        let (key_e, key_d, key_n) = (&key_pair.public_key_e, &key_pair.private_key_d, &key_pair.public_key_n);
+        let all_bytes: Vec<u8> = (0..=255).collect();
+
+        for padding in [RsaPadding::Raw, RsaPadding::Pkcs1v15, RsaPadding::Oaep(OaepParams::default())] {
+            let ciphertext = rsa_encrypt_bytes(&all_bytes, key_e, key_n, &padding).unwrap();
+            assert_eq!(rsa_decrypt_bytes(&ciphertext, key_d, key_n, None, None, &padding).unwrap(), all_bytes);
+            assert_eq!(rsa_decrypt_bytes(&ciphertext, key_d, key_n, key_pair.crt.as_ref(), None, &padding).unwrap(), all_bytes);
+
+            // The same through the entry point.
+            let public = RsaPublicKey { public_key_n: key_n.clone(), public_key_e: key_e.clone() };
+            let ciphertext = match rsa(RsaRequest::EncryptBytes { data: all_bytes.clone(), public, padding: padding.clone() }, None, &CancellationToken::new()).unwrap() {
+                RsaResult::BytesResult(ciphertext) => ciphertext,
+                _ => panic!("produced another result instead of the encrypted bytes (test_rsa_encryption_and_decryption_binary)"),
+            };
+            let private = RsaPrivateKey { public_key_n: key_n.clone(), private_key_d: key_d.clone(), crt: key_pair.crt.clone() };
+            let decryption_result = rsa(RsaRequest::DecryptBytes { ciphertext, private, padding, blinding: false }, None, &CancellationToken::new()).unwrap();
+            assert_eq!(decryption_result, RsaResult::BytesResult(all_bytes.clone()));
+        }
+
+        // The binary data is not returned as a string, the error replaces the invalid UTF-8 text.
+        let ciphertext = string_hex_encode(&rsa_encrypt_bytes(&all_bytes, key_e, key_n, &RsaPadding::Raw).unwrap()).unwrap();
+        let decryption_error = rsa_decrypt(&ciphertext, key_d, key_n, &RsaPadding::Raw).unwrap_err();
+        assert!(decryption_error.to_string().contains("not a valid UTF-8 text"));
+
+        // The decryption with a wrong key returns an error instead of the garbage string.
+        let ciphertext = rsa_encrypt("String for the decryption with a wrong key.", key_e, key_n, &RsaPadding::Raw).unwrap();
+        let other_key_pair = match rsa_key_generation_with_rng(768, &mut StdRng::seed_from_u64(71), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_encryption_and_decryption_binary)"),
+        };
+        let wrong_d = other_key_pair.private_key_d.rem_euclid(key_n);
+        assert!(rsa_decrypt(&ciphertext, &wrong_d, key_n, &RsaPadding::Raw).is_err());
+    }
+
+    // Test RSA streaming encryption and decryption of a file of several megabytes and of an empty file with all paddings,
+    // the streamed ciphertext is the same as the one of the whole data.
+    #[test]
+    fn test_rsa_encryption_and_decryption_stream() {
+        let key_pair = match rsa_key_generation_with_rng(768, &mut StdRng::seed_from_u64(71), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_encryption_and_decryption_stream)"),
+        };
+        let (key_e, key_d, key_n, crt) = (&key_pair.public_key_e, &key_pair.private_key_d, &key_pair.public_key_n, key_pair.crt.as_ref());
+
+        // A file of many blocks, which does not end on a block boundary.
+        check_rsa_stream_file_round_trip(&key_pair, 48 * 1024 + 7);
+
+        // The shorter data, the data of exactly one block and the empty data with all paddings.
+        for padding in [RsaPadding::Raw, RsaPadding::Pkcs1v15, RsaPadding::Oaep(OaepParams::default())] {
+            let block_size = rsa_message_block_size(key_n, &padding).unwrap();
+            for data_len in [0, 1, block_size, 3 * block_size + 1] {
+                let data = vec![0xA5u8; data_len];
+                let mut ciphertext = vec![];
+                rsa_encrypt_stream(data.as_slice(), &mut ciphertext, key_e, key_n, &padding).unwrap();
+                assert_eq!(rsa_decrypt_bytes(&ciphertext, key_d, key_n, crt, None, &padding).unwrap(), data);
+
+                let mut decrypted_bytes = vec![];
+                rsa_decrypt_stream(ciphertext.as_slice(), &mut decrypted_bytes, key_d, key_n, crt, None, &padding).unwrap();
+                assert_eq!(decrypted_bytes, data);
+            }
+
+            // The empty and the truncated ciphertexts are rejected.
+            let mut ciphertext = vec![];
+            rsa_encrypt_stream(&b"Truncated message."[..], &mut ciphertext, key_e, key_n, &padding).unwrap();
+            assert!(rsa_decrypt_stream(&ciphertext[..ciphertext.len() - 1], &mut vec![], key_d, key_n, crt, None, &padding).is_err());
+            assert!(rsa_decrypt_stream(&[][..], &mut vec![], key_d, key_n, crt, None, &padding).is_err());
+        }
+    }
+
+    // Test the RSA streaming encryption and decryption of a multi megabyte file.
+    // The block by block arithmetic is too slow for the default debug test run, run with "cargo test --release -- --ignored".
+    #[test]
+    #[ignore = "slow in the debug build"]
+    fn test_rsa_encryption_and_decryption_stream_large_file() {
+        let key_pair = match rsa_key_generation_with_rng(512, &mut StdRng::seed_from_u64(72), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_encryption_and_decryption_stream_large_file)"),
+        };
+
+        check_rsa_stream_file_round_trip(&key_pair, 2 * 1024 * 1024 + 7);
+    }
+
+    // Encrypt and decrypt a file of pseudo random content through the files on the disk.
+    fn check_rsa_stream_file_round_trip(key_pair: &RsaKeyPair, file_len: usize) {
+        let (key_e, key_d, key_n, crt) = (&key_pair.public_key_e, &key_pair.private_key_d, &key_pair.public_key_n, key_pair.crt.as_ref());
+        let work_dir = std::env::temp_dir().join(format!("enc_rsa_stream_{}_{}", std::process::id(), file_len));
+        std::fs::create_dir_all(&work_dir).unwrap();
+        let (input_path, ciphertext_path, output_path) = (work_dir.join("input.bin"), work_dir.join("ciphertext.bin"), work_dir.join("output.bin"));
+        let mut input_bytes = vec![0u8; file_len];
+        StdRng::seed_from_u64(2).fill_bytes(&mut input_bytes);
+        std::fs::write(&input_path, &input_bytes).unwrap();
+
+        let ciphertext_len = rsa_encrypt_stream(File::open(&input_path).unwrap(), File::create(&ciphertext_path).unwrap(), key_e, key_n, &RsaPadding::Raw).unwrap();
+        assert_eq!(ciphertext_len, std::fs::metadata(&ciphertext_path).unwrap().len());
+        let output_len = rsa_decrypt_stream(File::open(&ciphertext_path).unwrap(), File::create(&output_path).unwrap(), key_d, key_n, crt, None, &RsaPadding::Raw).unwrap();
+        assert_eq!(output_len, input_bytes.len() as u64);
+        assert!(std::fs::read(&output_path).unwrap() == input_bytes);
+
+        // The deterministic block mode produces the same ciphertext as the encryption of the whole data.
+        assert!(std::fs::read(&ciphertext_path).unwrap() == rsa_encrypt_bytes(&input_bytes, key_e, key_n, &RsaPadding::Raw).unwrap());
+        std::fs::remove_dir_all(&work_dir).unwrap();
+    }
+
+    // Test the interruption of the RSA streaming encryption and decryption by the failing writer and reader,
+    // the errors are returned instead of the panics.
+    #[test]
+    fn test_rsa_stream_interruption() {
+        // A writer, which fails after the limit of the written bytes.
+        struct FailingWriter {
+            written_len: usize,
+            limit: usize,
+        }
+
+        impl Write for FailingWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                if self.written_len + buf.len() > self.limit {
+                    return Err(std::io::Error::new(std::io::ErrorKind::Other, "the disk is full"));
+                }
+                self.written_len += buf.len();
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let key_modulus = ChonkerInt::from(String::from("441982524952231918609144409818894577105184461"));
+        let public_key_e = ChonkerInt::from(String::from("6119931580888508280272762765"));
+        let private_key_d = ChonkerInt::from(String::from("3257209244777795983999918284178604218550597"));
+        let data = vec![0x5Au8; 1000];
+
+        let encryption_error = rsa_encrypt_stream(data.as_slice(), FailingWriter { written_len: 0, limit: 100 }, &public_key_e, &key_modulus, &RsaPadding::Raw).unwrap_err();
+        assert!(encryption_error.to_string().contains("the disk is full"));
+
+        let mut ciphertext = vec![];
+        rsa_encrypt_stream(data.as_slice(), &mut ciphertext, &public_key_e, &key_modulus, &RsaPadding::Raw).unwrap();
+        let decryption_error = rsa_decrypt_stream(ciphertext.as_slice(), FailingWriter { written_len: 0, limit: 100 }, &private_key_d, &key_modulus, None, None, &RsaPadding::Raw).unwrap_err();
+        assert!(decryption_error.to_string().contains("the disk is full"));
+
+        // A reader, which fails in the middle of the data.
+        let failing_reader = data.as_slice().chain(FailingReader);
+        struct FailingReader;
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "the connection is lost"))
+            }
+        }
+        let encryption_error = rsa_encrypt_stream(failing_reader, &mut vec![], &public_key_e, &key_modulus, &RsaPadding::Raw).unwrap_err();
+        assert!(encryption_error.to_string().contains("the connection is lost"));
+    }
+
+    // Test RSA random key pair generation.
+    #[test]
+    fn test_rsa_key_pair_random_generation() {
+        let rsa_generation_result = rsa_key_generation(MODULUS_BIT_SIZE, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap();
+
+        match rsa_generation_result {
+            RsaResult::KeyPair(key_pair) => {
+                println!("Randomly generated RSA public/private key modulus n: {}", key_pair.public_key_n);
+                println!("Randomly generated RSA public key exponent e: {}", key_pair.public_key_e);
+                println!("Randomly generated RSA private key exponent d: {}", key_pair.private_key_d);
+                assert_eq!(*key_pair.private_key_d.get_sign(), BigIntSign::Positive);
+            }
+            RsaResult::StringResult(_) | RsaResult::BytesResult(_) | RsaResult::FileResult { .. } => panic!("produced a result of encryption/decryption instead of a randomly generated key pair (test_rsa_key_pair_random_generation)"),
+            RsaResult::BruteforceRSAResult(_) => panic!("somehow generated an RSA bruteforce result, while the encrypted string was desired (test_rsa_correct_input_handling)"),
+        }
+    }
+
+    // Test RSA key pair generation with a seeded random number generator, the same seed produces the same key pair.
+    #[test]
+    fn test_rsa_key_pair_seeded_generation() {
+        let key_pair = rsa_key_generation_with_rng(MODULUS_BIT_SIZE, &mut StdRng::seed_from_u64(42), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap();
+        let same_key_pair = rsa_key_generation_with_rng(MODULUS_BIT_SIZE, &mut StdRng::seed_from_u64(42), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap();
+        let other_key_pair = rsa_key_generation_with_rng(MODULUS_BIT_SIZE, &mut StdRng::seed_from_u64(43), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap();
+
+        assert!(matches!(key_pair, RsaResult::KeyPair(_)));
+        assert_eq!(key_pair, same_key_pair);
+        assert_ne!(key_pair, other_key_pair);
+    }
+
+    // Test the cancellation of the RSA key pair generation, a token with a distant deadline changes nothing.
+    #[test]
+    fn test_rsa_key_pair_cancelled_generation() {
+        let key_pair = rsa_key_generation_with_rng(MODULUS_BIT_SIZE, &mut StdRng::seed_from_u64(42), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap();
+        let distant_deadline = CancellationToken::with_timeout(Duration::from_secs(3600));
+        let same_key_pair = rsa_key_generation_with_rng(MODULUS_BIT_SIZE, &mut StdRng::seed_from_u64(42), &RsaKeygenOptions::default(), &distant_deadline).unwrap();
+        assert_eq!(key_pair, same_key_pair);
+
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+        let generation_error = rsa_key_generation_with_rng(4096, &mut StdRng::seed_from_u64(42), &RsaKeygenOptions::default(), &cancellation).unwrap_err();
+        assert!(generation_error.downcast_ref::<OperationError>().unwrap().get_cancelled_flag());
+    }
+
+    // Test RSA key pair generation with the requested bit size of the modulus and a round trip with a 256 bit key.
+    #[test]
+    fn test_rsa_key_pair_generation_by_bit_size() {
+        let mut rng = StdRng::seed_from_u64(256);
+        let key_pair = match rsa_key_generation_with_rng(256, &mut rng, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("expected a key pair from the RSA key generation (test_rsa_key_pair_generation_by_bit_size)"),
+        };
+
+        let modulus_bits = key_pair.public_key_n.bit_length();
+        assert!(modulus_bits == 255 || modulus_bits == 256);
+
+        let target_string = "String for RSA encryption with a 256 bit modulus.";
+        let encryption_result = rsa_encrypt(target_string, &key_pair.public_key_e, &key_pair.public_key_n, &RsaPadding::Raw).unwrap();
+        let decryption_result = rsa_decrypt(&encryption_result, &key_pair.private_key_d, &key_pair.public_key_n, &RsaPadding::Raw).unwrap();
+        assert_eq!(target_string, decryption_result);
+
+        // Default size and too small sizes.
+        match rsa_key_generation(MODULUS_BIT_SIZE, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => assert!(key_pair.public_key_n.bit_length() >= MODULUS_BIT_SIZE - 1),
+            _ => panic!("expected a key pair from the RSA key generation (test_rsa_key_pair_generation_by_bit_size)"),
+        }
+        assert!(rsa_key_generation_with_rng(3, &mut rng, &RsaKeygenOptions::default(), &CancellationToken::new()).is_err());
+
+        // The key size through the entry point.
+        let key_pair = match rsa(RsaRequest::Generate { bits: 256, options: RsaKeygenOptions::default() }, None, &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("expected a key pair from the RSA key generation (test_rsa_key_pair_generation_by_bit_size)"),
+        };
+        let modulus_bits = key_pair.public_key_n.bit_length();
+        assert!(modulus_bits == 255 || modulus_bits == 256);
+        let encryption_result = rsa_encrypt(target_string, &key_pair.public_key_e, &key_pair.public_key_n, &RsaPadding::Raw).unwrap();
+        assert_eq!(rsa_decrypt(&encryption_result, &key_pair.private_key_d, &key_pair.public_key_n, &RsaPadding::Raw).unwrap(), target_string);
+
+        // The key size out of the range 128-4096 or not a number is rejected by the configuration.
+        for key_size in ["127", "64", "4097", "-256", "256 bits", ""] {
+            let rsa_args = RsaArgs { key_modulus: Some(String::from(key_size)), ..RsaArgs::default() };
+            let generation_error = rsa_request_from_args(&Mode::Generate, rsa_args).unwrap_err();
+            assert!(generation_error.to_string().contains("the bit size of the RSA modulus"));
+        }
+    }
+
+    // Test the choice of the public exponent in the RSA key pair generation: the fixed 65537 by default or a random one.
+    #[test]
+    fn test_rsa_key_pair_generation_exponent_choice() {
+        let mut rng = StdRng::seed_from_u64(65537);
+        let target_string = "String for RSA encryption with the chosen public exponent.";
+
+        for (options, fixed_exponent) in [
+            (RsaKeygenOptions::default(), true),
+            (RsaKeygenOptions { exponent: ExponentChoice::Fixed65537 }, true),
+            (RsaKeygenOptions { exponent: ExponentChoice::Random }, false),
+        ] {
+            for _ in 0..3 {
+                let key_pair = match rsa_key_generation_with_rng(MODULUS_BIT_SIZE, &mut rng, &options, &CancellationToken::new()).unwrap() {
+                    RsaResult::KeyPair(key_pair) => key_pair,
+                    _ => panic!("expected a key pair from the RSA key generation (test_rsa_key_pair_generation_exponent_choice)"),
+                };
+
+                assert_eq!(key_pair.public_key_e == ChonkerInt::from(65537), fixed_exponent);
+
+                let encryption_result = rsa_encrypt(target_string, &key_pair.public_key_e, &key_pair.public_key_n, &RsaPadding::Raw).unwrap();
+                let decryption_result = rsa_decrypt(&encryption_result, &key_pair.private_key_d, &key_pair.public_key_n, &RsaPadding::Raw).unwrap();
+                assert_eq!(target_string, decryption_result);
+            }
+        }
+
+        // The small moduli, for which 65537 is bigger than lambda(n), still produce the valid key pairs.
+        for _ in 0..20 {
+            match rsa_key_generation_with_rng(16, &mut rng, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+                RsaResult::KeyPair(key_pair) => {
+                    let message = ChonkerInt::from(12345) % &key_pair.public_key_n;
+                    let encrypted = message.modpow(&key_pair.public_key_e, &key_pair.public_key_n);
+                    assert_eq!(encrypted.modpow(&key_pair.private_key_d, &key_pair.public_key_n), message);
+                }
+                _ => panic!("expected a key pair from the RSA key generation (test_rsa_key_pair_generation_exponent_choice)"),
+            }
+        }
+
+        // The choice through the entry point.
+        for (exponent, fixed_exponent) in [(ExponentChoice::Fixed65537, true), (ExponentChoice::Random, false)] {
+            let request = RsaRequest::Generate { bits: MODULUS_BIT_SIZE, options: RsaKeygenOptions { exponent } };
+            match rsa(request, None, &CancellationToken::new()).unwrap() {
+                RsaResult::KeyPair(key_pair) => assert_eq!(key_pair.public_key_e == ChonkerInt::from(65537), fixed_exponent),
+                _ => panic!("expected a key pair from the RSA key generation (test_rsa_key_pair_generation_exponent_choice)"),
+            }
+        }
+        let rsa_args = RsaArgs { key_exponent: Some(String::from("3")), ..RsaArgs::default() };
+        assert!(rsa_request_from_args(&Mode::Generate, rsa_args).is_err());
+    }
+
+    // Test RSA encryption and decryption with the block size derived from the modulus,
+    // with the moduli of 40, 80 and 200 digits and the messages of the lengths around the block size.
+    #[test]
+    fn test_rsa_encryption_and_decryption_block_size() {
+        let mut rng = StdRng::seed_from_u64(60);
+
+        for (bits, digit_count) in [(132u64, 40usize), (265, 80), (664, 200)] {
+            let key_pair = match rsa_key_generation_with_rng(bits, &mut rng, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+                RsaResult::KeyPair(key_pair) => key_pair,
+                _ => panic!("expected a key pair from the RSA key generation (test_rsa_encryption_and_decryption_block_size)"),
+            };
+            assert_eq!(key_pair.public_key_n.digit_count(), digit_count);
+
+            // The largest block of k bytes, 2^(8 * k) - 1, is below the modulus.
+            let block_size = rsa_block_size(&key_pair.public_key_n).unwrap();
+            assert_eq!(block_size as u64, (key_pair.public_key_n.bit_length() - 1) / 8);
+            assert!(ChonkerInt::from_bytes_be(&vec![0xFF; block_size]) < key_pair.public_key_n);
+
+            let block_message: String = (0..block_size).map(|index| (b'a' + (index % 26) as u8) as char).collect();
+            for target_string in [
+                String::new(),
+                String::from("x"),
+                block_message[..block_size - 1].to_string(),
+                block_message.clone(),
+                format!("{}!", block_message),
+                block_message.repeat(2),
+                format!("\0{}\0", &block_message[..block_size - 1]),
+            ] {
+                let encryption_result = rsa_encrypt(&target_string, &key_pair.public_key_e, &key_pair.public_key_n, &RsaPadding::Raw).unwrap();
+                let decryption_result = rsa_decrypt(&encryption_result, &key_pair.private_key_d, &key_pair.public_key_n, &RsaPadding::Raw).unwrap();
+                assert_eq!(target_string, decryption_result);
+            }
         }
 
-        let mut exponent = Some("ABCDE".to_string());
+        // The smallest modulus with the block of 1 byte, 17 * 19 = 323, and the modulus too small for a block.
+        let encryption_result = rsa_encrypt("Tiny blocks.", &ChonkerInt::from(5), &ChonkerInt::from(323), &RsaPadding::Raw).unwrap();
+        assert_eq!(rsa_decrypt(&encryption_result, &ChonkerInt::from(29), &ChonkerInt::from(323), &RsaPadding::Raw).unwrap(), "Tiny blocks.");
+        assert!(rsa_block_size(&ChonkerInt::from(221)).is_err());
+        assert!(rsa_encrypt("Too small.", &ChonkerInt::from(5), &ChonkerInt::from(221), &RsaPadding::Raw).is_err());
+    }
 
-        // Test for the incorrectness of the public exponent string during encryption.
-        match rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
-            Ok(_) => panic!("somehow generated a result, while the error for the incorrectness of the public exponent string during encryption was desired (test_rsa_incorrect_input_handling)"),
-            Err(e) => println!("Public exponent related error: {}", e),
+    // Test the framing of the RSA ciphertext: any byte values round trip, including the bytes 0x90 and 0xFF,
+    // the ciphertext consists of the fixed width fields, one per block of the padded message.
+    #[test]
+    fn test_rsa_encryption_and_decryption_framing() {
+        let key_pair = match rsa_key_generation_with_rng(MODULUS_BIT_SIZE, &mut StdRng::seed_from_u64(61), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("expected a key pair from the RSA key generation (test_rsa_encryption_and_decryption_framing)"),
+        };
+        let (key_e, key_d, key_n) = (&key_pair.public_key_e, &key_pair.private_key_d, &key_pair.public_key_n);
+        let block_size = rsa_padded_block_size(key_n).unwrap();
+        let field_width = rsa_field_width(key_n);
+
+        // All of the lengths from 0 to 3 blocks, the bytes cycle through the values 0x8E-0x91 and 0xFD-0xFF.
+        let byte_values = [0x90u8, 0xFF, 0x8E, 0xFE, 0x8F, 0x91, 0xFD, 0x00];
+        for message_len in 0..=3 * block_size {
+            let message: Vec<u8> = (0..message_len).map(|index| byte_values[index % byte_values.len()]).collect();
+
+            let ciphertext = rsa_encrypt_raw(&message, key_e, key_n).unwrap();
+            assert_eq!(ciphertext.len(), (message_len / block_size + 1) * field_width);
+            assert_eq!(rsa_decrypt_raw(&ciphertext, key_d, key_n, None, None).unwrap(), message);
         }
 
-        exponent = Some("45145151".to_string());
-        modulus = None;
-
-        // Test for the absence of the modulus string during encryption.
-        match rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
-            Ok(_) => panic!("somehow generated a result, while the error for the absence of the modulus string during encryption was desired (test_rsa_incorrect_input_handling)"),
-            Err(e) => println!("Modulus related error: {}", e),
+        // The messages, which end with the bytes looking like the padding.
+        for message in [vec![0x90u8; block_size], vec![0xFF; block_size - 1], vec![1], vec![2, 2]] {
+            let ciphertext = rsa_encrypt_raw(&message, key_e, key_n).unwrap();
+            assert_eq!(rsa_decrypt_raw(&ciphertext, key_d, key_n, None, None).unwrap(), message);
         }
 
-        modulus = Some("ABCDE".to_string());
+        // The string with the characters encoded with the byte 0x90, e.g. "ΐ" is 0xCE 0x90.
+        let target_string = "ΐ Ґ ΐΐ ѐ ꀐ at the block boundaries ΐ";
+        let encryption_result = rsa_encrypt(target_string, key_e, key_n, &RsaPadding::Raw).unwrap();
+        assert_eq!(rsa_decrypt(&encryption_result, key_d, key_n, &RsaPadding::Raw).unwrap(), target_string);
 
-        // Test for the incorrectness of the modulus string during encryption.
-        match rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
-            Ok(_) => panic!("somehow generated a result, while the error for the incorrectness of the modulus string during encryption was desired (test_rsa_incorrect_input_handling)"),
-            Err(e) => println!("Modulus related error: {}", e),
-        }
+        // The padded block is at most 255 bytes long for the long moduli, the field is the byte length of the modulus.
+        let long_modulus = &ChonkerInt::from(2).pow_u64(2100) + 1u64;
+        assert_eq!(rsa_block_size(&long_modulus).unwrap(), 262);
+        assert_eq!(rsa_padded_block_size(&long_modulus).unwrap(), 255);
+        assert_eq!(rsa_field_width(&long_modulus), 263);
+    }
 
-        modulus = Some("57885161".to_string());
+    // Test the rejection of the malformed RSA ciphertexts and padding.
+    #[test]
+    fn test_rsa_decryption_malformed_padding() {
+        // Primes: 1000000000000000003, 1000000000000000009.
+        let key_n = ChonkerInt::from(String::from("1000000000000000012000000000000000027"));
+        let key_e = ChonkerInt::from(65537);
+        let lambda_n = ChonkerInt::from(1000000000000000002u64).lcm(&ChonkerInt::from(1000000000000000008u64));
+        let key_d = key_e.modinv(&lambda_n).unwrap();
+        let block_size = rsa_padded_block_size(&key_n).unwrap();
+        let field_width = rsa_field_width(&key_n);
+
+        // Encrypt the raw blocks without the padding check.
+        let encrypt_raw_block = |block: &[u8]| {
+            let mut field = ChonkerInt::from_bytes_be(block).modpow(&key_e, &key_n).to_bytes_be();
+            let mut ciphertext = vec![0u8; field_width - field.len()];
+            ciphertext.append(&mut field);
+            ciphertext
+        };
 
-        // Test for the modulus being a composite number during encryption.
-        match rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
-            Ok(_) => panic!("somehow generated a result, while the error for the modulus being a composite number during encryption was desired (test_rsa_incorrect_input_handling)"),
-            Err(e) => println!("Modulus related error: {}", e),
+        let mut correct_block = vec![b'a'; block_size - 3];
+        correct_block.extend_from_slice(&[3, 3, 3]);
+        assert_eq!(rsa_decrypt_raw(&encrypt_raw_block(&correct_block), &key_d, &key_n, None, None).unwrap(), vec![b'a'; block_size - 3]);
+
+        // The zero padding length, the padding length bigger than the block, the padding bytes with another value.
+        let mut zero_padding = vec![b'a'; block_size];
+        zero_padding[block_size - 1] = 0;
+        let mut long_padding = vec![b'a'; block_size];
+        long_padding[block_size - 1] = block_size as u8 + 1;
+        let mut inconsistent_padding = correct_block.clone();
+        inconsistent_padding[block_size - 2] = 2;
+
+        for malformed_block in [zero_padding, long_padding, inconsistent_padding] {
+            let decryption_error = rsa_decrypt_raw(&encrypt_raw_block(&malformed_block), &key_d, &key_n, None, None).unwrap_err();
+            assert!(decryption_error.to_string().contains("padding"));
         }
 
-        modulus = Some("578851612".to_string());
+        // The empty ciphertext, the ciphertext of an incorrect length, the encrypted number not below the modulus.
+        let ciphertext = rsa_encrypt_raw(b"Correct message.", &key_e, &key_n).unwrap();
+        assert!(rsa_decrypt_raw(&[], &key_d, &key_n, None, None).is_err());
+        assert!(rsa_decrypt_raw(&ciphertext[1..], &key_d, &key_n, None, None).is_err());
+        assert!(rsa_decrypt_raw(&vec![0xFF; field_width], &key_d, &key_n, None, None).is_err());
+        assert!(rsa_decrypt("", &key_d, &key_n, &RsaPadding::Raw).is_err());
+
+        // The corrupted last block breaks the padding, the key of another modulus does not decrypt the message.
+        let mut corrupted_ciphertext = ciphertext.clone();
+        corrupted_ciphertext[ciphertext.len() - 1] ^= 1;
+        assert!(rsa_decrypt_raw(&corrupted_ciphertext, &key_d, &key_n, None, None).is_err());
+        assert_eq!(rsa_decrypt_raw(&ciphertext, &key_d, &key_n, None, None).unwrap(), b"Correct message.".to_vec());
+    }
 
-        // Test for the modulus being too short, having equal or less than 39 digits during encryption.
-        match rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
-            Ok(_) => panic!("somehow generated a result, while the error for the modulus being too short, having equal or less than 39 digits during encryption was desired (test_rsa_incorrect_input_handling)"),
-            Err(e) => println!("Modulus related error: {}", e),
+    // Test RSA encryption and decryption with the PKCS#1 v1.5 padding.
+    #[test]
+    fn test_rsa_encryption_and_decryption_pkcs1v15() {
+        // Primes: 1000000000000000003, 1000000000000000009.
+        let key_n = ChonkerInt::from(String::from("1000000000000000012000000000000000027"));
+        let key_e = ChonkerInt::from(65537);
+        let lambda_n = ChonkerInt::from(1000000000000000002u64).lcm(&ChonkerInt::from(1000000000000000008u64));
+        let key_d = key_e.modinv(&lambda_n).unwrap();
+        let field_width = rsa_field_width(&key_n);
+        let mut rng = StdRng::seed_from_u64(62);
+
+        // The empty message, the message of a single block, the message spread over several blocks.
+        for target in [&b""[..], b"a", b"Short.", b"A message longer than a single PKCS#1 v1.5 block."] {
+            let ciphertext = rsa_encrypt_pkcs1v15(target, &key_e, &key_n, &mut rng).unwrap();
+            assert_eq!(ciphertext.len(), field_width * target.len().max(1).div_ceil(field_width - 11));
+            assert_eq!(rsa_decrypt_pkcs1v15(&ciphertext, &key_d, &key_n, None, None).unwrap(), target.to_vec());
         }
 
-        thread_count = Some("65".to_string());
-
-        // Test for the thread count being too big, being over 64.
-        match rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
-            Ok(_) => panic!("somehow generated a result, while the error for the thread count being too big, being over 64 was desired (test_rsa_incorrect_input_handling)"),
-            Err(e) => println!("Thread count related error: {}", e),
+        // The randomly generated key pairs of several sizes restore the message.
+        for bits in [MODULUS_BIT_SIZE, 256, 512] {
+            let rsa_key_pair = match rsa_key_generation(bits, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+                RsaResult::KeyPair(key_pair) => key_pair,
+                _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_encryption_and_decryption_pkcs1v15)"),
+            };
+            let ciphertext = rsa_encrypt("String for the PKCS#1 v1.5 padding test.", &rsa_key_pair.public_key_e, &rsa_key_pair.public_key_n, &RsaPadding::Pkcs1v15).unwrap();
+            let decryption_result = rsa_decrypt(&ciphertext, &rsa_key_pair.private_key_d, &rsa_key_pair.public_key_n, &RsaPadding::Pkcs1v15).unwrap();
+            assert_eq!(decryption_result, "String for the PKCS#1 v1.5 padding test.");
         }
 
-        thread_count = Some("0".to_string());
+        // The padding is randomised, the same message is encrypted into different ciphertexts.
+        let first_ciphertext = rsa_encrypt("Same message.", &key_e, &key_n, &RsaPadding::Pkcs1v15).unwrap();
+        let second_ciphertext = rsa_encrypt("Same message.", &key_e, &key_n, &RsaPadding::Pkcs1v15).unwrap();
+        assert_ne!(first_ciphertext, second_ciphertext);
+        assert_eq!(rsa_decrypt(&first_ciphertext, &key_d, &key_n, &RsaPadding::Pkcs1v15).unwrap(), "Same message.");
+        assert_eq!(rsa_decrypt(&second_ciphertext, &key_d, &key_n, &RsaPadding::Pkcs1v15).unwrap(), "Same message.");
+
+        // The flipped bit of the ciphertext breaks the padding of the block.
+        let ciphertext = rsa_encrypt_pkcs1v15(b"Correct message.", &key_e, &key_n, &mut rng).unwrap();
+        let mut corrupted_ciphertext = ciphertext.clone();
+        corrupted_ciphertext[field_width - 1] ^= 1;
+        assert!(rsa_decrypt_pkcs1v15(&corrupted_ciphertext, &key_d, &key_n, None, None).unwrap_err().to_string().contains("padding"));
+
+        // The empty ciphertext, the ciphertext of an incorrect length, the ciphertext of the other padding.
+        assert!(rsa_decrypt_pkcs1v15(&[], &key_d, &key_n, None, None).is_err());
+        assert!(rsa_decrypt_pkcs1v15(&ciphertext[1..], &key_d, &key_n, None, None).is_err());
+        let raw_ciphertext = rsa_encrypt_raw(b"Correct message.", &key_e, &key_n).unwrap();
+        assert!(rsa_decrypt_pkcs1v15(&raw_ciphertext, &key_d, &key_n, None, None).is_err());
+
+        // The modulus shorter than 12 bytes does not fit the padding. Primes: 1000003, 1000033.
+        assert!(rsa_encrypt_pkcs1v15(b"a", &key_e, &ChonkerInt::from(1000036000099u64), &mut rng).is_err());
+
+        // The padding is chosen by its name, the paddings, which do not fit the modulus, are rejected.
+        let rsa_args = RsaArgs {
+            target: Some(String::from("Named padding.")),
+            key_exponent: Some(key_e.to_string()),
+            key_modulus: Some(key_n.to_string()),
+            padding: Some(String::from("pkcs1")),
+            ..RsaArgs::default()
+        };
+        let encryption_result = rsa(rsa_request_from_args(&Mode::Encode, rsa_args.clone()).unwrap(), None, &CancellationToken::new()).unwrap();
+        let ciphertext = match encryption_result {
+            RsaResult::StringResult(ciphertext) => ciphertext,
+            _ => panic!("produced another result instead of the encrypted string (test_rsa_encryption_and_decryption_pkcs1v15)"),
+        };
+        assert_eq!(rsa_decrypt(&ciphertext, &key_d, &key_n, &RsaPadding::Pkcs1v15).unwrap(), "Named padding.");
+        let rsa_args = RsaArgs { padding: Some(String::from("oaep")), ..rsa_args };
+        assert!(rsa(rsa_request_from_args(&Mode::Encode, rsa_args).unwrap(), None, &CancellationToken::new()).is_err());
+    }
 
-        // Test for the thread count being zero.
-        match rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()) {
-            Ok(_) => panic!("somehow generated a result, while the error for the thread count being zero was desired (test_rsa_incorrect_input_handling)"),
-            Err(e) => println!("Thread count related error: {}", e),
+    // Test RSA encryption and decryption with the OAEP padding.
+    #[test]
+    fn test_rsa_encryption_and_decryption_oaep() {
+        let params = OaepParams::default();
+        let labelled_params = OaepParams { hash: OaepHash::Sha256, label: b"label".to_vec() };
+
+        // The randomly generated key pairs of several sizes restore the empty, short and long messages.
+        for bits in [544, 768, 1024] {
+            let rsa_key_pair = match rsa_key_generation(bits, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+                RsaResult::KeyPair(key_pair) => key_pair,
+                _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_encryption_and_decryption_oaep)"),
+            };
+            let field_width = rsa_field_width(&rsa_key_pair.public_key_n);
+            let long_target = "A message longer than a single OAEP block of the smallest modulus.".repeat(3);
+
+            for target in ["", "Short.", &long_target] {
+                let ciphertext = rsa_encrypt(target, &rsa_key_pair.public_key_e, &rsa_key_pair.public_key_n, &RsaPadding::Oaep(params.clone())).unwrap();
+                assert_eq!(ciphertext.len() / 2 % field_width, 0);
+                let decryption_result = rsa_decrypt(&ciphertext, &rsa_key_pair.private_key_d, &rsa_key_pair.public_key_n, &RsaPadding::Oaep(params.clone())).unwrap();
+                assert_eq!(decryption_result, target);
+            }
+
+            // The label is bound to the ciphertext.
+            let ciphertext = rsa_encrypt("Labelled.", &rsa_key_pair.public_key_e, &rsa_key_pair.public_key_n, &RsaPadding::Oaep(labelled_params.clone())).unwrap();
+            assert_eq!(rsa_decrypt(&ciphertext, &rsa_key_pair.private_key_d, &rsa_key_pair.public_key_n, &RsaPadding::Oaep(labelled_params.clone())).unwrap(), "Labelled.");
+            assert!(rsa_decrypt(&ciphertext, &rsa_key_pair.private_key_d, &rsa_key_pair.public_key_n, &RsaPadding::Oaep(params.clone())).is_err());
+        }
+
+        // The tampered ciphertext, the truncated ciphertext and the ciphertext of another padding are rejected with the same error.
+        let rsa_key_pair = match rsa_key_generation(544, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_encryption_and_decryption_oaep)"),
+        };
+        let (key_e, key_d, key_n) = (&rsa_key_pair.public_key_e, &rsa_key_pair.private_key_d, &rsa_key_pair.public_key_n);
+        let mut rng = StdRng::seed_from_u64(63);
+        let ciphertext = rsa_encrypt_oaep(b"Correct message.", key_e, key_n, &params, &mut rng).unwrap();
+        let expected_error = rsa_decrypt_oaep(&[], key_d, key_n, None, None, &params).unwrap_err().to_string();
+
+        let mut tampered_ciphertext = ciphertext.clone();
+        tampered_ciphertext[ciphertext.len() - 1] ^= 1;
+        let pkcs1_ciphertext = rsa_encrypt_pkcs1v15(b"Correct message.", key_e, key_n, &mut rng).unwrap();
+        for incorrect_ciphertext in [&tampered_ciphertext[..], &ciphertext[1..], &pkcs1_ciphertext[..]] {
+            assert_eq!(rsa_decrypt_oaep(incorrect_ciphertext, key_d, key_n, None, None, &params).unwrap_err().to_string(), expected_error);
         }
+        assert_eq!(rsa_decrypt_oaep(&ciphertext, key_d, key_n, None, None, &params).unwrap(), b"Correct message.".to_vec());
+
+        // The modulus shorter than 2 * 32 + 3 bytes does not fit the padding.
+        assert!(rsa_encrypt("Too small.", key_e, &ChonkerInt::from(String::from("1000000000000000012000000000000000027")), &RsaPadding::Oaep(params.clone())).is_err());
+
+        // The padding is chosen by its name.
+        let rsa_args = RsaArgs {
+            target: Some(String::from("Named padding.")),
+            key_exponent: Some(key_e.to_string()),
+            key_modulus: Some(key_n.to_string()),
+            padding: Some(String::from("oaep")),
+            ..RsaArgs::default()
+        };
+        let encryption_result = rsa(rsa_request_from_args(&Mode::Encode, rsa_args).unwrap(), None, &CancellationToken::new()).unwrap();
+        let ciphertext = match encryption_result {
+            RsaResult::StringResult(ciphertext) => ciphertext,
+            _ => panic!("produced another result instead of the encrypted string (test_rsa_encryption_and_decryption_oaep)"),
+        };
+        assert_eq!(rsa_decrypt(&ciphertext, key_d, key_n, &RsaPadding::Oaep(params)).unwrap(), "Named padding.");
     }
 
-    // Test RSA handling of correct input data.
+    // Test the MGF1 masking and the OAEP encoding with a fixed seed against the known answers.
     #[test]
-    fn test_rsa_correct_input_handling() {
-        let mut mode = Mode::Generate;
-        let mut target_message: Option<String> = None;
-        let mut exponent = Some("45145151".to_string());
-        let mut modulus = Some("".to_string());
-        let mut thread_count = None;
-
-        // Test random RSA keypair generation with absent or incorrect data besides the mode..
-        let _keypair = rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+    fn test_rsa_oaep_known_answers() {
+        assert_eq!(
+            string_hex_encode(&mgf1(&OaepHash::Sha256, b"seed", 40)).unwrap(),
+            "336F28A022193939585A1B4EDC989F870917F3A5F6DDD16E4FB357084A6BDFC273A649427664D03B"
+        );
+        assert_eq!(mgf1(&OaepHash::Sha256, b"seed", 0), Vec::<u8>::new());
+
+        // Encoded messages of 80 bytes, the seed is the bytes from 0 to 31.
+        let seed: Vec<u8> = (0..32).collect();
+        let params = OaepParams::default();
+        let labelled_params = OaepParams { hash: OaepHash::Sha256, label: b"label".to_vec() };
+        let encoded_message = oaep_encode(b"Known answer.", 80, &params, &seed);
+        assert_eq!(
+            string_hex_encode(&encoded_message).unwrap(),
+            "009BA6E238C491139D2BDB70EA611FF55CDDEB4D8E911F014136D6BEBD2DFD03D39344C47FCA4AF717407EDA5BBC04E0A2927AC9D4FC20EA3F18C681D71E31C2D104A7DE6469A48D10EBB9A0170B8A3E"
+        );
+        assert_eq!(
+            string_hex_encode(&oaep_encode(b"Known answer.", 80, &labelled_params, &seed)).unwrap(),
+            "009A3929E6A5ED971458A236C164558CAEC95B72125C07B0D9EEE62E6943A3F66D6A3E80D5E7EA6B2CA1C65A9E87F2579DE2E13383BB6247C657899B59D6235F6604A7DE6469A48D10EBB9A0170B8A3E"
+        );
 
-        target_message = Some("Test string.".to_string());
-        exponent = None;
-        modulus = None;
+        // The decoding restores the message only with the same label and the leading zero byte.
+        assert_eq!(oaep_decode(&encoded_message, &params), Some(b"Known answer.".to_vec()));
+        assert_eq!(oaep_decode(&encoded_message, &labelled_params), None);
+        let mut nonzero_leading_byte = encoded_message.clone();
+        nonzero_leading_byte[0] = 1;
+        assert_eq!(oaep_decode(&nonzero_leading_byte, &params), None);
+    }
 
-        // Test random RSA keypair generation with absent or incorrect data besides the mode..
-        let _keypair = rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+    // Test the consistency check of the RSA key pairs.
+    #[test]
+    fn test_rsa_key_pair_validation() {
+        let key_pair = match rsa_key_generation_with_rng(256, &mut StdRng::seed_from_u64(67), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_key_pair_validation)"),
+        };
+        assert!(key_pair.validate().is_ok());
+
+        // Without the CRT components the key pair is checked with the round-trip of a random number.
+        let plain_key_pair = RsaKeyPair { crt: None, ..key_pair.clone() };
+        assert!(plain_key_pair.validate().is_ok());
+
+        // The private exponent off by one fails with and without the CRT components.
+        let incorrect_d = &key_pair.private_key_d + &ChonkerInt::from(1);
+        assert!(RsaKeyPair { private_key_d: incorrect_d.clone(), ..key_pair.clone() }.validate().is_err());
+        assert!(RsaKeyPair { private_key_d: incorrect_d, ..plain_key_pair.clone() }.validate().is_err());
+
+        // The even public exponent, the exponents out of range, the modulus of 1.
+        assert!(RsaKeyPair { public_key_e: ChonkerInt::from(65536), ..plain_key_pair.clone() }.validate().is_err());
+        assert!(RsaKeyPair { public_key_e: ChonkerInt::from(1), ..plain_key_pair.clone() }.validate().is_err());
+        assert!(RsaKeyPair { private_key_d: ChonkerInt::from(0), ..plain_key_pair.clone() }.validate().is_err());
+        assert!(RsaKeyPair { private_key_d: key_pair.public_key_n.clone(), ..plain_key_pair.clone() }.validate().is_err());
+        assert!(RsaKeyPair { public_key_n: ChonkerInt::from(1), ..plain_key_pair }.validate().is_err());
+
+        // The composite "prime" with the otherwise consistent values fails.
+        let composite_p = ChonkerInt::from(1009 * 1013);
+        let prime_q = ChonkerInt::from(1019);
+        let lambda_n = (&composite_p - &ChonkerInt::from(1)).lcm(&(&prime_q - &ChonkerInt::from(1)));
+        let key_e = ChonkerInt::from(5);
+        let key_d = key_e.modinv(&lambda_n).unwrap();
+        let composite_key_pair = RsaKeyPair {
+            public_key_n: &composite_p * &prime_q,
+            public_key_e: key_e,
+            private_key_d: key_d.clone(),
+            crt: RsaCrtComponents::new(&composite_p, &prime_q, &key_d),
+        };
+        let validation_error = composite_key_pair.validate().unwrap_err();
+        assert!(validation_error.to_string().contains("not primes"));
 
-        // A key pair that was produced separately by the rsa_key_generation() function.
-        // Randomly generated RSA public/private key modulus n: 441982524952231918609144409818894577105184461
-        // Randomly generated RSA public key exponent e: 6119931580888508280272762765
-        // Randomly generated RSA private key exponent d: 3257209244777795983999918284178604218550597
+        // The CRT components of another private exponent fail.
+        let mut mismatched_crt = key_pair.crt.clone().unwrap();
+        mismatched_crt.d_p = &mismatched_crt.d_p + &ChonkerInt::from(1);
+        assert!(RsaKeyPair { crt: Some(mismatched_crt), ..key_pair }.validate().is_err());
+    }
 
-        mode = Mode::Encode;
-        exponent = Some("6119931580888508280272762765".to_string());
-        modulus = Some("441982524952231918609144409818894577105184461".to_string());
+    // Test the construction of the RSA key pair from the known primes: the textbook example p = 61, q = 53, e = 17
+    // gives d = 413, the inverse of 17 modulo lambda(3233) = 780, instead of d = 2753 modulo phi(3233) = 3120.
+    #[test]
+    fn test_rsa_key_pair_from_primes() {
+        let key_pair = RsaKeyPair::from_primes(&ChonkerInt::from(61), &ChonkerInt::from(53), &ChonkerInt::from(17)).unwrap();
+        assert_eq!(key_pair.public_key_n, ChonkerInt::from(3233));
+        assert_eq!(key_pair.public_key_e, ChonkerInt::from(17));
+        assert_eq!(key_pair.private_key_d, ChonkerInt::from(413));
+        assert_eq!(ChonkerInt::from(2753).rem_euclid(&ChonkerInt::from(780)), key_pair.private_key_d);
+
+        // d_p = 413 mod 60, d_q = 413 mod 52, q_inv = 53^-1 mod 61.
+        let crt = key_pair.crt.clone().unwrap();
+        assert_eq!((crt.d_p, crt.d_q, crt.q_inv), (ChonkerInt::from(53), ChonkerInt::from(49), ChonkerInt::from(38)));
+
+        // The textbook message 65 is encrypted into 2790 and restored.
+        let ciphertext = ChonkerInt::from(65).checked_modpow(&key_pair.public_key_e, &key_pair.public_key_n).unwrap();
+        assert_eq!(ciphertext, ChonkerInt::from(2790));
+        assert_eq!(rsa_private_operation(&ciphertext, &key_pair.private_key_d, &key_pair.public_key_n, key_pair.crt.as_ref(), None).unwrap(), ChonkerInt::from(65));
+
+        // The composite, the non positive and the equal primes, and the exponents, which are not coprime to lambda(n), fail.
+        let prime_error = RsaKeyPair::from_primes(&ChonkerInt::from(62), &ChonkerInt::from(53), &ChonkerInt::from(17)).unwrap_err();
+        assert!(prime_error.to_string().contains("62 is not a prime"));
+        assert!(RsaKeyPair::from_primes(&ChonkerInt::from(61), &ChonkerInt::from(1), &ChonkerInt::from(17)).is_err());
+        assert!(RsaKeyPair::from_primes(&ChonkerInt::from(-61), &ChonkerInt::from(53), &ChonkerInt::from(17)).is_err());
+        let equal_error = RsaKeyPair::from_primes(&ChonkerInt::from(61), &ChonkerInt::from(61), &ChonkerInt::from(17)).unwrap_err();
+        assert!(equal_error.to_string().contains("distinct primes"));
+        for key_e in [1, 3, 13, 18] {
+            assert!(RsaKeyPair::from_primes(&ChonkerInt::from(61), &ChonkerInt::from(53), &ChonkerInt::from(key_e)).is_err());
+        }
 
-        // Test the target string encryption with correct parameters. Print out the encrypted string, it is also encoded in hexadecimal format.
-        let encryption_result = rsa(&mode, target_message.clone(), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+        // The request of the entry point produces the same key pair.
+        let request = RsaRequest::GenerateFromPrimes { prime_p: ChonkerInt::from(61), prime_q: ChonkerInt::from(53), public_key_e: ChonkerInt::from(17) };
+        assert_eq!(rsa(request, None, &CancellationToken::new()).unwrap(), RsaResult::KeyPair(key_pair));
+    }
 
-        let encrypted_string = match encryption_result {
-            RsaResult::KeyPair(_) => panic!("somehow generated a random RSA key pair, while the encrypted string was desired (test_rsa_correct_input_handling)"),
-            RsaResult::StringResult(encrypted_string) => {
-                println!("The encrypted string: {}", encrypted_string);
-                encrypted_string
-            }
-            RsaResult::BruteforceRSAResult(_) => panic!("somehow generated an RSA bruteforce result, while the encrypted string was desired (test_rsa_correct_input_handling)"),
+    // Test the fingerprints and the summaries of the RSA keys, they depend only on the public key.
+    #[test]
+    fn test_rsa_key_pair_fingerprint() {
+        let key_pair = RsaKeyPair::from_primes(&ChonkerInt::from(61), &ChonkerInt::from(53), &ChonkerInt::from(17)).unwrap();
+        let fingerprint = key_pair.fingerprint();
+        assert_eq!(fingerprint.len(), KEY_FINGERPRINT_LEN * 3 - 1);
+        assert!(fingerprint.split(':').all(|pair| pair.len() == 2 && pair.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_lowercase())));
+        assert_eq!(key_pair.summary(), format!("12 bit modulus, public exponent 17, fingerprint {}", fingerprint));
+
+        // The private part does not change the fingerprint, a different modulus or exponent does.
+        let mut other_key_pair = key_pair.clone();
+        other_key_pair.private_key_d = ChonkerInt::from(2753);
+        other_key_pair.crt = None;
+        assert_eq!(other_key_pair.fingerprint(), fingerprint);
+        other_key_pair.public_key_n = ChonkerInt::from(3232);
+        assert_ne!(other_key_pair.fingerprint(), fingerprint);
+        other_key_pair.public_key_n = ChonkerInt::from(3233);
+        other_key_pair.public_key_e = ChonkerInt::from(7);
+        assert_ne!(other_key_pair.fingerprint(), fingerprint);
+
+        // The bruteforce result of the same public key has the same summary.
+        let bruteforce_result = BruteforceResult {
+            prime_q: ChonkerInt::from(53),
+            prime_p: ChonkerInt::from(61),
+            public_key_n: ChonkerInt::from(3233),
+            public_key_e: ChonkerInt::from(17),
+            private_key_d: ChonkerInt::from(413),
+            crt: None,
         };
+        assert_eq!(bruteforce_result.summary(), key_pair.summary());
 
-        mode = Mode::Decode;
-        exponent = Some("3257209244777795983999918284178604218550597".to_string());
-
-        // Result of the encryption of the "Test string." with previous parameters.
-        // let encrypted_string = Some("0109020000060607080608020405030409090304010309000708090108070900050901080503010803");
+        let mut rng = StdRng::seed_from_u64(75);
+        match rsa_key_generation_with_rng(256, &mut rng, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => assert!(key_pair.summary().starts_with("256 bit modulus, public exponent ")),
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_key_pair_fingerprint)"),
+        }
+    }
 
-        // Test the target string decryption with correct parameters. Print out the decrypted string and comapre it with the original.
-        let decryption_result = rsa(&mode, Some(encrypted_string.clone()), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+    // Test RSA decryption with the Chinese remainder theorem, it matches the plain decryption.
+    #[test]
+    fn test_rsa_decryption_crt() {
+        let mut rng = StdRng::seed_from_u64(65);
 
-        let decrypted_string = match decryption_result {
-            RsaResult::KeyPair(_) => panic!("somehow generated a random RSA key pair, while the decrypted string was desired (test_rsa_correct_input_handling)"),
-            RsaResult::StringResult(decrypted_string) => {
-                println!("The decrypted string: {}", decrypted_string);
-                decrypted_string
+        for bits in [MODULUS_BIT_SIZE, 256, 544] {
+            let key_pair = match rsa_key_generation_with_rng(bits, &mut rng, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+                RsaResult::KeyPair(key_pair) => key_pair,
+                _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_decryption_crt)"),
+            };
+            let crt = key_pair.crt.clone().unwrap();
+            assert_eq!(&crt.prime_p * &crt.prime_q, key_pair.public_key_n);
+            assert_eq!((&crt.q_inv * &crt.prime_q).rem_euclid(&crt.prime_p), ChonkerInt::from(1));
+
+            // The private operation on random numbers below the modulus.
+            for _ in 0..8 {
+                let encrypted_block = ChonkerInt::new_rand_range_value_with_rng(&ChonkerInt::from(1), &key_pair.public_key_n, &BigIntSign::Positive, &mut rng);
+                assert_eq!(
+                    rsa_private_operation(&encrypted_block, &key_pair.private_key_d, &key_pair.public_key_n, Some(&crt), None).unwrap(),
+                    encrypted_block.modpow(&key_pair.private_key_d, &key_pair.public_key_n)
+                );
             }
-            RsaResult::BruteforceRSAResult(_) => panic!("somehow generated an RSA bruteforce result, while the encrypted string was desired (test_rsa_correct_input_handling)"),
-        };
 
-        // Check the original string with the decrypted string.
-        assert_eq!(target_message.unwrap(), decrypted_string);
+            // The messages with every padding, the key pair without the CRT components falls back to the plain decryption.
+            let plain_key_pair = RsaKeyPair { crt: None, ..key_pair.clone() };
+            let paddings = if bits == 544 { vec![RsaPadding::Raw, RsaPadding::Pkcs1v15, RsaPadding::Oaep(OaepParams::default())] } else { vec![RsaPadding::Raw, RsaPadding::Pkcs1v15] };
+            for padding in paddings {
+                for target in ["", "Message for the CRT decryption test.", "Tiny."] {
+                    let ciphertext = rsa_encrypt(target, &key_pair.public_key_e, &key_pair.public_key_n, &padding).unwrap();
+                    assert_eq!(rsa_decrypt_crt(&ciphertext, &key_pair, &padding).unwrap(), target);
+                    assert_eq!(rsa_decrypt_crt(&ciphertext, &plain_key_pair, &padding).unwrap(), target);
+                    assert_eq!(rsa_decrypt(&ciphertext, &plain_key_pair.private_key_d, &plain_key_pair.public_key_n, &padding).unwrap(), target);
+                }
+            }
+        }
 
-        // Test requests for bruteforcing.
-        mode = Mode::Bruteforce;
-        exponent = Some("85".to_string());
-        modulus = Some("268970693".to_string());
-        thread_count = None; // Rely on default 8 threads/workers.
+        // The bruteforce result carries the CRT components of the found primes.
+        let bruteforce_result = bruteforce_result_from_primes(ChonkerInt::from(1009), ChonkerInt::from(1013), &ChonkerInt::from(5), &ChonkerInt::from(1022117)).unwrap();
+        let crt = bruteforce_result.crt.unwrap();
+        assert_eq!(crt.d_p, bruteforce_result.private_key_d.rem_euclid(&ChonkerInt::from(1008)));
+        assert_eq!(crt.d_q, bruteforce_result.private_key_d.rem_euclid(&ChonkerInt::from(1012)));
+        assert_eq!(crt.q_inv, ChonkerInt::from(1013).modinv(&ChonkerInt::from(1009)).unwrap());
+    }
 
-        let target_modulus = ChonkerInt::from(268970693);
-        let target_public_exponent = ChonkerInt::from(85);
-        let private_key_comparison = ChonkerInt::from(88590349);
+    // Test the blinded decryption, it matches the unblinded decryption, and the blinding factor is new for every operation.
+    #[test]
+    fn test_rsa_decryption_blinding() {
+        let mut rng = StdRng::seed_from_u64(76);
 
-        let bruteforce_result = rsa(&mode, Some(encrypted_string.clone()), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+        for bits in [MODULUS_BIT_SIZE, 256, 544] {
+            let key_pair = match rsa_key_generation_with_rng(bits, &mut rng, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+                RsaResult::KeyPair(key_pair) => key_pair,
+                _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_decryption_blinding)"),
+            };
+            let (key_e, key_d, key_n) = (&key_pair.public_key_e, &key_pair.private_key_d, &key_pair.public_key_n);
+
+            // The blinded private operation on random numbers below the modulus, with and without the CRT components.
+            for _ in 0..8 {
+                let encrypted_block = ChonkerInt::new_rand_range_value_with_rng(&ChonkerInt::from(1), key_n, &BigIntSign::Positive, &mut rng);
+                let expected_block = rsa_private_operation(&encrypted_block, key_d, key_n, None, None).unwrap();
+                for crt in [None, key_pair.crt.as_ref()] {
+                    assert_eq!(rsa_blinded_private_operation(&encrypted_block, key_d, key_n, crt, key_e, &mut rng).unwrap(), expected_block);
+                    assert_eq!(rsa_private_operation(&encrypted_block, key_d, key_n, crt, Some(key_e)).unwrap(), expected_block);
+                }
+            }
 
-        let rsa_package = match bruteforce_result {
-            RsaResult::BruteforceRSAResult(rsa_result) => rsa_result,
-            _ => panic!(
-                "error in the algorithm, did not compute a bruteforce result (test_rsa_bruteforce)"
-            ),
-        };
+            // The messages with every padding are restored by the blinded decryption.
+            let paddings = if bits == 544 { vec![RsaPadding::Raw, RsaPadding::Pkcs1v15, RsaPadding::Oaep(OaepParams::default())] } else { vec![RsaPadding::Raw, RsaPadding::Pkcs1v15] };
+            for padding in paddings {
+                let ciphertext = rsa_encrypt_bytes(b"Message for the blinding test.", key_e, key_n, &padding).unwrap();
+                let expected_bytes = rsa_decrypt_bytes(&ciphertext, key_d, key_n, None, None, &padding).unwrap();
+                assert_eq!(rsa_decrypt_bytes(&ciphertext, key_d, key_n, key_pair.crt.as_ref(), Some(key_e), &padding).unwrap(), expected_bytes);
+                assert_eq!(rsa_decrypt_bytes(&ciphertext, key_d, key_n, None, Some(key_e), &padding).unwrap(), expected_bytes);
+            }
 
-        println!("Bruteforce result package: {:?}", rsa_package);
-        println!("Bruteforce prime p: {}", rsa_package.prime_p);
-        println!("Bruteforce prime q: {}", rsa_package.prime_q);
+            // The public exponent of the blinding is derived from the primes of the private key.
+            let private = RsaPrivateKey { public_key_n: key_n.clone(), private_key_d: key_d.clone(), crt: key_pair.crt.clone() };
+            assert_eq!(private.public_exponent(), Some(key_e.clone()));
+            let ciphertext = rsa_encrypt("Message for the blinding test.", key_e, key_n, &RsaPadding::Raw).unwrap();
+            let request = RsaRequest::Decrypt { ciphertext: ciphertext.clone(), private, padding: RsaPadding::Raw, blinding: true, encoding: None };
+            assert_eq!(rsa(request, None, &CancellationToken::new()).unwrap(), RsaResult::StringResult(String::from("Message for the blinding test.")));
+
+            // Without the primes the blinding is rejected.
+            let private = RsaPrivateKey { public_key_n: key_n.clone(), private_key_d: key_d.clone(), crt: None };
+            assert_eq!(private.public_exponent(), None);
+            let request = RsaRequest::Decrypt { ciphertext, private, padding: RsaPadding::Raw, blinding: true, encoding: None };
+            assert!(rsa(request, None, &CancellationToken::new()).unwrap_err().to_string().contains("blinding"));
+        }
 
-        assert_eq!(rsa_package.public_key_n, target_modulus);
-        assert_eq!(rsa_package.public_key_e, target_public_exponent);
-        assert_eq!(rsa_package.private_key_d, private_key_comparison);
+        // The same seeded generator produces a new blinding factor for every call, the factors are coprime to the modulus.
+        let key_n = ChonkerInt::from(String::from("503389953040597954843496152539898795547523683"));
+        let mut blinding_rng = StdRng::seed_from_u64(77);
+        let mut blinding_factors: Vec<ChonkerInt> = Vec::new();
+        for _ in 0..16 {
+            let (blinding_factor, blinding_inverse) = rsa_blinding_factor(&key_n, &mut blinding_rng).unwrap();
+            assert!(blinding_factor > ChonkerInt::from(1) && blinding_factor < key_n);
+            assert_eq!((&blinding_factor * &blinding_inverse).rem_euclid(&key_n), ChonkerInt::from(1));
+            assert!(!blinding_factors.contains(&blinding_factor));
+            blinding_factors.push(blinding_factor);
+        }
 
-        // Repeat the bruteforcing request with a custom amount of threads.
-        thread_count = Some("24".to_string()); // Rely on default 8 threads/workers.
-        let bruteforce_result = rsa(&mode, Some(encrypted_string.clone()), exponent.clone(), modulus.clone(), thread_count.clone()).unwrap();
+        // The generator of the same seed repeats the factors.
+        let mut repeated_rng = StdRng::seed_from_u64(77);
+        assert_eq!(rsa_blinding_factor(&key_n, &mut repeated_rng).unwrap().0, blinding_factors[0]);
+        assert!(rsa_blinding_factor(&ChonkerInt::from(3), &mut repeated_rng).is_err());
+    }
 
-        let rsa_package = match bruteforce_result {
-            RsaResult::BruteforceRSAResult(rsa_result) => rsa_result,
-            _ => panic!(
-                "error in the algorithm, did not compute a bruteforce result (test_rsa_bruteforce)"
-            ),
+    // Test the decryption with the Chinese remainder theorem is faster than the plain decryption for a 1024 bit key.
+    // The wall-clock comparison is unreliable on a loaded machine, run with "cargo test --release -- --ignored".
+    #[test]
+    #[ignore = "timing comparison, unreliable on a loaded machine"]
+    fn test_rsa_decryption_crt_timing() {
+        let mut rng = StdRng::seed_from_u64(1024);
+        let key_pair = match rsa_key_generation_with_rng(1024, &mut rng, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_decryption_crt_timing)"),
         };
+        let ciphertext = rsa_encrypt_raw(&[b'a'; 400], &key_pair.public_key_e, &key_pair.public_key_n).unwrap();
 
-        println!("Bruteforce result package: {:?}", rsa_package);
-        println!("Bruteforce prime p: {}", rsa_package.prime_p);
-        println!("Bruteforce prime q: {}", rsa_package.prime_q);
+        let plain_start = Instant::now();
+        let plain_result = rsa_decrypt_raw(&ciphertext, &key_pair.private_key_d, &key_pair.public_key_n, None, None).unwrap();
+        let plain_elapsed = plain_start.elapsed();
 
-        // let private_key_comparison = ChonkerInt::from(2485);
-        let private_key_comparison = ChonkerInt::from(88590349);
+        let crt_start = Instant::now();
+        let crt_result = rsa_decrypt_raw(&ciphertext, &key_pair.private_key_d, &key_pair.public_key_n, key_pair.crt.as_ref(), None).unwrap();
+        let crt_elapsed = crt_start.elapsed();
 
-        assert_eq!(rsa_package.public_key_n, target_modulus);
-        assert_eq!(rsa_package.public_key_e, target_public_exponent);
-        assert_eq!(rsa_package.private_key_d, private_key_comparison);
+        println!("Plain decryption: {:?}, CRT decryption: {:?}", plain_elapsed, crt_elapsed);
+        assert_eq!(plain_result, crt_result);
+        assert!(crt_elapsed < plain_elapsed);
     }
 
-    // Test RSA random key pair generation.
+    // Test RSA encryption and decryption with the key files in place of the exponent and the modulus.
     #[test]
-    fn test_rsa_key_pair_random_generation() {
-        let rsa_generation_result = rsa_key_generation().unwrap();
+    fn test_rsa_encryption_and_decryption_key_files() {
+        let key_dir = std::env::temp_dir().join(format!("enc_rsa_key_files_{}", std::process::id()));
+        std::fs::create_dir_all(&key_dir).unwrap();
+        let key_pair = match rsa_key_generation_with_rng(256, &mut StdRng::seed_from_u64(66), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_encryption_and_decryption_key_files)"),
+        };
+        key_pair.save_to_files(&key_dir).unwrap();
+        let public_key_file = Some(key_dir.join("rsa_public.key").display().to_string());
+        let private_key_file = Some(key_dir.join("rsa_private.key").display().to_string());
+
+        let target_string = "String for the RSA key files test.";
+        let encryption_args = RsaArgs {
+            target: Some(target_string.to_string()),
+            padding: Some(String::from("pkcs1")),
+            key_file: public_key_file.clone(),
+            ..RsaArgs::default()
+        };
+        let ciphertext = match rsa(rsa_request_from_args(&Mode::Encode, encryption_args.clone()).unwrap(), None, &CancellationToken::new()).unwrap() {
+            RsaResult::StringResult(ciphertext) => ciphertext,
+            _ => panic!("produced another result instead of the encrypted string (test_rsa_encryption_and_decryption_key_files)"),
+        };
 
-        match rsa_generation_result {
-            RsaResult::KeyPair(key_pair) => {
-                println!("Randomly generated RSA public/private key modulus n: {}", key_pair.public_key_n);
-                println!("Randomly generated RSA public key exponent e: {}", key_pair.public_key_e);
-                println!("Randomly generated RSA private key exponent d: {}", key_pair.private_key_d);
-                assert_eq!(*key_pair.private_key_d.get_sign(), BigIntSign::Positive);
-            }
-            RsaResult::StringResult(_) => panic!("produced a string from encryption/decryption instead of a randomly generated key pair (test_rsa_key_pair_random_generation)"),
-            RsaResult::BruteforceRSAResult(_) => panic!("somehow generated an RSA bruteforce result, while the encrypted string was desired (test_rsa_correct_input_handling)"),
-        }
+        // The private key file brings the CRT components into the request.
+        let decryption_args = RsaArgs { target: Some(ciphertext), key_file: private_key_file, ..encryption_args.clone() };
+        let decryption_request = rsa_request_from_args(&Mode::Decode, decryption_args.clone()).unwrap();
+        assert!(matches!(&decryption_request, RsaRequest::Decrypt { private, .. } if private.crt.is_some()));
+        let decryption_result = rsa(decryption_request, None, &CancellationToken::new()).unwrap();
+        assert_eq!(decryption_result, RsaResult::StringResult(target_string.to_string()));
+
+        // The public key file is not accepted for the decryption, the missing file is reported.
+        assert!(rsa_request_from_args(&Mode::Decode, RsaArgs { key_file: public_key_file, ..decryption_args }).is_err());
+        assert!(rsa_request_from_args(&Mode::Encode, RsaArgs { key_file: Some(key_dir.join("missing.key").display().to_string()), ..encryption_args.clone() }).is_err());
+
+        // The armored key files are reloaded, the armored ciphertext is decrypted with the detected encoding.
+        key_pair.save_to_armored_files(&key_dir).unwrap();
+        let armored_encryption_args = RsaArgs { armor: true, ..encryption_args };
+        let ciphertext = match rsa(rsa_request_from_args(&Mode::Encode, armored_encryption_args.clone()).unwrap(), None, &CancellationToken::new()).unwrap() {
+            RsaResult::StringResult(ciphertext) => ciphertext,
+            _ => panic!("produced another result instead of the encrypted string (test_rsa_encryption_and_decryption_key_files)"),
+        };
+        assert!(ciphertext.starts_with("-----BEGIN CIPHERTEXT-----\n"), "{}", ciphertext);
+        let decryption_args = RsaArgs { target: Some(ciphertext), key_file: Some(key_dir.join("rsa_private.key").display().to_string()), ..RsaArgs::default() };
+        let decryption_result = rsa(rsa_request_from_args(&Mode::Decode, RsaArgs { padding: Some(String::from("pkcs1")), ..decryption_args }).unwrap(), None, &CancellationToken::new()).unwrap();
+        assert_eq!(decryption_result, RsaResult::StringResult(target_string.to_string()));
+
+        std::fs::remove_dir_all(&key_dir).unwrap();
     }
 
     // Test RSA encryption and decryption of the target data string.
     #[test]
     fn test_rsa_encryption_and_decryption() {
         let target_string = "String for RSA encryption and decryption test.";
-        let rsa_generation_result = rsa_key_generation().unwrap();
+        let rsa_generation_result = rsa_key_generation(MODULUS_BIT_SIZE, &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap();
 
         let rsa_key_pair = match rsa_generation_result {
             RsaResult::KeyPair(key_pair) => {
                 key_pair
             }
-            RsaResult::StringResult(_) => panic!("produced a string from encryption/decryption instead of a randomly generated key pair (test_rsa_encryption_and_decryption)"),
+            RsaResult::StringResult(_) | RsaResult::BytesResult(_) | RsaResult::FileResult { .. } => panic!("produced a result of encryption/decryption instead of a randomly generated key pair (test_rsa_encryption_and_decryption)"),
             RsaResult::BruteforceRSAResult(_) => panic!("somehow generated an RSA bruteforce result, while the encrypted string was desired (test_rsa_correct_input_handling)"),
         };
 
@@ -779,6 +2984,7 @@ mod tests {
             target_string,
             &rsa_key_pair.public_key_e,
             &rsa_key_pair.public_key_n,
+            &RsaPadding::Raw,
         )
             .unwrap();
 
@@ -786,6 +2992,7 @@ mod tests {
             &encryption_result,
             &rsa_key_pair.private_key_d,
             &rsa_key_pair.public_key_n,
+            &RsaPadding::Raw,
         )
             .unwrap();
 
@@ -797,6 +3004,46 @@ mod tests {
         println!("RSA decryption result: {}", decryption_result);
 
         assert_eq!(target_string, decryption_result);
+
+        // Decryption with an incorrect exponent produces numbers that do not fit into the blocks.
+        let incorrect_decryption_result = rsa_decrypt(
+            &encryption_result,
+            &rsa_key_pair.public_key_e,
+            &rsa_key_pair.public_key_n,
+            &RsaPadding::Raw,
+        );
+
+        assert!(incorrect_decryption_result.is_err());
+    }
+
+    // Test RSA encryption and decryption with the private exponent derived modulo lambda(n) = lcm(p-1, q-1).
+    #[test]
+    fn test_rsa_encryption_and_decryption_with_carmichael_totient() {
+        let target_string = "String for RSA encryption and decryption with lambda(n).";
+        let big_one = ChonkerInt::from(1);
+        let public_key_e = ChonkerInt::from(65537);
+
+        // Generate primes, for which the public exponent is a coprime to lambda(n).
+        let (modulus_n, lambda_n, phi_n) = loop {
+            let prime_q = ChonkerInt::new_prime(&25);
+            let prime_p = ChonkerInt::new_prime(&21);
+            let lambda_n = (&prime_p - &big_one).lcm(&(&prime_q - &big_one));
+
+            if prime_q != prime_p && public_key_e.is_coprime(&lambda_n) {
+                let phi_n = &(&prime_p - &big_one) * &(&prime_q - &big_one);
+                break (&prime_q * &prime_p, lambda_n, phi_n);
+            }
+        };
+
+        // Lambda(n) divides phi(n), the derived private exponent is not bigger than the one derived from phi(n).
+        assert!((&phi_n % &lambda_n).is_zero());
+        let private_key_d = public_key_e.modinv(&lambda_n).unwrap();
+        assert!(private_key_d <= public_key_e.modinv(&phi_n).unwrap());
+
+        let encryption_result = rsa_encrypt(target_string, &public_key_e, &modulus_n, &RsaPadding::Raw).unwrap();
+        let decryption_result = rsa_decrypt(&encryption_result, &private_key_d, &modulus_n, &RsaPadding::Raw).unwrap();
+
+        assert_eq!(target_string, decryption_result);
     }
 
     // Test RSA brute force.
@@ -835,11 +3082,11 @@ mod tests {
         // let target_public_exponent = ChonkerInt::from(48517897146637569u128);
         let thread_count = None; // Rely on default 8 threads/workers.
 
-        let bruteforce_result = rsa_bruteforce(&target_public_exponent, &target_modulus, thread_count).unwrap();
+        let bruteforce_result = rsa_bruteforce(&target_public_exponent, &target_modulus, thread_count, &BruteforceStrategy::TrialDivision, None, &CancellationToken::new()).unwrap();
 
         // Retest with a custom amount of workers/threads.
         let thread_count = Some(32);
-        let bruteforce_result = rsa_bruteforce(&target_public_exponent, &target_modulus, thread_count).unwrap();
+        let bruteforce_result = rsa_bruteforce(&target_public_exponent, &target_modulus, thread_count, &BruteforceStrategy::TrialDivision, None, &CancellationToken::new()).unwrap();
 
         let rsa_package = match bruteforce_result {
             RsaResult::BruteforceRSAResult(rsa_result) => rsa_result,
@@ -860,4 +3107,267 @@ mod tests {
         assert_eq!(rsa_package.public_key_e, target_public_exponent);
         assert_eq!(rsa_package.private_key_d, private_key_comparison);
     }
+
+    // Test RSA brute force with a single thread, which covers the whole range of the candidates.
+    #[test]
+    fn test_rsa_bruteforce_single_thread() {
+        let ceiling_limit = ChonkerInt::from(99);
+        assert_eq!(bruteforce_starting_points(&ceiling_limit, 1), vec![ChonkerInt::from(3)]);
+
+        let bruteforce_result = rsa_bruteforce(&ChonkerInt::from(3589), &ChonkerInt::from(30221), Some(1), &BruteforceStrategy::TrialDivision, None, &CancellationToken::new()).unwrap();
+        let rsa_package = match bruteforce_result {
+            RsaResult::BruteforceRSAResult(rsa_result) => rsa_result,
+            _ => panic!("error in the algorithm, did not compute a bruteforce result (test_rsa_bruteforce_single_thread)"),
+        };
+
+        assert_eq!(rsa_package.prime_p, ChonkerInt::from(47));
+        assert_eq!(rsa_package.prime_q, ChonkerInt::from(643));
+        assert_eq!(rsa_package.private_key_d, ChonkerInt::from(2485));
+    }
+
+    // Test that the RSA brute force stops and joins all of the workers after the first result,
+    // the function returns only after the thread pool is shut down.
+    #[test]
+    fn test_rsa_bruteforce_workers_exit() {
+        let (main_sender, main_receiver) = std::sync::mpsc::channel();
+
+        std::thread::spawn(move || {
+            let bruteforce_result = rsa_bruteforce(&ChonkerInt::from(13807417), &ChonkerInt::from(1847419907), Some(8), &BruteforceStrategy::TrialDivision, None, &CancellationToken::new());
+            main_sender.send(bruteforce_result.is_ok()).unwrap();
+        });
+
+        assert_eq!(main_receiver.recv_timeout(std::time::Duration::from_secs(60)), Ok(true));
+    }
+
+    // Test the progress reports of the RSA brute force, the callback is called during the bruteforce,
+    // but not after the function returns.
+    #[test]
+    fn test_rsa_bruteforce_progress_callback() {
+        let progress_reports = Arc::new(AtomicUsize::new(0));
+        let callback_reports = Arc::clone(&progress_reports);
+        let progress_callback = Box::new(move |bruteforce_progress: BruteforceProgress| {
+            assert!(bruteforce_progress.worker < 2);
+            assert!(bruteforce_progress.candidates_tested > 0);
+            assert!(bruteforce_progress.current_candidate.is_odd());
+            callback_reports.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // The first worker tests 9721 candidates before it finds the factor 19441.
+        let bruteforce_result = rsa_bruteforce(&ChonkerInt::from(13807417), &ChonkerInt::from(1847419907), Some(2), &BruteforceStrategy::TrialDivision, Some(progress_callback), &CancellationToken::new()).unwrap();
+        assert!(matches!(bruteforce_result, RsaResult::BruteforceRSAResult(_)));
+
+        let reports_after_completion = progress_reports.load(Ordering::SeqCst);
+        assert!(reports_after_completion >= 1);
+
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert_eq!(progress_reports.load(Ordering::SeqCst), reports_after_completion);
+    }
+
+    // Test the cancellation of the RSA brute force of a modulus, which takes too long to factor:
+    // the bruteforce stops promptly after the token is cancelled or its deadline has passed.
+    #[test]
+    fn test_rsa_bruteforce_cancelled() {
+        // 14 digit modulus, primes: 1000003, 9999991.
+        let target_modulus = ChonkerInt::from(10000020999973u64);
+        let target_public_exponent = ChonkerInt::from(65537);
+
+        // Cancel the bruteforce from another thread after a second.
+        let cancellation = CancellationToken::with_timeout(Duration::from_secs(3600));
+        let cancelling_token = cancellation.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_secs(1));
+            cancelling_token.cancel();
+        });
+
+        let bruteforce_start = Instant::now();
+        let bruteforce_error = rsa_bruteforce(&target_public_exponent, &target_modulus, None, &BruteforceStrategy::TrialDivision, None, &cancellation).unwrap_err();
+        assert!(bruteforce_error.downcast_ref::<OperationError>().unwrap().get_cancelled_flag());
+        assert!(bruteforce_start.elapsed() < Duration::from_secs(2));
+
+        // The timeout of a second through the entry point, the progress reports do not delay the cancellation.
+        let bruteforce_start = Instant::now();
+        let request = RsaRequest::Bruteforce {
+            public: RsaPublicKey { public_key_n: target_modulus.clone(), public_key_e: target_public_exponent.clone() },
+            threads: None,
+            strategy: BruteforceStrategy::TrialDivision,
+        };
+        let bruteforce_error = rsa(request, Some(Box::new(|_| {})), &CancellationToken::with_timeout(Duration::from_secs(1))).unwrap_err();
+        assert!(bruteforce_error.downcast_ref::<OperationError>().unwrap().get_cancelled_flag());
+        assert!(bruteforce_start.elapsed() < Duration::from_secs(2));
+
+        // Without a deadline, the modulus is too long for the bruteforce.
+        let bruteforce_error = rsa_bruteforce(&target_public_exponent, &target_modulus, None, &BruteforceStrategy::TrialDivision, None, &CancellationToken::new()).unwrap_err();
+        assert!(!bruteforce_error.downcast_ref::<OperationError>().unwrap().get_cancelled_flag());
+    }
+
+    // Test the partition of the range of the candidates among the threads.
+    #[test]
+    fn test_rsa_bruteforce_starting_points() {
+        // The range 3 - 99999 is split into three parts of 33332 candidates.
+        let ceiling_limit = ChonkerInt::from(99999);
+        let starting_points = bruteforce_starting_points(&ceiling_limit, 3);
+        assert_eq!(starting_points, vec![ChonkerInt::from(3), ChonkerInt::from(33335), ChonkerInt::from(66667)]);
+
+        // The parts are consecutive: the starting points are odd and increasing, the gaps between them are equal
+        // up to the adjustment to the odd number, and the last part reaches the ceiling.
+        for thread_count in [1usize, 2, 3, 7, 8, 24, 64] {
+            let starting_points = bruteforce_starting_points(&ceiling_limit, thread_count);
+            let increment = (99999 - 3) / thread_count as u64;
+            assert_eq!(starting_points.len(), thread_count);
+            assert_eq!(starting_points[0], ChonkerInt::from(3));
+
+            for (thread, starting_point) in starting_points.iter().enumerate() {
+                let part_start = 3 + increment * thread as u64;
+                assert!(starting_point.is_odd());
+                assert!(*starting_point == part_start || *starting_point == part_start + 1);
+            }
+
+            let last_part_end = 3 + increment * thread_count as u64;
+            assert!(last_part_end <= 99999 && 99999 - last_part_end < thread_count as u64);
+        }
+
+        // Fewer candidates than threads.
+        assert_eq!(bruteforce_starting_points(&ChonkerInt::from(9), 8), vec![ChonkerInt::from(3)]);
+        assert_eq!(bruteforce_starting_points(&ChonkerInt::from(3), 8), vec![ChonkerInt::from(3)]);
+    }
+
+    // Test RSA brute force with the Fermat's factorization of a long modulus with close primes.
+    #[test]
+    fn test_rsa_fermat_bruteforce() {
+        // Two 40 digit primes, which differ by 3294, the modulus is too long for the trial division.
+        let prime_p = ChonkerInt::from(String::from("1000000000000000000000000000000000000037"));
+        let prime_q = ChonkerInt::from(String::from("1000000000000000000000000000000000003331"));
+        let target_modulus = &prime_p * &prime_q;
+        let target_public_exponent = ChonkerInt::from(65537);
+
+        let request = RsaRequest::Bruteforce {
+            public: RsaPublicKey { public_key_n: target_modulus.clone(), public_key_e: target_public_exponent.clone() },
+            threads: None,
+            strategy: BruteforceStrategy::Fermat,
+        };
+        let bruteforce_result = rsa(request, None, &CancellationToken::new()).unwrap();
+        let rsa_package = match bruteforce_result {
+            RsaResult::BruteforceRSAResult(rsa_result) => rsa_result,
+            _ => panic!("error in the algorithm, did not compute a bruteforce result (test_rsa_fermat_bruteforce)"),
+        };
+
+        assert_eq!(rsa_package.prime_p, prime_p);
+        assert_eq!(rsa_package.prime_q, prime_q);
+
+        // The recovered private exponent decrypts the messages encrypted with the public one.
+        let target_string = "Recovered with the Fermat's factorization.";
+        let encryption_result = rsa_encrypt(target_string, &target_public_exponent, &target_modulus, &RsaPadding::Raw).unwrap();
+        let decryption_result = rsa_decrypt(&encryption_result, &rsa_package.private_key_d, &target_modulus, &RsaPadding::Raw).unwrap();
+        assert_eq!(decryption_result, target_string);
+
+        // The trial division refuses the long modulus, and the Fermat's factorization gives up on well separated primes.
+        assert!(rsa_bruteforce(&target_public_exponent, &target_modulus, None, &BruteforceStrategy::TrialDivision, None, &CancellationToken::new()).is_err());
+        let separated_modulus = &prime_p * &ChonkerInt::from(String::from("170141183460469231731687303715884105727"));
+        assert!(rsa_bruteforce(&target_public_exponent, &separated_modulus, None, &BruteforceStrategy::Fermat, None, &CancellationToken::new()).is_err());
+
+        // Incorrect strategy.
+        let rsa_args = RsaArgs {
+            key_exponent: Some(String::from("85")),
+            key_modulus: Some(String::from("268970693")),
+            strategy: Some(String::from("quadratic_sieve")),
+            ..RsaArgs::default()
+        };
+        assert!(rsa_request_from_args(&Mode::Bruteforce, rsa_args).is_err());
+    }
+
+    // Test the recovery of the message from its two ciphertexts with the same modulus and the coprime public exponents,
+    // one of the Bézout coefficients is always negative, the exponents in both orders check both inversions.
+    #[test]
+    fn test_rsa_common_modulus_attack() {
+        let key_pair = match rsa_key_generation_with_rng(256, &mut StdRng::seed_from_u64(74), &RsaKeygenOptions::default(), &CancellationToken::new()).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_common_modulus_attack)"),
+        };
+        let key_n = &key_pair.public_key_n;
+        let (first_exponent, second_exponent) = (ChonkerInt::from(3), ChonkerInt::from(5));
+
+        // The message of several blocks.
+        let message = "The same message is encrypted twice with the same modulus.".as_bytes();
+        let first_ciphertext = rsa_encrypt_bytes(message, &first_exponent, key_n, &RsaPadding::Raw).unwrap();
+        let second_ciphertext = rsa_encrypt_bytes(message, &second_exponent, key_n, &RsaPadding::Raw).unwrap();
+        assert!(first_ciphertext.len() >= 2 * rsa_field_width(key_n));
+        assert_eq!(rsa_common_modulus_attack(&first_ciphertext, &second_ciphertext, &first_exponent, &second_exponent, key_n).unwrap(), message);
+        assert_eq!(rsa_common_modulus_attack(&second_ciphertext, &first_ciphertext, &second_exponent, &first_exponent, key_n).unwrap(), message);
+
+        // The request of the entry point receives the ciphertexts in hex.
+        let request = RsaRequest::CommonModulusAttack {
+            first_ciphertext: string_hex_encode(&first_ciphertext).unwrap(),
+            second_ciphertext: string_hex_encode(&second_ciphertext).unwrap(),
+            first_exponent: first_exponent.clone(),
+            second_exponent: second_exponent.clone(),
+            key_modulus: key_n.clone(),
+        };
+        assert_eq!(rsa(request, None, &CancellationToken::new()).unwrap(), RsaResult::StringResult(String::from_utf8(message.to_vec()).unwrap()));
+
+        // The exponents, which are not coprime, fail with the GCD in the error.
+        let third_ciphertext = rsa_encrypt_bytes(message, &ChonkerInt::from(9), key_n, &RsaPadding::Raw).unwrap();
+        let gcd_error = rsa_common_modulus_attack(&first_ciphertext, &third_ciphertext, &first_exponent, &ChonkerInt::from(9), key_n).unwrap_err();
+        assert!(gcd_error.to_string().contains("are not coprime, their GCD is 3"));
+
+        // The ciphertexts of the different lengths, the truncated ciphertexts and the ciphertexts of the different messages fail.
+        let other_ciphertext = rsa_encrypt_bytes(b"Short.", &second_exponent, key_n, &RsaPadding::Raw).unwrap();
+        assert!(rsa_common_modulus_attack(&first_ciphertext, &other_ciphertext, &first_exponent, &second_exponent, key_n).is_err());
+        let field_width = rsa_field_width(key_n);
+        assert!(rsa_common_modulus_attack(&first_ciphertext[..field_width - 1], &second_ciphertext[..field_width - 1], &first_exponent, &second_exponent, key_n).is_err());
+        let first_short = rsa_encrypt_bytes(b"Short!", &first_exponent, key_n, &RsaPadding::Raw).unwrap();
+        assert!(rsa_common_modulus_attack(&first_short, &other_ciphertext, &first_exponent, &second_exponent, key_n).is_err());
+    }
+
+    // Test RSA brute force with the Wiener's attack of a long modulus with a small private exponent,
+    // and its refusal of a key with the usual private exponent.
+    #[test]
+    fn test_rsa_wiener_bruteforce() {
+        let mut rng = StdRng::seed_from_u64(73);
+        let cancellation = CancellationToken::new();
+        let prime_p = ChonkerInt::new_prime_bits_until(256, &mut rng, &cancellation).unwrap();
+        let prime_q = loop {
+            let prime_q = ChonkerInt::new_prime_bits_until(256, &mut rng, &cancellation).unwrap();
+            if prime_q != prime_p {
+                break prime_q;
+            }
+        };
+        let target_modulus = &prime_p * &prime_q;
+        let phi_n = &(&prime_p - 1u64) * &(&prime_q - 1u64);
+
+        // The private exponent from the upper half of the vulnerable range d < n^(1/4) / 3, the public exponent is as long as the modulus.
+        let bound = &target_modulus.nth_root(4) / &ChonkerInt::from(3);
+        let private_key_d = loop {
+            let private_key_d = ChonkerInt::new_rand_range_value_with_rng(&(&bound / &ChonkerInt::from(2)), &bound, &BigIntSign::Positive, &mut rng);
+            if private_key_d.is_coprime(&phi_n) {
+                break private_key_d;
+            }
+        };
+        let target_public_exponent = private_key_d.modinv(&phi_n).unwrap();
+
+        let request = RsaRequest::Bruteforce {
+            public: RsaPublicKey { public_key_n: target_modulus.clone(), public_key_e: target_public_exponent.clone() },
+            threads: None,
+            strategy: BruteforceStrategy::Wiener,
+        };
+        let rsa_package = match rsa(request, None, &CancellationToken::new()).unwrap() {
+            RsaResult::BruteforceRSAResult(rsa_result) => rsa_result,
+            _ => panic!("error in the algorithm, did not compute a bruteforce result (test_rsa_wiener_bruteforce)"),
+        };
+
+        assert_eq!(rsa_package.private_key_d, private_key_d);
+        assert_eq!(&rsa_package.prime_p * &rsa_package.prime_q, target_modulus);
+        assert!(rsa_package.prime_p == prime_p || rsa_package.prime_p == prime_q);
+        assert!(rsa_package.prime_p < rsa_package.prime_q);
+
+        // The key pair of the key generation has the private exponent as long as the modulus, the attack ends without the primes.
+        let key_pair = match rsa_key_generation_with_rng(256, &mut rng, &RsaKeygenOptions::default(), &cancellation).unwrap() {
+            RsaResult::KeyPair(key_pair) => key_pair,
+            _ => panic!("produced another result instead of a randomly generated key pair (test_rsa_wiener_bruteforce)"),
+        };
+        let wiener_error = rsa_bruteforce(&key_pair.public_key_e, &key_pair.public_key_n, None, &BruteforceStrategy::Wiener, None, &cancellation).unwrap_err();
+        assert!(wiener_error.to_string().contains("not vulnerable"));
+
+        // The textbook key pair has the private exponent above n^(1/4) / 3.
+        assert!(rsa_bruteforce(&ChonkerInt::from(17), &ChonkerInt::from(3233), None, &BruteforceStrategy::Wiener, None, &cancellation).is_err());
+    }
 }
@@ -69,25 +69,25 @@ impl ThreadPool {
         // The free thread will pick up the task and execute it.
         self.sender.send(ThreadTask::NewJob(job)).unwrap();
     }
-}
 
-// Implement the Drop thread for the thread pool struct,
-// defining how the pool will destruct itself, when it goes out the scope.
-impl Drop for ThreadPool {
-    fn drop(&mut self) {
-        println!("Sending terminate ThreadTask to all workers.");
+    // Shut down the thread pool gracefully: the queued jobs are finished, then the workers terminate
+    // and the main thread waits for all of them to exit.
+    pub fn shutdown(mut self) {
+        self.terminate_workers();
+    }
 
+    // Send the termination signal to all the workers and join their threads.
+    // The termination signals are queued after the jobs, thus the workers finish the queued jobs first.
+    // The workers are removed from the pool, so that the repeated call does nothing.
+    // The pool prints nothing, the output of the tool is written only by the caller.
+    fn terminate_workers(&mut self) {
         // Send the termination signal to all the workers in the thread pool.
         for _ in &self.workers {
-            self.sender.send(ThreadTask::Terminate).unwrap();
+            let _sent_signal = self.sender.send(ThreadTask::Terminate);
         }
 
-        println!("Shutting down all workers.");
-
         // Wait for the closure of workers/threads.
-        for worker in &mut self.workers {
-            println!("Shutting down worker {}", worker.id);
-
+        for mut worker in self.workers.drain(..) {
             // If the thread still exits, wait for its exit.
             if let Some(thread) = worker.thread.take() {
                 thread.join().unwrap_or(());
@@ -96,6 +96,14 @@ impl Drop for ThreadPool {
     }
 }
 
+// Implement the Drop thread for the thread pool struct,
+// defining how the pool will destruct itself, when it goes out the scope.
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        self.terminate_workers();
+    }
+}
+
 // Implement methods on the worker struct.
 impl Worker {
     // Create/construct a new worker, receiving its own ID and a smart reference to the
@@ -107,14 +115,8 @@ impl Worker {
         let thread = thread::spawn(move || loop {
             let thread_task = receiver.lock().unwrap().recv().unwrap();
             match thread_task {
-                ThreadTask::NewJob(job) => {
-                    println!("Worker {} got a job; executing.", id);
-                    job();
-                }
-                ThreadTask::Terminate => {
-                    println!("Worker {} was told to terminate.", id);
-                    break;
-                }
+                ThreadTask::NewJob(job) => job(),
+                ThreadTask::Terminate => break,
             }
         });
 
@@ -127,7 +129,10 @@ impl Worker {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, mpsc, Mutex};
+    use std::thread;
+    use std::time::Duration;
 
     use crate::crypto::rsa::threadpool::{ThreadPool, ThreadTask, Worker};
 
@@ -156,6 +161,43 @@ mod tests {
         assert_eq!(thread_pool.workers.len(), amount_of_threads);
     }
 
+    // Test the graceful shutdown of the thread pool, all of the queued jobs are finished before the workers exit.
+    #[test]
+    fn test_rsa_thread_pool_shutdown() {
+        let thread_pool = ThreadPool::new(4);
+        let finished_jobs = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..16 {
+            let finished_jobs = Arc::clone(&finished_jobs);
+            thread_pool.execute(move || {
+                thread::sleep(Duration::from_millis(5));
+                finished_jobs.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        thread_pool.shutdown();
+        assert_eq!(finished_jobs.load(Ordering::SeqCst), 16);
+    }
+
+    // Test dropping of the thread pool with queued jobs, it must not deadlock.
+    #[test]
+    fn test_rsa_thread_pool_drop_with_queued_jobs() {
+        let (main_sender, main_receiver) = mpsc::channel();
+
+        // Drop the pool in a separate thread, so that a deadlock is detected with a timeout.
+        thread::spawn(move || {
+            let thread_pool = ThreadPool::new(2);
+            for _ in 0..32 {
+                thread_pool.execute(|| thread::sleep(Duration::from_millis(2)));
+            }
+            drop(thread_pool);
+
+            main_sender.send(()).unwrap();
+        });
+
+        assert!(main_receiver.recv_timeout(Duration::from_secs(30)).is_ok());
+    }
+
     // Test worker construction and operation.
     #[test]
     fn test_rsa_worker_construction_and_operation() {
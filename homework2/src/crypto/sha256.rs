@@ -0,0 +1,191 @@
+use crate::logic::error::OperationError;
+
+// Length of the SHA-256 digest in bytes.
+pub const SHA256_DIGEST_LEN: usize = 32;
+// Length of the SHA-256 block in bytes, the HMAC key is padded to it.
+const SHA256_BLOCK_LEN: usize = 64;
+// Longest output of the HKDF with SHA-256, 255 blocks of the expansion.
+pub const HKDF_SHA256_MAX_LEN: usize = 255 * SHA256_DIGEST_LEN;
+
+// Initial hash values: the first 32 bits of the fractional parts of the square roots of the first 8 primes.
+const INITIAL_HASH: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+// Round constants: the first 32 bits of the fractional parts of the cube roots of the first 64 primes.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+// Calculate the SHA-256 digest of the data, as defined in FIPS 180-4.
+// The data is padded with a single one bit, the zero bits and the 64 bit length of the data in bits,
+// then the blocks of 64 bytes are compressed one by one into the hash state.
+pub fn sha256(data: &[u8]) -> [u8; SHA256_DIGEST_LEN] {
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0x00);
+    }
+    message.extend_from_slice(&((data.len() as u64).wrapping_mul(8)).to_be_bytes());
+
+    let mut hash_state = INITIAL_HASH;
+    for block in message.chunks_exact(64) {
+        sha256_compress(&mut hash_state, block);
+    }
+
+    let mut digest = [0u8; SHA256_DIGEST_LEN];
+    for (digest_word, state_word) in digest.chunks_exact_mut(4).zip(hash_state.iter()) {
+        digest_word.copy_from_slice(&state_word.to_be_bytes());
+    }
+
+    digest
+}
+
+// Calculate the HMAC-SHA-256 of the data, as defined in RFC 2104: H((K ^ opad) || H((K ^ ipad) || data)).
+// The key longer than the block is hashed first, the shorter key is padded with the zero bytes.
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; SHA256_DIGEST_LEN] {
+    let mut block_key = [0u8; SHA256_BLOCK_LEN];
+    if key.len() > SHA256_BLOCK_LEN {
+        block_key[..SHA256_DIGEST_LEN].copy_from_slice(&sha256(key));
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner_message: Vec<u8> = block_key.iter().map(|byte| byte ^ 0x36).collect();
+    inner_message.extend_from_slice(data);
+    let mut outer_message: Vec<u8> = block_key.iter().map(|byte| byte ^ 0x5c).collect();
+    outer_message.extend_from_slice(&sha256(&inner_message));
+
+    sha256(&outer_message)
+}
+
+// Derive the key of the requested length with the HKDF of RFC 5869 over HMAC-SHA-256.
+// The extract step concentrates the input key material into the pseudorandom key PRK = HMAC(salt, IKM),
+// the expand step chains the blocks T(i) = HMAC(PRK, T(i - 1) || info || i) and takes the first bytes of them.
+// The output does not depend on the requested length, the shorter key is the prefix of the longer one with the same info.
+// More about the idea: https://www.rfc-editor.org/rfc/rfc5869
+pub fn hkdf_sha256(salt: &[u8], input_key: &[u8], info: &[u8], length: usize) -> Result<Vec<u8>, OperationError> {
+    if length == 0 || length > HKDF_SHA256_MAX_LEN {
+        return Err(OperationError::new(&format!("the requested length of the derived key is {} bytes, correct length is in the range 1-{}.", length, HKDF_SHA256_MAX_LEN)));
+    }
+
+    let pseudorandom_key = hmac_sha256(salt, input_key);
+
+    let mut derived_key = Vec::with_capacity(length + SHA256_DIGEST_LEN);
+    let mut block: Vec<u8> = Vec::new();
+    for counter in 1..=length.div_ceil(SHA256_DIGEST_LEN) {
+        block.extend_from_slice(info);
+        block.push(counter as u8);
+        block = hmac_sha256(&pseudorandom_key, &block).to_vec();
+        derived_key.extend_from_slice(&block);
+    }
+    derived_key.truncate(length);
+
+    Ok(derived_key)
+}
+
+// Compress a single block of 64 bytes into the hash state.
+fn sha256_compress(hash_state: &mut [u32; 8], block: &[u8]) {
+    // Prepare the message schedule of 64 words from the 16 words of the block.
+    let mut schedule = [0u32; 64];
+    for (word, bytes) in schedule.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    for index in 16..64 {
+        let sigma_0 = schedule[index - 15].rotate_right(7) ^ schedule[index - 15].rotate_right(18) ^ (schedule[index - 15] >> 3);
+        let sigma_1 = schedule[index - 2].rotate_right(17) ^ schedule[index - 2].rotate_right(19) ^ (schedule[index - 2] >> 10);
+        schedule[index] = schedule[index - 16].wrapping_add(sigma_0).wrapping_add(schedule[index - 7]).wrapping_add(sigma_1);
+    }
+
+    // Run the 64 rounds over the working variables.
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *hash_state;
+    for index in 0..64 {
+        let sum_1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let choice = (e & f) ^ (!e & g);
+        let temp_1 = h.wrapping_add(sum_1).wrapping_add(choice).wrapping_add(ROUND_CONSTANTS[index]).wrapping_add(schedule[index]);
+        let sum_0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let majority = (a & b) ^ (a & c) ^ (b & c);
+        let temp_2 = sum_0.wrapping_add(majority);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp_1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp_1.wrapping_add(temp_2);
+    }
+
+    // Add the compressed block to the hash state.
+    for (state_word, working_word) in hash_state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *state_word = state_word.wrapping_add(working_word);
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::crypto::sha256::{hkdf_sha256, hmac_sha256, sha256, HKDF_SHA256_MAX_LEN};
+    use crate::encoding::{string_hex_decode, string_hex_encode};
+
+    // Test the SHA-256 digests against the known answers of FIPS 180-4 and the messages around the block boundaries.
+    #[test]
+    fn test_sha256_known_answers() {
+        let known_answers: [(&[u8], &str); 4] = [
+            (b"", "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855"),
+            (b"abc", "BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD"),
+            (b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq", "248D6A61D20638B8E5C026930C3E6039A33CE45964FF2167F6ECEDD419DB06C1"),
+            (b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu", "CF5B16A778AF8380036CE59E7B0492370B249B11E8F07A51AFAC45037AFEE9D1"),
+        ];
+
+        for (message, digest) in known_answers {
+            assert_eq!(string_hex_encode(&sha256(message)).unwrap(), digest);
+        }
+
+        // The message of a million "a" characters spans many blocks.
+        assert_eq!(
+            string_hex_encode(&sha256(&vec![b'a'; 1_000_000])).unwrap(),
+            "CDC76E5C9914FB9281A1C7E284D73E67F1809A48A497200E046D39CCC7112CD0"
+        );
+    }
+    // Test HMAC-SHA-256 against the known answers of RFC 4231, the short key and the key longer than the block.
+    #[test]
+    fn test_hmac_sha256_known_answers() {
+        assert_eq!(
+            string_hex_encode(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")).unwrap(),
+            "5BDCC146BF60754E6A042426089575C75A003F089D2739839DEC58B964EC3843"
+        );
+        assert_eq!(
+            string_hex_encode(&hmac_sha256(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First")).unwrap(),
+            "60E431591EE0B67F0D8A26AACBF5B77F8E0BC6213728C5140546040F0EE37F54"
+        );
+    }
+
+    // Test HKDF-SHA-256 against the known answer of RFC 5869, the shorter key is the prefix of the longer one.
+    #[test]
+    fn test_hkdf_sha256() {
+        let input_key = [0x0b; 22];
+        let salt = string_hex_decode("000102030405060708090A0B0C").unwrap();
+        let info = string_hex_decode("F0F1F2F3F4F5F6F7F8F9").unwrap();
+        assert_eq!(
+            string_hex_encode(&hkdf_sha256(&salt, &input_key, &info, 42).unwrap()).unwrap(),
+            "3CB25F25FAACD57A90434F64D0362F2A2D2D0A90CF1A5A4C5DB02D56ECC4C5BF34007208D5B887185865"
+        );
+
+        let long_key = hkdf_sha256(&salt, &input_key, &info, 100).unwrap();
+        assert_eq!(hkdf_sha256(&salt, &input_key, &info, 16).unwrap(), long_key[..16].to_vec());
+        assert_ne!(hkdf_sha256(&salt, &input_key, b"other info", 16).unwrap(), long_key[..16].to_vec());
+
+        assert_eq!(hkdf_sha256(&[], &input_key, &[], HKDF_SHA256_MAX_LEN).unwrap().len(), HKDF_SHA256_MAX_LEN);
+        assert!(hkdf_sha256(&salt, &input_key, &info, 0).is_err());
+        assert!(hkdf_sha256(&salt, &input_key, &info, HKDF_SHA256_MAX_LEN + 1).is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use std::error::Error;
+use std::io::{Read, Write};
+
+use crate::crypto::caesar::decrypted_bytes_to_string;
+use crate::crypto::rsa::read_block;
+use crate::encoding::stream::{unwrap_hex_error, HexReader, HexWriter};
+use crate::encoding::{decode_ciphertext, Encoding, HexCase};
+use crate::logic::config::Mode;
+use crate::logic::error::OperationError;
+
+// Size of the chunks of the plaintext, processed at once by the streaming of the ciphers, which shift every byte on its own.
+pub const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+// A symmetric cipher over the bytes. The key of the command line is validated once and turned into the key bytes
+// of the cipher's own form, e.g. the single shift of Caesar, then the raw bytes are encrypted and decrypted with them.
+// The hex encoding of the ciphertext and the UTF-8 decoding of the decrypted bytes are shared by all ciphers,
+// see run_symmetric_cipher() below.
+pub trait SymmetricCipher {
+    // Name of the cipher in the messages.
+    fn name(&self) -> &'static str;
+
+    // Check the key and turn it into the key bytes for the encryption and the decryption.
+    fn validate_key(&self, key: &str) -> Result<Vec<u8>, OperationError>;
+
+    // Encrypt the bytes of the plaintext with the key bytes, produced by validate_key().
+    fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError>;
+
+    // Decrypt the bytes of the ciphertext with the key bytes, produced by validate_key().
+    fn decrypt(&self, ciphertext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError>;
+}
+
+// Error for the key bytes, which were not produced by validate_key() of the cipher.
+pub fn invalid_key_bytes(cipher: &str) -> OperationError {
+    OperationError::new(&format!("received the {} key bytes of an incorrect form, the key has to be validated first.", cipher))
+}
+
+// Encrypt the bytes of the reader into the hex ciphertext of the writer chunk by chunk, thus the memory use is bounded by the chunk size.
+// The encryption of the chunk receives the position of its first byte in the data, the position carries the key offset of the ciphers
+// with the repeated key across the chunk boundaries. The ciphertext is the same as the one of the whole data. Returns the amount of the written bytes.
+pub fn encrypt_stream<R: Read, W: Write, F: FnMut(&mut [u8], u64)>(mut reader: R, writer: W, mut encrypt_chunk: F) -> Result<u64, Box<dyn Error>> {
+    let mut chunk = vec![0u8; STREAM_CHUNK_SIZE];
+    let mut hex_writer = HexWriter::new(writer, HexCase::Upper);
+    let mut position = 0u64;
+
+    loop {
+        let chunk_len = read_block(&mut reader, &mut chunk)?;
+        if chunk_len == 0 {
+            break;
+        }
+
+        encrypt_chunk(&mut chunk[..chunk_len], position);
+        hex_writer.write_all(&chunk[..chunk_len])?;
+        position += chunk_len as u64;
+    }
+    hex_writer.flush()?;
+
+    Ok(2 * position)
+}
+
+// Decrypt the hex ciphertext of the reader into the bytes of the writer chunk by chunk, thus the memory use is bounded by the chunk size.
+// The decryption of the chunk receives the position of its first byte in the data, as the encryption of encrypt_stream().
+// The pairs of the hex symbols may be split by the separators as for string_hex_decode(), e.g. a line break at the end of the file.
+// The stream stops at the first invalid character, the decrypted bytes before it are already written. Returns the amount of the written bytes.
+pub fn decrypt_stream<R: Read, W: Write, F: FnMut(&mut [u8], u64)>(reader: R, mut writer: W, mut decrypt_chunk: F) -> Result<u64, Box<dyn Error>> {
+    let mut hex_reader = HexReader::new(reader);
+    let mut chunk = vec![0u8; STREAM_CHUNK_SIZE];
+    let mut position = 0u64;
+
+    loop {
+        let chunk_len = read_block(&mut hex_reader, &mut chunk).map_err(unwrap_hex_error)?;
+        if chunk_len == 0 {
+            break;
+        }
+
+        decrypt_chunk(&mut chunk[..chunk_len], position);
+        writer.write_all(&chunk[..chunk_len])?;
+        position += chunk_len as u64;
+    }
+    writer.flush()?;
+
+    Ok(position)
+}
+
+// Encrypt or decrypt the target with the symmetric cipher over the bytes: the key is validated by the cipher,
+// the bytes of the target are encrypted into the hex ciphertext, the hex ciphertext is decrypted into a UTF-8 text.
+pub(crate) fn run_symmetric_cipher(cipher: &dyn SymmetricCipher, mode: &Mode, target: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    // The hex ciphertext is always a valid UTF-8 text.
+    decrypted_bytes_to_string(run_symmetric_cipher_bytes(cipher, mode, target.as_bytes(), key, None)?, cipher.name())
+}
+
+// Encrypt or decrypt the target bytes with the symmetric cipher over the bytes: the encryption produces the bytes of the ciphertext
+// in the chosen encoding or in hex for None, the decryption of the bytes of the ciphertext in the chosen encoding or in hex for None
+// produces the raw decrypted bytes.
+pub(crate) fn run_symmetric_cipher_bytes(cipher: &dyn SymmetricCipher, mode: &Mode, target: &[u8], key: &str, encoding: Option<Encoding>) -> Result<Vec<u8>, Box<dyn Error>> {
+    let key = cipher.validate_key(key)?;
+
+    match mode {
+        Mode::Encode => Ok(encoding.unwrap_or(Encoding::Hex(HexCase::Upper)).encode(&cipher.encrypt(target, &key)?)?.into_bytes()),
+        Mode::Decode => {
+            // Convert received ciphertext into the vector of encrypted one bytes.
+            let decoded_string = decode_ciphertext(target_text(target)?, encoding)?;
+
+            Ok(cipher.decrypt(&decoded_string, &key)?)
+        }
+        _ => Err(Box::new(OperationError::new(&format!("received an incorrect argument for the encryption mode of {} cipher. Correct values: \"encrypt\" or \"decrypt\". (run_symmetric_cipher)", cipher.name())))),
+    }
+}
+
+// View the target bytes as a UTF-8 text for the operations on the texts: the hex ciphertexts, the classic mode and the one-time pad decryption.
+pub(crate) fn target_text(target: &[u8]) -> Result<&str, OperationError> {
+    match std::str::from_utf8(target) {
+        Ok(text) => Ok(text),
+        Err(e) => Err(OperationError::new(&format!("The target is not a valid UTF-8 text ({}), the chosen operation processes only the texts or the hex ciphertexts.", e))),
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::crypto::symmetric::{run_symmetric_cipher, run_symmetric_cipher_bytes, SymmetricCipher};
+    use crate::encoding::{Encoding, HexCase};
+    use crate::logic::config::Mode;
+    use crate::logic::error::OperationError;
+
+    // A dummy cipher for the tests of the shared path: the bytes are reversed and shifted by the single digit of the key.
+    struct ReverseCipher;
+
+    impl SymmetricCipher for ReverseCipher {
+        fn name(&self) -> &'static str {
+            "reverse"
+        }
+
+        fn validate_key(&self, key: &str) -> Result<Vec<u8>, OperationError> {
+            match key.as_bytes() {
+                [digit @ b'0'..=b'9'] => Ok(vec![digit - b'0']),
+                _ => Err(OperationError::new("the reverse key has to be a single digit.")),
+            }
+        }
+
+        fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+            Ok(plaintext.iter().rev().map(|byte| byte.wrapping_add(key[0])).collect())
+        }
+
+        fn decrypt(&self, ciphertext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+            Ok(ciphertext.iter().rev().map(|byte| byte.wrapping_sub(key[0])).collect())
+        }
+    }
+
+    // Test the shared path with the dummy cipher: the hex ciphertext, the round-trip, the errors of the key,
+    // of the hex, of the UTF-8 decoding with the name of the cipher and of the mode.
+    #[test]
+    fn test_run_symmetric_cipher() {
+        assert_eq!(run_symmetric_cipher(&ReverseCipher, &Mode::Encode, "abc", "1").unwrap(), "646362");
+        assert_eq!(run_symmetric_cipher(&ReverseCipher, &Mode::Decode, "646362", "1").unwrap(), "abc");
+
+        let target_original = "Цель 🗝";
+        let encryption_result = run_symmetric_cipher(&ReverseCipher, &Mode::Encode, target_original, "7").unwrap();
+        assert_eq!(run_symmetric_cipher(&ReverseCipher, &Mode::Decode, &encryption_result, "7").unwrap(), target_original);
+
+        assert!(run_symmetric_cipher(&ReverseCipher, &Mode::Encode, "abc", "12").unwrap_err().to_string().contains("single digit"));
+        assert!(run_symmetric_cipher(&ReverseCipher, &Mode::Decode, "6463G", "1").is_err());
+        assert!(run_symmetric_cipher(&ReverseCipher, &Mode::Decode, "FF", "0").unwrap_err().to_string().contains("the decrypted reverse message is not a valid UTF-8 text"));
+        assert!(run_symmetric_cipher(&ReverseCipher, &Mode::Bruteforce, "abc", "1").is_err());
+    }
+
+    // Test the lowercase hex ciphertexts of the shared path: the chosen case reaches the encoding, the ciphertext is decrypted back.
+    #[test]
+    fn test_symmetric_cipher_hex_case() {
+        let lower_result = run_symmetric_cipher_bytes(&ReverseCipher, &Mode::Encode, b"xyz", "5", Some(Encoding::Hex(HexCase::Lower))).unwrap();
+        assert_eq!(lower_result, b"7f7e7d");
+        assert_eq!(run_symmetric_cipher_bytes(&ReverseCipher, &Mode::Encode, b"xyz", "5", None).unwrap(), b"7F7E7D");
+        assert_eq!(run_symmetric_cipher_bytes(&ReverseCipher, &Mode::Decode, &lower_result, "5", None).unwrap(), b"xyz");
+    }
+}
@@ -1,58 +1,128 @@
 use std::error::Error;
-use std::str::from_utf8_unchecked;
+use std::io::{Read, Write};
 
 use crate::crypto::caesar::*;
-use crate::encoding::*;
+use crate::crypto::symmetric::{decrypt_stream, encrypt_stream, run_symmetric_cipher, SymmetricCipher};
 use crate::logic::config::Mode;
 use crate::logic::error::OperationError;
 
-pub fn vigenere(mode: &Mode, target: &mut str, key: &str) -> Result<String, Box<dyn Error>> {
-    // Turn key string into vector of bytes.
-    let key = key.as_bytes();
-    let key_len = key.len();
+pub mod analysis;
 
-    match mode {
-        Mode::Encode => {
-            // Convert string to the vector of unsigned one byte integers.
-            let target = unsafe { target.as_bytes_mut() };
+// Vigenère cipher over the bytes, every byte is shifted by the byte of the key, the key is repeated over the target.
+// The key bytes are the bytes of the key string.
+pub struct Vigenere;
 
-            // Iterator over key.
-            let mut i = 0;
+impl SymmetricCipher for Vigenere {
+    fn name(&self) -> &'static str {
+        "Vigenère"
+    }
 
-            // Encrypt vector of bytes one by one.
-            for char in target.iter_mut() {
-                caesar_encrypt_char(char, &key[i]);
-                i = (i + 1) % key_len;
-            }
+    // The key of no bytes cannot be cycled over the target. Every UTF-8 character takes at least one byte,
+    // thus only the empty string produces it.
+    fn validate_key(&self, key: &str) -> Result<Vec<u8>, OperationError> {
+        if key.is_empty() {
+            return Err(OperationError::new("Vigenère key must not be empty. (vigenere)"));
+        }
 
-            // Encode the vector of bytes into the hex string.
-            string_hex_encode(target)
+        Ok(key.as_bytes().to_vec())
+    }
+
+    fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        // Encrypt vector of bytes one by one, cycling over the key.
+        let mut encrypted_bytes = plaintext.to_vec();
+        for (char, key_byte) in encrypted_bytes.iter_mut().zip(key.iter().cycle()) {
+            caesar_encrypt_char(char, key_byte);
         }
-        Mode::Decode => {
-            // Convert received hex string into the vector of encrypted one bytes.
-            let mut decoded_string = string_hex_decode(target)?;
 
-            // Iterator over key.
-            let mut i = 0;
+        Ok(encrypted_bytes)
+    }
 
-            // Decrypt vector of bytes one by one.
-            for char in decoded_string.iter_mut() {
-                caesar_decrypt_char(char, &key[i]);
-                i = (i + 1) % key_len;
-            }
+    fn decrypt(&self, ciphertext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        // Decrypt vector of bytes one by one, cycling over the key.
+        let mut decrypted_bytes = ciphertext.to_vec();
+        for (char, key_byte) in decrypted_bytes.iter_mut().zip(key.iter().cycle()) {
+            caesar_decrypt_char(char, key_byte);
+        }
 
-            let result = unsafe { from_utf8_unchecked(&decoded_string) };
+        Ok(decrypted_bytes)
+    }
+}
 
-            Ok(String::from(result))
+// Function to encrypt or decrypt the target string under Vigenère cipher over the bytes.
+pub fn vigenere(mode: &Mode, target: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    run_symmetric_cipher(&Vigenere, mode, target, key)
+}
+
+// Function to encrypt the bytes of the reader into the hex ciphertext of the writer under Vigenère cipher over the bytes chunk by chunk,
+// for the inputs too large for the memory. Every chunk continues the key at the offset of its position. Returns the amount of the written bytes.
+pub fn vigenere_encrypt_stream<R: Read, W: Write>(reader: R, writer: W, key: &str) -> Result<u64, Box<dyn Error>> {
+    let key = Vigenere.validate_key(key)?;
+
+    encrypt_stream(reader, writer, |chunk, position| {
+        for (char, key_byte) in chunk.iter_mut().zip(key_at_offset(&key, position)) {
+            caesar_encrypt_char(char, key_byte);
+        }
+    })
+}
+
+// Function to decrypt the hex ciphertext of the reader into the bytes of the writer under Vigenère cipher over the bytes chunk by chunk,
+// for the inputs too large for the memory. Every chunk continues the key at the offset of its position. Returns the amount of the written bytes.
+pub fn vigenere_decrypt_stream<R: Read, W: Write>(reader: R, writer: W, key: &str) -> Result<u64, Box<dyn Error>> {
+    let key = Vigenere.validate_key(key)?;
+
+    decrypt_stream(reader, writer, |chunk, position| {
+        for (char, key_byte) in chunk.iter_mut().zip(key_at_offset(&key, position)) {
+            caesar_decrypt_char(char, key_byte);
         }
-        _ => Err(Box::new(OperationError::new("received an incorrect argument for the encryption mode. Correct values: \"encrypt\" or \"decrypt\". (vigenere)"))),
+    })
+}
+
+// Cycle over the key bytes, starting from the key byte of the byte at the position of the data.
+fn key_at_offset(key: &[u8], position: u64) -> impl Iterator<Item = &u8> {
+    key.iter().cycle().skip((position % key.len() as u64) as usize)
+}
+
+// Function to encrypt or decrypt the target string under the classic Vigenère cipher of the Latin alphabet.
+// The key consists only of the ASCII letters, A or a gives the shift of 0 and Z or z gives the shift of 25.
+// Only the ASCII letters of the target are shifted and consume the key positions, other characters pass through unchanged.
+pub fn vigenere_classic(mode: &Mode, target: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    if key.is_empty() {
+        return Err(Box::new(OperationError::new("Vigenère key must not be empty. (vigenere_classic)")));
     }
+    if !key.chars().all(|char| char.is_ascii_alphabetic()) {
+        return Err(Box::new(OperationError::new("the classic Vigenère key must consist only of the letters A-Z or a-z. (vigenere_classic)")));
+    }
+
+    // Turn key letters into their shifts, the decryption shifts forward by the complement of the shift.
+    let shifts: Vec<u8> = match mode {
+        Mode::Encode => key.bytes().map(|byte| byte.to_ascii_uppercase() - b'A').collect(),
+        Mode::Decode => key.bytes().map(|byte| CLASSIC_ALPHABET_LEN - (byte.to_ascii_uppercase() - b'A')).collect(),
+        _ => return Err(Box::new(OperationError::new("received an incorrect argument for the encryption mode. Correct values: \"encrypt\" or \"decrypt\". (vigenere_classic)"))),
+    };
+
+    // Iterator over key, it advances only on the letters.
+    let mut i = 0;
+
+    Ok(target
+        .chars()
+        .map(|char| {
+            if !char.is_ascii_alphabetic() {
+                return char;
+            }
+
+            let shifted_char = classic_shift_char(char, shifts[i]);
+            i = (i + 1) % shifts.len();
+            shifted_char
+        })
+        .collect())
 }
 
 // Test module.
 #[cfg(test)]
 mod tests {
-    use crate::crypto::vigenere::vigenere;
+    use crate::crypto::symmetric::{SymmetricCipher, STREAM_CHUNK_SIZE};
+    use crate::crypto::vigenere::{vigenere, vigenere_classic, vigenere_decrypt_stream, vigenere_encrypt_stream, Vigenere};
+    use crate::encoding::string_hex_encode;
     use crate::logic::config::Mode;
 
     // Test Vigenere encryption.
@@ -111,4 +181,60 @@ mod tests {
 
         assert_eq!(target_original, decryption_result);
     }
+
+    // Test Vigenere decryption into the invalid UTF-8: the byte 0xFF, shifted by "A", is the lone continuation byte 0xBE.
+    #[test]
+    fn test_vigenere_decryption_incorrect_key() {
+        match vigenere(&Mode::Decode, &mut String::from("FF"), "A") {
+            Ok(_) => panic!("Expected an error for the decryption into the invalid UTF-8 (test_vigenere_decryption_incorrect_key)"),
+            Err(e) => assert!(e.to_string().contains("the decrypted Vigenère message is not a valid UTF-8 text")),
+        }
+    }
+
+    // Test the classic Vigenere on the textbook example, the spaces, punctuation and digits do not consume the key positions,
+    // the lowercase key equals the uppercase one, the key of non letters is denied.
+    #[test]
+    fn test_vigenere_classic() {
+        assert_eq!(vigenere_classic(&Mode::Encode, "ATTACKATDAWN", "LEMON").unwrap(), "LXFOPVEFRNHR");
+        assert_eq!(vigenere_classic(&Mode::Encode, "ATTACK AT DAWN", "LEMON").unwrap(), "LXFOPV EF RNHR");
+        assert_eq!(vigenere_classic(&Mode::Decode, "LXFOPV EF RNHR", "lemon").unwrap(), "ATTACK AT DAWN");
+
+        let target_original = "Attack at dawn, 6:00 sharp!";
+        let encryption_result = vigenere_classic(&Mode::Encode, target_original, "Lemon").unwrap();
+        assert_eq!(encryption_result, "Lxfopv ef rnhr, 6:00 evnct!");
+        assert_eq!(vigenere_classic(&Mode::Decode, &encryption_result, "LEMON").unwrap(), target_original);
+
+        assert!(vigenere_classic(&Mode::Encode, "ATTACK", "LEMON 2").unwrap_err().to_string().contains("only of the letters"));
+        assert!(vigenere_classic(&Mode::Encode, "ATTACK", "").unwrap_err().to_string().contains("Vigenère key must not be empty"));
+    }
+
+    // Test Vigenere streaming encryption and decryption, the key of the length, which does not divide the chunk size,
+    // continues across the chunk boundaries, thus the streamed ciphertext is the same as the one of the whole data.
+    #[test]
+    fn test_vigenere_stream_key_alignment() {
+        let data: Vec<u8> = (0..2 * STREAM_CHUNK_SIZE + 5).map(|index| (index * 31 % 251) as u8).collect();
+        for key in ["K", "Ключ!", "SevenKy"] {
+            let mut ciphertext = vec![];
+            vigenere_encrypt_stream(data.as_slice(), &mut ciphertext, key).unwrap();
+            let key_bytes = Vigenere.validate_key(key).unwrap();
+            assert!(ciphertext == string_hex_encode(&Vigenere.encrypt(&data, &key_bytes).unwrap()).unwrap().into_bytes());
+
+            let mut decrypted_bytes = vec![];
+            vigenere_decrypt_stream(ciphertext.as_slice(), &mut decrypted_bytes, key).unwrap();
+            assert!(decrypted_bytes == data);
+        }
+
+        assert!(vigenere_encrypt_stream(&b"data"[..], &mut vec![], "").unwrap_err().to_string().contains("Vigenère key must not be empty"));
+    }
+
+    // Test Vigenere with the empty key, both modes return an error instead of panicking, even for the empty target.
+    #[test]
+    fn test_vigenere_empty_key() {
+        for (mode, target) in [(Mode::Encode, "TargetText"), (Mode::Decode, "C8DC"), (Mode::Encode, "")] {
+            match vigenere(&mode, &mut String::from(target), "") {
+                Ok(result) => panic!("Expected an error for the empty key, received the result: {} (test_vigenere_empty_key)", result),
+                Err(error) => assert!(error.to_string().contains("Vigenère key must not be empty")),
+            }
+        }
+    }
 }
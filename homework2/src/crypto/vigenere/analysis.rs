@@ -0,0 +1,219 @@
+use std::error::Error;
+
+use crate::crypto::caesar::{caesar_decrypt_char, ENGLISH_LETTER_FREQUENCIES};
+use crate::encoding::string_hex_decode;
+use crate::logic::error::OperationError;
+
+// The Vigenère ciphertext is analysed in two steps. The key length is estimated by the index of coincidence:
+// the bytes of every column, encrypted with the same key byte, keep the uneven distribution of the plaintext,
+// while the columns of an incorrect period mix the shifts and approach the uniform distribution.
+// Then every key byte is recovered by the shift of its column, which is the closest to the English byte frequencies
+// by the chi-square statistic. Both steps are statistical, the results are guesses and may be wrong for the short
+// or the non English plaintexts.
+// More about the idea: https://en.wikipedia.org/wiki/Vigen%C3%A8re_cipher#Cryptanalysis
+
+// The periods 1-32 are tried as the key length.
+pub const VIGENERE_ANALYSIS_MAX_KEY_LEN: usize = 32;
+// Shortest ciphertext, which is analysed, the shorter ones do not carry enough statistics.
+pub const VIGENERE_ANALYSIS_MIN_LEN: usize = 64;
+// Amount of the key length candidates, listed by the analysis.
+pub const VIGENERE_ANALYSIS_KEY_LEN_CANDIDATES: usize = 3;
+
+// A period is tried only if every column has at least 8 bytes, the index of coincidence of the shorter columns is noise.
+const MIN_COLUMN_LEN: usize = 8;
+// The shortest period with the index of coincidence of at least 90% of the best one is chosen,
+// the multiples of the key length score about the same as the key length itself.
+const KEY_LEN_TOLERANCE: f64 = 0.9;
+// Index of coincidence of the bytes of the English text with the spaces, the punctuation and the mixed case.
+const ENGLISH_BYTE_INDEX_OF_COINCIDENCE: f64 = 0.06;
+// Expected shares of the spaces, of the other printable characters and of the non printable bytes in the English text,
+// the rest is shared by the letters according to their frequencies.
+const SPACE_SHARE: f64 = 0.15;
+const OTHER_PRINTABLE_SHARE: f64 = 0.04;
+const NON_PRINTABLE_SHARE: f64 = 0.001;
+
+// A candidate of the key length with the average index of coincidence of its columns.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyLengthCandidate {
+    pub length: usize,
+    pub index_of_coincidence: f64,
+}
+
+// The result of the Vigenère analysis: the best key length candidates, the guessed one first, the others
+// by their index of coincidence, the guessed key and the plaintext,
+// decrypted with it, and the confidence in the range 0-1. The invalid UTF-8 sequences of the plaintext are replaced
+// with the replacement character.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VigenereAnalysis {
+    pub key_lengths: Vec<KeyLengthCandidate>,
+    pub key: Vec<u8>,
+    pub plaintext: String,
+    pub confidence: f64,
+}
+
+// Function to analyse the hex ciphertext of Vigenère cipher over the bytes and guess its key and plaintext.
+pub fn vigenere_analyze(target: &str) -> Result<VigenereAnalysis, Box<dyn Error>> {
+    // Convert received hex string into the vector of encrypted one bytes.
+    let decoded_string = string_hex_decode(target)?;
+    if decoded_string.len() < VIGENERE_ANALYSIS_MIN_LEN {
+        return Err(Box::new(OperationError::new(&format!("not enough data for the Vigenère analysis, the ciphertext has {} bytes, at least {} bytes are required. (vigenere_analyze)", decoded_string.len(), VIGENERE_ANALYSIS_MIN_LEN))));
+    }
+
+    // Score every period, which leaves enough bytes in its columns, and rank them by the index of coincidence.
+    let max_key_len = VIGENERE_ANALYSIS_MAX_KEY_LEN.min(decoded_string.len() / MIN_COLUMN_LEN);
+    let mut key_lengths: Vec<KeyLengthCandidate> = (1..=max_key_len)
+        .map(|length| KeyLengthCandidate { length, index_of_coincidence: period_index_of_coincidence(&decoded_string, length) })
+        .collect();
+    key_lengths.sort_by(|first, second| second.index_of_coincidence.total_cmp(&first.index_of_coincidence));
+
+    // Choose the shortest period, close enough to the best one, and move it to the top of the candidates.
+    let best_index_of_coincidence = key_lengths[0].index_of_coincidence;
+    let key_length_position = (0..key_lengths.len())
+        .filter(|position| key_lengths[*position].index_of_coincidence >= KEY_LEN_TOLERANCE * best_index_of_coincidence)
+        .min_by_key(|position| key_lengths[*position].length)
+        .unwrap_or_default();
+    let key_length = key_lengths.remove(key_length_position);
+    key_lengths.insert(0, key_length.clone());
+    key_lengths.truncate(VIGENERE_ANALYSIS_KEY_LEN_CANDIDATES);
+
+    // Recover the key byte of every column and decrypt the ciphertext with the guessed key.
+    let key: Vec<u8> = (0..key_length.length).map(|column| column_key_byte(&column_bytes(&decoded_string, key_length.length, column))).collect();
+    let mut decrypted_bytes = decoded_string;
+    for (char, key_byte) in decrypted_bytes.iter_mut().zip(key.iter().cycle()) {
+        caesar_decrypt_char(char, key_byte);
+    }
+
+    // The confidence grows with the share of the printable plaintext and with the index of coincidence of the key length,
+    // approaching the one of the English text.
+    let printable = decrypted_bytes.iter().filter(|byte| is_printable(**byte)).count();
+    let confidence = printable as f64 / decrypted_bytes.len() as f64 * (key_length.index_of_coincidence / ENGLISH_BYTE_INDEX_OF_COINCIDENCE).min(1.0);
+
+    Ok(VigenereAnalysis {
+        key_lengths,
+        key,
+        plaintext: String::from_utf8_lossy(&decrypted_bytes).into_owned(),
+        confidence,
+    })
+}
+
+// The bytes of the column, encrypted with the same key byte, if the key has the given length.
+fn column_bytes(bytes: &[u8], key_length: usize, column: usize) -> Vec<u8> {
+    bytes.iter().skip(column).step_by(key_length).copied().collect()
+}
+
+// Average index of coincidence of the columns of the period: the probability of two bytes of a column,
+// picked without replacement, to be equal.
+fn period_index_of_coincidence(bytes: &[u8], key_length: usize) -> f64 {
+    let total: f64 = (0..key_length)
+        .map(|column| {
+            let column = column_bytes(bytes, key_length, column);
+            let mut counts = [0usize; 256];
+            for byte in column.iter() {
+                counts[usize::from(*byte)] += 1;
+            }
+
+            let coincidences: usize = counts.iter().map(|count| count * count.saturating_sub(1)).sum();
+            coincidences as f64 / (column.len() * (column.len() - 1)) as f64
+        })
+        .sum();
+
+    total / key_length as f64
+}
+
+// Find the key byte of the column, which decrypts it into the bytes, the closest to the English text by the chi-square statistic.
+// The equal statistics are resolved in favour of the smaller key byte.
+fn column_key_byte(column: &[u8]) -> u8 {
+    (0..=u8::MAX)
+        .map(|key_byte| {
+            let mut decrypted_column = column.to_vec();
+            for char in decrypted_column.iter_mut() {
+                caesar_decrypt_char(char, &key_byte);
+            }
+
+            (key_byte, english_chi_square(&decrypted_column))
+        })
+        .min_by(|first, second| first.1.total_cmp(&second.1))
+        .map(|(key_byte, _)| key_byte)
+        .unwrap_or_default()
+}
+
+// Chi-square statistic of the bytes against the English text: the categories are the 26 letters regardless of the case,
+// the space, the other printable characters and the non printable bytes.
+fn english_chi_square(bytes: &[u8]) -> f64 {
+    let mut observed = [0usize; 29];
+    for byte in bytes {
+        let category = match byte.to_ascii_lowercase() {
+            letter @ b'a'..=b'z' => usize::from(letter - b'a'),
+            b' ' => 26,
+            byte if is_printable(byte) => 27,
+            _ => 28,
+        };
+        observed[category] += 1;
+    }
+
+    let letter_share = 1.0 - SPACE_SHARE - OTHER_PRINTABLE_SHARE - NON_PRINTABLE_SHARE;
+    let letter_total: f64 = ENGLISH_LETTER_FREQUENCIES.iter().sum();
+    let expected_shares = ENGLISH_LETTER_FREQUENCIES
+        .iter()
+        .map(|frequency| frequency / letter_total * letter_share)
+        .chain([SPACE_SHARE, OTHER_PRINTABLE_SHARE, NON_PRINTABLE_SHARE]);
+
+    observed
+        .iter()
+        .zip(expected_shares)
+        .map(|(observed, share)| {
+            let expected = share * bytes.len() as f64;
+            (*observed as f64 - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+// Check if the byte is a printable ASCII character or a whitespace.
+fn is_printable(byte: u8) -> bool {
+    byte.is_ascii_graphic() || byte.is_ascii_whitespace()
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::crypto::vigenere::analysis::{vigenere_analyze, VIGENERE_ANALYSIS_KEY_LEN_CANDIDATES};
+    use crate::crypto::vigenere::vigenere;
+    use crate::logic::config::Mode;
+
+    // English plaintext of a few hundred bytes for the analysis.
+    const ENGLISH_TEXT: &str = "It was the best of times, it was the worst of times, it was the age of wisdom, it was the age of foolishness, \
+        it was the epoch of belief, it was the epoch of incredulity, it was the season of Light, it was the season of Darkness, \
+        it was the spring of hope, it was the winter of despair, we had everything before us, we had nothing before us, \
+        we were all going direct to Heaven, we were all going direct the other way.";
+
+    // Test the analysis of the English text, encrypted with the keys of 5 bytes, the key and the plaintext are recovered exactly.
+    #[test]
+    fn test_vigenere_analyze() {
+        for key in ["Ks9!q", "LEMON", "\u{7f}a~0Z"] {
+            let encryption_result = vigenere(&Mode::Encode, &mut String::from(ENGLISH_TEXT), key).unwrap();
+            let analysis = vigenere_analyze(&encryption_result).unwrap();
+
+            assert_eq!(analysis.key, key.as_bytes());
+            assert_eq!(analysis.plaintext, ENGLISH_TEXT);
+            assert_eq!(analysis.key_lengths.len(), VIGENERE_ANALYSIS_KEY_LEN_CANDIDATES);
+            assert_eq!(analysis.key_lengths[0].length, 5);
+            assert!(analysis.confidence > 0.9 && analysis.confidence <= 1.0);
+        }
+    }
+
+    // Test the analysis of the short ciphertexts and of the incorrect hex, an error is returned instead of a guess.
+    #[test]
+    fn test_vigenere_analyze_not_enough_data() {
+        let encryption_result = vigenere(&Mode::Encode, &mut String::from("Attack at dawn"), "LEMON").unwrap();
+
+        for target in [encryption_result.as_str(), ""] {
+            match vigenere_analyze(target) {
+                Ok(analysis) => panic!("Expected an error for the short ciphertext, got the key {:?} (test_vigenere_analyze_not_enough_data)", analysis.key),
+                Err(e) => assert!(e.to_string().contains("not enough data")),
+            }
+        }
+        assert!(vigenere_analyze("NotAHexText").is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use std::error::Error;
+
+use crate::crypto::symmetric::{run_symmetric_cipher, SymmetricCipher};
+use crate::logic::config::Mode;
+use crate::logic::error::OperationError;
+
+// The repeating-key XOR cipher, every byte of the target is XORed with the byte of the key, the key is repeated over the target,
+// thus the encryption and the decryption are the same operation, only the hex encoding of the ciphertext differs.
+// The key bytes are the bytes of the key string.
+pub struct Xor;
+
+impl SymmetricCipher for Xor {
+    fn name(&self) -> &'static str {
+        "XOR"
+    }
+
+    // Every UTF-8 character takes at least one byte, thus only the empty string produces no bytes.
+    fn validate_key(&self, key: &str) -> Result<Vec<u8>, OperationError> {
+        if key.is_empty() {
+            return Err(OperationError::new("XOR key must not be empty. (xor)"));
+        }
+
+        Ok(key.as_bytes().to_vec())
+    }
+
+    fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        Ok(xor_bytes(plaintext, key))
+    }
+
+    fn decrypt(&self, ciphertext: &[u8], key: &[u8]) -> Result<Vec<u8>, OperationError> {
+        Ok(xor_bytes(ciphertext, key))
+    }
+}
+
+// Function to encrypt or decrypt the target string under the repeating-key XOR cipher.
+pub fn xor(mode: &Mode, target: &str, key: &str) -> Result<String, Box<dyn Error>> {
+    run_symmetric_cipher(&Xor, mode, target, key)
+}
+
+// XOR the bytes with the key, repeated over them, the key must not be empty.
+pub fn xor_bytes(bytes: &[u8], key: &[u8]) -> Vec<u8> {
+    bytes.iter().zip(key.iter().cycle()).map(|(byte, key_byte)| byte ^ key_byte).collect()
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::crypto::xor::xor;
+    use crate::logic::config::Mode;
+
+    // Test XOR encryption on the known answer: "hello" ^ "keyke".
+    #[test]
+    fn test_xor_known_answer() {
+        assert_eq!(xor(&Mode::Encode, "hello", "key").unwrap(), "030015070A");
+        assert_eq!(xor(&Mode::Decode, "030015070A", "key").unwrap(), "hello");
+    }
+
+    // Test XOR encryption and decryption with the multi-byte characters in the target and the key.
+    #[test]
+    fn test_xor_encryption_and_decryption() {
+        let target_original = "TargetText, Целевой текст 🗝";
+        let key = "!АбвгдKey_123";
+
+        let encryption_result = xor(&Mode::Encode, target_original, key).unwrap();
+        assert_ne!(encryption_result, target_original);
+        assert_eq!(encryption_result.len(), 2 * target_original.len());
+        assert_eq!(xor(&Mode::Decode, &encryption_result, key).unwrap(), target_original);
+    }
+
+    // Test XOR with the key, longer than the target: only the prefix of the key is used.
+    #[test]
+    fn test_xor_key_longer_than_target() {
+        let encryption_result = xor(&Mode::Encode, "hi", "keyThatIsLongerThanTheTarget").unwrap();
+
+        assert_eq!(encryption_result, xor(&Mode::Encode, "hi", "ke").unwrap());
+        assert_eq!(xor(&Mode::Decode, &encryption_result, "keyThatIsLongerThanTheTarget").unwrap(), "hi");
+    }
+
+    // Test XOR with the empty key, both modes return an error.
+    #[test]
+    fn test_xor_empty_key() {
+        for (mode, target) in [(Mode::Encode, "hello"), (Mode::Decode, "030015070A")] {
+            match xor(&mode, target, "") {
+                Ok(result) => panic!("Expected an error for the empty key, received the result: {} (test_xor_empty_key)", result),
+                Err(error) => assert!(error.to_string().contains("XOR key must not be empty")),
+            }
+        }
+    }
+}
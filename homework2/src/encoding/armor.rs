@@ -0,0 +1,169 @@
+use crate::encoding::base64::{base64_decode, base64_encode};
+use crate::logic::error::OperationError;
+
+// The armored texts wrap the bytes into the lines of base64 between the begin and the end lines with the label of the content,
+// thus the long keys and ciphertexts stay readable and survive the email clients, which break the long lines.
+//
+//     -----BEGIN CIPHERTEXT-----
+//     <base64 of the bytes, 64 characters per line>
+//     -----END CIPHERTEXT-----
+
+// Labels of the armored contents, the labels of the keys are the ones of PKCS#1, but the bodies of the armored keys
+// are specific to this tool (see the key files of the RSA module).
+pub const RSA_PUBLIC_KEY_LABEL: &str = "RSA PUBLIC KEY";
+pub const RSA_PRIVATE_KEY_LABEL: &str = "RSA PRIVATE KEY";
+pub const CIPHERTEXT_LABEL: &str = "CIPHERTEXT";
+
+// Amount of the base64 characters on a line of the armored text.
+const ARMOR_LINE_LEN: usize = 64;
+// The dashes around the begin and the end lines.
+const ARMOR_DASHES: &str = "-----";
+
+// Wrap the bytes into the armored text with the label, the last line is the end line without a line break.
+pub fn armor_wrap(label: &str, bytes: &[u8]) -> String {
+    let body = base64_encode(bytes, true);
+    let mut result = String::with_capacity(body.len() + body.len() / ARMOR_LINE_LEN + 2 * label.len() + 32);
+
+    result.push_str(&format!("{0}BEGIN {1}{0}\n", ARMOR_DASHES, label));
+    // The base64 text consists only of the ASCII characters, thus it is split at any byte.
+    for line_start in (0..body.len()).step_by(ARMOR_LINE_LEN) {
+        result.push_str(&body[line_start..body.len().min(line_start + ARMOR_LINE_LEN)]);
+        result.push('\n');
+    }
+    result.push_str(&format!("{0}END {1}{0}", ARMOR_DASHES, label));
+
+    result
+}
+
+// Check if the text starts with the begin line of an armored text of any label.
+pub fn is_armored(text: &str) -> bool {
+    text.trim_start().starts_with(&format!("{}BEGIN ", ARMOR_DASHES))
+}
+
+// Strip the armor with the expected label from the text and decode its base64 body into the bytes.
+// The whitespace around the lines and inside the body, the empty lines and the "\r\n" line endings are ignored.
+// The begin and the end lines must carry the expected label, nothing may follow the end line.
+pub fn armor_unwrap(text: &str, label: &str) -> Result<Vec<u8>, OperationError> {
+    match armor_decode(text, label) {
+        Ok(bytes) => Ok(bytes),
+        Err(reason) => Err(OperationError::new(&format!("Received incorrect armored text, {}.", reason))),
+    }
+}
+
+// Strip the armor as armor_unwrap() does, the error is the reason of the failure for the own errors of the callers, e.g. of the key files.
+pub fn armor_decode(text: &str, label: &str) -> Result<Vec<u8>, String> {
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+
+    let begin_label = match lines.next().and_then(|line| armor_line_label(line, "BEGIN")) {
+        Some(begin_label) => begin_label,
+        None => return Err(format!("it does not start with the \"{0}BEGIN {1}{0}\" line", ARMOR_DASHES, label)),
+    };
+    if begin_label != label {
+        return Err(format!("the label \"{}\" of the begin line is not the expected \"{}\"", begin_label, label));
+    }
+
+    let mut body = String::new();
+    let mut end_label = None;
+    for line in lines.by_ref() {
+        if line.starts_with(ARMOR_DASHES) {
+            end_label = armor_line_label(line, "END");
+            if end_label.is_none() {
+                return Err(format!("the line \"{}\" is neither a part of the body nor the end line", line));
+            }
+            break;
+        }
+        body.extend(line.chars().filter(|char| !char.is_whitespace()));
+    }
+
+    match end_label {
+        None => return Err(format!("it does not end with the \"{0}END {1}{0}\" line, it may be truncated", ARMOR_DASHES, label)),
+        Some(end_label) if end_label != begin_label => {
+            return Err(format!("the label \"{}\" of the end line does not match the label \"{}\" of the begin line", end_label, begin_label));
+        }
+        Some(_) => (),
+    }
+    if lines.next().is_some() {
+        return Err(format!("it contains data after the \"{0}END {1}{0}\" line", ARMOR_DASHES, label));
+    }
+
+    base64_decode(&body).map_err(|e| format!("the body is not a correct base64 text: {}", e.to_string().trim_end_matches('.')))
+}
+
+// Take the label out of the begin or the end line, e.g. "CIPHERTEXT" of "-----BEGIN CIPHERTEXT-----".
+fn armor_line_label<'a>(line: &'a str, kind: &str) -> Option<&'a str> {
+    let label = line.strip_prefix(ARMOR_DASHES)?.strip_prefix(kind)?.strip_prefix(' ')?.strip_suffix(ARMOR_DASHES)?;
+
+    match label.is_empty() {
+        true => None,
+        false => Some(label),
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::encoding::armor::{armor_unwrap, armor_wrap, is_armored, CIPHERTEXT_LABEL, RSA_PRIVATE_KEY_LABEL, RSA_PUBLIC_KEY_LABEL};
+
+    // Test wrapping of the bytes of different lengths into the armored texts and unwrapping them back,
+    // the body lines are at most 64 characters long.
+    #[test]
+    fn test_armor_round_trip() {
+        let mut rng = StdRng::seed_from_u64(105);
+        for bytes_len in [0, 1, 47, 48, 49, 96, 1000] {
+            let bytes: Vec<u8> = (0..bytes_len).map(|_| rng.gen()).collect();
+            for label in [CIPHERTEXT_LABEL, RSA_PUBLIC_KEY_LABEL, RSA_PRIVATE_KEY_LABEL] {
+                let armored_text = armor_wrap(label, &bytes);
+                assert!(is_armored(&armored_text));
+                assert!(armored_text.starts_with(&format!("-----BEGIN {}-----\n", label)));
+                assert!(armored_text.ends_with(&format!("\n-----END {}-----", label)));
+                assert!(armored_text.lines().all(|line| line.len() <= 64 || line.starts_with("-----")), "{}", armored_text);
+                assert_eq!(armor_unwrap(&armored_text, label).unwrap(), bytes);
+            }
+        }
+
+        assert_eq!(armor_wrap(CIPHERTEXT_LABEL, b"foobar"), "-----BEGIN CIPHERTEXT-----\nZm9vYmFy\n-----END CIPHERTEXT-----");
+        assert!(!is_armored("Zm9vYmFy"));
+    }
+
+    // Test unwrapping of the armored texts with the stray whitespace, the empty lines and the "\r\n" line endings.
+    #[test]
+    fn test_armor_unwrap_whitespace() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let armored_text = armor_wrap(CIPHERTEXT_LABEL, &bytes);
+
+        for changed_text in [
+            armored_text.replace('\n', "\r\n"),
+            format!("\n  {}\r\n\n", armored_text.replace('\n', " \n\t")),
+            armored_text.replacen("\n", "\n\n", 3),
+            armored_text.replacen("AAEC", "AA EC", 1),
+        ] {
+            assert_eq!(armor_unwrap(&changed_text, CIPHERTEXT_LABEL).unwrap(), bytes, "{:?}", changed_text);
+        }
+    }
+
+    // Test the rejection of the armored texts with the mismatched or unexpected labels, without the armor lines,
+    // with the data after the end line and with the incorrect base64 body.
+    #[test]
+    fn test_armor_unwrap_errors() {
+        let armored_text = armor_wrap(CIPHERTEXT_LABEL, b"foobar");
+
+        for (text, message) in [
+            (armored_text.replacen("END CIPHERTEXT", "END RSA PUBLIC KEY", 1), "label \"RSA PUBLIC KEY\" of the end line does not match"),
+            (armored_text.replacen("BEGIN CIPHERTEXT", "BEGIN RSA PUBLIC KEY", 1), "label \"RSA PUBLIC KEY\" of the begin line is not the expected \"CIPHERTEXT\""),
+            (String::from("Zm9vYmFy"), "does not start with the \"-----BEGIN CIPHERTEXT-----\" line"),
+            (armored_text.replacen("-----BEGIN CIPHERTEXT-----", "-----BEGIN -----", 1), "does not start with"),
+            (armored_text.replacen("\n-----END CIPHERTEXT-----", "", 1), "may be truncated"),
+            (armored_text.replacen("-----END", "-----STOP", 1), "neither a part of the body nor the end line"),
+            (format!("{}\nZm9v", armored_text), "data after the \"-----END CIPHERTEXT-----\" line"),
+            (armored_text.replacen("Zm9vYmFy", "Zm9v!mFy", 1), "character '!' at position 4"),
+        ] {
+            match armor_unwrap(&text, CIPHERTEXT_LABEL) {
+                Ok(bytes) => panic!("Expected an error for the armored text {:?}, received the bytes: {:?} (test_armor_unwrap_errors)", text, bytes),
+                Err(e) => assert!(e.to_string().contains(message), "{}", e),
+            }
+        }
+    }
+}
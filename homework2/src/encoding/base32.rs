@@ -0,0 +1,137 @@
+use crate::logic::error::OperationError;
+
+// The alphabet of the standard base32 encoding of RFC 4648, every character encodes 5 bits.
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+// The character, which pads the last group of the encoding to 8 characters.
+const BASE32_PADDING: u8 = b'=';
+
+// Encode the bytes into the base32 text of RFC 4648, every 5 bytes become 8 characters,
+// the last group of 1, 2, 3 or 4 bytes becomes 2, 4, 5 or 7 characters, which are followed by "=" to the length of 8 with the padding.
+pub fn base32_encode(bytes: &[u8], padding: bool) -> String {
+    let mut result = String::with_capacity((bytes.len() + 4) / 5 * 8);
+
+    for group in bytes.chunks(5) {
+        // Join the bytes of the group into 40 bits, the missing bytes are zero.
+        let bits = group.iter().enumerate().fold(0u64, |bits, (index, byte)| bits | (*byte as u64) << (32 - 8 * index));
+
+        // The group of n bytes is encoded by the characters, which cover its 8n bits.
+        let char_count = (8 * group.len() + 4) / 5;
+        for index in 0..char_count {
+            result.push(BASE32_ALPHABET[(bits >> (35 - 5 * index) & 0x1F) as usize] as char);
+        }
+        if padding {
+            for _index in char_count..8 {
+                result.push(BASE32_PADDING as char);
+            }
+        }
+    }
+
+    result
+}
+
+// Match the base32 character to its 5 bit value, the lowercase letters are accepted for the texts, which were read aloud.
+fn one_base32_to_u8(char: u8) -> Option<u8> {
+    match char {
+        b'A'..=b'Z' => Some(char - b'A'),
+        b'a'..=b'z' => Some(char - b'a'),
+        b'2'..=b'7' => Some(char - b'2' + 26),
+        _ => None,
+    }
+}
+
+// Decode the base32 text of RFC 4648 with or without the padding into the bytes. The characters outside the alphabet,
+// the padding anywhere except for the end of the last group of 8 characters, the last group of 1, 3 or 6 characters
+// and the last character with the unused bits, which are not zero, are denied with the position of the error.
+pub fn base32_decode(text: &str) -> Result<Vec<u8>, OperationError> {
+    let text = text.as_bytes();
+
+    // The padding is a part of the last group, the text with the padding consists only of the whole groups.
+    let data_len = text.iter().rposition(|char| *char != BASE32_PADDING).map_or(0, |index| index + 1);
+    let padding_len = text.len() - data_len;
+    if padding_len > 0 && (!matches!(padding_len, 1 | 3 | 4 | 6) || text.len() % 8 != 0) {
+        return Err(OperationError::new(&format!("Received incorrect ciphertext in base32 format, the padding at position {} does not complete the last group of 8 characters.", data_len)));
+    }
+
+    // Check the characters before the groups, the padding may be only at the end.
+    for (position, char) in text[..data_len].iter().enumerate() {
+        if one_base32_to_u8(*char).is_none() {
+            if *char == BASE32_PADDING {
+                return Err(OperationError::new(&format!("Received incorrect ciphertext in base32 format, the padding at position {} is followed by the data.", position)));
+            }
+            return Err(OperationError::new(&format!("Received incorrect ciphertext in base32 format, the character {:?} at position {} is not a part of the base32 alphabet A-Z and 2-7.", *char as char, position)));
+        }
+    }
+    if matches!(data_len % 8, 1 | 3 | 6) {
+        return Err(OperationError::new(&format!("Received incorrect ciphertext in base32 format, the length {} of the last group at position {} does not encode whole bytes.", data_len % 8, data_len - data_len % 8)));
+    }
+
+    let mut result = Vec::with_capacity(data_len / 8 * 5 + 4);
+    for (group_index, group) in text[..data_len].chunks(8).enumerate() {
+        let bits = group.iter().enumerate().fold(0u64, |bits, (index, char)| bits | (one_base32_to_u8(*char).unwrap_or(0) as u64) << (35 - 5 * index));
+
+        // The group of n characters encodes the whole bytes of its 5n bits, the unused bits of the last character must be zero.
+        let byte_count = 5 * group.len() / 8;
+        if bits & ((1u64 << (40 - 8 * byte_count)) - 1) != 0 {
+            return Err(OperationError::new(&format!("Received incorrect ciphertext in base32 format, the unused bits of the last character at position {} are not zero.", 8 * group_index + group.len() - 1)));
+        }
+        for index in 0..byte_count {
+            result.push((bits >> (32 - 8 * index)) as u8);
+        }
+    }
+
+    Ok(result)
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::encoding::base32::{base32_decode, base32_encode};
+
+    // Test base32 encoding and decoding with the test vectors of RFC 4648, with and without the padding.
+    #[test]
+    fn test_base32_rfc4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+
+        for (bytes, text) in vectors {
+            assert_eq!(base32_encode(bytes.as_bytes(), true), text);
+            assert_eq!(base32_encode(bytes.as_bytes(), false), text.trim_end_matches('='));
+            assert_eq!(base32_decode(text).unwrap(), bytes.as_bytes());
+            assert_eq!(base32_decode(text.trim_end_matches('=')).unwrap(), bytes.as_bytes());
+            assert_eq!(base32_decode(&text.to_lowercase()).unwrap(), bytes.as_bytes());
+        }
+
+        // All byte values round trip, the last characters of the alphabet are used.
+        let all_bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(base32_decode(&base32_encode(&all_bytes, true)).unwrap(), all_bytes);
+        assert_eq!(base32_encode(&[0xFF; 5], true), "77777777");
+    }
+
+    // Test base32 decoding of the incorrect texts, the errors name the position of the incorrect character or padding.
+    #[test]
+    fn test_base32_decoding_errors() {
+        for (text, message) in [
+            ("MZXW1YTB", "character '1' at position 4"),
+            ("MZXW 6YTB", "character ' ' at position 4"),
+            ("MY=====", "padding at position 2"),
+            ("MZXW6Y==", "padding at position 6"),
+            ("MY======MZXQ====", "padding at position 2 is followed by the data"),
+            ("MZXW6YTBM", "length 1 of the last group at position 8"),
+            ("MZX", "length 3 of the last group at position 0"),
+            ("MZ======", "unused bits of the last character at position 1"),
+            ("MZXW6YR=", "unused bits of the last character at position 6"),
+        ] {
+            match base32_decode(text) {
+                Ok(bytes) => panic!("Expected an error for the base32 text {:?}, received the bytes: {:?} (test_base32_decoding_errors)", text, bytes),
+                Err(e) => assert!(e.to_string().contains(message), "{}", e),
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::logic::error::OperationError;
+
+// The alphabet of the standard base64 encoding of RFC 4648, every character encodes 6 bits.
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+// The character, which pads the last group of the encoding to 4 characters.
+const BASE64_PADDING: u8 = b'=';
+
+// Encode the bytes into the base64 text of RFC 4648, every 3 bytes become 4 characters,
+// the last group of 1 or 2 bytes becomes 2 or 3 characters, which are followed by "=" to the length of 4 with the padding.
+pub fn base64_encode(bytes: &[u8], padding: bool) -> String {
+    let mut result = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+    for group in bytes.chunks(3) {
+        // Join the bytes of the group into 24 bits, the missing bytes are zero.
+        let bits = group.iter().enumerate().fold(0u32, |bits, (index, byte)| bits | (*byte as u32) << (16 - 8 * index));
+
+        // The group of n bytes is encoded by n + 1 characters.
+        for index in 0..=group.len() {
+            result.push(BASE64_ALPHABET[(bits >> (18 - 6 * index) & 0x3F) as usize] as char);
+        }
+        if padding {
+            for _index in group.len()..3 {
+                result.push(BASE64_PADDING as char);
+            }
+        }
+    }
+
+    result
+}
+
+// Match the base64 character to its 6 bit value.
+fn one_base64_to_u8(char: u8) -> Option<u8> {
+    match char {
+        b'A'..=b'Z' => Some(char - b'A'),
+        b'a'..=b'z' => Some(char - b'a' + 26),
+        b'0'..=b'9' => Some(char - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+// Decode the base64 text of RFC 4648 with or without the padding into the bytes. The characters outside the alphabet,
+// the padding anywhere except for the end of the last group of 4 characters, the last group of a single character
+// and the last character with the unused bits, which are not zero, are denied with the position of the error.
+pub fn base64_decode(text: &str) -> Result<Vec<u8>, OperationError> {
+    let text = text.as_bytes();
+
+    // The padding is a part of the last group, the text with the padding consists only of the whole groups.
+    let data_len = text.iter().rposition(|char| *char != BASE64_PADDING).map_or(0, |index| index + 1);
+    let padding_len = text.len() - data_len;
+    if padding_len > 0 && (padding_len > 2 || text.len() % 4 != 0) {
+        return Err(OperationError::new(&format!("Received incorrect ciphertext in base64 format, the padding at position {} does not complete the last group of 4 characters.", data_len)));
+    }
+
+    // Check the characters before the groups, the padding may be only at the end.
+    for (position, char) in text[..data_len].iter().enumerate() {
+        if one_base64_to_u8(*char).is_none() {
+            if *char == BASE64_PADDING {
+                return Err(OperationError::new(&format!("Received incorrect ciphertext in base64 format, the padding at position {} is followed by the data.", position)));
+            }
+            return Err(OperationError::new(&format!("Received incorrect ciphertext in base64 format, the character {:?} at position {} is not a part of the base64 alphabet A-Z, a-z, 0-9, \"+\" and \"/\".", *char as char, position)));
+        }
+    }
+    if data_len % 4 == 1 {
+        return Err(OperationError::new(&format!("Received incorrect ciphertext in base64 format, the last group at position {} has a single character, which does not encode a byte.", data_len - 1)));
+    }
+
+    let mut result = Vec::with_capacity(data_len / 4 * 3 + 2);
+    for (group_index, group) in text[..data_len].chunks(4).enumerate() {
+        let bits = group.iter().enumerate().fold(0u32, |bits, (index, char)| bits | (one_base64_to_u8(*char).unwrap_or(0) as u32) << (18 - 6 * index));
+
+        // The group of n characters encodes n - 1 bytes, the unused bits of the last character must be zero.
+        let byte_count = group.len() - 1;
+        if bits & (0xFF_FFFF >> (8 * byte_count)) != 0 {
+            return Err(OperationError::new(&format!("Received incorrect ciphertext in base64 format, the unused bits of the last character at position {} are not zero.", 4 * group_index + byte_count)));
+        }
+        for index in 0..byte_count {
+            result.push((bits >> (16 - 8 * index)) as u8);
+        }
+    }
+
+    Ok(result)
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::encoding::base64::{base64_decode, base64_encode};
+
+    // Test base64 encoding and decoding with the test vectors of RFC 4648, with and without the padding.
+    #[test]
+    fn test_base64_rfc4648_vectors() {
+        let vectors = [("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"), ("foob", "Zm9vYg=="), ("fooba", "Zm9vYmE="), ("foobar", "Zm9vYmFy")];
+
+        for (bytes, text) in vectors {
+            assert_eq!(base64_encode(bytes.as_bytes(), true), text);
+            assert_eq!(base64_encode(bytes.as_bytes(), false), text.trim_end_matches('='));
+            assert_eq!(base64_decode(text).unwrap(), bytes.as_bytes());
+            assert_eq!(base64_decode(text.trim_end_matches('=')).unwrap(), bytes.as_bytes());
+        }
+
+        // All byte values round trip, the last characters of the alphabet are used.
+        let all_bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(base64_decode(&base64_encode(&all_bytes, true)).unwrap(), all_bytes);
+        assert_eq!(base64_encode(&[0xFB, 0xFF], true), "+/8=");
+    }
+
+    // Test base64 decoding of the incorrect texts, the errors name the position of the incorrect character or padding.
+    #[test]
+    fn test_base64_decoding_errors() {
+        for (text, message) in [
+            ("Zm9v!mFy", "character '!' at position 4"),
+            ("Zm 9v", "character ' ' at position 2"),
+            ("Zg=", "padding at position 2"),
+            ("Zg===", "padding at position 2"),
+            ("Z===", "padding at position 1"),
+            ("Zg==Zm8=", "padding at position 2 is followed by the data"),
+            ("Zm9vY", "last group at position 4"),
+            ("Zh==", "unused bits of the last character at position 1"),
+            ("Zm9=", "unused bits of the last character at position 2"),
+        ] {
+            match base64_decode(text) {
+                Ok(bytes) => panic!("Expected an error for the base64 text {:?}, received the bytes: {:?} (test_base64_decoding_errors)", text, bytes),
+                Err(e) => assert!(e.to_string().contains(message), "{}", e),
+            }
+        }
+    }
+}
@@ -1,84 +1,303 @@
 use std::error::Error;
 
+use crate::encoding::armor::{armor_unwrap, armor_wrap, is_armored, CIPHERTEXT_LABEL};
 use crate::logic::error::OperationError;
 
-// Encode a half of the received byte and return character.
-fn encode_part_of_byte(int_half: u8) -> Result<char, Box<dyn Error>> {
-    match int_half {
-        10..=15 => Ok((int_half + 55) as char),
-        0..=9 => Ok((int_half + 48) as char),
-        _ => Err(Box::new(OperationError::new("An error was encountered during ciphertext encoding. Try to encrypt/decrypt again. (one_u8_to_hex)"))),
+// Module for the hex encoding and decoding adapters of the readers and the writers.
+pub mod stream;
+
+// Module for the armored texts of the keys and the ciphertexts.
+pub mod armor;
+
+// Module containing encoding/decoding into/from base64 format.
+pub mod base64;
+
+// Module containing encoding/decoding into/from base32 format.
+pub mod base32;
+
+// The base32 and base64 functions are available from the encoding module next to the hex functions.
+pub use crate::encoding::base32::{base32_decode, base32_encode};
+pub use crate::encoding::base64::{base64_decode, base64_encode};
+
+// An enumeration of the text encodings of the ciphertexts: the hex encoding doubles the length of the ciphertext,
+// the grouped hex separates every byte by the separator for the display, the base32 encoding with the padding
+// takes 8 characters of a single case for every 5 bytes, the base64 encoding with the padding takes 4 characters for every 3 bytes,
+// the armor wraps the base64 encoding into the lines between the "CIPHERTEXT" begin and end lines.
+// The hex encodings carry the case of the hex letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Hex(HexCase),
+    HexGrouped(char, HexCase),
+    Base32,
+    Base64,
+    Armor,
+}
+
+// An enumeration of the cases of the hex letters A-F, the uppercase is the default of this tool,
+// the lowercase matches the output of the other tools, e.g. openssl or xxd.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexCase {
+    Upper,
+    Lower,
+}
+
+impl Encoding {
+    // Encode the bytes of the ciphertext into the text.
+    pub fn encode(&self, bytes: &[u8]) -> Result<String, Box<dyn Error>> {
+        match self {
+            Encoding::Hex(case) => string_hex_encode_with_case(bytes, *case),
+            Encoding::HexGrouped(separator, case) => string_hex_encode_grouped_with_case(bytes, 1, *separator, *case),
+            Encoding::Base32 => Ok(base32_encode(bytes, true)),
+            Encoding::Base64 => Ok(base64_encode(bytes, true)),
+            Encoding::Armor => Ok(armor_wrap(CIPHERTEXT_LABEL, bytes)),
+        }
+    }
+
+    // Decode the text of the ciphertext into the bytes, the errors of the base32 or base64 decoding of a hex text
+    // point at the hex encoding.
+    pub fn decode(&self, text: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        let result = match self {
+            Encoding::Hex(_) | Encoding::HexGrouped(..) => return string_hex_decode(text),
+            Encoding::Base32 => base32_decode(text),
+            Encoding::Base64 => base64_decode(text),
+            Encoding::Armor => armor_unwrap(text, CIPHERTEXT_LABEL),
+        };
+
+        match result {
+            Ok(bytes) => Ok(bytes),
+            Err(e) if is_hex_text(text) => Err(Box::new(OperationError::new(&format!("{} The ciphertext looks like a hex text, choose \"encoding=hex\" for it.", e)))),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    // Detect the encoding of the ciphertext: the text starting with the begin line is armored, any other text is hex.
+    // The base32 and base64 texts have to be selected, a malformed hex text is a valid base64 text too often.
+    pub fn detect(text: &str) -> Encoding {
+        if is_armored(text) {
+            Encoding::Armor
+        } else {
+            Encoding::Hex(HexCase::Upper)
+        }
+    }
+}
+
+// Decode the ciphertext in the selected encoding or in the detected one for None. The error of the detected hex encoding
+// of a text, which is a correct base64 text, points at the base64 encoding.
+pub fn decode_ciphertext(text: &str, encoding: Option<Encoding>) -> Result<Vec<u8>, Box<dyn Error>> {
+    if let Some(encoding) = encoding {
+        return encoding.decode(text);
+    }
+
+    let encoding = Encoding::detect(text);
+    match encoding.decode(text) {
+        Err(e) if encoding != Encoding::Armor && base64_decode(text).is_ok() => Err(Box::new(OperationError::new(&format!("{} The ciphertext is decoded from hex by default, choose \"encoding=base64\" for a base64 text.", e)))),
+        result => result,
+    }
+}
+
+// Check if the text is a hex text of an even amount of the hex digits, the separators are allowed.
+fn is_hex_text(text: &str) -> bool {
+    text.bytes().all(|char| char.is_ascii_hexdigit() || is_hex_separator(char)) && text.bytes().filter(u8::is_ascii_hexdigit).count() % 2 == 0
+}
+
+// The hex symbols of the halves of the bytes in both cases, the half is the index of its symbol.
+const HEX_UPPER_TABLE: &[u8; 16] = b"0123456789ABCDEF";
+const HEX_LOWER_TABLE: &[u8; 16] = b"0123456789abcdef";
+
+// The values of the reverse table for the characters, which are not hex symbols, and for the separators of the hex pairs.
+const HEX_INVALID: u8 = 0xFF;
+const HEX_SEPARATOR: u8 = 0xFE;
+// The reverse table of the hex symbols, every byte of the text is matched to its half of the byte, to a separator or to an invalid character.
+const HEX_DECODE_TABLE: [u8; 256] = hex_decode_table();
+
+// Build the reverse table of the hex symbols of both cases, a colon, a dash and the ASCII whitespace are the separators.
+const fn hex_decode_table() -> [u8; 256] {
+    let mut table = [HEX_INVALID; 256];
+    let mut index = 0;
+    while index < 16 {
+        table[HEX_UPPER_TABLE[index] as usize] = index as u8;
+        table[HEX_LOWER_TABLE[index] as usize] = index as u8;
+        index += 1;
+    }
+    let separators = *b": -\t\n\x0C\r";
+    let mut index = 0;
+    while index < separators.len() {
+        table[separators[index] as usize] = HEX_SEPARATOR;
+        index += 1;
+    }
+
+    table
+}
+
+// Pick the table of the hex symbols of the case.
+fn hex_table(case: HexCase) -> &'static [u8; 16] {
+    match case {
+        HexCase::Upper => HEX_UPPER_TABLE,
+        HexCase::Lower => HEX_LOWER_TABLE,
+    }
+}
+
+// Check if the character separates the hex pairs: a colon, a dash or a whitespace.
+pub fn is_hex_separator(char: u8) -> bool {
+    HEX_DECODE_TABLE[char as usize] == HEX_SEPARATOR
+}
+
+// Encode a half of the received byte and return character, the letters are in the chosen case.
+fn encode_part_of_byte(int_half: u8, case: HexCase) -> Result<char, Box<dyn Error>> {
+    match hex_table(case).get(int_half as usize) {
+        Some(hex) => Ok(*hex as char),
+        None => Err(Box::new(OperationError::new("An error was encountered during ciphertext encoding. Try to encrypt/decrypt again. (one_u8_to_hex)"))),
     }
 }
 
 // Match 1 byte value to hexadecimal representation.
-fn one_u8_to_hex(int: u8) -> Result<Vec<char>, Box<dyn Error>> {
+fn one_u8_to_hex(int: u8, case: HexCase) -> Result<Vec<char>, Box<dyn Error>> {
     // Create two variables representing two halves of the byte.
     let int_left_half = int >> 4;
     let int_right_half = (int << 4) >> 4;
     let result = vec![
         // Encode left half of the byte and push the result to the vector of characters.
-        encode_part_of_byte(int_left_half)?,
+        encode_part_of_byte(int_left_half, case)?,
         // Encode right half of the byte and push the result to the vector of characters.
-        encode_part_of_byte(int_right_half)?,
+        encode_part_of_byte(int_right_half, case)?,
     ];
 
     Ok(result)
 }
 
-// Transform string consisting of ciphertext bytes into the hexadecimal string.
+// Transform string consisting of ciphertext bytes into the uppercase hexadecimal string.
 pub fn string_hex_encode(string: &[u8]) -> Result<String, Box<dyn Error>> {
-    let mut result = Vec::new();
+    string_hex_encode_with_case(string, HexCase::Upper)
+}
+
+// Transform string consisting of ciphertext bytes into the hexadecimal string with the letters in the chosen case.
+pub fn string_hex_encode_with_case(string: &[u8], case: HexCase) -> Result<String, Box<dyn Error>> {
+    let mut result = String::with_capacity(2 * string.len());
+    hex_encode_into(string, &mut result, case)?;
 
-    // Convert every character from the ciphertext into its hexadecimal representation.
-    for char in string {
-        let mut hex_pair = one_u8_to_hex(*char)?;
-        result.append(&mut hex_pair);
+    Ok(result)
+}
+
+// Transform the ciphertext bytes into the uppercase hexadecimal string with the groups of the bytes, which are split by the separator,
+// e.g. "DE:AD:BE:EF" for the groups of a single byte and a colon.
+pub fn string_hex_encode_grouped(bytes: &[u8], group_size: usize, separator: char) -> Result<String, Box<dyn Error>> {
+    string_hex_encode_grouped_with_case(bytes, group_size, separator, HexCase::Upper)
+}
+
+// Transform the ciphertext bytes into the grouped hexadecimal string with the letters in the chosen case.
+pub fn string_hex_encode_grouped_with_case(bytes: &[u8], group_size: usize, separator: char, case: HexCase) -> Result<String, Box<dyn Error>> {
+    if group_size == 0 {
+        return Err(Box::new(OperationError::new("Received the group size of 0 for the grouped hexadecimal encoding, only positive sizes are accepted.")));
     }
 
-    Ok(result.into_iter().collect())
+    let mut result = String::with_capacity(bytes.len() * 3);
+    for (index, group) in bytes.chunks(group_size).enumerate() {
+        if index > 0 {
+            result.push(separator);
+        }
+        hex_encode_into(group, &mut result, case)?;
+    }
+
+    Ok(result)
+}
+
+// Append the hexadecimal representation of the bytes to the string, the string is reused between the chunks of a stream.
+// Every half of the byte is looked up in the table of the hex symbols.
+pub fn hex_encode_into(bytes: &[u8], hex_string: &mut String, case: HexCase) -> Result<(), Box<dyn Error>> {
+    let table = hex_table(case);
+    hex_string.reserve(bytes.len() * 2);
+    for byte in bytes {
+        hex_string.push(table[(byte >> 4) as usize] as char);
+        hex_string.push(table[(byte & 0x0F) as usize] as char);
+    }
+
+    Ok(())
 }
 
 // Match hex value to the 1 byte decimal representation.
-fn one_hex_to_u8(hex: u8) -> Result<u8, Box<dyn Error>> {
-    match hex {
-        b'A'..=b'F' => Ok(hex - b'A' + 10),
-        b'a'..=b'f' => Ok(hex - b'a' + 10),
-        b'0'..=b'9' => Ok(hex - b'0'),
+pub fn one_hex_to_u8(hex: u8) -> Result<u8, Box<dyn Error>> {
+    match HEX_DECODE_TABLE[hex as usize] {
+        half @ 0..=15 => Ok(half),
         _ => Err(Box::new(OperationError::new("Received incorrect ciphertext in hexadecimal format for processing, only texts consisting of A-F, a-f and 0-9 values are accepted."))),
     }
 }
 
-// Transform string consisting of hex symbols into the vector of decimal integers of one byte.
-pub fn string_hex_decode(hex_string: &str) -> Result<Vec<u8>, Box<dyn Error>> {
-    // Check if the received string has an even length.
-    if hex_string.chars().count() % 2 != 0 {
-        return Err(Box::new(OperationError::new("Received ciphertext in hexadecimal with odd amount for characters, only texts with even amount are accepted.")));
+// Amount of the problems of the hex text, which are listed by the error of the hex decoding, the rest is counted.
+const HEX_PROBLEMS_LISTED: usize = 10;
+
+// Describe the character of the hex text at the byte offset, which is not a hex symbol.
+pub fn invalid_hex_char(char: char, position: u64) -> String {
+    format!("the character {:?} at position {} is not a hex symbol", char, position)
+}
+
+// Describe the separator of the hex text at the byte offset, which splits a pair of the hex symbols.
+pub fn split_hex_pair(separator: char, position: u64) -> String {
+    format!("the separator {:?} at position {} splits a pair of the hex symbols", separator, position)
+}
+
+// Create the error of the hex decoding, which lists the first problems of the hex text and counts the rest of them
+// up to the total amount of the problems found in the text.
+pub fn hex_decoding_error(listed: &[String], total: usize) -> OperationError {
+    let listed = &listed[..listed.len().min(HEX_PROBLEMS_LISTED)];
+    let mut listed_problems = listed.join(", ");
+    if total > listed.len() {
+        listed_problems.push_str(&format!(" and {} more", total - listed.len()));
     }
 
-    // Transform hex string into a vector of one byte values.
-    let mut decoded_cipher: Vec<u8> = Vec::new();
-    let ref_cipher: &[u8] = hex_string.as_ref();
+    OperationError::new(&format!("Received incorrect ciphertext in hexadecimal format for processing: {}. Only texts consisting of A-F, a-f and 0-9 values are accepted, the pairs may be split by \":\", \"-\" or whitespace.", listed_problems))
+}
 
-    // Check if the hex string contains allowed values, A-F, a-f and 0-9.
-    let int_iter = ref_cipher.iter();
-    for char in int_iter {
-        match char {
-            b'A'..=b'F' => continue,
-            b'a'..=b'f' => continue,
-            b'0'..=b'9' => continue,
-            _ => return Err(Box::new(OperationError::new("Received incorrect ciphertext in hexadecimal format for processing, only texts consisting of A-F, a-f and 0-9 values are accepted."))),
+// Transform string consisting of hex symbols into the vector of decimal integers of one byte.
+// The pairs of the hex symbols may be split by the separators, e.g. "DE:AD:BE:EF", "de-ad-be-ef" or "de ad be ef",
+// a separator inside a pair is denied. All of the invalid characters and separators are collected in one pass
+// with their byte offsets, so the whole text can be fixed at once. Only the first problems are described,
+// the rest of them are counted, so a long invalid text does not build a message of its size.
+pub fn string_hex_decode(hex_string: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut decoded_cipher: Vec<u8> = Vec::with_capacity(hex_string.len() / 2);
+    // The high half of the byte, whose low half is the next hex symbol.
+    let mut high_half: Option<u8> = None;
+    let mut problems = Vec::new();
+    let mut problems_total = 0;
+
+    // Every byte of the text is looked up in the reverse table in a single pass.
+    // First hex symbol of the pair is translated and then bits are shifted to the left by 4 bits.
+    // Translation of the second hex symbol is added to the first with the bitwise OR.
+    for (position, byte) in hex_string.bytes().enumerate() {
+        let half = match HEX_DECODE_TABLE[byte as usize] {
+            half @ 0..=15 => half,
+            HEX_SEPARATOR => {
+                if high_half.is_some() {
+                    problems_total += 1;
+                    if problems.len() < HEX_PROBLEMS_LISTED {
+                        problems.push(split_hex_pair(byte as char, position as u64));
+                    }
+                }
+                continue;
+            }
+            // The invalid character takes the place of a hex symbol, so the pairs of the rest of the text stay aligned.
+            // The following bytes of a multi-byte character are skipped, the character is reported at its first byte.
+            _ if !hex_string.is_char_boundary(position) => continue,
+            _ => {
+                problems_total += 1;
+                if problems.len() < HEX_PROBLEMS_LISTED {
+                    let char = hex_string[position..].chars().next().unwrap_or(byte as char);
+                    problems.push(invalid_hex_char(char, position as u64));
+                }
+                0
+            }
+        };
+        match high_half.take() {
+            Some(high) => decoded_cipher.push(high << 4 | half),
+            None => high_half = Some(half),
         }
     }
 
-    // Split hex string into the chunks of 2 hex characters and translate them into the decimal representation.
-    // First hex symbol is translated and then bits are shifted to the left by 4 bits.
-    // Translation of the second hex symbol is added to the first with the bitwise OR.
-    let char_vector = ref_cipher.chunks(2).enumerate().map(|(_index, hex_pair)| {
-        one_hex_to_u8(hex_pair[0]).unwrap() << 4 | one_hex_to_u8(hex_pair[1]).unwrap()
-    });
-    for character in char_vector {
-        decoded_cipher.push(character as u8);
+    if problems_total > 0 {
+        return Err(Box::new(hex_decoding_error(&problems, problems_total)));
+    }
+    // Check if the received string has an even amount of the hex symbols.
+    if high_half.is_some() {
+        return Err(Box::new(OperationError::new("Received ciphertext in hexadecimal with odd amount for characters, only texts with even amount are accepted.")));
     }
 
     Ok(decoded_cipher)
@@ -87,11 +306,19 @@ pub fn string_hex_decode(hex_string: &str) -> Result<Vec<u8>, Box<dyn Error>> {
 // Test module.
 #[cfg(test)]
 mod tests {
+    use std::error::Error;
     use std::str::from_utf8_unchecked;
+    use std::time::{Duration, Instant};
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     use crate::encoding::{
-        encode_part_of_byte, one_hex_to_u8, one_u8_to_hex, string_hex_decode, string_hex_encode,
+        decode_ciphertext, encode_part_of_byte, hex_decoding_error, hex_encode_into, invalid_hex_char, is_hex_separator, one_hex_to_u8, one_u8_to_hex,
+        split_hex_pair, string_hex_decode, string_hex_encode, string_hex_encode_grouped, string_hex_encode_grouped_with_case, string_hex_encode_with_case, Encoding,
+        HexCase,
     };
+    use crate::logic::error::OperationError;
 
     // Test encoding of a half of the received byte and return character,
     // while this half's value is in the range of 0-9.
@@ -102,7 +329,7 @@ mod tests {
 
         // Considering that the passed value is 4, according to the algorithm,
         // it should increase by 48, the result should be 52.
-        let encoding_result = encode_part_of_byte(test_int).unwrap() as u8;
+        let encoding_result = encode_part_of_byte(test_int, HexCase::Upper).unwrap() as u8;
 
         assert_eq!(encoding_result, 52);
     }
@@ -116,9 +343,11 @@ mod tests {
 
         // Considering that the passed value is 12, according to the algorithm,
         // it should increase by 55, the result should be 67.
-        let encoding_result = encode_part_of_byte(test_int).unwrap() as u8;
+        let encoding_result = encode_part_of_byte(test_int, HexCase::Upper).unwrap() as u8;
 
         assert_eq!(encoding_result, 67);
+        // The lowercase letter is 32 further, the result should be 99.
+        assert_eq!(encode_part_of_byte(test_int, HexCase::Lower).unwrap() as u8, 99);
     }
 
     // Test encoding of a half of the received byte and return character,
@@ -131,7 +360,7 @@ mod tests {
 
         // Considering that the passed value is 100, according to the algorithm,
         // an error should be returned as the result.
-        if let Err(e) = encode_part_of_byte(test_int) {
+        if let Err(e) = encode_part_of_byte(test_int, HexCase::Upper) {
             panic!("{}", e);
         }
     }
@@ -143,7 +372,7 @@ mod tests {
         let test_int = 100;
 
         // Encode the whole byte in to its hexadecimal representation.
-        let encoding_result = match one_u8_to_hex(test_int) {
+        let encoding_result = match one_u8_to_hex(test_int, HexCase::Upper) {
             Ok(result) => result,
             Err(e) => panic!("Failed to encode a byte into its hexadecimal representation: {} (test_one_u8_to_hex)", e),
         };
@@ -191,6 +420,121 @@ mod tests {
         assert_eq!(encoding_result, comparison_encoded_hex_string);
     }
 
+    // The previous hex encoding, which collected the pairs of the characters of every byte, for the comparison with the table-driven one.
+    fn previous_string_hex_encode(string: &[u8], case: HexCase) -> String {
+        string.iter().flat_map(|byte| one_u8_to_hex(*byte, case).unwrap()).collect()
+    }
+
+    // The previous hex decoding, which matched every character on its own, for the comparison with the table-driven one.
+    fn previous_string_hex_decode(hex_string: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut decoded_cipher = Vec::new();
+        let mut high_half: Option<u8> = None;
+        let mut problems = Vec::new();
+        for (position, char) in hex_string.char_indices() {
+            if char.is_ascii() && is_hex_separator(char as u8) {
+                if high_half.is_some() {
+                    problems.push(split_hex_pair(char, position as u64));
+                }
+                continue;
+            }
+            let half = match char {
+                'A'..='F' => char as u8 - b'A' + 10,
+                'a'..='f' => char as u8 - b'a' + 10,
+                '0'..='9' => char as u8 - b'0',
+                _ => {
+                    problems.push(invalid_hex_char(char, position as u64));
+                    0
+                }
+            };
+            match high_half.take() {
+                Some(high) => decoded_cipher.push(high << 4 | half),
+                None => high_half = Some(half),
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(Box::new(hex_decoding_error(&problems, problems.len())));
+        }
+        if high_half.is_some() {
+            return Err(Box::new(OperationError::new("Received ciphertext in hexadecimal with odd amount for characters, only texts with even amount are accepted.")));
+        }
+        Ok(decoded_cipher)
+    }
+
+    // Test the table-driven hex encoding and decoding against the previous implementation on the random buffers
+    // and on the random texts of the hex symbols, the separators and the invalid characters, the results and the errors are the same.
+    #[test]
+    fn test_hex_tables_match_previous_implementation() {
+        let mut rng = StdRng::seed_from_u64(103);
+        for buffer_len in [0, 1, 2, 15, 16, 255, 4096] {
+            let buffer: Vec<u8> = (0..buffer_len).map(|_| rng.gen()).collect();
+            for case in [HexCase::Upper, HexCase::Lower] {
+                let encoding_result = string_hex_encode_with_case(&buffer, case).unwrap();
+                assert_eq!(encoding_result, previous_string_hex_encode(&buffer, case));
+                assert_eq!(encoding_result.len(), 2 * buffer_len);
+                assert_eq!(string_hex_decode(&encoding_result).unwrap(), buffer);
+            }
+        }
+
+        let alphabet: Vec<char> = "0123456789abcdefABCDEF:- \t\r\nGgz;Жé🗝".chars().collect();
+        for _text_index in 0..2000 {
+            let text: String = (0..rng.gen_range(0..12)).map(|_| alphabet[rng.gen_range(0..alphabet.len())]).collect();
+            match (string_hex_decode(&text), previous_string_hex_decode(&text)) {
+                (Ok(bytes), Ok(previous_bytes)) => assert_eq!(bytes, previous_bytes, "{:?}", text),
+                (Err(e), Err(previous_e)) => assert_eq!(e.to_string(), previous_e.to_string(), "{:?}", text),
+                (result, previous_result) => panic!("The results differ for {:?}: {:?} and {:?} (test_hex_tables_match_previous_implementation)", text, result.is_ok(), previous_result.is_ok()),
+            }
+        }
+    }
+
+    // Test the rough speed of the table-driven hex encoding and decoding on a buffer of 10 MB.
+    // The wall-clock comparison is unreliable on a loaded machine, run with "cargo test --release -- --ignored".
+    #[test]
+    #[ignore = "timing comparison, unreliable on a loaded machine"]
+    fn test_hex_tables_speed() {
+        let buffer: Vec<u8> = (0..10 * 1024 * 1024).map(|index: usize| (index.wrapping_mul(2654435761) >> 13) as u8).collect();
+
+        let start = Instant::now();
+        let encoding_result = string_hex_encode(&buffer).unwrap();
+        let decoding_result = string_hex_decode(&encoding_result).unwrap();
+        let elapsed = start.elapsed();
+
+        assert!(decoding_result == buffer);
+        assert!(elapsed < Duration::from_secs(10), "The hex encoding and decoding of 10 MB took {:?} (test_hex_tables_speed)", elapsed);
+    }
+
+    // Test encoding of the same bytes into the uppercase and the lowercase hex, the texts differ only in the case
+    // and both are decoded back into the bytes.
+    #[test]
+    fn test_string_hex_encoding_case() {
+        let target: Vec<u8> = (0..=255).collect();
+        let upper_result = string_hex_encode_with_case(&target, HexCase::Upper).unwrap();
+        let lower_result = string_hex_encode_with_case(&target, HexCase::Lower).unwrap();
+
+        assert_eq!(upper_result, string_hex_encode(&target).unwrap());
+        assert_ne!(upper_result, lower_result);
+        assert_eq!(upper_result.to_lowercase(), lower_result);
+        assert!(lower_result.bytes().all(|char| !char.is_ascii_uppercase()));
+        assert_eq!(string_hex_decode(&upper_result).unwrap(), target);
+        assert_eq!(string_hex_decode(&lower_result).unwrap(), target);
+
+        assert_eq!(string_hex_encode_grouped_with_case(&[0xDE, 0xAD], 1, ':', HexCase::Lower).unwrap(), "de:ad");
+        assert_eq!(Encoding::Hex(HexCase::Lower).encode(&[0xBE, 0xEF]).unwrap(), "beef");
+        assert_eq!(Encoding::HexGrouped(' ', HexCase::Lower).encode(&[0xBE, 0xEF]).unwrap(), "be ef");
+    }
+
+    // Test encoding of the chunks of bytes into the same string, the result equals the encoding of the whole bytes.
+    #[test]
+    fn test_hex_encode_into() {
+        let target: Vec<u8> = (0..=255).collect();
+        let mut encoding_result = String::new();
+        for chunk in target.chunks(100) {
+            hex_encode_into(chunk, &mut encoding_result, HexCase::Upper).unwrap();
+        }
+
+        assert_eq!(encoding_result, string_hex_encode(&target).unwrap());
+    }
+
     // Test decoding a byte from its capital letter hexadecimal representation.
     #[test]
     fn test_one_capital_letter_hex_to_u8() {
@@ -269,4 +613,114 @@ mod tests {
         assert_ne!(test_string, decoding_result_string);
         assert_eq!(decoding_result_string, comparison_decoded_hex_string);
     }
+
+    // Test encoding of the ciphertexts into hex or base64 and their decoding with the selected or the default hex encoding,
+    // the hex text under the base64 encoding is denied with a hint.
+    #[test]
+    fn test_ciphertext_encoding() {
+        let target = "ifmmp".as_bytes();
+        assert_eq!(Encoding::Hex(HexCase::Upper).encode(target).unwrap(), "69666D6D70");
+        assert_eq!(Encoding::Base64.encode(target).unwrap(), "aWZtbXA=");
+
+        assert_eq!(Encoding::detect("69666D6D70"), Encoding::Hex(HexCase::Upper));
+        assert_eq!(Encoding::detect("aWZtbXA="), Encoding::Hex(HexCase::Upper));
+
+        for (text, encoding) in [("69666D6D70", None), ("aWZtbXA=", Some(Encoding::Base64)), ("aWZtbXA", Some(Encoding::Base64)), ("69666d6d70", Some(Encoding::Hex(HexCase::Upper)))] {
+            assert_eq!(decode_ciphertext(text, encoding).unwrap(), target);
+        }
+
+        // The malformed hex ciphertexts are denied instead of being decoded as base64, the base64 text receives a hint.
+        for text in ["4B4C4D4Z", "4B4C4D4", "ABC", "aWZtbXA="] {
+            assert!(decode_ciphertext(text, None).is_err(), "{}", text);
+        }
+        let error_message = decode_ciphertext("4B4C4D4Z", None).unwrap_err().to_string();
+        assert!(error_message.contains("choose \"encoding=base64\""), "{}", error_message);
+        let error_message = decode_ciphertext("4B4C4D4Z", Some(Encoding::Hex(HexCase::Upper))).unwrap_err().to_string();
+        assert!(!error_message.contains("encoding=base64"), "{}", error_message);
+
+        let error_message = decode_ciphertext("69666D6D70", Some(Encoding::Base64)).unwrap_err().to_string();
+        assert!(error_message.contains("looks like a hex text"), "{}", error_message);
+        assert!(decode_ciphertext("aWZtbXA=", Some(Encoding::Hex(HexCase::Upper))).is_err());
+
+        // The grouped hex and base32 ciphertexts, the grouped hex is decoded by default, base32 has to be selected.
+        assert_eq!(Encoding::HexGrouped(':', HexCase::Upper).encode(target).unwrap(), "69:66:6D:6D:70");
+        assert_eq!(Encoding::Base32.encode(target).unwrap(), "NFTG23LQ");
+        assert_eq!(decode_ciphertext("69:66:6D:6D:70", None).unwrap(), target);
+        assert_eq!(decode_ciphertext("nftg23lq", Some(Encoding::Base32)).unwrap(), target);
+        let error_message = decode_ciphertext("69 66 6D 6D 70", Some(Encoding::Base32)).unwrap_err().to_string();
+        assert!(error_message.contains("looks like a hex text"), "{}", error_message);
+
+        // The armored ciphertext is detected by its begin line.
+        let armored_text = Encoding::Armor.encode(target).unwrap();
+        assert_eq!(armored_text, "-----BEGIN CIPHERTEXT-----\naWZtbXA=\n-----END CIPHERTEXT-----");
+        assert_eq!(Encoding::detect(&armored_text), Encoding::Armor);
+        assert_eq!(decode_ciphertext(&armored_text, None).unwrap(), target);
+        assert!(decode_ciphertext(&armored_text.replace("CIPHERTEXT", "RSA PUBLIC KEY"), None).is_err());
+    }
+
+    // Test decoding of the hex texts with the separators between the pairs, the separator inside a pair is denied.
+    #[test]
+    fn test_string_hex_decoding_separators() {
+        let target = vec![0xDE, 0xAD, 0xBE, 0xEF];
+        for text in ["deadbeef", "de ad be ef", "DE:AD:BE:EF", "DE-AD-BE-EF", " dead\tbeef\n", "DE:AD be-ef"] {
+            assert_eq!(string_hex_decode(text).unwrap(), target, "{}", text);
+        }
+
+        for (text, message) in [("d:ead", "separator ':' at position 1"), ("dea d", "separator ' ' at position 3"), ("de:ad:b", "odd amount"), ("de;ad", "character ';' at position 2")] {
+            match string_hex_decode(text) {
+                Ok(bytes) => panic!("Expected an error for the hex text {:?}, received the bytes: {:?} (test_string_hex_decoding_separators)", text, bytes),
+                Err(e) => assert!(e.to_string().contains(message), "{}", e),
+            }
+        }
+    }
+
+    // Test the positioned errors of the hex decoding: the invalid characters at the start, in the middle and at the end
+    // are reported with their byte offsets, all of them are collected in one pass, the odd length still errors
+    // and the valid texts are decoded as before.
+    #[test]
+    fn test_string_hex_decoding_errors() {
+        for (text, message) in [
+            ("G4C4D", "the character 'G' at position 0 is not a hex symbol"),
+            ("4B4X4D", "the character 'X' at position 3 is not a hex symbol"),
+            ("4B4C4?", "the character '?' at position 5 is not a hex symbol"),
+            // The offset of the character after a multi-byte character is the byte offset.
+            ("4BЖ4CZ", "the character 'Ж' at position 2 is not a hex symbol, the character 'Z' at position 6 is not a hex symbol"),
+            ("xx:4B:y:z", "the character 'x' at position 0 is not a hex symbol, the character 'x' at position 1 is not a hex symbol, the character 'y' at position 6"),
+            ("4:B4C", "the separator ':' at position 1 splits a pair of the hex symbols"),
+        ] {
+            match string_hex_decode(text) {
+                Ok(bytes) => panic!("Expected an error for the hex text {:?}, received the bytes: {:?} (test_string_hex_decoding_errors)", text, bytes),
+                Err(e) => assert!(e.to_string().contains(message), "{}", e),
+            }
+        }
+
+        // The amount of the listed problems is limited, the rest is counted.
+        let error_message = string_hex_decode(&"zz".repeat(8)).unwrap_err().to_string();
+        assert!(error_message.contains("at position 9 is not a hex symbol and 6 more"), "{}", error_message);
+        assert!(!error_message.contains("position 10"), "{}", error_message);
+        // The message of a long invalid text keeps its size.
+        let error_message = string_hex_decode(&"z".repeat(100000)).unwrap_err().to_string();
+        assert!(error_message.contains("at position 9 is not a hex symbol and 99990 more"), "{}", error_message);
+        assert!(error_message.len() < 1000, "{}", error_message);
+
+        let error_message = string_hex_decode("4B4C4").unwrap_err().to_string();
+        assert!(error_message.contains("odd amount"), "{}", error_message);
+
+        assert_eq!(string_hex_decode("4B4c00fF").unwrap(), vec![0x4B, 0x4C, 0x00, 0xFF]);
+        assert_eq!(string_hex_decode("").unwrap(), Vec::<u8>::new());
+    }
+
+    // Test encoding of the bytes into the hex text of the groups, which are split by the separator.
+    #[test]
+    fn test_string_hex_encoding_grouped() {
+        let target = [0xDE, 0xAD, 0xBE, 0xEF, 0x01];
+        assert_eq!(string_hex_encode_grouped(&target, 1, ':').unwrap(), "DE:AD:BE:EF:01");
+        assert_eq!(string_hex_encode_grouped(&target, 2, ' ').unwrap(), "DEAD BEEF 01");
+        assert_eq!(string_hex_encode_grouped(&target, 8, '-').unwrap(), "DEADBEEF01");
+        assert_eq!(string_hex_encode_grouped(&[], 1, ':').unwrap(), "");
+        assert!(string_hex_encode_grouped(&target, 0, ':').is_err());
+
+        // The grouped hex text is decoded back into the same bytes.
+        assert_eq!(string_hex_decode(&string_hex_encode_grouped(&target, 2, '-').unwrap()).unwrap(), target);
+    }
 }
@@ -0,0 +1,254 @@
+use std::io::{Error, ErrorKind, Read, Write};
+
+use crate::encoding::{hex_decoding_error, hex_encode_into, invalid_hex_char, is_hex_separator, one_hex_to_u8, split_hex_pair, HexCase};
+use crate::logic::error::OperationError;
+
+// Size of the buffer of the hex text, which is read at once by the hex reader from the inner reader.
+const HEX_READER_BUFFER_SIZE: usize = 64 * 1024;
+
+// Adapter, which encodes the bytes written to it into the hex text of the chosen case and forwards the text to the inner writer.
+// Every written byte is encoded at once, so the adapter never keeps a part of the data between the writes.
+pub struct HexWriter<W: Write> {
+    inner: W,
+    case: HexCase,
+    hex_buffer: String,
+}
+
+impl<W: Write> HexWriter<W> {
+    // Wrap the inner writer, which receives the hex text.
+    pub fn new(inner: W, case: HexCase) -> HexWriter<W> {
+        HexWriter { inner, case, hex_buffer: String::new() }
+    }
+
+    // Unwrap the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for HexWriter<W> {
+    // Encode all of the bytes and write their hex text to the inner writer, the bytes are counted as written only with their whole text.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        self.hex_buffer.clear();
+        if let Err(e) = hex_encode_into(buf, &mut self.hex_buffer, self.case) {
+            return Err(Error::new(ErrorKind::InvalidInput, e.to_string()));
+        }
+        self.inner.write_all(self.hex_buffer.as_bytes())?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.inner.flush()
+    }
+}
+
+// Adapter, which reads the hex text from the inner reader and yields the decoded bytes. The pairs of the hex symbols
+// may be split across the reads of the inner reader and by the separators between the pairs, as for string_hex_decode().
+// The invalid character, the separator inside a pair and the odd amount of the hex symbols are returned as the errors
+// of the kind InvalidData, whose inner error is the OperationError with the position of the problem in the hex text.
+// The bytes before the invalid character are yielded first, the error is returned by the next read.
+pub struct HexReader<R: Read> {
+    inner: R,
+    hex_buffer: Vec<u8>,
+    // The high half of the byte, whose low half is not read yet.
+    high_half: Option<u8>,
+    // The position of the next read hex symbol in the hex text for the errors.
+    hex_position: u64,
+    pending_error: Option<OperationError>,
+}
+
+impl<R: Read> HexReader<R> {
+    // Wrap the inner reader of the hex text.
+    pub fn new(inner: R) -> HexReader<R> {
+        HexReader { inner, hex_buffer: Vec::new(), high_half: None, hex_position: 0, pending_error: None }
+    }
+
+    // Unwrap the inner reader, the hex text, which was read and not decoded yet, is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+// Turn the error of the hex text into the error of the reader, which carries it as the inner error.
+fn hex_read_error(error: OperationError) -> Error {
+    Error::new(ErrorKind::InvalidData, error)
+}
+
+// Take the error of the hex text back out of the error of the reader, so the caller receives the OperationError as
+// the other errors of the hex decoding. The other errors of the reader are returned as they are.
+pub fn unwrap_hex_error(error: Error) -> Box<dyn std::error::Error> {
+    if !error.get_ref().map_or(false, |inner| inner.is::<OperationError>()) {
+        return Box::new(error);
+    }
+    error.into_inner().expect("The error of the hex text has to carry the inner error (unwrap_hex_error)")
+}
+
+impl<R: Read> Read for HexReader<R> {
+    // Read the hex text of at most two symbols for every byte of the buffer, so the decoded bytes with the carried half always fit.
+    // The reads, which produce only the separators or a single half, are repeated, since no bytes mean the end of the hex text.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        if let Some(error) = self.pending_error.take() {
+            return Err(hex_read_error(error));
+        }
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.hex_buffer.is_empty() {
+            self.hex_buffer = vec![0u8; HEX_READER_BUFFER_SIZE];
+        }
+
+        loop {
+            let hex_len = self.inner.read(&mut self.hex_buffer[..HEX_READER_BUFFER_SIZE.min(2 * buf.len())])?;
+            if hex_len == 0 {
+                if self.high_half.take().is_some() {
+                    return Err(hex_read_error(OperationError::new("Received ciphertext in hexadecimal with odd amount for characters, only texts with even amount are accepted.")));
+                }
+                return Ok(0);
+            }
+
+            let mut decoded_len = 0;
+            for (index, hex) in self.hex_buffer[..hex_len].iter().enumerate() {
+                let position = self.hex_position + index as u64;
+                let half = match one_hex_to_u8(*hex) {
+                    Ok(half) => half,
+                    Err(_) if is_hex_separator(*hex) && self.high_half.is_none() => continue,
+                    Err(_) => {
+                        let problem = match is_hex_separator(*hex) {
+                            true => split_hex_pair(*hex as char, position),
+                            false => invalid_hex_char(*hex as char, position),
+                        };
+                        // The error follows the decoded bytes of this read, the rest of the hex text is not decoded.
+                        self.hex_position = position;
+                        if decoded_len == 0 {
+                            return Err(hex_read_error(hex_decoding_error(&[problem], 1)));
+                        }
+                        self.pending_error = Some(hex_decoding_error(&[problem], 1));
+                        return Ok(decoded_len);
+                    }
+                };
+                match self.high_half.take() {
+                    Some(high) => {
+                        buf[decoded_len] = high << 4 | half;
+                        decoded_len += 1;
+                    }
+                    None => self.high_half = Some(half),
+                }
+            }
+            self.hex_position += hex_len as u64;
+
+            if decoded_len > 0 {
+                return Ok(decoded_len);
+            }
+        }
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use std::io::{ErrorKind, Read, Write};
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::encoding::stream::{HexReader, HexWriter};
+    use crate::encoding::{string_hex_decode, string_hex_encode_with_case, HexCase};
+    use crate::logic::error::OperationError;
+
+    // Reader, which returns the parts of the text of the given lengths on its reads.
+    struct PartsReader {
+        text: Vec<u8>,
+        part_lens: Vec<usize>,
+    }
+
+    impl Read for PartsReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let part_len = match self.part_lens.first() {
+                Some(part_len) => *part_len,
+                None => self.text.len(),
+            };
+            let read_len = part_len.min(buf.len()).min(self.text.len());
+            buf[..read_len].copy_from_slice(&self.text[..read_len]);
+            self.text.drain(..read_len);
+            if !self.part_lens.is_empty() {
+                self.part_lens.remove(0);
+            }
+            Ok(read_len)
+        }
+    }
+
+    // Test the round trip of the random buffers through the adapters, the hex text and the bytes equal the ones
+    // of the buffer-based functions for the writes and the reads of any size.
+    #[test]
+    fn test_hex_adapters_round_trip() {
+        let mut rng = StdRng::seed_from_u64(104);
+        for buffer_len in [0, 1, 7, 4096, 100_003] {
+            let buffer: Vec<u8> = (0..buffer_len).map(|_| rng.gen()).collect();
+            for case in [HexCase::Upper, HexCase::Lower] {
+                let mut hex_writer = HexWriter::new(Vec::new(), case);
+                for part in buffer.chunks(rng.gen_range(1..5000)) {
+                    hex_writer.write_all(part).unwrap();
+                }
+                hex_writer.flush().unwrap();
+                let hex_text = hex_writer.into_inner();
+                assert!(hex_text == string_hex_encode_with_case(&buffer, case).unwrap().into_bytes());
+
+                let mut hex_reader = HexReader::new(hex_text.as_slice());
+                let mut decoded_bytes = Vec::new();
+                let mut read_buffer = vec![0u8; rng.gen_range(1..3000)];
+                loop {
+                    let read_len = hex_reader.read(&mut read_buffer).unwrap();
+                    if read_len == 0 {
+                        break;
+                    }
+                    decoded_bytes.extend_from_slice(&read_buffer[..read_len]);
+                }
+                assert!(decoded_bytes == string_hex_decode(std::str::from_utf8(&hex_text).unwrap()).unwrap());
+                assert!(decoded_bytes == buffer);
+            }
+        }
+    }
+
+    // Test the pairs of the hex symbols, which are split across the reads of the inner reader and by the separators,
+    // the reads of only the separators or of a single half do not end the text.
+    #[test]
+    fn test_hex_reader_split_pairs() {
+        let reader = PartsReader { text: b"4B4C:4D\n".to_vec(), part_lens: vec![1, 2, 1, 1, 1, 1] };
+        let mut decoded_bytes = Vec::new();
+        HexReader::new(reader).read_to_end(&mut decoded_bytes).unwrap();
+        assert_eq!(decoded_bytes, vec![0x4B, 0x4C, 0x4D]);
+
+        // A single byte of the buffer reads two hex symbols at most.
+        let mut hex_reader = HexReader::new(&b"  DE:ad"[..]);
+        let mut read_buffer = [0u8; 1];
+        assert_eq!(hex_reader.read(&mut read_buffer).unwrap(), 1);
+        assert_eq!(read_buffer[0], 0xDE);
+        assert_eq!(hex_reader.read(&mut read_buffer).unwrap(), 1);
+        assert_eq!(read_buffer[0], 0xAD);
+        assert_eq!(hex_reader.read(&mut read_buffer).unwrap(), 0);
+    }
+
+    // Test the errors of the hex reader: the invalid character in the middle of the stream errors after the bytes before it,
+    // at its position in the whole text, the split pair and the odd amount of the hex symbols error as well.
+    #[test]
+    fn test_hex_reader_errors() {
+        let reader = PartsReader { text: b"4B4C4D4G4E".to_vec(), part_lens: vec![4, 6] };
+        let mut hex_reader = HexReader::new(reader);
+        let mut read_buffer = [0u8; 16];
+        assert_eq!(hex_reader.read(&mut read_buffer).unwrap(), 2);
+        assert_eq!(hex_reader.read(&mut read_buffer).unwrap(), 1);
+        assert_eq!(read_buffer[0], 0x4D);
+        let error = hex_reader.read(&mut read_buffer).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        let inner_error = error.into_inner().unwrap();
+        assert!(inner_error.downcast_ref::<OperationError>().is_some());
+        assert!(inner_error.to_string().contains("the character 'G' at position 7 is not a hex symbol"), "{}", inner_error);
+
+        for (text, message) in [(&b"4B4:C"[..], "the separator ':' at position 3 splits a pair"), (&b"4B4C4"[..], "odd amount")] {
+            let error = HexReader::new(text).read_to_end(&mut Vec::new()).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::InvalidData);
+            assert!(error.to_string().contains(message), "{}", error);
+        }
+    }
+}
@@ -2,10 +2,10 @@
 #![allow(warnings)]
 
 // Module containing Caesar/Vigenere encryption/decryption operations.
-mod crypto;
+pub mod crypto;
 
 // Module containing encoding/decoding into/from hexadecimal format.
-mod encoding;
+pub mod encoding;
 
 // Tool's logic.
 pub mod logic;
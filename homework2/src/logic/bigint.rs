@@ -1,17 +1,56 @@
 use crate::logic::error::OperationError;
 
+// Implement a binary operator for owned BigInts and mixes of owned and borrowed ones,
+// delegating to the implementation of the operator for two borrowed BigInts, e.g. "a + b", "a + &b", "&a + b".
+// Must be defined before the declarations of the submodules, which use it.
+macro_rules! forward_binary_operator {
+    ($operator_trait:ident, $operator_method:ident) => {
+        impl std::ops::$operator_trait<crate::logic::bigint::ChonkerInt> for crate::logic::bigint::ChonkerInt {
+            type Output = crate::logic::bigint::ChonkerInt;
+
+            fn $operator_method(self, other: crate::logic::bigint::ChonkerInt) -> Self::Output {
+                std::ops::$operator_trait::$operator_method(&self, &other)
+            }
+        }
+
+        impl<'a> std::ops::$operator_trait<&'a crate::logic::bigint::ChonkerInt> for crate::logic::bigint::ChonkerInt {
+            type Output = crate::logic::bigint::ChonkerInt;
+
+            fn $operator_method(self, other: &'a crate::logic::bigint::ChonkerInt) -> Self::Output {
+                std::ops::$operator_trait::$operator_method(&self, other)
+            }
+        }
+
+        impl<'a> std::ops::$operator_trait<crate::logic::bigint::ChonkerInt> for &'a crate::logic::bigint::ChonkerInt {
+            type Output = crate::logic::bigint::ChonkerInt;
+
+            fn $operator_method(self, other: crate::logic::bigint::ChonkerInt) -> Self::Output {
+                std::ops::$operator_trait::$operator_method(self, &other)
+            }
+        }
+    };
+}
+
 pub mod addition;
+pub mod barrett;
 pub mod comparison;
+pub mod continued_fraction;
 pub mod conversion;
 pub mod division;
 pub mod exponentiation;
 pub mod factor;
 pub mod gcd;
 pub mod modulus;
+mod montgomery;
 pub mod multiplication;
 pub mod negation;
 pub mod prime;
+pub mod primitive;
 pub mod randomisation;
+pub mod root;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod shift;
 pub mod subtraction;
 
 // Definitions for a custom BigInt.
@@ -48,35 +87,38 @@ pub mod subtraction;
 // ⠀⠀⠀⡐⠈⠁⠈⠛⣛⠿⠟⠑⠈⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 // ⠀⠀⠉⠑⠒⠀⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 
-// Constant declaring radix/base of separate digits in the BigInt's vector;
+// Constant declaring radix/base of separate limbs in the BigInt's vector, every limb packs 9 decimal digits;
+// amount of decimal digits stored in a single limb;
 // and value difference between decimal numbers and their ASCII character representation.
-const RADIX: i8 = 10;
-const ASCII_DIFF: i8 = 48;
+const RADIX: u32 = 1_000_000_000;
+const RADIX_DIGITS: usize = 9;
+const ASCII_DIFF: u8 = 48;
 
 // Enumeration determining BigInt's sign.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BigIntSign {
     Positive,
     Zero,
     Negative,
 }
 
-// Define BigInt struct, storing separate digits in 1 byte signed integers in an array,
-// in a little endian format.
-#[derive(Debug, PartialEq, Eq)]
+// Define BigInt struct, storing limbs of 9 decimal digits (base 10^9) in 4 byte unsigned integers in an array,
+// in a little endian format. The representation is canonical, thus the derived hash agrees with the equality.
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct ChonkerInt {
-    digits: Vec<i8>,
+    digits: Vec<u32>,
     sign: BigIntSign,
 }
 
-// Retrieve remainder of the digit after adjusting it to radix.
-fn clip(digit: i8) -> i8 {
-    digit.rem_euclid(RADIX)
+// Retrieve remainder of the limb after adjusting it to radix.
+fn clip(limb: u64) -> u32 {
+    (limb % RADIX as u64) as u32
 }
 
-// Retrieve overflow of the digit.
-fn overflow(digit: i8) -> i8 {
-    (digit - clip(digit)) / RADIX
+// Retrieve overflow of the limb.
+fn overflow(limb: u64) -> u64 {
+    limb / RADIX as u64
 }
 
 // Implement methods for BigInt.
@@ -89,8 +131,31 @@ impl ChonkerInt {
         ChonkerInt { digits, sign }
     }
 
-    // Get an immutable reference to the internal vector of digits.
-    pub fn get_vec(&self) -> &[i8] {
+    // Get the separate decimal digits of the BigInt in a little endian format, expanded from the internal limbs.
+    // Leading zeros are not included, zero has no digits.
+    // Example: ChonkerInt::from(-123).get_vec() == vec![3, 2, 1].
+    pub fn get_vec(&self) -> Vec<i8> {
+        let mut decimal_digits = vec![];
+
+        for limb in self.significant_digits() {
+            let mut limb = *limb;
+            for _ in 0..RADIX_DIGITS {
+                decimal_digits.push((limb % 10) as i8);
+                limb /= 10;
+            }
+        }
+
+        // Remove leading zeros of the most significant limb.
+        while decimal_digits.last() == Some(&0) {
+            decimal_digits.pop();
+        }
+
+        decimal_digits
+    }
+
+    // Get an immutable reference to the internal vector of base 10^9 limbs, in a little endian format.
+    // Example: ChonkerInt::from(1_000_000_002).get_limbs() == [2, 1].
+    pub fn get_limbs(&self) -> &[u32] {
         &self.digits
     }
 
@@ -99,39 +164,128 @@ impl ChonkerInt {
         &self.sign
     }
 
-    // Normalize BigInt, remove leading zeros.
-    fn normalize(&mut self) {
-        // Check if there are any digits in the vector.
-        if self.digits.is_empty() {
-            return;
+    // Check if the BigInt is zero, leading zeros are ignored.
+    // Example: ChonkerInt::new().is_zero() == true, ChonkerInt::from(-3).is_zero() == false.
+    pub fn is_zero(&self) -> bool {
+        self.significant_digits().is_empty()
+    }
+
+    // Check if the BigInt is exactly one.
+    // Example: ChonkerInt::from(1).is_one() == true, ChonkerInt::from(-1).is_one() == false.
+    pub fn is_one(&self) -> bool {
+        self.sign == BigIntSign::Positive && self.significant_digits() == [1]
+    }
+
+    // Check if the BigInt is smaller than zero.
+    // Example: ChonkerInt::from(-3).is_negative() == true, ChonkerInt::new().is_negative() == false.
+    pub fn is_negative(&self) -> bool {
+        self.sign == BigIntSign::Negative && !self.is_zero()
+    }
+
+    // Check if the BigInt is bigger than zero.
+    // Example: ChonkerInt::from(3).is_positive() == true, ChonkerInt::new().is_positive() == false.
+    pub fn is_positive(&self) -> bool {
+        self.sign == BigIntSign::Positive && !self.is_zero()
+    }
+
+    // Check if the BigInt is even, only the least significant limb is checked, zero is even.
+    // Example: ChonkerInt::from(-4).is_even() == true, ChonkerInt::new().is_even() == true.
+    pub fn is_even(&self) -> bool {
+        match self.digits.first() {
+            Some(digit) => digit % 2 == 0,
+            None => true,
         }
+    }
 
-        let mut digit_index = self.digits.len() - 1;
+    // Check if the BigInt is odd, only the least significant limb is checked.
+    // Example: ChonkerInt::from(-3).is_odd() == true, ChonkerInt::from(10).is_odd() == false.
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
 
-        while self.digits[digit_index] == 0 {
-            self.digits.remove(digit_index);
-            if digit_index == 0 {
-                return;
+    // Get the absolute value of the BigInt.
+    // Example: ChonkerInt::from(-42).abs() == ChonkerInt::from(42).
+    pub fn abs(&self) -> ChonkerInt {
+        let mut absolute = (*self).clone();
+        if absolute.sign == BigIntSign::Negative {
+            absolute.set_positive_sign();
+        }
+
+        absolute
+    }
+
+    // Get the sign of the BigInt as a number: -1 for negative, 0 for zero, 1 for positive.
+    // Example: ChonkerInt::from(-42).signum() == -1, ChonkerInt::new().signum() == 0.
+    pub fn signum(&self) -> i8 {
+        if self.is_zero() {
+            0
+        } else if self.sign == BigIntSign::Negative {
+            -1
+        } else {
+            1
+        }
+    }
+
+    // Get the number of decimal digits of the BigInt, leading zeros and the sign are not counted.
+    // Zero is written with a single digit "0", thus its digit count is 1.
+    // Example: ChonkerInt::from(-12345).digit_count() == 5, ChonkerInt::new().digit_count() == 1.
+    pub fn digit_count(&self) -> usize {
+        let limbs = self.significant_digits();
+
+        match limbs.last() {
+            // Every limb below the most significant one holds exactly 9 decimal digits.
+            Some(most_significant_limb) => {
+                let mut top_length = 0;
+                let mut limb = *most_significant_limb;
+                while limb > 0 {
+                    top_length += 1;
+                    limb /= 10;
+                }
+
+                (limbs.len() - 1) * RADIX_DIGITS + top_length
             }
-            digit_index -= 1;
+            None => 1,
+        }
+    }
+
+    // Get the number of bits required to represent the absolute value of the BigInt, zero has bit length of 0.
+    // Example: ChonkerInt::from(255).bit_length() == 8, ChonkerInt::from(-256).bit_length() == 9.
+    pub fn bit_length(&self) -> u64 {
+        // Convert the magnitude into bytes by repeated division by 256, then count the bits of the most significant byte.
+        let bytes = self.to_bytes_be();
+
+        match bytes.first() {
+            Some(most_significant_byte) => (bytes.len() as u64 - 1) * 8 + (8 - most_significant_byte.leading_zeros() as u64),
+            None => 0,
         }
     }
 
-    // Push a new digit to the vector of digits.
-    fn push(&mut self, digit: i8) -> Result<(), Box<dyn std::error::Error>> {
-        // Check if the digit for insertion is in a valid range of 0-9.
+    // Normalize BigInt, remove leading zeros with a single truncation of the vector.
+    // If no significant limbs are left, the BigInt is zero and its sign is set to zero as well.
+    fn normalize(&mut self) {
+        let significant_length = self.significant_digits().len();
+        self.digits.truncate(significant_length);
+
+        if self.digits.is_empty() {
+            self.sign = BigIntSign::Zero;
+        }
+    }
+
+    // Push a new limb to the vector of limbs.
+    fn push(&mut self, digit: u32) -> Result<(), Box<dyn std::error::Error>> {
+        // Check if the limb for insertion is in a valid range of 0-999999999.
         match digit {
-            0..=9 => {
+            0..=999_999_999 => {
                 self.digits.push(digit);
                 Ok(())
             }
-            _ => Err(Box::new(OperationError::new("Did not receive a correct digit for insertion into the BigInt's vector. Allowed values are in range of 0-9."))),
+            _ => Err(Box::new(OperationError::new("Did not receive a correct limb for insertion into the BigInt's vector. Allowed values are in range of 0-999999999."))),
         }
     }
 
-    // Push vector of digits.
+    // Push vector of limbs.
     // Note: leading zeros are allowed.
-    fn push_vec(&mut self, digits_slice: &[i8]) {
+    fn push_vec(&mut self, digits_slice: &[u32]) {
         // Check if the vector is added to an empty/zero BigInt.
         if *self == ChonkerInt::new() {
             self.set_positive_sign();
@@ -217,6 +371,156 @@ mod tests {
         let comparison_digits_vector: Vec<i8> = vec![3, 2, 1];
 
         assert_eq!(*bigint.get_vec(), comparison_digits_vector);
+
+        // Digits are expanded from the limbs, zeros inside the lower limbs are preserved.
+        let limb_boundary_bigint = ChonkerInt::from(String::from("-1000000002"));
+        assert_eq!(limb_boundary_bigint.get_vec(), vec![2, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(limb_boundary_bigint.get_limbs(), [2, 1]);
+    }
+
+    // Test arithmetic and conversions of BigInts around the boundaries of the base 10^9 limbs.
+    #[test]
+    fn test_bigint_limb_boundaries() {
+        let boundaries: [u128; 4] = [1_000_000_000, 1_000_000_000_000_000_000, 999_999_999, 999_999_999_999_999_999];
+
+        for boundary in boundaries {
+            for offset in 0..3u128 {
+                for value in [boundary - offset, boundary + offset] {
+                    let bigint = ChonkerInt::from(value);
+
+                    // Conversions into and from strings and primitive integers.
+                    assert_eq!(bigint.to_string(), value.to_string());
+                    assert_eq!(value.to_string().parse::<ChonkerInt>().unwrap(), bigint);
+                    assert_eq!(bigint.to_u128().unwrap(), value);
+                    assert_eq!(bigint.digit_count(), value.to_string().len());
+
+                    // Carries and borrows across the limbs.
+                    assert_eq!((&bigint + &ChonkerInt::from(1)).to_u128().unwrap(), value + 1);
+                    assert_eq!((&bigint - &ChonkerInt::from(1)).to_u128().unwrap(), value - 1);
+                    assert_eq!((&bigint * &bigint).to_string(), (value * value).to_string());
+                    assert_eq!((&(&bigint * &bigint) / &bigint), bigint);
+                    assert_eq!((&bigint / &ChonkerInt::from(7)).to_u128().unwrap(), value / 7);
+                    assert_eq!((&bigint % &ChonkerInt::from(999_999_937)).to_u128().unwrap(), value % 999_999_937);
+                }
+            }
+        }
+
+        // Zeros inside the lower limbs are printed with padding.
+        assert_eq!(ChonkerInt::from(String::from("-1000000000000000001")).to_string(), "-1000000000000000001");
+        assert_eq!(ChonkerInt::from(1_000_000_000_000_000_000u64).get_limbs(), [0, 0, 1]);
+    }
+
+    // Test the zero and one predicates.
+    #[test]
+    fn test_bigint_is_zero_and_is_one() {
+        assert!(ChonkerInt::new().is_zero());
+        assert!(ChonkerInt::from(0).is_zero());
+        assert!(!ChonkerInt::from(1).is_zero());
+        assert!(!ChonkerInt::from(-1).is_zero());
+
+        // Zero with leading zeros is still zero.
+        let zero_with_leading_zeros = ChonkerInt { digits: vec![0, 0], sign: BigIntSign::Positive };
+        assert!(zero_with_leading_zeros.is_zero());
+
+        assert!(ChonkerInt::from(1).is_one());
+        assert!(ChonkerInt { digits: vec![1, 0, 0], sign: BigIntSign::Positive }.is_one());
+        assert!(!ChonkerInt::from(-1).is_one());
+        assert!(!ChonkerInt::new().is_one());
+        assert!(!ChonkerInt::from(11).is_one());
+    }
+
+    // Test the sign predicates and signum.
+    #[test]
+    fn test_bigint_sign_predicates_and_signum() {
+        let positive_bigint = ChonkerInt::from(String::from("123456789012345678901234567890"));
+        let negative_bigint = ChonkerInt::from(String::from("-123456789012345678901234567890"));
+        let zero_bigint = ChonkerInt::new();
+
+        assert!(positive_bigint.is_positive());
+        assert!(!positive_bigint.is_negative());
+        assert_eq!(positive_bigint.signum(), 1);
+
+        assert!(negative_bigint.is_negative());
+        assert!(!negative_bigint.is_positive());
+        assert_eq!(negative_bigint.signum(), -1);
+
+        assert!(!zero_bigint.is_positive());
+        assert!(!zero_bigint.is_negative());
+        assert_eq!(zero_bigint.signum(), 0);
+
+        // A negative zero with leading zeros is neither positive nor negative.
+        let negative_zero = ChonkerInt { digits: vec![0], sign: BigIntSign::Negative };
+        assert!(!negative_zero.is_negative());
+        assert_eq!(negative_zero.signum(), 0);
+    }
+
+    // Test the parity predicates.
+    #[test]
+    fn test_bigint_is_even_and_is_odd() {
+        for value in -25i64..=25 {
+            let bigint = ChonkerInt::from(value);
+            assert_eq!(bigint.is_even(), value % 2 == 0);
+            assert_eq!(bigint.is_odd(), value % 2 != 0);
+        }
+
+        assert!(ChonkerInt::new().is_even());
+        assert!(!ChonkerInt::new().is_odd());
+        assert!(ChonkerInt::from(String::from("-98765432109876543210")).is_even());
+        assert!(ChonkerInt::from(String::from("98765432109876543211")).is_odd());
+    }
+
+    // Test the absolute value of the BigInt.
+    #[test]
+    fn test_bigint_abs() {
+        assert_eq!(ChonkerInt::from(-42).abs(), ChonkerInt::from(42));
+        assert_eq!(ChonkerInt::from(42).abs(), ChonkerInt::from(42));
+        assert_eq!(ChonkerInt::new().abs(), ChonkerInt::new());
+        assert_eq!(
+            ChonkerInt::from(String::from("-123456789012345678901234567890")).abs(),
+            ChonkerInt::from(String::from("123456789012345678901234567890"))
+        );
+    }
+
+    // Test the decimal digit count of the BigInt.
+    #[test]
+    fn test_bigint_digit_count() {
+        assert_eq!(ChonkerInt::new().digit_count(), 1);
+        assert_eq!(ChonkerInt { digits: vec![0, 0], sign: BigIntSign::Positive }.digit_count(), 1);
+        assert_eq!(ChonkerInt::from(7).digit_count(), 1);
+        assert_eq!(ChonkerInt::from(-12345).digit_count(), 5);
+        assert_eq!(ChonkerInt { digits: vec![123, 0, 0], sign: BigIntSign::Positive }.digit_count(), 3);
+
+        // Powers of ten.
+        let mut power_of_ten = ChonkerInt::from(1);
+        for exponent in 0..60 {
+            assert_eq!(power_of_ten.digit_count(), exponent + 1);
+            assert_eq!((&power_of_ten - 1u64).digit_count(), exponent.max(1));
+            power_of_ten = &power_of_ten * 10u64;
+        }
+    }
+
+    // Test the bit length of the BigInt.
+    #[test]
+    fn test_bigint_bit_length() {
+        assert_eq!(ChonkerInt::new().bit_length(), 0);
+        assert_eq!(ChonkerInt::from(1).bit_length(), 1);
+        assert_eq!(ChonkerInt::from(-1).bit_length(), 1);
+        assert_eq!(ChonkerInt::from(255).bit_length(), 8);
+        assert_eq!(ChonkerInt::from(-256).bit_length(), 9);
+
+        // Powers of two.
+        let mut power_of_two = ChonkerInt::from(1);
+        for exponent in 0..200u64 {
+            assert_eq!(power_of_two.bit_length(), exponent + 1);
+            assert_eq!((-&power_of_two).bit_length(), exponent + 1);
+            assert_eq!((&power_of_two - 1u64).bit_length(), exponent);
+            power_of_two = &power_of_two * 2u64;
+        }
+
+        // Compare against the primitive integers.
+        for value in [3u64, 10, 1000, 65537, u32::MAX as u64, u64::MAX] {
+            assert_eq!(ChonkerInt::from(value).bit_length(), 64 - value.leading_zeros() as u64);
+        }
     }
 
     // Test retrieval of a reference to the BigInt's sign.
@@ -246,6 +550,24 @@ mod tests {
         assert_eq!(bigint, comparison_bigint);
     }
 
+    // Test normalization of BigInts consisting only of zeros, the sign should become zero.
+    #[test]
+    fn test_bigint_normalization_of_zeros() {
+        for sign in [BigIntSign::Positive, BigIntSign::Negative, BigIntSign::Zero] {
+            let mut zeros_bigint = ChonkerInt { digits: vec![0; 1000], sign };
+            zeros_bigint.normalize();
+
+            assert!(zeros_bigint.digits.is_empty());
+            assert_eq!(*zeros_bigint.get_sign(), BigIntSign::Zero);
+            assert_eq!(zeros_bigint, ChonkerInt::new());
+        }
+
+        // The sign of a BigInt with significant limbs is preserved.
+        let mut negative_bigint = ChonkerInt { digits: vec![7, 0, 0, 0], sign: BigIntSign::Negative };
+        negative_bigint.normalize();
+        assert_eq!(negative_bigint, ChonkerInt::from(-7));
+    }
+
     // Test BigInt vector/slice insertion.
     #[test]
     fn test_bigint_vector_insertion() {
@@ -260,7 +582,7 @@ mod tests {
         bigint_empty.push_vec(&digits_vector);
 
         let mut comparison_bigint1 = ChonkerInt::new();
-        let comparison_digits_vector = vec![5, 4, 3, 2, 1, 1, 2, 3, 0];
+        let comparison_digits_vector = vec![12345, 1, 2, 3, 0];
         comparison_bigint1.push_vec(&comparison_digits_vector);
         comparison_bigint1.set_positive_sign();
 
@@ -339,12 +661,12 @@ mod tests {
         let target_for_splicing_clone1 = ChonkerInt::from(01);
         let target_for_splicing_clone3 = ChonkerInt::from(0);
 
-        // Mind little endian.
+        // Mind little endian, whole limbs of 9 digits are appended.
         target_bigint1.splice(target_for_splicing_clone0);
         target_bigint2.splice(target_for_splicing_clone3);
         target_bigint_empty.splice(target_for_splicing_clone1);
 
-        assert_eq!(target_bigint1, ChonkerInt::from(45123));
+        assert_eq!(target_bigint1, ChonkerInt::from(45_000_000_123u64));
         assert_eq!(target_bigint2, ChonkerInt::from(101));
         assert_eq!(target_bigint_empty, ChonkerInt::from(1));
     }
@@ -1627,7 +1949,7 @@ mod tests {
 //                 let cut_dividend_splice =
 //                     &self.digits[(dividend_index - difference)..=(dividend_index - 1)];
 //
-//                 // Add remaining digits form the dividend to the remainder from previous division operation.
+//                 // Add remaining digits from the dividend to the remainder from previous division operation.
 //                 // Preserve little endian from the dividend with reverse of the iterator over the dividend.
 //                 for digit in cut_dividend_splice.iter().rev() {
 //                     cut_dividend.digits.insert(0, *digit);
@@ -1893,7 +2215,7 @@ mod tests {
 //                 let cut_dividend_splice =
 //                     &self.digits[(dividend_index - difference)..=(dividend_index - 1)];
 //
-//                 // Add remaining digits form the dividend to the remainder from previous division operation.
+//                 // Add remaining digits from the dividend to the remainder from previous division operation.
 //                 // Preserve little endian from the dividend with reverse of the iterator over the dividend.
 //                 for digit in cut_dividend_splice.iter().rev() {
 //                     cut_dividend.digits.insert(0, *digit);
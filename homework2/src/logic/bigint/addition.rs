@@ -1,5 +1,6 @@
 // BigInt module regarding addition of BigInts.
 
+use std::iter::Sum;
 use std::ops::{Add, Neg};
 
 use crate::logic::bigint::{clip, overflow, BigIntSign, ChonkerInt};
@@ -44,9 +45,9 @@ impl<'a, 'b> Add<&'b ChonkerInt> for &'a ChonkerInt {
         let mut other_offset = 0;
         let other_length = other.digits.len();
 
-        // Determine the longer/bigger BigInt and calculate addition.
+        // Determine the longer BigInt and calculate addition.
         // If vector were of different lengths, finish operation on the digits of the longer vector/BigInt with a second loop.
-        if *self >= *other {
+        if self_length >= other_length {
             while other_offset < other_length {
                 add_digits(
                     &self.digits,
@@ -88,9 +89,12 @@ impl<'a, 'b> Add<&'b ChonkerInt> for &'a ChonkerInt {
 
         // Check for a possible remaining overflow.
         if last_digit_overflow > 0 {
-            let _ = result.push(last_digit_overflow);
+            let _ = result.push(last_digit_overflow as u32);
         }
 
+        // Remove leading zeros, which could be carried over from the operands.
+        result.normalize();
+
         // Set a positive sing of the resulting BigInt.
         result.set_positive_sign();
 
@@ -98,50 +102,167 @@ impl<'a, 'b> Add<&'b ChonkerInt> for &'a ChonkerInt {
     }
 }
 
-// Addition of two passed digits.
+impl ChonkerInt {
+    // Add another BigInt to this one in place, reusing the vector of limbs of self,
+    // no allocation happens if the vector has enough capacity for the sum.
+    // The result is equal to "&self + other", operands with different signs fall back to the operator.
+    pub fn add_assign_ref(&mut self, other: &ChonkerInt) {
+        // If other is empty/zero, leave self unchanged.
+        if other.is_zero() {
+            return;
+        }
+
+        // If self is empty/zero, copy the limbs of other into the existing vector.
+        if self.is_zero() {
+            self.digits.clear();
+            self.digits.extend_from_slice(other.significant_digits());
+            self.sign = if other.is_negative() {
+                BigIntSign::Negative
+            } else {
+                BigIntSign::Positive
+            };
+            return;
+        }
+
+        // Different signs require a subtraction of the magnitudes.
+        if self.sign != other.sign {
+            *self = &*self + other;
+            return;
+        }
+
+        // Same signs, add the magnitudes limb by limb, the sign stays the same.
+        let other_digits = other.significant_digits();
+        if self.digits.len() < other_digits.len() {
+            self.digits.resize(other_digits.len(), 0);
+        }
+
+        let mut last_digit_overflow = 0;
+        for (index, digit) in self.digits.iter_mut().enumerate() {
+            // Stop as soon as the limbs of other are over and there is nothing to carry.
+            if index >= other_digits.len() && last_digit_overflow == 0 {
+                break;
+            }
+
+            let sum = *digit as u64 + *other_digits.get(index).unwrap_or(&0) as u64 + last_digit_overflow;
+            last_digit_overflow = overflow(sum);
+            *digit = clip(sum);
+        }
+
+        // Check for a possible remaining overflow.
+        if last_digit_overflow > 0 {
+            self.digits.push(last_digit_overflow as u32);
+        }
+
+        // Remove leading zeros, which could be carried over from self.
+        self.normalize();
+    }
+}
+
+// Addition of two passed limbs.
 fn add_digits(
-    one_vec: &[i8],
-    other_vec: &[i8],
+    one_vec: &[u32],
+    other_vec: &[u32],
     one_offset: &mut usize,
     other_offset: &mut usize,
     result: &mut ChonkerInt,
-    last_digit_overflow: &mut i8,
+    last_digit_overflow: &mut u64,
 ) {
-    // Calculate sum of digits.
-    let mut sum = (*one_vec)[*one_offset] + (*other_vec)[*other_offset] + (*last_digit_overflow);
+    // Calculate sum of limbs, widened to avoid an overflow of the limb type.
+    let sum = (*one_vec)[*one_offset] as u64 + (*other_vec)[*other_offset] as u64 + (*last_digit_overflow);
 
     // Check for the overflow.
     *last_digit_overflow = overflow(sum);
-    sum = clip(sum);
 
-    let _ = result.push(sum);
+    let _ = result.push(clip(sum));
     *one_offset += 1;
     *other_offset += 1;
 }
 
-// Addition of one passed digit and a result slot.
+// Addition of one passed limb and a result slot.
 fn add_digit_and_overflow(
-    one_vec: &[i8],
+    one_vec: &[u32],
     one_offset: &mut usize,
     result: &mut ChonkerInt,
-    last_digit_overflow: &mut i8,
+    last_digit_overflow: &mut u64,
 ) {
-    // Calculate sum of digits.
-    let mut sum = (*one_vec)[*one_offset] + (*last_digit_overflow);
+    // Calculate sum of the limb and the overflow.
+    let sum = (*one_vec)[*one_offset] as u64 + (*last_digit_overflow);
 
     // Check for the overflow.
     *last_digit_overflow = overflow(sum);
-    sum = clip(sum);
 
-    let _ = result.push(sum);
+    let _ = result.push(clip(sum));
     *one_offset += 1;
 }
 
+// Implement addition "+" operator for owned BigInts and mixes of owned and borrowed ones.
+forward_binary_operator!(Add, add);
+
+// Implement summation of an iterator over BigInts, e.g. "vector.iter().sum::<ChonkerInt>()".
+// Sum of an empty iterator is an empty/zero BigInt.
+impl Sum for ChonkerInt {
+    fn sum<I: Iterator<Item = ChonkerInt>>(iter: I) -> Self {
+        iter.fold(ChonkerInt::new(), |accumulator, bigint| &accumulator + &bigint)
+    }
+}
+
+impl<'a> Sum<&'a ChonkerInt> for ChonkerInt {
+    fn sum<I: Iterator<Item = &'a ChonkerInt>>(iter: I) -> Self {
+        iter.fold(ChonkerInt::new(), |accumulator, bigint| &accumulator + bigint)
+    }
+}
+
 // Test module.
 #[cfg(test)]
 mod tests {
+    use rand::Rng;
+
     use crate::logic::bigint::addition::{add_digit_and_overflow, add_digits};
-    use crate::logic::bigint::ChonkerInt;
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
+
+    // Test the in place addition against the addition operator, and the reuse of the vector of limbs.
+    #[test]
+    fn test_bigint_add_assign_ref() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..500 {
+            let self_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let other_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let bigint = ChonkerInt::new_rand(&rng.gen_range(1..=60), &self_sign);
+            let other = ChonkerInt::new_rand(&rng.gen_range(1..=60), &other_sign);
+
+            let mut in_place = bigint.clone();
+            in_place.add_assign_ref(&other);
+            assert_eq!(in_place, &bigint + &other);
+        }
+
+        // Zeros, carries over all limbs and the cancellation of the operands.
+        let nines = ChonkerInt::from(String::from("999999999999999999999999999"));
+        let cases = [
+            (ChonkerInt::new(), ChonkerInt::from(-5)),
+            (ChonkerInt::from(-5), ChonkerInt::new()),
+            (nines.clone(), ChonkerInt::from(1)),
+            (ChonkerInt::from(1), nines.clone()),
+            (-&nines, -&nines),
+            (nines.clone(), -&nines),
+        ];
+        for (bigint, other) in cases.iter() {
+            let mut in_place = bigint.clone();
+            in_place.add_assign_ref(other);
+            assert_eq!(in_place, bigint + other);
+            assert_eq!(in_place.to_string(), (bigint + other).to_string());
+        }
+
+        // The vector of limbs is reused, if it has enough capacity for the sum.
+        let mut sum = ChonkerInt::from(123_456_789_123_456_789u64);
+        sum.digits.reserve(10);
+        let buffer = sum.get_limbs().as_ptr();
+        for _ in 0..1000 {
+            sum.add_assign_ref(&nines);
+        }
+        assert_eq!(sum.get_limbs().as_ptr(), buffer);
+        assert_eq!(sum, &ChonkerInt::from(123_456_789_123_456_789u64) + &(&nines * 1000u64));
+    }
 
     // Test of BigInt's addition operation.
     #[test]
@@ -228,15 +349,15 @@ mod tests {
         );
     }
 
-    // Test addition of two digits.
+    // Test addition of two limbs.
     #[test]
     fn test_digits_addition() {
-        let one_vec1: Vec<i8> = vec![1];
-        let one_vec2: Vec<i8> = vec![2];
+        let one_vec1: Vec<u32> = vec![1];
+        let one_vec2: Vec<u32> = vec![2];
         let mut one_offset1 = 0;
         let mut one_offset2 = 0;
-        let other_vec1: Vec<i8> = vec![9];
-        let other_vec2: Vec<i8> = vec![5];
+        let other_vec1: Vec<u32> = vec![999_999_999];
+        let other_vec2: Vec<u32> = vec![5];
         let mut other_offset1 = 0;
         let mut other_offset2 = 0;
         let mut result1 = ChonkerInt::new();
@@ -261,24 +382,24 @@ mod tests {
             &mut last_digit_overflow2,
         );
 
-        // First test of addition, 1+9
-        assert_eq!((*result1.get_vec())[0], 0);
+        // First test of addition, 1 + 999999999
+        assert_eq!(result1.get_limbs()[0], 0);
         assert_eq!(last_digit_overflow1, 1);
         assert_eq!(one_offset1, 1);
         assert_eq!(other_offset1, 1);
 
-        // Second test of addition, 0+5
-        assert_eq!((*result2.get_vec())[0], 7);
+        // Second test of addition, 2 + 5
+        assert_eq!(result2.get_limbs()[0], 7);
         assert_eq!(last_digit_overflow2, 0);
         assert_eq!(one_offset2, 1);
         assert_eq!(other_offset2, 1);
     }
 
-    // Test addition of a digit with an overflow.
+    // Test addition of a limb with an overflow.
     #[test]
     fn test_digit_and_overflow_addition() {
-        let one_vec1: Vec<i8> = vec![9];
-        let one_vec2: Vec<i8> = vec![0];
+        let one_vec1: Vec<u32> = vec![999_999_999];
+        let one_vec2: Vec<u32> = vec![0];
         let mut one_offset1 = 0;
         let mut one_offset2 = 0;
         let mut result1 = ChonkerInt::new();
@@ -299,14 +420,46 @@ mod tests {
             &mut last_digit_overflow2,
         );
 
-        // First test of addition, 9 + 1
-        assert_eq!((*result1.get_vec())[0], 0);
+        // First test of addition, 999999999 + 1
+        assert_eq!(result1.get_limbs()[0], 0);
         assert_eq!(last_digit_overflow1, 1);
         assert_eq!(one_offset1, 1);
 
         // Second test of addition, 0 + 0
-        assert_eq!((*result2.get_vec())[0], 0);
+        assert_eq!(result2.get_limbs()[0], 0);
         assert_eq!(last_digit_overflow2, 0);
         assert_eq!(one_offset2, 1);
     }
+
+    // Test addition of owned BigInts and mixes of owned and borrowed ones.
+    #[test]
+    fn test_bigint_owned_addition() {
+        let target = ChonkerInt::from(-1234567);
+        let other = ChonkerInt::from(89);
+        let reference_result = &target + &other;
+
+        assert_eq!(ChonkerInt::from(-1234567) + ChonkerInt::from(89), reference_result);
+        assert_eq!(ChonkerInt::from(-1234567) + &other, reference_result);
+        assert_eq!(&target + ChonkerInt::from(89), reference_result);
+    }
+
+    // Test summation of iterators over BigInts.
+    #[test]
+    fn test_bigint_sum() {
+        let bigints = vec![
+            ChonkerInt::from(123),
+            ChonkerInt::from(-1000),
+            ChonkerInt::from(77),
+            ChonkerInt::from(String::from("100000000000000000000000000000")),
+        ];
+        let sum_result = ChonkerInt::from(String::from("99999999999999999999999999200"));
+
+        assert_eq!(bigints.iter().sum::<ChonkerInt>(), sum_result);
+        assert_eq!(bigints.into_iter().sum::<ChonkerInt>(), sum_result);
+
+        // Sum of an empty iterator is zero.
+        let empty_bigints: Vec<ChonkerInt> = vec![];
+        assert_eq!(empty_bigints.iter().sum::<ChonkerInt>(), ChonkerInt::new());
+        assert_eq!(empty_bigints.into_iter().sum::<ChonkerInt>(), ChonkerInt::new());
+    }
 }
@@ -0,0 +1,141 @@
+// BigInt module regarding Barrett reduction of BigInts.
+// Barrett reduction replaces the division by a fixed modulus with two multiplications and a subtraction,
+// using the reciprocal of the modulus, which is computed only once.
+// More about the idea: https://en.wikipedia.org/wiki/Barrett_reduction
+
+use crate::logic::bigint::{BigIntSign, ChonkerInt, RADIX_DIGITS};
+
+// Reducer of BigInts modulo a fixed modulus m with k limbs.
+// Values in the range |x| < RADIX^(2k), e.g. products of two values smaller than the modulus,
+// are reduced with the precomputed reciprocal, bigger values fall back to the modulus operation.
+#[derive(Debug, Clone)]
+pub struct BarrettReducer {
+    // The modulus with its sign, the result of the reduction follows it.
+    modulus: ChonkerInt,
+    // Absolute value of the modulus without leading zeros.
+    absolute_modulus: ChonkerInt,
+    // Amount of limbs k of the modulus.
+    length: usize,
+    // Approximation of the reciprocal: floor(RADIX^(2k) / |m|).
+    reciprocal: ChonkerInt,
+}
+
+impl BarrettReducer {
+    // Precompute the reciprocal approximation for the modulus.
+    pub fn new(modulus: &ChonkerInt) -> BarrettReducer {
+        // Check if the modulus is zero, if so, panic.
+        if modulus.is_zero() {
+            panic!("attempt to construct a Barrett reducer with a modulus of zero (BarrettReducer::new())");
+        }
+
+        let mut absolute_modulus = modulus.abs();
+        absolute_modulus.normalize();
+        let length = absolute_modulus.digits.len();
+        let reciprocal = &ChonkerInt::from(1).mul_pow10(2 * length * RADIX_DIGITS) / &absolute_modulus;
+
+        BarrettReducer {
+            modulus: (*modulus).clone(),
+            absolute_modulus,
+            length,
+            reciprocal,
+        }
+    }
+
+    // Get an immutable reference to the modulus of the reducer.
+    pub fn modulus(&self) -> &ChonkerInt {
+        &self.modulus
+    }
+
+    // Reduce the BigInt by the modulus, the result is equal to "x % m": the sign of the result follows the modulus.
+    // Example: BarrettReducer::new(&ChonkerInt::from(7)).reduce(&ChonkerInt::from(-30)) == ChonkerInt::from(5).
+    pub fn reduce(&self, x: &ChonkerInt) -> ChonkerInt {
+        // Values out of the range of the reciprocal approximation are reduced with the full division.
+        if x.significant_digits().len() > 2 * self.length {
+            return x % &self.modulus;
+        }
+
+        // Estimate the quotient of the absolute value: q = ((|x| / RADIX^(k-1)) * reciprocal) / RADIX^(k+1).
+        // The estimate is smaller than the true quotient at most by 2.
+        let absolute_x = x.abs();
+        let quotient = (&absolute_x.div_pow10((self.length - 1) * RADIX_DIGITS) * &self.reciprocal)
+            .div_pow10((self.length + 1) * RADIX_DIGITS);
+        let mut remainder = &absolute_x - &(&quotient * &self.absolute_modulus);
+
+        // Correct the estimate.
+        while remainder >= self.absolute_modulus {
+            remainder = &remainder - &self.absolute_modulus;
+        }
+
+        // The remainder of the absolute values follows the dividend,
+        // if the signs of the dividend and the modulus differ, count it from the modulus.
+        if remainder.is_zero() {
+            return ChonkerInt::new();
+        } else if x.is_negative() != self.modulus.is_negative() {
+            remainder = &self.absolute_modulus - &remainder;
+        }
+
+        if self.modulus.sign == BigIntSign::Negative {
+            remainder.set_negative_sign();
+        }
+
+        remainder
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::logic::bigint::barrett::BarrettReducer;
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
+
+    // Test Barrett reduction against the modulus operation with random values.
+    #[test]
+    fn test_bigint_barrett_reduction() {
+        let mut rng = rand::thread_rng();
+
+        for modulus_length in [1u64, 5, 9, 10, 18, 19, 40, 100] {
+            let modulus = ChonkerInt::new_rand(&modulus_length, &BigIntSign::Positive);
+            let negative_modulus = -&modulus;
+            let reducer = BarrettReducer::new(&modulus);
+            let negative_reducer = BarrettReducer::new(&negative_modulus);
+            let modulus_squared = &modulus * &modulus;
+
+            for _ in 0..500 {
+                let sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+                let x = ChonkerInt::new_rand_range_len(&1, &(2 * modulus_length), &sign);
+
+                assert_eq!(reducer.reduce(&x), &x % &modulus);
+                assert_eq!(negative_reducer.reduce(&x), &x % &negative_modulus);
+            }
+
+            // Values smaller than the modulus, equal to it and just below its square.
+            let boundary_values = [
+                ChonkerInt::new(),
+                ChonkerInt::from(1),
+                &modulus - 1u64,
+                modulus.clone(),
+                &modulus + 1u64,
+                &modulus_squared - &modulus,
+                &modulus_squared - 1u64,
+                -&(&modulus_squared - 1u64),
+            ];
+            for x in boundary_values.iter() {
+                assert_eq!(reducer.reduce(x), x % &modulus);
+                assert_eq!(negative_reducer.reduce(x), x % &negative_modulus);
+            }
+
+            // Values out of the range of the reciprocal approximation.
+            let big_x = &(&modulus_squared * &modulus_squared) + 12345u64;
+            assert_eq!(reducer.reduce(&big_x), &big_x % &modulus);
+        }
+    }
+
+    // Test construction of the Barrett reducer with a modulus of zero.
+    #[test]
+    #[should_panic]
+    fn test_bigint_barrett_zero_modulus() {
+        BarrettReducer::new(&ChonkerInt::new());
+    }
+}
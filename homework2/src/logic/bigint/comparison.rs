@@ -7,100 +7,56 @@ use crate::logic::bigint::{BigIntSign, ChonkerInt};
 // Implement total ordering for the BigInt.
 impl Ord for ChonkerInt {
     fn cmp(&self, other: &Self) -> Ordering {
-        // Compare signs, if they are equal, proceed with length and content checks.
-        // If both are zero/empty, return equal ordering.
-        if (self.digits.is_empty() && other.digits.is_empty())
-            || (self.sign == BigIntSign::Zero && other.sign == BigIntSign::Zero)
-        {
-            return Ordering::Equal;
+        // Determine the significant parts of both vectors, skipping leading zeros,
+        // which can be left in the vector, e.g. after insertion of a vector of digits.
+        let self_digits = self.significant_digits();
+        let other_digits = other.significant_digits();
+
+        // Determine the effective signs: a BigInt without significant digits is zero regardless of its sign,
+        // e.g. a negative zero is equal to zero.
+        let sign_rank = |sign: &BigIntSign, digits: &[u32]| -> i8 {
+            match (digits.is_empty(), sign) {
+                (true, _) | (false, BigIntSign::Zero) => 0,
+                (false, BigIntSign::Positive) => 1,
+                (false, BigIntSign::Negative) => -1,
+            }
+        };
+        let self_sign_rank = sign_rank(&self.sign, self_digits);
+        let other_sign_rank = sign_rank(&other.sign, other_digits);
+
+        // Compare signs, if they are different, the ordering is determined by them.
+        // If both are zero, return equal ordering.
+        if self_sign_rank != other_sign_rank || self_sign_rank == 0 {
+            return self_sign_rank.cmp(&other_sign_rank);
         }
 
-        if self.sign == other.sign {
-            // If the lengths are equal compare separate digits in the vector.
-            // Reminder: digits are stored in little endian format.
-            // If the self length is less that other's return Ordering variant Less, Greater otherwise.
-            // Consider the signs: if the signs are positive, longer length - greater, bigger separate digit - greater;
-            // if the signs are negative, shorter length - greater, smaller separate digit - greater.
-            let ordering_determination = |sign: &BigIntSign| {
-                match self.digits.len().cmp(&other.digits.len()) {
-                    Ordering::Less => {
-                        if *sign == BigIntSign::Positive {
-                            Ordering::Less
-                        } else {
-                            // Negative case.
-                            Ordering::Greater
-                        }
-                    }
-                    Ordering::Equal => {
-                        let mut self_char_index = self.digits.len() - 1;
-                        let mut other_char_index = other.digits.len() - 1;
-
-                        loop {
-                            match self.digits[self_char_index].cmp(&other.digits[other_char_index])
-                            {
-                                Ordering::Less => {
-                                    return if *sign == BigIntSign::Positive {
-                                        Ordering::Less
-                                    } else {
-                                        // Negative case.
-                                        Ordering::Greater
-                                    };
-                                }
-                                Ordering::Equal => {}
-                                Ordering::Greater => {
-                                    return if *sign == BigIntSign::Positive {
-                                        Ordering::Greater
-                                    } else {
-                                        // Negative case.
-                                        Ordering::Less
-                                    };
-                                }
-                            }
-
-                            // Check for index being zero, if allowed,
-                            // to proceed an attempt at subtraction against unsigned zero would happen.
-                            if self_char_index == 0 {
-                                break;
-                            }
-
-                            self_char_index -= 1;
-                            other_char_index -= 1;
-                        }
-
-                        Ordering::Equal
-                    }
-                    Ordering::Greater => {
-                        if *sign == BigIntSign::Positive {
-                            Ordering::Greater
-                        } else {
-                            // Negative case.
-                            Ordering::Less
-                        }
-                    }
-                }
-            };
-
-            return match self.sign {
-                BigIntSign::Positive => ordering_determination(&self.sign),
-                BigIntSign::Zero => Ordering::Equal,
-                BigIntSign::Negative => ordering_determination(&self.sign),
-            };
-        } else if self.sign == BigIntSign::Negative
-            && (other.sign == BigIntSign::Positive || other.sign == BigIntSign::Zero)
-        {
-            return Ordering::Less;
-        } else if self.sign == BigIntSign::Positive
-            && (other.sign == BigIntSign::Negative || other.sign == BigIntSign::Zero)
-        {
-            return Ordering::Greater;
-        } else if self.sign == BigIntSign::Zero && other.sign == BigIntSign::Positive {
-            return Ordering::Less;
-        } else if self.sign == BigIntSign::Zero && other.sign == BigIntSign::Negative {
-            return Ordering::Greater;
+        // Compare magnitudes: longer length - greater, otherwise compare separate digits
+        // starting from the most significant one.
+        // Reminder: digits are stored in little endian format.
+        let magnitude_ordering = self_digits
+            .len()
+            .cmp(&other_digits.len())
+            .then_with(|| self_digits.iter().rev().cmp(other_digits.iter().rev()));
+
+        // Consider the signs: if the signs are positive, greater magnitude - greater;
+        // if the signs are negative, greater magnitude - less, e.g. -100 < -2.
+        if self_sign_rank > 0 {
+            magnitude_ordering
+        } else {
+            magnitude_ordering.reverse()
         }
+    }
+}
 
-        // If the signs, lengths and digits were equal, return Ordering variant of Equal.
-        Ordering::Equal
+impl ChonkerInt {
+    // Retrieve the slice of limbs without leading zeros.
+    pub(super) fn significant_digits(&self) -> &[u32] {
+        let significant_length = match self.digits.iter().rposition(|digit| *digit != 0) {
+            Some(index) => index + 1,
+            None => 0,
+        };
+
+        &self.digits[..significant_length]
     }
 }
 
@@ -115,7 +71,9 @@ impl PartialOrd for ChonkerInt {
 // Test module.
 #[cfg(test)]
 mod tests {
-    use crate::logic::bigint::ChonkerInt;
+    use std::cmp::Ordering;
+
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
 
     // Test comparisons of BigInts.
     #[test]
@@ -192,4 +150,62 @@ mod tests {
 
         assert_eq!(bigint_target_list, bigint_comparison_list)
     }
+
+    // Test comparisons of BigInts over all combinations of signs.
+    #[test]
+    fn test_bigint_comparison_sign_combinations() {
+        // Ordered list of values, covering equal magnitudes with different signs and different lengths.
+        let ordered_values: Vec<i128> = vec![
+            -10000, -9999, -100, -12, -10, -2, -1, 0, 1, 2, 10, 12, 100, 9999, 10000,
+        ];
+
+        for (self_index, self_value) in ordered_values.iter().enumerate() {
+            for (other_index, other_value) in ordered_values.iter().enumerate() {
+                let self_bigint = ChonkerInt::from(*self_value);
+                let other_bigint = ChonkerInt::from(*other_value);
+
+                assert_eq!(
+                    self_bigint.cmp(&other_bigint),
+                    self_index.cmp(&other_index),
+                    "incorrect ordering of {} and {}",
+                    self_value,
+                    other_value
+                );
+                assert_eq!(self_bigint.partial_cmp(&other_bigint), Some(self_value.cmp(other_value)));
+            }
+        }
+
+        assert!(ChonkerInt::from(-100) < ChonkerInt::from(-2));
+        assert!(ChonkerInt::from(-2) > ChonkerInt::from(-100));
+        assert!(ChonkerInt::from(-123) < ChonkerInt::from(-122));
+        assert!(ChonkerInt::from(-123) <= ChonkerInt::from(-123));
+    }
+
+    // Test comparisons of BigInts with zero values carrying a sign and vectors with leading zeros.
+    #[test]
+    fn test_bigint_comparison_zero_corner_cases() {
+        let zero_bigint = ChonkerInt::new();
+
+        // Negative zero, e.g. a negative sign with an empty or zero filled vector.
+        let mut negative_zero_bigint = ChonkerInt::new();
+        negative_zero_bigint.set_negative_sign();
+        let mut negative_zeros_bigint = ChonkerInt::new();
+        negative_zeros_bigint.push_vec(&[0, 0, 0]);
+        negative_zeros_bigint.set_negative_sign();
+
+        assert_eq!(negative_zero_bigint.cmp(&zero_bigint), Ordering::Equal);
+        assert_eq!(negative_zeros_bigint.cmp(&zero_bigint), Ordering::Equal);
+        assert_eq!(zero_bigint.cmp(&negative_zeros_bigint), Ordering::Equal);
+        assert!(negative_zero_bigint > ChonkerInt::from(-1));
+        assert!(negative_zero_bigint < ChonkerInt::from(1));
+
+        // Leading zeros do not affect the magnitude.
+        let mut leading_zeros_bigint = ChonkerInt::new();
+        leading_zeros_bigint.push_vec(&[2, 0, 0, 0]);
+        leading_zeros_bigint.set_negative_sign();
+
+        assert_eq!(leading_zeros_bigint.cmp(&ChonkerInt::from(-2)), Ordering::Equal);
+        assert!(leading_zeros_bigint > ChonkerInt::from(-100));
+        assert_eq!(*leading_zeros_bigint.get_sign(), BigIntSign::Negative);
+    }
 }
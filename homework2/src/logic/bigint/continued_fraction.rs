@@ -0,0 +1,102 @@
+// BigInt module regarding continued fractions of the rationals of BigInts.
+
+use crate::logic::bigint::ChonkerInt;
+
+// An iterator over the convergents h/k of the continued fraction of a rational, each convergent is the pair (h, k).
+// The partial quotients a_i are produced by the Euclidean algorithm on the numerator and the denominator,
+// the convergents follow h_i = a_i * h_(i-1) + h_(i-2) and k_i = a_i * k_(i-1) + k_(i-2).
+// The iterator is finite, the last convergent is the rational itself in the lowest terms.
+// More about the idea: https://en.wikipedia.org/wiki/Continued_fraction#Infinite_continued_fractions_and_convergents
+#[derive(Debug, Clone)]
+pub struct Convergents {
+    numerator: ChonkerInt,
+    denominator: ChonkerInt,
+    previous: (ChonkerInt, ChonkerInt),
+    current: (ChonkerInt, ChonkerInt),
+}
+
+// Implement continued fraction methods for BigInt.
+impl ChonkerInt {
+    // Iterate over the convergents of the continued fraction of the rational self / denominator.
+    // Works only with the non negative numerators and the positive denominators.
+    pub fn convergents(&self, denominator: &ChonkerInt) -> Convergents {
+        if self.is_negative() || !denominator.is_positive() {
+            panic!("the convergents are calculated only for the non negative numerators and the positive denominators (ChonkerInt::convergents())");
+        }
+
+        // The convergents h_(-2)/k_(-2) = 0/1 and h_(-1)/k_(-1) = 1/0 start the recurrence.
+        Convergents {
+            numerator: (*self).clone(),
+            denominator: (*denominator).clone(),
+            previous: (ChonkerInt::new(), ChonkerInt::from(1)),
+            current: (ChonkerInt::from(1), ChonkerInt::new()),
+        }
+    }
+}
+
+// Implement Iterator trait for the convergents, the next partial quotient produces the next convergent.
+impl Iterator for Convergents {
+    type Item = (ChonkerInt, ChonkerInt);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.denominator.is_zero() {
+            return None;
+        }
+
+        let (quotient, remainder) = self.numerator.div_rem(&self.denominator);
+        self.numerator = std::mem::replace(&mut self.denominator, remainder);
+
+        let convergent = (
+            &(&quotient * &self.current.0) + &self.previous.0,
+            &(&quotient * &self.current.1) + &self.previous.1,
+        );
+        self.previous = std::mem::replace(&mut self.current, convergent.clone());
+
+        Some(convergent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logic::bigint::ChonkerInt;
+
+    // Collect the convergents of the rational of the primitive integers.
+    fn convergents(numerator: u64, denominator: u64) -> Vec<(ChonkerInt, ChonkerInt)> {
+        ChonkerInt::from(numerator).convergents(&ChonkerInt::from(denominator)).collect()
+    }
+
+    // Test the convergents of the rationals against the known continued fractions.
+    #[test]
+    fn test_bigint_convergents() {
+        // 649/200 = [3; 4, 12, 4].
+        let expected: Vec<(ChonkerInt, ChonkerInt)> = [(3, 1), (13, 4), (159, 49), (649, 200)].iter().map(|(h, k)| (ChonkerInt::from(*h), ChonkerInt::from(*k))).collect();
+        assert_eq!(convergents(649, 200), expected);
+
+        // The proper fraction starts with 0/1, the rational is reduced to the lowest terms, the integer has a single convergent.
+        assert_eq!(convergents(17, 3233)[0], (ChonkerInt::new(), ChonkerInt::from(1)));
+        assert_eq!(convergents(12, 18).last().cloned(), Some((ChonkerInt::from(2), ChonkerInt::from(3))));
+        assert_eq!(convergents(42, 1), vec![(ChonkerInt::from(42), ChonkerInt::from(1))]);
+        assert_eq!(convergents(0, 5), vec![(ChonkerInt::new(), ChonkerInt::from(1))]);
+
+        // The consecutive Fibonacci numbers give the longest continued fraction of [1; 1, ..., 1, 2],
+        // every convergent is a ratio of the consecutive Fibonacci numbers.
+        let (mut fibonacci_previous, mut fibonacci) = (ChonkerInt::from(1), ChonkerInt::from(1));
+        for _index in 0..150 {
+            let next = &fibonacci + &fibonacci_previous;
+            fibonacci_previous = std::mem::replace(&mut fibonacci, next);
+        }
+        let fibonacci_convergents: Vec<(ChonkerInt, ChonkerInt)> = fibonacci.convergents(&fibonacci_previous).collect();
+        assert_eq!(fibonacci_convergents.len(), 150);
+        assert_eq!(fibonacci_convergents.last().cloned(), Some((fibonacci.clone(), fibonacci_previous.clone())));
+        for (h, k) in fibonacci_convergents {
+            assert!(h.is_coprime(&k));
+        }
+    }
+
+    // Test the convergents with the zero denominator, it panics.
+    #[test]
+    #[should_panic]
+    fn test_bigint_convergents_zero_denominator() {
+        ChonkerInt::from(5).convergents(&ChonkerInt::new());
+    }
+}
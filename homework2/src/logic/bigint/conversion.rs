@@ -1,41 +1,39 @@
 // BigInt module regarding conversion from a specific data type to BigInt and vice versa.
 
 use std::fmt::{Display, Formatter};
-use std::str::from_utf8_unchecked;
-
+use std::str::FromStr;
 use num_traits::{PrimInt, Signed, Zero};
 
-use crate::logic::bigint::{BigIntSign, ChonkerInt, ASCII_DIFF};
+use crate::logic::bigint::{BigIntSign, ChonkerInt, ASCII_DIFF, RADIX, RADIX_DIGITS};
+use crate::logic::error::OperationError;
 
 // Implement conversion methods for BigInt.
 // Turn BigInt into a string consisting of its digits in big endian format.
+// The inherent to_string() of the BigInt is provided by the ToString trait through this implementation.
 impl Display for ChonkerInt {
     // Produce a string representing the number inside the BigInt.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        // Check if the BigInt is zero.
-        if (*self) == ChonkerInt::new() {
-            return write!(f, "0");
-        }
-
-        let mut digits_vec = self.digits.clone();
-        digits_vec.reverse();
-
-        let modified_digits_vec: Vec<u8> = digits_vec
-            .iter_mut()
-            .map(|x| (*x + ASCII_DIFF) as u8)
-            .collect();
-
-        let digits_string = unsafe { from_utf8_unchecked(&modified_digits_vec) };
+        // Determine the significant limbs, skipping leading zeros,
+        // which can be left in the vector, e.g. after insertion of a vector of limbs.
+        // Reminder: limbs are stored in little endian format, leading zeros are at the end of the vector.
+        let limbs = self.significant_digits();
+
+        // Check if the BigInt is zero, regardless of its sign.
+        let (most_significant_limb, lower_limbs) = match limbs.split_last() {
+            Some(split) => split,
+            None => return f.pad_integral(true, "", "0"),
+        };
 
-        let mut result;
-        // If the BigInt is negative, add minus/hyphen to the result string.
-        result = String::from(digits_string);
-        if self.sign == BigIntSign::Negative {
-            let minus = '-';
-            result.insert(0, minus);
+        // Turn the limbs into their decimal representation in big endian format.
+        // The most significant limb is written without leading zeros, every other limb is padded to 9 digits.
+        let mut digits_string = most_significant_limb.to_string();
+        for limb in lower_limbs.iter().rev() {
+            digits_string.push_str(&format!("{:09}", limb));
         }
 
-        write!(f, "{}", result)
+        // If the BigInt is negative, the minus/hyphen is added to the result string by the formatter,
+        // which also respects requested width, fill and alignment.
+        f.pad_integral(self.sign != BigIntSign::Negative, "", &digits_string)
     }
 }
 
@@ -52,103 +50,59 @@ impl ChonkerInt {
             panic!("the target BigInt for conversion into the unsgned 16 byte integer is too long/big (ChonkerInt::to_digit)")
         }
 
-        // Define the initial base of 1 that will be multiplied by 10 for each digit.
-        let mut base = 1;
-        let digits_iter = self.digits.iter();
-        let mut result_integer: u128 = 0;
-        let max_base_value = (10_u128).pow(38);
-
-        // Multiply each digit by the appropriate base/order and store the result in the result integer.
-        // Check if the base hit the limit, if it did, exit the loop.
-        for digit in digits_iter {
-            result_integer += *digit as u128 * base;
+        // Accumulate the limbs starting from the most significant one.
+        // The value was checked to fit, thus the multiplication and addition can not overflow.
+        self.significant_digits()
+            .iter()
+            .rev()
+            .fold(0u128, |result, limb| result * RADIX as u128 + *limb as u128)
+    }
+}
 
-            if base == max_base_value {
-                break;
-            }
+// Turn BigInt into a 16 byte unsigned integer, checking if the value fits into it.
+// Unlike to_digit(), negative BigInts and BigInts bigger than u128::MAX produce an error.
+impl ChonkerInt {
+    pub fn to_u128(&self) -> Result<u128, OperationError> {
+        // Check if the BigInt is negative.
+        if self.sign == BigIntSign::Negative {
+            return Err(OperationError::new(&format!("the BigInt {} is negative and can not be converted into the unsigned 16 byte integer.", self)));
+        }
 
-            base *= 10;
+        // Check if the BigInt is too big for the 16 byte unsigned integer.
+        if self > &ChonkerInt::from(u128::MAX) {
+            return Err(OperationError::new(&format!("the BigInt {} is bigger than the maximal value of the unsigned 16 byte integer {}.", self, u128::MAX)));
         }
 
-        result_integer
+        // Accumulate the limbs starting from the most significant one.
+        // The value was checked to fit, thus the multiplication and addition can not overflow.
+        let result_integer = self
+            .significant_digits()
+            .iter()
+            .rev()
+            .fold(0u128, |result, limb| result * RADIX as u128 + *limb as u128);
+
+        Ok(result_integer)
     }
 }
 
-// Conversion of an integer into BigInt.
-fn digit_convert(int: &u128) -> Result<Vec<i8>, Box<dyn std::error::Error>> {
-    let mut base = 10;
-    let max_base_value = (10_u128).pow(38);
-    let mut prev_base = 10;
-    let mut remainder = *int % base;
-    let mut prev_remainder = remainder;
-    let mut digit;
-    // Create a result vector and add the first element.
-    let mut result_vec: Vec<i8> = vec![remainder as i8];
-
-    base *= 10;
-    while remainder != *int {
-        // Calculate remainder with the current base and then calculate the target digit with it.
-        remainder = *int % base;
-        digit = (remainder - prev_remainder) / prev_base;
-
-        result_vec.push(digit as i8);
-
-        prev_remainder = remainder;
-        prev_base = base;
-
-        // Check if the base hit the limit, if it did,
-        // calculate the last digit most significant separately and exit the loop.
-        if base == max_base_value {
-            digit = (*int - prev_remainder) / prev_base;
-            result_vec.push(digit as i8);
-            break;
-        }
-        base *= 10;
+// Conversion of an integer into BigInt's limbs.
+fn digit_convert(int: &u128) -> Result<Vec<u32>, Box<dyn std::error::Error>> {
+    let mut remainder = *int;
+    // Create a result vector and add the first element, so zero is represented by a single limb.
+    let mut result_vec: Vec<u32> = vec![(remainder % RADIX as u128) as u32];
+    remainder /= RADIX as u128;
+
+    // Split off the limbs starting from the least significant one.
+    while remainder > 0 {
+        result_vec.push((remainder % RADIX as u128) as u32);
+        remainder /= RADIX as u128;
     }
 
     Ok(result_vec)
 }
 
-// Conversions from specific integer data types.
-// Convert a 16 byte signed integer into a BigInt.
-// impl From<i128> for ChonkerInt {
-//     fn from(int: i128) -> ChonkerInt {
-//         // Check for zero.
-//         if int == 0 {
-//             return ChonkerInt::new();
-//         }
-//
-//         let mut sign = BigIntSign::Zero;
-//
-//         // Determine the sign of the target and save it.
-//         if int.is_negative() {
-//             sign = BigIntSign::Negative;
-//         } else {
-//             sign = BigIntSign::Positive;
-//         }
-//
-//         let unsigned_int = (int.abs()) as u128;
-//
-//         digit_vector_produce(&unsigned_int, sign)
-//     }
-// }
-
-// Convert a 16 byte unsigned integer into a BigInt.
-// impl From<u128> for ChonkerInt {
-//     fn from(int: u128) -> ChonkerInt {
-//         // Check for zero.
-//         if int == 0 {
-//             return ChonkerInt::new();
-//         }
-//
-//         let mut sign = BigIntSign::Positive;
-//
-//         digit_vector_produce(&int, sign)
-//     }
-// }
-
-// Accept the 16 byte unsigned integer, produce the vector of unsigned bytes,
-// representing separate digits and use it in BigInt construction.
+// Accept the 16 byte unsigned integer, produce the vector of limbs
+// and use it in BigInt construction.
 fn digit_vector_produce(int: &u128, sign: BigIntSign) -> ChonkerInt {
     // Define BigInt's vector, check for the integer being zero.
     let digits = if *int == 0 {
@@ -160,66 +114,22 @@ fn digit_vector_produce(int: &u128, sign: BigIntSign) -> ChonkerInt {
     ChonkerInt { digits, sign }
 }
 
-// Convert a generic integer into a BigInt.
-impl From<u128> for ChonkerInt {
-    fn from(int: u128) -> ChonkerInt {
-        generic_from_unsigned_integer(int)
-    }
-}
-
-impl From<u64> for ChonkerInt {
-    fn from(int: u64) -> ChonkerInt {
-        generic_from_unsigned_integer(int)
-    }
-}
-
-impl From<u32> for ChonkerInt {
-    fn from(int: u32) -> ChonkerInt {
-        generic_from_unsigned_integer(int)
-    }
-}
-
-impl From<u16> for ChonkerInt {
-    fn from(int: u16) -> ChonkerInt {
-        generic_from_unsigned_integer(int)
-    }
-}
-
-impl From<u8> for ChonkerInt {
-    fn from(int: u8) -> ChonkerInt {
-        generic_from_unsigned_integer(int)
-    }
-}
-
-impl From<i128> for ChonkerInt {
-    fn from(int: i128) -> ChonkerInt {
-        generic_from_signed_integer(int)
-    }
-}
-
-impl From<i64> for ChonkerInt {
-    fn from(int: i64) -> ChonkerInt {
-        generic_from_signed_integer(int)
-    }
-}
-
-impl From<i32> for ChonkerInt {
-    fn from(int: i32) -> ChonkerInt {
-        generic_from_signed_integer(int)
-    }
-}
-
-impl From<i16> for ChonkerInt {
-    fn from(int: i16) -> ChonkerInt {
-        generic_from_signed_integer(int)
-    }
+// Implement conversions from every primitive integer type into a BigInt.
+// The macro receives a generic conversion function and a list of the integer types to use it for.
+macro_rules! impl_from_integer {
+    ($conversion_function:ident, $($int_type:ty),+) => {
+        $(
+            impl From<$int_type> for ChonkerInt {
+                fn from(int: $int_type) -> ChonkerInt {
+                    $conversion_function(int)
+                }
+            }
+        )+
+    };
 }
 
-impl From<i8> for ChonkerInt {
-    fn from(int: i8) -> ChonkerInt {
-        generic_from_signed_integer(int)
-    }
-}
+impl_from_integer!(generic_from_unsigned_integer, u8, u16, u32, u64, u128, usize);
+impl_from_integer!(generic_from_signed_integer, i8, i16, i32, i64, i128, isize);
 
 // For generic operations: num-traits = "0.2.14" crate should be added to the Cargo.toml
 // Convert a generic integer into a BigInt.
@@ -260,112 +170,290 @@ where
         sign = BigIntSign::Positive;
     }
 
-    let unsigned_int = (int.abs()).to_u128().unwrap_or(0);
+    // Take the magnitude through the 16 byte integer with unsigned_abs(),
+    // since abs() overflows for the minimal value of a signed integer type, e.g. i128::MIN.
+    let unsigned_int = int.to_i128().unwrap_or(0).unsigned_abs();
 
     // Define BigInt's vector, check for the integer being zero.
     digit_vector_produce(&unsigned_int, sign)
 }
 
-// Convert a string of digits, possibly with a minus/hyphnen sign, into a BigInt.
+// Parse a string of digits, possibly with a leading minus/hyphen sign, into a BigInt.
+// Only ASCII digits 0-9 are accepted after the optional sign, leading zeros are removed.
+// Empty strings, strings consisting only of the sign, or containing any other characters,
+// e.g. letters, whitespaces or repeated signs, are rejected with a descriptive error.
+impl FromStr for ChonkerInt {
+    type Err = OperationError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        // Check if the first character is minus/hyphen, if so, remove it and remember the negative sign.
+        let (sign, digits_string) = match string.strip_prefix('-') {
+            Some(unsigned_string) => (BigIntSign::Negative, unsigned_string),
+            None => (BigIntSign::Positive, string),
+        };
+
+        // Check if there are any digits to parse.
+        if digits_string.is_empty() {
+            return Err(OperationError::new(&format!("the received string \"{}\" for the BigInt conversion does not contain any digits. Correct value is a whole number, consisting of digits 0-9 with an optional leading minus/hyphen.", string)));
+        }
+
+        // Check if every other character is an ASCII digit, report the first incorrect one and its position.
+        if let Some((position, char)) = digits_string
+            .char_indices()
+            .find(|(_position, char)| !char.is_ascii_digit())
+        {
+            let position = position + (string.len() - digits_string.len());
+            return Err(OperationError::new(&format!("the received string \"{}\" for the BigInt conversion contains an incorrect character '{}' at the position {}. Correct value is a whole number, consisting of digits 0-9 with an optional leading minus/hyphen.", string, char, position)));
+        }
+
+        // Remove leading zeros, convert ASCII/UTF-8 representation of the digits into true integers
+        // and pack them into limbs of 9 digits, starting from the least significant end of the string.
+        // Store the limbs in the little endian format.
+        let digits: Vec<u32> = digits_string
+            .trim_start_matches('0')
+            .as_bytes()
+            .rchunks(RADIX_DIGITS)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0u32, |limb, byte| limb * 10 + (byte - ASCII_DIFF) as u32)
+            })
+            .collect();
+
+        // Check if the string consisted only from zeros, if so, return an empty BigInt.
+        if digits.is_empty() {
+            return Ok(ChonkerInt::new());
+        }
+
+        Ok(ChonkerInt { digits, sign })
+    }
+}
+
+// Convert a string of digits, possibly with a minus/hyphen sign, into a BigInt.
+// Kept for backwards compatibility, incorrect strings are silently turned into an empty/zero BigInt.
+// Prefer parsing with the FromStr trait, e.g. "123".parse::<ChonkerInt>(), to handle incorrect input.
 impl From<String> for ChonkerInt {
     fn from(string: String) -> ChonkerInt {
-        let mut char_iter = string.chars();
-        let mut sign = BigIntSign::Positive;
-        let mut digits = string.clone();
-
-        // Check if the first character is minus/hyphen or a number.
-        // If so, proceed with further checking.
-        // If not, return an empty BigInt.
-        if let Some(char) = char_iter.next() {
-            if !(char == '-' || char.is_numeric()) {
-                return ChonkerInt {
-                    digits: vec![],
-                    sign: BigIntSign::Zero,
-                };
-            } else if char == '-' {
-                // If the first char was negative and others numbers,
-                // remove the first hyphen char and change sign's value to negative.
-                sign = BigIntSign::Negative;
-                digits.remove(0);
-            }
-        }
+        string.parse().unwrap_or_else(|_| ChonkerInt::new())
+    }
+}
 
-        // Check if every other character is numeric.
-        // If the input string is incorrect, return an empty BigInt.
-        for char in char_iter {
-            if !char.is_numeric() {
-                return ChonkerInt {
-                    digits: vec![],
-                    sign: BigIntSign::Zero,
-                };
-            }
+// Parse a string of digits in the requested radix/base, possibly with a leading minus/hyphen sign, into a BigInt.
+// Radices 2-36 are supported, digits above 9 are represented by letters a-z regardless of their case.
+impl ChonkerInt {
+    pub fn from_str_radix(string: &str, radix: u32) -> Result<ChonkerInt, OperationError> {
+        // Check if the radix is supported.
+        if !(2..=36).contains(&radix) {
+            return Err(OperationError::new(&format!("the received radix {} for the BigInt conversion is not supported. Correct value is a radix in the range 2-36.", radix)));
         }
 
-        // Check for leading zeros, if present, remove them.
-        // Check if the first char was a minus/hyphen in the original string, if so, skip it.
-        let mut char_iter = string.chars();
-        if sign == BigIntSign::Negative {
-            char_iter.next();
+        // Check if the first character is minus/hyphen, if so, remove it and remember the negative sign.
+        let (sign, digits_string) = match string.strip_prefix('-') {
+            Some(unsigned_string) => (BigIntSign::Negative, unsigned_string),
+            None => (BigIntSign::Positive, string),
+        };
+
+        // Check if there are any digits to parse.
+        if digits_string.is_empty() {
+            return Err(OperationError::new(&format!("the received string \"{}\" for the BigInt conversion in radix {} does not contain any digits.", string, radix)));
         }
-        for char in char_iter {
-            if char == '0' {
-                digits.remove(0);
-            } else {
-                break;
-            }
+
+        // Accumulate the value in the limbs of the BigInt, starting from the most significant digit:
+        // multiply the already accumulated value by the radix and add the next digit to it.
+        let mut digits: Vec<u32> = vec![];
+        for (position, char) in digits_string.char_indices() {
+            let digit = match char.to_digit(radix) {
+                Some(digit) => digit,
+                None => {
+                    let position = position + (string.len() - digits_string.len());
+                    return Err(OperationError::new(&format!("the received string \"{}\" for the BigInt conversion contains an incorrect character '{}' at the position {} for the radix {}.", string, char, position, radix)));
+                }
+            };
+
+            multiply_add_digits(&mut digits, radix, digit);
         }
 
         // Check if the string consisted only from zeros, if so, return an empty BigInt.
-        let digits_length = digits.len();
-        if digits_length == 0 {
-            return ChonkerInt {
-                digits: vec![],
-                sign: BigIntSign::Zero,
-            };
+        if digits.is_empty() {
+            return Ok(ChonkerInt::new());
         }
 
-        // Convert string into a vector/slice of one byte unsigned integers, containing digits of a numbers.
-        // Subtract 48 from ASCII/UTF-8 representation of integers to get true integers.
-        let mut digits: Vec<i8> = unsafe { digits.as_bytes_mut() }
-            .iter_mut()
-            .map(|x| (*x - (ASCII_DIFF as u8)) as i8)
-            .collect();
-        digits.reverse();
+        Ok(ChonkerInt { digits, sign })
+    }
+}
 
-        ChonkerInt { digits, sign }
+// Turn BigInt into a string of its digits in the requested radix/base, in big endian format.
+// Radices 2-36 are supported, digits above 9 are represented by lowercase letters a-z.
+impl ChonkerInt {
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        // Check if the radix is supported.
+        if !(2..=36).contains(&radix) {
+            panic!("the requested radix {} for the BigInt conversion is not supported, correct radix is in the range 2-36 (ChonkerInt::to_str_radix)", radix);
+        }
+
+        // Work on a copy of the digits without leading zeros.
+        let mut digits = self.digits.clone();
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        // Check if the BigInt is zero.
+        if digits.is_empty() {
+            return "0".to_string();
+        }
+
+        // Repeatedly divide the value by the radix, the remainders are the digits in the target radix
+        // starting from the least significant one.
+        let mut radix_digits: Vec<char> = vec![];
+        while !digits.is_empty() {
+            let remainder = divide_digits(&mut digits, radix);
+            radix_digits.push(std::char::from_digit(remainder, radix).unwrap());
+        }
+
+        // Add the minus/hyphen if the BigInt is negative and reverse the digits into big endian format.
+        if self.sign == BigIntSign::Negative {
+            radix_digits.push('-');
+        }
+
+        radix_digits.iter().rev().collect()
+    }
+}
+
+// Divide the vector of limbs in the little endian format by a small divisor in place,
+// keeping the vector free of leading zeros, and return the remainder.
+pub(super) fn divide_digits(digits: &mut Vec<u32>, divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+
+    // Divide starting from the most significant limb, carrying the remainder to the next limb.
+    for digit in digits.iter_mut().rev() {
+        let dividend = remainder * RADIX as u64 + *digit as u64;
+        *digit = (dividend / divisor as u64) as u32;
+        remainder = dividend % divisor as u64;
+    }
+
+    // Remove the leading zeros left after the division.
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    remainder as u32
+}
+
+// Conversions between BigInt and raw bytes of its magnitude, e.g. for interoperability of RSA keys.
+// Unlike the conversion from a slice of decimal digits, every byte is a base 256 digit.
+// The bytes are treated as an unsigned magnitude: constructors produce non-negative BigInts,
+// exporters ignore the sign. Empty input produces zero and zero is exported as an empty vector.
+impl ChonkerInt {
+    // Construct a non-negative BigInt from bytes in the big endian format.
+    pub fn from_bytes_be(bytes: &[u8]) -> ChonkerInt {
+        let mut digits: Vec<u32> = vec![];
+
+        for byte in bytes {
+            multiply_add_digits(&mut digits, 256, *byte as u32);
+        }
+
+        // Check if the bytes were empty or only zeros, if so, return an empty BigInt.
+        if digits.is_empty() {
+            return ChonkerInt::new();
+        }
+
+        ChonkerInt {
+            digits,
+            sign: BigIntSign::Positive,
+        }
+    }
+
+    // Construct a non-negative BigInt from bytes in the little endian format.
+    pub fn from_bytes_le(bytes: &[u8]) -> ChonkerInt {
+        let bytes_be: Vec<u8> = bytes.iter().rev().copied().collect();
+
+        ChonkerInt::from_bytes_be(&bytes_be)
+    }
+
+    // Export the magnitude of the BigInt as bytes in the big endian format, without leading zero bytes.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+
+        bytes
+    }
+
+    // Export the magnitude of the BigInt as bytes in the little endian format, without trailing zero bytes.
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        // Work on a copy of the digits without leading zeros.
+        let mut digits = self.digits.clone();
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        // Repeatedly divide the value by 256, the remainders are the bytes starting from the least significant one.
+        let mut bytes: Vec<u8> = vec![];
+        while !digits.is_empty() {
+            bytes.push(divide_digits(&mut digits, 256) as u8);
+        }
+
+        bytes
+    }
+}
+
+// Multiply the vector of limbs in the little endian format by a small multiplier
+// and add a small addend to it in place, keeping the vector free of leading zeros.
+fn multiply_add_digits(digits: &mut Vec<u32>, multiplier: u32, addend: u32) {
+    let mut carry = addend as u64;
+
+    // Multiply every limb, add the carry from the previous, less significant, limb and keep the remainder.
+    for digit in digits.iter_mut() {
+        let product = *digit as u64 * multiplier as u64 + carry;
+        *digit = (product % RADIX as u64) as u32;
+        carry = product / RADIX as u64;
+    }
+
+    // Push the rest of the carry as new most significant limbs.
+    while carry > 0 {
+        digits.push((carry % RADIX as u64) as u32);
+        carry /= RADIX as u64;
     }
 }
 
+// Pack decimal digits in the little endian format into limbs of 9 digits in the little endian format,
+// leading zeros are not packed. The digits are expected to be in the range of 0-9.
+// Example: pack_decimal_digits(&[3, 2, 1, 0, 0, 0, 0, 0, 0, 7]) == vec![123, 7].
+pub(super) fn pack_decimal_digits(decimal_digits: &[u8]) -> Vec<u32> {
+    let mut digits: Vec<u32> = decimal_digits
+        .chunks(RADIX_DIGITS)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .rev()
+                .fold(0u32, |limb, digit| limb * 10 + *digit as u32)
+        })
+        .collect();
+
+    // Remove leading zeros.
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    digits
+}
+
 // Convert a one byte slice of digits into a positive BigInt.
 // It is assumed that the target one byte slice contains digits in the little endian format,
 // and values from the range of 0-9.
 impl From<&[u8]> for ChonkerInt {
     fn from(slice: &[u8]) -> ChonkerInt {
-        let mut digit_iter = slice.iter();
         let sign = BigIntSign::Positive;
-        let mut digits: Vec<i8> = digit_iter.map(|digit| *digit as i8).collect();
-        digit_iter = slice.iter();
 
         // Check if the numbers are in range of 0-9.
         let digit_range = 0..=9;
-        for digit in digit_iter {
+        for digit in slice.iter() {
             if !digit_range.contains(digit) {
                 panic!("the one byte slice for BigInt conversions contained values out of allowed range of 0-9 (From<&[u8]> for ChonkerInt)");
             }
         }
 
-        // Check for leading zeros, if present, remove them.
-        // Reverse digits to the big endian before check and back after.
-        digits.reverse();
-        let digit_iter = slice.iter().rev();
-        for digit in digit_iter {
-            if *digit == 0u8 {
-                digits.remove(0);
-            } else {
-                break;
-            }
-        }
-        digits.reverse();
+        // Pack the digits into limbs, leading zeros are removed.
+        let digits = pack_decimal_digits(slice);
 
         // Check if the vector consisted only from zeros, if so, return an empty BigInt.
         if digits.is_empty() {
@@ -383,7 +471,7 @@ impl From<&[u8]> for ChonkerInt {
 #[cfg(test)]
 mod tests {
     use crate::logic::bigint::conversion::digit_convert;
-    use crate::logic::bigint::ChonkerInt;
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
     use crate::logic::error::OperationError;
 
     // Test BigInt to string conversion.
@@ -406,6 +494,93 @@ mod tests {
         assert_eq!(zero_bigint3.to_string(), zero_bigint_string);
     }
 
+    // Test BigInt formatting with the Display trait in the corner cases.
+    #[test]
+    fn test_bigint_display_formatting() {
+        // Explicitly negated and positivized zeros should be printed without a sign.
+        let mut negated_zero_bigint = ChonkerInt::new();
+        negated_zero_bigint.set_negative_sign();
+        let mut positivized_zero_bigint = ChonkerInt::new();
+        positivized_zero_bigint.set_positive_sign();
+
+        // Leading zero limbs left after insertion of a vector of limbs should not be printed.
+        // Mind little endian.
+        let mut leading_zeros_bigint = ChonkerInt::new();
+        leading_zeros_bigint.push_vec(&[321, 0, 0, 0]);
+        let mut negative_leading_zeros_bigint = leading_zeros_bigint.clone();
+        negative_leading_zeros_bigint.set_negative_sign();
+        let mut only_zeros_bigint = ChonkerInt::new();
+        only_zeros_bigint.push_vec(&[0, 0, 0]);
+
+        // Very long values.
+        let long_string = "9".repeat(5000);
+        let long_bigint = ChonkerInt::from(long_string.clone());
+        let negative_long_bigint = ChonkerInt::from(format!("-{}", long_string));
+
+        assert_eq!(format!("{}", negated_zero_bigint), "0");
+        assert_eq!(format!("{}", positivized_zero_bigint), "0");
+        assert_eq!(format!("{}", leading_zeros_bigint), "321");
+        assert_eq!(format!("{}", negative_leading_zeros_bigint), "-321");
+        assert_eq!(format!("{}", only_zeros_bigint), "0");
+        assert_eq!(format!("{}", long_bigint), long_string);
+        assert_eq!(
+            format!("{}", negative_long_bigint),
+            format!("-{}", long_string)
+        );
+
+        // Check that the formatter parameters are respected.
+        assert_eq!(format!("{:>6}", ChonkerInt::from(-123)), "  -123");
+        assert_eq!(format!("{:06}", ChonkerInt::from(-123)), "-00123");
+        assert_eq!(format!("{:+}", ChonkerInt::from(123)), "+123");
+        assert_eq!(format!("{:<4}|", ChonkerInt::new()), "0   |");
+    }
+
+    // Test conversion of the extreme values of every primitive integer type into a BigInt and back.
+    #[test]
+    fn test_primitive_integer_conversion() {
+        // Compare the BigInt produced from the integer with the integer's own string representation,
+        // and with the BigInt parsed from that string.
+        macro_rules! assert_round_trip {
+            ($($int:expr),+) => {
+                $(
+                    let bigint = ChonkerInt::from($int);
+                    assert_eq!(bigint.to_string(), $int.to_string());
+                    assert_eq!($int.to_string().parse::<ChonkerInt>().unwrap(), bigint);
+                )+
+            };
+        }
+
+        assert_round_trip!(u8::MIN, u8::MAX, u16::MIN, u16::MAX, u32::MIN, u32::MAX);
+        assert_round_trip!(u64::MIN, u64::MAX, u128::MIN, u128::MAX, usize::MIN, usize::MAX);
+        assert_round_trip!(i8::MIN, i8::MAX, i16::MIN, i16::MAX, i32::MIN, i32::MAX);
+        assert_round_trip!(i64::MIN, i64::MAX, i128::MIN, i128::MAX, isize::MIN, isize::MAX);
+
+        // Check the signs of the produced BigInts.
+        assert_eq!(*ChonkerInt::from(0usize).get_sign(), BigIntSign::Zero);
+        assert_eq!(*ChonkerInt::from(-1isize).get_sign(), BigIntSign::Negative);
+        assert_eq!(*ChonkerInt::from(i8::MIN).get_sign(), BigIntSign::Negative);
+        assert_eq!(*ChonkerInt::from(u8::MAX).get_sign(), BigIntSign::Positive);
+    }
+
+    // Test conversion of the minimal, maximal and zero 16 byte signed integers into a BigInt.
+    // The minimal value can not be negated inside its own type and must not cause an overflow.
+    #[test]
+    fn test_signed_integer_extremes_conversion() {
+        let min_bigint = ChonkerInt::from(i128::MIN);
+        let max_bigint = ChonkerInt::from(i128::MAX);
+        let zero_bigint = ChonkerInt::from(0i128);
+
+        assert_eq!(min_bigint.to_string(), "-170141183460469231731687303715884105728");
+        assert_eq!(*min_bigint.get_sign(), BigIntSign::Negative);
+        assert_eq!(max_bigint.to_string(), "170141183460469231731687303715884105727");
+        assert_eq!(*max_bigint.get_sign(), BigIntSign::Positive);
+        assert_eq!(zero_bigint.to_string(), "0");
+        assert_eq!(zero_bigint, ChonkerInt::new());
+
+        // The magnitude of the minimal value is bigger by one than the maximal value.
+        assert_eq!(&(-&min_bigint) - &max_bigint, ChonkerInt::from(1));
+    }
+
     // Test BigInt to unsigned 16 byte integer conversion.
     #[test]
     fn test_bigint_to_unsigned_integer_conversion() {
@@ -429,6 +604,22 @@ mod tests {
         assert_eq!(zero_bigint3.to_digit(), zero_bigint_number);
     }
 
+    // Test checked BigInt to unsigned 16 byte integer conversion.
+    #[test]
+    fn test_bigint_to_u128_checked_conversion() {
+        let max_bigint = ChonkerInt::from(u128::MAX);
+        let too_big_bigint = &max_bigint + &ChonkerInt::from(1);
+        let longer_bigint = "3402823669209384634633746074317682114550".parse::<ChonkerInt>().unwrap();
+        let negative_bigint = ChonkerInt::from(-12300);
+
+        assert_eq!(max_bigint.to_u128().unwrap(), u128::MAX);
+        assert_eq!(ChonkerInt::from(12300).to_u128().unwrap(), 12300u128);
+        assert_eq!(ChonkerInt::new().to_u128().unwrap(), 0u128);
+        assert!(too_big_bigint.to_u128().is_err());
+        assert!(longer_bigint.to_u128().is_err());
+        assert!(negative_bigint.to_u128().is_err());
+    }
+
     // Test u128 integer conversion into a BigInt
     #[test]
     fn test_digit_conversion() {
@@ -443,14 +634,12 @@ mod tests {
             }
         };
 
-        // let comparison_vec: Vec<i8> = vec![3,4,0,2,8,2,3,6,6,9,2,0,9,3,8,4,6,3,4,6,3,3,7,4,6,0,7,4,3,1,7,6,8,2,1,1,4,5,5,];
-        let mut comparison_vec: Vec<i8> = vec![
-            1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2,
-            3, 1, 2, 3, 1, 2, 3, 1,
-        ];
-        comparison_vec.reverse();
+        // Limbs of 9 digits in the little endian format.
+        let comparison_vec: Vec<u32> = vec![231231231, 231231231, 231231231, 231231231, 1];
 
         assert_eq!(result, comparison_vec);
+        assert_eq!(digit_convert(&0).unwrap(), vec![0]);
+        assert_eq!(digit_convert(&1_000_000_000).unwrap(), vec![0, 1]);
     }
 
     // Test string conversion into a BigInt
@@ -476,6 +665,133 @@ mod tests {
         assert_eq!(*other_result.get_vec(), other_comparison_vec);
     }
 
+    // Test parsing of a string into a BigInt through the FromStr trait, including incorrect input.
+    #[test]
+    fn test_string_parsing() {
+        let result = "-000123".parse::<ChonkerInt>().unwrap();
+        let mut comparison_vec: Vec<i8> = vec![1, 2, 3];
+        comparison_vec.reverse();
+
+        assert_eq!(*result.get_vec(), comparison_vec);
+        assert_eq!(*result.get_sign(), BigIntSign::Negative);
+
+        // Negative zero is turned into a signless empty BigInt.
+        assert_eq!("-0".parse::<ChonkerInt>().unwrap(), ChonkerInt::new());
+        assert_eq!("0000".parse::<ChonkerInt>().unwrap(), ChonkerInt::new());
+
+        // Incorrect strings produce errors instead of a zero BigInt.
+        assert!("12a3".parse::<ChonkerInt>().is_err());
+        assert!("--5".parse::<ChonkerInt>().is_err());
+        assert!("".parse::<ChonkerInt>().is_err());
+        assert!("-".parse::<ChonkerInt>().is_err());
+        assert!(" 42 ".parse::<ChonkerInt>().is_err());
+        assert!("+42".parse::<ChonkerInt>().is_err());
+        assert!("４２".parse::<ChonkerInt>().is_err());
+
+        // The error message points to the incorrect character.
+        let error = "12a3".parse::<ChonkerInt>().unwrap_err();
+        assert!(error.to_string().contains("'a' at the position 2"));
+
+        // The backwards compatible conversion still returns zero for incorrect strings.
+        assert_eq!(ChonkerInt::from(String::from("12a3")), ChonkerInt::new());
+    }
+
+    // Test parsing of a string in a specific radix into a BigInt.
+    #[test]
+    fn test_string_radix_parsing() {
+        assert_eq!(ChonkerInt::from_str_radix("ff", 16).unwrap(), ChonkerInt::from(255));
+        assert_eq!(ChonkerInt::from_str_radix("-FF", 16).unwrap(), ChonkerInt::from(-255));
+        assert_eq!(ChonkerInt::from_str_radix("101", 2).unwrap(), ChonkerInt::from(5));
+        assert_eq!(ChonkerInt::from_str_radix("zZ", 36).unwrap(), ChonkerInt::from(1295));
+        assert_eq!(ChonkerInt::from_str_radix("0000", 8).unwrap(), ChonkerInt::new());
+        assert_eq!(ChonkerInt::from_str_radix("-0", 16).unwrap(), ChonkerInt::new());
+        assert_eq!(
+            ChonkerInt::from_str_radix("ffffffffffffffffffffffffffffffff", 16).unwrap(),
+            ChonkerInt::from(u128::MAX)
+        );
+
+        // 512 bit hexadecimal number compared with its decimal representation.
+        let hex_string = "c8d4f5a3b1e2970f6a5d4c3b2a1908f7e6d5c4b3a29180f7e6d5c4b3a2918076\
+                          5f4e3d2c1b0a9988776655443322110ffeeddccbbaa99887766554433221100f";
+        let decimal_string = "10518418677905557258054191634273636891446931524626247160693595361103619833389042198557952728040855395171735355245636160217044769678455301058336025628184591";
+        assert_eq!(
+            ChonkerInt::from_str_radix(hex_string, 16).unwrap(),
+            decimal_string.parse::<ChonkerInt>().unwrap()
+        );
+
+        // Incorrect input.
+        assert!(ChonkerInt::from_str_radix("12", 1).is_err());
+        assert!(ChonkerInt::from_str_radix("12", 37).is_err());
+        assert!(ChonkerInt::from_str_radix("102", 2).is_err());
+        assert!(ChonkerInt::from_str_radix("fg", 16).is_err());
+        assert!(ChonkerInt::from_str_radix("", 16).is_err());
+        assert!(ChonkerInt::from_str_radix("-", 16).is_err());
+        assert!(ChonkerInt::from_str_radix("--f", 16).is_err());
+    }
+
+    // Test conversion of a BigInt into a string in a specific radix.
+    #[test]
+    fn test_bigint_to_string_radix_conversion() {
+        assert_eq!(ChonkerInt::from(255).to_str_radix(16), "ff");
+        assert_eq!(ChonkerInt::from(-255).to_str_radix(16), "-ff");
+        assert_eq!(ChonkerInt::from(5).to_str_radix(2), "101");
+        assert_eq!(ChonkerInt::from(1295).to_str_radix(36), "zz");
+        assert_eq!(ChonkerInt::new().to_str_radix(8), "0");
+        assert_eq!(ChonkerInt::from(u128::MAX).to_str_radix(16), format!("{:x}", u128::MAX));
+
+        // Radix 10 agrees with the Display implementation.
+        let decimal_bigint = ChonkerInt::new_rand(&300, &BigIntSign::Negative);
+        assert_eq!(decimal_bigint.to_str_radix(10), decimal_bigint.to_string());
+
+        // Round trip of multi-hundred digit values through the radix parsing.
+        for radix in [2, 16, 36] {
+            let positive_bigint = ChonkerInt::new_rand(&300, &BigIntSign::Positive);
+            let negative_bigint = ChonkerInt::new_rand(&300, &BigIntSign::Negative);
+
+            assert_eq!(
+                ChonkerInt::from_str_radix(&positive_bigint.to_str_radix(radix), radix).unwrap(),
+                positive_bigint
+            );
+            assert_eq!(
+                ChonkerInt::from_str_radix(&negative_bigint.to_str_radix(radix), radix).unwrap(),
+                negative_bigint
+            );
+        }
+    }
+
+    // Test conversion of a BigInt into a string with an unsupported radix.
+    #[test]
+    #[should_panic]
+    fn test_bigint_to_string_radix_conversion_unsupported_radix() {
+        ChonkerInt::from(255).to_str_radix(37);
+    }
+
+    // Test conversion of a BigInt into raw bytes and back.
+    #[test]
+    fn test_bigint_bytes_conversion() {
+        let bigint = ChonkerInt::from(0x0102030405u64);
+
+        assert_eq!(bigint.to_bytes_be(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(bigint.to_bytes_le(), vec![5, 4, 3, 2, 1]);
+        assert_eq!(ChonkerInt::from_bytes_be(&[1, 2, 3, 4, 5]), bigint);
+        assert_eq!(ChonkerInt::from_bytes_le(&[5, 4, 3, 2, 1]), bigint);
+
+        // Leading zero bytes are ignored, zero is exported as an empty vector.
+        assert_eq!(ChonkerInt::from_bytes_be(&[0, 0, 1, 0]), ChonkerInt::from(256));
+        assert_eq!(ChonkerInt::from_bytes_be(&[]), ChonkerInt::new());
+        assert_eq!(ChonkerInt::from_bytes_le(&[0, 0]), ChonkerInt::new());
+        assert!(ChonkerInt::new().to_bytes_be().is_empty());
+
+        // Only the magnitude is exported.
+        assert_eq!(ChonkerInt::from(-258).to_bytes_be(), vec![1, 2]);
+        assert_eq!(ChonkerInt::from(u128::MAX).to_bytes_be(), u128::MAX.to_be_bytes().to_vec());
+
+        // Round trip of multi-hundred digit values.
+        let big_bigint = ChonkerInt::new_rand(&300, &BigIntSign::Positive);
+        assert_eq!(ChonkerInt::from_bytes_be(&big_bigint.to_bytes_be()), big_bigint);
+        assert_eq!(ChonkerInt::from_bytes_le(&big_bigint.to_bytes_le()), big_bigint);
+    }
+
     // Test one byte slice conversion into a BigInt
     #[test]
     fn test_unsigned_one_byte_slice_conversion() {
@@ -1,325 +1,267 @@
 // BigInt module regarding division of BigInts.
-// Paper on Bernikel Zielger’s recursive division algorithm https://citeseerx.ist.psu.edu/viewdoc/download?doi=10.1.1.47.565&rep=rep1&type=pdf
+// The long division follows Knuth, The Art of Computer Programming, Vol. 2, section 4.3.1, Algorithm D.
 
 use std::ops::Div;
 
+use crate::logic::bigint::conversion::divide_digits;
 use crate::logic::bigint::{BigIntSign, ChonkerInt, RADIX};
+use crate::logic::error::OperationError;
 
 // Implement division "/" operator for the BigInt
-// Division and remainder calculation were achieved with Quotient Estimation Algorithm,
-// based on on Lemma 2 from Bernikel Zielger’s recursive division algorithm paper.
-// Algorithm says that if A (the dividend) is at most l digits longer than B (the divisor) and B is normalized
-// (more than half the value if all digits were max. value, e.g. 444 is not normalized because it is less than 999/2).
-// A is also less than RADIX*B.
-// Quotient has to fit into one digit under the RADIX. In decimal system, allowed values would be in the range of 0-9.
-// Then you can estimate the quotient to be the division of A be B,
-// where we exclude the lowest k digits of both numbers (where k is B’s length minus l).
-// This estimate is at most two more than the actual quotient.
-
-// Example: In the decimal system: 378,546÷78,356.
-//
-//     378,546 is (atmost) 1 digit longer than 78,356 (l = 1).
-//     Hence, k = 5 – 1=4, where 5 = length(78,356) and 1= l.
-//     78,356 is normalized — it’s greater than half of 99999.
-//     Clearly, 378,546 is less than 10 times 78,356 (and 10 is our radix); hence, this is a valid use case for us.
+// Division and remainder calculation are achieved with the classic long division: Knuth's Algorithm D
+// (The Art of Computer Programming, Vol. 2, section 4.3.1), working on the limbs in the base of RADIX.
+// The quotient is calculated one limb at a time, starting from the most significant one.
+// Every quotient limb is estimated from the top two limbs of the partial remainder and the top limb of the divisor.
+// If the divisor is normalized (its most significant limb is at least RADIX / 2),
+// the estimate is at most two more than the actual quotient limb, one correction is done with the second limb
+// of the divisor before the multiplication, the rare remaining one by adding the divisor back after the subtraction.
 //
-// Hence, our quotient estimate will be division of 37/7 (excluding lower k digits) which is 5. Now,
-// 5 is at most 2 more than the actual quotient — hence, q can be 3, 4, or 5.
+// Example: In the decimal system: 378,546÷78,356.
 //
-//     78,356*5 = 391,780 (wrong)
-//     78,356*4 = 313,424 (right, where remainder r is 65122)
-//     don’t check for 3 now — we found q already.
+//     78,356 is normalized — its most significant digit 7 is at least 10 / 2.
+//     The estimate from the top digits is 37/7 = 5, while the true quotient is 4:
+//     78,356*5 = 391,780 (too big), 78,356*4 = 313,424 (right, where remainder r is 65,122).
 //
 // Normalization of B is ensured with fractional equivalency.
-//
-// We need to multiply A and B by a number such that B becomes normalized, if not already.
-// That number is equal Math.floor(radix / (B._digits[0] + 1)) in code.
-// This ensures that the first digit of B is as high as it can get without increasing the number of digits.
+// Both the dividend and the divisor are multiplied by a single scaling factor Math.floor(RADIX / (B's top limb + 1)),
+// which makes the most significant limb of B as high as it can get without increasing the number of limbs.
+// The quotient does not change, the remainder is divided back by the same factor at the end.
 
 impl<'a, 'b> Div<&'b ChonkerInt> for &'a ChonkerInt {
     type Output = ChonkerInt;
 
     fn div(self, rhs: &'b ChonkerInt) -> Self::Output {
-        // Check for division by zero, if the divisor is zero, panic.
-        if *rhs == ChonkerInt::new() || rhs.digits.is_empty() {
-            panic!("cannot divide by zero (ChonkerInt::div())");
-        }
-
-        // Check if the zero is divided, if the dividend is zero, return zero.
-        if *self == ChonkerInt::new() || self.digits.is_empty() {
-            return ChonkerInt::new();
-        }
-
-        // Clone dividend and divisor, make them absolute for comparisons.
-        let mut absolute_dividend = (*self).clone();
-        absolute_dividend.set_positive_sign();
-        let mut absolute_divisor = (*rhs).clone();
-        absolute_divisor.set_positive_sign();
-
-        // Compare the lengths/values of the dividend and divisor.
-        // If self/dividend is smaller that the divisor, return empty/zero BigInt as a quotient/result.
-        // If self/dividend is equal to the divisor, return 1 or -1 as a BigInt.
-        // If self/dividend is bigger that the divisor, proceed with calculations.
-        if absolute_dividend < absolute_divisor {
-            return ChonkerInt::new();
-        } else if *self == *rhs && *self == absolute_divisor {
-            // If self/dividend and rhs/divisor are positive.
-            return ChonkerInt::from(1);
-        } else if *self != *rhs && *self == absolute_divisor {
-            // If self/dividend is positive, and rhs/divisor is negative.
-            return ChonkerInt::from(-1);
-        } else if *self == *rhs && *self != absolute_divisor {
-            // If self/dividend and rhs/divisor are negative.
-            return ChonkerInt::from(1);
-        } else if *self != *rhs && absolute_dividend == *rhs {
-            // If self/dividend is negative, and rhs/divisor is positive.
-            return ChonkerInt::from(-1);
+        match self.try_div(rhs) {
+            Ok(result) => result,
+            Err(error) => panic!("{} (ChonkerInt::div())", error),
         }
+    }
+}
 
-        let mut quotient = ChonkerInt::new();
-
-        let mut cut_dividend = ChonkerInt::new();
-        cut_dividend.set_positive_sign();
-
-        // Compare lengths of the dividend and divisor and normalization of the most significant digits.
-        // Length of the dividend should not be more than length of the divisor + 1.
-        // If it is, cut the dividend.
-        if self.digits.len() > rhs.digits.len()
-        {
-            // Cut the dividend to the smaller size of divisor's length with or without additional digit,
-            // calculate the quotient digit and the remainder that will be used as the dividend,
-            // and then repeat the process by adding digits from the original dividend to the remainders one by one in the loop.
-
-            let mut dividend_index = self.digits.len();
-            // let mut difference = rhs.digits.len() + 1;
-            let mut difference;
-
-            // Check normalization of the divisor's most significant digit and compare it to the divident's digit,
-            // if divisor's digit is not normalized, and it is smaller than the dividend's one,
-            // make the difference/required length for the cut dividend equal to the divisor's length;
-            // otherwise add 1 to it.
-            if (self.digits[self.digits.len() - 1] > rhs.digits[rhs.digits.len() - 1])
-                || (absolute_dividend > absolute_divisor)
-            {
-                difference = rhs.digits.len();
-            } else {
-                difference = rhs.digits.len() + 1;
-            }
-
-            let cut_dividend_splice =
-                &self.digits[(dividend_index - difference)..=(dividend_index - 1)];
+impl ChonkerInt {
+    // Divide the BigInt by another one, return an error instead of panicking if the divisor is zero.
+    pub fn checked_div(&self, rhs: &ChonkerInt) -> Result<ChonkerInt, OperationError> {
+        let (quotient, _remainder) = self.checked_div_rem(rhs)?;
 
-            for digit in cut_dividend_splice.iter().rev() {
-                cut_dividend.digits.insert(0, *digit);
-            }
+        Ok(quotient)
+    }
 
-            let (mut quotient_digit, remainder_digit) =
-                quotient_estimation_algorithm(&cut_dividend, rhs);
+    // Divide the BigInt by another one, return an error if the divisor is zero
+    // or the long division reached an impossible state. The "/" operator unwraps the result.
+    pub fn try_div(&self, rhs: &ChonkerInt) -> Result<ChonkerInt, OperationError> {
+        self.checked_div(rhs)
+    }
 
-            if quotient_digit.digits.len() > 1 {
-                quotient_digit.digits.reverse();
-            }
+    // Divide the BigInt by another one and return both the quotient and the remainder: (quotient, remainder).
+    // The quotient is truncated towards zero and the remainder follows the sign of the dividend,
+    // like division and remainder of the primitive integers, thus quotient * rhs + remainder == self.
+    // Note: the "%" operator implements the modulo operation, which follows the sign of the divisor instead.
+    pub fn div_rem(&self, rhs: &ChonkerInt) -> (ChonkerInt, ChonkerInt) {
+        match self.checked_div_rem(rhs) {
+            Ok(result) => result,
+            Err(error) => panic!("{} (ChonkerInt::div_rem())", error),
+        }
+    }
 
-            quotient.push_vec(&quotient_digit.digits);
+    // Calculate the quotient and the remainder in one pass of the long division,
+    // return an error instead of panicking if the divisor is zero or the long division reached an impossible state.
+    pub fn checked_div_rem(&self, rhs: &ChonkerInt) -> Result<(ChonkerInt, ChonkerInt), OperationError> {
+        // Check for division by zero, if the divisor is zero, return an error.
+        if rhs.significant_digits().is_empty() {
+            return Err(OperationError::new("attempt to divide/take modulus by zero, the divisor is zero."));
+        }
 
-            cut_dividend = remainder_digit;
+        // Check if the zero is divided, if the dividend is zero, return zeros.
+        if *self == ChonkerInt::new() || self.digits.is_empty() {
+            return Ok((ChonkerInt::new(), ChonkerInt::new()));
+        }
 
-            dividend_index -= difference;
+        // Check if the absolute value of the dividend is smaller than the divisor's,
+        // if so, return a zero quotient and the dividend as a remainder.
+        if self.abs() < rhs.abs() {
+            let mut remainder = (*self).clone();
+            remainder.normalize();
+            return Ok((ChonkerInt::new(), remainder));
+        }
 
-            difference = 1;
+        // Divide the magnitudes, operate only on the significant limbs.
+        let (quotient_digits, remainder_digits) =
+            long_division(self.significant_digits(), rhs.significant_digits())?;
 
-            // Loop over the dividend's digits one by one and add them to the remainder from the previous iteration.
-            while dividend_index > 0 {
-                let cut_dividend_splice =
-                    &self.digits[(dividend_index - difference)..=(dividend_index - 1)];
+        // Determine the sign of the quotient.
+        // Check the signs of both operands, if they are not the same, the resulting sign is negative.
+        // Normalization sets the sign of a zero quotient.
+        let mut quotient = ChonkerInt {
+            digits: quotient_digits,
+            sign: if self.sign != rhs.sign {
+                BigIntSign::Negative
+            } else {
+                BigIntSign::Positive
+            },
+        };
+        quotient.normalize();
 
-                // Add remaining digits form the dividend to the remainder from previous division operation.
-                // Preserve little endian from the dividend with reverse of the iterator over the dividend.
-                for digit in cut_dividend_splice.iter().rev() {
-                    cut_dividend.digits.insert(0, *digit);
-                }
-                cut_dividend.set_positive_sign();
-                cut_dividend.normalize();
-
-                // Check if the dividend became shorter/smaller than the divisor, if so,
-                // add a quotient digit of zero and proceed to the next iteration.
-                if cut_dividend < absolute_divisor {
-                    let _ = quotient.push(0);
-                    dividend_index -= difference;
-                    continue;
-                }
+        // Determine the sign of the remainder, it follows the sign of the dividend.
+        let mut remainder = ChonkerInt {
+            digits: remainder_digits,
+            sign: if self.sign == BigIntSign::Negative {
+                BigIntSign::Negative
+            } else {
+                BigIntSign::Positive
+            },
+        };
+        remainder.normalize();
 
-                let (mut quotient_digit, remainder_digit) =
-                    quotient_estimation_algorithm(&cut_dividend, rhs);
+        Ok((quotient, remainder))
+    }
 
-                // If quotient has several digit, then it stored in the little endian by default.
-                // But separate quotient digits are stored in the big endian format.
-                // As the result, if the partial quotient has several digits by itself,
-                // it has to be reversed.
-                if quotient_digit.digits.len() > 1 {
-                    quotient_digit.digits.reverse();
-                }
+    // Divide the BigInt by 2 in place, truncating towards zero like the "/" operator,
+    // with a single pass over the limbs instead of the long division.
+    // Example: ChonkerInt::from(-7) halved in place is ChonkerInt::from(-3).
+    pub fn halve_in_place(&mut self) {
+        self.normalize();
+        divide_digits(&mut self.digits, 2);
+        self.normalize();
+    }
+}
 
-                // Save the quotient digit.
-                quotient.push_vec(&quotient_digit.digits);
+// Divide the magnitudes given as limbs in the little endian format without leading zeros,
+// the dividend has to be at least as long as the divisor.
+// Return the limbs of the quotient and the remainder in form of a tuple: (quotient, remainder),
+// or an error if the long division reached an impossible state.
+fn long_division(dividend: &[u32], divisor: &[u32]) -> Result<(Vec<u32>, Vec<u32>), OperationError> {
+    let radix = RADIX as u64;
+    let divisor_length = divisor.len();
+
+    // Check if the divisor has a single limb, if so, the short division is enough.
+    if divisor_length == 1 {
+        let mut quotient = dividend.to_vec();
+        let remainder = divide_digits(&mut quotient, divisor[0]);
+        return Ok((quotient, vec![remainder]));
+    }
 
-                // Use the remainder digit(-s) for the new partial dividend.
-                // Reverse digits from little endian to big endian.
-                cut_dividend = remainder_digit.clone();
+    // Normalize the divisor, scale both operands by the same factor.
+    // The scaled divisor keeps its length, the scaled dividend gets an additional most significant limb.
+    let scale = radix / (divisor[divisor_length - 1] as u64 + 1);
+    let divisor = scale_digits(divisor, scale);
+    let mut remainder = scale_digits(dividend, scale);
+    if remainder.len() == dividend.len() {
+        remainder.push(0);
+    }
 
-                dividend_index -= difference;
+    // Two most significant limbs of the divisor, used for the estimates.
+    let divisor_top = divisor[divisor_length - 1] as u64;
+    let divisor_second = divisor[divisor_length - 2] as u64;
+
+    let quotient_length = dividend.len() - divisor_length + 1;
+    let mut quotient = vec![0u32; quotient_length];
+
+    // Calculate the quotient limbs starting from the most significant one.
+    for index in (0..quotient_length).rev() {
+        // Estimate the quotient limb from the two most significant limbs of the partial remainder.
+        let remainder_top = remainder[index + divisor_length] as u64 * radix
+            + remainder[index + divisor_length - 1] as u64;
+        let mut estimate = remainder_top / divisor_top;
+        let mut estimate_remainder = remainder_top % divisor_top;
+
+        // Correct the estimate with the second limb of the divisor, it can be too big at most by 2.
+        while estimate >= radix
+            || estimate * divisor_second
+                > estimate_remainder * radix + remainder[index + divisor_length - 2] as u64
+        {
+            estimate -= 1;
+            estimate_remainder += divisor_top;
+            if estimate_remainder >= radix {
+                break;
             }
-
-            // Digits of the quotient were stored in big endian during calculation, reverse the vector of digits.
-            quotient.digits.reverse();
-        } else {
-            // If lengths of dividend and divisor are equal.
-            let (quotient_digit, _remainder_digit) = quotient_estimation_algorithm(self, rhs);
-            // Save the quotient digit.
-            quotient.push_vec(&quotient_digit.digits);
         }
 
-        // Determine the sign of the quotient.
-        // Check the signs of both operands, if they are not the same, the resulting sign is negative.
-        // By default the sign is zero, check if it should be change wiht the digits vector's length.
-        // If the signs are the same and positive, the resulting sign is positive.
-        // If the signs are the same and negative, the resulting sign is positive.
-        if !quotient.digits.is_empty() {
-            if self.sign != rhs.sign {
-                quotient.set_negative_sign();
+        // Multiply the divisor by the estimate and subtract it from the partial remainder.
+        let mut carry = 0u64;
+        let mut borrow = 0i64;
+        for position in 0..divisor_length {
+            let product = estimate * divisor[position] as u64 + carry;
+            carry = product / radix;
+
+            let difference = remainder[index + position] as i64 - (product % radix) as i64 - borrow;
+            if difference < 0 {
+                remainder[index + position] = (difference + radix as i64) as u32;
+                borrow = 1;
             } else {
-                quotient.set_positive_sign();
+                remainder[index + position] = difference as u32;
+                borrow = 0;
             }
         }
+        let difference = remainder[index + divisor_length] as i64 - carry as i64 - borrow;
+
+        // Check if the subtraction went below zero, if so, the estimate was still too big by one:
+        // decrease it and add the divisor back, the carry out of the most significant limb cancels the borrow.
+        if difference < 0 {
+            remainder[index + divisor_length] = (difference + radix as i64) as u32;
+            estimate -= 1;
+
+            let mut carry = 0u64;
+            for position in 0..divisor_length {
+                let sum = remainder[index + position] as u64 + divisor[position] as u64 + carry;
+                remainder[index + position] = (sum % radix) as u32;
+                carry = sum / radix;
+            }
+            let sum = remainder[index + divisor_length] as u64 + carry;
+            remainder[index + divisor_length] = (sum % radix) as u32;
+        } else {
+            remainder[index + divisor_length] = difference as u32;
+        }
 
-        // Cut the leading zeros.
-        quotient.normalize();
-        quotient
+        quotient[index] = estimate as u32;
     }
-}
 
-// Calculate division or modulus depending on the mode.
-// Dividend should be bigger than the divisor, thus dividend should be longer or equal in length to the divisor.
-// The function returns separate digits of the quotient or the remainder,
-// in form of a tuple: (quotient, remainder)
-pub fn quotient_estimation_algorithm(
-    dividend: &ChonkerInt,
-    divisor: &ChonkerInt,
-) -> (ChonkerInt, ChonkerInt) {
-    // Make dividends and divisors absolute, positive.
-    let mut dividend_original = (*dividend).clone();
-    dividend_original.set_positive_sign();
-    let mut dividend = (*dividend).clone();
-    dividend.set_positive_sign();
-    let mut divisor_original = (*divisor).clone();
-    divisor_original.set_positive_sign();
-    let mut divisor = (*divisor).clone();
-    divisor.set_positive_sign();
-
-    // Normalize divisor and calculate the coefficient for the fractional equivalency.
-    let coefficient = RADIX / (divisor.digits[divisor.digits.len() - 1] + 1);
-
-    // Check if the calculated equivalency bigger than 1, if it is,
-    // use it to increase dividend and divisor.
-    if coefficient > 1 {
-        let bigint_coefficient = ChonkerInt::from(coefficient as i128);
-        dividend = &dividend * &bigint_coefficient;
-        divisor = &divisor * &bigint_coefficient;
+    // Remove the leading zeros of the quotient.
+    while quotient.last() == Some(&0) {
+        quotient.pop();
     }
 
-    let mut quotient;
-    let remainder;
-
-    // Calculate quotient estimate. If dividend's length is equal or longer by 1 than the divisor's.
-    if (dividend.digits.len()) == (divisor.digits.len() + 1) {
-        quotient = ChonkerInt::from(
-            ((dividend.digits[dividend.digits.len() - 1] * RADIX
-                + dividend.digits[dividend.digits.len() - 2])
-                / divisor.digits[divisor.digits.len() - 1]) as i128,
-        );
-    } else if dividend.digits.len() == divisor.digits.len() {
-        quotient = ChonkerInt::from(
-            (dividend.digits[dividend.digits.len() - 1] / divisor.digits[divisor.digits.len() - 1])
-                as i128,
-        );
-    } else {
-        panic!("dividend has to be equal in length or longer by 1 than the divisor (division_or_modulus_calculation)")
+    // The remainder fits into the divisor's limbs, divide it back by the scaling factor.
+    // The scaled remainder has to be divisible by the factor, otherwise the division went wrong.
+    remainder.truncate(divisor_length);
+    while remainder.last() == Some(&0) {
+        remainder.pop();
     }
-
-    // Calculate a product between calculated quotient modified divisor.
-    let mut check_quotient_product = &quotient * &divisor_original;
-
-    // Create an empty/zero BigInt for comparison with the estimated quotient,
-    // if they are equal, create a BigInt, containing zero and return as the quotient.
-    let empty_bigint = ChonkerInt::new();
-
-    // Check the quotient estimate, if it does not suit, reduce it by 1 up to 3 times.
-    // If it does suit, exit the loop.
-    for delta in 0..=3 {
-        check_quotient_product.set_positive_sign();
-        if quotient == empty_bigint {
-            break;
-        }
-        // if check_quotient_product <= dividend  {
-        if check_quotient_product <= dividend_original {
-            break;
-        }
-
-        quotient = &quotient - &ChonkerInt::from(1);
-        check_quotient_product = &check_quotient_product - &divisor_original;
-
-        // There is an error in the algorithm, if after three reductions in the quotient,
-        // quotient is still unsuitable and we reach this point.
-        if delta == 3 {
-            panic!("error with the division algorithm (division_or_modulus_calculation)")
-        }
+    if divide_digits(&mut remainder, scale as u32) != 0 {
+        return Err(OperationError::new("the long division left a remainder, which is not divisible by the scaling factor of the normalization."));
     }
 
-    remainder = &dividend_original - &(&quotient * &divisor_original);
-
-    if quotient == empty_bigint {
-        quotient.digits.push(0);
-    }
-    (quotient, remainder)
+    Ok((quotient, remainder))
 }
 
-// Bruteforce method of calculating the division, the bigger the quotient the more time it takes to compute.
-// Time requirement increases exponentially, to compute very big quotients.
-// While recursive method provides immediate answer, bruteforce could not calculate the result for several minutes.
-fn bruteforce_division(dividend: &ChonkerInt, divisor: &ChonkerInt) -> (ChonkerInt, ChonkerInt) {
-    // Make dividends and divisors absolute, positive.
-    let mut dividend_original = (*dividend).clone();
-    dividend_original.set_positive_sign();
-    let mut dividend = (*dividend).clone();
-    dividend.set_positive_sign();
-    let mut divisor_original = (*divisor).clone();
-    divisor_original.set_positive_sign();
-    let mut divisor = (*divisor).clone();
-    divisor.set_positive_sign();
-
-    let zero_bigint = ChonkerInt::new();
-    let mut quotient = ChonkerInt::new();
-
-    while dividend >= zero_bigint {
-        dividend = &dividend - &divisor;
-        quotient = &quotient + &ChonkerInt::from(String::from("1"));
-        println!("{:?}", dividend);
-        println!("{:?}", quotient);
+// Multiply the vector of limbs in the little endian format by a single limb factor,
+// return the product with an additional most significant limb, if the multiplication carried over.
+fn scale_digits(digits: &[u32], factor: u64) -> Vec<u32> {
+    let radix = RADIX as u64;
+    let mut product = Vec::with_capacity(digits.len() + 1);
+    let mut carry = 0u64;
+
+    for digit in digits {
+        let limb = *digit as u64 * factor + carry;
+        product.push((limb % radix) as u32);
+        carry = limb / radix;
     }
 
-    if dividend.sign == BigIntSign::Negative {
-        quotient = &quotient - &ChonkerInt::from(String::from("1"));
+    if carry > 0 {
+        product.push(carry as u32);
     }
 
-    let remainder = &dividend_original - &(&divisor_original * &quotient);
-
-    (quotient, remainder)
+    product
 }
 
+// Implement division "/" operator for owned BigInts and mixes of owned and borrowed ones.
+forward_binary_operator!(Div, div);
+
 // Test module.
 #[cfg(test)]
 mod tests {
-    use crate::logic::bigint::ChonkerInt;
+    use rand::Rng;
+
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
 
     // Test division of two BigInts.
     #[test]
@@ -731,4 +673,233 @@ mod tests {
             negative_very_big_smaller_by_negative_very_big_bigger_result
         );
     }
+
+    // Test division of owned BigInts and mixes of owned and borrowed ones.
+    #[test]
+    fn test_bigint_owned_division() {
+        let target = ChonkerInt::from(-1234567);
+        let other = ChonkerInt::from(89);
+        let reference_result = &target / &other;
+
+        assert_eq!(ChonkerInt::from(-1234567) / ChonkerInt::from(89), reference_result);
+        assert_eq!(ChonkerInt::from(-1234567) / &other, reference_result);
+        assert_eq!(&target / ChonkerInt::from(89), reference_result);
+    }
+
+    // Test checked division of BigInts.
+    #[test]
+    fn test_bigint_checked_division() {
+        let bigint = ChonkerInt::from(-1234567);
+
+        // Zero divisor produces an error instead of a panic.
+        assert!(bigint.checked_div(&ChonkerInt::new()).is_err());
+        assert!(ChonkerInt::new().checked_div(&ChonkerInt::new()).is_err());
+
+        // Zero dividend produces zero.
+        assert_eq!(ChonkerInt::new().checked_div(&bigint).unwrap(), ChonkerInt::new());
+
+        // Equal operands produce one or minus one.
+        assert_eq!(bigint.checked_div(&bigint).unwrap(), ChonkerInt::from(1));
+        assert_eq!(bigint.checked_div(&-&bigint).unwrap(), ChonkerInt::from(-1));
+
+        // Checked division agrees with the operator.
+        let divisor = ChonkerInt::from(89);
+        assert_eq!(bigint.checked_div(&divisor).unwrap(), &bigint / &divisor);
+    }
+
+    // Reference implementation of the truncated division for operands fitting into i128.
+    fn reference_div_rem_i128(dividend: &ChonkerInt, divisor: &ChonkerInt) -> (ChonkerInt, ChonkerInt) {
+        let dividend = dividend.to_string().parse::<i128>().unwrap();
+        let divisor = divisor.to_string().parse::<i128>().unwrap();
+
+        (ChonkerInt::from(dividend / divisor), ChonkerInt::from(dividend % divisor))
+    }
+
+    // Reference implementation of the division of non-negative BigInts with the repeated subtraction,
+    // usable for operands of any length, as long as the quotient is small.
+    fn reference_div_rem_subtraction(dividend: &ChonkerInt, divisor: &ChonkerInt) -> (ChonkerInt, ChonkerInt) {
+        let mut quotient = ChonkerInt::new();
+        let mut remainder = (*dividend).clone();
+
+        while remainder >= *divisor {
+            remainder = &remainder - divisor;
+            quotient = &quotient + 1u64;
+        }
+
+        (quotient, remainder)
+    }
+
+    // Test the long division against the i128 reference with random small operands of all signs,
+    // and with the example and the unnormalized divisors from the description of the algorithm.
+    #[test]
+    fn test_bigint_division_against_i128_reference() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..3000 {
+            let dividend_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let divisor_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let dividend = ChonkerInt::new_rand(&rng.gen_range(1..=38), &dividend_sign);
+            let divisor = ChonkerInt::new_rand(&rng.gen_range(1..=38), &divisor_sign);
+
+            assert_eq!(dividend.div_rem(&divisor), reference_div_rem_i128(&dividend, &divisor));
+        }
+
+        let pairs = [
+            (378_546i128, 78_356i128),
+            (999_999, 444),
+            (444_444_444_444_444_444, 444_444_444),
+            (1_000_000_000_000_000_000, 1_000_000_001),
+            (999_999_999_999_999_999_999_999_999, 1_000_000_000_000_000_001),
+            (499_999_999_999_999_999_999_999_999, 499_999_999_999_999_999),
+            (i128::MAX, 1_000_000_000),
+            (i128::MIN + 1, 999_999_999_999_999_999),
+        ];
+        for (dividend, divisor) in pairs {
+            let dividend = ChonkerInt::from(dividend);
+            let divisor = ChonkerInt::from(divisor);
+            assert_eq!(dividend.div_rem(&divisor), reference_div_rem_i128(&dividend, &divisor));
+            assert_eq!((-&dividend).div_rem(&divisor), reference_div_rem_i128(&-&dividend, &divisor));
+        }
+    }
+
+    // Test the long division against the repeated subtraction with long operands and small quotients.
+    #[test]
+    fn test_bigint_division_against_subtraction_reference() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..300 {
+            let divisor = ChonkerInt::new_rand(&rng.gen_range(1..=200), &BigIntSign::Positive);
+            let quotient = ChonkerInt::from(rng.gen_range(0..20u64));
+            let remainder = ChonkerInt::new_rand(&rng.gen_range(1..=200), &BigIntSign::Positive).rem_euclid(&divisor);
+            let dividend = &(&divisor * &quotient) + &remainder;
+
+            assert_eq!(dividend.div_rem(&divisor), reference_div_rem_subtraction(&dividend, &divisor));
+            assert_eq!(dividend.div_rem(&divisor), (quotient, remainder));
+        }
+    }
+
+    // Test the long division with operands around the limits of the quotient limb estimate:
+    // divisors with the most significant limb far from normalized, just below the half of the radix or full of nines,
+    // and quotients at the limits of a single limb.
+    #[test]
+    fn test_bigint_long_division_corner_cases() {
+        let radix = ChonkerInt::from(1_000_000_000);
+        let mut divisors = vec![];
+        for limbs in 1..6u32 {
+            let power = radix.pow_u64(limbs as u64);
+            divisors.push(&power + 1u64); // 1000...0001, unnormalized top limb.
+            divisors.push(&(&power * 500_000_000u64) - 1u64); // 499999999 999999999 ..., just below the half.
+            divisors.push(&(&power * 500_000_000u64) + 1u64); // 500000000 000000000 ... 1, just above the half.
+            divisors.push(&power - 1u64); // Only nines.
+            divisors.push(power.clone());
+        }
+
+        let quotients = [0u64, 1, 2, 499_999_999, 500_000_000, 999_999_998, 999_999_999, 1_000_000_000, 999_999_999_999_999_999];
+        for divisor in divisors.iter() {
+            for quotient in quotients {
+                for remainder in [ChonkerInt::new(), ChonkerInt::from(1), divisor - 1u64] {
+                    let dividend = &(divisor * quotient) + &remainder;
+
+                    assert_eq!(dividend.try_div(divisor).unwrap(), ChonkerInt::from(quotient));
+                    assert_eq!(dividend.try_rem(divisor).unwrap(), remainder);
+                    assert_eq!(dividend.div_rem(divisor), (ChonkerInt::from(quotient), remainder.clone()));
+                }
+            }
+        }
+
+        // Squares of the divisors minus one have the longest quotients.
+        for divisor in divisors.iter() {
+            let dividend = &(divisor * divisor) - 1u64;
+            assert_eq!(&dividend / divisor, divisor - 1u64);
+            assert_eq!(&dividend % divisor, divisor - 1u64);
+        }
+    }
+
+    // Test the invariant of the division with random operands: quotient * divisor + remainder == dividend,
+    // the remainder is smaller than the divisor and follows the sign of the dividend.
+    #[test]
+    fn test_bigint_division_invariant() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..3000 {
+            let dividend_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let divisor_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let dividend = ChonkerInt::new_rand(&rng.gen_range(1..=400), &dividend_sign);
+            let divisor = ChonkerInt::new_rand(&rng.gen_range(1..=200), &divisor_sign);
+
+            let (quotient, remainder) = dividend.div_rem(&divisor);
+
+            assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+            assert!(remainder.abs() < divisor.abs());
+            assert!(remainder.is_zero() || remainder.is_negative() == dividend.is_negative());
+            assert_eq!(dividend.try_div(&divisor).unwrap(), quotient);
+        }
+    }
+
+    // Test the division by 2 in place against the division operator.
+    #[test]
+    fn test_bigint_halve_in_place() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..500 {
+            let sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let bigint = ChonkerInt::new_rand(&rng.gen_range(1..=60), &sign);
+
+            let mut halved = bigint.clone();
+            halved.halve_in_place();
+            assert_eq!(halved, &bigint / &ChonkerInt::from(2));
+        }
+
+        for value in [0i64, 1, -1, 2, -7, 1_000_000_000, 1_000_000_001, -2_000_000_001] {
+            let mut halved = ChonkerInt::from(value);
+            halved.halve_in_place();
+            assert_eq!(halved, ChonkerInt::from(value / 2));
+            assert_eq!(halved.to_string(), (value / 2).to_string());
+        }
+    }
+
+    // Test division by zero with the operator.
+    #[test]
+    #[should_panic]
+    fn test_bigint_division_by_zero() {
+        let _ = &ChonkerInt::from(12345) / &ChonkerInt::new();
+    }
+
+    // Test the single-pass division with remainder, quotient * divisor + remainder should be equal to the dividend.
+    #[test]
+    fn test_bigint_div_rem() {
+        // All sign combinations with the primitive integers as a reference.
+        for (dividend, divisor) in [(7i64, 3i64), (-7, 3), (7, -3), (-7, -3), (6, 3), (-6, 3), (2, 5), (-2, -5), (0, 4)] {
+            let bigint_dividend = ChonkerInt::from(dividend);
+            let bigint_divisor = ChonkerInt::from(divisor);
+            let (quotient, remainder) = bigint_dividend.div_rem(&bigint_divisor);
+
+            assert_eq!(quotient, ChonkerInt::from(dividend / divisor));
+            assert_eq!(remainder, ChonkerInt::from(dividend % divisor));
+            assert_eq!(&(&quotient * &bigint_divisor) + &remainder, bigint_dividend);
+        }
+
+        // Very large operands of all sign combinations.
+        for _ in 0..5 {
+            for dividend_sign in [BigIntSign::Positive, BigIntSign::Negative] {
+                for divisor_sign in [BigIntSign::Positive, BigIntSign::Negative] {
+                    let dividend = ChonkerInt::new_rand(&300, &dividend_sign);
+                    let divisor = ChonkerInt::new_rand(&120, &divisor_sign);
+                    let (quotient, remainder) = dividend.div_rem(&divisor);
+
+                    assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+                    assert_eq!(quotient, &dividend / &divisor);
+                    assert!(remainder.abs() < divisor.abs());
+                    assert!(remainder.is_zero() || remainder.signum() == dividend.signum());
+                }
+            }
+        }
+    }
+
+    // Test division with remainder by zero.
+    #[test]
+    #[should_panic]
+    fn test_bigint_div_rem_by_zero() {
+        let _ = ChonkerInt::from(12345).div_rem(&ChonkerInt::new());
+    }
 }
@@ -1,6 +1,15 @@
 // BigInt module regarding (modular) exponentiation of BigInts.
 
+use crate::logic::bigint::barrett::BarrettReducer;
+use crate::logic::bigint::montgomery::MontgomeryContext;
 use crate::logic::bigint::ChonkerInt;
+use crate::logic::error::OperationError;
+
+// Exponents with more decimal digits than the threshold use the sliding window exponentiation,
+// shorter exponents keep the simple binary method, for which the precomputation would not pay off.
+const SLIDING_WINDOW_THRESHOLD: usize = 20;
+// Width of the sliding window in bits, 2^(width - 1) odd powers of the base are precomputed.
+const SLIDING_WINDOW_WIDTH: usize = 4;
 
 // Implement conversion methods for BigInt.
 impl ChonkerInt {
@@ -8,11 +17,59 @@ impl ChonkerInt {
     // It is accomplished with the use of exponentiation by squaring algorithm, in an iterative form.
     // More about the idea: https://en.wikipedia.org/wiki/Exponentiation_by_squaring
     pub fn pow(&self, power: &ChonkerInt) -> ChonkerInt {
+        // If the power is positive and fits into the u64, use the exponentiation with the native exponent.
+        if !power.is_negative() {
+            if let Ok(exponent) = power.to_u128() {
+                if exponent <= u64::MAX as u128 {
+                    return self.pow_u64(exponent as u64);
+                }
+            }
+        }
+
+        self.pow_by_squaring(power)
+    }
+
+    // Implement exponentiation operation with the exponent fitting into the u64.
+    // It is accomplished with the same exponentiation by squaring algorithm, but the exponent is halved natively.
+    pub fn pow_u64(&self, exp: u64) -> ChonkerInt {
+        // If the base is zero, return zero.
+        if self.is_zero() {
+            return ChonkerInt::new();
+        }
+
+        // Check if the power is zero or one and take according action.
+        if exp == 0 {
+            return ChonkerInt::from(1);
+        } else if exp == 1 {
+            return (*self).clone();
+        }
+
+        let mut base = (*self).clone();
+        let mut exp = exp;
+        let mut result = ChonkerInt::from(1);
+
+        // The result and the base are updated in place, the old vectors of limbs are not cloned on every iteration.
+        loop {
+            // If the power is odd, multiply the result by the base.
+            if exp % 2 == 1 {
+                result.mul_assign_ref(&base);
+            }
+
+            exp /= 2;
+            if exp == 0 {
+                return result;
+            }
+
+            base.square_in_place();
+        }
+    }
+
+    // Exponentiation by squaring with the BigInt exponent, used when the exponent does not fit into the u64.
+    fn pow_by_squaring(&self, power: &ChonkerInt) -> ChonkerInt {
         let mut base = (*self).clone();
         let mut power = (*power).clone();
         let zero_bigint = ChonkerInt::new();
         let big_one = ChonkerInt::from(1);
-        let big_two = ChonkerInt::from(2);
 
         // If the base is zero, return zero.
         if *self == zero_bigint {
@@ -27,14 +84,14 @@ impl ChonkerInt {
         } else if power == big_one {
             return (*self).clone();
         } else if power > zero_bigint {
-            while power > zero_bigint {
+            while power > 0u64 {
                 // If the power is odd, split it in half and multiply base by itself.
-                if &power % &big_two == big_one {
-                    result = &result * &base;
+                if power.is_odd() {
+                    result.mul_assign_ref(&base);
                 }
 
-                base = &base * &base;
-                power = &power / &big_two;
+                base.square_in_place();
+                power.halve_in_place();
             }
         } else if power < zero_bigint {
             return zero_bigint;
@@ -43,56 +100,178 @@ impl ChonkerInt {
         result
     }
 
-    // Implement modular exponentiation with Right-to-left binary which includes memory efficient method.
+    // Implement modular exponentiation with Right-to-left binary which includes memory efficient method,
+    // long exponents use the sliding window method.
     pub fn modpow(&self, power: &ChonkerInt, modulus: &ChonkerInt) -> ChonkerInt {
+        match self.checked_modpow(power, modulus) {
+            Ok(result) => result,
+            Err(error) => panic!("{} (ChonkerInt::modpow())", error),
+        }
+    }
+
+    // Implement modular exponentiation, return an error instead of panicking if the modulus is zero.
+    // For moduli coprime to the radix, i.e. odd and not divisible by 5, Montgomery multiplication is used,
+    // other moduli fall back to the Barrett reduction after every multiplication.
+    // Long exponents are processed with the sliding window method instead of the binary one.
+    pub fn checked_modpow(&self, power: &ChonkerInt, modulus: &ChonkerInt) -> Result<ChonkerInt, OperationError> {
+        // Check if the modulus is zero, if so, return an error.
+        if modulus.significant_digits().is_empty() {
+            return Err(OperationError::new("attempt to calculate modular exponentiation with a modulus of zero."));
+        }
+
+        // Montgomery form and sliding window pay off only for the exponents bigger than one,
+        // other cases are handled by the fallback.
+        if !self.is_zero() && power > &ChonkerInt::from(1) {
+            if power.digit_count() > SLIDING_WINDOW_THRESHOLD {
+                return Ok(self.modpow_sliding_window(power, modulus));
+            }
+
+            if let Some(context) = MontgomeryContext::new(modulus) {
+                return Ok(context.modpow(&self.rem_euclid(modulus), power));
+            }
+        }
+
+        self.modpow_by_reduction(power, modulus)
+    }
+
+    // Modular exponentiation with the sliding window method for a non-zero base and a power bigger than one.
+    // The multiplications are done in the Montgomery form if the modulus allows it, otherwise with the Barrett reduction.
+    fn modpow_sliding_window(&self, power: &ChonkerInt, modulus: &ChonkerInt) -> ChonkerInt {
+        let base = self.rem_euclid(modulus);
+
+        match MontgomeryContext::new(modulus) {
+            Some(context) => {
+                let result = sliding_window_pow(
+                    &context.to_montgomery(&base),
+                    power,
+                    context.to_montgomery(&ChonkerInt::from(1)),
+                    |a, b| context.multiply(a, b),
+                );
+
+                context.from_montgomery(&result)
+            }
+            None => {
+                let reducer = BarrettReducer::new(&modulus.abs());
+
+                sliding_window_pow(&base, power, reducer.reduce(&ChonkerInt::from(1)), |a, b| {
+                    reducer.reduce(&(a * b))
+                })
+            }
+        }
+    }
+
+    // Modular exponentiation with the Right-to-left binary method,
+    // the intermediate results are reduced with the Barrett reduction, the reciprocal of the modulus is computed once.
+    fn modpow_by_reduction(&self, power: &ChonkerInt, modulus: &ChonkerInt) -> Result<ChonkerInt, OperationError> {
         let mut base = (*self).clone();
         let mut power = (*power).clone();
         let modulus = (*modulus).clone();
 
         let zero_bigint = ChonkerInt::new();
         let big_one = ChonkerInt::from(1);
-        let big_two = ChonkerInt::from(2);
 
         // If the base is zero, return zero.
         if *self == zero_bigint {
-            return zero_bigint;
+            return Ok(zero_bigint);
         }
 
+        // The modulus is not zero, reduce by its absolute value to keep the intermediate results non-negative.
+        let reducer = BarrettReducer::new(&modulus.abs());
         let mut result = ChonkerInt::from(1);
-        base = &base % &modulus;
+        base = reducer.reduce(&base);
 
         // Check if the power is zero, one, positive or negative and take according action.
         if power == zero_bigint {
-            return big_one;
+            return Ok(big_one);
         } else if power == big_one {
-            return (*self).clone();
+            return Ok((*self).clone());
         } else if power > zero_bigint {
             loop {
-                if (&power % &big_two) == big_one {
-                    result = &result * &base;
-                    result = &result % &modulus;
+                if power.is_odd() {
+                    result.mul_assign_ref(&base);
+                    result = reducer.reduce(&result);
                 }
 
-                if power == big_one {
-                    return result;
+                if power == 1u64 {
+                    return Ok(result);
                 }
 
-                power = &power / &big_two;
-                base = &base * &base;
-                base = &base % &modulus;
+                power.halve_in_place();
+                base.square_in_place();
+                base = reducer.reduce(&base);
             }
         } else if power < zero_bigint {
-            return zero_bigint;
+            return Ok(zero_bigint);
         }
 
-        result
+        Ok(result)
+    }
+}
+
+// Calculate base^power with the left-to-right sliding window method, the multiplication is provided by the caller,
+// e.g. the Montgomery multiplication, and one is the neutral element of it.
+// Odd powers of the base up to 2^width - 1 are precomputed, then every window of bits of the power,
+// starting and ending with a set bit, costs one multiplication and the zero bits between windows are only squared.
+// More about the idea: https://en.wikipedia.org/wiki/Exponentiation_by_squaring#Sliding-window_method
+fn sliding_window_pow<F>(base: &ChonkerInt, power: &ChonkerInt, one: ChonkerInt, multiply: F) -> ChonkerInt
+where
+    F: Fn(&ChonkerInt, &ChonkerInt) -> ChonkerInt,
+{
+    // Precompute the odd powers: base^1, base^3, ..., base^(2^width - 1).
+    let base_squared = multiply(base, base);
+    let mut odd_powers = vec![(*base).clone()];
+    for index in 1..(1 << (SLIDING_WINDOW_WIDTH - 1)) {
+        let next_power = multiply(&odd_powers[index - 1], &base_squared);
+        odd_powers.push(next_power);
     }
+
+    // Bits of the power starting from the most significant one, the leading zero bits are skipped.
+    let bits: Vec<bool> = power
+        .to_bytes_be()
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| (byte >> bit) & 1 == 1))
+        .skip_while(|bit| !bit)
+        .collect();
+
+    let mut result = one;
+    let mut index = 0;
+    while index < bits.len() {
+        // Square the result for a zero bit outside of a window.
+        if !bits[index] {
+            result = multiply(&result, &result);
+            index += 1;
+            continue;
+        }
+
+        // Find the longest window, which starts at the current set bit and ends with a set bit.
+        let mut window_end = (index + SLIDING_WINDOW_WIDTH).min(bits.len()) - 1;
+        while !bits[window_end] {
+            window_end -= 1;
+        }
+
+        // Shift the result by the width of the window and multiply it by the odd power, represented by the window.
+        let mut window_value = 0;
+        for bit in &bits[index..=window_end] {
+            result = multiply(&result, &result);
+            window_value = (window_value << 1) | *bit as usize;
+        }
+        result = multiply(&result, &odd_powers[window_value >> 1]);
+
+        index = window_end + 1;
+    }
+
+    result
 }
 
 // Test module.
 #[cfg(test)]
 mod tests {
-    use crate::logic::bigint::ChonkerInt;
+    use std::time::Instant;
+
+    use rand::Rng;
+
+    use crate::logic::bigint::montgomery::MontgomeryContext;
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
 
     // Test BigInt's power operation.
     #[test]
@@ -283,4 +462,164 @@ mod tests {
             negative_into_zero_exponentiation_result
         );
     }
+
+    // Test checked modular exponentiation of BigInts.
+    #[test]
+    fn test_bigint_checked_modpow_operation() {
+        let base = ChonkerInt::from(7);
+        let power = ChonkerInt::from(12323);
+        let modulus = ChonkerInt::from(13);
+
+        assert_eq!(base.checked_modpow(&power, &modulus).unwrap(), base.modpow(&power, &modulus));
+        assert!(base.checked_modpow(&power, &ChonkerInt::new()).is_err());
+        assert!(ChonkerInt::new().checked_modpow(&power, &ChonkerInt::new()).is_err());
+    }
+
+    // Test exponentiation with the native exponent against the exponentiation with the BigInt exponent.
+    #[test]
+    fn test_bigint_pow_u64() {
+        // Zero and one exponents, zero base matches the BigInt exponent behaviour.
+        let bigint = ChonkerInt::from(-12345);
+        assert_eq!(bigint.pow_u64(0), ChonkerInt::from(1));
+        assert_eq!(bigint.pow_u64(1), bigint);
+        assert_eq!(bigint.pow_u64(3), ChonkerInt::from(-12345i64 * 12345 * 12345));
+        assert_eq!(ChonkerInt::new().pow_u64(0), ChonkerInt::new().pow_by_squaring(&ChonkerInt::new()));
+        assert_eq!(ChonkerInt::new().pow_u64(5), ChonkerInt::new());
+
+        // Random bases with the exponents up to 10_000, compare both methods and their time.
+        let mut rng = rand::thread_rng();
+        for (base_length, exponent_limit) in [(1u64, 10_000u64), (20, 200), (100, 20)] {
+            let base = ChonkerInt::new_rand(&base_length, &BigIntSign::Positive);
+            let exponent = rng.gen_range(0..=exponent_limit);
+
+            let native_start = Instant::now();
+            let native_result = base.pow_u64(exponent);
+            let native_time = native_start.elapsed();
+
+            let bigint_start = Instant::now();
+            let bigint_result = base.pow_by_squaring(&ChonkerInt::from(exponent));
+            let bigint_time = bigint_start.elapsed();
+
+            println!("{}-digit base ^ {}: pow_u64 {:?}, BigInt exponent {:?}", base_length, exponent, native_time, bigint_time);
+            assert_eq!(native_result, bigint_result);
+            assert_eq!(base.pow(&ChonkerInt::from(exponent)), native_result);
+        }
+
+        // Negative bases.
+        let base = ChonkerInt::new_rand(&5, &BigIntSign::Negative);
+        assert_eq!(base.pow_u64(7), base.pow_by_squaring(&ChonkerInt::from(7)));
+        assert_eq!(base.pow_u64(8), base.pow_by_squaring(&ChonkerInt::from(8)));
+    }
+
+    // Test modular exponentiation with Montgomery multiplication against the Barrett reduction.
+    #[test]
+    fn test_bigint_modpow_montgomery() {
+        let mut rng = rand::thread_rng();
+
+        for (modulus_length, exponent_length) in [(1u64, 3u64), (5, 10), (9, 20), (10, 20), (18, 30), (40, 40), (100, 20)] {
+            // Generate a random modulus coprime to the radix, i.e. odd and not divisible by 5.
+            let mut modulus = ChonkerInt::new_rand(&modulus_length, &BigIntSign::Positive);
+            while &modulus % 2u64 == 0 || &modulus % 5u64 == 0 {
+                modulus = &modulus + 1u64;
+            }
+            assert!(MontgomeryContext::new(&modulus).is_some());
+
+            for _ in 0..3 {
+                let base_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+                let base = ChonkerInt::new_rand(&rng.gen_range(1..=modulus_length + 5), &base_sign);
+                let power = ChonkerInt::new_rand(&exponent_length, &BigIntSign::Positive);
+
+                let montgomery_start = Instant::now();
+                let montgomery_result = base.modpow(&power, &modulus);
+                let montgomery_time = montgomery_start.elapsed();
+
+                let reduction_start = Instant::now();
+                let reduction_result = base.modpow_by_reduction(&power, &modulus).unwrap();
+                let reduction_time = reduction_start.elapsed();
+
+                println!("{}-digit modulus, {}-digit exponent: Montgomery {:?}, Barrett {:?}", modulus_length, exponent_length, montgomery_time, reduction_time);
+                assert_eq!(montgomery_result, reduction_result);
+
+                // Negative modulus uses its absolute value.
+                assert_eq!(base.modpow(&power, &(-&modulus)), base.modpow_by_reduction(&power, &(-&modulus)).unwrap());
+            }
+        }
+    }
+
+    // Test modular exponentiation with the sliding window against the binary method.
+    #[test]
+    fn test_bigint_modpow_sliding_window() {
+        let mut rng = rand::thread_rng();
+
+        for (modulus_length, exponent_length) in [(1u64, 21u64), (10, 25), (18, 40), (40, 100), (100, 100), (100, 300)] {
+            // Odd modulus coprime to the radix for the Montgomery form and an even one for the Barrett reduction.
+            let mut odd_modulus = ChonkerInt::new_rand(&modulus_length, &BigIntSign::Positive);
+            while &odd_modulus % 2u64 == 0 || &odd_modulus % 5u64 == 0 {
+                odd_modulus = &odd_modulus + 1u64;
+            }
+            let even_modulus = &odd_modulus + 1u64;
+
+            for modulus in [odd_modulus, even_modulus] {
+                let base_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+                let base = ChonkerInt::new_rand(&rng.gen_range(1..=modulus_length + 5), &base_sign);
+                let power = ChonkerInt::new_rand(&exponent_length, &BigIntSign::Positive);
+
+                let binary_result = base.modpow_by_reduction(&power, &modulus).unwrap();
+                assert_eq!(base.modpow_sliding_window(&power, &modulus), binary_result);
+                assert_eq!(base.modpow(&power, &modulus), binary_result);
+            }
+        }
+
+        // Zero, one and negative exponents behave the same regardless of the length of the exponent.
+        let base = ChonkerInt::from(String::from("123456789123456789123456789"));
+        let modulus = ChonkerInt::from(1_000_003);
+        let long_negative_power = ChonkerInt::new_rand(&50, &BigIntSign::Negative);
+        assert_eq!(base.modpow(&long_negative_power, &modulus), ChonkerInt::new());
+        assert_eq!(base.modpow(&ChonkerInt::new(), &modulus), ChonkerInt::from(1));
+        assert_eq!(base.modpow(&ChonkerInt::from(1), &modulus), base);
+        assert_eq!(ChonkerInt::new().modpow(&ChonkerInt::new_rand(&50, &BigIntSign::Positive), &modulus), ChonkerInt::new());
+    }
+
+    // Test the coarse timing of the sliding window against the binary method in the Montgomery form, the window should not be slower.
+    // The wall-clock comparison is unreliable on a loaded machine, run with "cargo test --release -- --ignored".
+    #[test]
+    #[ignore = "timing comparison, unreliable on a loaded machine"]
+    fn test_bigint_modpow_sliding_window_timing() {
+        let mut modulus = ChonkerInt::new_rand(&300, &BigIntSign::Positive);
+        while &modulus % 2u64 == 0 || &modulus % 5u64 == 0 {
+            modulus = &modulus + 1u64;
+        }
+        let context = MontgomeryContext::new(&modulus).unwrap();
+        let base = ChonkerInt::new_rand(&300, &BigIntSign::Positive).rem_euclid(&modulus);
+        let power = ChonkerInt::new_rand(&300, &BigIntSign::Positive);
+
+        let binary_start = Instant::now();
+        let binary_result = context.modpow(&base, &power);
+        let binary_time = binary_start.elapsed();
+
+        let window_start = Instant::now();
+        let window_result = base.modpow_sliding_window(&power, &modulus);
+        let window_time = window_start.elapsed();
+
+        println!("300-digit modulus and exponent: binary {:?}, sliding window {:?}", binary_time, window_time);
+        assert_eq!(window_result, binary_result);
+        assert!(window_time < binary_time * 2);
+    }
+
+    // Test modular exponentiation with moduli sharing a factor with the radix, which use the fallback.
+    #[test]
+    fn test_bigint_modpow_fallback() {
+        // Even modulus.
+        let modulus = ChonkerInt::from(String::from("123456789012345678901234567890"));
+        assert!(MontgomeryContext::new(&modulus).is_none());
+        let base = ChonkerInt::from(String::from("98765432109876543210"));
+        let power = ChonkerInt::from(65537);
+        assert_eq!(base.modpow(&power, &modulus), base.modpow_by_reduction(&power, &modulus).unwrap());
+        assert_eq!(ChonkerInt::from(3).modpow(&ChonkerInt::from(20), &ChonkerInt::from(1_000_000)), ChonkerInt::from(486_784_401 % 1_000_000));
+
+        // Modulus divisible by 5.
+        let modulus = ChonkerInt::from(3125);
+        assert!(MontgomeryContext::new(&modulus).is_none());
+        assert_eq!(ChonkerInt::from(7).modpow(&ChonkerInt::from(13), &modulus), ChonkerInt::from(96_889_010_407i64 % 3125));
+    }
 }
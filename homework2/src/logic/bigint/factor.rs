@@ -1,11 +1,193 @@
 // BigInt module regarding factorisation of BigInts.
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::logic::bigint::barrett::BarrettReducer;
+use crate::logic::bigint::prime::SMALL_ODD_PRIMES;
 use crate::logic::bigint::{BigIntSign, ChonkerInt};
 
-// Implement methods factoring the BigInt, time complexity is O(sqrt(n)).
+// Amount of the steps of the Pollard's rho algorithm, which are accumulated into a single product before the GCD.
+const POLLARD_RHO_BATCH_SIZE: u64 = 128;
+
+// Implement methods factoring the BigInt.
 impl ChonkerInt {
-    // Generate a vector of all factors for the target BigInt.
-    pub fn factor(&self) -> Vec<ChonkerInt> {
+    // Generate the prime factorization of the absolute value of the BigInt: the primes in the ascending order
+    // with their exponents. Zero and one have no prime factors, the vectors are empty.
+    // The small factors are removed with the trial division by the small primes, the rest are split with
+    // the Pollard's rho algorithm with the Brent's cycle detection, the factors are confirmed with the probabilistic test.
+    pub fn factor(&self) -> Vec<(ChonkerInt, u32)> {
+        let mut target = self.abs();
+        target.normalize();
+        let mut prime_factors: Vec<ChonkerInt> = vec![];
+
+        if target.is_zero() {
+            return vec![];
+        }
+
+        // Trial division by 2 and the small odd primes.
+        for small_prime in std::iter::once(2).chain(SMALL_ODD_PRIMES.iter().copied()) {
+            let big_small_prime = ChonkerInt::from(small_prime);
+            while &target % small_prime == 0 {
+                prime_factors.push(big_small_prime.clone());
+                target = &target / &big_small_prime;
+            }
+        }
+
+        // Split the remaining composite factors, until only the prime ones are left.
+        let mut composite_factors = vec![];
+        if !target.is_one() {
+            composite_factors.push(target);
+        }
+
+        while let Some(factor) = composite_factors.pop() {
+            if factor.is_prime_probabilistic(None) {
+                prime_factors.push(factor);
+                continue;
+            }
+
+            // The Pollard's rho algorithm finds only the whole number for some of the perfect powers,
+            // split them with the integer roots first.
+            let divisor = match factor.perfect_power_root() {
+                Some(root) => root,
+                None => factor.pollard_rho_brent(),
+            };
+            composite_factors.push(&factor / &divisor);
+            composite_factors.push(divisor);
+        }
+
+        // Sort the prime factors and count the repeated ones.
+        prime_factors.sort();
+        let mut factorization: Vec<(ChonkerInt, u32)> = vec![];
+        for prime in prime_factors {
+            match factorization.last_mut() {
+                Some((last_prime, exponent)) if *last_prime == prime => *exponent += 1,
+                _ => factorization.push((prime, 1)),
+            }
+        }
+
+        factorization
+    }
+
+    // Find the root r of the perfect power self = r^k, k > 1, the BigInt has no prime factors below 2000.
+    // Returns None, if the BigInt is not a perfect power.
+    fn perfect_power_root(&self) -> Option<ChonkerInt> {
+        // The root is at least 2000, thus the exponent is at most log_2000(self).
+        let mut exponent = 2;
+        while ChonkerInt::from(2000).pow_u64(exponent as u64) <= *self {
+            let root = self.nth_root(exponent);
+            if root.pow_u64(exponent as u64) == *self {
+                return Some(root);
+            }
+
+            exponent += 1;
+        }
+
+        None
+    }
+
+    // Find a non-trivial divisor of the odd composite BigInt with the Pollard's rho algorithm,
+    // which iterates the pseudorandom function f(x) = x^2 + c (modulo n) and looks for a cycle modulo the unknown factor.
+    // The Brent's cycle detection doubles the length of the power of two cycle and accumulates the differences
+    // into a product, so that the GCD is calculated only once per batch. If the whole number is found,
+    // the batch is repeated step by step, and if it still fails, the next constant c is tried.
+    // More about the idea: https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm#Variants
+    fn pollard_rho_brent(&self) -> ChonkerInt {
+        let reducer = BarrettReducer::new(self);
+        let step = |x: &ChonkerInt, c: &ChonkerInt| reducer.reduce(&(&(x * x) + c));
+        let mut c = ChonkerInt::from(1);
+
+        loop {
+            let mut y = ChonkerInt::from(2);
+            let mut x = y.clone();
+            let mut saved_y = y.clone();
+            let mut divisor = ChonkerInt::from(1);
+            let mut product = ChonkerInt::from(1);
+            let mut cycle_length = 1;
+
+            while divisor.is_one() {
+                // Remember the start of the cycle and move the tortoise to it.
+                x = y.clone();
+                for _step in 0..cycle_length {
+                    y = step(&y, &c);
+                }
+
+                let mut steps_done = 0;
+                while steps_done < cycle_length && divisor.is_one() {
+                    saved_y = y.clone();
+                    for _step in 0..POLLARD_RHO_BATCH_SIZE.min(cycle_length - steps_done) {
+                        y = step(&y, &c);
+                        product = reducer.reduce(&(&product * &(&x - &y).abs()));
+                    }
+
+                    divisor = product.gcd(self);
+                    steps_done += POLLARD_RHO_BATCH_SIZE;
+                }
+
+                cycle_length *= 2;
+            }
+
+            // The product of the batch is a multiple of the number, repeat the batch step by step.
+            if divisor == *self {
+                loop {
+                    saved_y = step(&saved_y, &c);
+                    divisor = (&x - &saved_y).abs().gcd(self);
+                    if !divisor.is_one() {
+                        break;
+                    }
+                }
+            }
+
+            if divisor != *self {
+                return divisor;
+            }
+
+            c = &c + 1u64;
+        }
+    }
+
+    // Split the BigInt into two factors p <= q with the Fermat's method: n = a^2 - b^2 = (a - b) * (a + b).
+    // Starting from a = ceil(sqrt(n)), a is increased until a^2 - n is a perfect square, the amount of iterations
+    // is small, when the factors are close to each other, e.g. the primes of a badly generated RSA modulus.
+    // Returns None, if no factors were found in the requested amount of iterations, or the BigInt is below 4 or a prime.
+    // Even numbers are not differences of squares with the odd factors, they are split by 2 instead.
+    // More about the idea: https://en.wikipedia.org/wiki/Fermat%27s_factorization_method
+    pub fn factor_fermat(&self, max_iterations: u64) -> Option<(ChonkerInt, ChonkerInt)> {
+        if *self < 4u64 || self.is_negative() {
+            return None;
+        } else if self.is_even() {
+            let mut other_factor = self.clone();
+            other_factor.halve_in_place();
+            return Some((ChonkerInt::from(2), other_factor));
+        }
+
+        let mut a = self.sqrt();
+        if &a * &a != *self {
+            a = &a + 1u64;
+        }
+
+        // b^2 = a^2 - n, the next value is increased by (a + 1)^2 - a^2 = 2a + 1.
+        let mut b_square = &(&a * &a) - self;
+        for _iteration in 0..max_iterations {
+            if let Some(b) = b_square.sqrt_exact() {
+                let first_factor = &a - &b;
+
+                // The trivial factorization 1 * n means that the number is a prime.
+                if first_factor.is_one() {
+                    return None;
+                }
+
+                return Some((first_factor, &a + &b));
+            }
+
+            b_square = &(&b_square + &(&a * 2u64)) + 1u64;
+            a = &a + 1u64;
+        }
+
+        None
+    }
+
+    // Generate a vector of all divisors for the target BigInt, time complexity is O(sqrt(n)).
+    pub fn divisors(&self) -> Vec<ChonkerInt> {
         let mut absolute_target = (*self).clone();
         absolute_target.set_positive_sign();
         let big_zero = ChonkerInt::new();
@@ -132,33 +314,69 @@ impl ChonkerInt {
         factor_list
     }
 
-    // Generate a vector of all factors for the target BigInt.
+    // Generate a vector of both prime factors of the RSA modulus with the prime factorization of ChonkerInt::factor(),
+    // the starting point is only checked, the factorization does not depend on it. The workers of the bruteforce,
+    // which split the range of the candidates by the starting points, scan it with ChonkerInt::factor_rsa_modulus_until().
     pub fn factor_rsa_modulus(&self, iteration_start_point: &ChonkerInt) -> Vec<ChonkerInt> {
-        let mut absolute_target = (*self).clone();
-        absolute_target.set_positive_sign();
-        let mut factor_candidate = (*iteration_start_point).clone();
+        self.check_rsa_modulus_factorisation(iteration_start_point);
 
-        let big_zero = ChonkerInt::new();
-        let big_one = ChonkerInt::from(1);
-        let big_two = ChonkerInt::from(2);
+        let mut factor_list: Vec<ChonkerInt> = vec![];
+        for (prime, exponent) in self.factor() {
+            for _power in 0..exponent {
+                factor_list.push(prime.clone());
+            }
+        }
 
+        // The modulus is not a product of two primes.
+        if factor_list.len() != 2 {
+            panic!("the generated factor of the target is a composite number, thus the received RSA modulus was incorrect. Correct RSA modulus is a produce of two prime numbers. (factor_rsa_modulus)");
+        }
+
+        factor_list
+    }
+
+    // Check the starting point and the target of the factorisation of the RSA modulus, panic on the incorrect ones.
+    fn check_rsa_modulus_factorisation(&self, iteration_start_point: &ChonkerInt) {
         // Check for the provided starting point for factor candidate calculation, for zero or being negative.
-        if factor_candidate == big_zero || iteration_start_point.sign == BigIntSign::Negative {
+        if iteration_start_point.is_zero() || iteration_start_point.sign == BigIntSign::Negative {
             panic!("the provided starting point for factor candidate calculation, for the factoring of the RSA modulus is incorrect. The candidate should be a positive number. (factor_rsa_modulus)");
         }
 
         // Check for zero, one and two targets.
         // Check if the target is a prime number or negative.
-        if ((*self) == big_zero)
-            || ((*self) == big_one)
-            || ((*self) == big_two)
-            || self.is_prime_probabilistic(Some(2))
-        {
+        if self.is_zero() || self.is_one() || *self == ChonkerInt::from(2) || self.is_prime_probabilistic(Some(2)) {
             panic!("the provided target for factorisation, for the factoring of the RSA modulus is incorrect. The target should be a positive composite number. (factor_rsa_modulus)");
         }
+    }
+
+    // Generate a vector of all factors for the target BigInt, the search is stopped, when the cancellation flag is set,
+    // e.g. by another thread, which has already found the factors. The cancelled search returns an empty vector.
+    pub fn factor_rsa_modulus_until(&self, iteration_start_point: &ChonkerInt, cancelled: &AtomicBool) -> Vec<ChonkerInt> {
+        self.factor_rsa_modulus_with_progress(iteration_start_point, cancelled, u64::MAX, |_candidate, _tested| {})
+    }
+
+    // Generate a vector of all factors for the target BigInt, until the cancellation flag is set, and report the progress:
+    // the current candidate and the amount of the tested candidates are passed to the callback every requested amount of candidates.
+    pub fn factor_rsa_modulus_with_progress<F: FnMut(&ChonkerInt, u64)>(
+        &self,
+        iteration_start_point: &ChonkerInt,
+        cancelled: &AtomicBool,
+        report_interval: u64,
+        mut progress: F,
+    ) -> Vec<ChonkerInt> {
+        let mut absolute_target = (*self).clone();
+        absolute_target.set_positive_sign();
+        let mut factor_candidate = (*iteration_start_point).clone();
+
+        let big_zero = ChonkerInt::new();
+        let big_one = ChonkerInt::from(1);
+        let big_two = ChonkerInt::from(2);
+
+        self.check_rsa_modulus_factorisation(iteration_start_point);
 
         let mut factor_list: Vec<ChonkerInt> = vec![];
         let mut factor_other;
+        let mut candidates_tested = 0;
 
         // Check if the target is even, if it is,
         // check if the second is a prime number as well, if both are prime,
@@ -183,6 +401,17 @@ impl ChonkerInt {
 
         // Loop from the requested start to the sqrt(n).
         while (factor_candidate.pow(&big_two)) <= absolute_target {
+            // Check if the search was cancelled.
+            if cancelled.load(Ordering::SeqCst) {
+                return vec![];
+            }
+
+            // Report the progress of the search.
+            candidates_tested += 1;
+            if candidates_tested % report_interval == 0 {
+                progress(&factor_candidate, candidates_tested);
+            }
+
             // Check if the candidate factor is a prime value, if it is not,
             // continue to the next iteration.
             if !factor_candidate.is_prime_probabilistic(Some(1)) {
@@ -217,8 +446,109 @@ impl ChonkerInt {
 // Test module.
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::AtomicBool;
+
     use crate::logic::bigint::ChonkerInt;
 
+    // Multiply the prime factorization back into the number.
+    fn multiply_factorization(factorization: &[(ChonkerInt, u32)]) -> ChonkerInt {
+        factorization
+            .iter()
+            .fold(ChonkerInt::from(1), |product, (prime, exponent)| &product * &prime.pow_u64(*exponent as u64))
+    }
+
+    // Test the prime factorization of semiprimes and numbers with small and repeated factors.
+    #[test]
+    fn test_bigint_prime_factorization() {
+        // Semiprimes up to 10^18, the factors are found by the Pollard's rho algorithm.
+        let semiprimes = [
+            (1000000007u64, 999999937u64),
+            (2147483647, 2147483629),
+            (4294967291, 4294967279),
+            (1000003, 999983),
+            (2003, 1000000000039),
+            (2017, 2017),
+        ];
+        for (first_prime, second_prime) in semiprimes.iter() {
+            let target = &ChonkerInt::from(*first_prime) * &ChonkerInt::from(*second_prime);
+            let factorization = target.factor();
+            let mut expected = vec![(ChonkerInt::from(*first_prime.min(second_prime)), 1), (ChonkerInt::from(*first_prime.max(second_prime)), 1)];
+            if first_prime == second_prime {
+                expected = vec![(ChonkerInt::from(*first_prime), 2)];
+            }
+
+            assert_eq!(factorization, expected, "{}", target);
+            assert_eq!(multiply_factorization(&factorization), target);
+        }
+
+        // Small, big and repeated factors together: 2^5 * 3^3 * 1993 * 1000003^2 * 2147483647.
+        let target = ChonkerInt::from(String::from("3697885948134790287512270496"));
+        let factorization = target.factor();
+        assert_eq!(multiply_factorization(&factorization), target);
+        assert!(factorization.iter().all(|(prime, _exponent)| prime.is_prime_probabilistic(None)));
+        assert_eq!(
+            factorization,
+            vec![
+                (ChonkerInt::from(2), 5),
+                (ChonkerInt::from(3), 3),
+                (ChonkerInt::from(1993), 1),
+                (ChonkerInt::from(1000003), 2),
+                (ChonkerInt::from(2147483647), 1),
+            ]
+        );
+
+        // The factorization of the negative number is the one of its absolute value.
+        assert_eq!(ChonkerInt::from(-12).factor(), vec![(ChonkerInt::from(2), 2), (ChonkerInt::from(3), 1)]);
+    }
+
+    // Test the Fermat's factorization of a product of two close primes and its failure on well separated primes.
+    #[test]
+    fn test_bigint_fermat_factorisation() {
+        // Two 40 digit primes, which differ by 3294.
+        let prime_p = ChonkerInt::from(String::from("1000000000000000000000000000000000000037"));
+        let prime_q = ChonkerInt::from(String::from("1000000000000000000000000000000000003331"));
+        let modulus = &prime_p * &prime_q;
+        assert_eq!(modulus.factor_fermat(1), Some((prime_p.clone(), prime_q.clone())));
+
+        // A perfect square and a pair of small primes, which differ more.
+        assert_eq!((&prime_p * &prime_p).factor_fermat(1), Some((prime_p.clone(), prime_p.clone())));
+        assert_eq!(ChonkerInt::from(1613 * 36037).factor_fermat(20_000), Some((ChonkerInt::from(1613), ChonkerInt::from(36037))));
+
+        // Well separated primes are not found in the bounded amount of iterations.
+        let separated_modulus = &prime_p * &ChonkerInt::from(String::from("170141183460469231731687303715884105727"));
+        assert_eq!(separated_modulus.factor_fermat(1000), None);
+
+        // Primes, even numbers and numbers below 4.
+        assert_eq!(ChonkerInt::from(1999).factor_fermat(10_000), None);
+        assert_eq!(ChonkerInt::from(46).factor_fermat(1), Some((ChonkerInt::from(2), ChonkerInt::from(23))));
+        assert_eq!(ChonkerInt::from(3).factor_fermat(10), None);
+        assert_eq!(ChonkerInt::from(-15).factor_fermat(10), None);
+    }
+
+    // Test the prime factorization of primes, perfect powers, zero and one.
+    #[test]
+    fn test_bigint_prime_factorization_edge_cases() {
+        assert!(ChonkerInt::new().factor().is_empty());
+        assert!(ChonkerInt::from(1).factor().is_empty());
+        assert!(ChonkerInt::from(-1).factor().is_empty());
+
+        // Primes are their own factorization.
+        for prime in ["2", "1993", "2003", "2147483647", "170141183460469231731687303715884105727"] {
+            let prime = ChonkerInt::from(String::from(prime));
+            assert_eq!(prime.factor(), vec![(prime.clone(), 1)]);
+        }
+
+        // Perfect powers of small and big primes, and of a semiprime.
+        assert_eq!(ChonkerInt::from(2u64).pow_u64(64).factor(), vec![(ChonkerInt::from(2), 64)]);
+        assert_eq!(ChonkerInt::from(3u64).pow_u64(40).factor(), vec![(ChonkerInt::from(3), 40)]);
+        assert_eq!(ChonkerInt::from(1000003u64).pow_u64(3).factor(), vec![(ChonkerInt::from(1000003), 3)]);
+        assert_eq!(ChonkerInt::from(2003u64).pow_u64(5).factor(), vec![(ChonkerInt::from(2003), 5)]);
+        assert_eq!(
+            ChonkerInt::from(2003u64 * 2011).pow_u64(2).factor(),
+            vec![(ChonkerInt::from(2003), 2), (ChonkerInt::from(2011), 2)]
+        );
+    }
+
     // Test a factorisation of a composite BigInt.
     #[test]
     fn test_bigint_factorisation() {
@@ -233,14 +563,14 @@ mod tests {
         let bigint_prime = ChonkerInt::new_prime(&10);
 
         // Calculate vectors with factors of the target BigInts.
-        let bigint_candidate1_factors = bigint_candidate1.factor();
-        let bigint_candidate2_factors = bigint_candidate2.factor();
-        let bigint_candidate3_factors = bigint_candidate3.factor();
-        let bigint_candidate5_factors = bigint_candidate5.factor();
+        let bigint_candidate1_factors = bigint_candidate1.divisors();
+        let bigint_candidate2_factors = bigint_candidate2.divisors();
+        let bigint_candidate3_factors = bigint_candidate3.divisors();
+        let bigint_candidate5_factors = bigint_candidate5.divisors();
 
-        let bigint_zero_factors = bigint_zero.factor();
-        let bigint_one_factors = bigint_one.factor();
-        let bigint_prime_factors = bigint_prime.factor();
+        let bigint_zero_factors = bigint_zero.divisors();
+        let bigint_one_factors = bigint_one.divisors();
+        let bigint_prime_factors = bigint_prime.divisors();
 
         assert_eq!(
             bigint_candidate1_factors,
@@ -344,13 +674,43 @@ mod tests {
                 ChonkerInt::from(264137),
             ]
         );
-        assert_eq!(bigint_candidate5_factors, vec![]);
+        assert_eq!(bigint_candidate5_factors, Vec::<ChonkerInt>::new());
 
         assert!(bigint_zero_factors.is_empty());
         assert_eq!(bigint_one_factors, vec![ChonkerInt::from(1)]);
         assert!(bigint_prime_factors.is_empty());
     }
 
+    // Test the cancelled factorisation of an RSA modulus.
+    #[test]
+    fn test_bigint_rsa_modulus_factorisation_cancelled() {
+        let target_modulus = ChonkerInt::from(1847419907);
+        let iteration_start_point = ChonkerInt::from(1);
+
+        assert!(target_modulus.factor_rsa_modulus_until(&iteration_start_point, &AtomicBool::new(true)).is_empty());
+        assert_eq!(
+            target_modulus.factor_rsa_modulus_until(&iteration_start_point, &AtomicBool::new(false)),
+            vec![ChonkerInt::from(19441), ChonkerInt::from(95027)]
+        );
+    }
+
+    // Test the progress reports of the factorisation of an RSA modulus.
+    #[test]
+    fn test_bigint_rsa_modulus_factorisation_progress() {
+        let target_modulus = ChonkerInt::from(1847419907);
+        let mut reports = vec![];
+
+        let factor_list = target_modulus.factor_rsa_modulus_with_progress(&ChonkerInt::from(1), &AtomicBool::new(false), 1000, |candidate, tested| {
+            reports.push((candidate.clone(), tested));
+        });
+
+        // The smaller factor 19441 is the 9721st odd candidate, the 1000th candidate is 1999.
+        assert_eq!(factor_list, vec![ChonkerInt::from(19441), ChonkerInt::from(95027)]);
+        assert_eq!(reports.len(), 9);
+        assert_eq!(reports[0], (ChonkerInt::from(1999), 1000));
+        assert!(reports.windows(2).all(|pair| pair[0].0 < pair[1].0 && pair[0].1 + 1000 == pair[1].1));
+    }
+
     // Test a factorisation of an RSA modulus.
     #[test]
     fn test_bigint_rsa_modulus_factorisation() {
@@ -369,7 +729,7 @@ mod tests {
         // Randomly generated RSA public key exponent e: 13807417
         // Randomly generated RSA private key exponent d: 811358953
 
-        // Took ~1 min, length 11, primes: 19441, 95027
+        // Took ~1 min, length 11, primes: 270163, 312863
         // Randomly generated RSA public/private key modulus n: 84524006669
         // Randomly generated RSA public key exponent e: 7
         // Randomly generated RSA private key exponent d: 36224324419
@@ -381,7 +741,7 @@ mod tests {
         let target_modulus1 = ChonkerInt::from(30221);
         let target_modulus2 = ChonkerInt::from(58127681);
         let target_modulus3 = ChonkerInt::from(1847419907);
-        // let target_modulus4 = ChonkerInt::from(84524006669u64);
+        let target_modulus4 = ChonkerInt::from(84524006669u64);
         // let target_modulus5 = ChonkerInt::from(String::from("3934282079717923720147924446353532421128856963"));
 
         // Panics with zero and negative values.
@@ -393,7 +753,7 @@ mod tests {
         let factor_list1 = target_modulus1.factor_rsa_modulus(&iteration_start_point);
         let factor_list2 = target_modulus2.factor_rsa_modulus(&iteration_start_point);
         let factor_list3 = target_modulus3.factor_rsa_modulus(&iteration_start_point);
-        // let factor_list4 = target_modulus4.factor_rsa_modulus(&iteration_start_point);
+        let factor_list4 = target_modulus4.factor_rsa_modulus(&iteration_start_point);
 
         assert_eq!(
             factor_list1,
@@ -407,12 +767,22 @@ mod tests {
             factor_list3,
             vec![ChonkerInt::from(19441), ChonkerInt::from(95027),]
         );
-        // assert_eq!(
-        //     factor_list3,
-        //     vec![
-        //         ChonkerInt::from(270163),
-        //         ChonkerInt::from(312863),
-        //     ]
-        // );
+        assert_eq!(
+            factor_list4,
+            vec![
+                ChonkerInt::from(270163),
+                ChonkerInt::from(312863),
+            ]
+        );
+
+        // The square of a prime is a product of two primes.
+        assert_eq!(ChonkerInt::from(1018081).factor_rsa_modulus(&iteration_start_point), vec![ChonkerInt::from(1009), ChonkerInt::from(1009)]);
+    }
+
+    // Test the factorisation of an incorrect RSA modulus, which has more than two prime factors.
+    #[test]
+    #[should_panic]
+    fn test_bigint_rsa_modulus_factorisation_three_primes() {
+        ChonkerInt::from(30).factor_rsa_modulus(&ChonkerInt::from(1));
     }
 }
@@ -1,66 +1,113 @@
 // BigInt module regarding greatest common divisor of BigInts.
 
+use crate::logic::bigint::conversion::divide_digits;
 use crate::logic::bigint::ChonkerInt;
 
+// Result of the extended Euclidean algorithm: the GCD and the Bézout coefficients,
+// such that self * x + other * y == gcd.
 #[derive(Debug, PartialEq, Eq)]
-pub struct EGCDResult {
+pub struct EgcdResult {
     pub gcd: ChonkerInt,
-    pub self_x: ChonkerInt,
-    pub other_y: ChonkerInt,
+    pub x: ChonkerInt,
+    pub y: ChonkerInt,
+}
+
+// The previous name of the extended Euclidean algorithm result. The previous fields self_x/other_y are the fields x/y now,
+// the old code reads them through the deprecated accessors self_x()/other_y() instead of the fields.
+#[deprecated(note = "use EgcdResult, the coefficients were renamed from self_x/other_y to x/y, read them with the fields x/y")]
+pub type EGCDResult = EgcdResult;
+
+// Implement the accessors of the previous field names.
+impl EgcdResult {
+    // The coefficient of self, the previous self_x field.
+    #[deprecated(note = "use the field x")]
+    pub fn self_x(&self) -> &ChonkerInt {
+        &self.x
+    }
+
+    // The coefficient of other, the previous other_y field.
+    #[deprecated(note = "use the field y")]
+    pub fn other_y(&self) -> &ChonkerInt {
+        &self.y
+    }
 }
 
 // Implement gcd method for BigInt.
 impl ChonkerInt {
-    // A recursive function to find the greatest common divisor.
+    // An iterative function to find the greatest common divisor with the binary GCD (Stein's) algorithm.
+    // Instead of the full division it uses parity checks, halving and subtraction.
+    // The result is always non-negative, GCD with zero is the absolute value of the other operand.
+    // More about the idea: https://en.wikipedia.org/wiki/Binary_GCD_algorithm
     pub fn gcd(&self, other: &ChonkerInt) -> ChonkerInt {
-        let big_zero = ChonkerInt::new();
+        // Ensure that the operands are positive, make values absolute.
+        let mut first_operand = self.abs();
+        let mut second_operand = other.abs();
+        first_operand.normalize();
+        second_operand.normalize();
 
         // Check arguments for zeros.
-        if *self == big_zero || self.digits.is_empty() {
-            return (*other).clone();
-        } else if *other == big_zero || other.digits.is_empty() {
-            return (*self).clone();
+        if first_operand.is_zero() {
+            return if second_operand.is_zero() { ChonkerInt::new() } else { second_operand };
+        } else if second_operand.is_zero() {
+            return first_operand;
         }
 
-        // Ensure that the operands are positive, make values absolute.
-        let mut first_operand = (*self).clone();
-        first_operand.set_positive_sign();
-        let mut second_operand = (*other).clone();
-        second_operand.set_positive_sign();
-
-        if first_operand < second_operand {
-            second_operand.gcd(&first_operand)
-        } else if (&first_operand % &second_operand) == big_zero {
-            second_operand.clone()
-        } else {
-            second_operand.gcd(&(&first_operand % &second_operand))
+        // Remove the common factors of two, count them to restore at the end.
+        let mut common_twos = 0;
+        while first_operand.is_even() && second_operand.is_even() {
+            divide_digits(&mut first_operand.digits, 2);
+            divide_digits(&mut second_operand.digits, 2);
+            common_twos += 1;
         }
+
+        // The remaining factors of two are not common, remove them from the first operand.
+        while first_operand.is_even() {
+            divide_digits(&mut first_operand.digits, 2);
+        }
+
+        // The first operand is odd at this point, subtract the smaller odd operand from the bigger one,
+        // which results in an even number, and remove its factors of two, until the difference is zero.
+        loop {
+            while second_operand.is_even() {
+                divide_digits(&mut second_operand.digits, 2);
+            }
+
+            if first_operand > second_operand {
+                std::mem::swap(&mut first_operand, &mut second_operand);
+            }
+
+            second_operand = &second_operand - &first_operand;
+            if second_operand.is_zero() {
+                break;
+            }
+        }
+
+        &first_operand << common_twos
     }
 
-    // A recursive function to find the greatest common divisor.
-    pub fn egcd(&self, other: &ChonkerInt) -> EGCDResult {
+    // An iterative function to find the greatest common divisor and the Bézout coefficients,
+    // the extended Euclidean algorithm. The GCD is always non-negative and self * x + other * y == gcd.
+    pub fn egcd(&self, other: &ChonkerInt) -> EgcdResult {
         let big_zero = ChonkerInt::new();
         let big_one = ChonkerInt::from(1);
 
-        // Check arguments for zeros.
-        if *self == big_zero {
-            return EGCDResult {
-                gcd: (*other).clone(),
-                self_x: big_zero,
-                other_y: big_one,
+        // Check arguments for zeros, GCD with zero is the absolute value of the other operand.
+        if self.is_zero() {
+            return EgcdResult {
+                gcd: other.abs(),
+                x: big_zero,
+                y: ChonkerInt::from(other.signum()),
             };
-        } else if *other == big_zero {
-            return EGCDResult {
-                gcd: (*self).clone(),
-                self_x: big_zero,
-                other_y: big_one,
+        } else if other.is_zero() {
+            return EgcdResult {
+                gcd: self.abs(),
+                x: ChonkerInt::from(self.signum()),
+                y: big_zero,
             };
         }
 
-        let mut first_operand = (*self).clone();
-        first_operand.set_positive_sign();
-        let mut second_operand = (*other).clone();
-        second_operand.set_positive_sign();
+        let first_operand = self.abs();
+        let second_operand = other.abs();
 
         // Bézout coefficients x for self and y for other.
         let mut self_xs_old = big_one.clone();
@@ -75,15 +122,11 @@ impl ChonkerInt {
         let mut gcd_r = second_operand.clone();
 
         let mut temp;
-        let mut quotient;
 
         while gcd_r != big_zero {
-            quotient = &gcd_r_old / &gcd_r;
-
-            // Calculate remainders, last non-zero remainder is the GCD.
-            temp = gcd_r.clone();
-            gcd_r = &gcd_r_old - &(&quotient * &temp);
-            gcd_r_old = temp.clone();
+            // Calculate the quotient and the remainder in one pass, last non-zero remainder is the GCD.
+            let (quotient, remainder) = gcd_r_old.div_rem(&gcd_r);
+            gcd_r_old = std::mem::replace(&mut gcd_r, remainder);
 
             // Calculate coefficient x/s of the self, penultimate is the x/s coefficient of the self.
             temp = self_xs.clone();
@@ -96,22 +139,55 @@ impl ChonkerInt {
             other_yt_old = temp.clone();
         }
 
-        // Signs should be appropriately modified, unfinished section.
-        // Change the signs of coefficient based on the signs of inputs.
-        // if self.sign == BigIntSign::Negative && other.sign == BigIntSign::Negative {
-        //     self_xs_old.set_negative_sign();
-        //     other_yt_old.set_positive_sign();
-        // } else if self.sign == BigIntSign::Positive && other.sign == BigIntSign::Negative {
-        //     other_yt_old.set_positive_sign();
-        // } else if self.sign == BigIntSign::Negative && other.sign == BigIntSign::Positive {
-        //     self_xs_old.set_negative_sign();
-        // } // If both are positive, x is positive, y is negative by default.
+        // The coefficients were calculated for the absolute values,
+        // change the signs of coefficients based on the signs of inputs.
+        if self.is_negative() {
+            self_xs_old = -&self_xs_old;
+        }
+        if other.is_negative() {
+            other_yt_old = -&other_yt_old;
+        }
 
-        EGCDResult {
+        EgcdResult {
             gcd: gcd_r_old,
-            self_x: self_xs_old,
-            other_y: other_yt_old,
+            x: self_xs_old,
+            y: other_yt_old,
+        }
+    }
+
+    // Find the least common multiple of two BigInts, the result is always non-negative.
+    // The division by the GCD is done first to avoid an oversized intermediate product.
+    // If either of the operands is zero, the result is zero.
+    pub fn lcm(&self, other: &ChonkerInt) -> ChonkerInt {
+        if self.is_zero() || other.is_zero() {
+            return ChonkerInt::new();
+        }
+
+        let first_operand = self.abs();
+        let second_operand = other.abs();
+
+        &(&first_operand / &first_operand.gcd(&second_operand)) * &second_operand
+    }
+
+    // Find the modular multiplicative inverse of the BigInt with the extended Euclidean algorithm.
+    // Returns None, if the BigInt and the modulus are not coprime, thus the inverse does not exist,
+    // otherwise the inverse is normalized into the range [0, |modulus|).
+    pub fn modinv(&self, modulus: &ChonkerInt) -> Option<ChonkerInt> {
+        let modulus = modulus.abs();
+        if modulus.is_zero() {
+            return None;
         }
+
+        // Reduce the BigInt into the range of the modulus, the extended Euclidean algorithm expects non-negative operands.
+        let reduced = self.rem_euclid(&modulus);
+        let result = reduced.egcd(&modulus);
+
+        if !result.gcd.is_one() {
+            return None;
+        }
+
+        // The Bézout coefficient may be negative, normalize it into the range of the modulus.
+        Some(result.x.rem_euclid(&modulus))
     }
 }
 
@@ -119,6 +195,8 @@ impl ChonkerInt {
 #[cfg(test)]
 mod tests {
     use crate::logic::bigint::{BigIntSign, ChonkerInt};
+    #[allow(deprecated)]
+    use crate::logic::bigint::gcd::EGCDResult;
 
     // Test the method computing the greatest common divisor between two BigInts.
     #[test]
@@ -222,7 +300,157 @@ mod tests {
         );
 
         assert_eq!(result_custom_gcd_calculated.gcd, result_custom_gcd);
-        assert_eq!(result_custom_gcd_calculated.self_x, custom_xs);
-        assert_eq!(result_custom_gcd_calculated.other_y, custom_yt);
+        assert_eq!(result_custom_gcd_calculated.x, custom_xs);
+        assert_eq!(result_custom_gcd_calculated.y, custom_yt);
+
+        // The previous names of the result and of its coefficients still work.
+        #[allow(deprecated)]
+        {
+            let previous_result: EGCDResult = bigint4.egcd(&bigint5);
+            assert_eq!((previous_result.self_x(), previous_result.other_y()), (&custom_xs, &custom_yt));
+        }
+    }
+
+    // Test the Bézout identity of the extended Euclidean algorithm for all sign combinations.
+    #[test]
+    fn test_bigint_egcd_bezout_identity() {
+        for _ in 0..5 {
+            for first_sign in [BigIntSign::Positive, BigIntSign::Negative] {
+                for second_sign in [BigIntSign::Positive, BigIntSign::Negative] {
+                    let bigint1 = ChonkerInt::new_rand(&30, &first_sign);
+                    let bigint2 = ChonkerInt::new_rand(&20, &second_sign);
+                    let result = bigint1.egcd(&bigint2);
+
+                    assert_eq!(&(&bigint1 * &result.x) + &(&bigint2 * &result.y), result.gcd);
+                    assert_eq!(result.gcd, bigint1.gcd(&bigint2));
+                }
+            }
+        }
+
+        // Zero operands.
+        let bigint = ChonkerInt::from(-12);
+        let zero_bigint = ChonkerInt::new();
+        for (first_operand, second_operand) in [(&bigint, &zero_bigint), (&zero_bigint, &bigint), (&zero_bigint, &zero_bigint)] {
+            let result = first_operand.egcd(second_operand);
+            assert_eq!(&(first_operand * &result.x) + &(second_operand * &result.y), result.gcd);
+            assert_eq!(result.gcd, first_operand.gcd(second_operand));
+        }
+    }
+
+    // The previous recursive Euclidean algorithm, used as a reference for the binary GCD.
+    fn euclidean_gcd(first_operand: &ChonkerInt, second_operand: &ChonkerInt) -> ChonkerInt {
+        if second_operand.is_zero() {
+            return first_operand.abs();
+        }
+
+        euclidean_gcd(second_operand, &first_operand.rem_euclid(second_operand))
+    }
+
+    // Test the binary GCD against the Euclidean algorithm.
+    #[test]
+    fn test_bigint_binary_gcd() {
+        // Random inputs of various lengths and signs, including common factors of two.
+        for (first_length, second_length) in [(5u64, 3u64), (20, 20), (40, 15), (60, 35)] {
+            let bigint1 = ChonkerInt::new_rand(&first_length, &BigIntSign::Positive);
+            let bigint2 = ChonkerInt::new_rand(&second_length, &BigIntSign::Negative);
+            let common_factor = ChonkerInt::new_rand(&10, &BigIntSign::Positive);
+            let bigint3 = &bigint1 * &(&common_factor * 64u64);
+            let bigint4 = &bigint2 * &(&common_factor * 48u64);
+
+            assert_eq!(bigint1.gcd(&bigint2), euclidean_gcd(&bigint1, &bigint2));
+            assert_eq!(bigint3.gcd(&bigint4), euclidean_gcd(&bigint3, &bigint4));
+            assert_eq!(bigint4.gcd(&bigint3), bigint3.gcd(&bigint4));
+        }
+
+        // Small known values.
+        assert_eq!(ChonkerInt::from(48).gcd(&ChonkerInt::from(18)), ChonkerInt::from(6));
+        assert_eq!(ChonkerInt::from(-48).gcd(&ChonkerInt::from(-18)), ChonkerInt::from(6));
+        assert_eq!(ChonkerInt::from(1024).gcd(&ChonkerInt::from(4096)), ChonkerInt::from(1024));
+        assert_eq!(ChonkerInt::from(17).gcd(&ChonkerInt::from(17)), ChonkerInt::from(17));
+        assert_eq!(ChonkerInt::from(17).gcd(&ChonkerInt::from(19)), ChonkerInt::from(1));
+    }
+
+    // Test the GCD with zero, which is the absolute value of the other operand.
+    #[test]
+    fn test_bigint_gcd_with_zero() {
+        let bigint = ChonkerInt::new_rand(&30, &BigIntSign::Negative);
+
+        assert_eq!(ChonkerInt::new().gcd(&bigint), bigint.abs());
+        assert_eq!(bigint.gcd(&ChonkerInt::new()), bigint.abs());
+        assert_eq!(ChonkerInt::new().gcd(&ChonkerInt::from(-7)), ChonkerInt::from(7));
+        assert_eq!(ChonkerInt::new().gcd(&ChonkerInt::new()), ChonkerInt::new());
+    }
+
+    // Stress test the GCD of two 5000 digit numbers.
+    #[test]
+    fn test_bigint_gcd_stress() {
+        let common_factor = ChonkerInt::new_rand(&100, &BigIntSign::Positive);
+        let bigint1 = &ChonkerInt::new_rand(&4900, &BigIntSign::Positive) * &common_factor;
+        let bigint2 = &ChonkerInt::new_rand(&4900, &BigIntSign::Negative) * &common_factor;
+
+        let start = std::time::Instant::now();
+        let result = bigint1.gcd(&bigint2);
+        println!("GCD of two 5000 digit numbers took {:?}", start.elapsed());
+
+        // The common factor divides the result, and the result divides both operands.
+        assert!((&result % &common_factor).is_zero());
+        assert!((&bigint1 % &result).is_zero());
+        assert!((&bigint2 % &result).is_zero());
+    }
+
+    // Test the least common multiple of BigInts.
+    #[test]
+    fn test_bigint_lcm() {
+        // Small known values.
+        assert_eq!(ChonkerInt::from(4).lcm(&ChonkerInt::from(6)), ChonkerInt::from(12));
+        assert_eq!(ChonkerInt::from(21).lcm(&ChonkerInt::from(6)), ChonkerInt::from(42));
+        assert_eq!(ChonkerInt::from(7).lcm(&ChonkerInt::from(13)), ChonkerInt::from(91));
+        assert_eq!(ChonkerInt::from(12).lcm(&ChonkerInt::from(12)), ChonkerInt::from(12));
+        assert_eq!(ChonkerInt::from(1).lcm(&ChonkerInt::from(99)), ChonkerInt::from(99));
+
+        // Zero operands.
+        assert_eq!(ChonkerInt::new().lcm(&ChonkerInt::from(6)), ChonkerInt::new());
+        assert_eq!(ChonkerInt::from(6).lcm(&ChonkerInt::new()), ChonkerInt::new());
+        assert_eq!(ChonkerInt::new().lcm(&ChonkerInt::new()), ChonkerInt::new());
+
+        // Negative operands produce a non-negative result.
+        assert_eq!(ChonkerInt::from(-4).lcm(&ChonkerInt::from(6)), ChonkerInt::from(12));
+        assert_eq!(ChonkerInt::from(4).lcm(&ChonkerInt::from(-6)), ChonkerInt::from(12));
+        assert_eq!(ChonkerInt::from(-4).lcm(&ChonkerInt::from(-6)), ChonkerInt::from(12));
+
+        // Big values, lcm * gcd == |a * b|.
+        let bigint1 = ChonkerInt::new_rand(&30, &BigIntSign::Positive);
+        let bigint2 = ChonkerInt::new_rand(&25, &BigIntSign::Negative);
+        assert_eq!(&bigint1.lcm(&bigint2) * &bigint1.gcd(&bigint2), (&bigint1 * &bigint2).abs());
+    }
+
+    // Test the modular multiplicative inverse of BigInts.
+    #[test]
+    fn test_bigint_modinv() {
+        // Known inverse pairs.
+        assert_eq!(ChonkerInt::from(3).modinv(&ChonkerInt::from(11)), Some(ChonkerInt::from(4)));
+        assert_eq!(ChonkerInt::from(10).modinv(&ChonkerInt::from(17)), Some(ChonkerInt::from(12)));
+        assert_eq!(ChonkerInt::from(85).modinv(&ChonkerInt::from(268934988)), Some(ChonkerInt::from(88590349)));
+        assert_eq!(ChonkerInt::from(-3).modinv(&ChonkerInt::from(11)), Some(ChonkerInt::from(7)));
+        assert_eq!(ChonkerInt::from(14).modinv(&ChonkerInt::from(11)), Some(ChonkerInt::from(4)));
+        assert_eq!(ChonkerInt::from(5).modinv(&ChonkerInt::from(1)), Some(ChonkerInt::new()));
+
+        // Non-invertible inputs.
+        assert_eq!(ChonkerInt::from(6).modinv(&ChonkerInt::from(9)), None);
+        assert_eq!(ChonkerInt::new().modinv(&ChonkerInt::from(9)), None);
+        assert_eq!(ChonkerInt::from(6).modinv(&ChonkerInt::new()), None);
+
+        // Random coprime pairs, a * a^-1 % m == 1.
+        let modulus = ChonkerInt::new_prime(&15);
+        for _ in 0..10 {
+            let bigint = ChonkerInt::new_rand(&30, &BigIntSign::Negative);
+            if (&bigint % &modulus).is_zero() {
+                continue;
+            }
+
+            let inverse = bigint.modinv(&modulus).unwrap();
+            assert!(!inverse.is_negative() && inverse < modulus);
+            assert!((&(&bigint * &inverse) % &modulus).is_one());
+        }
     }
 }
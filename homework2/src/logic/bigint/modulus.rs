@@ -1,154 +1,78 @@
 // BigInt module regarding modulus division of BigInts.
-// Paper on Bernikel Zielger’s recursive division algorithm https://citeseerx.ist.psu.edu/viewdoc/download?doi=10.1.1.47.565&rep=rep1&type=pdf
+// The long division follows Knuth, The Art of Computer Programming, Vol. 2, section 4.3.1, Algorithm D.
 
 use std::ops::Rem;
 
-use crate::logic::bigint::division::quotient_estimation_algorithm;
 use crate::logic::bigint::{BigIntSign, ChonkerInt};
+use crate::logic::error::OperationError;
 
-// Implement modulus "%" operator for the BigInt. The sign of the result follows the divisor.
-// The implementation is a copy of the division implementation, except it returns the remainder instead of quotient,
-// which is not even stored.
+// Implement modulus "%" operator for the BigInt. The sign of the result follows the divisor (floored modulo),
+// e.g. -7 % 3 == 2 and 7 % -3 == -2, which differs from the "%" of the primitive integers.
+// For the primitive integer semantics use ChonkerInt::rem_trunc(), for the always non-negative result use ChonkerInt::rem_euclid().
+// The implementation is based on the division, which calculates the quotient and the remainder in one pass.
 impl<'a, 'b> Rem<&'b ChonkerInt> for &'a ChonkerInt {
     type Output = ChonkerInt;
 
     fn rem(self, rhs: &'b ChonkerInt) -> Self::Output {
-        // Check for division by zero, if the divisor is zero, panic.
-        if *rhs == ChonkerInt::new() || rhs.digits.is_empty() {
-            panic!("attempt to divide/take modulus by zero (ChonkerInt::rem())");
+        match self.try_rem(rhs) {
+            Ok(result) => result,
+            Err(error) => panic!("{} (ChonkerInt::rem())", error),
         }
+    }
+}
 
-        // Check if the zero is divided, if the dividend is zero, return zero.
-        if *self == ChonkerInt::new() || self.digits.is_empty() {
-            return ChonkerInt::new();
-        }
+impl ChonkerInt {
+    // Take modulus of the BigInt by another one, return an error instead of panicking if the divisor is zero.
+    pub fn checked_rem(&self, rhs: &ChonkerInt) -> Result<ChonkerInt, OperationError> {
+        let (_quotient, mut remainder) = self.checked_div_rem(rhs)?;
 
-        // Clone dividend and divisor, make them absolute for comparisons.
-        let mut absolute_dividend = (*self).clone();
-        absolute_dividend.set_positive_sign();
-        let mut absolute_divisor = (*rhs).clone();
-        absolute_divisor.set_positive_sign();
-
-        // Compare the lengths/values of the dividend and divisor.
-        // If self/dividend is smaller that the divisor, either the dividend or the sum of  the divisor with the dividend.
-        if absolute_dividend < absolute_divisor {
-            if (self.sign == BigIntSign::Negative && rhs.sign == BigIntSign::Positive)
-                || (self.sign == BigIntSign::Positive && rhs.sign == BigIntSign::Negative)
-            {
-                return rhs + self;
-            } else if self.sign == BigIntSign::Negative && rhs.sign == BigIntSign::Negative {
-                return (*self).clone();
-            }
-            // Both dividend and divisor are positive.
-            return (*self).clone();
+        // This is an implementaion of the modulo operation, not the remainder,
+        // thus the final sign follows the sign of the divisor.
+        // Check for the signs of the truncated remainder, which follows the dividend, and the divisor,
+        // if they differ and the remainder is not a zero, add 1 divisor to it.
+        if remainder.sign != BigIntSign::Zero && remainder.sign != rhs.sign {
+            remainder = &remainder + rhs;
         }
 
-        let mut remainder;
-        let mut cut_dividend = ChonkerInt::new();
-        cut_dividend.set_positive_sign();
-
-        // Compare lengths of the dividend and divisor and normalization of the most significant digits.
-        // Length of the dividend should not be more than length of the divisor + 1.
-        // If it is, cut the dividend.
-        if self.digits.len() > rhs.digits.len()
-        {
-            // Cut the dividend to the smaller size of divisor's length with or without additional digit,
-            // calculate the remainder that will be used as the dividend,
-            // and then repeat the process by adding digits from the original dividend to the remainders one by one in the loop.
-
-            let mut dividend_index = self.digits.len();
-            // let mut difference = rhs.digits.len() + 1;
-            let mut difference;
-
-            // Check normalization of the divisor's most significant digit and compare it to the dividend's digit,
-            // if divisor's digit is not normalized, and it is smaller than the dividend's one,
-            // make the difference/required length for the cut dividend equal to the divisor's length;
-            // otherwise add 1 to it.
-            if (self.digits[self.digits.len() - 1] > rhs.digits[rhs.digits.len() - 1])
-                || (absolute_dividend > absolute_divisor)
-            {
-                difference = rhs.digits.len();
-            } else {
-                difference = rhs.digits.len() + 1;
-            }
-
-            let cut_dividend_splice =
-                &self.digits[(dividend_index - difference)..=(dividend_index - 1)];
-
-            for digit in cut_dividend_splice.iter().rev() {
-                cut_dividend.digits.insert(0, *digit);
-            }
-
-            let (_quotient_digit, remainder_digit) =
-                quotient_estimation_algorithm(&cut_dividend, rhs);
-
-            cut_dividend = remainder_digit;
-
-            dividend_index -= difference;
-
-            difference = 1;
-
-            // Loop over the dividend's digits one by one and add them to the remainder from the previous iteration.
-            while dividend_index > 0 {
-                let cut_dividend_splice =
-                    &self.digits[(dividend_index - difference)..=(dividend_index - 1)];
-
-                // Add remaining digits from the dividend to the remainder from previous division operation.
-                // Preserve little endian from the dividend with reverse of the iterator over the dividend.
-                for digit in cut_dividend_splice.iter().rev() {
-                    cut_dividend.digits.insert(0, *digit);
-                }
-                cut_dividend.set_positive_sign();
-                cut_dividend.normalize();
-
-                // Check if the dividend became shorter/smaller than the divisor, if so,
-                // skip the iteration.
-                if cut_dividend < absolute_divisor {
-                    dividend_index -= difference;
-                    continue;
-                }
-
-                let (_quotient_digit, remainder_digit) =
-                    quotient_estimation_algorithm(&cut_dividend, rhs);
-
-                // Use the remainder digit(-s) for the new partial dividend.
-                // Reverse digits from little endian to big endian.
-                cut_dividend = remainder_digit.clone();
+        Ok(remainder)
+    }
 
-                dividend_index -= difference;
-            }
+    // Take modulus of the BigInt by another one, return an error if the divisor is zero
+    // or the long division reached an impossible state. The "%" operator unwraps the result.
+    pub fn try_rem(&self, rhs: &ChonkerInt) -> Result<ChonkerInt, OperationError> {
+        self.checked_rem(rhs)
+    }
 
-            // Clone the remainder.
-            remainder = cut_dividend.clone();
-        } else {
-            // If lengths of dividend and divisor are equal.
-            let (_quotient_digit, remainder_digit) = quotient_estimation_algorithm(self, rhs);
-            // Clone the remainder.
-            remainder = remainder_digit;
+    // Calculate the truncated remainder, the sign of the result follows the dividend,
+    // like the "%" operator of the primitive integers, e.g. -7 rem_trunc 3 == -1.
+    pub fn rem_trunc(&self, rhs: &ChonkerInt) -> ChonkerInt {
+        match self.checked_div_rem(rhs) {
+            Ok((_quotient, remainder)) => remainder,
+            Err(error) => panic!("{} (ChonkerInt::rem_trunc())", error),
         }
+    }
 
-        // This is an implementaion of the modulo operation, not the remainder,
-        // thus the final sign follows the sign of the divisor.
-        // Check for the sign of the dividend,
-        // if it is negative and the remainder is not a zero,
-        // make the remainder negative and add 1 divisor to it.
-        if remainder != ChonkerInt::new() {
-            if self.sign == BigIntSign::Negative && rhs.sign == BigIntSign::Positive {
-                remainder.set_negative_sign();
-                remainder = &remainder + rhs;
-            } else if self.sign == BigIntSign::Positive && rhs.sign == BigIntSign::Negative {
+    // Calculate the Euclidean remainder, the result is always non-negative regardless of the signs of the operands,
+    // like i64::rem_euclid(), e.g. -7 rem_euclid 3 == 2 and -7 rem_euclid -3 == 2.
+    pub fn rem_euclid(&self, rhs: &ChonkerInt) -> ChonkerInt {
+        let mut remainder = self.rem_trunc(rhs);
+
+        // If the truncated remainder is negative, shift it by the absolute value of the divisor.
+        if remainder.sign == BigIntSign::Negative {
+            if rhs.sign == BigIntSign::Negative {
+                remainder = &remainder - rhs;
+            } else {
                 remainder = &remainder + rhs;
-            } else if self.sign == BigIntSign::Negative && rhs.sign == BigIntSign::Negative {
-                remainder.set_negative_sign();
             }
         }
 
-        // Cut the leading zeros.
-        remainder.normalize();
         remainder
     }
 }
 
+// Implement modulo "%" operator for owned BigInts and mixes of owned and borrowed ones.
+forward_binary_operator!(Rem, rem);
+
 #[cfg(test)]
 mod tests {
     use crate::logic::bigint::ChonkerInt;
@@ -360,4 +284,73 @@ mod tests {
             negative_very_big_smaller_by_negative_very_big_bigger_result
         );
     }
+
+    // Test modulo of owned BigInts and mixes of owned and borrowed ones.
+    #[test]
+    fn test_bigint_owned_modulus() {
+        let target = ChonkerInt::from(-1234567);
+        let other = ChonkerInt::from(89);
+        let reference_result = &target % &other;
+
+        assert_eq!(ChonkerInt::from(-1234567) % ChonkerInt::from(89), reference_result);
+        assert_eq!(ChonkerInt::from(-1234567) % &other, reference_result);
+        assert_eq!(&target % ChonkerInt::from(89), reference_result);
+    }
+
+    // Test checked modulus of BigInts.
+    #[test]
+    fn test_bigint_checked_modulus() {
+        let bigint = ChonkerInt::from(-1234567);
+
+        // Zero divisor produces an error instead of a panic.
+        assert!(bigint.checked_rem(&ChonkerInt::new()).is_err());
+        assert!(ChonkerInt::new().checked_rem(&ChonkerInt::new()).is_err());
+
+        // Zero dividend produces zero.
+        assert_eq!(ChonkerInt::new().checked_rem(&bigint).unwrap(), ChonkerInt::new());
+
+        // Equal operands produce zero.
+        assert_eq!(bigint.checked_rem(&bigint).unwrap(), ChonkerInt::new());
+
+        // Checked modulus agrees with the operator.
+        let divisor = ChonkerInt::from(89);
+        assert_eq!(bigint.checked_rem(&divisor).unwrap(), &bigint % &divisor);
+    }
+
+    // Test modulus by zero with the operator.
+    #[test]
+    #[should_panic]
+    fn test_bigint_modulus_by_zero() {
+        let _ = &ChonkerInt::from(12345) % &ChonkerInt::new();
+    }
+
+    // Test the truncated and Euclidean remainders against the primitive integers for all sign combinations.
+    #[test]
+    fn test_bigint_rem_trunc_and_rem_euclid() {
+        for (dividend, divisor) in [(7i64, 3i64), (-7, 3), (7, -3), (-7, -3), (6, 3), (-6, -3), (2, 5), (-2, 5), (2, -5), (-2, -5), (0, 3), (0, -3)] {
+            let bigint_dividend = ChonkerInt::from(dividend);
+            let bigint_divisor = ChonkerInt::from(divisor);
+
+            assert_eq!(bigint_dividend.rem_trunc(&bigint_divisor), ChonkerInt::from(dividend % divisor));
+            assert_eq!(bigint_dividend.rem_euclid(&bigint_divisor), ChonkerInt::from(dividend.rem_euclid(divisor)));
+        }
+
+        // The "%" operator follows the divisor.
+        assert_eq!(&ChonkerInt::from(-7) % &ChonkerInt::from(3), ChonkerInt::from(2));
+        assert_eq!(&ChonkerInt::from(7) % &ChonkerInt::from(-3), ChonkerInt::from(-2));
+    }
+
+    // Test the truncated remainder by zero.
+    #[test]
+    #[should_panic]
+    fn test_bigint_rem_trunc_by_zero() {
+        let _ = ChonkerInt::from(12345).rem_trunc(&ChonkerInt::new());
+    }
+
+    // Test the Euclidean remainder by zero.
+    #[test]
+    #[should_panic]
+    fn test_bigint_rem_euclid_by_zero() {
+        let _ = ChonkerInt::from(-12345).rem_euclid(&ChonkerInt::new());
+    }
 }
@@ -0,0 +1,186 @@
+// BigInt module regarding Montgomery multiplication and reduction of BigInts.
+// Montgomery form replaces the full division after every multiplication in the modular exponentiation
+// with a reduction by the power of the radix, which is just a shift of the limbs.
+// The radix of the limbs is 10^9, thus the modulus has to be coprime to 10: odd and not divisible by 5.
+// More about the idea: https://en.wikipedia.org/wiki/Montgomery_modular_multiplication
+
+use crate::logic::bigint::{BigIntSign, ChonkerInt, RADIX, RADIX_DIGITS};
+
+// Precomputed parameters of the Montgomery reduction for a modulus N with n limbs and R = RADIX^n.
+pub(super) struct MontgomeryContext {
+    // Positive modulus N without leading zeros.
+    modulus: ChonkerInt,
+    // Limbs of the modulus, the amount of limbs n determines R.
+    modulus_limbs: Vec<u32>,
+    // Negated inverse of the least significant limb of the modulus: -N^(-1) mod RADIX.
+    inverse: u64,
+    // R^2 mod N, used for the conversion into the Montgomery form.
+    r_squared: ChonkerInt,
+}
+
+impl MontgomeryContext {
+    // Precompute the Montgomery parameters for the absolute value of the modulus.
+    // Return None if the modulus is not coprime to the radix, i.e. it is even or divisible by 5.
+    pub(super) fn new(modulus: &ChonkerInt) -> Option<MontgomeryContext> {
+        let mut modulus = modulus.abs();
+        modulus.normalize();
+
+        // Check if the modulus is coprime to 10, the least significant decimal digit decides it.
+        let least_significant_limb = *modulus.digits.first()?;
+        if least_significant_limb % 2 == 0 || least_significant_limb % 5 == 0 {
+            return None;
+        }
+
+        // Inverse of the least significant limb modulo RADIX exists, since they are coprime.
+        let limb_inverse = ChonkerInt::from(least_significant_limb)
+            .modinv(&ChonkerInt::from(RADIX))?
+            .to_u128()
+            .ok()? as u64;
+        let inverse = (RADIX as u64 - limb_inverse) % RADIX as u64;
+
+        // R^2 = RADIX^(2n), reduce it by the modulus once, for all conversions.
+        let modulus_limbs = modulus.digits.clone();
+        let r_squared = ChonkerInt::from(1)
+            .mul_pow10(2 * modulus_limbs.len() * RADIX_DIGITS)
+            .rem_euclid(&modulus);
+
+        Some(MontgomeryContext {
+            modulus,
+            modulus_limbs,
+            inverse,
+            r_squared,
+        })
+    }
+
+    // Convert a non-negative number smaller than the modulus into the Montgomery form: x * R mod N.
+    pub(super) fn to_montgomery(&self, number: &ChonkerInt) -> ChonkerInt {
+        self.multiply(number, &self.r_squared)
+    }
+
+    // Convert a number from the Montgomery form back: x * R^(-1) mod N.
+    pub(super) fn from_montgomery(&self, number: &ChonkerInt) -> ChonkerInt {
+        self.multiply(number, &ChonkerInt::from(1))
+    }
+
+    // Calculate a * b * R^(-1) mod N of two non-negative numbers smaller than the modulus,
+    // with the interleaved multiplication and reduction one limb at a time (CIOS method).
+    pub(super) fn multiply(&self, a: &ChonkerInt, b: &ChonkerInt) -> ChonkerInt {
+        let radix = RADIX as u64;
+        let length = self.modulus_limbs.len();
+        let a_limbs = a.significant_digits();
+        let b_limbs = b.significant_digits();
+
+        // Accumulator of n + 2 limbs, every limb fits into 32 bits, the calculations are done in 64 bits:
+        // limb + limb * limb + carry < RADIX^2 + 2 * RADIX < 2^64.
+        let mut accumulator = vec![0u64; length + 2];
+
+        for index in 0..length {
+            let a_limb = *a_limbs.get(index).unwrap_or(&0) as u64;
+
+            // Add a_i * b to the accumulator.
+            let mut carry = 0;
+            for (position, b_limb) in b_limbs.iter().enumerate() {
+                let sum = accumulator[position] + a_limb * *b_limb as u64 + carry;
+                accumulator[position] = sum % radix;
+                carry = sum / radix;
+            }
+            for limb in accumulator.iter_mut().skip(b_limbs.len()) {
+                if carry == 0 {
+                    break;
+                }
+                let sum = *limb + carry;
+                *limb = sum % radix;
+                carry = sum / radix;
+            }
+
+            // Add m * N, which makes the least significant limb zero, and shift the accumulator by one limb.
+            let m = accumulator[0] * self.inverse % radix;
+            let mut carry = (accumulator[0] + m * self.modulus_limbs[0] as u64) / radix;
+            for position in 1..length {
+                let sum = accumulator[position] + m * self.modulus_limbs[position] as u64 + carry;
+                accumulator[position - 1] = sum % radix;
+                carry = sum / radix;
+            }
+            let sum = accumulator[length] + carry;
+            accumulator[length - 1] = sum % radix;
+            accumulator[length] = accumulator[length + 1] + sum / radix;
+            accumulator[length + 1] = 0;
+        }
+
+        let mut result = ChonkerInt {
+            digits: accumulator.iter().map(|limb| *limb as u32).collect(),
+            sign: BigIntSign::Positive,
+        };
+        result.normalize();
+
+        // The result is smaller than 2N, a single subtraction brings it into the range of the modulus.
+        if result >= self.modulus {
+            result = &result - &self.modulus;
+        }
+
+        result
+    }
+
+    // Calculate base^power mod N for a non-negative base smaller than the modulus and a positive power,
+    // with the left-to-right binary exponentiation in the Montgomery form.
+    pub(super) fn modpow(&self, base: &ChonkerInt, power: &ChonkerInt) -> ChonkerInt {
+        let montgomery_base = self.to_montgomery(base);
+        let mut result = self.to_montgomery(&ChonkerInt::from(1));
+
+        // Go over the bits of the power starting from the most significant one.
+        for byte in power.to_bytes_be() {
+            for bit in (0..8).rev() {
+                result = self.multiply(&result, &result);
+
+                if (byte >> bit) & 1 == 1 {
+                    result = self.multiply(&result, &montgomery_base);
+                }
+            }
+        }
+
+        self.from_montgomery(&result)
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::logic::bigint::montgomery::MontgomeryContext;
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
+
+    // Test conversion into the Montgomery form and back, and the Montgomery multiplication.
+    #[test]
+    fn test_bigint_montgomery_multiplication() {
+        for modulus_length in [1u64, 9, 10, 25, 60] {
+            let mut modulus = ChonkerInt::new_rand(&modulus_length, &BigIntSign::Positive);
+            while &modulus % 2u64 == 0 || &modulus % 5u64 == 0 {
+                modulus = &modulus + 1u64;
+            }
+            let context = MontgomeryContext::new(&modulus).unwrap();
+
+            for _ in 0..10 {
+                let a = ChonkerInt::new_rand(&(modulus_length + 3), &BigIntSign::Positive).rem_euclid(&modulus);
+                let b = ChonkerInt::new_rand(&(modulus_length + 3), &BigIntSign::Positive).rem_euclid(&modulus);
+
+                let montgomery_a = context.to_montgomery(&a);
+                let montgomery_b = context.to_montgomery(&b);
+
+                assert_eq!(context.from_montgomery(&montgomery_a), a);
+                assert_eq!(
+                    context.from_montgomery(&context.multiply(&montgomery_a, &montgomery_b)),
+                    (&a * &b).rem_euclid(&modulus)
+                );
+            }
+        }
+    }
+
+    // Test that moduli sharing a factor with the radix are rejected.
+    #[test]
+    fn test_bigint_montgomery_unsupported_modulus() {
+        assert!(MontgomeryContext::new(&ChonkerInt::new()).is_none());
+        assert!(MontgomeryContext::new(&ChonkerInt::from(1_000_000_000)).is_none());
+        assert!(MontgomeryContext::new(&ChonkerInt::from(1234)).is_none());
+        assert!(MontgomeryContext::new(&ChonkerInt::from(-125)).is_none());
+        assert!(MontgomeryContext::new(&ChonkerInt::from(-127)).is_some());
+    }
+}
@@ -1,5 +1,6 @@
 // BigInt module regarding multiplication of BigInts.
 
+use std::iter::Product;
 use std::ops::Mul;
 
 use crate::logic::bigint::{clip, overflow, BigIntSign, ChonkerInt};
@@ -62,12 +63,12 @@ impl<'a, 'b> Mul<&'b ChonkerInt> for &'a ChonkerInt {
         let mut rhs_offset = 0;
         let rhs_length = rhs.digits.len();
         let mut partial_product_bigint: ChonkerInt;
-        let mut partial_product_digit: i8;
+        let mut partial_product_digit: u64;
 
         // Calculate intermediate/partial products and add them together to get the final product.
         while rhs_offset < rhs_length {
-            // Calculate a partial product. Iterate one digit of the rhs BigInt over all digits of the self BigInt
-            // and shift the result with zeros.
+            // Calculate a partial product. Iterate one limb of the rhs BigInt over all limbs of the self BigInt
+            // and shift the result with zero limbs.
 
             // Construct a temporary BigInt for the partial product.
             partial_product_bigint = ChonkerInt::new();
@@ -83,14 +84,14 @@ impl<'a, 'b> Mul<&'b ChonkerInt> for &'a ChonkerInt {
             // println!("Partial product bigint after shifting: {:?}", partial_product_bigint);
 
             while self_offset < self_length {
+                // Product of two limbs with the overflow always fits into 64 bits: (10^9 - 1)^2 + 10^9 < 2^64.
                 partial_product_digit =
-                    self.digits[self_offset] * rhs.digits[rhs_offset] + last_digit_overflow;
+                    self.digits[self_offset] as u64 * rhs.digits[rhs_offset] as u64 + last_digit_overflow;
 
                 // Check a partial product for overflow.
                 last_digit_overflow = overflow(partial_product_digit);
-                partial_product_digit = clip(partial_product_digit);
 
-                let _ = partial_product_bigint.push(partial_product_digit);
+                let _ = partial_product_bigint.push(clip(partial_product_digit));
 
                 self_offset += 1;
             }
@@ -100,12 +101,12 @@ impl<'a, 'b> Mul<&'b ChonkerInt> for &'a ChonkerInt {
 
             // println!("Partial product bigint after product calculation: {:?}", partial_product_bigint);
 
-            // If there is an overflow add as a last digit.
+            // If there is an overflow add as a last limb.
             // Considering that a number length after multiplication equals the sum of lengths of both operands,
-            // and the target is multiplied only by one digit at a time, the result will be longer only by 1 digit.
+            // and the target is multiplied only by one limb at a time, the result will be longer only by 1 limb.
             // Nullify overflow after its addition.
             if last_digit_overflow > 0 {
-                let _ = partial_product_bigint.push(last_digit_overflow);
+                let _ = partial_product_bigint.push(last_digit_overflow as u32);
                 last_digit_overflow = 0;
             }
 
@@ -131,10 +132,131 @@ impl<'a, 'b> Mul<&'b ChonkerInt> for &'a ChonkerInt {
     }
 }
 
+impl ChonkerInt {
+    // Multiply this BigInt by another one in place, the result is equal to "&self * other".
+    // The product is accumulated in a single new vector of limbs, which replaces the old one,
+    // instead of the temporary BigInts for every partial product of the operator.
+    pub fn mul_assign_ref(&mut self, other: &ChonkerInt) {
+        // If self or other is empty/zero, the product is zero.
+        if self.is_zero() || other.is_zero() {
+            self.digits.clear();
+            self.sign = BigIntSign::Zero;
+            return;
+        }
+
+        let negative = self.is_negative() != other.is_negative();
+        self.digits = multiply_digits(self.significant_digits(), other.significant_digits());
+        self.sign = if negative {
+            BigIntSign::Negative
+        } else {
+            BigIntSign::Positive
+        };
+        self.normalize();
+    }
+
+    // Square this BigInt in place, the result is equal to "&self * &self".
+    pub fn square_in_place(&mut self) {
+        // Zero squared is zero.
+        if self.is_zero() {
+            self.normalize();
+            return;
+        }
+
+        self.digits = multiply_digits(self.significant_digits(), self.significant_digits());
+        self.sign = BigIntSign::Positive;
+        self.normalize();
+    }
+}
+
+// Multiply two vectors of limbs in the little endian format,
+// the partial products are accumulated in place in the vector of the result.
+fn multiply_digits(one_vec: &[u32], other_vec: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; one_vec.len() + other_vec.len()];
+
+    for (other_offset, other_digit) in other_vec.iter().enumerate() {
+        let mut last_digit_overflow = 0;
+
+        for (one_offset, one_digit) in one_vec.iter().enumerate() {
+            // Limb of the result, product of two limbs and the overflow fit into 64 bits:
+            // (10^9 - 1) + (10^9 - 1)^2 + 10^9 < 2^64.
+            let partial_product_digit = result[one_offset + other_offset] as u64
+                + *one_digit as u64 * *other_digit as u64
+                + last_digit_overflow;
+
+            last_digit_overflow = overflow(partial_product_digit);
+            result[one_offset + other_offset] = clip(partial_product_digit);
+        }
+
+        result[one_vec.len() + other_offset] = last_digit_overflow as u32;
+    }
+
+    result
+}
+
+// Implement multiplication "*" operator for owned BigInts and mixes of owned and borrowed ones.
+forward_binary_operator!(Mul, mul);
+
+// Implement multiplication of all BigInts of an iterator, e.g. "vector.iter().product::<ChonkerInt>()".
+// Product of an empty iterator is one.
+impl Product for ChonkerInt {
+    fn product<I: Iterator<Item = ChonkerInt>>(iter: I) -> Self {
+        iter.fold(ChonkerInt::from(1), |accumulator, bigint| &accumulator * &bigint)
+    }
+}
+
+impl<'a> Product<&'a ChonkerInt> for ChonkerInt {
+    fn product<I: Iterator<Item = &'a ChonkerInt>>(iter: I) -> Self {
+        iter.fold(ChonkerInt::from(1), |accumulator, bigint| &accumulator * bigint)
+    }
+}
+
 // Test module.
 #[cfg(test)]
 mod tests {
-    use crate::logic::bigint::ChonkerInt;
+    use rand::Rng;
+
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
+
+    // Test the in place multiplication and squaring against the multiplication operator.
+    #[test]
+    fn test_bigint_mul_assign_ref_and_square_in_place() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..300 {
+            let self_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let other_sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let bigint = ChonkerInt::new_rand(&rng.gen_range(1..=60), &self_sign);
+            let other = ChonkerInt::new_rand(&rng.gen_range(1..=60), &other_sign);
+
+            let mut product = bigint.clone();
+            product.mul_assign_ref(&other);
+            assert_eq!(product, &bigint * &other);
+
+            let mut square = bigint.clone();
+            square.square_in_place();
+            assert_eq!(square, &bigint * &bigint);
+        }
+
+        // Zeros, ones and the limb boundaries.
+        let nines = ChonkerInt::from(999_999_999_999_999_999u64);
+        let cases = [
+            (ChonkerInt::new(), ChonkerInt::from(-5)),
+            (ChonkerInt::from(-5), ChonkerInt::new()),
+            (ChonkerInt::from(-1), nines.clone()),
+            (nines.clone(), -&nines),
+            (ChonkerInt::from(1_000_000_000), ChonkerInt::from(1_000_000_000)),
+        ];
+        for (bigint, other) in cases.iter() {
+            let mut product = bigint.clone();
+            product.mul_assign_ref(other);
+            assert_eq!(product, bigint * other);
+            assert_eq!(product.to_string(), (bigint * other).to_string());
+
+            let mut square = bigint.clone();
+            square.square_in_place();
+            assert_eq!(square, bigint * bigint);
+        }
+    }
 
     // Test multiplication of two BigInts.
     #[test]
@@ -220,4 +342,39 @@ mod tests {
             bigger_and_smaller_negative_empty_multiplication_result
         );
     }
+
+    // Test multiplication of owned BigInts and mixes of owned and borrowed ones.
+    #[test]
+    fn test_bigint_owned_multiplication() {
+        let target = ChonkerInt::from(-1234567);
+        let other = ChonkerInt::from(89);
+        let reference_result = &target * &other;
+
+        assert_eq!(ChonkerInt::from(-1234567) * ChonkerInt::from(89), reference_result);
+        assert_eq!(ChonkerInt::from(-1234567) * &other, reference_result);
+        assert_eq!(&target * ChonkerInt::from(89), reference_result);
+    }
+
+    // Test multiplication of all BigInts of iterators.
+    #[test]
+    fn test_bigint_product() {
+        let bigints = vec![
+            ChonkerInt::from(-12),
+            ChonkerInt::from(1000),
+            ChonkerInt::from(-7),
+            ChonkerInt::from(String::from("100000000000000000000")),
+        ];
+        let product_result = ChonkerInt::from(String::from("8400000000000000000000000"));
+
+        assert_eq!(bigints.iter().product::<ChonkerInt>(), product_result);
+        assert_eq!(bigints.into_iter().product::<ChonkerInt>(), product_result);
+
+        // Product with a zero is zero, product of an empty iterator is one.
+        let zero_bigints = vec![ChonkerInt::from(5), ChonkerInt::new(), ChonkerInt::from(-3)];
+        assert_eq!(zero_bigints.iter().product::<ChonkerInt>(), ChonkerInt::new());
+
+        let empty_bigints: Vec<ChonkerInt> = vec![];
+        assert_eq!(empty_bigints.iter().product::<ChonkerInt>(), ChonkerInt::from(1));
+        assert_eq!(empty_bigints.into_iter().product::<ChonkerInt>(), ChonkerInt::from(1));
+    }
 }
@@ -25,6 +25,21 @@ impl Neg for &ChonkerInt {
     }
 }
 
+// Implement negation "-" operator for an owned BigInt, reusing its vector of digits.
+impl Neg for ChonkerInt {
+    type Output = ChonkerInt;
+
+    fn neg(mut self) -> Self::Output {
+        match self.sign {
+            BigIntSign::Negative => self.set_positive_sign(),
+            BigIntSign::Positive => self.set_negative_sign(),
+            BigIntSign::Zero => (),
+        }
+
+        self
+    }
+}
+
 // Test module.
 #[cfg(test)]
 mod tests {
@@ -56,4 +71,15 @@ mod tests {
 
         assert_eq!(comparison_negative_bigint, negated_positive_bigint);
     }
+
+    // Test negation of an owned BigInt.
+    #[test]
+    fn test_owned_bigint_negation() {
+        let bigint = ChonkerInt::from(-1234567);
+
+        assert_eq!(-ChonkerInt::from(-1234567), -&bigint);
+        assert_eq!(-ChonkerInt::from(1234567), ChonkerInt::from(-1234567));
+        assert_eq!(-ChonkerInt::new(), ChonkerInt::new());
+        assert_eq!(-(-ChonkerInt::from(89)), ChonkerInt::from(89));
+    }
 }
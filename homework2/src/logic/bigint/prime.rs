@@ -1,52 +1,132 @@
 // BigInt module regarding prime BigInts.
 
 // Import required randomisation items.
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 
+use crate::logic::bigint::barrett::BarrettReducer;
+use crate::logic::bigint::conversion::pack_decimal_digits;
+use crate::logic::bigint::randomisation::{random_bytes_with_bit_length, secure_rng};
 use crate::logic::bigint::{BigIntSign, ChonkerInt};
+use crate::logic::cancellation::CancellationToken;
+use crate::logic::error::OperationError;
+
+// Number of the small odd primes used for the trial division of the candidates before the probabilistic test.
+const SMALL_ODD_PRIMES_COUNT: usize = 300;
+
+// The first 300 odd primes, 3 - 1993, the table is computed at compile time.
+pub(super) const SMALL_ODD_PRIMES: [u64; SMALL_ODD_PRIMES_COUNT] = small_odd_primes();
+
+// Largest prime of the table, numbers below its square without small factors are primes.
+const LARGEST_SMALL_PRIME: u64 = SMALL_ODD_PRIMES[SMALL_ODD_PRIMES_COUNT - 1];
+
+// Fill the table of the small odd primes by the trial division with the primes found so far.
+const fn small_odd_primes() -> [u64; SMALL_ODD_PRIMES_COUNT] {
+    let mut primes = [0u64; SMALL_ODD_PRIMES_COUNT];
+    let mut count = 0;
+    let mut candidate = 3;
+
+    while count < SMALL_ODD_PRIMES_COUNT {
+        let mut index = 0;
+        let mut is_prime = true;
+
+        while index < count && primes[index] * primes[index] <= candidate {
+            if candidate % primes[index] == 0 {
+                is_prime = false;
+                break;
+            }
+            index += 1;
+        }
+
+        if is_prime {
+            primes[count] = candidate;
+            count += 1;
+        }
+        candidate += 2;
+    }
+
+    primes
+}
+
+// Witnesses of the deterministic Miller - Rabin test, the first 13 primes.
+const DETERMINISTIC_WITNESSES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+// Bound of the deterministic Miller - Rabin test, the smallest strong pseudoprime to all of its witnesses.
+const DETERMINISTIC_WITNESSES_BOUND: u128 = 3_317_044_064_679_887_385_961_981;
 
 // Implement BigInt methods for random prime generation and primality testing.
 impl ChonkerInt {
     // Initialize a randomly filled prime BigInt.
-    // Test for primality is based on the Miller-Rabin probabilistic test. 10 trials are done.
+    // Test for primality is based on the Baillie-PSW test, see ChonkerInt::is_prime_baillie_psw().
+    // Primes are key material, the digits are drawn from the cryptographically secure generator.
     pub fn new_prime(length: &u64) -> ChonkerInt {
+        ChonkerInt::new_prime_with_rng(length, &mut secure_rng())
+    }
+
+    // Initialize a randomly filled prime BigInt, the search stops with an error once the token is cancelled.
+    pub fn new_prime_until(length: &u64, cancellation: &CancellationToken) -> Result<ChonkerInt, OperationError> {
+        ChonkerInt::new_prime_with_rng_until(length, &mut secure_rng(), cancellation)
+    }
+
+    // Initialize a randomly filled prime BigInt with the provided random number generator,
+    // which is used for the candidates, the Baillie-PSW test of primality has fixed parameters.
+    // The same seeded generator produces the same prime, e.g. with StdRng::seed_from_u64().
+    pub fn new_prime_with_rng<R: Rng + ?Sized>(length: &u64, rng: &mut R) -> ChonkerInt {
+        match ChonkerInt::new_prime_with_rng_until(length, rng, &CancellationToken::new()) {
+            Ok(prime) => prime,
+            Err(_) => unreachable!("the prime search without a cancellation can not be cancelled (ChonkerInt::new_prime_with_rng)"),
+        }
+    }
+
+    // Initialize a randomly filled prime BigInt with the provided random number generator,
+    // the token is checked before each candidate, once it is cancelled, the search stops with an error.
+    pub fn new_prime_with_rng_until<R: Rng + ?Sized>(length: &u64, rng: &mut R, cancellation: &CancellationToken) -> Result<ChonkerInt, OperationError> {
         if *length == 0 {
             panic!("requested length for random bigint generation is 0, nothing to generate");
         }
 
-        let mut rng = rand::thread_rng();
         let mut bigint = ChonkerInt::new();
         bigint.set_positive_sign();
-        let main_length = *length - 2;
-        let mut digit: i8;
-        let least_significant_candidates: Vec<i8> = vec![1, 3, 5, 7, 9];
+        let mut decimal_digits: Vec<u8>;
+        let least_significant_candidates: Vec<u8> = vec![1, 3, 7, 9];
 
         // If the length of the requested prime is 1, generate the prime separately.
         if *length == 1 {
-            let one_digit_prime_candidates: Vec<i8> = vec![2, 3, 5, 7];
-            digit = *(one_digit_prime_candidates.choose(&mut rng).unwrap());
-            let _ = bigint.push(digit);
+            let one_digit_prime_candidates: Vec<u32> = vec![2, 3, 5, 7];
+            let _ = bigint.push(*(one_digit_prime_candidates.choose(rng).unwrap()));
 
-            return bigint;
+            return Ok(bigint);
         }
 
+        let main_length = *length - 2;
         loop {
-            // Ensure that the produced BigInt is odd, by limiting the least significant values to odd ones:
-            // 1, 3, 5, 7, 9.
-            digit = *(least_significant_candidates.choose(&mut rng).unwrap());
-            let _ = bigint.push(digit);
+            if cancellation.is_cancelled() {
+                return Err(OperationError::new_cancelled(&format!("the search for a random prime of length {} was cancelled.", length)));
+            }
+
+            decimal_digits = Vec::with_capacity(*length as usize);
+
+            // Ensure that the produced BigInt is odd and not divisible by 5, by limiting the least significant values:
+            // 1, 3, 7, 9.
+            decimal_digits.push(*(least_significant_candidates.choose(rng).unwrap()));
 
             // Fill the empty BigInt with the requested amount of random digits in the range of 0-9.
             for _iteration in 0..main_length {
-                digit = rng.gen_range(0..=9);
-                let _ = bigint.push(digit);
+                decimal_digits.push(rng.gen_range(0..=9));
             }
             // Ensure that the leading/last digit is not zero. Generate it separately.
-            digit = rng.gen_range(1..=9);
-            let _ = bigint.push(digit);
+            decimal_digits.push(rng.gen_range(1..=9));
+
+            // Pack the generated decimal digits into limbs.
+            bigint.digits = pack_decimal_digits(&decimal_digits);
 
-            if bigint.is_prime_probabilistic(Some(5)) {
+            // Candidates with small factors are rejected by the trial division before the Baillie-PSW trials.
+            if bigint.is_prime_baillie_psw() {
                 break;
             } else {
                 bigint = ChonkerInt::new();
@@ -54,15 +134,208 @@ impl ChonkerInt {
             }
         }
 
-        bigint
+        Ok(bigint)
+    }
+
+    // Initialize a random prime BigInt with exactly the requested bit length, the most significant bit is set.
+    // Candidates are random odd numbers of the bit length, filtered with the trial division by the small primes,
+    // then tested with the Baillie-PSW test.
+    pub fn new_prime_bits<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> ChonkerInt {
+        match ChonkerInt::new_prime_bits_until(bits, rng, &CancellationToken::new()) {
+            Ok(prime) => prime,
+            Err(_) => unreachable!("the prime search without a cancellation can not be cancelled (ChonkerInt::new_prime_bits)"),
+        }
+    }
+
+    // Initialize a random prime BigInt with exactly the requested bit length,
+    // the token is checked before each candidate, once it is cancelled, the search stops with an error.
+    pub fn new_prime_bits_until<R: Rng + ?Sized>(bits: u64, rng: &mut R, cancellation: &CancellationToken) -> Result<ChonkerInt, OperationError> {
+        if bits < 2 {
+            panic!("requested bit length for random prime generation is below 2, there are no such primes (ChonkerInt::new_prime_bits)");
+        }
+
+        // The only primes with the bit length of 2 are 2 and 3, the odd candidates would skip 2.
+        if bits == 2 {
+            return Ok(ChonkerInt::from(*[2u32, 3].choose(rng).unwrap()));
+        }
+
+        loop {
+            if cancellation.is_cancelled() {
+                return Err(OperationError::new_cancelled(&format!("the search for a random prime of {} bits was cancelled.", bits)));
+            }
+
+            let candidate = ChonkerInt::new_odd_candidate_bits(bits, rng);
+            if candidate.is_prime_baillie_psw() {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    // Initialize a random prime BigInt with exactly the requested bit length, the candidates are generated and tested
+    // by the requested amount of the scoped threads. The first prime found is returned through the channel,
+    // the other workers are signalled to stop. Worker generators are seeded with the seed plus the worker index,
+    // thus a single thread with a seed reproduces ChonkerInt::new_prime_bits() with StdRng::seed_from_u64(seed).
+    // Without a seed, the generators are seeded from the cryptographically secure generator.
+    pub fn new_prime_parallel(bits: u64, thread_count: usize, rng_seed: Option<u64>) -> ChonkerInt {
+        ChonkerInt::prime_search_parallel(bits, thread_count, rng_seed).0
+    }
+
+    // Run the parallel prime search, return the prime and the amount of the workers still running after the shutdown.
+    fn prime_search_parallel(bits: u64, thread_count: usize, rng_seed: Option<u64>) -> (ChonkerInt, usize) {
+        if bits < 2 {
+            panic!("requested bit length for random prime generation is below 2, there are no such primes (ChonkerInt::new_prime_parallel)");
+        } else if thread_count == 0 {
+            panic!("requested thread count for random prime generation is 0, at least one thread is required (ChonkerInt::new_prime_parallel)");
+        }
+
+        let prime_found = AtomicBool::new(false);
+        let running_workers = AtomicUsize::new(0);
+        let (worker_sender, main_receiver) = mpsc::channel();
+        let mut secure_generator = secure_rng();
+
+        // The scope joins all of the workers before it returns, thus the workers borrow the flags.
+        let prime = thread::scope(|scope| {
+            for worker in 0..thread_count {
+                let mut rng = match rng_seed {
+                    Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(worker as u64)),
+                    None => StdRng::from_rng(&mut secure_generator).unwrap(),
+                };
+                let (prime_found, running_workers) = (&prime_found, &running_workers);
+                let worker_sender = worker_sender.clone();
+                running_workers.fetch_add(1, Ordering::SeqCst);
+
+                scope.spawn(move || {
+                    // Another worker has already found a prime, stop the search.
+                    while !prime_found.load(Ordering::SeqCst) {
+                        // The only primes with the bit length of 2 are 2 and 3, the odd candidates would skip 2.
+                        let candidate = if bits == 2 {
+                            ChonkerInt::from(*[2u32, 3].choose(&mut rng).unwrap())
+                        } else {
+                            ChonkerInt::new_odd_candidate_bits(bits, &mut rng)
+                        };
+
+                        if candidate.is_prime_baillie_psw() {
+                            prime_found.store(true, Ordering::SeqCst);
+                            let _sent_result = worker_sender.send(candidate);
+                        }
+                    }
+
+                    running_workers.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+
+            // Receive the first prime and signal the other workers to stop, the scope waits for them to finish.
+            let prime = main_receiver.recv().unwrap();
+            prime_found.store(true, Ordering::SeqCst);
+            prime
+        });
+
+        (prime, running_workers.load(Ordering::SeqCst))
+    }
+
+    // Generate a random odd candidate with exactly the requested bit length, the most and the least significant bits are set.
+    fn new_odd_candidate_bits<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> ChonkerInt {
+        let mut bytes = random_bytes_with_bit_length(bits, rng);
+        let last_byte = bytes.len() - 1;
+        bytes[last_byte] |= 1;
+
+        ChonkerInt::from_bytes_be(&bytes)
+    }
+
+    // Initialize a random safe prime p = 2q + 1 with exactly the requested bit length, where q is also a prime.
+    // Safe primes are used as the Diffie-Hellman modulus, the multiplicative group has no small subgroups,
+    // except the one of order 2. Candidates q of bits - 1 bits are filtered with the trial division of both q and 2q + 1,
+    // and only then tested with the Miller-Rabin test, first with a single trial, then with 5 more trials.
+    pub fn new_safe_prime<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> ChonkerInt {
+        if bits < 3 {
+            panic!("requested bit length for random safe prime generation is below 3, there are no such safe primes (ChonkerInt::new_safe_prime)");
+        }
+
+        loop {
+            // Ensure that q is odd by setting the least significant bit, p = 2q + 1 has the requested bit length.
+            let mut bytes = random_bytes_with_bit_length(bits - 1, rng);
+            let last_byte = bytes.len() - 1;
+            bytes[last_byte] |= 1;
+            let candidate_q = ChonkerInt::from_bytes_be(&bytes);
+            let candidate_p = &(&candidate_q * 2u64) + 1u64;
+
+            if candidate_q.is_safe_prime_candidate()
+                && candidate_q.is_prime_probabilistic_with_rng(Some(1), rng)
+                && candidate_p.is_prime_probabilistic_with_rng(Some(1), rng)
+                && candidate_q.is_prime_probabilistic_with_rng(Some(5), rng)
+                && candidate_p.is_prime_probabilistic_with_rng(Some(5), rng)
+            {
+                return candidate_p;
+            }
+        }
+    }
+
+    // Check with the trial division by the small odd primes, if the odd number q and 2q + 1 can both be primes:
+    // q must not be divisible by a small prime r and 2q + 1 is divisible by r when q mod r == (r - 1) / 2.
+    // The small primes themselves are not rejected. Both remainders come from a single division of q.
+    fn is_safe_prime_candidate(&self) -> bool {
+        let candidate_p = &(self * 2u64) + 1u64;
+
+        for small_prime in SMALL_ODD_PRIMES.iter() {
+            let remainder = self % *small_prime;
+
+            if (remainder == 0 && *self != *small_prime)
+                || (remainder == (small_prime - 1) / 2 && candidate_p != *small_prime)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    // Check with the trial division by 2 and the table of the small odd primes, if the number has a small factor.
+    // The small primes themselves have no smaller factors and are not reported, works only with the natural numbers.
+    // The check costs a single word remainder per prime, it is run before the Miller-Rabin test to skip its exponentiations.
+    pub fn divisible_by_small_prime(&self) -> bool {
+        if self.is_even() {
+            return *self != 2u64;
+        }
+
+        SMALL_ODD_PRIMES
+            .iter()
+            .any(|small_prime| self % *small_prime == 0 && *self != *small_prime)
+    }
+
+    // Generate a primitive root to the modulo of the safe prime p = 2q + 1 with the provided random number generator.
+    // The factorization of p - 1 = 2q is known, thus a number g from the range 2 - (p - 2) is a primitive root,
+    // if g^q != 1 (modulo p), the check g^2 != 1 (modulo p) always holds in this range.
+    pub fn new_safe_prime_primitive_root<R: Rng + ?Sized>(&self, rng: &mut R) -> ChonkerInt {
+        // Check if the target is a safe prime.
+        let mut prime_q = self - 1u64;
+        prime_q.halve_in_place();
+        if !self.is_prime_probabilistic_with_rng(Some(5), rng) || !prime_q.is_prime_probabilistic_with_rng(Some(5), rng) {
+            panic!("a safe prime is required for the primitive root generation (ChonkerInt::new_safe_prime_primitive_root)");
+        }
+
+        loop {
+            let candidate = ChonkerInt::new_rand_range_value_with_rng(
+                &ChonkerInt::from(2),
+                &(self - 2u64),
+                &BigIntSign::Positive,
+                rng,
+            );
+
+            if !candidate.modpow(&prime_q, self).is_one() {
+                return candidate;
+            }
+        }
     }
 
     // Generate a coprime to the number.
     pub fn new_coprime(&self) -> ChonkerInt {
-        let big_zero = ChonkerInt::new();
+        self.new_coprime_with_rng(&mut rand::thread_rng())
+    }
 
+    // Generate a coprime to the number with the provided random number generator.
+    pub fn new_coprime_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> ChonkerInt {
         // Check the target for being a zero.
-        if *self == big_zero {
+        if self.is_zero() {
             panic!(
                 "a target number for coprime generation cannot be zero (ChonkerInt::new_coprime)"
             )
@@ -73,10 +346,11 @@ impl ChonkerInt {
         // Generate a random candidate with length from the range 1 - self,
         // and check it is a comprime number to the target.
         loop {
-            candidate = ChonkerInt::new_rand_range_len(
+            candidate = ChonkerInt::new_rand_range_len_with_rng(
                 &1,
-                &(self.digits.len() as u64),
+                &(self.digit_count() as u64),
                 &BigIntSign::Positive,
+                rng,
             );
             if candidate.is_coprime(self) {
                 break;
@@ -88,17 +362,21 @@ impl ChonkerInt {
 
     // Generate a primitive root to the modulo of prime n
     pub fn new_primitive_root(&self) -> ChonkerInt {
-        let big_one = ChonkerInt::from(1);
+        self.new_primitive_root_with_rng(&mut rand::thread_rng())
+    }
+
+    // Generate a primitive root to the modulo of prime n with the provided random number generator.
+    pub fn new_primitive_root_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> ChonkerInt {
         let big_two = ChonkerInt::from(2);
 
         // Check if the target is a prime number.
-        if !self.is_prime_probabilistic(Some(2)) {
+        if !self.is_prime_probabilistic_with_rng(Some(2), rng) {
             panic!("a prime number is required for primitive root generation (ChonkerInt::new_primitive_root)");
         }
 
         // Find value of Euler Totient function of n. Since n is a prime number, the value of Euler
         // Totient function is n-1 as there are n-1 relatively prime numbers.
-        let target_one = self - &big_one;
+        let target_one = self - 1u64;
 
         // Calculate factors of the prime-1 and determine exponents for the testing
         // from (prime-1 / factor) calculation.
@@ -108,17 +386,21 @@ impl ChonkerInt {
 
         // Pick a random number from the suitable range, check if it is a primitive root.
         'outer: loop {
-            candidate =
-                ChonkerInt::new_rand_range_value(&big_two, &target_one, &BigIntSign::Positive);
+            candidate = ChonkerInt::new_rand_range_value_with_rng(
+                &big_two,
+                &target_one,
+                &BigIntSign::Positive,
+                rng,
+            );
 
             // Check x^(p-1) = 1 (modulo p), if the result does not equal 1, restart the search.
-            if candidate.modpow(&target_one, self) != big_one {
+            if !candidate.modpow(&target_one, self).is_one() {
                 continue;
             }
 
             // Check x^((p-1)/f) (modulo p), if any factor results in 1, restart the search.
             for exponent in prime_factor_list.iter() {
-                if candidate.modpow(&(&target_one / exponent), self) == big_one {
+                if candidate.modpow(&(&target_one / exponent), self).is_one() {
                     continue 'outer;
                 }
             }
@@ -132,15 +414,11 @@ impl ChonkerInt {
     // Time complexity is O(sqrt(N)), check of the even numbers is skipped.
     pub fn is_prime(&self) -> bool {
         // Return false if the BigInt is negative, zero or one.
-        if (*self == ChonkerInt::from(1))
-            || (*self == ChonkerInt::new() || self.sign == BigIntSign::Zero)
-            || (self.sign == BigIntSign::Negative)
-        {
+        if self.is_one() || self.is_zero() || self.is_negative() {
             return false;
         }
 
         let mut factor = ChonkerInt::from(5);
-        let big_zero = ChonkerInt::new();
         let big_two = ChonkerInt::from(2);
         let big_three = ChonkerInt::from(3);
         let big_six = ChonkerInt::from(6);
@@ -152,16 +430,17 @@ impl ChonkerInt {
 
         // Check if the target is even, divisible by even numbers,
         // or if it is divisible by 3.
-        if (self % &big_two == big_zero) || (self % &big_three == big_zero) {
+        if self.is_even() || (self % 3u64 == 0) {
             return false;
         }
 
         // Loop will cover odd values from 3 to sqrt(self)
         // Equality is allowed for the cases, such as 4 * 4 = 16,
         // when the target number may have a doubled factor.
-        while (&factor * &factor) <= (*self) {
+        let square_root = self.sqrt();
+        while factor <= square_root {
             // Check if the factor divides self without leaving a remainder.
-            if (self % &factor == big_zero) || (self % &(&factor + &big_two) == big_zero) {
+            if (self % &factor).is_zero() || (self % &(&factor + &big_two)).is_zero() {
                 // This means that self has an odd factor in between 3 and sqrt(self),
                 // so it is not a prime number.
                 return false;
@@ -177,89 +456,366 @@ impl ChonkerInt {
     // Running complexity is O(k log3n).
     // More information: https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test
     pub fn is_prime_probabilistic(&self, number_of_trials: Option<u64>) -> bool {
+        self.is_prime_probabilistic_with_rng(number_of_trials, &mut rand::thread_rng())
+    }
+
+    // Miller - Rabin primality test with the bases drawn from the provided random number generator.
+    // Targets with small factors are rejected by the trial division before any exponentiation.
+    // Targets below the bound of the deterministic test are checked with its fixed witnesses instead of the random bases.
+    pub fn is_prime_probabilistic_with_rng<R: Rng + ?Sized>(&self, number_of_trials: Option<u64>, rng: &mut R) -> bool {
+        if let Some(is_prime) = self.is_prime_deterministic_small() {
+            return is_prime;
+        }
+
         // A number of tests to run.
         let number_of_trials = number_of_trials.unwrap_or(40);
         let target_original = (*self).clone();
+        let big_two = ChonkerInt::from(2);
+
+        // Check if the target is divisible by 2 or by a small odd prime.
+        if self.divisible_by_small_prime() {
+            return false;
+        }
+
+        // 2^s * d + 1 = n , d - odd; d = (n - 1) / 2^s
+        let target_one = &target_original - 1u64;
+        let (d, s) = self.miller_rabin_decomposition();
+
+        // Squarings of the trial result are reduced by the same modulus, compute its reciprocal once.
+        let reducer = BarrettReducer::new(&target_original);
+
+        // Testing loop/witness loop.
+        for _iteration in 0..number_of_trials {
+            // Generate a random base, a possible witness or a liar, from the range 2 - (self - 2)
+            let base = ChonkerInt::new_rand_range_value_with_rng(
+                &big_two,
+                &(&target_original - &big_two),
+                &BigIntSign::Positive,
+                rng,
+            );
+
+            if !self.is_strong_probable_prime(&base, &d, s, &target_one, &reducer) {
+                return false;
+            }
+        }
 
+        true
+    }
+
+    // Miller - Rabin primality test with the trials distributed across the requested amount of the scoped threads.
+    // Workers stop as soon as any of them finds a witness of compositeness, the trials are independent.
+    // Targets below the bound of the deterministic test are checked with its fixed witnesses without the threads.
+    pub fn is_prime_probabilistic_parallel(&self, number_of_trials: Option<u64>, thread_count: usize) -> bool {
+        self.miller_rabin_parallel(number_of_trials.unwrap_or(40), thread_count).0
+    }
+
+    // Run the trials of the parallel Miller - Rabin test, return the outcome and the amount of the trials done.
+    fn miller_rabin_parallel(&self, number_of_trials: u64, thread_count: usize) -> (bool, u64) {
+        if thread_count == 0 {
+            panic!("requested thread count for the primality test is 0, at least one thread is required (ChonkerInt::is_prime_probabilistic_parallel)");
+        }
+
+        if let Some(is_prime) = self.is_prime_deterministic_small() {
+            return (is_prime, 0);
+        }
+
+        // Check if the target is divisible by 2 or by a small odd prime.
+        if self.divisible_by_small_prime() {
+            return (false, 0);
+        }
+
+        // There are no more workers than trials, the trials are spread evenly among the workers.
+        let worker_count = (thread_count as u64).min(number_of_trials);
+        if worker_count == 0 {
+            return (true, 0);
+        }
+
+        // 2^s * d + 1 = n , d - odd; d = (n - 1) / 2^s
+        let (d, s) = self.miller_rabin_decomposition();
+        let target_one = self - 1u64;
+        let reducer = BarrettReducer::new(self);
+        let composite_found = AtomicBool::new(false);
+        let trials_done = AtomicU64::new(0);
+
+        // The scope waits for all of the workers to finish their trials, thus the workers borrow the target and the flags.
+        thread::scope(|scope| {
+            for worker in 0..worker_count {
+                let worker_trials = number_of_trials / worker_count + u64::from(worker < number_of_trials % worker_count);
+                let (d, target_one, reducer) = (&d, &target_one, &reducer);
+                let (composite_found, trials_done) = (&composite_found, &trials_done);
+
+                scope.spawn(move || {
+                    let mut rng = rand::thread_rng();
+
+                    for _iteration in 0..worker_trials {
+                        // Another worker has already proven, that the target is a composite number.
+                        if composite_found.load(Ordering::SeqCst) {
+                            break;
+                        }
+
+                        // Generate a random base, a possible witness or a liar, from the range 2 - (self - 2)
+                        let base = ChonkerInt::new_rand_range_value_with_rng(
+                            &ChonkerInt::from(2),
+                            &(self - 2u64),
+                            &BigIntSign::Positive,
+                            &mut rng,
+                        );
+
+                        trials_done.fetch_add(1, Ordering::SeqCst);
+                        if !self.is_strong_probable_prime(&base, d, s, target_one, reducer) {
+                            composite_found.store(true, Ordering::SeqCst);
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        (!composite_found.load(Ordering::SeqCst), trials_done.load(Ordering::SeqCst))
+    }
+
+    // Baillie - PSW primality test: a strong Miller - Rabin trial with the base 2 and a strong Lucas test.
+    // There are no known composites passing both of the tests, the outcome does not depend on the random bases.
+    // Targets with small factors are rejected by the trial division before the probabilistic tests.
+    // More information: https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test
+    pub fn is_prime_baillie_psw(&self) -> bool {
         // Return false if the BigInt is negative, zero or one.
-        if (*self == ChonkerInt::from(1))
-            || (*self == ChonkerInt::new() || self.sign == BigIntSign::Zero)
-            || (self.sign == BigIntSign::Negative)
-        {
+        if self.is_one() || self.is_zero() || self.is_negative() {
             return false;
         }
 
-        let big_zero = ChonkerInt::new();
-        let big_one = ChonkerInt::from(1);
-        let big_two = ChonkerInt::from(2);
-        let big_three = ChonkerInt::from(3);
+        // Check if the target is divisible by 2 or by a small odd prime, the small primes themselves are not rejected.
+        if self.divisible_by_small_prime() {
+            return false;
+        }
 
-        // Check if the target number is 2 or 3, which are primes.
-        if (*self == big_two) || (*self == big_three) {
+        // The target has no factors up to the largest small prime, it is a prime if it is below its square.
+        if *self < LARGEST_SMALL_PRIME * LARGEST_SMALL_PRIME {
             return true;
         }
 
-        // Check if the target is even, divisible by even numbers,
-        // or if it is divisible by 3.
-        if (self % &big_two == big_zero) || (self % &big_three == big_zero) {
+        self.baillie_psw_trials()
+    }
+
+    // Run the trials of the Baillie - PSW test without the trial division, works only with the odd numbers above 2.
+    fn baillie_psw_trials(&self) -> bool {
+        let target_one = self - 1u64;
+        let (d, s) = self.miller_rabin_decomposition();
+        let reducer = BarrettReducer::new(self);
+
+        self.is_strong_probable_prime(&ChonkerInt::from(2), &d, s, &target_one, &reducer)
+            && self.is_strong_lucas_probable_prime()
+    }
+
+    // Strong Lucas probable prime test with the parameters chosen by the Selfridge's method A:
+    // D is the first of 5, -7, 9, -11, ... with the Jacobi symbol (D / n) == -1, P = 1 and Q = (1 - D) / 4.
+    // With n + 1 = 2^s * d, d - odd, the target is a strong Lucas probable prime,
+    // if U_d == 0 (modulo n) or V_(d * 2^r) == 0 (modulo n) for some 0 <= r < s. Works only with the odd numbers above 2.
+    // More information: https://en.wikipedia.org/wiki/Lucas_pseudoprime#Strong_Lucas_pseudoprimes
+    fn is_strong_lucas_probable_prime(&self) -> bool {
+        // The Jacobi symbol (D / n) is never -1 for the perfect squares, reject them before the search of D.
+        let square_root = self.sqrt();
+        if &square_root * &square_root == *self {
             return false;
         }
 
-        // 2^s * d + 1 = n , d - odd; d = (n - 1) / 2^s
-        let target_one = &target_original - &big_one;
-        let mut d = target_one.clone();
-        let mut s = ChonkerInt::new();
+        // Search for D, a common factor of D and the target means that the target is a composite number,
+        // unless the target is the factor itself.
+        let mut d_parameter: i64 = 5;
+        loop {
+            let big_d_parameter = ChonkerInt::from(d_parameter);
+            match big_d_parameter.jacobi(self) {
+                -1 => break,
+                0 if *self != big_d_parameter.abs() => return false,
+                _ => {}
+            }
 
-        while &d % &big_two == big_one {
-            d = &d / &big_two;
-            s = &s + &big_one;
+            d_parameter = if d_parameter > 0 { -(d_parameter + 2) } else { -d_parameter + 2 };
         }
 
-        let mut base;
-        let mut trial_result;
+        // Parameters are taken modulo the target, the negative ones are reduced to the range 0 - (self - 1).
+        let reducer = BarrettReducer::new(self);
+        let q_parameter = reducer.reduce(&ChonkerInt::from((1 - d_parameter) / 4));
+        let d_parameter = reducer.reduce(&ChonkerInt::from(d_parameter));
+
+        // n + 1 = 2^s * d, d - odd
+        let mut d = self + 1u64;
+        let mut s = 0;
+        while d.is_even() {
+            d.halve_in_place();
+            s += 1;
+        }
 
-        // Testing loop/witness loop.
-        'outer: for _iteration in 0..number_of_trials {
-            // Generate a random base, a possible witness or a liar, from the range 2 - (self - 2)
-            base = ChonkerInt::new_rand_range_value(
-                &big_two,
-                &(&target_original - &big_two),
-                &BigIntSign::Positive,
-            );
+        // Compute U_d, V_d and Q^d with the binary method over the bits of d, starting from U_1 = 1, V_1 = P = 1, Q^1.
+        // Doubling: U_2k = U_k * V_k, V_2k = V_k^2 - 2 * Q^k.
+        // Increment with P = 1: U_(k+1) = (U_k + V_k) / 2, V_(k+1) = (D * U_k + V_k) / 2.
+        let mut u = ChonkerInt::from(1);
+        let mut v = ChonkerInt::from(1);
+        let mut q_power = q_parameter.clone();
+        let bytes = d.to_bytes_be();
+        let bit_count = d.bit_length();
+
+        for bit_index in (0..bit_count - 1).rev() {
+            u = reducer.reduce(&(&u * &v));
+            v = reducer.reduce(&(&(&v * &v) - &(&q_power * 2u64)));
+            q_power = reducer.reduce(&(&q_power * &q_power));
+
+            let byte = bytes[bytes.len() - 1 - (bit_index / 8) as usize];
+            if (byte >> (bit_index % 8)) & 1 == 1 {
+                let next_u = self.halve_modulo(&reducer.reduce(&(&u + &v)));
+                v = self.halve_modulo(&reducer.reduce(&(&(&d_parameter * &u) + &v)));
+                u = next_u;
+                q_power = reducer.reduce(&(&q_power * &q_parameter));
+            }
+        }
 
-            trial_result = base.modpow(&d, &target_original);
+        if u.is_zero() || v.is_zero() {
+            return true;
+        }
 
-            // Check the trial result, if it is equals 1 or (self - 1), proceed to the next trials,
-            // otherwise continue the current one.
-            if (trial_result == big_one) || (trial_result == target_one) {
-                continue;
+        // V_(d * 2^r) for 0 < r < s.
+        for _doubling in 1..s {
+            v = reducer.reduce(&(&(&v * &v) - &(&q_power * 2u64)));
+            q_power = reducer.reduce(&(&q_power * &q_power));
+
+            if v.is_zero() {
+                return true;
             }
+        }
 
-            let mut s_clone = s.clone();
+        false
+    }
 
-            // Increase the base, take a power of 2 of the base, while decreasing the s exponent by one
-            // and take modulus of the original target.
-            // If the calculation result equals (self - 1), proceed to the next trial,
-            // otherwise the target is a composite number.
-            while s_clone > big_zero {
-                trial_result = trial_result.modpow(&big_two, &target_original);
+    // Divide the value from the range 0 - (self - 1) by 2 modulo the odd target: odd values are shifted by the target first.
+    fn halve_modulo(&self, value: &ChonkerInt) -> ChonkerInt {
+        let mut result = if value.is_odd() { value + self } else { value.clone() };
+        result.halve_in_place();
 
-                if trial_result == target_one {
-                    continue 'outer;
+        result
+    }
+
+    // Calculate the Jacobi symbol (self / n) for the odd positive n, the result is -1, 0 or 1.
+    // It is accomplished with the law of quadratic reciprocity, similar to the Euclidean algorithm.
+    // More about the idea: https://en.wikipedia.org/wiki/Jacobi_symbol#Calculating_the_Jacobi_symbol
+    pub fn jacobi(&self, n: &ChonkerInt) -> i8 {
+        if !n.is_positive() || n.is_even() {
+            panic!("the Jacobi symbol is defined only for the odd positive denominators (ChonkerInt::jacobi)");
+        }
+
+        let mut a = self.rem_euclid(n);
+        let mut n = n.clone();
+        let mut result = 1;
+
+        while !a.is_zero() {
+            // (2 / n) == -1, when n == 3 or 5 (modulo 8).
+            while a.is_even() {
+                a.halve_in_place();
+                let remainder = &n % 8u64;
+                if remainder == 3 || remainder == 5 {
+                    result = -result;
                 }
+            }
 
-                s_clone = &s_clone - &big_one;
+            // (a / n) == -(n / a), when both of them are 3 (modulo 4), otherwise they are equal.
+            std::mem::swap(&mut a, &mut n);
+            if &a % 4u64 == 3 && &n % 4u64 == 3 {
+                result = -result;
             }
+            a = &a % &n;
+        }
 
-            return false;
+        if n.is_one() {
+            result
+        } else {
+            0
         }
+    }
 
-        true
+    // Deterministic Miller - Rabin primality test for the targets below 3.3 * 10^24.
+    // The first 13 primes, 2 - 41, as the witnesses leave no strong pseudoprimes below the bound, so the outcome is guaranteed.
+    // Returns None for the targets above the bound, works only with the natural numbers, the others are not primes.
+    // More information: https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test#Testing_against_small_sets_of_bases
+    pub fn is_prime_deterministic_small(&self) -> Option<bool> {
+        // Return false if the BigInt is negative, zero or one.
+        if self.is_one() || self.is_zero() || self.is_negative() {
+            return Some(false);
+        }
+
+        if *self >= ChonkerInt::from(DETERMINISTIC_WITNESSES_BOUND) {
+            return None;
+        }
+
+        // Check if the target is divisible by 2 or by a small odd prime, the small primes themselves are not rejected.
+        if self.divisible_by_small_prime() {
+            return Some(false);
+        }
+
+        // The target has no factors up to the largest small prime, it is a prime if it is below its square.
+        if *self < LARGEST_SMALL_PRIME * LARGEST_SMALL_PRIME {
+            return Some(true);
+        }
+
+        // 2^s * d + 1 = n , d - odd; d = (n - 1) / 2^s
+        let target_one = self - 1u64;
+        let (d, s) = self.miller_rabin_decomposition();
+        let reducer = BarrettReducer::new(self);
+
+        Some(
+            DETERMINISTIC_WITNESSES
+                .iter()
+                .all(|witness| self.is_strong_probable_prime(&ChonkerInt::from(*witness), &d, s, &target_one, &reducer)),
+        )
+    }
+
+    // Decompose the odd target n as 2^s * d + 1, where d is odd, for the Miller - Rabin test.
+    fn miller_rabin_decomposition(&self) -> (ChonkerInt, u64) {
+        let mut d = self - 1u64;
+        let mut s = 0;
+
+        while d.is_even() {
+            d.halve_in_place();
+            s += 1;
+        }
+
+        (d, s)
+    }
+
+    // Single trial of the Miller - Rabin test with the provided base, the target is n = 2^s * d + 1.
+    // Returns true, when the target is a strong probable prime to the base, false when the base is a witness of compositeness.
+    fn is_strong_probable_prime(
+        &self,
+        base: &ChonkerInt,
+        d: &ChonkerInt,
+        s: u64,
+        target_one: &ChonkerInt,
+        reducer: &BarrettReducer,
+    ) -> bool {
+        let mut trial_result = base.modpow(d, self);
+
+        // Check the trial result, if it is equals 1 or (self - 1), the base is not a witness,
+        // otherwise continue the trial.
+        if trial_result.is_one() || (trial_result == *target_one) {
+            return true;
+        }
+
+        // Take a power of 2 of the trial result s - 1 times and take modulus of the original target.
+        // If the calculation result equals (self - 1), the base is not a witness,
+        // otherwise the target is a composite number.
+        for _squaring in 1..s {
+            trial_result.square_in_place();
+            trial_result = reducer.reduce(&trial_result);
+
+            if trial_result == *target_one {
+                return true;
+            }
+        }
+
+        false
     }
 
     // Check if the target is a coprime BigInt to another target BigInt.
     pub fn is_coprime(&self, other: &ChonkerInt) -> bool {
-        if self.gcd(other) != ChonkerInt::from(1) {
+        if !self.gcd(other).is_one() {
             return false;
         }
 
@@ -270,10 +826,8 @@ impl ChonkerInt {
     // Returns true, when the number is a primitive root, false otherwise.
     // Time complexity is O(sqrt(N)), check of the even numbers is skipped.
     pub fn is_primitive_root(&self, prime: &ChonkerInt) -> bool {
-        let big_one = ChonkerInt::from(1);
-
         // Check if the primitive root is negative or zero.
-        if self.sign == BigIntSign::Negative || self.sign == BigIntSign::Zero {
+        if !self.is_positive() {
             panic!("a positive primitive root is required for the primitive root validity check (ChonkerInt::is_primitive_root)");
         }
 
@@ -284,7 +838,7 @@ impl ChonkerInt {
 
         // Find value of Euler Totient function of n. Since n is a prime number, the value of Euler
         // Totient function is n-1 as there are n-1 relatively prime numbers.
-        let target_one = prime - &big_one;
+        let target_one = prime - 1u64;
 
         // Calculate factors of the prime-1 and determine exponents for the testing
         // from (prime-1 / factor) calculation.
@@ -292,13 +846,13 @@ impl ChonkerInt {
         prime_factor_list.dedup();
 
         // Check x^(p-1) = 1 (modulo p), if the result does not equal 1, return false.
-        if self.modpow(&target_one, prime) != big_one {
+        if !self.modpow(&target_one, prime).is_one() {
             return false;
         }
 
         // Check x^((p-1)/f) (modulo p), if any factor results in 1, return false.
         for exponent in prime_factor_list.iter() {
-            if self.modpow(&(&target_one / exponent), prime) == big_one {
+            if self.modpow(&(&target_one / exponent), prime).is_one() {
                 return false;
             }
         }
@@ -310,7 +864,410 @@ impl ChonkerInt {
 // Test module.
 #[cfg(test)]
 mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::logic::bigint::barrett::BarrettReducer;
     use crate::logic::bigint::{BigIntSign, ChonkerInt};
+    use crate::logic::cancellation::CancellationToken;
+
+    // Test construction of random primes, coprimes and primitive roots with a seeded random number generator,
+    // the same seed reproduces the same BigInts.
+    #[test]
+    fn test_random_prime_bigint_seeded_construction() {
+        let prime = ChonkerInt::new_prime_with_rng(&30, &mut StdRng::seed_from_u64(11));
+        assert_eq!(prime, ChonkerInt::new_prime_with_rng(&30, &mut StdRng::seed_from_u64(11)));
+        assert!(prime.is_prime_probabilistic(None));
+        assert_eq!(prime.digit_count(), 30);
+
+        let target = ChonkerInt::from(2 * 3 * 5 * 7 * 11 * 13 * 17 * 19u64);
+        let coprime = target.new_coprime_with_rng(&mut StdRng::seed_from_u64(12));
+        assert_eq!(coprime, target.new_coprime_with_rng(&mut StdRng::seed_from_u64(12)));
+        assert!(coprime.is_coprime(&target));
+
+        let small_prime = ChonkerInt::from(1_000_003);
+        let root = small_prime.new_primitive_root_with_rng(&mut StdRng::seed_from_u64(13));
+        assert_eq!(root, small_prime.new_primitive_root_with_rng(&mut StdRng::seed_from_u64(13)));
+        assert!(root.is_primitive_root(&small_prime));
+    }
+
+    // Test construction of random primes by the bit length.
+    #[test]
+    fn test_random_prime_bigint_bits_construction() {
+        let mut rng = StdRng::seed_from_u64(128);
+
+        for bits in [2u64, 3, 4, 8, 17, 32, 64, 100, 128] {
+            for _ in 0..3 {
+                let prime = ChonkerInt::new_prime_bits(bits, &mut rng);
+                assert_eq!(prime.bit_length(), bits);
+                assert!(prime.is_prime_probabilistic(None));
+            }
+        }
+
+        // Small primes are checked deterministically.
+        for bits in 2..=20 {
+            assert!(ChonkerInt::new_prime_bits(bits, &mut rng).is_prime());
+        }
+    }
+
+    // Test the cancellation of the random prime search, a token, which is never cancelled, changes nothing.
+    #[test]
+    fn test_random_prime_bigint_cancelled_construction() {
+        let cancellation = CancellationToken::new();
+        let prime = ChonkerInt::new_prime_with_rng_until(&30, &mut StdRng::seed_from_u64(11), &cancellation).unwrap();
+        assert_eq!(prime, ChonkerInt::new_prime_with_rng(&30, &mut StdRng::seed_from_u64(11)));
+        let prime = ChonkerInt::new_prime_bits_until(128, &mut StdRng::seed_from_u64(12), &cancellation).unwrap();
+        assert_eq!(prime, ChonkerInt::new_prime_bits(128, &mut StdRng::seed_from_u64(12)));
+        assert!(ChonkerInt::new_prime_until(&20, &cancellation).unwrap().is_prime_probabilistic(None));
+
+        // A cancelled token stops the search before the first candidate.
+        cancellation.cancel();
+        assert!(ChonkerInt::new_prime_until(&300, &cancellation).unwrap_err().get_cancelled_flag());
+        assert!(ChonkerInt::new_prime_bits_until(1024, &mut StdRng::seed_from_u64(13), &cancellation).unwrap_err().get_cancelled_flag());
+    }
+
+    // Test the parallel search of random primes by the bit length.
+    #[test]
+    fn test_random_prime_bigint_parallel_construction() {
+        for bits in [2u64, 3, 64, 160] {
+            let (prime, running_workers) = ChonkerInt::prime_search_parallel(bits, 4, None);
+            assert!(prime.is_prime_probabilistic(None));
+            assert_eq!(prime.bit_length(), bits);
+            assert_eq!(running_workers, 0);
+        }
+
+        // A seeded run with a single thread is reproducible, it matches the sequential search with the same seed.
+        let prime = ChonkerInt::new_prime_parallel(128, 1, Some(49));
+        assert_eq!(prime, ChonkerInt::new_prime_parallel(128, 1, Some(49)));
+        assert_eq!(prime, ChonkerInt::new_prime_bits(128, &mut StdRng::seed_from_u64(49)));
+        assert_eq!(prime.bit_length(), 128);
+
+        // More threads, than the cores.
+        let (prime, running_workers) = ChonkerInt::prime_search_parallel(96, 32, Some(50));
+        assert_eq!(prime.bit_length(), 96);
+        assert_eq!(running_workers, 0);
+    }
+
+    // Test the parallel search of random primes with zero threads.
+    #[test]
+    #[should_panic]
+    fn test_random_prime_bigint_parallel_zero_threads() {
+        let _ = ChonkerInt::new_prime_parallel(64, 0, None);
+    }
+
+    // Test construction of random safe primes p = 2q + 1 and their primitive roots, including a 128 bit safe prime.
+    #[test]
+    fn test_random_safe_prime_bigint_construction() {
+        let mut rng = StdRng::seed_from_u64(1024);
+
+        for bits in [3u64, 4, 5, 8, 16, 32, 64, 128] {
+            let safe_prime = ChonkerInt::new_safe_prime(bits, &mut rng);
+            let prime_q = &(&safe_prime - 1u64) / &ChonkerInt::from(2);
+
+            assert_eq!(safe_prime.bit_length(), bits);
+            assert!(safe_prime.is_prime_probabilistic(None));
+            assert!(prime_q.is_prime_probabilistic(None) || prime_q == 2u64);
+
+            // Small safe primes are checked deterministically, including their primitive roots.
+            let root = safe_prime.new_safe_prime_primitive_root(&mut rng);
+            if bits <= 16 {
+                assert!(safe_prime.is_prime() && prime_q.is_prime());
+                assert!(root.is_primitive_root(&safe_prime));
+            }
+            assert!(!root.modpow(&prime_q, &safe_prime).is_one());
+        }
+    }
+
+    // Test the trial division filter of the safe prime candidates.
+    #[test]
+    fn test_safe_prime_candidate_filter() {
+        // Sophie Germain primes: 2q + 1 is a prime too, including the small primes of the filter.
+        for prime_q in [3u64, 5, 11, 23, 29, 41, 53, 83, 89, 113, 131, 173, 179, 191, 233, 239, 251, 281, 293] {
+            assert!(ChonkerInt::from(prime_q).is_safe_prime_candidate(), "{}", prime_q);
+        }
+
+        // Either q or 2q + 1 has a small factor.
+        for candidate in [7u64, 9, 13, 15, 17, 19, 21, 255, 257, 263] {
+            assert!(!ChonkerInt::from(candidate).is_safe_prime_candidate(), "{}", candidate);
+        }
+    }
+
+    // Test the trial division by the small primes, which runs before the Miller-Rabin test.
+    #[test]
+    fn test_bigint_divisible_by_small_prime() {
+        // Table is complete and ends with the largest small prime.
+        assert!(super::SMALL_ODD_PRIMES.iter().all(|prime| ChonkerInt::from(*prime).is_prime()));
+        assert!(super::SMALL_ODD_PRIMES.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(super::LARGEST_SMALL_PRIME, 1993);
+
+        // Known primes pass the filter, including the small primes themselves and primes beyond the table.
+        for prime in ["2", "3", "1993", "1997", "2147483647", "2305843009213693951", "170141183460469231731687303715884105727"] {
+            let prime = ChonkerInt::from(String::from(prime));
+            assert!(!prime.divisible_by_small_prime(), "{}", prime);
+            assert!(prime.is_prime_probabilistic(None), "{}", prime);
+        }
+
+        // Smooth composites are rejected by the filter alone.
+        for composite in [
+            "4",
+            "9",
+            "3972049",
+            "1993000000000000000000000000001993",
+            "45258345737283182017089090305074280268250019885459315445980274088749695418815233072251658086315728724002838134765625",
+        ] {
+            let composite = ChonkerInt::from(String::from(composite));
+            assert!(composite.divisible_by_small_prime(), "{}", composite);
+            assert!(!composite.is_prime_probabilistic(Some(1)), "{}", composite);
+        }
+
+        // Composites without small factors are left to the Miller-Rabin test.
+        let composite = ChonkerInt::from(1997u64 * 1999);
+        assert!(!composite.divisible_by_small_prime());
+        assert!(!composite.is_prime_probabilistic(None));
+
+        // Count the Miller-Rabin invocations saved by the filter on the random odd 256 bit candidates of a fixed seed.
+        let mut rng = StdRng::seed_from_u64(41);
+        let candidates = 1000;
+        let mut tested = 0;
+        for _ in 0..candidates {
+            let candidate = &(&ChonkerInt::new_rand_bits(255, &BigIntSign::Positive, &mut rng) * 2u64) + 1u64;
+            if !candidate.divisible_by_small_prime() {
+                tested += 1;
+            }
+        }
+        println!("Miller-Rabin invocations with the trial division filter: {} of {} candidates", tested, candidates);
+        // About 15% of the odd numbers have no odd prime factors up to 1993.
+        assert!(tested < candidates / 4);
+    }
+
+    // Test the deterministic Miller-Rabin test against the sieve of Eratosthenes and the Carmichael numbers below 100 000.
+    #[test]
+    fn test_bigint_is_prime_deterministic_small() {
+        let limit = 100_000;
+        let mut sieve = vec![true; limit];
+        sieve[0] = false;
+        sieve[1] = false;
+        for number in 2..limit {
+            if sieve[number] {
+                for multiple in (number * number..limit).step_by(number) {
+                    sieve[multiple] = false;
+                }
+            }
+        }
+
+        for (number, is_prime) in sieve.iter().enumerate() {
+            assert_eq!(ChonkerInt::from(number as u64).is_prime_deterministic_small(), Some(*is_prime), "{}", number);
+        }
+
+        // Carmichael numbers are Fermat pseudoprimes to all of the coprime bases.
+        for carmichael in [
+            561u64, 1105, 1729, 2465, 2821, 6601, 8911, 10585, 15841, 29341, 41041, 46657, 52633, 62745, 63973, 75361,
+        ] {
+            assert_eq!(ChonkerInt::from(carmichael).is_prime_deterministic_small(), Some(false), "{}", carmichael);
+        }
+
+        assert_eq!(ChonkerInt::from(-7).is_prime_deterministic_small(), Some(false));
+    }
+
+    // Test the deterministic Miller-Rabin test with the smallest strong pseudoprimes to the first primes as the bases.
+    #[test]
+    fn test_bigint_strong_pseudoprimes() {
+        // Smallest strong pseudoprimes to the bases 2, to 2 and 3, ..., to the first 12 primes,
+        // some of them are the smallest ones for several sets of the bases.
+        let strong_pseudoprimes = [
+            (1, "2047"),
+            (2, "1373653"),
+            (3, "25326001"),
+            (4, "3215031751"),
+            (5, "2152302898747"),
+            (6, "3474749660383"),
+            (8, "341550071728321"),
+            (11, "3825123056546413051"),
+            (12, "318665857834031151167461"),
+        ];
+
+        for (witness_count, pseudoprime) in strong_pseudoprimes.iter() {
+            let pseudoprime = ChonkerInt::from(String::from(*pseudoprime));
+            let target_one = &pseudoprime - 1u64;
+            let (d, s) = pseudoprime.miller_rabin_decomposition();
+            let reducer = BarrettReducer::new(&pseudoprime);
+
+            // All of the smaller bases are liars, the next prime is a witness.
+            for (index, witness) in super::DETERMINISTIC_WITNESSES.iter().enumerate() {
+                let is_liar = pseudoprime.is_strong_probable_prime(&ChonkerInt::from(*witness), &d, s, &target_one, &reducer);
+                if index < *witness_count {
+                    assert!(is_liar, "{} {}", pseudoprime, witness);
+                } else if index == *witness_count {
+                    assert!(!is_liar, "{} {}", pseudoprime, witness);
+                }
+            }
+
+            assert_eq!(pseudoprime.is_prime_deterministic_small(), Some(false), "{}", pseudoprime);
+            assert!(!pseudoprime.is_prime_probabilistic(None), "{}", pseudoprime);
+        }
+
+        // Smallest strong pseudoprime to the first 13 primes is the bound of the deterministic test.
+        let bound = ChonkerInt::from(String::from("3317044064679887385961981"));
+        assert_eq!(bound.is_prime_deterministic_small(), None);
+        assert_eq!((&bound - 2u64).is_prime_deterministic_small(), Some(false));
+        assert!(!bound.is_prime_probabilistic(None));
+
+        // Largest prime below the bound and a product of two primes without small factors close to it.
+        assert_eq!(ChonkerInt::from(String::from("3317044064679887385961813")).is_prime_deterministic_small(), Some(true));
+        assert_eq!(ChonkerInt::from(String::from("3000000000130000000000507")).is_prime_deterministic_small(), Some(false));
+    }
+
+    // Test the Baillie-PSW test against the iterative test, the strong pseudoprimes to the base 2 and the known primes.
+    #[test]
+    fn test_bigint_is_prime_baillie_psw() {
+        for number in 0..100_000u64 {
+            let bigint = ChonkerInt::from(number);
+            assert_eq!(bigint.is_prime_baillie_psw(), bigint.is_prime(), "{}", number);
+
+            // Trials without the trial division agree with it on the odd numbers too, they are slower, check the smaller ones.
+            if number > 2 && number < 10_000 && number % 2 == 1 {
+                assert_eq!(bigint.baillie_psw_trials(), bigint.is_prime(), "{}", number);
+            }
+        }
+
+        // Strong pseudoprimes to the base 2 pass the Miller-Rabin trial, but are rejected by the strong Lucas test.
+        for pseudoprime in ["2047", "3277", "4033", "4681", "8321", "1373653", "25326001", "3215031751", "2152302898747"] {
+            let pseudoprime = ChonkerInt::from(String::from(pseudoprime));
+            let (d, s) = pseudoprime.miller_rabin_decomposition();
+            let reducer = BarrettReducer::new(&pseudoprime);
+            assert!(pseudoprime.is_strong_probable_prime(&ChonkerInt::from(2), &d, s, &(&pseudoprime - 1u64), &reducer));
+            assert!(!pseudoprime.is_strong_lucas_probable_prime(), "{}", pseudoprime);
+            assert!(!pseudoprime.baillie_psw_trials(), "{}", pseudoprime);
+            assert!(!pseudoprime.is_prime_baillie_psw(), "{}", pseudoprime);
+        }
+
+        // Strong Lucas pseudoprimes pass the strong Lucas test, but are rejected by the Miller-Rabin trial.
+        for pseudoprime in [5459u64, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519, 75077, 97439] {
+            let pseudoprime = ChonkerInt::from(pseudoprime);
+            assert!(pseudoprime.is_strong_lucas_probable_prime(), "{}", pseudoprime);
+            assert!(!pseudoprime.baillie_psw_trials(), "{}", pseudoprime);
+        }
+
+        // Mersenne primes 2^61 - 1, 2^89 - 1, 2^127 - 1, 2^521 - 1 and other large primes.
+        for prime in [
+            "2305843009213693951",
+            "618970019642690137449562111",
+            "170141183460469231731687303715884105727",
+            "6864797660130609714981900799081393217269435300143305409394463459185543183397656052122559640661454554977296311391480858037121987999716643812574028291115057151",
+            "1000000000000066600000000000001",
+            "7434295549380978012839955681932280624399018262337538072234694442121788312959451806126604174504220901",
+            "14043145053387290701740553217226309216528545163443",
+        ] {
+            let prime = ChonkerInt::from(String::from(prime));
+            assert!(prime.is_prime_baillie_psw(), "{}", prime);
+        }
+
+        // Large composites: a square of a prime, a product of two primes and a strong pseudoprime to the first 13 primes.
+        let prime = ChonkerInt::from(String::from("170141183460469231731687303715884105727"));
+        assert!(!(&prime * &prime).is_prime_baillie_psw());
+        assert!(!(&prime * &ChonkerInt::from(String::from("2305843009213693951"))).is_prime_baillie_psw());
+        assert!(!ChonkerInt::from(String::from("3317044064679887385961981")).is_prime_baillie_psw());
+    }
+
+    // Test the parallel Miller-Rabin test against the sequential one on primes and composites.
+    #[test]
+    fn test_bigint_is_prime_probabilistic_parallel() {
+        let corpus = [
+            "2305843009213693951",
+            "170141183460469231731687303715884105727",
+            "7434295549380978012839955681932280624399018262337538072234694442121788312959451806126604174504220901",
+            "3317044064679887385961981",
+            "3317044064679887385961813",
+            "28948022309329048855892746252171976963317496166410141009864396001978282409983",
+            "4231689648728034761024109348723094713208529386505712",
+            "9231689641731777",
+            "561",
+            "97",
+        ];
+
+        for number in corpus.iter() {
+            let number = ChonkerInt::from(String::from(*number));
+            assert_eq!(
+                number.is_prime_probabilistic_parallel(Some(20), 4),
+                number.is_prime_probabilistic(Some(20)),
+                "{}",
+                number
+            );
+        }
+
+        // Primes run all of the trials.
+        let prime = ChonkerInt::from(String::from("170141183460469231731687303715884105727"));
+        assert_eq!(prime.miller_rabin_parallel(12, 3), (true, 12));
+    }
+
+    // Test the early exit of the parallel Miller-Rabin test, when the first witnesses disprove the primality,
+    // and the thread count above the amount of trials.
+    #[test]
+    fn test_bigint_is_prime_probabilistic_parallel_early_exit() {
+        // A product of two primes without small factors, almost every base is a witness.
+        let composite = &ChonkerInt::from(String::from("2305843009213693951"))
+            * &ChonkerInt::from(String::from("170141183460469231731687303715884105727"));
+        let (is_prime, trials_done) = composite.miller_rabin_parallel(1000, 4);
+        assert!(!is_prime);
+        assert!(trials_done <= 4, "{}", trials_done);
+
+        // More threads than trials.
+        let prime = ChonkerInt::from(String::from("618970019642690137449562111"));
+        assert_eq!(prime.miller_rabin_parallel(3, 16), (true, 3));
+        assert_eq!(prime.miller_rabin_parallel(0, 16), (true, 0));
+        assert!(!composite.is_prime_probabilistic_parallel(Some(2), 64));
+    }
+
+    // Test the parallel Miller-Rabin test with zero threads.
+    #[test]
+    #[should_panic]
+    fn test_bigint_is_prime_probabilistic_parallel_zero_threads() {
+        let _ = ChonkerInt::from(String::from("618970019642690137449562111")).is_prime_probabilistic_parallel(None, 0);
+    }
+
+    // Test the Jacobi symbol against the product of the Legendre symbols, calculated with the Euler's criterion.
+    #[test]
+    fn test_bigint_jacobi() {
+        let legendre = |a: i64, p: i64| -> i8 {
+            let result = ChonkerInt::from(a.rem_euclid(p)).modpow(&ChonkerInt::from((p - 1) / 2), &ChonkerInt::from(p));
+            if result.is_zero() {
+                0
+            } else if result.is_one() {
+                1
+            } else {
+                -1
+            }
+        };
+
+        let primes = [3i64, 5, 7, 11, 13, 17, 19, 23];
+        for p in primes.iter() {
+            for q in primes.iter() {
+                let n = ChonkerInt::from(p * q);
+                for a in -40..40 {
+                    assert_eq!(ChonkerInt::from(a).jacobi(&n), legendre(a, *p) * legendre(a, *q), "{} {}", a, p * q);
+                }
+            }
+        }
+
+        assert_eq!(ChonkerInt::from(5).jacobi(&ChonkerInt::from(1)), 1);
+        assert_eq!(ChonkerInt::from(1001).jacobi(&ChonkerInt::from(9907)), -1);
+        assert_eq!(ChonkerInt::from(19).jacobi(&ChonkerInt::from(45)), 1);
+        assert_eq!(ChonkerInt::from(30).jacobi(&ChonkerInt::from(45)), 0);
+    }
+
+    // Test the Jacobi symbol with an even denominator.
+    #[test]
+    #[should_panic]
+    fn test_bigint_jacobi_even_denominator() {
+        let _ = ChonkerInt::from(3).jacobi(&ChonkerInt::from(10));
+    }
+
+    // Test construction of random safe primes with a bit length too small for them.
+    #[test]
+    #[should_panic]
+    fn test_random_safe_prime_bigint_too_short_construction() {
+        ChonkerInt::new_safe_prime(2, &mut StdRng::seed_from_u64(0));
+    }
 
     // Test creation/construction of a random prime BigInt.
     #[test]
@@ -323,7 +1280,7 @@ mod tests {
         // let random_zero_bigint = ChonkerInt::new_rand(&requested_zero_length);
 
         assert_eq!(random_prime_bigint.sign, BigIntSign::Positive);
-        assert_eq!(random_prime_bigint.digits.len(), requested_length as usize);
+        assert_eq!(random_prime_bigint.digit_count(), requested_length as usize);
         assert!(random_prime_bigint.is_prime());
     }
 
@@ -458,6 +1415,16 @@ mod tests {
         assert!(small_bigint_prime.is_prime_probabilistic(Some(2)));
     }
 
+    // Test the Miller-Rabin test on the Carmichael numbers: every base coprime to them passes the Fermat test,
+    // only the square roots of 1 of the decomposition n - 1 = 2^s * d with the odd d reveal them as the composites.
+    // The factors of 1339280649331561 = 60661 * 121321 * 181981 are big, the random bases are almost never their multiples.
+    #[test]
+    fn test_bigint_is_prime_probabilistic_carmichael() {
+        for carmichael_number in ["561", "1729", "1339280649331561"] {
+            assert!(!ChonkerInt::from(String::from(carmichael_number)).is_prime_probabilistic(None));
+        }
+    }
+
     // Test the method checking the BigInt, if it is a primitive root of a prime number.
     #[test]
     fn test_bigint_is_primitive_root() {
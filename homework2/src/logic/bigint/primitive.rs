@@ -0,0 +1,327 @@
+// BigInt module regarding arithmetic and comparison of BigInts with primitive integers.
+// Multiplication, modulus and comparison with a single machine word are done in one pass over the limbs,
+// without construction of a second BigInt for the primitive operand.
+
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Rem, Sub};
+
+use crate::logic::bigint::{BigIntSign, ChonkerInt, RADIX};
+
+// Implement addition "+" operator for the BigInt and an unsigned 8 byte integer.
+impl<'a> Add<u64> for &'a ChonkerInt {
+    type Output = ChonkerInt;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        self + &ChonkerInt::from(rhs)
+    }
+}
+
+// Implement addition "+" operator for the BigInt and a signed 8 byte integer.
+impl<'a> Add<i64> for &'a ChonkerInt {
+    type Output = ChonkerInt;
+
+    fn add(self, rhs: i64) -> Self::Output {
+        self + &ChonkerInt::from(rhs)
+    }
+}
+
+// Implement subtraction "-" operator for the BigInt and an unsigned 8 byte integer.
+impl<'a> Sub<u64> for &'a ChonkerInt {
+    type Output = ChonkerInt;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        self - &ChonkerInt::from(rhs)
+    }
+}
+
+// Implement subtraction "-" operator for the BigInt and a signed 8 byte integer.
+impl<'a> Sub<i64> for &'a ChonkerInt {
+    type Output = ChonkerInt;
+
+    fn sub(self, rhs: i64) -> Self::Output {
+        self - &ChonkerInt::from(rhs)
+    }
+}
+
+// Implement multiplication "*" operator for the BigInt and an unsigned 8 byte integer.
+// Each limb is multiplied by the whole integer, the overflow is carried to the next limb.
+impl<'a> Mul<u64> for &'a ChonkerInt {
+    type Output = ChonkerInt;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        // If self or rhs is empty/zero, return an empty/zero BigInt.
+        let self_digits = self.significant_digits();
+        if self_digits.is_empty() || rhs == 0 {
+            return ChonkerInt::new();
+        }
+
+        let mut digits: Vec<u32> = Vec::with_capacity(self_digits.len() + 3);
+        let mut carry: u128 = 0;
+
+        for digit in self_digits {
+            let product = *digit as u128 * rhs as u128 + carry;
+            digits.push((product % RADIX as u128) as u32);
+            carry = product / RADIX as u128;
+        }
+
+        // Push the rest of the carry as new most significant limbs.
+        while carry > 0 {
+            digits.push((carry % RADIX as u128) as u32);
+            carry /= RADIX as u128;
+        }
+
+        let sign = match self.sign {
+            BigIntSign::Negative => BigIntSign::Negative,
+            _ => BigIntSign::Positive,
+        };
+
+        ChonkerInt { digits, sign }
+    }
+}
+
+// Implement multiplication "*" operator for the BigInt and a signed 8 byte integer.
+impl<'a> Mul<i64> for &'a ChonkerInt {
+    type Output = ChonkerInt;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        let result = self * rhs.unsigned_abs();
+
+        if rhs < 0 {
+            -result
+        } else {
+            result
+        }
+    }
+}
+
+// Implement modulus "%" operator for the BigInt and an unsigned 8 byte integer.
+// The sign of the result follows the divisor, thus the result is always in the range 0..rhs.
+impl<'a> Rem<u64> for &'a ChonkerInt {
+    type Output = u64;
+
+    fn rem(self, rhs: u64) -> Self::Output {
+        // Check for division by zero, if the divisor is zero, panic.
+        if rhs == 0 {
+            panic!("attempt to divide/take modulus by zero (ChonkerInt::rem())");
+        }
+
+        let remainder = self.magnitude_rem(rhs);
+
+        // For the negative dividend the remainder of the magnitude is counted from the divisor.
+        if self.sign == BigIntSign::Negative && remainder != 0 {
+            rhs - remainder
+        } else {
+            remainder
+        }
+    }
+}
+
+// Implement modulus "%" operator for the BigInt and a signed 8 byte integer.
+// The sign of the result follows the divisor.
+impl<'a> Rem<i64> for &'a ChonkerInt {
+    type Output = i64;
+
+    fn rem(self, rhs: i64) -> Self::Output {
+        // Check for division by zero, if the divisor is zero, panic.
+        if rhs == 0 {
+            panic!("attempt to divide/take modulus by zero (ChonkerInt::rem())");
+        }
+
+        let absolute_rhs = rhs.unsigned_abs();
+        let remainder = self.magnitude_rem(absolute_rhs);
+
+        // If the signs of the dividend and the divisor differ, count the remainder from the divisor.
+        let absolute_result = if remainder != 0 && (self.sign == BigIntSign::Negative) != (rhs < 0) {
+            absolute_rhs - remainder
+        } else {
+            remainder
+        };
+
+        if rhs < 0 {
+            (absolute_result as i64).wrapping_neg()
+        } else {
+            absolute_result as i64
+        }
+    }
+}
+
+impl ChonkerInt {
+    // Calculate the remainder of the BigInt's magnitude divided by an unsigned 8 byte integer.
+    // Division starts from the most significant limb, the remainder is carried to the next limb.
+    fn magnitude_rem(&self, divisor: u64) -> u64 {
+        let divisor = divisor as u128;
+
+        self.digits
+            .iter()
+            .rev()
+            .fold(0u128, |remainder, digit| {
+                (remainder * RADIX as u128 + *digit as u128) % divisor
+            }) as u64
+    }
+
+    // Compare the magnitude of the BigInt with an unsigned 8 byte integer.
+    fn magnitude_cmp(&self, other: u64) -> Ordering {
+        let digits = self.significant_digits();
+
+        // The maximal unsigned 8 byte integer has 20 digits, which fit into 3 limbs.
+        if digits.len() > 3 {
+            return Ordering::Greater;
+        }
+
+        let magnitude = digits
+            .iter()
+            .rev()
+            .fold(0u128, |result, digit| result * RADIX as u128 + *digit as u128);
+
+        magnitude.cmp(&(other as u128))
+    }
+}
+
+// Implement comparison operators "==", "!=" for the BigInt and an unsigned 8 byte integer.
+impl PartialEq<u64> for ChonkerInt {
+    fn eq(&self, other: &u64) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+// Implement comparison operators "<", "<=", ">", ">=" for the BigInt and an unsigned 8 byte integer.
+impl PartialOrd<u64> for ChonkerInt {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        // A negative BigInt with significant digits is less than any unsigned integer.
+        if self.sign == BigIntSign::Negative && !self.significant_digits().is_empty() {
+            return Some(Ordering::Less);
+        }
+
+        Some(self.magnitude_cmp(*other))
+    }
+}
+
+// Implement comparison operators "==", "!=" for the BigInt and a signed 8 byte integer.
+impl PartialEq<i64> for ChonkerInt {
+    fn eq(&self, other: &i64) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+// Implement comparison operators "<", "<=", ">", ">=" for the BigInt and a signed 8 byte integer.
+impl PartialOrd<i64> for ChonkerInt {
+    fn partial_cmp(&self, other: &i64) -> Option<Ordering> {
+        let self_negative = self.sign == BigIntSign::Negative && !self.significant_digits().is_empty();
+
+        // Compare signs, if they are equal, compare magnitudes considering the sign.
+        let ordering = match (self_negative, *other < 0) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.magnitude_cmp(other.unsigned_abs()),
+            (true, true) => self.magnitude_cmp(other.unsigned_abs()).reverse(),
+        };
+
+        Some(ordering)
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
+
+    // Test arithmetic of BigInts and unsigned 8 byte integers against the general BigInt arithmetic.
+    #[test]
+    fn test_bigint_unsigned_primitive_arithmetic() {
+        let mut rng = rand::thread_rng();
+
+        for _iteration in 0..50 {
+            let length: u64 = rng.gen_range(1..=60);
+            let sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let bigint = ChonkerInt::new_rand(&length, &sign);
+            let primitive: u64 = rng.gen_range(1..=u64::MAX);
+            let primitive_bigint = ChonkerInt::from(primitive);
+
+            assert_eq!(&bigint + primitive, &bigint + &primitive_bigint);
+            assert_eq!(&bigint - primitive, &bigint - &primitive_bigint);
+            assert_eq!(&bigint * primitive, &bigint * &primitive_bigint);
+            assert_eq!(ChonkerInt::from(&bigint % primitive), &bigint % &primitive_bigint);
+        }
+
+        // Corner cases.
+        let zero: u64 = 0;
+        assert_eq!(&ChonkerInt::from(12345) * zero, ChonkerInt::new());
+        assert_eq!(&ChonkerInt::new() * 7u64, ChonkerInt::new());
+        assert_eq!(&ChonkerInt::new() % 7u64, 0);
+        assert_eq!(&ChonkerInt::from(-7) % 3u64, 2);
+        assert_eq!(&ChonkerInt::from(-6) % 3u64, 0);
+        assert_eq!(&ChonkerInt::from(u64::MAX) * u64::MAX, ChonkerInt::from(u64::MAX as u128 * u64::MAX as u128));
+    }
+
+    // Test arithmetic of BigInts and signed 8 byte integers against the general BigInt arithmetic.
+    #[test]
+    fn test_bigint_signed_primitive_arithmetic() {
+        let mut rng = rand::thread_rng();
+
+        for _iteration in 0..50 {
+            let length: u64 = rng.gen_range(1..=60);
+            let sign = if rng.gen_bool(0.5) { BigIntSign::Positive } else { BigIntSign::Negative };
+            let bigint = ChonkerInt::new_rand(&length, &sign);
+            let primitive: i64 = loop {
+                let value: i64 = rng.gen();
+                if value != 0 {
+                    break value;
+                }
+            };
+            let primitive_bigint = ChonkerInt::from(primitive);
+
+            assert_eq!(&bigint + primitive, &bigint + &primitive_bigint);
+            assert_eq!(&bigint - primitive, &bigint - &primitive_bigint);
+            assert_eq!(&bigint * primitive, &bigint * &primitive_bigint);
+            assert_eq!(ChonkerInt::from(&bigint % primitive), &bigint % &primitive_bigint);
+        }
+
+        // Corner cases, the sign of the remainder follows the divisor.
+        assert_eq!(&ChonkerInt::from(-7) % 3i64, 2);
+        assert_eq!(&ChonkerInt::from(7) % -3i64, -2);
+        assert_eq!(&ChonkerInt::from(-7) % -3i64, -1);
+        assert_eq!(&ChonkerInt::from(7) % i64::MIN, i64::MIN + 7);
+        assert_eq!(&ChonkerInt::from(-12) * -3i64, ChonkerInt::from(36));
+        assert_eq!(&ChonkerInt::from(12) * i64::MIN, ChonkerInt::from(12 * i64::MIN as i128));
+    }
+
+    // Test modulus by zero unsigned 8 byte integer.
+    #[test]
+    #[should_panic]
+    fn test_bigint_primitive_modulus_by_zero() {
+        let _ = &ChonkerInt::from(12345) % 0u64;
+    }
+
+    // Test comparisons of BigInts and primitive integers.
+    #[test]
+    fn test_bigint_primitive_comparison() {
+        assert!(ChonkerInt::from(1) == 1u64);
+        assert!(ChonkerInt::new() == 0u64);
+        assert!(ChonkerInt::new() == 0i64);
+        assert!(ChonkerInt::from(u64::MAX) == u64::MAX);
+        assert!(ChonkerInt::from(u64::MAX as u128 + 1) > u64::MAX);
+        assert!(ChonkerInt::from(-1) < 0u64);
+        assert!(ChonkerInt::from(-1) == -1i64);
+        assert!(ChonkerInt::from(-100) < -2i64);
+        assert!(ChonkerInt::from(-2) > -100i64);
+        assert!(ChonkerInt::from(i64::MIN) == i64::MIN);
+        assert!(ChonkerInt::from(i64::MIN as i128 - 1) < i64::MIN);
+        assert!(ChonkerInt::from(5) != 6u64);
+        assert!(ChonkerInt::from(5) >= 5i64);
+
+        // Random comparisons against the general BigInt comparison.
+        let mut rng = rand::thread_rng();
+
+        for _iteration in 0..100 {
+            let self_value: i64 = rng.gen_range(-1000..=1000);
+            let other_value: i64 = rng.gen_range(-1000..=1000);
+
+            assert_eq!(
+                ChonkerInt::from(self_value).partial_cmp(&other_value),
+                ChonkerInt::from(self_value).partial_cmp(&ChonkerInt::from(other_value))
+            );
+        }
+    }
+}
@@ -1,19 +1,26 @@
 // BigInt module regarding randomisation of BigInts.
 
 // Import required randomisation items.
-use rand::Rng;
+use rand::rngs::{OsRng, StdRng};
+use rand::{Rng, SeedableRng};
 
-use crate::logic::bigint::{BigIntSign, ChonkerInt};
+use crate::logic::bigint::conversion::pack_decimal_digits;
+use crate::logic::bigint::{BigIntSign, ChonkerInt, RADIX};
 
 // Implement randomisation methods for BigInt.
 impl ChonkerInt {
     // Initialize a randomly filled BigInt.
     pub fn new_rand(length: &u64, sign: &BigIntSign) -> ChonkerInt {
+        ChonkerInt::new_rand_with_rng(length, sign, &mut rand::thread_rng())
+    }
+
+    // Initialize a randomly filled BigInt with the digits drawn from the provided random number generator.
+    // The same seeded generator produces the same BigInt, e.g. with StdRng::seed_from_u64().
+    pub fn new_rand_with_rng<R: Rng + ?Sized>(length: &u64, sign: &BigIntSign, rng: &mut R) -> ChonkerInt {
         if *length == 0 {
             panic!("requested length for random bigint generation is 0, nothing to generate");
         }
 
-        let mut rng = rand::thread_rng();
         let mut bigint = ChonkerInt::new();
 
         // Assign the requested sign.
@@ -24,23 +31,50 @@ impl ChonkerInt {
         }
 
         let main_length = *length - 1;
-        let mut digit: i8;
+        let mut decimal_digits: Vec<u8> = Vec::with_capacity(main_length as usize + 1);
 
         // Fill the empty BigInt with the requested amount of random digits in the range of 0-9.
         for _iteration in 0..main_length {
-            digit = rng.gen_range(0..=9);
-            let _ = bigint.push(digit);
+            decimal_digits.push(rng.gen_range(0..=9));
         }
 
         // Ensure that the leading/last digit is not zero. Generate it separately.
-        digit = rng.gen_range(1..=9);
-        let _ = bigint.push(digit);
+        decimal_digits.push(rng.gen_range(1..=9));
+
+        // Pack the generated decimal digits into limbs.
+        bigint.digits = pack_decimal_digits(&decimal_digits);
+
+        bigint
+    }
+
+    // Initialize a random BigInt with exactly the requested bit length, the most significant bit is always set,
+    // e.g. ChonkerInt::new_rand_bits(256, ...) is in the range [2^255, 2^256).
+    // Random bytes are generated and the bits above the requested length are cleared.
+    pub fn new_rand_bits<R: Rng + ?Sized>(bits: u64, sign: &BigIntSign, rng: &mut R) -> ChonkerInt {
+        let mut bigint = ChonkerInt::from_bytes_be(&random_bytes_with_bit_length(bits, rng));
+
+        // Assign the requested sign.
+        match *sign {
+            BigIntSign::Positive => bigint.set_positive_sign(),
+            BigIntSign::Negative => bigint.set_negative_sign(),
+            BigIntSign::Zero => panic!("zeros are not randomly generated"),
+        }
 
         bigint
     }
 
     // Initialize a randomly filled BigInt from the provided range of lengths.
     pub fn new_rand_range_len(start: &u64, end: &u64, sign: &BigIntSign) -> ChonkerInt {
+        ChonkerInt::new_rand_range_len_with_rng(start, end, sign, &mut rand::thread_rng())
+    }
+
+    // Initialize a randomly filled BigInt from the provided range of lengths with the provided random number generator.
+    pub fn new_rand_range_len_with_rng<R: Rng + ?Sized>(
+        start: &u64,
+        end: &u64,
+        sign: &BigIntSign,
+        rng: &mut R,
+    ) -> ChonkerInt {
         if *start == 0 || *end == 0 {
             panic!("start or end length boundary for the random BigInt generation is zero, nothing to generate (ChonkerInt::new_rand_range_len)");
         }
@@ -49,7 +83,6 @@ impl ChonkerInt {
             panic!("provided incorrect boundaries for the random BigInt generation, starting boundary must be lower or equal to the ending one (ChonkerInt::new_rand_range_len)");
         }
 
-        let mut rng = rand::thread_rng();
         let mut bigint = ChonkerInt::new();
 
         // Assign requested sign.
@@ -62,17 +95,18 @@ impl ChonkerInt {
         // Randomly generate the length of the BigInt from the provided range.
         let main_length = (rng.gen_range((*start)..=(*end))) - 1;
 
-        let mut digit: i8;
+        let mut decimal_digits: Vec<u8> = Vec::with_capacity(main_length as usize + 1);
 
         // Fill the empty BigInt with the requested amount of random digits in the range of 0-9.
         for _iteration in 0..main_length {
-            digit = rng.gen_range(0..=9);
-            let _ = bigint.push(digit);
+            decimal_digits.push(rng.gen_range(0..=9));
         }
 
         // Ensure that the leading/last digit is not zero. Generate it separately.
-        digit = rng.gen_range(1..=9);
-        let _ = bigint.push(digit);
+        decimal_digits.push(rng.gen_range(1..=9));
+
+        // Pack the generated decimal digits into limbs.
+        bigint.digits = pack_decimal_digits(&decimal_digits);
 
         bigint
     }
@@ -82,6 +116,17 @@ impl ChonkerInt {
         start: &ChonkerInt,
         end: &ChonkerInt,
         sign: &BigIntSign,
+    ) -> ChonkerInt {
+        ChonkerInt::new_rand_range_value_with_rng(start, end, sign, &mut rand::thread_rng())
+    }
+
+    // Initialize a BigInt uniformly distributed over the provided range of values, boundaries included,
+    // with the provided random number generator. See ChonkerInt::new_rand_below_with_rng() for the rejection rate.
+    pub fn new_rand_range_value_with_rng<R: Rng + ?Sized>(
+        start: &ChonkerInt,
+        end: &ChonkerInt,
+        sign: &BigIntSign,
+        rng: &mut R,
     ) -> ChonkerInt {
         let big_zero = ChonkerInt::new();
 
@@ -105,40 +150,218 @@ impl ChonkerInt {
             panic!("zeros are not randomly generated");
         }
 
-        let mut bigint = ChonkerInt::new();
-        bigint.set_positive_sign();
+        // Sample uniformly over the width of the range, then shift the result by the starting boundary.
+        let width = &(end - start) + 1u64;
+        let mut bigint = start + &ChonkerInt::new_rand_below_with_rng(&width, rng);
+
+        // Assign requested sign.
+        if *sign == BigIntSign::Negative {
+            bigint.set_negative_sign();
+        }
+
+        bigint
+    }
+
+    // Initialize a BigInt uniformly distributed over the range [0, bound), the bound has to be positive.
+    pub fn new_rand_below(bound: &ChonkerInt) -> ChonkerInt {
+        ChonkerInt::new_rand_below_with_rng(bound, &mut rand::thread_rng())
+    }
+
+    // Initialize a BigInt uniformly distributed over the range [0, bound) with the provided random number generator.
+    // Candidates have as many limbs as the bound, the most significant limb is drawn from the range
+    // [0, most significant limb of the bound], the other ones from the full range of a limb.
+    // Only the candidates, which are not below the bound, are rejected, all of them share the most significant limb
+    // with the bound, thus at most a half of the candidates is rejected and less than 2 candidates are expected
+    // per BigInt. The bigger the most significant limb of the bound, the lower the rejection rate,
+    // e.g. for the bound 10^30 (most significant limb 1000) it is below 0.1%.
+    pub fn new_rand_below_with_rng<R: Rng + ?Sized>(bound: &ChonkerInt, rng: &mut R) -> ChonkerInt {
+        // Check if the bound is positive, there is nothing to generate below zero.
+        if !bound.is_positive() {
+            panic!("the bound for the uniform random BigInt generation must be positive, nothing to generate (ChonkerInt::new_rand_below)");
+        }
+
+        let bound_limbs = bound.significant_digits();
+        let most_significant_limb = bound_limbs[bound_limbs.len() - 1];
 
-        // Randomly generate the BigInt from the provided range of values.
         loop {
-            bigint = ChonkerInt::new_rand_range_len(
-                &(start.digits.len() as u64),
-                &(end.digits.len() as u64),
-                &BigIntSign::Positive,
-            );
-
-            // Check if the generated value is in between the requested boundaries.
-            if (bigint >= (*start)) && (bigint <= (*end)) {
-                // Assign requested sign.
-                match *sign {
-                    BigIntSign::Positive => bigint.set_positive_sign(),
-                    BigIntSign::Negative => bigint.set_negative_sign(),
-                    _ => (),
-                }
-
-                return bigint;
-            } else {
-                bigint = ChonkerInt::new();
-                bigint.set_positive_sign();
+            // Fill the candidate with random limbs, the most significant one is limited by the bound.
+            let mut digits: Vec<u32> = (1..bound_limbs.len()).map(|_| rng.gen_range(0..RADIX)).collect();
+            digits.push(rng.gen_range(0..=most_significant_limb));
+
+            let mut candidate = ChonkerInt {
+                digits,
+                sign: BigIntSign::Positive,
+            };
+            candidate.normalize();
+
+            // Reject the candidates out of the range.
+            if candidate < *bound {
+                return candidate;
             }
         }
     }
 }
 
+// Generate random bytes in the big endian format of a number with exactly the requested bit length:
+// the bits above the length are cleared and the most significant bit is set.
+pub(super) fn random_bytes_with_bit_length<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> Vec<u8> {
+    if bits == 0 {
+        panic!("requested bit length for random bigint generation is 0, nothing to generate");
+    }
+
+    let mut bytes = vec![0u8; ((bits + 7) / 8) as usize];
+    rng.fill(&mut bytes[..]);
+
+    // Bits of the most significant byte, which belong to the requested length: 1-8.
+    let top_bits = (bits - 1) % 8 + 1;
+    bytes[0] &= (0xFFu16 >> (8 - top_bits)) as u8;
+    bytes[0] |= 1 << (top_bits - 1);
+
+    bytes
+}
+
+// Create a cryptographically secure random number generator for the key material:
+// StdRng (ChaCha12 stream cipher) seeded from the random source of the operating system, OsRng.
+// Use the "_with_rng" constructors with a seeded StdRng instead, when reproducible results are required.
+pub fn secure_rng() -> StdRng {
+    match StdRng::from_rng(OsRng) {
+        Ok(rng) => rng,
+        Err(error) => panic!("failed to seed the random number generator from the operating system, {} (randomisation::secure_rng)", error),
+    }
+}
+
 // Test module.
 #[cfg(test)]
 mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
     use crate::logic::bigint::{BigIntSign, ChonkerInt};
 
+    // Test construction of random BigInts with a seeded random number generator,
+    // the same seed reproduces the same BigInts, another seed produces different ones.
+    #[test]
+    fn test_random_bigint_seeded_construction() {
+        let generate = |seed: u64| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let start = ChonkerInt::from(1000);
+            let end = ChonkerInt::from(String::from("1000000000000000000000"));
+
+            vec![
+                ChonkerInt::new_rand_with_rng(&100, &BigIntSign::Positive, &mut rng),
+                ChonkerInt::new_rand_range_len_with_rng(&1, &50, &BigIntSign::Negative, &mut rng),
+                ChonkerInt::new_rand_range_value_with_rng(&start, &end, &BigIntSign::Positive, &mut rng),
+            ]
+        };
+
+        let bigints = generate(7);
+        assert_eq!(bigints, generate(7));
+        assert_ne!(bigints, generate(8));
+
+        assert_eq!(bigints[0].digit_count(), 100);
+        assert!(bigints[1].is_negative() && bigints[1].digit_count() <= 50);
+        assert!(bigints[2] >= ChonkerInt::from(1000));
+    }
+
+    // Chi-square statistic of the observed counts against the uniform distribution over the buckets.
+    fn chi_square(counts: &[u64]) -> f64 {
+        let expected = counts.iter().sum::<u64>() as f64 / counts.len() as f64;
+
+        counts
+            .iter()
+            .map(|count| (*count as f64 - expected).powi(2) / expected)
+            .sum()
+    }
+
+    // Critical value of the chi-square distribution with 9 degrees of freedom at the significance level of 0.001.
+    const CHI_SQUARE_CRITICAL_9: f64 = 27.877;
+
+    // Statistical smoke test of the uniform sampling: the leading digit of the offset from the starting boundary,
+    // padded to the width of the range, has to be uniformly distributed over 0-9.
+    #[test]
+    fn test_random_bigint_range_value_uniformity() {
+        let mut rng = StdRng::seed_from_u64(2021);
+
+        // Short range, which spans several digit lengths, and a long range of 25 digits.
+        let ranges = [
+            (ChonkerInt::from(1), ChonkerInt::from(100), 1usize),
+            (ChonkerInt::from(1).mul_pow10(20), &ChonkerInt::from(1).mul_pow10(20) + &(&ChonkerInt::from(1).mul_pow10(25) - 1u64), 24),
+        ];
+
+        for (start, end, shift) in ranges.iter() {
+            let mut counts = [0u64; 10];
+            for _ in 0..10_000 {
+                let value = ChonkerInt::new_rand_range_value_with_rng(start, end, &BigIntSign::Positive, &mut rng);
+                assert!(value >= *start && value <= *end);
+
+                let leading_digit = (&value - start).div_pow10(*shift).to_u128().unwrap();
+                counts[leading_digit as usize] += 1;
+            }
+
+            assert!(chi_square(&counts) < CHI_SQUARE_CRITICAL_9, "{:?}", counts);
+        }
+    }
+
+    // Statistical smoke test of the uniform sampling below a bound with a small most significant limb,
+    // which has the highest rejection rate, the last digit has to be uniformly distributed over 0-9.
+    #[test]
+    fn test_random_bigint_below_uniformity() {
+        let mut rng = StdRng::seed_from_u64(2022);
+        let bound = ChonkerInt::from(1_000_000_010u64);
+
+        let mut counts = [0u64; 10];
+        for _ in 0..10_000 {
+            let value = ChonkerInt::new_rand_below_with_rng(&bound, &mut rng);
+            assert!(!value.is_negative() && value < bound);
+
+            counts[(&value % 10u64) as usize] += 1;
+        }
+
+        assert!(chi_square(&counts) < CHI_SQUARE_CRITICAL_9, "{:?}", counts);
+
+        // The smallest bound leaves the only value of zero.
+        assert!(ChonkerInt::new_rand_below(&ChonkerInt::from(1)).is_zero());
+    }
+
+    // Test uniform sampling with a non-positive bound.
+    #[test]
+    #[should_panic]
+    fn test_random_bigint_below_zero_bound() {
+        ChonkerInt::new_rand_below(&ChonkerInt::new());
+    }
+
+    // Test construction of random BigInts by the bit length, the most significant bit is always set.
+    #[test]
+    fn test_random_bigint_bits_construction() {
+        let mut rng = StdRng::seed_from_u64(64);
+
+        for bits in (1..=70).chain([127, 128, 129, 256, 1024]) {
+            for _ in 0..20 {
+                let bigint = ChonkerInt::new_rand_bits(bits, &BigIntSign::Positive, &mut rng);
+                assert_eq!(bigint.bit_length(), bits);
+                assert!(bigint.is_positive());
+            }
+        }
+
+        let negative_bigint = ChonkerInt::new_rand_bits(100, &BigIntSign::Negative, &mut rng);
+        assert!(negative_bigint.is_negative());
+        assert_eq!(negative_bigint.bit_length(), 100);
+
+        // Values of 3 bits cover the whole range 4-7.
+        let mut seen = [false; 8];
+        for _ in 0..200 {
+            seen[ChonkerInt::new_rand_bits(3, &BigIntSign::Positive, &mut rng).to_u128().unwrap() as usize] = true;
+        }
+        assert_eq!(seen, [false, false, false, false, true, true, true, true]);
+    }
+
+    // Test construction of random BigInts with the bit length of zero.
+    #[test]
+    #[should_panic]
+    fn test_random_bigint_zero_bits_construction() {
+        ChonkerInt::new_rand_bits(0, &BigIntSign::Positive, &mut StdRng::seed_from_u64(0));
+    }
+
     // Test creation/construction of a filled BigInt with random digits.
     #[test]
     fn test_random_bigint_construction() {
@@ -158,12 +381,12 @@ mod tests {
 
         assert_eq!(random_positive_bigint.sign, requested_positive_sign);
         assert_eq!(
-            random_positive_bigint.digits.len(),
+            random_positive_bigint.digit_count(),
             requested_length as usize
         );
         assert_eq!(random_negative_bigint.sign, requested_negative_sign);
         assert_eq!(
-            random_negative_bigint.digits.len(),
+            random_negative_bigint.digit_count(),
             requested_length as usize
         );
     }
@@ -192,9 +415,9 @@ mod tests {
         // let random_zero_bigint1 = ChonkerInt::new_rand_range_len(&requested_length_start, &requested_length_end, &requested_zero_sign);
 
         assert_eq!(random_positive_bigint.sign, requested_positive_sign);
-        assert!((1..=12).contains(&(random_positive_bigint.digits.len())));
+        assert!((1..=12).contains(&(random_positive_bigint.digit_count())));
         assert_eq!(random_negative_bigint.sign, requested_negative_sign);
-        assert!((1..=12).contains(&(random_negative_bigint.digits.len())));
+        assert!((1..=12).contains(&(random_negative_bigint.digit_count())));
     }
 
     // Test creation/construction of random BigInt from the provided range of values.
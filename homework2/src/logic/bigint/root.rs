@@ -0,0 +1,324 @@
+// BigInt module regarding integer roots and modular square roots of BigInts.
+
+use crate::logic::bigint::{BigIntSign, ChonkerInt};
+
+// Implement root methods for BigInt.
+impl ChonkerInt {
+    // Calculate the integer square root, the floor of the square root of the BigInt.
+    // It is accomplished with the Newton's method: x = (x + self / x) / 2, starting from a value above the root.
+    // More about the idea: https://en.wikipedia.org/wiki/Integer_square_root
+    pub fn sqrt(&self) -> ChonkerInt {
+        if self.is_negative() {
+            panic!("attempt to calculate the square root of a negative number (ChonkerInt::sqrt())");
+        }
+
+        self.nth_root(2)
+    }
+
+    // Calculate the exact integer square root, if the BigInt is a perfect square, otherwise return None.
+    // Example: ChonkerInt::from(49).sqrt_exact() == Some(ChonkerInt::from(7)), ChonkerInt::from(50).sqrt_exact() == None.
+    pub fn sqrt_exact(&self) -> Option<ChonkerInt> {
+        if self.is_negative() {
+            return None;
+        }
+
+        let root = self.sqrt();
+        match &root * &root == *self {
+            true => Some(root),
+            false => None,
+        }
+    }
+
+    // Calculate the integer n-th root, the floor of the n-th root of the BigInt.
+    // It is accomplished with the Newton's method: x = ((n - 1) * x + self / x^(n - 1)) / n,
+    // starting from a value above the root, the iteration stops once the value stops decreasing.
+    pub fn nth_root(&self, n: u32) -> ChonkerInt {
+        if self.is_negative() {
+            panic!("attempt to calculate the n-th root of a negative number (ChonkerInt::nth_root())");
+        } else if n == 0 {
+            panic!("attempt to calculate the zeroth root of a number (ChonkerInt::nth_root())");
+        }
+
+        // Zero and one are roots of themselves, the first root is the number itself.
+        if self.is_zero() {
+            return ChonkerInt::new();
+        } else if self.is_one() || n == 1 {
+            return (*self).clone();
+        }
+
+        let big_n = ChonkerInt::from(n);
+        let big_n_one = ChonkerInt::from(n - 1);
+
+        // Start with a power of ten, which has at least as many digits as the root, thus it is bigger than the root.
+        let root_length = (self.digit_count() + n as usize - 1) / n as usize;
+        let mut root = ChonkerInt::from(1).mul_pow10(root_length);
+
+        loop {
+            let next_root = &(&(&big_n_one * &root) + &(self / &root.pow_u64(n as u64 - 1))) / &big_n;
+
+            // Check if the estimation stopped decreasing, if so, the previous estimation is the floor of the root.
+            if next_root >= root {
+                return root;
+            }
+
+            root = next_root;
+        }
+    }
+
+    // Calculate the square root modulo the odd prime p, the smaller of the two roots r and p - r is returned,
+    // the choice of the other one is left to the caller. Returns None, when the BigInt is a quadratic non-residue,
+    // which is determined by the Legendre symbol (self / p). Works only with the prime moduli.
+    pub fn sqrt_mod(&self, p: &ChonkerInt) -> Option<ChonkerInt> {
+        if !p.is_positive() || p.is_even() || p.is_one() {
+            panic!("the modular square root is calculated only modulo the odd primes (ChonkerInt::sqrt_mod())");
+        }
+
+        let residue = self.rem_euclid(p);
+        if residue.is_zero() {
+            return Some(ChonkerInt::new());
+        } else if residue.jacobi(p) != 1 {
+            return None;
+        }
+
+        let root = if p % 4u64 == 3 {
+            residue.sqrt_mod_3_mod_4(p)
+        } else {
+            residue.tonelli_shanks(p)?
+        };
+
+        let other_root = p - &root;
+        if other_root < root {
+            Some(other_root)
+        } else {
+            Some(root)
+        }
+    }
+
+    // Calculate the square root of the quadratic residue modulo the prime p == 3 (modulo 4): r = a^((p + 1) / 4).
+    // r^2 = a^((p + 1) / 2) = a * a^((p - 1) / 2) = a (modulo p) by the Euler's criterion.
+    fn sqrt_mod_3_mod_4(&self, p: &ChonkerInt) -> ChonkerInt {
+        let mut exponent = p + 1u64;
+        exponent.halve_in_place();
+        exponent.halve_in_place();
+
+        self.modpow(&exponent, p)
+    }
+
+    // Calculate the square root of the quadratic residue modulo the odd prime p with the Tonelli - Shanks algorithm.
+    // With p - 1 = 2^s * q, q - odd, the root estimation r = a^((q + 1) / 2) is corrected with the powers of
+    // a non-residue z, until the error t = a^q becomes 1. Returns None, if the modulus turns out to be a composite number.
+    // More about the idea: https://en.wikipedia.org/wiki/Tonelli%E2%80%93Shanks_algorithm
+    fn tonelli_shanks(&self, p: &ChonkerInt) -> Option<ChonkerInt> {
+        // p - 1 = 2^s * q, q - odd
+        let mut q = p - 1u64;
+        let mut s = 0;
+        while q.is_even() {
+            q.halve_in_place();
+            s += 1;
+        }
+
+        // Search for a quadratic non-residue z, half of the numbers are non-residues.
+        let mut z = ChonkerInt::from(2);
+        while z.jacobi(p) != -1 {
+            z = &z + 1u64;
+        }
+
+        let mut m = s;
+        let mut c = z.modpow(&q, p);
+        let mut t = self.modpow(&q, p);
+        let mut root_exponent = &q + 1u64;
+        root_exponent.halve_in_place();
+        let mut root = self.modpow(&root_exponent, p);
+
+        while !t.is_one() {
+            // Find the least i, 0 < i < m, such that t^(2^i) == 1 (modulo p).
+            let mut i = 0;
+            let mut t_power = t.clone();
+            while !t_power.is_one() {
+                t_power.square_in_place();
+                t_power = &t_power % p;
+                i += 1;
+
+                // The order of t is not a power of two below 2^m, which is impossible modulo a prime.
+                if i == m {
+                    return None;
+                }
+            }
+
+            // b = c^(2^(m - i - 1)), then update m = i, c = b^2, t = t * b^2, r = r * b.
+            let mut b = c;
+            for _squaring in 0..(m - i - 1) {
+                b.square_in_place();
+                b = &b % p;
+            }
+
+            m = i;
+            c = &(&b * &b) % p;
+            t = &(&t * &c) % p;
+            root = &(&root * &b) % p;
+        }
+
+        Some(root)
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
+
+    // Test the integer square root of BigInts.
+    #[test]
+    fn test_bigint_sqrt() {
+        assert_eq!(ChonkerInt::new().sqrt(), ChonkerInt::new());
+        assert_eq!(ChonkerInt::from(1).sqrt(), ChonkerInt::from(1));
+        assert_eq!(ChonkerInt::from(2).sqrt(), ChonkerInt::from(1));
+        assert_eq!(ChonkerInt::from(3).sqrt(), ChonkerInt::from(1));
+
+        // Perfect squares and perfect squares minus one.
+        for value in 2u64..200 {
+            let square = ChonkerInt::from(value * value);
+            assert_eq!(square.sqrt(), ChonkerInt::from(value));
+            assert_eq!((&square - 1u64).sqrt(), ChonkerInt::from(value - 1));
+        }
+
+        // Compare against the primitive integers.
+        for value in [10u64, 99, 1000, 65537, 123456789, u32::MAX as u64 * 7] {
+            assert_eq!(ChonkerInt::from(value).sqrt(), ChonkerInt::from((value as f64).sqrt() as u64));
+        }
+
+        // A 100 digit number, verify the result by squaring: root^2 <= target < (root + 1)^2.
+        let target = ChonkerInt::new_rand(&100, &BigIntSign::Positive);
+        let root = target.sqrt();
+        let next_root = &root + 1u64;
+        assert!(&root * &root <= target);
+        assert!(&next_root * &next_root > target);
+    }
+
+    // Test the exact integer square root of the perfect squares and its absence for the other BigInts.
+    #[test]
+    fn test_bigint_sqrt_exact() {
+        assert_eq!(ChonkerInt::new().sqrt_exact(), Some(ChonkerInt::new()));
+        for value in 1u64..200 {
+            let square = ChonkerInt::from(value * value);
+            assert_eq!(square.sqrt_exact(), Some(ChonkerInt::from(value)));
+            assert_eq!((&square + 1u64).sqrt_exact(), None);
+        }
+
+        let root = ChonkerInt::new_rand(&60, &BigIntSign::Positive);
+        assert_eq!((&root * &root).sqrt_exact(), Some(root.clone()));
+        assert_eq!((&(&root * &root) - 1u64).sqrt_exact(), None);
+        assert_eq!(ChonkerInt::from(-4).sqrt_exact(), None);
+    }
+
+    // Test the integer n-th root of BigInts.
+    #[test]
+    fn test_bigint_nth_root() {
+        assert_eq!(ChonkerInt::new().nth_root(3), ChonkerInt::new());
+        assert_eq!(ChonkerInt::from(1).nth_root(5), ChonkerInt::from(1));
+        assert_eq!(ChonkerInt::from(12345).nth_root(1), ChonkerInt::from(12345));
+
+        // Perfect powers and perfect powers minus one.
+        for n in 2u32..6 {
+            for value in 2u64..30 {
+                let power = ChonkerInt::from(value.pow(n));
+                assert_eq!(power.nth_root(n), ChonkerInt::from(value));
+                assert_eq!((&power - 1u64).nth_root(n), ChonkerInt::from(value - 1));
+            }
+        }
+
+        // A 100 digit number, verify the result by exponentiation: root^n <= target < (root + 1)^n.
+        let target = ChonkerInt::new_rand(&100, &BigIntSign::Positive);
+        let power = ChonkerInt::from(7);
+        let root = target.nth_root(7);
+        assert!(root.pow(&power) <= target);
+        assert!((&root + 1u64).pow(&power) > target);
+    }
+
+    // Test the modular square root with the random primes and the random residues.
+    #[test]
+    fn test_bigint_sqrt_mod() {
+        let mut rng = StdRng::seed_from_u64(45);
+
+        // Random primes of both residue classes modulo 4, and primes with a big power of two in p - 1.
+        let mut primes: Vec<ChonkerInt> = (0..8).map(|_| ChonkerInt::new_prime_bits(96, &mut rng)).collect();
+        for prime in [3u64, 5, 7, 13, 17, 41, 97, 7340033, 998244353] {
+            primes.push(ChonkerInt::from(prime));
+        }
+
+        for prime in primes.iter() {
+            for _ in 0..5 {
+                let value = ChonkerInt::new_rand_range_value_with_rng(&ChonkerInt::from(1), &(prime - 1u64), &BigIntSign::Positive, &mut rng);
+                let residue = &(&value * &value) % prime;
+
+                let root = residue.sqrt_mod(prime).unwrap();
+                assert_eq!(&(&root * &root) % prime, residue);
+                assert!(root <= prime - &root);
+                assert!(root == value || root == prime - &value);
+            }
+
+            // The product of a residue and a non-residue is a non-residue.
+            let mut non_residue = ChonkerInt::from(2);
+            while non_residue.jacobi(prime) != -1 {
+                non_residue = &non_residue + 1u64;
+            }
+            assert_eq!(non_residue.sqrt_mod(prime), None);
+            assert_eq!((&(&non_residue * 4u64) % prime).sqrt_mod(prime), None);
+        }
+
+        // Zero and the negative residues.
+        let prime = ChonkerInt::from(97);
+        assert_eq!(ChonkerInt::new().sqrt_mod(&prime), Some(ChonkerInt::new()));
+        assert_eq!(ChonkerInt::from(-97 * 3).sqrt_mod(&prime), Some(ChonkerInt::new()));
+        assert_eq!(ChonkerInt::from(-88).sqrt_mod(&prime), Some(ChonkerInt::from(3)));
+    }
+
+    // Test the agreement of the special case for the primes p == 3 (modulo 4) with the Tonelli - Shanks algorithm.
+    #[test]
+    fn test_bigint_sqrt_mod_3_mod_4() {
+        let mut rng = StdRng::seed_from_u64(46);
+        let mut tested = 0;
+
+        while tested < 8 {
+            let prime = ChonkerInt::new_prime_bits(80, &mut rng);
+            if &prime % 4u64 != 3 {
+                continue;
+            }
+            tested += 1;
+
+            for _ in 0..5 {
+                let value = ChonkerInt::new_rand_range_value_with_rng(&ChonkerInt::from(1), &(&prime - 1u64), &BigIntSign::Positive, &mut rng);
+                let residue = &(&value * &value) % &prime;
+
+                let special_root = residue.sqrt_mod_3_mod_4(&prime);
+                let general_root = residue.tonelli_shanks(&prime).unwrap();
+                assert!(special_root == general_root || special_root == &prime - &general_root);
+                assert_eq!(&(&special_root * &special_root) % &prime, residue);
+            }
+        }
+    }
+
+    // Test the modular square root with an even modulus.
+    #[test]
+    #[should_panic]
+    fn test_bigint_sqrt_mod_even_modulus() {
+        let _ = ChonkerInt::from(4).sqrt_mod(&ChonkerInt::from(10));
+    }
+
+    // Test the square root of a negative BigInt.
+    #[test]
+    #[should_panic]
+    fn test_bigint_sqrt_of_negative() {
+        let _ = ChonkerInt::from(-16).sqrt();
+    }
+
+    // Test the zeroth root of a BigInt.
+    #[test]
+    #[should_panic]
+    fn test_bigint_zeroth_root() {
+        let _ = ChonkerInt::from(16).nth_root(0);
+    }
+}
@@ -0,0 +1,135 @@
+// BigInt module regarding serialization and deserialization with serde, enabled by the "serde" feature.
+// BigInt is represented by a decimal string to avoid precision loss in formats with limited numbers, e.g. JSON.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::logic::bigint::ChonkerInt;
+
+// Serialize BigInt as a decimal string, e.g. "-123".
+impl Serialize for ChonkerInt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+// Deserialize BigInt from a decimal string, validating it with the same rules as parsing through FromStr.
+impl<'de> Deserialize<'de> for ChonkerInt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+
+        string.parse::<ChonkerInt>().map_err(D::Error::custom)
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::crypto::diffie_hellman::{DhParty, DhPublicKey, DiffieHellmanResult};
+    use crate::crypto::rsa::RsaKeyPair;
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
+
+    // Test serialization of BigInts into decimal strings and back.
+    #[test]
+    fn test_bigint_serialization() {
+        let positive_bigint = ChonkerInt::from(12300);
+        let negative_bigint = ChonkerInt::from(-12300);
+        let big_bigint = ChonkerInt::new_rand(&300, &BigIntSign::Negative);
+
+        assert_eq!(serde_json::to_string(&positive_bigint).unwrap(), "\"12300\"");
+        assert_eq!(serde_json::to_string(&negative_bigint).unwrap(), "\"-12300\"");
+        assert_eq!(serde_json::to_string(&ChonkerInt::new()).unwrap(), "\"0\"");
+
+        let serialized_big_bigint = serde_json::to_string(&big_bigint).unwrap();
+        assert_eq!(serde_json::from_str::<ChonkerInt>(&serialized_big_bigint).unwrap(), big_bigint);
+        assert_eq!(serde_json::from_str::<ChonkerInt>("\"-000123\"").unwrap(), ChonkerInt::from(-123));
+
+        // Garbage and non-string values are rejected.
+        assert!(serde_json::from_str::<ChonkerInt>("\"12a3\"").is_err());
+        assert!(serde_json::from_str::<ChonkerInt>("\"\"").is_err());
+        assert!(serde_json::from_str::<ChonkerInt>("\" 42 \"").is_err());
+        assert!(serde_json::from_str::<ChonkerInt>("123").is_err());
+
+        // Sign is serialized as its variant name.
+        assert_eq!(serde_json::to_string(&BigIntSign::Negative).unwrap(), "\"Negative\"");
+        assert_eq!(serde_json::from_str::<BigIntSign>("\"Zero\"").unwrap(), BigIntSign::Zero);
+    }
+
+    // Test serialization of the RSA key pair into JSON and back.
+    #[test]
+    fn test_rsa_key_pair_serialization() {
+        let key_pair = RsaKeyPair {
+            public_key_n: ChonkerInt::from(3233),
+            public_key_e: ChonkerInt::from(17),
+            private_key_d: ChonkerInt::from(413),
+            crt: None,
+        };
+
+        let serialized_key_pair = serde_json::to_string(&key_pair).unwrap();
+
+        assert_eq!(
+            serialized_key_pair,
+            r#"{"public_key_n":"3233","public_key_e":"17","private_key_d":"413"}"#
+        );
+        assert_eq!(serde_json::from_str::<RsaKeyPair>(&serialized_key_pair).unwrap(), key_pair);
+
+        // Incorrect key value is rejected.
+        assert!(serde_json::from_str::<RsaKeyPair>(
+            r#"{"public_key_n":"32x3","public_key_e":"17","private_key_d":"413"}"#
+        )
+        .is_err());
+    }
+
+    // Test serialization of the Diffie-Hellman result into JSON and back.
+    #[test]
+    fn test_diffie_hellman_result_serialization() {
+        let result = DiffieHellmanResult {
+            prime: ChonkerInt::from(13),
+            base: ChonkerInt::from(7),
+            secret_a: ChonkerInt::from(5),
+            secret_b: ChonkerInt::from(6),
+            public_a: ChonkerInt::from(11),
+            public_b: ChonkerInt::from(12),
+            shared_key: ChonkerInt::from(12),
+            derived_key: None,
+        };
+
+        let serialized_result = serde_json::to_value(&result).unwrap();
+
+        assert_eq!(
+            serialized_result,
+            serde_json::json!({
+                "prime": "13",
+                "base": "7",
+                "secret_a": "5",
+                "secret_b": "6",
+                "public_a": "11",
+                "public_b": "12",
+                "shared_key": "12",
+                "derived_key": null
+            })
+        );
+
+        let deserialized_result: DiffieHellmanResult = serde_json::from_value(serialized_result).unwrap();
+
+        assert_eq!(deserialized_result, result);
+        assert!(deserialized_result.verify());
+    }
+    // Test serialization of the public part of the Diffie-Hellman participant into JSON and back.
+    #[test]
+    fn test_dh_public_key_serialization() {
+        let party = DhParty::from_secret(&ChonkerInt::from(2147483647u64), &ChonkerInt::from(7), &ChonkerInt::from(3)).unwrap();
+        let public_key = party.public_key();
+
+        let serialized_public_key = serde_json::to_string(&public_key).unwrap();
+
+        assert_eq!(serialized_public_key, r#"{"shared_prime":"2147483647","shared_base":"7","public_value":"343"}"#);
+        assert_eq!(serde_json::from_str::<DhPublicKey>(&serialized_public_key).unwrap(), public_key);
+    }
+}
@@ -0,0 +1,139 @@
+// BigInt module regarding binary and decimal shifts of BigInts.
+
+use std::ops::{Shl, Shr};
+
+use crate::logic::bigint::conversion::divide_digits;
+use crate::logic::bigint::{ChonkerInt, RADIX_DIGITS};
+
+// Implement left shift "<<" operator for the BigInt, a binary shift.
+// Shifting left by n bits multiplies the BigInt by 2^n, the sign is preserved.
+impl<'a> Shl<usize> for &'a ChonkerInt {
+    type Output = ChonkerInt;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        if self.is_zero() || rhs == 0 {
+            return (*self).clone();
+        }
+
+        self * &ChonkerInt::from(2).pow_u64(rhs as u64)
+    }
+}
+
+// Implement right shift ">>" operator for the BigInt, a binary shift.
+// Shifting right by n bits divides the BigInt by 2^n, the sign is preserved and the result is truncated towards zero,
+// e.g. -5 >> 1 == -2, unlike the arithmetic shift of the primitive integers, which rounds towards negative infinity.
+// Shifting right past all bits results in zero.
+impl<'a> Shr<usize> for &'a ChonkerInt {
+    type Output = ChonkerInt;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        if self.is_zero() || rhs == 0 {
+            return (*self).clone();
+        } else if rhs as u64 >= self.bit_length() {
+            return ChonkerInt::new();
+        }
+
+        self / &ChonkerInt::from(2).pow_u64(rhs as u64)
+    }
+}
+
+// Implement decimal shifts for BigInt.
+impl ChonkerInt {
+    // Multiply the BigInt by 10^n, shift the digits to the left by n positions.
+    pub fn mul_pow10(&self, n: usize) -> ChonkerInt {
+        if self.is_zero() {
+            return ChonkerInt::new();
+        }
+
+        // Split the shift into whole limbs and the rest of the digits inside a limb.
+        let limb_shift = n / RADIX_DIGITS;
+        let digit_shift = (n % RADIX_DIGITS) as u32;
+
+        // Multiply by the rest of the shift, then insert zero limbs at the start of the little endian vector.
+        let mut result = self * 10u64.pow(digit_shift);
+        result.digits.splice(0..0, std::iter::repeat(0).take(limb_shift));
+
+        result
+    }
+
+    // Divide the BigInt by 10^n, shift the digits to the right by n positions, the sign is preserved
+    // and the result is truncated towards zero. Shifting past all digits results in zero.
+    pub fn div_pow10(&self, n: usize) -> ChonkerInt {
+        if n >= self.digit_count() || self.is_zero() {
+            return ChonkerInt::new();
+        }
+
+        // Split the shift into whole limbs and the rest of the digits inside a limb.
+        let limb_shift = n / RADIX_DIGITS;
+        let digit_shift = (n % RADIX_DIGITS) as u32;
+
+        // Limbs are stored in the little endian, drop the least significant limbs,
+        // then divide by the rest of the shift.
+        let mut result = (*self).clone();
+        result.digits.truncate(self.significant_digits().len());
+        result.digits.drain(0..limb_shift);
+        divide_digits(&mut result.digits, 10u32.pow(digit_shift));
+
+        result
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
+
+    // Test binary shifts of the BigInt against the primitive integers.
+    #[test]
+    fn test_bigint_binary_shifts() {
+        for value in [0i64, 1, 5, -5, 12345, -12345, 1 << 40, -(1 << 40)] {
+            let bigint = ChonkerInt::from(value);
+            for shift in 0..20usize {
+                assert_eq!(&bigint << shift, ChonkerInt::from((value as i128) << shift));
+                // Right shift truncates towards zero, like division.
+                assert_eq!(&bigint >> shift, ChonkerInt::from(value / (1i64 << shift)));
+            }
+        }
+
+        // Zero shifted by anything stays zero.
+        assert_eq!(&ChonkerInt::new() << 1000, ChonkerInt::new());
+        assert_eq!(&ChonkerInt::new() >> 1000, ChonkerInt::new());
+
+        // Shifting right past all bits results in zero.
+        assert_eq!(&ChonkerInt::from(255) >> 8, ChonkerInt::new());
+        assert_eq!(&ChonkerInt::from(-256) >> 8, ChonkerInt::from(-1));
+        assert_eq!(&ChonkerInt::from(-256) >> 9, ChonkerInt::new());
+
+        // Big values, shifting left and right by the same amount results in the same value.
+        let bigint = ChonkerInt::new_rand(&100, &BigIntSign::Negative);
+        let shifted_bigint = &bigint << 300;
+        assert_eq!(shifted_bigint, &bigint * &ChonkerInt::from(2).pow(&ChonkerInt::from(300)));
+        assert_eq!(&shifted_bigint >> 300, bigint);
+    }
+
+    // Test decimal shifts of the BigInt.
+    #[test]
+    fn test_bigint_decimal_shifts() {
+        for value in [0i64, 7, -7, 12345, -12345, 1000] {
+            let bigint = ChonkerInt::from(value);
+            for shift in 0..10u32 {
+                assert_eq!(bigint.mul_pow10(shift as usize), ChonkerInt::from(value as i128 * 10i128.pow(shift)));
+                assert_eq!(bigint.div_pow10(shift as usize), ChonkerInt::from(value / 10i64.pow(shift)));
+            }
+        }
+
+        // Zero shifted by anything stays zero.
+        assert_eq!(ChonkerInt::new().mul_pow10(50), ChonkerInt::new());
+        assert_eq!(ChonkerInt::new().div_pow10(50), ChonkerInt::new());
+
+        // Shifting right past all digits results in zero.
+        assert_eq!(ChonkerInt::from(-12345).div_pow10(5), ChonkerInt::new());
+        assert_eq!(ChonkerInt::from(-12345).div_pow10(4), ChonkerInt::from(-1));
+
+        // Big values against the string representation.
+        let bigint = ChonkerInt::new_rand(&100, &BigIntSign::Positive);
+        let bigint_string = bigint.to_string();
+        assert_eq!(bigint.mul_pow10(20), ChonkerInt::from(format!("{}{}", bigint_string, "0".repeat(20))));
+        assert_eq!(bigint.div_pow10(20), ChonkerInt::from(String::from(&bigint_string[..80])));
+    }
+}
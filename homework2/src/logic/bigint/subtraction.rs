@@ -44,11 +44,16 @@ impl<'a, 'b> Sub<&'b ChonkerInt> for &'a ChonkerInt {
 
         let mut result = ChonkerInt::new();
 
+        // Operate only on the significant digits, so the BigInt with the bigger magnitude
+        // is guaranteed to have the longer vector, even if leading zeros were left in the operands.
+        let self_digits = self.significant_digits();
+        let other_digits = other.significant_digits();
+
         let mut last_digit_underflow = 0;
         let mut self_offset = 0;
-        let self_length = self.digits.len();
+        let self_length = self_digits.len();
         let mut other_offset = 0;
-        let other_length = other.digits.len();
+        let other_length = other_digits.len();
 
         // Determine the longer/bigger BigInt and calculate subtraction and a sign.
         // If vector were of different lengths, finish operation on the digits of the longer vector/BigInt with a second loop.
@@ -56,8 +61,8 @@ impl<'a, 'b> Sub<&'b ChonkerInt> for &'a ChonkerInt {
             Ordering::Less => {
                 while self_offset < self_length {
                     subtract_digits(
-                        &other.digits,
-                        &self.digits,
+                        other_digits,
+                        self_digits,
                         &mut other_offset,
                         &mut self_offset,
                         &mut result,
@@ -66,7 +71,7 @@ impl<'a, 'b> Sub<&'b ChonkerInt> for &'a ChonkerInt {
                 }
                 while other_offset < other_length {
                     subtract_digit_and_underflow(
-                        &other.digits,
+                        other_digits,
                         &mut other_offset,
                         &mut result,
                         &mut last_digit_underflow,
@@ -81,8 +86,8 @@ impl<'a, 'b> Sub<&'b ChonkerInt> for &'a ChonkerInt {
             Ordering::Greater => {
                 while other_offset < other_length {
                     subtract_digits(
-                        &self.digits,
-                        &other.digits,
+                        self_digits,
+                        other_digits,
                         &mut self_offset,
                         &mut other_offset,
                         &mut result,
@@ -91,7 +96,7 @@ impl<'a, 'b> Sub<&'b ChonkerInt> for &'a ChonkerInt {
                 }
                 while self_offset < self_length {
                     subtract_digit_and_underflow(
-                        &self.digits,
+                        self_digits,
                         &mut self_offset,
                         &mut result,
                         &mut last_digit_underflow,
@@ -107,18 +112,18 @@ impl<'a, 'b> Sub<&'b ChonkerInt> for &'a ChonkerInt {
     }
 }
 
-// Subtract of two passed digits.
+// Subtract of two passed limbs.
 fn subtract_digits(
-    minuend_vec: &[i8],
-    subtrahend_vec: &[i8],
+    minuend_vec: &[u32],
+    subtrahend_vec: &[u32],
     minuend_offset: &mut usize,
     subtrahend_offset: &mut usize,
     result: &mut ChonkerInt,
-    last_digit_underflow: &mut i8,
+    last_digit_underflow: &mut i64,
 ) {
-    // Calculate sum of digits.
-    let mut difference = (*minuend_vec)[*minuend_offset]
-        - (*subtrahend_vec)[*subtrahend_offset]
+    // Calculate difference of limbs, widened to a signed type to detect the underflow.
+    let mut difference = (*minuend_vec)[*minuend_offset] as i64
+        - (*subtrahend_vec)[*subtrahend_offset] as i64
         - (*last_digit_underflow);
 
     // Nullify the underflow from previous operation after being used.
@@ -127,23 +132,23 @@ fn subtract_digits(
     // Check for the difference being negative, if it is, set underflow.
     if difference.is_negative() {
         *last_digit_underflow = 1;
-        difference += RADIX;
+        difference += RADIX as i64;
     }
 
-    let _ = result.push(difference);
+    let _ = result.push(difference as u32);
     *minuend_offset += 1;
     *subtrahend_offset += 1;
 }
 
-// Subtract of one passed digit and a result slot.
+// Subtract of one passed limb and a result slot.
 fn subtract_digit_and_underflow(
-    one_vec: &[i8],
+    one_vec: &[u32],
     one_offset: &mut usize,
     result: &mut ChonkerInt,
-    last_digit_underflow: &mut i8,
+    last_digit_underflow: &mut i64,
 ) {
-    // Calculate sum of digits.
-    let mut difference = (*one_vec)[*one_offset] - (*last_digit_underflow);
+    // Calculate difference of the limb and the underflow.
+    let mut difference = (*one_vec)[*one_offset] as i64 - (*last_digit_underflow);
 
     // Nullify the underflow from previous operation after being used.
     *last_digit_underflow = 0;
@@ -151,18 +156,23 @@ fn subtract_digit_and_underflow(
     // Check for the difference being negative, if it is, set underflow.
     if difference.is_negative() {
         *last_digit_underflow = 1;
-        difference += RADIX;
+        difference += RADIX as i64;
     }
 
-    let _ = result.push(difference);
+    let _ = result.push(difference as u32);
     *one_offset += 1;
 }
 
+// Implement subtraction "-" operator for owned BigInts and mixes of owned and borrowed ones.
+forward_binary_operator!(Sub, sub);
+
 // Test module.
 #[cfg(test)]
 mod tests {
+    use std::time::Instant;
+
     use crate::logic::bigint::subtraction::{subtract_digit_and_underflow, subtract_digits};
-    use crate::logic::bigint::ChonkerInt;
+    use crate::logic::bigint::{BigIntSign, ChonkerInt};
 
     // Test of BigInt's subtraction operation.
     #[test]
@@ -270,15 +280,15 @@ mod tests {
         );
     }
 
-    // Test subtraction of two digits.
+    // Test subtraction of two limbs.
     #[test]
     fn test_digits_subtraction() {
-        let one_vec1: Vec<i8> = vec![9];
-        let one_vec2: Vec<i8> = vec![2];
+        let one_vec1: Vec<u32> = vec![999_999_999];
+        let one_vec2: Vec<u32> = vec![2];
         let mut one_offset1 = 0;
         let mut one_offset2 = 0;
-        let other_vec1: Vec<i8> = vec![1];
-        let other_vec2: Vec<i8> = vec![5];
+        let other_vec1: Vec<u32> = vec![1];
+        let other_vec2: Vec<u32> = vec![5];
         let mut other_offset1 = 0;
         let mut other_offset2 = 0;
         let mut result1 = ChonkerInt::new();
@@ -303,24 +313,24 @@ mod tests {
             &mut last_digit_underflow2,
         );
 
-        // First test of subtraction, 9-1
-        assert_eq!((*result1.get_vec())[0], 8);
+        // First test of subtraction, 999999999 - 1
+        assert_eq!(result1.get_limbs()[0], 999_999_998);
         assert_eq!(last_digit_underflow1, 0);
         assert_eq!(one_offset1, 1);
         assert_eq!(other_offset1, 1);
 
-        // Second test of subtraction, 2-5
-        assert_eq!((*result2.get_vec())[0], 7);
+        // Second test of subtraction, 2 - 5, borrowing a whole limb
+        assert_eq!(result2.get_limbs()[0], 999_999_997);
         assert_eq!(last_digit_underflow2, 1);
         assert_eq!(one_offset2, 1);
         assert_eq!(other_offset2, 1);
     }
 
-    // Test subtraction of a digit with and underflow.
+    // Test subtraction of a limb with and underflow.
     #[test]
     fn test_digit_and_underflow_subtraction() {
-        let one_vec1: Vec<i8> = vec![9];
-        let one_vec2: Vec<i8> = vec![0];
+        let one_vec1: Vec<u32> = vec![999_999_999];
+        let one_vec2: Vec<u32> = vec![0];
         let mut one_offset1 = 0;
         let mut one_offset2 = 0;
         let mut result1 = ChonkerInt::new();
@@ -341,14 +351,98 @@ mod tests {
             &mut last_digit_underflow2,
         );
 
-        // First test of subtraction, 9 - 0
-        assert_eq!((*result1.get_vec())[0], 9);
+        // First test of subtraction, 999999999 - 0
+        assert_eq!(result1.get_limbs()[0], 999_999_999);
         assert_eq!(last_digit_underflow1, 0);
         assert_eq!(one_offset1, 1);
 
         // Second test of subtraction, 0 - 1
-        assert_eq!((*result2.get_vec())[0], 9);
+        assert_eq!(result2.get_limbs()[0], 999_999_999);
         assert_eq!(last_digit_underflow2, 1);
         assert_eq!(one_offset2, 1);
     }
+
+    // Test subtraction resulting in zero and in long chains of borrows, the results should be normalized.
+    #[test]
+    fn test_bigint_subtraction_normalization() {
+        // Subtraction of equal BigInts produces a zero with the zero sign.
+        for bigint in [ChonkerInt::from(123456789), ChonkerInt::from(-1_000_000_000), ChonkerInt::new_rand(&500, &BigIntSign::Positive)] {
+            let difference = &bigint - &bigint;
+            assert_eq!(difference, ChonkerInt::new());
+            assert_eq!(*difference.get_sign(), BigIntSign::Zero);
+            assert!(difference.get_limbs().is_empty());
+        }
+
+        // Borrow through all limbs: 10^1000 - 1 == 999...9.
+        let power_of_ten = ChonkerInt::from(1).mul_pow10(1000);
+        let nines = &power_of_ten - &ChonkerInt::from(1);
+        assert_eq!(nines.to_string(), "9".repeat(1000));
+        assert_eq!(nines.get_limbs().len(), 1000 / 9 + 1);
+
+        // Borrow leaving only the least significant limb: (10^1000 + 5) - 10^1000 == 5.
+        let difference = &(&power_of_ten + &ChonkerInt::from(5)) - &power_of_ten;
+        assert_eq!(difference, ChonkerInt::from(5));
+        assert_eq!(difference.get_limbs(), [5]);
+        let difference = &power_of_ten - &(&power_of_ten + &ChonkerInt::from(5));
+        assert_eq!(difference, ChonkerInt::from(-5));
+        assert_eq!(difference.get_limbs(), [5]);
+    }
+
+    // Test subtraction of two nearly equal long BigInts, which leaves many leading zeros to be normalized.
+    #[test]
+    fn test_bigint_subtraction_of_nearly_equal_long_bigints() {
+        let minuend = ChonkerInt::new_rand(&10_000, &BigIntSign::Positive);
+        let subtrahend = &minuend - &ChonkerInt::from(12345);
+
+        let difference = &minuend - &subtrahend;
+        assert_eq!(difference, ChonkerInt::from(12345));
+        assert_eq!(difference.get_limbs(), [12345]);
+        let difference = &subtrahend - &minuend;
+        assert_eq!(difference, ChonkerInt::from(-12345));
+        assert_eq!(difference.get_limbs(), [12345]);
+    }
+
+    // Test the coarse timing of the subtraction of two nearly equal long BigInts, the normalization should not take seconds.
+    // The wall-clock measurement is unreliable on a loaded machine, run with "cargo test --release -- --ignored".
+    #[test]
+    #[ignore = "timing comparison, unreliable on a loaded machine"]
+    fn test_bigint_subtraction_of_nearly_equal_long_bigints_timing() {
+        let minuend = ChonkerInt::new_rand(&10_000, &BigIntSign::Positive);
+        let subtrahend = &minuend - &ChonkerInt::from(12345);
+
+        let start = Instant::now();
+        for _ in 0..100 {
+            assert_eq!(&minuend - &subtrahend, ChonkerInt::from(12345));
+            assert_eq!(&subtrahend - &minuend, ChonkerInt::from(-12345));
+        }
+        let elapsed = start.elapsed();
+
+        println!("200 subtractions of nearly equal 10000-digit BigInts: {:?}", elapsed);
+        assert!(elapsed.as_secs() < 5);
+    }
+
+    // Test subtraction of owned BigInts and mixes of owned and borrowed ones.
+    #[test]
+    fn test_bigint_owned_subtraction() {
+        let target = ChonkerInt::from(-1234567);
+        let other = ChonkerInt::from(89);
+        let reference_result = &target - &other;
+
+        assert_eq!(ChonkerInt::from(-1234567) - ChonkerInt::from(89), reference_result);
+        assert_eq!(ChonkerInt::from(-1234567) - &other, reference_result);
+        assert_eq!(&target - ChonkerInt::from(89), reference_result);
+    }
+
+    // Test addition and subtraction of BigInts with leading zeros left in their vectors of digits.
+    #[test]
+    fn test_bigint_subtraction_with_leading_zeros() {
+        let mut leading_zeros_bigint = ChonkerInt::new();
+        leading_zeros_bigint.push_vec(&[1, 0, 0]);
+        let other = ChonkerInt::from(25);
+
+        assert_eq!(&leading_zeros_bigint - &other, ChonkerInt::from(-24));
+        assert_eq!(&other - &leading_zeros_bigint, ChonkerInt::from(24));
+        assert_eq!(&leading_zeros_bigint + &other, ChonkerInt::from(26));
+        assert_eq!(&other + &leading_zeros_bigint, ChonkerInt::from(26));
+    }
 }
@@ -0,0 +1,88 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// A token for the cancellation of the long running operations, e.g. the RSA bruteforce or the prime generation.
+// The clones of the token share the flag, thus the operation is cancelled from another thread with any of the clones.
+// The optional deadline cancels the operation, once the time is up.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    // Create a new token, which is cancelled only explicitly.
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    // Create a new token, which is cancelled explicitly or after the provided timeout from now.
+    pub fn with_timeout(timeout: Duration) -> CancellationToken {
+        CancellationToken {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            deadline: Instant::now().checked_add(timeout),
+        }
+    }
+
+    // Cancel the operations, which check this token or any of its clones.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    // Check if the token was cancelled or its deadline has passed.
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::SeqCst) {
+            return true;
+        }
+
+        match self.deadline {
+            Some(deadline) => Instant::now() >= deadline,
+            None => false,
+        }
+    }
+
+    // Get the deadline of the token, if there is one.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::logic::cancellation::CancellationToken;
+
+    // Test the explicit cancellation of a token and its clones.
+    #[test]
+    fn test_cancellation_token_cancel() {
+        let token = CancellationToken::new();
+        let token_clone = token.clone();
+        assert!(!token.is_cancelled());
+        assert!(!token_clone.is_cancelled());
+        assert_eq!(token.deadline(), None);
+
+        // Cancel the clone from another thread, the original token is cancelled as well.
+        thread::spawn(move || token_clone.cancel()).join().unwrap();
+        assert!(token.is_cancelled());
+    }
+
+    // Test the cancellation of a token after its timeout.
+    #[test]
+    fn test_cancellation_token_timeout() {
+        let token = CancellationToken::with_timeout(Duration::from_millis(50));
+        assert!(token.deadline().is_some());
+        assert!(!token.is_cancelled());
+
+        thread::sleep(Duration::from_millis(100));
+        assert!(token.is_cancelled());
+
+        // A token with a long timeout is still cancelled explicitly.
+        let token = CancellationToken::with_timeout(Duration::from_secs(3600));
+        token.cancel();
+        assert!(token.is_cancelled());
+    }
+}
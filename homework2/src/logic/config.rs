@@ -1,8 +1,43 @@
-use std::io::BufWriter;
+use std::fs;
+use std::io::{self, BufWriter, ErrorKind, Read};
+use std::path::Path;
 use std::str::from_utf8_unchecked;
 
+use crate::crypto::affine::{Affine, AffineKey, AFFINE_BYTE_MODULUS};
+use crate::crypto::caesar::{Caesar, CAESAR_BRUTEFORCE_TOP, CLASSIC_ALPHABET_LEN};
+use crate::crypto::rail_fence::{parse_rail_count, RailFence};
+use crate::crypto::symmetric::SymmetricCipher;
+use crate::crypto::vigenere::Vigenere;
+use crate::crypto::xor::Xor;
+use crate::crypto::diffie_hellman::{check_parameter_is_numeric, df_modp_group, DF_DERIVED_KEY_LEN};
+use crate::crypto::sha256::HKDF_SHA256_MAX_LEN;
+use crate::crypto::rsa::{
+    rsa, BruteforceStrategy, ExponentChoice, OaepParams, RsaKeyPair, RsaKeygenOptions, RsaPadding, RsaPrivateKey, RsaPublicKey, RsaRequest, RsaResult, MAX_KEY_SIZE, MIN_KEY_SIZE, MODULUS_BIT_SIZE,
+};
+use crate::encoding::{Encoding, HexCase};
+use crate::logic::bigint::ChonkerInt;
+use crate::logic::cancellation::CancellationToken;
 use crate::logic::error::OperationError;
-use crate::logic::output::print_help;
+use crate::logic::flags::{named_flags_to_args, option_flag_name, uses_named_flags};
+use crate::logic::json::JSON_FEATURE_REQUIRED;
+use crate::logic::output::{print_help, RESULT_FILE_NAME};
+
+// The flag argument of the RSA encryption and decryption for the raw bytes of the input files.
+const BINARY_FLAG: &str = "--binary";
+// The flag argument of the RSA decryption for the blinding of the private key operations against the timing attacks.
+const BLINDING_FLAG: &str = "--blinding";
+// The flag argument of the ciphertexts and the saved RSA key files in the armored format.
+const ARMOR_FLAG: &str = "--armor";
+// The flag argument of Caesar, Vigenere and affine for the classic mode, which shifts only the letters of the Latin alphabet.
+const ALPHA_FLAG: &str = "--alpha";
+// The flag argument of the Caesar bruteforce for the output of the candidates of all shifts.
+const ALL_FLAG: &str = "--all";
+// The prefix of the target of the symmetric ciphers, which is read from the file with the following path.
+const FILE_PREFIX: &str = "file:";
+// The target of the symmetric ciphers and of the RSA text encryption and decryption, which is read from the standard input.
+const STDIN_TARGET: &str = "-";
+// The length of the input file of Caesar or Vigenere in bytes, above which the file is streamed instead of being read into the memory.
+const SYMMETRIC_STREAM_THRESHOLD: u64 = 1024 * 1024;
 
 // Tool's configuration variants.
 #[derive(Debug, PartialEq, Eq)]
@@ -12,17 +47,27 @@ pub enum ConfigVariant {
     RSA(ConfigRSA),
 }
 
-// Tool's symmetric cipher configuration.
+// Tool's symmetric cipher configuration, the key is empty for the one-time pad encryption, which generates it,
+// and for the Caesar bruteforce, which outputs the amount of the best candidates or all of them for None.
+// The target is the bytes of the argument or of the input file, the result file is at the output path or the default one for None.
+// The large input file of Caesar or Vigenere is streamed chunk by chunk into the result file from the stream path, the target is empty then.
+// The ciphertext is encoded into the chosen encoding, the uppercase hex one for None, the decrypted ciphertext is hex or armored for None.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ConfigSymmetric {
     pub cipher: Cipher,
     pub mode: Mode,
     pub output: Output,
-    pub target: String,
+    pub target: Vec<u8>,
     pub key: String,
+    pub classic: bool,
+    pub candidates: Option<usize>,
+    pub output_path: Option<String>,
+    pub stream_path: Option<String>,
+    pub encoding: Option<Encoding>,
 }
 
-// Tool's Diffie-Hellman configuration.
+// Tool's Diffie-Hellman configuration, the multi-party exchange receives the secrets of all participants
+// instead of the secrets A and B, the discrete logarithm search receives the public value of a participant.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ConfigDF {
     pub cipher: Cipher,
@@ -32,53 +77,157 @@ pub struct ConfigDF {
     pub shared_base: Option<String>,
     pub secret_a: Option<String>,
     pub secret_b: Option<String>,
+    pub secrets: Vec<Option<String>>,
+    pub public_value: Option<String>,
+    pub key_length: usize,
 }
 
-// Tool's RSA configuration.
+// Tool's RSA configuration, the arguments of the operation are interpreted into the typed request.
+// The generated key pair is saved into the key directory, in the armored key files for the armored keys flag.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ConfigRSA {
     pub cipher: Cipher,
     pub mode: Mode,
     pub output: Output,
+    pub request: RsaRequest,
+    pub timeout_secs: Option<u64>,
+    pub key_dir: Option<String>,
+    pub armored_keys: bool,
+}
+
+// The string arguments of an RSA operation, as they are received from the command line.
+// In the key generation the key exponent is the choice of the public exponent and the key modulus is the bit size.
+// The output file streams the encryption or the decryption of the input file, named by the target, into it.
+// In the key generation from the primes the key exponent is the public exponent.
+// The blinding flag blinds the private key operations of the decryption, the encoding is the one of the ciphertext text,
+// the hex case is the case of the letters of the encrypted hex ciphertext, the armor flag selects the armored ciphertext.
+// In the common modulus attack the target and the key exponent are the first ciphertext and its public exponent,
+// the second target and the second exponent are the other ones.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RsaArgs {
     pub target: Option<String>,
     pub key_exponent: Option<String>,
     pub key_modulus: Option<String>,
     pub thread_count: Option<String>,
+    pub strategy: Option<String>,
+    pub padding: Option<String>,
+    pub encoding: Option<String>,
+    pub hex_case: Option<String>,
+    pub armor: bool,
+    pub key_file: Option<String>,
+    pub binary: bool,
+    pub blinding: bool,
+    pub output_file: Option<String>,
+    pub prime_p: Option<String>,
+    pub prime_q: Option<String>,
+    pub second_target: Option<String>,
+    pub second_exponent: Option<String>,
 }
 
 // Enumeration of the available ciphers for processing.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Cipher {
     Caesar,
     Vigenere,
+    Xor,
+    Otp,
+    Affine,
+    RailFence,
     DiffieHellman,
     RSA,
 }
 
+impl Cipher {
+    // Name of the cipher type on the command line.
+    pub fn command_name(&self) -> &'static str {
+        match self {
+            Cipher::Caesar => "caesar",
+            Cipher::Vigenere => "vigenere",
+            Cipher::Xor => "xor",
+            Cipher::Otp => "otp",
+            Cipher::Affine => "affine",
+            Cipher::RailFence => "railfence",
+            Cipher::DiffieHellman => "df",
+            Cipher::RSA => "rsa",
+        }
+    }
+
+    // The symmetric cipher over the bytes for the cipher type, the one-time pad, Diffie-Hellman and RSA have their own processing.
+    pub(crate) fn symmetric_cipher(&self) -> Option<Box<dyn SymmetricCipher>> {
+        match self {
+            Cipher::Caesar => Some(Box::new(Caesar)),
+            Cipher::Vigenere => Some(Box::new(Vigenere)),
+            Cipher::Xor => Some(Box::new(Xor)),
+            Cipher::Affine => Some(Box::new(Affine)),
+            Cipher::RailFence => Some(Box::new(RailFence)),
+            Cipher::Otp | Cipher::DiffieHellman | Cipher::RSA => None,
+        }
+    }
+}
+
 // Enumeration of the available encryption modes for processing.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
     Encode,
     Decode,
     Generate,
+    GenerateFromPrimes,
     Bruteforce,
+    CommonModulus,
+    MultiParty,
+    Crack,
+    Analyze,
+}
+
+impl Mode {
+    // Name of the encryption mode on the command line.
+    pub fn command_name(&self) -> &'static str {
+        match self {
+            Mode::Encode => "encrypt",
+            Mode::Decode => "decrypt",
+            Mode::Generate => "generate",
+            Mode::GenerateFromPrimes => "generate-from",
+            Mode::Bruteforce => "bruteforce",
+            Mode::CommonModulus => "common-modulus",
+            Mode::MultiParty => "multi",
+            Mode::Crack => "crack",
+            Mode::Analyze => "analyze",
+        }
+    }
 }
 
 // Enumeration of the available outputs modes for the produced result.
-#[derive(Debug, PartialEq, Eq)]
+// The JSON output prints the result as a single JSON document, it requires the serde feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Output {
     Console,
     File,
     Both,
+    Json,
 }
 
 impl ConfigVariant {
     // Create a new Config struct, filled with received arguments from the command line.
     // Accepted parameter is trait bound by the Iterator trait, but only Args iterator is expected to be received.
     // More generic solution was implemented for unit-tests, so the method could accept custom iterators.
+    // The target "-" is read from the standard input.
     pub fn new(args: impl Iterator<Item=String>) -> Result<ConfigVariant, Box<dyn std::error::Error>> {
+        ConfigVariant::new_with_stdin(args, io::stdin())
+    }
+
+    // Create a new Config struct as new() does, the target "-" is read from the provided reader in place of the standard input.
+    pub fn new_with_stdin(args: impl Iterator<Item=String>, mut stdin: impl Read) -> Result<ConfigVariant, Box<dyn std::error::Error>> {
         // Collect arguments and re-iterate them.
         let arg_vec: Vec<String> = args.collect();
+
+        // Translate the named flags into the positional arguments, their options are already separated.
+        let (arg_vec, named_options) = match uses_named_flags(&arg_vec) {
+            true => {
+                let named_args = named_flags_to_args(&arg_vec)?;
+                (named_args.positional, Some(named_args.options))
+            }
+            false => (arg_vec, None),
+        };
         let arg_iterator = arg_vec.iter();
 
         // Check for the help argument, if it is found, create a help message and return it.
@@ -103,29 +252,102 @@ impl ConfigVariant {
             }
         }
 
+        // Separate the optional "<name>=<value>" arguments of the RSA operations and the two-party Diffie-Hellman exchange,
+        // the rest of the arguments are positional.
+        // The RSA encryption and decryption separate only the padding, the encoding, the hex case, the armor, the binary and the blinding flags, since their texts may contain "=".
+        // The symmetric ciphers separate the output path, the ciphers over the bytes separate also the encoding, the hex case and the armor flag,
+        // Caesar, Vigenere and affine separate also the classic mode flag, the Caesar bruteforce separates the amount of the candidates.
+        // The texts of the other operations are never separated.
+        let option_prefixes: &[&str] = match (arg_vec.first().map(String::as_str), arg_vec.get(1).map(String::as_str)) {
+            (Some("caesar"), Some("bruteforce")) => &["top=", ALL_FLAG, ALPHA_FLAG, "out="],
+            (Some("caesar"), _) | (Some("vigenere"), _) | (Some("affine"), _) => &[ALPHA_FLAG, "out=", "encoding=", "hex-case=", ARMOR_FLAG],
+            (Some("xor"), _) | (Some("railfence"), _) => &["out=", "encoding=", "hex-case=", ARMOR_FLAG],
+            (Some("otp"), _) => &["out="],
+            (Some("rsa"), Some("generate")) | (Some("rsa"), Some("generate-from")) | (Some("rsa"), Some("bruteforce")) => &["timeout=", "e=", "padding=", "save=", ARMOR_FLAG, BINARY_FLAG, BLINDING_FLAG],
+            (Some("rsa"), Some("encrypt")) | (Some("rsa"), Some("decrypt")) => &["padding=", "encoding=", "hex-case=", ARMOR_FLAG, BINARY_FLAG, BLINDING_FLAG],
+            (Some("df"), Some("generate")) => &["key-length="],
+            _ => &[],
+        };
+        let (option_args, arg_vec): (Vec<String>, Vec<String>) = match named_options {
+            // The options of the named flags have to be the options of the operation.
+            Some(options) => {
+                if let Some(option) = options.iter().find(|option| !option_prefixes.iter().any(|prefix| option.starts_with(prefix))) {
+                    return Err(Box::new(OperationError::new(&format!("The flag \"{}\" is not supported by the \"{}\" operation.", option_flag_name(option), arg_vec[..2].join(" ")))));
+                }
+                (options, arg_vec)
+            }
+            None => arg_vec.into_iter().partition(|arg| option_prefixes.iter().any(|prefix| arg.starts_with(prefix))),
+        };
+
+        let timeout_secs = match option_value(&option_args, "timeout=")? {
+            None => None,
+            Some(value) => match value.parse::<u64>() {
+                Ok(seconds) if seconds > 0 => Some(seconds),
+                _ => return Err(Box::new(OperationError::new("Did not receive a correct value for the timeout. Correct values: \"timeout=<positive number of seconds>\"."))),
+            },
+        };
+        let public_exponent = option_value(&option_args, "e=")?.map(String::from);
+        let padding = option_value(&option_args, "padding=")?.map(String::from);
+        let key_dir = option_value(&option_args, "save=")?.map(String::from);
+        let binary = option_flag(&option_args, BINARY_FLAG)?;
+        let blinding = option_flag(&option_args, BLINDING_FLAG)?;
+        let classic = option_flag(&option_args, ALPHA_FLAG)?;
+        let output_path = option_value(&option_args, "out=")?.map(String::from);
+        let encoding = option_value(&option_args, "encoding=")?.map(String::from);
+        let hex_case = option_value(&option_args, "hex-case=")?.map(String::from);
+        let armor = option_flag(&option_args, ARMOR_FLAG)?;
+        let candidates = match (option_value(&option_args, "top=")?, option_flag(&option_args, ALL_FLAG)?) {
+            (None, false) => Some(CAESAR_BRUTEFORCE_TOP),
+            (None, true) => None,
+            (Some(_), true) => return Err(Box::new(OperationError::new("Received both the amount of the candidates and the \"--all\" flag, only one of them can be set."))),
+            (Some(value), false) => match value.parse::<usize>() {
+                Ok(amount) if amount > 0 => Some(amount),
+                _ => return Err(Box::new(OperationError::new("Did not receive a correct value for the amount of the candidates. Correct values: \"top=<positive number>\"."))),
+            },
+        };
+        let key_length = match option_value(&option_args, "key-length=")? {
+            None => DF_DERIVED_KEY_LEN,
+            Some(value) => match value.parse::<usize>() {
+                Ok(bytes) if (1..=HKDF_SHA256_MAX_LEN).contains(&bytes) => bytes,
+                _ => return Err(Box::new(OperationError::new(&format!("Did not receive a correct value for the length of the DF derived key. Correct values: \"key-length=<number of bytes>\" in the range 1-{}.", HKDF_SHA256_MAX_LEN)))),
+            },
+        };
+
         // Create a new iterator for a separate argument checking.
         let mut arg_iterator = arg_vec.iter();
 
-        // Determine cipher type to use, Caesar, Vigenere, RSA or Diffie-Hellman key exchange algorithm.
+        // Determine cipher type to use, Caesar, Vigenere, XOR, one-time pad, affine, rail fence, RSA or Diffie-Hellman key exchange algorithm.
         let cipher = match arg_iterator.next() {
             Some(arg) if arg.eq("caesar") => Cipher::Caesar,
             Some(arg) if arg.eq("vigenere") => Cipher::Vigenere,
+            Some(arg) if arg.eq("xor") => Cipher::Xor,
+            Some(arg) if arg.eq("otp") => Cipher::Otp,
+            Some(arg) if arg.eq("affine") => Cipher::Affine,
+            Some(arg) if arg.eq("railfence") => Cipher::RailFence,
             Some(arg) if arg.eq("df") => Cipher::DiffieHellman,
             Some(arg) if arg.eq("rsa") => Cipher::RSA,
-            _ => return Err(Box::new(OperationError::new("Did not receive an argument for the cipher type or it was incorrect. Correct values: \"caesar\" or \"vigenere\"."))),
+            _ => return Err(Box::new(OperationError::new("Did not receive an argument for the cipher type or it was incorrect. Correct values: \"caesar\", \"vigenere\", \"xor\", \"otp\", \"affine\", \"railfence\", \"df\" or \"rsa\"."))),
         };
 
         // Check if there is a correct amount of arguments.
         // Do not proceed with operations if there are none or an incorrect amount.
         // Define allowed amounts of arguments for DF and RSA.
-        let df_argument_counts = vec![3, 7];
-        let rsa_argument_counts = vec![3, 5, 6];
-        if arg_vec.len() != 5 && (cipher == Cipher::Caesar || cipher == Cipher::Vigenere) {
-            return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for processing. 5 arguments required for Caesar or Vigenere calculations.")));
-        } else if !df_argument_counts.contains(&arg_vec.len()) && cipher == Cipher::DiffieHellman {
-            return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for processing. 3 or 7 arguments required for Diffie-Hellman calculations.")));
+        let df_argument_counts = vec![3, 6, 7];
+        let rsa_argument_counts = vec![3, 4, 5, 6, 7, 8];
+        let caesar_bruteforce = cipher == Cipher::Caesar && arg_vec.get(1).map(String::as_str) == Some("bruteforce");
+        let vigenere_analysis = cipher == Cipher::Vigenere && arg_vec.get(1).map(String::as_str) == Some("analyze");
+        if caesar_bruteforce && arg_vec.len() != 4 {
+            return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for processing. 4 arguments required for the Caesar bruteforce.")));
+        } else if vigenere_analysis && arg_vec.len() != 4 {
+            return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for processing. 4 arguments required for the Vigenere analysis.")));
+        } else if !caesar_bruteforce && !vigenere_analysis && arg_vec.len() != 5 && (cipher == Cipher::Caesar || cipher == Cipher::Vigenere || cipher == Cipher::Xor || cipher == Cipher::Affine || cipher == Cipher::RailFence) {
+            return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for processing. 5 arguments required for Caesar, Vigenere, XOR, affine or rail fence calculations.")));
+        } else if cipher == Cipher::Otp && arg_vec.len() != 4 + usize::from(arg_vec.get(1).map(String::as_str) == Some("decrypt")) {
+            return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for processing. 4 arguments required for the one-time pad encryption, 5 arguments for the decryption.")));
+        } else if !df_argument_counts.contains(&arg_vec.len()) && !(arg_vec.len() > 7 && arg_vec[1] == "multi") && cipher == Cipher::DiffieHellman {
+            return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for processing. 3, 6 or 7 arguments required for Diffie-Hellman calculations, 7 or more for the multi-party Diffie-Hellman calculations.")));
         } else if !rsa_argument_counts.contains(&arg_vec.len()) && cipher == Cipher::RSA {
-            return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for processing. 3, 4, 6 arguments required for RSA calculations.")));
+            return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for processing. 3, 4, 5, 6, 7 or 8 arguments required for RSA calculations.")));
         }
 
         // Determine encryption mode to use, encryption or decryption.
@@ -133,21 +355,62 @@ impl ConfigVariant {
             Some(arg) if arg.eq("encrypt") => Mode::Encode,
             Some(arg) if arg.eq("decrypt") => Mode::Decode,
             Some(arg) if arg.eq("generate") => Mode::Generate,
+            Some(arg) if arg.eq("generate-from") => Mode::GenerateFromPrimes,
             Some(arg) if arg.eq("bruteforce") => Mode::Bruteforce,
-            _ => return Err(Box::new(OperationError::new("Did not receive an argument for the encryption mode or it was incorrect. Correct values: \"encrypt\", \"decrypt\", \"generate\" or \"bruteforce\"."))),
+            Some(arg) if arg.eq("common-modulus") => Mode::CommonModulus,
+            Some(arg) if arg.eq("multi") => Mode::MultiParty,
+            Some(arg) if arg.eq("crack") => Mode::Crack,
+            Some(arg) if arg.eq("analyze") => Mode::Analyze,
+            _ => return Err(Box::new(OperationError::new("Did not receive an argument for the encryption mode or it was incorrect. Correct values: \"encrypt\", \"decrypt\", \"generate\", \"generate-from\", \"bruteforce\", \"common-modulus\", \"multi\", \"crack\" or \"analyze\"."))),
         };
 
-        // Determine output mode to use, output result to the console, file or both.
+        // Determine output mode to use, output result to the console, file, both or as JSON.
         let output = match arg_iterator.next() {
             Some(arg) if arg.eq("console") => Output::Console,
             Some(arg) if arg.eq("file") => Output::File,
             Some(arg) if arg.eq("both") => Output::Both,
-            _ => return Err(Box::new(OperationError::new("Did not receive an argument for the output mode or it was incorrect. Correct values: \"console\", \"file\" or \"both\"."))),
+            Some(arg) if arg.eq("json") && cfg!(feature = "serde") => Output::Json,
+            Some(arg) if arg.eq("json") => return Err(Box::new(OperationError::new(JSON_FEATURE_REQUIRED))),
+            _ => return Err(Box::new(OperationError::new("Did not receive an argument for the output mode or it was incorrect. Correct values: \"console\", \"file\", \"both\" or \"json\"."))),
         };
 
+        // The public exponent is chosen only for the RSA key generation, the bruteforce receives it positionally.
+        if public_exponent.is_some() && mode != Mode::Generate {
+            return Err(Box::new(OperationError::new("The public exponent option is supported only for the RSA key generation.")));
+        }
+
+        // The key files are saved only by the RSA key generation.
+        if key_dir.is_some() && mode != Mode::Generate && mode != Mode::GenerateFromPrimes {
+            return Err(Box::new(OperationError::new("The option for saving of the key files is supported only for the RSA key generation.")));
+        }
+
+        // The armor is chosen for the ciphertexts of the encryption and decryption and for the key files of the RSA key generation,
+        // which are saved only into the key directory.
+        if armor && mode != Mode::Encode && mode != Mode::Decode && mode != Mode::Generate && mode != Mode::GenerateFromPrimes {
+            return Err(Box::new(OperationError::new("The armor option is supported only for the encryption and decryption and for the RSA key generation.")));
+        }
+        if armor && (mode == Mode::Generate || mode == Mode::GenerateFromPrimes) && key_dir.is_none() {
+            return Err(Box::new(OperationError::new("The armor option of the RSA key generation requires the \"save=<directory>\" option for the armored key files.")));
+        }
+
+        // The raw bytes of the files are processed only by the RSA encryption and decryption.
+        if binary && mode != Mode::Encode && mode != Mode::Decode {
+            return Err(Box::new(OperationError::new("The binary option is supported only for the RSA encryption and decryption.")));
+        }
+
+        // The private key operations are blinded only by the RSA decryption.
+        if blinding && mode != Mode::Decode {
+            return Err(Box::new(OperationError::new("The blinding option is supported only for the RSA decryption.")));
+        }
+
+        // The padding is chosen only for the RSA encryption and decryption.
+        if padding.is_some() && mode != Mode::Encode && mode != Mode::Decode {
+            return Err(Box::new(OperationError::new("The padding option is supported only for the RSA encryption and decryption.")));
+        }
+
         // Decide further argument retrieval based on the algorithm and mode requested.
         // After processing is complete, return the prepared config.
-        if cipher == Cipher::Caesar || cipher == Cipher::Vigenere {
+        if cipher == Cipher::Caesar || cipher == Cipher::Vigenere || cipher == Cipher::Xor || cipher == Cipher::Otp || cipher == Cipher::Affine || cipher == Cipher::RailFence {
 
             // Retrieve a plaintext or a ciphertext for processing.
             let target = match arg_iterator.next() {
@@ -159,8 +422,9 @@ impl ConfigVariant {
                 }
             };
 
-            // Retrieve a key for processing.
+            // Retrieve a key for processing, the one-time pad encryption generates it, the Caesar bruteforce and the Vigenere analysis search for it.
             let key = match arg_iterator.next() {
+                None if (cipher == Cipher::Otp && mode == Mode::Encode) || caesar_bruteforce || vigenere_analysis => String::new(),
                 Some(arg) => arg.clone(),
                 _ => {
                     return Err(Box::new(OperationError::new(
@@ -169,17 +433,125 @@ impl ConfigVariant {
                 }
             };
 
+            // The affine key "a,b" is split and checked before the calculations, a has to be invertible modulo 26 or 256.
+            if cipher == Cipher::Affine {
+                let modulus = if classic { u16::from(CLASSIC_ALPHABET_LEN) } else { AFFINE_BYTE_MODULUS };
+                if let Err(e) = AffineKey::parse(&key, modulus) {
+                    return Err(Box::new(OperationError::new(&format!("Received incorrect key for affine processing, {}", e))));
+                }
+            }
+
+            // The Caesar bruteforce and the Vigenere analysis work with the shifts of the bytes, the classic mode is not supported.
+            if caesar_bruteforce && classic {
+                return Err(Box::new(OperationError::new("The classic mode is not supported by the Caesar bruteforce.")));
+            }
+            if vigenere_analysis && classic {
+                return Err(Box::new(OperationError::new("The classic mode is not supported by the Vigenere analysis.")));
+            }
+
+            // The ciphertexts of the ciphers over the bytes are encoded into the chosen encoding, the classic mode produces a plain text,
+            // the key searches accept only the hex ciphertexts.
+            let encoding = ciphertext_encoding(encoding.as_deref(), parse_hex_case(hex_case.as_deref(), &mode)?, armor)?;
+            if encoding.is_some() && (classic || (mode != Mode::Encode && mode != Mode::Decode)) {
+                return Err(Box::new(OperationError::new("The encoding option is supported only for the encryption and decryption of Caesar, Vigenere, XOR, affine or rail fence cipher over the bytes.")));
+            }
+
+            // The amount of the rails is checked before the calculations.
+            if cipher == Cipher::RailFence {
+                if let Err(e) = parse_rail_count(&key) {
+                    return Err(Box::new(OperationError::new(&format!("Received incorrect key for rail fence processing, {}", e))));
+                }
+            }
+
+            // The result is saved into the output path only by the "file", "both" or "json" output modes, the JSON output saves its document.
+            match output_path.as_deref() {
+                Some(_) if output == Output::Console => return Err(Box::new(OperationError::new("The output path option is supported only for the \"file\", \"both\" or \"json\" output modes."))),
+                Some("") => return Err(Box::new(OperationError::new("Did not receive a correct value for the output path. Correct values: \"out=<path of the result file>\"."))),
+                _ => (),
+            }
+
+            // The target with the "file:" prefix is read from the file after the other arguments are checked, the target "-" from the standard input.
+            // The hex ciphertext of the file or the standard input may end with a line break, thus the surrounding whitespace is trimmed,
+            // the texts of the encryption and of the classic mode are processed as they are.
+            // The file above the threshold is streamed by Caesar or Vigenere into the result file instead, when only the file is the output.
+            // The streaming encodes the ciphertext only in plain uppercase hex, the other chosen encodings keep the file in the memory.
+            let streamable = matches!(cipher, Cipher::Caesar | Cipher::Vigenere) && matches!(mode, Mode::Encode | Mode::Decode) && !classic && output == Output::File && matches!(encoding, None | Some(Encoding::Hex(HexCase::Upper)));
+            let mut stream_path = None;
+            let target = match target.strip_prefix(FILE_PREFIX) {
+                Some(path) if streamable && target_file_len(path)? > SYMMETRIC_STREAM_THRESHOLD => {
+                    if is_same_file(path, output_path.as_deref().unwrap_or(RESULT_FILE_NAME)) {
+                        return Err(Box::new(OperationError::new(&format!("Did not receive a correct output path for the streaming of the input file, \"{}\" is the input file. Correct value is another file.", path))));
+                    }
+                    stream_path = Some(path.to_string());
+                    Vec::new()
+                }
+                Some(path) => trim_ciphertext(read_target_file(path)?, &mode, classic),
+                None if target == STDIN_TARGET => trim_ciphertext(read_stdin_target(&mut stdin)?, &mode, classic),
+                None => target.into_bytes(),
+            };
+
             let symmetric_config = ConfigSymmetric {
                 cipher,
                 mode,
                 output,
                 target,
                 key,
+                classic,
+                candidates,
+                output_path,
+                stream_path,
+                encoding,
             };
 
             return Ok(ConfigVariant::Symmetric(symmetric_config));
         } else if cipher == Cipher::DiffieHellman {
 
+            // The discrete logarithm search receives the shared prime, the shared base and the public value of a participant.
+            if mode == Mode::Crack {
+                if arg_vec.len() != 6 {
+                    return Err(Box::new(OperationError::new("Did not receive a correct amount of arguments for the DF discrete logarithm search. Correct values: the shared prime, the shared base and the public value, each of them is \"your own number\".")));
+                }
+
+                let df_config = ConfigDF {
+                    cipher,
+                    mode,
+                    output,
+                    shared_prime: arg_iterator.next().cloned(),
+                    shared_base: arg_iterator.next().cloned(),
+                    secret_a: None,
+                    secret_b: None,
+                    secrets: Vec::new(),
+                    public_value: arg_iterator.next().cloned(),
+                    key_length,
+                };
+
+                return Ok(ConfigVariant::DF(df_config));
+            }
+
+            // The multi-party exchange receives the shared prime, the shared base and the secrets of at least 2 participants,
+            // each of them is a number or "none" for a random value.
+            if mode == Mode::MultiParty {
+                if arg_vec.len() < 7 {
+                    return Err(Box::new(OperationError::new("Did not receive enough arguments for the multi-party DF calculation. Correct values: the shared prime, the shared base and at least 2 secrets, each of them is \"your own number\" or \"none\".")));
+                }
+
+                let mut values = arg_iterator.map(|arg| if arg.eq("none") { None } else { Some(arg.clone()) });
+                let df_config = ConfigDF {
+                    cipher,
+                    mode,
+                    output,
+                    shared_prime: values.next().flatten(),
+                    shared_base: values.next().flatten(),
+                    secret_a: None,
+                    secret_b: None,
+                    secrets: values.collect(),
+                    public_value: None,
+                    key_length,
+                };
+
+                return Ok(ConfigVariant::DF(df_config));
+            }
+
             // If there are no additional parameters, all of them will be randomised.
             if arg_vec.len() == 3 && arg_iterator.next() == None {
                 let df_config = ConfigDF {
@@ -190,6 +562,38 @@ impl ConfigVariant {
                     shared_base: None,
                     secret_a: None,
                     secret_b: None,
+                    secrets: Vec::new(),
+                    public_value: None,
+                    key_length,
+                };
+
+                return Ok(ConfigVariant::DF(df_config));
+            } else if arg_vec.len() == 6 {
+
+                // The secrets are followed by the name of the MODP group, which replaces the shared prime and the shared base.
+                let mut secrets = arg_iterator.by_ref().take(2).map(|arg| if arg.eq("none") { None } else { Some(arg.clone()) });
+                let (secret_a, secret_b) = (secrets.next().flatten(), secrets.next().flatten());
+
+                // Determine the MODP group.
+                let shared_prime = match arg_iterator.next() {
+                    Some(arg) => match df_modp_group(arg) {
+                        Ok(group) => Some(group.name.to_string()),
+                        Err(e) => return Err(Box::new(OperationError::new(&format!("Did not receive a correct argument for the DF group, {}", e)))),
+                    },
+                    None => return Err(Box::new(OperationError::new("Did not receive an argument for the DF group. Correct values: \"group5\", \"group14\", \"group15\" or \"group16\"."))),
+                };
+
+                let df_config = ConfigDF {
+                    cipher,
+                    mode,
+                    output,
+                    shared_prime,
+                    shared_base: None,
+                    secret_a,
+                    secret_b,
+                    secrets: Vec::new(),
+                    public_value: None,
+                    key_length,
                 };
 
                 return Ok(ConfigVariant::DF(df_config));
@@ -232,6 +636,9 @@ impl ConfigVariant {
                     shared_base,
                     secret_a,
                     secret_b,
+                    secrets: Vec::new(),
+                    public_value: None,
+                    key_length,
                 };
 
                 return Ok(ConfigVariant::DF(df_config));
@@ -240,163 +647,934 @@ impl ConfigVariant {
             }
         } else if cipher == Cipher::RSA {
 
-            // If there are no additional parameters, required ones will be randomised.
-            if arg_vec.len() == 3 && arg_iterator.next() == None && mode == Mode::Generate {
+            // Collect the string arguments of the requested operation, the options were already separated.
+            let mut rsa_args = RsaArgs {
+                key_exponent: public_exponent,
+                padding,
+                encoding,
+                hex_case,
+                armor,
+                binary,
+                blinding,
+                ..RsaArgs::default()
+            };
+
+            if (arg_vec.len() == 3 || arg_vec.len() == 4) && mode == Mode::Generate {
 
                 // If there are no additional parameters, required ones will be randomised.
-                let rsa_config = ConfigRSA {
-                    cipher,
-                    mode,
-                    output,
-                    target: None,
-                    key_exponent: None,
-                    key_modulus: None,
-                    thread_count: None,
-                };
-                return Ok(ConfigVariant::RSA(rsa_config));
-            } else if arg_vec.len() == 5 && mode == Mode::Bruteforce {
+                // The public exponent is 65537, unless "e=random" was requested.
+                // The optional parameter is the bit size of the key modulus, it is passed as the key modulus.
+                rsa_args.key_modulus = arg_iterator.next().cloned();
+            } else if arg_vec.len() == 8 && mode == Mode::CommonModulus {
+
+                // Determine RSA ciphertexts of the same message, their public exponents and the common modulus.
+                rsa_args.target = arg_iterator.next().cloned();
+                rsa_args.second_target = arg_iterator.next().cloned();
+                rsa_args.key_exponent = arg_iterator.next().cloned();
+                rsa_args.second_exponent = arg_iterator.next().cloned();
+                rsa_args.key_modulus = arg_iterator.next().cloned();
+            } else if arg_vec.len() == 6 && mode == Mode::GenerateFromPrimes {
+
+                // Determine RSA primes and public exponent of the key pair.
+                rsa_args.prime_p = arg_iterator.next().cloned();
+                rsa_args.prime_q = arg_iterator.next().cloned();
+                rsa_args.key_exponent = arg_iterator.next().cloned();
+            } else if (5..=7).contains(&arg_vec.len()) && mode == Mode::Bruteforce {
+
+                // Determine RSA exponent and modulus.
+                rsa_args.key_exponent = arg_iterator.next().cloned();
+                rsa_args.key_modulus = arg_iterator.next().cloned();
+
+                // Determine RSA thread count, "none" uses the default thread count, and the bruteforce strategy.
+                rsa_args.thread_count = arg_iterator.next().filter(|arg| arg.as_str() != "none").cloned();
+                rsa_args.strategy = arg_iterator.next().cloned();
+            } else if arg_vec.len() == 5 && (mode == Mode::Encode || mode == Mode::Decode) {
 
-                // Determine RSA exponent.
-                let key_exponent = match arg_iterator.next() {
-                    Some(arg) => Some(arg.clone()),
-                    _ => return Err(Box::new(OperationError::new("Did not receive an argument for the RSA exponent or it was incorrect. Correct values: \"your own positive number\"."))),
+                // Determine RSA target for encryption or decryption.
+                rsa_args.target = arg_iterator.next().cloned();
+
+                // Determine RSA key file in place of the exponent and the modulus,
+                // it is the path with the "file:" prefix or the path of an existing file.
+                rsa_args.key_file = match arg_iterator.next() {
+                    Some(arg) if arg.starts_with("file:") => Some(arg["file:".len()..].to_string()),
+                    Some(arg) if Path::new(arg).is_file() => Some(arg.clone()),
+                    _ => return Err(Box::new(OperationError::new("Did not receive an argument for the RSA key file or it was incorrect. Correct values: \"file:<path to the key file>\" or \"the path to an existing key file\"."))),
                 };
+            } else if arg_vec.len() == 6 && (mode == Mode::Encode || mode == Mode::Decode) {
 
-                // Determine RSA modulus.
-                let key_modulus = match arg_iterator.next() {
-                    Some(arg) => Some(arg.clone()),
-                    _ => return Err(Box::new(OperationError::new("Did not receive an argument for the RSA modulus or it was incorrect. Correct values: \"your own positive composite number\"."))),
-                };
+                // Determine RSA target for encryption or decryption, the exponent and the modulus.
+                rsa_args.target = arg_iterator.next().cloned();
+                rsa_args.key_exponent = arg_iterator.next().cloned();
+                rsa_args.key_modulus = arg_iterator.next().cloned();
+            } else if arg_vec.len() == 7 && (mode == Mode::Encode || mode == Mode::Decode) {
 
-                // Collect the config and send it off,
-                let rsa_config = ConfigRSA {
-                    cipher,
-                    mode,
-                    output,
-                    target: None,
-                    key_exponent,
-                    key_modulus,
-                    thread_count: None,
-                };
-                return Ok(ConfigVariant::RSA(rsa_config));
-            } else if arg_vec.len() == 6 && mode == Mode::Bruteforce {
+                // The streaming of the input file writes the result only into the output file, the JSON output reports it.
+                if output != Output::File && output != Output::Json {
+                    return Err(Box::new(OperationError::new("Did not receive a correct output mode for the RSA file streaming. Correct values: \"file\" or \"json\", the result is written into the output file.")));
+                }
 
-                // Determine RSA exponent.
-                let key_exponent = match arg_iterator.next() {
-                    Some(arg) => Some(arg.clone()),
-                    _ => return Err(Box::new(OperationError::new("Did not receive an argument for the RSA exponent or it was incorrect. Correct values: \"your own positive number\"."))),
-                };
+                // Determine RSA input and output files for encryption or decryption, the exponent and the modulus.
+                rsa_args.target = arg_iterator.next().cloned();
+                rsa_args.output_file = arg_iterator.next().cloned();
+                rsa_args.key_exponent = arg_iterator.next().cloned();
+                rsa_args.key_modulus = arg_iterator.next().cloned();
+            } else {
+                return Err(Box::new(OperationError::new("Error with RSA configuration logic.")));
+            }
 
-                // Determine RSA modulus.
-                let key_modulus = match arg_iterator.next() {
-                    Some(arg) => Some(arg.clone()),
-                    _ => return Err(Box::new(OperationError::new("Did not receive an argument for the RSA modulus or it was incorrect. Correct values: \"your own positive composite number\"."))),
+            // The target "-" of the text encryption or decryption is read from the standard input, as the text of the argument.
+            // The binary mode and the file streaming name the input file by the target.
+            let text_target = (mode == Mode::Encode || mode == Mode::Decode) && !binary && rsa_args.output_file.is_none();
+            if text_target && rsa_args.target.as_deref() == Some(STDIN_TARGET) {
+                let bytes = trim_ciphertext(read_stdin_target(&mut stdin)?, &mode, false);
+                rsa_args.target = match String::from_utf8(bytes) {
+                    Ok(text) => Some(text),
+                    Err(_) => return Err(Box::new(OperationError::new("The standard input of the RSA encryption or decryption is not a UTF-8 text, use the \"--binary\" option with an input file for the raw bytes."))),
                 };
+            }
 
-                // Determine RSA thread count.
-                let thread_count = match arg_iterator.next() {
-                    Some(arg) => Some(arg.clone()),
-                    _ => return Err(Box::new(OperationError::new("Did not receive an argument for the RSA thread count or it was incorrect. Correct values: \"your own positive number in the range of 1-64\"."))),
-                };
+            // Interpret the arguments into the typed request, collect the config and send it off.
+            let armored_keys = armor && (mode == Mode::Generate || mode == Mode::GenerateFromPrimes);
+            let rsa_config = ConfigRSA {
+                cipher,
+                request: rsa_request_from_args(&mode, rsa_args)?,
+                mode,
+                output,
+                timeout_secs,
+                key_dir,
+                armored_keys,
+            };
 
-                // Collect the config and send it off,
-                let rsa_config = ConfigRSA {
-                    cipher,
-                    mode,
-                    output,
-                    target: None,
-                    key_exponent,
-                    key_modulus,
-                    thread_count,
-                };
+            return Ok(ConfigVariant::RSA(rsa_config));
+        }
 
-                return Ok(ConfigVariant::RSA(rsa_config));
-            } else if arg_vec.len() == 6 && (mode == Mode::Encode || mode == Mode::Decode) {
+        Err(Box::new(OperationError::new("Error with the configuration logic.")))
+    }
+}
 
-                // Determine RSA target for encryption or decryption.
-                let target = match arg_iterator.next() {
-                    Some(arg) => Some(arg.clone()),
-                    _ => return Err(Box::new(OperationError::new("Did not receive an argument for the RSA target for encryption or encryption or it was incorrect. Correct values: \"your own text for encryption\" or \"the produced hex before for decryption\"."))),
-                };
+// Check if the arguments request the JSON output mode, so the errors of the configuration are reported as JSON as well.
+// The output mode is the value of the "--out" flag or the third positional argument, the tool without the serde feature has no JSON output.
+pub fn json_output_requested(args: &[String]) -> bool {
+    let output = match args.iter().position(|arg| arg == "--out") {
+        Some(index) => args.get(index + 1),
+        None => args.get(2),
+    };
+
+    cfg!(feature = "serde") && output.map(String::as_str) == Some("json")
+}
+
+// Interpret the string arguments of an RSA operation into the typed request, the required arguments depend on the mode:
+// the key generation accepts the optional public exponent choice and the bit size, passed as the exponent and the modulus,
+// the key generation from the primes requires both primes and the public exponent,
+// the encryption and the decryption require the target and the exponent with the modulus or the key file,
+// the target is the input file for the binary mode or the streaming into the output file,
+// the bruteforce requires the public exponent and the modulus, and accepts the optional thread count and strategy,
+// the common modulus attack requires both ciphertexts, both public exponents and the modulus.
+pub fn rsa_request_from_args(mode: &Mode, rsa_args: RsaArgs) -> Result<RsaRequest, Box<dyn std::error::Error>> {
+    match mode {
+        Mode::Generate => Ok(RsaRequest::Generate {
+            bits: rsa_key_size(rsa_args.key_modulus.as_deref())?,
+            options: rsa_keygen_options(rsa_args.key_exponent.as_deref())?,
+        }),
+        Mode::GenerateFromPrimes => Ok(RsaRequest::GenerateFromPrimes {
+            prime_p: rsa_number_from_arg(rsa_args.prime_p, "prime p", "key generation from the primes")?,
+            prime_q: rsa_number_from_arg(rsa_args.prime_q, "prime q", "key generation from the primes")?,
+            public_key_e: rsa_number_from_arg(rsa_args.key_exponent, "public exponent", "key generation from the primes")?,
+        }),
+        Mode::Encode => {
+            let message = match rsa_args.target {
+                Some(value) => value,
+                None => return Err(Box::new(OperationError::new("Did not receive a text for the RSA encryption. Correct value is a string."))),
+            };
+
+            let public = match rsa_args.key_file {
+                Some(ref key_file) => RsaKeyPair::load_public(Path::new(key_file))?,
+                None => {
+                    let (public_key_e, public_key_n) = rsa_key_from_args(rsa_args.key_exponent, rsa_args.key_modulus, "public exponent", "encryption")?;
+                    RsaPublicKey { public_key_n, public_key_e }
+                }
+            };
+            check_rsa_modulus(&public.public_key_n, "encryption", true)?;
+            let padding = rsa_padding(rsa_args.padding.as_deref())?;
+            let hex_case = parse_hex_case(rsa_args.hex_case.as_deref(), &Mode::Encode)?;
+            let encoding = rsa_encoding(rsa_args.encoding.as_deref(), hex_case, rsa_args.armor, rsa_args.binary || rsa_args.output_file.is_some())?;
+
+            // The streaming encryption reads the input file, named by the target, block by block into the output file.
+            if let Some(output) = rsa_args.output_file {
+                check_rsa_stream_args(&message, &output, rsa_args.binary)?;
+                return Ok(RsaRequest::EncryptStream { input: message, output, public, padding });
+            }
+
+            // The binary encryption reads the raw bytes of the input file, named by the target.
+            if rsa_args.binary {
+                return Ok(RsaRequest::EncryptBytes {
+                    data: read_rsa_input_file(&message, "encryption")?,
+                    public,
+                    padding,
+                });
+            }
+
+            Ok(RsaRequest::Encrypt { message, public, padding, encoding: encoding.unwrap_or(Encoding::Hex(HexCase::Upper)) })
+        }
+        Mode::Decode => {
+            let ciphertext = match rsa_args.target {
+                Some(value) => value,
+                None => return Err(Box::new(OperationError::new("Did not receive a ciphertext for the RSA decryption. Correct value is the hex string, produced by the RSA encryption of this tool."))),
+            };
+
+            let private = match rsa_args.key_file {
+                Some(ref key_file) => RsaKeyPair::load_private(Path::new(key_file))?,
+                None => {
+                    let (private_key_d, public_key_n) = rsa_key_from_args(rsa_args.key_exponent, rsa_args.key_modulus, "private exponent", "decryption")?;
+                    RsaPrivateKey { public_key_n, private_key_d, crt: None }
+                }
+            };
+            check_rsa_modulus(&private.public_key_n, "decryption", true)?;
+            let padding = rsa_padding(rsa_args.padding.as_deref())?;
+            let hex_case = parse_hex_case(rsa_args.hex_case.as_deref(), &Mode::Decode)?;
+            let encoding = rsa_encoding(rsa_args.encoding.as_deref(), hex_case, rsa_args.armor, rsa_args.binary || rsa_args.output_file.is_some())?;
+            let blinding = rsa_args.blinding;
+
+            // The public exponent of the blinding is derived from the primes of the private key file.
+            if blinding && private.crt.is_none() {
+                return Err(Box::new(OperationError::new("The blinding option requires the private key file with the primes p and q, the public exponent of the blinding is derived from them.")));
+            }
+
+            // The streaming decryption reads the ciphertext of the input file, named by the target, field by field into the output file.
+            if let Some(output) = rsa_args.output_file {
+                check_rsa_stream_args(&ciphertext, &output, rsa_args.binary)?;
+                return Ok(RsaRequest::DecryptStream { input: ciphertext, output, private, padding, blinding });
+            }
+
+            // The binary decryption reads the raw ciphertext of the input file, named by the target.
+            if rsa_args.binary {
+                return Ok(RsaRequest::DecryptBytes {
+                    ciphertext: read_rsa_input_file(&ciphertext, "decryption")?,
+                    private,
+                    padding,
+                    blinding,
+                });
+            }
+
+            Ok(RsaRequest::Decrypt { ciphertext, private, padding, blinding, encoding })
+        }
+        Mode::CommonModulus => {
+            let (first_ciphertext, second_ciphertext) = match (rsa_args.target, rsa_args.second_target) {
+                (Some(first_ciphertext), Some(second_ciphertext)) => (first_ciphertext, second_ciphertext),
+                _ => return Err(Box::new(OperationError::new("Did not receive two ciphertexts for the RSA common modulus attack. Correct values are the hex strings, produced by the RSA encryption of this tool."))),
+            };
+            let first_exponent = rsa_number_from_arg(rsa_args.key_exponent, "first public exponent", "common modulus attack")?;
+            let second_exponent = rsa_number_from_arg(rsa_args.second_exponent, "second public exponent", "common modulus attack")?;
+            let key_modulus = rsa_number_from_arg(rsa_args.key_modulus, "modulus", "common modulus attack")?;
+            check_rsa_modulus(&key_modulus, "common modulus attack", true)?;
+
+            Ok(RsaRequest::CommonModulusAttack { first_ciphertext, second_ciphertext, first_exponent, second_exponent, key_modulus })
+        }
+        Mode::Bruteforce => {
+            let (public_key_e, public_key_n) = rsa_key_from_args(rsa_args.key_exponent, rsa_args.key_modulus, "public exponent", "bruteforce")?;
+            check_rsa_modulus(&public_key_n, "bruteforce", false)?;
+
+            Ok(RsaRequest::Bruteforce {
+                public: RsaPublicKey { public_key_n, public_key_e },
+                threads: rsa_thread_count(rsa_args.thread_count.as_deref())?,
+                strategy: rsa_bruteforce_strategy(rsa_args.strategy.as_deref())?,
+            })
+        }
+        Mode::MultiParty => Err(Box::new(OperationError::new("The multi-party mode is supported only for the Diffie-Hellman calculations."))),
+        Mode::Crack => Err(Box::new(OperationError::new("The discrete logarithm search is supported only for the Diffie-Hellman calculations."))),
+        Mode::Analyze => Err(Box::new(OperationError::new("The analysis is supported only for the Vigenere cipher."))),
+    }
+}
+
+// Perform the RSA operation, described by the string arguments, the previous signature of the entry point.
+// The operation runs with the default strategy and padding, without the progress reports and the cancellation.
+#[deprecated(note = "create the RsaRequest with logic::config::rsa_request_from_args() and pass it to rsa()")]
+pub fn rsa_from_strings(
+    mode: &Mode,
+    target: Option<String>,
+    key_exponent: Option<String>,
+    key_modulus: Option<String>,
+    thread_count: Option<String>,
+) -> Result<RsaResult, Box<dyn std::error::Error>> {
+    let rsa_args = RsaArgs {
+        target,
+        key_exponent,
+        key_modulus,
+        thread_count,
+        ..RsaArgs::default()
+    };
+
+    rsa(rsa_request_from_args(mode, rsa_args)?, None, &CancellationToken::new())
+}
+
+// Check the input and the output files of the RSA streaming, the streaming always processes the raw bytes,
+// and the output file can not overwrite the input file, which is still being read.
+fn check_rsa_stream_args(input: &str, output: &str, binary: bool) -> Result<(), OperationError> {
+    if binary {
+        return Err(OperationError::new("The binary option is not supported for the RSA file streaming, the input file is always processed as the raw bytes."));
+    }
+
+    if is_same_file(input, output) {
+        return Err(OperationError::new(&format!("Did not receive a correct output file for the RSA file streaming, \"{}\" is the input file. Correct value is another file.", output)));
+    }
+
+    Ok(())
+}
+
+// Read the raw bytes of the input file for the binary RSA encryption or decryption.
+fn read_rsa_input_file(path: &str, operation: &str) -> Result<Vec<u8>, OperationError> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(bytes),
+        Err(e) => Err(OperationError::new(&format!("Failed to read the input file \"{}\" for the binary RSA {}: {}.", path, operation, e))),
+    }
+}
+
+// Parse the exponent and the modulus of the RSA operation into the BigInts, check if they are positive numbers.
+fn rsa_key_from_args(key_exponent: Option<String>, key_modulus: Option<String>, exponent_name: &str, operation: &str) -> Result<(ChonkerInt, ChonkerInt), OperationError> {
+    let exponent_error = |reason: &str| OperationError::new(&format!("Did not receive {} for the RSA {} for the {}. Correct value is a positive number.", reason, exponent_name, operation));
+    let key_exponent = match key_exponent.map(|value| value.parse::<ChonkerInt>()) {
+        Some(Ok(value)) if value.is_positive() => value,
+        Some(_) => return Err(exponent_error("a correct value")),
+        None => return Err(exponent_error("an argument")),
+    };
+
+    let modulus_error = |reason: &str| OperationError::new(&format!("Did not receive {} for the RSA modulus for the {}. Correct value is a positive composite number.", reason, operation));
+    let key_modulus = match key_modulus.map(|value| value.parse::<ChonkerInt>()) {
+        Some(Ok(value)) if value.is_positive() => value,
+        Some(_) => return Err(modulus_error("a correct value")),
+        None => return Err(modulus_error("an argument")),
+    };
+
+    Ok((key_exponent, key_modulus))
+}
+
+// Parse a number of the RSA operation into the BigInt, check if it is a positive number.
+fn rsa_number_from_arg(value: Option<String>, name: &str, operation: &str) -> Result<ChonkerInt, OperationError> {
+    let number_error = |reason: &str| OperationError::new(&format!("Did not receive {} for the RSA {} for the {}. Correct value is a positive number.", reason, name, operation));
+    match value.map(|value| value.parse::<ChonkerInt>()) {
+        Some(Ok(value)) if value.is_positive() => Ok(value),
+        Some(_) => Err(number_error("a correct value")),
+        None => Err(number_error("an argument")),
+    }
+}
+
+// Check if the RSA modulus is a composite number, for the encryption and the decryption
+// check if the modulus fits the block of at least 1 byte. For bruteforcing the length of the modulus is checked later.
+fn check_rsa_modulus(key_modulus: &ChonkerInt, operation: &str, requires_block: bool) -> Result<(), OperationError> {
+    if key_modulus.is_prime_probabilistic(Some(1)) {
+        return Err(OperationError::new(&format!("Did not receive a correct value for the RSA modulus for the {}. Correct value is a positive composite number.", operation)));
+    }
+
+    if requires_block && key_modulus.bit_length() <= 8 {
+        return Err(OperationError::new(&format!("Did not receive a correct value for the RSA modulus for the {}. Correct value is a positive composite number bigger than 256.", operation)));
+    }
+
+    Ok(())
+}
+
+// Parse the padding of the RSA encryption and decryption, the block mode of this tool by default.
+fn rsa_padding(padding: Option<&str>) -> Result<RsaPadding, OperationError> {
+    match padding {
+        None | Some("raw") => Ok(RsaPadding::Raw),
+        Some("pkcs1") => Ok(RsaPadding::Pkcs1v15),
+        Some("oaep") => Ok(RsaPadding::Oaep(OaepParams::default())),
+        Some(_) => Err(OperationError::new("Did not receive a correct value for the padding of the RSA encryption/decryption. Correct values are \"raw\" for the block mode of this tool, \"pkcs1\" for the PKCS#1 v1.5 padding or \"oaep\" for the OAEP padding with SHA-256. It can be omitted to use the block mode of this tool.")),
+    }
+}
+
+// Parse the case of the letters of the hex ciphertext, the case is chosen only for the encryption, the decryption accepts both cases.
+fn parse_hex_case(hex_case: Option<&str>, mode: &Mode) -> Result<Option<HexCase>, OperationError> {
+    let case = match hex_case {
+        None => return Ok(None),
+        Some("upper") => HexCase::Upper,
+        Some("lower") => HexCase::Lower,
+        Some(_) => return Err(OperationError::new("Did not receive a correct value for the case of the hex ciphertext. Correct values are \"upper\" or \"lower\". It can be omitted to encrypt into the uppercase hex.")),
+    };
+    if *mode != Mode::Encode {
+        return Err(OperationError::new("The hex case option is supported only for the encryption, the decryption accepts the hex ciphertexts in both cases."));
+    }
+
+    Ok(Some(case))
+}
+
+// Parse the encoding of the ciphertexts, None is the default hex encoding of the encryption and the decryption.
+// The chosen hex case selects the hex encoding of that case, unless another hex encoding is chosen.
+// The armor flag selects the armored ciphertext, which is not combined with the other encodings.
+fn ciphertext_encoding(encoding: Option<&str>, hex_case: Option<HexCase>, armor: bool) -> Result<Option<Encoding>, OperationError> {
+    if armor {
+        if encoding.is_some() || hex_case.is_some() {
+            return Err(OperationError::new("The armor option is not supported together with the encoding or the hex case options, the armored ciphertext is encoded in base64."));
+        }
+        return Ok(Some(Encoding::Armor));
+    }
+
+    let case = hex_case.unwrap_or(HexCase::Upper);
+    match encoding {
+        None => Ok(hex_case.map(Encoding::Hex)),
+        Some("base32") | Some("base64") if hex_case.is_some() => Err(OperationError::new("The hex case option is supported only for the hex encodings of the ciphertext.")),
+        Some("hex") => Ok(Some(Encoding::Hex(case))),
+        Some("hex-colon") => Ok(Some(Encoding::HexGrouped(':', case))),
+        Some("hex-dash") => Ok(Some(Encoding::HexGrouped('-', case))),
+        Some("hex-space") => Ok(Some(Encoding::HexGrouped(' ', case))),
+        Some("base32") => Ok(Some(Encoding::Base32)),
+        Some("base64") => Ok(Some(Encoding::Base64)),
+        Some(_) => Err(OperationError::new("Did not receive a correct value for the encoding of the ciphertext. Correct values are \"hex\", \"hex-colon\", \"hex-dash\" or \"hex-space\" for the bytes split by the separator, \"base32\" or \"base64\". It can be omitted to encrypt into hex and to decrypt the hex or the armored ciphertext.")),
+    }
+}
+
+// Parse the encoding of the RSA ciphertext, the binary encryption/decryption and the file streaming process the raw bytes.
+fn rsa_encoding(encoding: Option<&str>, hex_case: Option<HexCase>, armor: bool, raw_bytes: bool) -> Result<Option<Encoding>, OperationError> {
+    let encoding = ciphertext_encoding(encoding, hex_case, armor)?;
+    if encoding.is_some() && raw_bytes {
+        return Err(OperationError::new("The encoding, the hex case and the armor options are not supported for the binary RSA encryption/decryption or the RSA file streaming, the ciphertext is saved as the raw bytes."));
+    }
+
+    Ok(encoding)
+}
+
+// Parse the thread count of the RSA bruteforce, the range of the count is checked by the bruteforce.
+fn rsa_thread_count(thread_count: Option<&str>) -> Result<Option<usize>, OperationError> {
+    let thread_count = match thread_count {
+        None => return Ok(None),
+        Some(thread_count) => thread_count,
+    };
+
+    // Check if the thread count parameter is numeric and not too long.
+    if !check_parameter_is_numeric(thread_count) || thread_count.len() > 2 {
+        return Err(OperationError::new("Did not receive a correct value for the thread count for the RSA bruteforce. Correct value is a positive number in the range 1-64. It can be omitted, or \"none\" can be written instead to use the default value of 8."));
+    }
+
+    match thread_count.parse::<usize>() {
+        Ok(parsed_count) => Ok(Some(parsed_count)),
+        Err(_) => Err(OperationError::new("Did not receive a correct value for the thread count for the RSA bruteforce. Correct value is a positive number in the range 1-64.")),
+    }
+}
+
+// Parse the strategy of the RSA bruteforce, the trial division by default.
+fn rsa_bruteforce_strategy(strategy: Option<&str>) -> Result<BruteforceStrategy, OperationError> {
+    match strategy {
+        None | Some("trial") => Ok(BruteforceStrategy::TrialDivision),
+        Some("fermat") => Ok(BruteforceStrategy::Fermat),
+        Some("wiener") => Ok(BruteforceStrategy::Wiener),
+        Some(_) => Err(OperationError::new("Did not receive a correct value for the strategy of the RSA bruteforce. Correct values are \"trial\" for the trial division, \"fermat\" for the Fermat's factorization or \"wiener\" for the Wiener's attack. It can be omitted to use the trial division.")),
+    }
+}
+
+// Parse the choice of the public exponent for the RSA key generation: "65537" or "random", 65537 by default.
+fn rsa_keygen_options(exponent: Option<&str>) -> Result<RsaKeygenOptions, OperationError> {
+    let exponent = match exponent {
+        None | Some("65537") => ExponentChoice::Fixed65537,
+        Some("random") => ExponentChoice::Random,
+        Some(_) => return Err(OperationError::new("Did not receive a correct value for the public exponent for the RSA key generation. Correct values are \"65537\" or \"random\". It can be omitted to use 65537.")),
+    };
+
+    Ok(RsaKeygenOptions { exponent })
+}
+
+// Parse the requested bit size of the RSA modulus for the key generation, the default size is used, if it is absent.
+fn rsa_key_size(key_size: Option<&str>) -> Result<u64, OperationError> {
+    let key_size = match key_size {
+        None => return Ok(MODULUS_BIT_SIZE),
+        Some(key_size) => key_size,
+    };
+
+    match key_size.parse::<u64>() {
+        Ok(bits) if (MIN_KEY_SIZE..=MAX_KEY_SIZE).contains(&bits) => Ok(bits),
+        _ => Err(OperationError::new(&format!("Did not receive a correct value for the bit size of the RSA modulus for the key generation. Correct value is a number in the range {}-{}. It can be omitted to use the default value of {}.", MIN_KEY_SIZE, MAX_KEY_SIZE, MODULUS_BIT_SIZE))),
+    }
+}
+
+// Check if the optional flag argument is present, the flag can be set only once and does not accept a value.
+fn option_flag(option_args: &[String], flag: &str) -> Result<bool, OperationError> {
+    let mut flags = option_args.iter().filter(|arg| arg.starts_with(flag));
+
+    match (flags.next(), flags.next()) {
+        (None, _) => Ok(false),
+        (Some(arg), None) if arg == flag => Ok(true),
+        (Some(_), None) => Err(OperationError::new(&format!("Received an incorrect \"{}\" argument, the flag does not accept a value.", flag))),
+        (Some(_), Some(_)) => Err(OperationError::new(&format!("Received more than one \"{}\" argument, the flag can be set only once.", flag))),
+    }
+}
+
+// Check if the paths lead to the same file, the paths of the files, which do not exist yet, are compared as they are.
+fn is_same_file(input: &str, output: &str) -> bool {
+    match (fs::canonicalize(input), fs::canonicalize(output)) {
+        (Ok(input_path), Ok(output_path)) => input_path == output_path,
+        _ => input == output,
+    }
+}
+
+// Find the length of the target file of the symmetric ciphers, the missing, the unreadable and the empty files are denied as by read_target_file().
+fn target_file_len(path: &str) -> Result<u64, OperationError> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Err(OperationError::new(&format!("The input file \"{}\" does not exist.", path))),
+        Err(e) => return Err(OperationError::new(&format!("Failed to read the input file \"{}\": {}.", path, e))),
+    };
+
+    if !metadata.is_file() {
+        return Err(OperationError::new(&format!("Failed to read the input file \"{}\": it is not a file.", path)));
+    }
+    if metadata.len() == 0 {
+        return Err(OperationError::new(&format!("The input file \"{}\" is empty, there is nothing to process.", path)));
+    }
+
+    Ok(metadata.len())
+}
+
+// Read the bytes of the target file of the symmetric ciphers, the missing, the unreadable and the empty files are denied.
+fn read_target_file(path: &str) -> Result<Vec<u8>, OperationError> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Err(OperationError::new(&format!("The input file \"{}\" does not exist.", path))),
+        Err(e) => return Err(OperationError::new(&format!("Failed to read the input file \"{}\": {}.", path, e))),
+    };
+
+    if bytes.is_empty() {
+        return Err(OperationError::new(&format!("The input file \"{}\" is empty, there is nothing to process.", path)));
+    }
+
+    Ok(bytes)
+}
+
+// Read the bytes of the target from the standard input, the empty input is denied.
+fn read_stdin_target(stdin: &mut impl Read) -> Result<Vec<u8>, OperationError> {
+    let mut bytes = Vec::new();
+    if let Err(e) = stdin.read_to_end(&mut bytes) {
+        return Err(OperationError::new(&format!("Failed to read the target from the standard input: {}.", e)));
+    }
+
+    if bytes.is_empty() {
+        return Err(OperationError::new("The standard input is empty, there is nothing to process. Pipe the text into the tool or pass it as an argument instead of \"-\"."));
+    }
+
+    Ok(bytes)
+}
+
+// Trim the surrounding whitespace of the ciphertext, e.g. the line break at the end of the file,
+// the texts of the encryption and of the classic mode are kept as they are.
+fn trim_ciphertext(bytes: Vec<u8>, mode: &Mode, classic: bool) -> Vec<u8> {
+    if *mode == Mode::Encode || classic {
+        bytes
+    } else {
+        bytes.trim_ascii().to_vec()
+    }
+}
+
+// Find the value of the optional "<name>=<value>" argument by its prefix, the option can be set only once.
+fn option_value<'a>(option_args: &'a [String], prefix: &str) -> Result<Option<&'a str>, OperationError> {
+    let mut values = option_args.iter().filter_map(|arg| arg.strip_prefix(prefix));
+    let value = values.next();
+
+    if values.next().is_some() {
+        return Err(OperationError::new(&format!("Received more than one \"{}<value>\" argument, the option can be set only once.", prefix)));
+    }
+
+    Ok(value)
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use std::iter::empty;
+
+    use crate::crypto::rsa::{BruteforceStrategy, ExponentChoice, OaepParams, RsaKeyPair, RsaKeygenOptions, RsaPadding, RsaPrivateKey, RsaPublicKey, RsaRequest, MODULUS_BIT_SIZE};
+    use crate::encoding::{Encoding, HexCase};
+    use crate::logic::bigint::ChonkerInt;
+    use crate::logic::config::{json_output_requested, Cipher, ConfigRSA, ConfigVariant, Mode, Output};
+    use crate::logic::error::OperationError;
+
+    // Test creation of configuration with correct arguments for symmetric algorithms.
+    #[test]
+    fn test_symmetric_config_creation() {
+        // Test Caesar decryption.
+        let args_vec = vec!["caesar", "decrypt", "console", "4E626E6E624E6A62", "1"];
+        let args = args_vec.iter().map(|s| s.to_string());
+
+        let config = ConfigVariant::new(args);
+
+        // Check if config was not successfully created.
+        if let Err(e) = config {
+            panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation)", e);
+        }
+
+        let config = config.unwrap();
+
+        let config = match config {
+            ConfigVariant::Symmetric(symmetric_config) => symmetric_config,
+            ConfigVariant::DF(_) => panic!("    A symmetric configuration was expected, but received DF config. (test_config_creation)"),
+            ConfigVariant::RSA(_) => panic!("    A symmetric configuration was expected, but received RSA config. (test_config_creation)"),
+        };
+
+        let cipher = Cipher::Caesar;
+        let mode = Mode::Decode;
+        let output = Output::Console;
+
+        // Check equality of values between provided arguments and produced config's fields.
+        // Check encryption type, caesar/vigenere.
+        assert_eq!(config.cipher, cipher);
+        // Check encryption mode, encrypt/decrypt.
+        assert_eq!(config.mode, mode);
+        // Check output mode, console/file/both.
+        assert_eq!(config.output, output);
+        // Check target string.
+        assert_eq!(config.target, args_vec.get(3).unwrap().as_bytes());
+        // Check key.
+        assert_eq!(config.key, *args_vec.get(4).unwrap());
+        // Check the classic mode, it is off without the flag.
+        assert!(!config.classic);
+    }
+
+    // Test creation of configuration for the XOR cipher, it takes the same arguments as the other symmetric ciphers.
+    #[test]
+    fn test_xor_config_creation() {
+        let args_vec = vec!["xor", "encrypt", "both", "hello", "key"];
+        let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::Symmetric(symmetric_config)) => symmetric_config,
+            Ok(_) => panic!("    A symmetric configuration was expected. (test_xor_config_creation)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_xor_config_creation)", e),
+        };
+
+        assert_eq!(config.cipher, Cipher::Xor);
+        assert_eq!(config.mode, Mode::Encode);
+        assert_eq!(config.output, Output::Both);
+        assert_eq!((config.target.as_slice(), config.key.as_str()), (&b"hello"[..], "key"));
+        assert!(!config.classic);
+
+        // The classic mode is not separated for XOR, the flag is an extra argument.
+        assert!(ConfigVariant::new(["xor", "encrypt", "console", "hello", "key", "--alpha"].iter().map(|s| s.to_string())).is_err());
+    }
+
+    // Test creation of configuration for Caesar bruteforce: it receives no key, the amount of the candidates is 5 by default,
+    // set with "top=" or unlimited with "--all".
+    #[test]
+    fn test_caesar_bruteforce_config_creation() {
+        for (args_vec, candidates) in [
+            (vec!["caesar", "bruteforce", "console", "9BBEB5B5B8"], Some(5)),
+            (vec!["caesar", "bruteforce", "file", "top=12", "9BBEB5B5B8"], Some(12)),
+            (vec!["caesar", "bruteforce", "both", "9BBEB5B5B8", "--all"], None),
+        ] {
+            let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(ConfigVariant::Symmetric(symmetric_config)) => symmetric_config,
+                Ok(_) => panic!("    A symmetric configuration was expected. (test_caesar_bruteforce_config_creation)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_caesar_bruteforce_config_creation)", e),
+            };
+
+            assert_eq!(config.cipher, Cipher::Caesar);
+            assert_eq!(config.mode, Mode::Bruteforce);
+            assert_eq!((config.target.as_slice(), config.key.as_str()), (&b"9BBEB5B5B8"[..], ""));
+            assert_eq!(config.candidates, candidates);
+        }
+
+        // The key, the incorrect amounts of the candidates, both options or the classic mode are denied.
+        for args_vec in [
+            vec!["caesar", "bruteforce", "console", "9BBEB5B5B8", "3"],
+            vec!["caesar", "bruteforce", "console", "9BBEB5B5B8", "top=0"],
+            vec!["caesar", "bruteforce", "console", "9BBEB5B5B8", "top=many"],
+            vec!["caesar", "bruteforce", "console", "9BBEB5B5B8", "top=3", "--all"],
+            vec!["caesar", "bruteforce", "console", "9BBEB5B5B8", "--alpha"],
+            vec!["caesar", "encrypt", "console", "Text", "3", "--all"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
+
+    // Test creation of configuration for the Vigenere analysis: it receives no key, the classic mode or an extra argument are denied.
+    #[test]
+    fn test_vigenere_analysis_config_creation() {
+        let args_vec = vec!["vigenere", "analyze", "both", "9BBEB5B5B8"];
+        let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::Symmetric(symmetric_config)) => symmetric_config,
+            Ok(_) => panic!("    A symmetric configuration was expected. (test_vigenere_analysis_config_creation)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_vigenere_analysis_config_creation)", e),
+        };
+
+        assert_eq!(config.cipher, Cipher::Vigenere);
+        assert_eq!(config.mode, Mode::Analyze);
+        assert_eq!((config.target.as_slice(), config.key.as_str()), (&b"9BBEB5B5B8"[..], ""));
+
+        for args_vec in [
+            vec!["vigenere", "analyze", "console", "9BBEB5B5B8", "LEMON"],
+            vec!["vigenere", "analyze", "console", "9BBEB5B5B8", "--alpha"],
+            vec!["caesar", "analyze", "console", "9BBEB5B5B8"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
+
+    // Test creation of configuration for the one-time pad: the encryption receives no key, the decryption receives it.
+    #[test]
+    fn test_otp_config_creation() {
+        for (args_vec, mode, key) in [(vec!["otp", "encrypt", "console", "hello"], Mode::Encode, ""), (vec!["otp", "decrypt", "file", "696B", "0102"], Mode::Decode, "0102")] {
+            let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(ConfigVariant::Symmetric(symmetric_config)) => symmetric_config,
+                Ok(_) => panic!("    A symmetric configuration was expected. (test_otp_config_creation)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_otp_config_creation)", e),
+            };
+
+            assert_eq!(config.cipher, Cipher::Otp);
+            assert_eq!(config.mode, mode);
+            assert_eq!(config.target, args_vec[3].as_bytes());
+            assert_eq!(config.key, key);
+        }
+
+        // The encryption with a key, the decryption without it and the missing arguments are rejected.
+        for args_vec in [vec!["otp", "encrypt", "console", "hello", "key"], vec!["otp", "decrypt", "console", "696B"], vec!["otp"], vec!["df"]] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
+
+    // Test creation of configuration for the affine cipher: the key is checked against the modulus of the mode,
+    // a = 13 is invertible modulo 256, but not modulo 26 of the classic mode.
+    #[test]
+    fn test_affine_config_creation() {
+        for (args_vec, classic) in [(vec!["affine", "encrypt", "console", "AFFINE CIPHER", "5,8", "--alpha"], true), (vec!["affine", "decrypt", "console", "C8DC", "13,8"], false)] {
+            let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(ConfigVariant::Symmetric(symmetric_config)) => symmetric_config,
+                Ok(_) => panic!("    A symmetric configuration was expected. (test_affine_config_creation)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_affine_config_creation)", e),
+            };
+
+            assert_eq!(config.cipher, Cipher::Affine);
+            assert_eq!(config.classic, classic);
+            assert_eq!(config.key, args_vec[4]);
+        }
+
+        let error_message = |args_vec: Vec<&str>| match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(_) => panic!("    An error was expected for the affine key {}. (test_affine_config_creation)", args_vec[4]),
+            Err(e) => e.to_string(),
+        };
+        assert!(error_message(vec!["affine", "encrypt", "console", "AFFINE", "13,8", "--alpha"]).contains("a = 13 is not invertible modulo 26"));
+        assert!(error_message(vec!["affine", "encrypt", "console", "AFFINE", "2,8"]).contains("a = 2 is not invertible modulo 256"));
+        assert!(error_message(vec!["affine", "encrypt", "console", "AFFINE", "5"]).contains("does not consist of two values"));
+        assert!(error_message(vec!["affine", "encrypt", "console", "AFFINE", "5,b"]).contains("is not a whole number"));
+    }
+
+    // Test creation of configuration for the rail fence cipher, the key is the amount of the rails of at least 2.
+    #[test]
+    fn test_rail_fence_config_creation() {
+        let args_vec = vec!["railfence", "encrypt", "console", "WEAREDISCOVEREDFLEEATONCE", "3"];
+        let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::Symmetric(symmetric_config)) => symmetric_config,
+            Ok(_) => panic!("    A symmetric configuration was expected. (test_rail_fence_config_creation)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_rail_fence_config_creation)", e),
+        };
+        assert_eq!(config.cipher, Cipher::RailFence);
+        assert_eq!(config.key, "3");
+
+        for (key, message) in [("three", "is not a whole number"), ("1", "at least 2 rails"), ("-2", "is not a whole number")] {
+            match ConfigVariant::new(["railfence", "encrypt", "console", "WEAREDISCOVERED", key].iter().map(|s| s.to_string())) {
+                Ok(_) => panic!("    An error was expected for the rail fence key {}. (test_rail_fence_config_creation)", key),
+                Err(e) => assert!(e.to_string().contains(message)),
+            }
+        }
+    }
+
+    // Test creation of configuration with the target "-" read from the provided reader in place of the standard input:
+    // the bytes of the symmetric ciphers are kept as they are, the ciphertexts are trimmed, the RSA text is read as the target,
+    // the empty input and the RSA input, which is not a UTF-8 text, are denied.
+    #[test]
+    fn test_config_creation_stdin_target() {
+        for (args_vec, stdin, target) in [
+            (vec!["vigenere", "encrypt", "console", "-", "mykey"], &b"line one\n\xFF\x00"[..], &b"line one\n\xFF\x00"[..]),
+            (vec!["caesar", "decrypt", "file", "-", "3", "out=result.txt"], &b"4E62\r\n"[..], &b"4E62"[..]),
+            (vec!["caesar", "encrypt", "console", "-", "3", "--alpha"], &b" HELLO\n"[..], &b" HELLO\n"[..]),
+            (vec!["caesar", "bruteforce", "console", "-"], &b"4A4D4B\n"[..], &b"4A4D4B"[..]),
+        ] {
+            match ConfigVariant::new_with_stdin(args_vec.iter().map(|s| s.to_string()), stdin) {
+                Ok(ConfigVariant::Symmetric(symmetric_config)) => assert_eq!(symmetric_config.target, target),
+                Ok(_) => panic!("    A symmetric configuration was expected. (test_config_creation_stdin_target)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation_stdin_target)", e),
+            }
+        }
+
+        // The reader is not touched by the other targets.
+        let args_vec = vec!["xor", "encrypt", "console", "hello", "-"];
+        match ConfigVariant::new_with_stdin(args_vec.iter().map(|s| s.to_string()), &b""[..]) {
+            Ok(ConfigVariant::Symmetric(symmetric_config)) => assert_eq!(symmetric_config.key, "-"),
+            _ => panic!("    A symmetric configuration was expected. (test_config_creation_stdin_target)"),
+        }
 
-                // Determine RSA exponent.
-                let key_exponent = match arg_iterator.next() {
-                    Some(arg) => Some(arg.clone()),
-                    _ => return Err(Box::new(OperationError::new("Did not receive an argument for the RSA exponent or it was incorrect. Correct values: \"your own positive number\"."))),
-                };
+        let args_vec = vec!["rsa", "encrypt", "console", "-", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683"];
+        match ConfigVariant::new_with_stdin(args_vec.iter().map(|s| s.to_string()), &b"Piped RSA text\n"[..]) {
+            Ok(ConfigVariant::RSA(rsa_config)) => assert!(matches!(rsa_config.request, RsaRequest::Encrypt { ref message, .. } if message == "Piped RSA text\n")),
+            _ => panic!("    An RSA configuration was expected. (test_config_creation_stdin_target)"),
+        }
 
-                // Determine RSA modulus.
-                let key_modulus = match arg_iterator.next() {
-                    Some(arg) => Some(arg.clone()),
-                    _ => return Err(Box::new(OperationError::new("Did not receive an argument for the RSA modulus or it was incorrect. Correct values: \"your own positive composite number\"."))),
-                };
+        for (args_vec, stdin, message) in [
+            (vec!["caesar", "encrypt", "console", "-", "3"], &b""[..], "standard input is empty"),
+            (vec!["otp", "encrypt", "console", "-"], &b""[..], "standard input is empty"),
+            (vec!["rsa", "decrypt", "console", "-", "239227093839837965545527797083977554955436111", "503389953040597954843496152539898795547523683"], &b""[..], "standard input is empty"),
+            (vec!["rsa", "encrypt", "console", "-", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683"], &b"\xFF"[..], "not a UTF-8 text"),
+        ] {
+            match ConfigVariant::new_with_stdin(args_vec.iter().map(|s| s.to_string()), stdin) {
+                Ok(_) => panic!("    An error was expected for the standard input {:?}. (test_config_creation_stdin_target)", stdin),
+                Err(e) => assert!(e.to_string().contains(message), "{}", e),
+            }
+        }
+    }
 
-                // Collect the config and send it off,
-                let rsa_config = ConfigRSA {
-                    cipher,
-                    mode,
-                    output,
-                    target,
-                    key_exponent,
-                    key_modulus,
-                    thread_count: None,
-                };
+    // Test creation of configuration for the symmetric algorithms with the target read from the file and the output path:
+    // the hex ciphertext of the file is trimmed, the missing, the unreadable and the empty files are denied.
+    #[test]
+    fn test_symmetric_config_creation_file_target() {
+        let directory = std::env::temp_dir().join(format!("enc_config_file_target_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let text_path = directory.join("plaintext.txt");
+        let hex_path = directory.join("ciphertext.txt");
+        let empty_path = directory.join("empty.txt");
+        std::fs::write(&text_path, b"hello world\n").unwrap();
+        std::fs::write(&hex_path, b"4E626E6E624E6A62\n").unwrap();
+        std::fs::write(&empty_path, b"").unwrap();
+
+        for (mode, path, target) in [("encrypt", &text_path, &b"hello world\n"[..]), ("decrypt", &hex_path, &b"4E626E6E624E6A62"[..])] {
+            let args_vec = vec!["caesar".to_string(), mode.to_string(), "both".to_string(), format!("file:{}", path.display()), "1".to_string(), "out=result.txt".to_string()];
+            let config = match ConfigVariant::new(args_vec.into_iter()) {
+                Ok(ConfigVariant::Symmetric(symmetric_config)) => symmetric_config,
+                Ok(_) => panic!("    A symmetric configuration was expected. (test_symmetric_config_creation_file_target)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_symmetric_config_creation_file_target)", e),
+            };
+            assert_eq!(config.target, target);
+            assert_eq!(config.output_path.as_deref(), Some("result.txt"));
+        }
 
-                return Ok(ConfigVariant::RSA(rsa_config));
-            } else {
-                return Err(Box::new(OperationError::new("Error with RSA configuration logic.")));
+        let missing_path = directory.join("missing.txt");
+        for (path, message) in [(&missing_path, "does not exist"), (&directory, "Failed to read the input file"), (&empty_path, "is empty")] {
+            match ConfigVariant::new(vec!["xor".to_string(), "encrypt".to_string(), "file".to_string(), format!("file:{}", path.display()), "key".to_string()].into_iter()) {
+                Ok(_) => panic!("    An error was expected for the input file {}. (test_symmetric_config_creation_file_target)", path.display()),
+                Err(e) => assert!(e.to_string().contains(message)),
             }
         }
 
-        Err(Box::new(OperationError::new("Error with the configuration logic.")))
-    }
-}
+        // The input file above the threshold is streamed by Caesar or Vigenere into the file only, otherwise it is read into the memory.
+        let large_path = directory.join("large.bin");
+        std::fs::write(&large_path, vec![b'A'; 1024 * 1024 + 1]).unwrap();
+        for (cipher, output, streamed) in [("vigenere", "file", true), ("caesar", "file", true), ("caesar", "both", false), ("xor", "file", false)] {
+            let args_vec = vec![cipher.to_string(), "encrypt".to_string(), output.to_string(), format!("file:{}", large_path.display()), "1".to_string()];
+            let config = match ConfigVariant::new(args_vec.into_iter()) {
+                Ok(ConfigVariant::Symmetric(symmetric_config)) => symmetric_config,
+                Ok(_) => panic!("    A symmetric configuration was expected. (test_symmetric_config_creation_file_target)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_symmetric_config_creation_file_target)", e),
+            };
+            assert_eq!(config.stream_path.is_some(), streamed);
+            assert_eq!(config.target.is_empty(), streamed);
+        }
+        let args_vec = vec!["caesar".to_string(), "encrypt".to_string(), "file".to_string(), format!("file:{}", large_path.display()), "1".to_string(), format!("out={}", large_path.display())];
+        assert!(ConfigVariant::new(args_vec.into_iter()).unwrap_err().to_string().contains("is the input file"));
 
-// Test module.
-#[cfg(test)]
-mod tests {
-    use std::iter::empty;
+        // The output path is denied for the console output and without a value.
+        for args_vec in [vec!["xor", "encrypt", "console", "hello", "key", "out=result.txt"], vec!["xor", "encrypt", "file", "hello", "key", "out="]] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
 
-    use crate::logic::config::{Cipher, ConfigVariant, Mode, Output};
-    use crate::logic::error::OperationError;
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
 
-    // Test creation of configuration with correct arguments for symmetric algorithms.
+    // Test creation of configuration with the encoding of the ciphertexts for the symmetric algorithms over the bytes and RSA,
+    // the encoding is denied for the classic mode, the binary RSA mode and the other operations,
+    // the hex case reaches the hex encodings of the encryption and is denied for the decryption and the other encodings,
+    // the armor flag selects the armored ciphertexts and is denied together with the other encoding options.
     #[test]
-    fn test_symmetric_config_creation() {
-        // Test Caesar decryption.
-        let args_vec = vec!["caesar", "decrypt", "console", "4E626E6E624E6A62", "1"];
-        let args = args_vec.iter().map(|s| s.to_string());
+    fn test_config_creation_encoding() {
+        for (args_vec, encoding) in [
+            (vec!["caesar", "encrypt", "console", "hello", "3", "encoding=base64"], Some(Encoding::Base64)),
+            (vec!["xor", "decrypt", "console", "encoding=hex", "4E62", "key"], Some(Encoding::Hex(HexCase::Upper))),
+            (vec!["vigenere", "encrypt", "console", "hello", "key", "encoding=hex-colon"], Some(Encoding::HexGrouped(':', HexCase::Upper))),
+            (vec!["caesar", "encrypt", "console", "hello", "3", "hex-case=lower"], Some(Encoding::Hex(HexCase::Lower))),
+            (vec!["railfence", "encrypt", "console", "hex-case=upper", "hello", "2"], Some(Encoding::Hex(HexCase::Upper))),
+            (vec!["xor", "encrypt", "console", "hello", "key", "hex-case=lower", "encoding=hex-dash"], Some(Encoding::HexGrouped('-', HexCase::Lower))),
+            (vec!["affine", "decrypt", "console", "NBSWY3DP", "5,8", "encoding=base32"], Some(Encoding::Base32)),
+            (vec!["railfence", "decrypt", "console", "Zm9v", "2"], None),
+            (vec!["vigenere", "encrypt", "console", "hello", "--armor", "key"], Some(Encoding::Armor)),
+            (vec!["xor", "decrypt", "console", "-----BEGIN CIPHERTEXT-----\nJgAV\n-----END CIPHERTEXT-----", "key", "--armor"], Some(Encoding::Armor)),
+        ] {
+            match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(ConfigVariant::Symmetric(symmetric_config)) => assert_eq!(symmetric_config.encoding, encoding),
+                Ok(_) => panic!("    A symmetric configuration was expected. (test_config_creation_encoding)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation_encoding)", e),
+            }
+        }
 
-        let config = ConfigVariant::new(args);
+        let args_vec = vec!["rsa", "decrypt", "console", "Zm9v", "239227093839837965545527797083977554955436111", "503389953040597954843496152539898795547523683", "encoding=base64"];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(rsa_config)) => assert!(matches!(rsa_config.request, RsaRequest::Decrypt { encoding: Some(Encoding::Base64), .. })),
+            Ok(_) => panic!("    An RSA configuration was expected. (test_config_creation_encoding)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation_encoding)", e),
+        }
 
-        // Check if config was not successfully created.
-        if let Err(e) = config {
-            panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation)", e);
+        let args_vec = vec!["rsa", "encrypt", "console", "hello", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "hex-case=lower"];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(rsa_config)) => assert!(matches!(rsa_config.request, RsaRequest::Encrypt { encoding: Encoding::Hex(HexCase::Lower), .. })),
+            Ok(_) => panic!("    An RSA configuration was expected. (test_config_creation_encoding)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation_encoding)", e),
         }
 
-        let config = config.unwrap();
+        let args_vec = vec!["rsa", "encrypt", "console", "hello", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "--armor"];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(rsa_config)) => assert!(matches!(rsa_config.request, RsaRequest::Encrypt { encoding: Encoding::Armor, .. }) && !rsa_config.armored_keys),
+            Ok(_) => panic!("    An RSA configuration was expected. (test_config_creation_encoding)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation_encoding)", e),
+        }
 
-        let config = match config {
-            ConfigVariant::Symmetric(symmetric_config) => symmetric_config,
-            ConfigVariant::DF(_) => panic!("    A symmetric configuration was expected, but received DF config. (test_config_creation)"),
-            ConfigVariant::RSA(_) => panic!("    A symmetric configuration was expected, but received RSA config. (test_config_creation)"),
-        };
+        // The armor of the key generation saves the armored key files.
+        let args_vec = vec!["rsa", "generate", "console", "--armor", "save=keys"];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(rsa_config)) => assert!(rsa_config.armored_keys),
+            Ok(_) => panic!("    An RSA configuration was expected. (test_config_creation_encoding)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation_encoding)", e),
+        }
 
-        let cipher = Cipher::Caesar;
-        let mode = Mode::Decode;
-        let output = Output::Console;
+        for args_vec in [
+            vec!["caesar", "encrypt", "console", "hello", "3", "encoding=base16"],
+            vec!["caesar", "encrypt", "console", "hello", "3", "encoding=hex-"],
+            vec!["caesar", "encrypt", "console", "hello", "3", "encoding=hex", "encoding=base64"],
+            vec!["caesar", "encrypt", "console", "hello", "3", "hex-case=mixed"],
+            vec!["caesar", "encrypt", "console", "hello", "3", "hex-case=lower", "encoding=base64"],
+            vec!["caesar", "decrypt", "console", "4E62", "3", "hex-case=lower"],
+            vec!["vigenere", "encrypt", "console", "HELLO", "KEY", "--alpha", "hex-case=lower"],
+            vec!["rsa", "decrypt", "console", "4E62", "239227093839837965545527797083977554955436111", "503389953040597954843496152539898795547523683", "hex-case=upper"],
+            vec!["caesar", "encrypt", "console", "HELLO", "3", "--alpha", "encoding=base64"],
+            vec!["caesar", "bruteforce", "console", "4E62", "encoding=base64"],
+            vec!["vigenere", "analyze", "console", "4E62", "encoding=base64"],
+            vec!["otp", "encrypt", "console", "hello", "encoding=base64"],
+            vec!["rsa", "encrypt", "console", "Cargo.toml", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "--binary", "encoding=base64"],
+            vec!["caesar", "encrypt", "console", "hello", "3", "--armor", "encoding=base64"],
+            vec!["caesar", "encrypt", "console", "hello", "3", "--armor", "hex-case=lower"],
+            vec!["caesar", "encrypt", "console", "hello", "3", "--armor", "--armor"],
+            vec!["caesar", "encrypt", "console", "hello", "3", "--armor=yes"],
+            vec!["caesar", "encrypt", "console", "HELLO", "3", "--alpha", "--armor"],
+            vec!["rsa", "encrypt", "console", "Cargo.toml", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "--binary", "--armor"],
+            vec!["rsa", "generate", "console", "--armor"],
+            vec!["rsa", "bruteforce", "console", "12", "19784619", "--armor"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
 
-        // Check equality of values between provided arguments and produced config's fields.
-        // Check encryption type, caesar/vigenere.
-        assert_eq!(config.cipher, cipher);
-        // Check encryption mode, encrypt/decrypt.
-        assert_eq!(config.mode, mode);
-        // Check output mode, console/file/both.
-        assert_eq!(config.output, output);
-        // Check target string.
-        assert_eq!(config.target, *args_vec.get(3).unwrap());
-        // Check key.
-        assert_eq!(config.key, *args_vec.get(4).unwrap());
+    // Test creation of configuration for the classic mode of the symmetric algorithms: the flag at any position
+    // turns it on, the flag with a value or the repeated flag are denied.
+    #[test]
+    fn test_symmetric_config_creation_classic() {
+        for (args_vec, target) in [
+            (vec!["caesar", "encrypt", "console", "ATTACK AT DAWN", "3", "--alpha"], "ATTACK AT DAWN"),
+            (vec!["vigenere", "--alpha", "decrypt", "console", "LXFOPV EF RNHR", "LEMON"], "LXFOPV EF RNHR"),
+        ] {
+            let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(ConfigVariant::Symmetric(symmetric_config)) => symmetric_config,
+                Ok(_) => panic!("    A symmetric configuration was expected. (test_symmetric_config_creation_classic)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_symmetric_config_creation_classic)", e),
+            };
+
+            assert!(config.classic);
+            assert_eq!(config.target, target.as_bytes());
+        }
+
+        for args_vec in [
+            vec!["caesar", "encrypt", "console", "ATTACK AT DAWN", "3", "--alpha=yes"],
+            vec!["caesar", "encrypt", "console", "ATTACK AT DAWN", "3", "--alpha", "--alpha"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
     }
 
     // Test creation of configuration with correct arguments for Diffie-Hellman algorithm,
@@ -543,6 +1721,119 @@ mod tests {
         assert_eq!(config.secret_b, secret_b);
     }
 
+    // Test creation of configuration with correct arguments for Diffie-Hellman algorithm over the MODP group,
+    // the group replaces the shared prime and the shared base.
+    #[test]
+    fn test_df_config_creation_modp_group() {
+        let args_vec = vec!["df", "generate", "console", "12345", "none", "group14"];
+        let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::DF(df_config)) => df_config,
+            Ok(_) => panic!("    A DF configuration was expected, but received another one. (test_df_config_creation_modp_group)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_df_config_creation_modp_group)", e),
+        };
+
+        assert_eq!(config.mode, Mode::Generate);
+        assert_eq!(config.shared_prime, Some(String::from("group14")));
+        assert_eq!(config.shared_base, None);
+        assert_eq!(config.secret_a, Some(String::from("12345")));
+        assert_eq!(config.secret_b, None);
+
+        // The unknown group is rejected with the list of the correct groups.
+        let args_vec = vec!["df", "generate", "console", "none", "none", "group1"];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Err(e) => assert!(e.to_string().contains("\"group5\", \"group14\", \"group15\", \"group16\"")),
+            Ok(_) => panic!("    An error was expected for the unknown DF group. (test_df_config_creation_modp_group)"),
+        }
+    }
+
+    // Test creation of configuration for the discrete logarithm search of the Diffie-Hellman secret.
+    #[test]
+    fn test_df_config_creation_crack() {
+        let args_vec = vec!["df", "crack", "console", "10000000019", "2", "6560363120"];
+        let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::DF(df_config)) => df_config,
+            Ok(_) => panic!("    A DF configuration was expected, but received another one. (test_df_config_creation_crack)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_df_config_creation_crack)", e),
+        };
+
+        assert_eq!(config.mode, Mode::Crack);
+        assert_eq!(config.shared_prime, Some(String::from("10000000019")));
+        assert_eq!(config.shared_base, Some(String::from("2")));
+        assert_eq!(config.public_value, Some(String::from("6560363120")));
+
+        // The missing public value, the extra argument and the search of the other ciphers are rejected.
+        for args_vec in [
+            vec!["df", "crack", "console", "10000000019", "2", "6560363120", "none"],
+            vec!["df", "crack", "console"],
+            vec!["rsa", "crack", "console", "10000000019", "2", "6560363120"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
+
+    // Test creation of configuration for Diffie-Hellman algorithm with the length of the derived key,
+    // the option is accepted in any position and defaults to 32 bytes.
+    #[test]
+    fn test_df_config_creation_key_length() {
+        for (args_vec, key_length) in [
+            (vec!["df", "generate", "console"], 32),
+            (vec!["df", "generate", "console", "key-length=16"], 16),
+            (vec!["df", "generate", "console", "none", "none", "group14", "key-length=64"], 64),
+            (vec!["df", "generate", "console", "key-length=8160", "13", "7", "none", "none"], 8160),
+        ] {
+            match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(ConfigVariant::DF(df_config)) => assert_eq!(df_config.key_length, key_length),
+                _ => panic!("    A DF configuration was expected for the arguments {:?}. (test_df_config_creation_key_length)", args_vec),
+            }
+        }
+
+        // The zero, too long, not numeric and repeated lengths are rejected.
+        for args_vec in [
+            vec!["df", "generate", "console", "key-length=0"],
+            vec!["df", "generate", "console", "key-length=8161"],
+            vec!["df", "generate", "console", "key-length=abc"],
+            vec!["df", "generate", "console", "key-length=16", "key-length=32"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
+
+    // Test creation of configuration with correct arguments for the multi-party Diffie-Hellman algorithm,
+    // with the secrets of any amount of participants.
+    #[test]
+    fn test_df_config_creation_multi_party() {
+        let args_vec = vec!["df", "multi", "console", "2147483647", "none", "3", "none", "7", "11", "none"];
+        let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::DF(df_config)) => df_config,
+            Ok(_) => panic!("    A DF configuration was expected, but received another one. (test_df_config_creation_multi_party)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_df_config_creation_multi_party)", e),
+        };
+
+        assert_eq!(config.cipher, Cipher::DiffieHellman);
+        assert_eq!(config.mode, Mode::MultiParty);
+        assert_eq!(config.shared_prime, Some(String::from("2147483647")));
+        assert_eq!(config.shared_base, None);
+        assert_eq!(config.secrets, vec![Some(String::from("3")), None, Some(String::from("7")), Some(String::from("11")), None]);
+        assert_eq!((config.secret_a, config.secret_b), (None, None));
+
+        // The two-party exchange has no list of the secrets.
+        let args_vec = vec!["df", "generate", "console", "5", "2", "1", "3"];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::DF(df_config)) => assert!(df_config.secrets.is_empty()),
+            _ => panic!("    A DF configuration was expected. (test_df_config_creation_multi_party)"),
+        }
+
+        // A single secret, no parameters, the two-party exchange with too many secrets and the multi-party mode of the other ciphers are rejected.
+        for args_vec in [
+            vec!["df", "multi", "console", "none", "none", "none"],
+            vec!["df", "multi", "console"],
+            vec!["df", "generate", "console", "none", "none", "none", "none", "none"],
+            vec!["rsa", "multi", "console", "none", "none", "none", "none"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
+
     // Test creation of configuration with more incorrect arguments for Diffie-Hellman algorithm.
     #[test]
     #[should_panic]
@@ -601,26 +1892,20 @@ mod tests {
         let cipher = Cipher::RSA;
         let mode = Mode::Generate;
         let output = Output::Console;
-        let target = None;
-        let key_exponent = None;
-        let key_modulus = None;
-        let thread_count = None;
+        let request = RsaRequest::Generate {
+            bits: MODULUS_BIT_SIZE,
+            options: RsaKeygenOptions::default(),
+        };
 
         // Check equality of values between provided arguments and produced config's fields.
-        // Check encryption type, DF.
+        // Check encryption type, RSA.
         assert_eq!(config.cipher, cipher);
-        // Check encryption mode, does not matter for the DF.
+        // Check encryption mode.
         assert_eq!(config.mode, mode);
         // Check output mode, console/file/both.
         assert_eq!(config.output, output);
-        // Check shared the target for encryption/decryption.
-        assert_eq!(config.target, target);
-        // Check the RSA exponent for encryption/decryption.
-        assert_eq!(config.key_exponent, key_exponent);
-        // Check the RSA modulus.
-        assert_eq!(config.key_modulus, key_modulus);
-        // Check the thread count.
-        assert_eq!(config.thread_count, thread_count);
+        // Check the request, the default key size and the public exponent 65537.
+        assert_eq!(config.request, request);
     }
 
     // Test creation of configuration with incorrect arguments for RSA algorithm,
@@ -696,7 +1981,7 @@ mod tests {
     #[test]
     fn test_rsa_encrypt_config_creation_correct_args() {
         // Test RSA algorithm with random algorithm without providing args.
-        let args_vec = vec!["rsa", "encrypt", "console", "target", "exponent", "modulus"];
+        let args_vec = vec!["rsa", "encrypt", "console", "target", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683"];
         let args = args_vec.iter().map(|s| s.to_string());
 
         let config = ConfigVariant::new(args);
@@ -717,26 +2002,274 @@ mod tests {
         let cipher = Cipher::RSA;
         let mode = Mode::Encode;
         let output = Output::Console;
-        let target = Some(String::from("target"));
-        let key_exponent = Some(String::from("exponent"));
-        let key_modulus = Some(String::from("modulus"));
-        let thread_count = None;
+        let request = RsaRequest::Encrypt {
+            message: String::from("target"),
+            public: RsaPublicKey {
+                public_key_n: ChonkerInt::from(String::from("503389953040597954843496152539898795547523683")),
+                public_key_e: ChonkerInt::from(String::from("9683922000451682283955009414215846271")),
+            },
+            // The block mode of the tool is used by default, the ciphertext is encoded in hex.
+            padding: RsaPadding::Raw,
+            encoding: Encoding::Hex(HexCase::Upper),
+        };
 
         // Check equality of values between provided arguments and produced config's fields.
-        // Check encryption type, DF.
+        // Check encryption type, RSA.
         assert_eq!(config.cipher, cipher);
-        // Check encryption mode, does not matter for the DF.
+        // Check encryption mode.
         assert_eq!(config.mode, mode);
         // Check output mode, console/file/both.
         assert_eq!(config.output, output);
-        // Check shared the target for encryption/decryption.
-        assert_eq!(config.target, target);
-        // Check the RSA exponent for encryption/decryption.
-        assert_eq!(config.key_exponent, key_exponent);
-        // Check the RSA modulus.
-        assert_eq!(config.key_modulus, key_modulus);
-        // Check the thread count.
-        assert_eq!(config.thread_count, thread_count);
+        // Check the request with the target, the public key and the padding.
+        assert_eq!(config.request, request);
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with the padding of the encryption and decryption.
+    #[test]
+    fn test_rsa_encrypt_config_creation_padding() {
+        for (mode_arg, mode) in [("encrypt", Mode::Encode), ("decrypt", Mode::Decode)] {
+            let args_vec = vec!["rsa", mode_arg, "console", "target", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "padding=pkcs1"];
+            let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+                Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_rsa_encrypt_config_creation_padding)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_rsa_encrypt_config_creation_padding)", e),
+            };
+
+            assert_eq!(config.mode, mode);
+            match config.request {
+                RsaRequest::Encrypt { message: target, padding, .. } | RsaRequest::Decrypt { ciphertext: target, padding, .. } => {
+                    assert_eq!(target, "target");
+                    assert_eq!(padding, RsaPadding::Pkcs1v15);
+                }
+                _ => panic!("    An RSA encryption or decryption request was expected. (test_rsa_encrypt_config_creation_padding)"),
+            }
+        }
+
+        // The padding can be set only once, the unknown paddings are rejected.
+        for args_vec in [
+            vec!["rsa", "encrypt", "console", "target", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "padding=raw", "padding=pkcs1"],
+            vec!["rsa", "encrypt", "console", "target", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "padding=pkcs2"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with the key files for the encryption and decryption, and the key directory for the key generation.
+    #[test]
+    fn test_rsa_config_creation_key_files() {
+        let key_dir = std::env::temp_dir().join(format!("enc_config_key_files_{}", std::process::id()));
+        std::fs::create_dir_all(&key_dir).unwrap();
+        let key_pair = RsaKeyPair {
+            public_key_n: ChonkerInt::from(String::from("503389953040597954843496152539898795547523683")),
+            public_key_e: ChonkerInt::from(String::from("9683922000451682283955009414215846271")),
+            private_key_d: ChonkerInt::from(String::from("239227093839837965545527797083977554955436111")),
+            crt: None,
+        };
+        key_pair.save_to_files(&key_dir).unwrap();
+        let public_key_file = key_dir.join("rsa_public.key").display().to_string();
+        let private_key_file = key_dir.join("rsa_private.key").display().to_string();
+
+        let key_file_arg = format!("file:{}", private_key_file);
+        let args_vec = vec!["rsa", "decrypt", "console", "target", key_file_arg.as_str(), "padding=oaep"];
+        let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+            Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_rsa_config_creation_key_files)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_rsa_config_creation_key_files)", e),
+        };
+
+        // The private key is read from the key file.
+        assert_eq!(config.mode, Mode::Decode);
+        let request = RsaRequest::Decrypt {
+            ciphertext: String::from("target"),
+            private: RsaPrivateKey {
+                public_key_n: key_pair.public_key_n.clone(),
+                private_key_d: key_pair.private_key_d.clone(),
+                crt: None,
+            },
+            padding: RsaPadding::Oaep(OaepParams::default()),
+            blinding: false,
+            encoding: None,
+        };
+        assert_eq!(config.request, request);
+
+        // The path of an existing file is accepted without the prefix.
+        let args_vec = vec!["rsa", "encrypt", "console", "target", public_key_file.as_str()];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(rsa_config)) => match rsa_config.request {
+                RsaRequest::Encrypt { public, .. } => assert_eq!(public.public_key_e, key_pair.public_key_e),
+                _ => panic!("    An RSA encryption request was expected. (test_rsa_config_creation_key_files)"),
+            },
+            _ => panic!("    An RSA configuration was expected. (test_rsa_config_creation_key_files)"),
+        };
+
+        let args_vec = vec!["rsa", "generate", "console", "256", "save=keys"];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(rsa_config)) => {
+                assert_eq!(rsa_config.key_dir, Some(String::from("keys")));
+                assert_eq!(rsa_config.request, RsaRequest::Generate { bits: 256, options: RsaKeygenOptions::default() });
+            }
+            _ => panic!("    An RSA configuration was expected. (test_rsa_config_creation_key_files)"),
+        };
+
+        // The missing file without the prefix, the missing file with the prefix, the public key file for the decryption
+        // and the key directory outside of the key generation are rejected.
+        let public_key_file_arg = format!("file:{}", public_key_file);
+        for args_vec in [
+            vec!["rsa", "encrypt", "console", "target", "missing_file.key"],
+            vec!["rsa", "encrypt", "console", "target", "file:missing_file.key"],
+            vec!["rsa", "decrypt", "console", "target", public_key_file_arg.as_str()],
+            vec!["rsa", "bruteforce", "console", "85", "268970693", "save=keys"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+
+        std::fs::remove_dir_all(&key_dir).unwrap();
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with the blinding of the decryption with the private key file, which contains the primes.
+    #[test]
+    fn test_rsa_decrypt_config_creation_blinding() {
+        let key_dir = std::env::temp_dir().join(format!("enc_config_blinding_{}", std::process::id()));
+        std::fs::create_dir_all(&key_dir).unwrap();
+        let key_pair = RsaKeyPair::from_primes(&ChonkerInt::from(61), &ChonkerInt::from(53), &ChonkerInt::from(17)).unwrap();
+        key_pair.save_to_files(&key_dir).unwrap();
+        let key_file_arg = format!("file:{}", key_dir.join("rsa_private.key").display());
+
+        let args_vec = vec!["rsa", "decrypt", "console", "target", key_file_arg.as_str(), "--blinding"];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(rsa_config)) => match rsa_config.request {
+                RsaRequest::Decrypt { private, blinding, .. } => {
+                    assert!(blinding);
+                    assert_eq!(private.public_exponent(), Some(ChonkerInt::from(17)));
+                }
+                _ => panic!("    An RSA decryption request was expected. (test_rsa_decrypt_config_creation_blinding)"),
+            },
+            _ => panic!("    An RSA configuration was expected. (test_rsa_decrypt_config_creation_blinding)"),
+        };
+
+        // The decryption is not blinded by default.
+        let args_vec = vec!["rsa", "decrypt", "console", "target", key_file_arg.as_str()];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(rsa_config)) => assert!(matches!(rsa_config.request, RsaRequest::Decrypt { blinding: false, .. })),
+            _ => panic!("    An RSA configuration was expected. (test_rsa_decrypt_config_creation_blinding)"),
+        };
+
+        // The private exponent without the primes, the operations without the private key and the repeated flag are rejected.
+        for args_vec in [
+            vec!["rsa", "decrypt", "console", "target", "413", "3233", "--blinding"],
+            vec!["rsa", "encrypt", "console", "target", "17", "3233", "--blinding"],
+            vec!["rsa", "bruteforce", "console", "17", "3233", "--blinding"],
+            vec!["rsa", "decrypt", "console", "target", key_file_arg.as_str(), "--blinding", "--blinding"],
+            vec!["rsa", "decrypt", "console", "target", key_file_arg.as_str(), "--blinding=yes"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+
+        std::fs::remove_dir_all(&key_dir).unwrap();
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with the choice of the public exponent for the key generation.
+    #[test]
+    fn test_rsa_generate_config_creation_exponent_choice() {
+        for (exponent_arg, exponent) in [("e=65537", ExponentChoice::Fixed65537), ("e=random", ExponentChoice::Random)] {
+            let args_vec = vec!["rsa", "generate", "console", exponent_arg];
+            let args = args_vec.iter().map(|s| s.to_string());
+
+            let config = match ConfigVariant::new(args) {
+                Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+                Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_config_creation)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation)", e),
+            };
+
+            assert_eq!(config.mode, Mode::Generate);
+            assert_eq!(config.request, RsaRequest::Generate { bits: MODULUS_BIT_SIZE, options: RsaKeygenOptions { exponent } });
+        }
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with the bit size of the key modulus for the key generation.
+    #[test]
+    fn test_rsa_generate_config_creation_key_size() {
+        let args_vec = vec!["rsa", "generate", "console", "512", "e=random"];
+        let args = args_vec.iter().map(|s| s.to_string());
+
+        let config = match ConfigVariant::new(args) {
+            Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+            Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_config_creation)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation)", e),
+        };
+
+        assert_eq!(config.mode, Mode::Generate);
+        let options = RsaKeygenOptions { exponent: ExponentChoice::Random };
+        assert_eq!(config.request, RsaRequest::Generate { bits: 512, options });
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with ones for the common modulus attack of two ciphertexts.
+    #[test]
+    fn test_rsa_common_modulus_config_creation() {
+        let args_vec = vec!["rsa", "common-modulus", "console", "09A7F17BFD5A6EBA7644ED88C26F84B52B59F7", "0F6FFACCF225260953EEFC868EA42642F3F282", "3", "5", "503389953040597954843496152539898795547523683"];
+        let args = args_vec.iter().map(|s| s.to_string());
+
+        let config = match ConfigVariant::new(args) {
+            Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+            Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_rsa_common_modulus_config_creation)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_rsa_common_modulus_config_creation)", e),
+        };
+
+        assert_eq!(config.mode, Mode::CommonModulus);
+        assert_eq!(
+            config.request,
+            RsaRequest::CommonModulusAttack {
+                first_ciphertext: String::from("09A7F17BFD5A6EBA7644ED88C26F84B52B59F7"),
+                second_ciphertext: String::from("0F6FFACCF225260953EEFC868EA42642F3F282"),
+                first_exponent: ChonkerInt::from(3),
+                second_exponent: ChonkerInt::from(5),
+                key_modulus: ChonkerInt::from(String::from("503389953040597954843496152539898795547523683")),
+            }
+        );
+
+        // The missing modulus, the negative exponent and the prime modulus are rejected.
+        for args_vec in [
+            vec!["rsa", "common-modulus", "console", "09A7F17BFD5A6EBA7644ED88C26F84B52B59F7", "0F6FFACCF225260953EEFC868EA42642F3F282", "3", "5"],
+            vec!["rsa", "common-modulus", "console", "09A7F17BFD5A6EBA7644ED88C26F84B52B59F7", "0F6FFACCF225260953EEFC868EA42642F3F282", "-3", "5", "503389953040597954843496152539898795547523683"],
+            vec!["rsa", "common-modulus", "console", "09A7F17BFD5A6EBA7644ED88C26F84B52B59F7", "0F6FFACCF225260953EEFC868EA42642F3F282", "3", "5", "57885161"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with ones for the key generation from the known primes and the public exponent.
+    #[test]
+    fn test_rsa_generate_from_primes_config_creation() {
+        let args_vec = vec!["rsa", "generate-from", "console", "61", "53", "17", "save=."];
+        let args = args_vec.iter().map(|s| s.to_string());
+
+        let config = match ConfigVariant::new(args) {
+            Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+            Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_rsa_generate_from_primes_config_creation)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_rsa_generate_from_primes_config_creation)", e),
+        };
+
+        assert_eq!(config.mode, Mode::GenerateFromPrimes);
+        assert_eq!(config.key_dir, Some(String::from(".")));
+        assert_eq!(config.request, RsaRequest::GenerateFromPrimes { prime_p: ChonkerInt::from(61), prime_q: ChonkerInt::from(53), public_key_e: ChonkerInt::from(17) });
+
+        // The missing, the negative and the malformed numbers, and the options of the random key generation are rejected.
+        for args_vec in [
+            vec!["rsa", "generate-from", "console", "61", "53"],
+            vec!["rsa", "generate-from", "console", "61", "-53", "17"],
+            vec!["rsa", "generate-from", "console", "61", "53", "e17"],
+            vec!["rsa", "generate-from", "console", "61", "53", "17", "e=random"],
+            vec!["rsa", "generate-from", "console", "61", "53", "17", "padding=oaep"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
     }
 
     // Test creation of configuration with correct arguments for RSA algorithm,
@@ -744,7 +2277,7 @@ mod tests {
     #[test]
     fn test_rsa_bruteforce_no_custom_threads_config_creation_correct_args() {
         // Test RSA algorithm with random algorithm without providing args.
-        let args_vec = vec!["rsa", "bruteforce", "console", "exponent", "modulus"];
+        let args_vec = vec!["rsa", "bruteforce", "console", "85", "268970693"];
         let args = args_vec.iter().map(|s| s.to_string());
 
         let config = ConfigVariant::new(args);
@@ -765,26 +2298,24 @@ mod tests {
         let cipher = Cipher::RSA;
         let mode = Mode::Bruteforce;
         let output = Output::Console;
-        let target = None;
-        let key_exponent = Some(String::from("exponent"));
-        let key_modulus = Some(String::from("modulus"));
-        let thread_count = None;
+        let request = RsaRequest::Bruteforce {
+            public: RsaPublicKey {
+                public_key_n: ChonkerInt::from(268970693),
+                public_key_e: ChonkerInt::from(85),
+            },
+            threads: None,
+            strategy: BruteforceStrategy::TrialDivision,
+        };
 
         // Check equality of values between provided arguments and produced config's fields.
-        // Check encryption type, DF.
+        // Check encryption type, RSA.
         assert_eq!(config.cipher, cipher);
-        // Check encryption mode, does not matter for the DF.
+        // Check encryption mode.
         assert_eq!(config.mode, mode);
         // Check output mode, console/file/both.
         assert_eq!(config.output, output);
-        // Check shared the target for encryption/decryption.
-        assert_eq!(config.target, target);
-        // Check the RSA exponent for encryption/decryption.
-        assert_eq!(config.key_exponent, key_exponent);
-        // Check the RSA modulus.
-        assert_eq!(config.key_modulus, key_modulus);
-        // Check the thread count.
-        assert_eq!(config.thread_count, thread_count);
+        // Check the request with the public key, the thread count and the default strategy.
+        assert_eq!(config.request, request);
     }
 
     // Test creation of configuration with correct arguments for RSA algorithm,
@@ -792,7 +2323,7 @@ mod tests {
     #[test]
     fn test_rsa_bruteforce_custom_threads_config_creation_correct_args() {
         // Test RSA algorithm with random algorithm without providing args.
-        let args_vec = vec!["rsa", "bruteforce", "console", "exponent", "modulus", "custom_amount_of_threads"];
+        let args_vec = vec!["rsa", "bruteforce", "console", "85", "268970693", "24"];
         let args = args_vec.iter().map(|s| s.to_string());
 
         let config = ConfigVariant::new(args);
@@ -813,26 +2344,209 @@ mod tests {
         let cipher = Cipher::RSA;
         let mode = Mode::Bruteforce;
         let output = Output::Console;
-        let target = None;
-        let key_exponent = Some(String::from("exponent"));
-        let key_modulus = Some(String::from("modulus"));
-        let thread_count = Some(String::from("custom_amount_of_threads"));
+        let request = RsaRequest::Bruteforce {
+            public: RsaPublicKey {
+                public_key_n: ChonkerInt::from(268970693),
+                public_key_e: ChonkerInt::from(85),
+            },
+            threads: Some(24),
+            strategy: BruteforceStrategy::TrialDivision,
+        };
 
         // Check equality of values between provided arguments and produced config's fields.
-        // Check encryption type, DF.
+        // Check encryption type, RSA.
         assert_eq!(config.cipher, cipher);
-        // Check encryption mode, does not matter for the DF.
+        // Check encryption mode.
         assert_eq!(config.mode, mode);
         // Check output mode, console/file/both.
         assert_eq!(config.output, output);
-        // Check shared the target for encryption/decryption.
-        assert_eq!(config.target, target);
-        // Check the RSA exponent for encryption/decryption.
-        assert_eq!(config.key_exponent, key_exponent);
-        // Check the RSA modulus.
-        assert_eq!(config.key_modulus, key_modulus);
-        // Check the thread count.
-        assert_eq!(config.thread_count, thread_count);
+        // Check the request with the public key, the thread count and the default strategy.
+        assert_eq!(config.request, request);
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with ones for bruteforcing of a public key with the default amount of threads and a bruteforce strategy.
+    #[test]
+    fn test_rsa_bruteforce_strategy_config_creation_correct_args() {
+        let args_vec = vec!["rsa", "bruteforce", "console", "85", "268970693", "none", "fermat"];
+        let args = args_vec.iter().map(|s| s.to_string());
+
+        let config = match ConfigVariant::new(args) {
+            Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+            Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_config_creation)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation)", e),
+        };
+
+        assert_eq!(config.mode, Mode::Bruteforce);
+        match config.request {
+            RsaRequest::Bruteforce { public, threads, strategy } => {
+                assert_eq!(public.public_key_e, ChonkerInt::from(85));
+                assert_eq!(public.public_key_n, ChonkerInt::from(268970693));
+                // Check the thread count, "none" uses the default one.
+                assert_eq!(threads, None);
+                // Check the bruteforce strategy.
+                assert_eq!(strategy, BruteforceStrategy::Fermat);
+            }
+            _ => panic!("    An RSA bruteforce request was expected. (test_rsa_bruteforce_strategy_config_creation_correct_args)"),
+        }
+        assert_eq!(config.timeout_secs, None);
+
+        // The Wiener's attack.
+        let args_vec = vec!["rsa", "bruteforce", "console", "85", "268970693", "none", "wiener"];
+        match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+            Ok(ConfigVariant::RSA(ConfigRSA { request: RsaRequest::Bruteforce { strategy, .. }, .. })) => assert_eq!(strategy, BruteforceStrategy::Wiener),
+            _ => panic!("    An RSA bruteforce request was expected. (test_rsa_bruteforce_strategy_config_creation_correct_args)"),
+        }
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with ones for bruteforcing of a public key with a thread count and a timeout.
+    #[test]
+    fn test_rsa_bruteforce_timeout_config_creation_correct_args() {
+        let args_vec = vec!["rsa", "bruteforce", "console", "85", "268970693", "4", "timeout=30"];
+        let args = args_vec.iter().map(|s| s.to_string());
+
+        let config = match ConfigVariant::new(args) {
+            Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+            Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_config_creation)"),
+            Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_config_creation)", e),
+        };
+
+        assert_eq!(config.mode, Mode::Bruteforce);
+        assert!(matches!(config.request, RsaRequest::Bruteforce { threads: Some(4), strategy: BruteforceStrategy::TrialDivision, .. }));
+        assert_eq!(config.timeout_secs, Some(30));
+
+        // The timeout is accepted by the key generation as well, in any position after the mode.
+        let args = ["rsa", "generate", "timeout=5", "console"].iter().map(|s| s.to_string());
+        match ConfigVariant::new(args) {
+            Ok(ConfigVariant::RSA(rsa_config)) => assert_eq!(rsa_config.timeout_secs, Some(5)),
+            _ => panic!("    An RSA configuration was expected. (test_config_creation)"),
+        };
+
+        // Incorrect, repeated or unsupported timeouts are rejected.
+        for args_vec in [
+            vec!["rsa", "bruteforce", "console", "85", "268970693", "timeout=0"],
+            vec!["rsa", "bruteforce", "console", "85", "268970693", "timeout=soon"],
+            vec!["rsa", "bruteforce", "console", "85", "268970693", "timeout=1", "timeout=2"],
+            vec!["rsa", "encrypt", "console", "target", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "timeout=1"],
+            vec!["caesar", "encrypt", "console", "target", "3", "timeout=1"],
+            vec!["rsa", "bruteforce", "console", "85", "268970693", "e=random"],
+            vec!["rsa", "generate", "console", "e=3", "e=random"],
+            vec!["rsa", "generate", "console", "padding=pkcs1"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+    }
+
+    // Test creation of configuration with correct arguments for RSA algorithm,
+    // with the binary flag, which reads the raw bytes of the input file for the encryption and decryption.
+    #[test]
+    fn test_rsa_config_creation_binary() {
+        let input_file = std::env::temp_dir().join(format!("enc_config_binary_{}.bin", std::process::id()));
+        let input_bytes: Vec<u8> = (0..=255).collect();
+        std::fs::write(&input_file, &input_bytes).unwrap();
+        let input_path = input_file.display().to_string();
+
+        for (mode_arg, mode) in [("encrypt", Mode::Encode), ("decrypt", Mode::Decode)] {
+            let args_vec = vec!["rsa", mode_arg, "console", input_path.as_str(), "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "--binary", "padding=pkcs1"];
+            let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+                Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_rsa_config_creation_binary)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_rsa_config_creation_binary)", e),
+            };
+
+            assert_eq!(config.mode, mode);
+            match config.request {
+                RsaRequest::EncryptBytes { data: bytes, padding, .. } | RsaRequest::DecryptBytes { ciphertext: bytes, padding, .. } => {
+                    assert_eq!(bytes, input_bytes);
+                    assert_eq!(padding, RsaPadding::Pkcs1v15);
+                }
+                _ => panic!("    An RSA binary request was expected. (test_rsa_config_creation_binary)"),
+            }
+        }
+
+        // The missing input file, the repeated flag, the flag with a value and the flag outside of the encryption and decryption are rejected.
+        for args_vec in [
+            vec!["rsa", "encrypt", "console", "missing_file.bin", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "--binary"],
+            vec!["rsa", "encrypt", "console", input_path.as_str(), "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "--binary", "--binary"],
+            vec!["rsa", "encrypt", "console", input_path.as_str(), "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "--binary=yes"],
+            vec!["rsa", "generate", "console", "--binary"],
+            vec!["rsa", "bruteforce", "console", "85", "268970693", "--binary"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+
+        std::fs::remove_file(&input_file).unwrap();
+    }
+
+    // Test creation of configuration struct for RSA algorithm with the input and the output files of the streaming,
+    // which is accepted only with the "file" output mode.
+    #[test]
+    fn test_rsa_config_creation_stream() {
+        let input_file = std::env::temp_dir().join(format!("enc_config_stream_{}.bin", std::process::id()));
+        std::fs::write(&input_file, b"Streamed data.").unwrap();
+        let input_path = input_file.display().to_string();
+
+        for (mode_arg, mode) in [("encrypt", Mode::Encode), ("decrypt", Mode::Decode)] {
+            let args_vec = vec!["rsa", mode_arg, "file", input_path.as_str(), "output.bin", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "padding=oaep"];
+            let config = match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(ConfigVariant::RSA(rsa_config)) => rsa_config,
+                Ok(_) => panic!("    An RSA configuration was expected, but received another one. (test_rsa_config_creation_stream)"),
+                Err(e) => panic!("    An error was encountered during creation of a config struct in a test: {}. (test_rsa_config_creation_stream)", e),
+            };
+
+            assert_eq!(config.mode, mode);
+            assert_eq!(config.output, Output::File);
+            match config.request {
+                RsaRequest::EncryptStream { input, output, padding, .. } | RsaRequest::DecryptStream { input, output, padding, .. } => {
+                    assert_eq!(input, input_path);
+                    assert_eq!(output, "output.bin");
+                    assert_eq!(padding, RsaPadding::Oaep(OaepParams::default()));
+                }
+                _ => panic!("    An RSA streaming request was expected. (test_rsa_config_creation_stream)"),
+            }
+        }
+
+        // The console output, the binary flag and the output file in place of the input file are rejected.
+        for args_vec in [
+            vec!["rsa", "encrypt", "console", input_path.as_str(), "output.bin", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683"],
+            vec!["rsa", "decrypt", "both", input_path.as_str(), "output.bin", "239227093839837965545527797083977554955436111", "503389953040597954843496152539898795547523683"],
+            vec!["rsa", "encrypt", "file", input_path.as_str(), "output.bin", "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683", "--binary"],
+            vec!["rsa", "encrypt", "file", input_path.as_str(), input_path.as_str(), "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683"],
+        ] {
+            assert!(ConfigVariant::new(args_vec.iter().map(|s| s.to_string())).is_err());
+        }
+
+        std::fs::remove_file(&input_file).unwrap();
+    }
+
+    // Test failure of configuration struct creation for RSA algorithm with malformed arguments,
+    // the errors are reported during the configuration parsing and name the failed operation.
+    #[test]
+    fn test_rsa_config_creation_malformed_args() {
+        for (args_vec, expected_error) in [
+            (vec!["rsa", "generate", "console", "64"], "the bit size of the RSA modulus for the key generation"),
+            (vec!["rsa", "generate", "console", "big"], "the bit size of the RSA modulus for the key generation"),
+            (vec!["rsa", "generate", "console", "e=3"], "the public exponent for the RSA key generation"),
+            (vec!["rsa", "encrypt", "console", "target", "ABCDE", "503389953040597954843496152539898795547523683"], "the RSA public exponent for the encryption"),
+            (vec!["rsa", "encrypt", "console", "target", "9683922000451682283955009414215846271", "ABCDE"], "the RSA modulus for the encryption"),
+            (vec!["rsa", "encrypt", "console", "target", "9683922000451682283955009414215846271", "57885161"], "the RSA modulus for the encryption"),
+            (vec!["rsa", "encrypt", "console", "target", "9683922000451682283955009414215846271", "221"], "the RSA modulus for the encryption"),
+            (vec!["rsa", "encrypt", "console", "target", "-9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683"], "the RSA public exponent for the encryption"),
+            (vec!["rsa", "decrypt", "console", "target", "ABCDE", "503389953040597954843496152539898795547523683"], "the RSA private exponent for the decryption"),
+            (vec!["rsa", "decrypt", "console", "target", "239227093839837965545527797083977554955436111", "ABCDE"], "the RSA modulus for the decryption"),
+            (vec!["rsa", "decrypt", "console", "target", "239227093839837965545527797083977554955436111", "503389953040597954843496152539898795547523683", "padding=none"], "the padding of the RSA encryption/decryption"),
+            (vec!["rsa", "bruteforce", "console", "ABCDE", "268970693"], "the RSA public exponent for the bruteforce"),
+            (vec!["rsa", "bruteforce", "console", "85", "57885161"], "the RSA modulus for the bruteforce"),
+            (vec!["rsa", "bruteforce", "console", "85", "268970693", "many"], "the thread count for the RSA bruteforce"),
+            (vec!["rsa", "bruteforce", "console", "85", "268970693", "128"], "the thread count for the RSA bruteforce"),
+            (vec!["rsa", "bruteforce", "console", "85", "268970693", "none", "rho"], "the strategy of the RSA bruteforce"),
+        ] {
+            match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(_) => panic!("    The malformed arguments {:?} produced a configuration. (test_rsa_config_creation_malformed_args)", args_vec),
+                Err(e) => assert!(e.to_string().contains(expected_error), "    Unexpected error for {:?}: {}. (test_rsa_config_creation_malformed_args)", args_vec, e),
+            }
+        }
     }
 
     // Test failure of configuration struct creation, when an incorrect amount of arguments passed.
@@ -886,6 +2600,105 @@ mod tests {
         }
     }
 
+    // Test the named flags in any order produce the same configurations as the positional arguments of the same operations.
+    #[test]
+    fn test_config_creation_named_flags() {
+        let exponent = "9683922000451682283955009414215846271";
+        let modulus = "503389953040597954843496152539898795547523683";
+        for (positional_args, named_args) in [
+            (vec!["vigenere", "encrypt", "both", "HELLO", "KEY", "--alpha"], vec!["--alpha", "--key", "KEY", "--out", "both", "--text", "HELLO", "--mode", "encrypt", "--cipher", "vigenere"]),
+            (vec!["xor", "encrypt", "file", "hello", "key", "out=res.txt", "encoding=base64"], vec!["--cipher", "xor", "--mode", "encrypt", "--out", "file", "--text", "hello", "--key", "key", "--out-path", "res.txt", "--encoding", "base64"]),
+            (vec!["caesar", "bruteforce", "console", "4A4D4B", "top=3"], vec!["--cipher", "caesar", "--mode", "bruteforce", "--out", "console", "--top", "3", "--text", "4A4D4B"]),
+            (vec!["railfence", "decrypt", "console", "Zm9vYmFy", "2", "encoding=base64"], vec!["--cipher", "railfence", "--mode", "decrypt", "--out", "console", "--text", "Zm9vYmFy", "--key", "2", "--encoding", "base64"]),
+            (vec!["otp", "encrypt", "console", "hello"], vec!["--out", "console", "--cipher", "otp", "--mode", "encrypt", "--text", "hello"]),
+            (vec!["df", "generate", "console"], vec!["--cipher", "df", "--mode", "generate", "--out", "console"]),
+            (vec!["df", "generate", "console", "none", "none", "6", "none", "key-length=16"], vec!["--cipher", "df", "--mode", "generate", "--out", "console", "--secret-a", "6", "--key-length", "16"]),
+            (vec!["df", "generate", "console", "5", "7", "group14"], vec!["--cipher", "df", "--mode", "generate", "--out", "console", "--group", "group14", "--secret-b", "7", "--secret-a", "5"]),
+            (vec!["df", "crack", "console", "23", "5", "8"], vec!["--cipher", "df", "--mode", "crack", "--out", "console", "--public-value", "8", "--base", "5", "--prime", "23"]),
+            (vec!["df", "multi", "console", "2147483647", "none", "3", "none", "7"], vec!["--cipher", "df", "--mode", "multi", "--out", "console", "--prime", "2147483647", "--secrets", "3,none,7"]),
+            (vec!["rsa", "generate", "console", "512", "e=random", "timeout=10", "save=keys", "--armor"], vec!["--cipher", "rsa", "--mode", "generate", "--out", "console", "--bits", "512", "--public-exponent", "random", "--timeout", "10", "--save", "keys", "--armor"]),
+            (vec!["rsa", "generate-from", "console", "61", "53", "17"], vec!["--cipher", "rsa", "--mode", "generate-from", "--out", "console", "--exponent", "17", "--prime-q", "53", "--prime-p", "61"]),
+            (vec!["rsa", "bruteforce", "console", "17", "3233", "none", "fermat"], vec!["--cipher", "rsa", "--mode", "bruteforce", "--out", "console", "--exponent", "17", "--modulus", "3233", "--strategy", "fermat"]),
+            (vec!["rsa", "bruteforce", "console", "17", "3233", "4"], vec!["--cipher", "rsa", "--mode", "bruteforce", "--out", "console", "--threads", "4", "--exponent", "17", "--modulus", "3233"]),
+            (vec!["rsa", "encrypt", "console", "text=with=equals", exponent, modulus, "padding=oaep"], vec!["--cipher", "rsa", "--mode", "encrypt", "--out", "console", "--padding", "oaep", "--modulus", modulus, "--exponent", exponent, "--text", "text=with=equals"]),
+            (vec!["rsa", "encrypt", "file", "input.bin", "output.bin", exponent, modulus], vec!["--cipher", "rsa", "--mode", "encrypt", "--out", "file", "--in", "input.bin", "--output-file", "output.bin", "--exponent", exponent, "--modulus", modulus]),
+            (
+                vec!["rsa", "common-modulus", "console", "09A7F17BFD5A6EBA7644ED88C26F84B52B59F7", "0F6FFACCF225260953EEFC868EA42642F3F282", "3", "5", modulus],
+                vec!["--cipher", "rsa", "--mode", "common-modulus", "--out", "console", "--modulus", modulus, "--second-exponent", "5", "--exponent", "3", "--second-text", "0F6FFACCF225260953EEFC868EA42642F3F282", "--text", "09A7F17BFD5A6EBA7644ED88C26F84B52B59F7"],
+            ),
+        ] {
+            let positional_config = ConfigVariant::new(positional_args.iter().map(|s| s.to_string()));
+            let named_config = ConfigVariant::new(named_args.iter().map(|s| s.to_string()));
+            match (positional_config, named_config) {
+                (Ok(positional_config), Ok(named_config)) => assert_eq!(positional_config, named_config),
+                (Err(e), _) | (_, Err(e)) => panic!("    An error was encountered during creation of a config struct for {:?}: {}. (test_config_creation_named_flags)", named_args, e),
+            }
+        }
+
+        // The input file "-" is the standard input.
+        let named_args = vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "--in", "-", "--key", "3"];
+        match ConfigVariant::new_with_stdin(named_args.iter().map(|s| s.to_string()), &b"piped"[..]) {
+            Ok(ConfigVariant::Symmetric(symmetric_config)) => assert_eq!(symmetric_config.target, b"piped"),
+            _ => panic!("    A symmetric configuration was expected. (test_config_creation_named_flags)"),
+        }
+    }
+
+    // Test the JSON output mode of the positional arguments and of the named flags, it is available only with the serde feature,
+    // the output path of the symmetric ciphers receives its document.
+    #[test]
+    fn test_config_creation_json_output() {
+        for args_vec in [
+            vec!["caesar", "encrypt", "json", "hello", "3", "out=result.json"],
+            vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "json", "--text", "hello", "--key", "3", "--out-path", "result.json"],
+        ] {
+            let args: Vec<String> = args_vec.iter().map(|s| s.to_string()).collect();
+            assert_eq!(json_output_requested(&args), cfg!(feature = "serde"));
+
+            match ConfigVariant::new(args.into_iter()) {
+                Ok(ConfigVariant::Symmetric(symmetric_config)) => {
+                    assert!(cfg!(feature = "serde"));
+                    assert_eq!(symmetric_config.output, Output::Json);
+                    assert_eq!(symmetric_config.output_path, Some(String::from("result.json")));
+                }
+                Ok(_) => panic!("    A symmetric configuration was expected. (test_config_creation_json_output)"),
+                Err(e) => assert!(!cfg!(feature = "serde") && e.to_string().contains("\"serde\" feature"), "{}", e),
+            }
+        }
+
+        assert!(!json_output_requested(&["caesar".to_string(), "encrypt".to_string(), "console".to_string(), "json".to_string()]));
+    }
+
+    // Test the errors of the named flags: the unknown, the duplicate and the missing flags, the flags without the values,
+    // the positional arguments together with the flags and the flags, which are not supported by the operation.
+    #[test]
+    fn test_config_creation_named_flags_errors() {
+        for (args_vec, message) in [
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "--text", "hello", "--kee", "3"], "unknown flag \"--kee\""),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "--text", "hello", "--key", "3", "--key", "4"], "flag \"--key\" more than once"),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "--text", "hello"], "required flag \"--key\" of the \"caesar encrypt\" operation"),
+            (vec!["--mode", "encrypt", "--out", "console", "--text", "hello", "--key", "3"], "required flag \"--cipher\""),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--text", "hello", "--key", "3"], "required flag \"--out\""),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "--key", "3"], "\"--text\" or \"--in\""),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "--text", "hello", "--in", "-", "--key", "3"], "both the \"--text\" and the \"--in\" flags"),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "--text", "hello", "--key"], "value of the flag \"--key\""),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--text", "--out", "console", "--key", "3"], "value of the flag \"--text\""),
+            (vec!["caesar", "encrypt", "console", "hello", "--key", "3"], "positional argument \"caesar\" together with the named flags"),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "hello", "--key", "3"], "positional argument \"hello\""),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "--text", "hello", "--key", "3", "--bits", "512"], "flag \"--bits\" is not supported"),
+            (vec!["--cipher", "caesar", "--mode", "encrypt", "--out", "console", "--text", "hello", "--key", "3", "--padding", "oaep"], "flag \"--padding\" is not supported by the \"caesar encrypt\" operation"),
+            (vec!["--cipher", "otp", "--mode", "encrypt", "--out", "console", "--text", "hello", "--armor"], "flag \"--armor\" is not supported"),
+            (vec!["--cipher", "df", "--mode", "generate", "--out", "console", "--prime", "23", "--group", "group14"], "flag \"--prime\" together with \"--group\""),
+            (vec!["--cipher", "rsa", "--mode", "encrypt", "--out", "console", "--in", "input.bin", "--key-file", "rsa_public.key"], "requires the \"--binary\" or the \"--output-file\" flag"),
+            (vec!["--cipher", "caesar", "--mode", "sign", "--out", "console"], "mode \"sign\" of the \"--mode\" flag is not supported by the \"caesar\" cipher"),
+            (vec!["--cipher", "aes", "--mode", "encrypt", "--out", "console"], "cipher type"),
+        ] {
+            match ConfigVariant::new(args_vec.iter().map(|s| s.to_string())) {
+                Ok(_) => panic!("    An error was expected for the flags {:?}. (test_config_creation_named_flags_errors)", args_vec),
+                Err(e) => assert!(e.to_string().contains(message), "{:?}: {}", args_vec, e),
+            }
+        }
+    }
+
     // Test of handling of the "help" as the only argument.
     #[test]
     fn test_config_with_only_help_arg() -> Result<(), Box<dyn std::error::Error>> {
@@ -6,6 +6,7 @@ use std::fmt::Formatter;
 pub struct OperationError {
     err_message: String,
     help_message_flag: bool,
+    cancelled_flag: bool,
 }
 
 impl OperationError {
@@ -13,6 +14,15 @@ impl OperationError {
         OperationError {
             err_message: String::from(msg),
             help_message_flag: Default::default(),
+            cancelled_flag: Default::default(),
+        }
+    }
+
+    // Create an error for an operation, which was cancelled or ran out of time, and set the cancellation flag.
+    pub fn new_cancelled(msg: &str) -> OperationError {
+        OperationError {
+            cancelled_flag: true,
+            ..OperationError::new(msg)
         }
     }
 
@@ -25,6 +35,11 @@ impl OperationError {
     pub fn get_help_flag(&self) -> bool {
         self.help_message_flag
     }
+
+    // Get the flag for the cancellation of the operation.
+    pub fn get_cancelled_flag(&self) -> bool {
+        self.cancelled_flag
+    }
 }
 
 // Implement Display trait for possible formatting.
@@ -68,6 +83,20 @@ mod tests {
         assert_eq!(flag, true);
     }
 
+    // Test creation of the error for a cancelled operation.
+    #[test]
+    fn test_error_cancelled_flag() {
+        let error_message = "this is a test error message";
+
+        let error_struct = OperationError::new(error_message);
+        assert_eq!(error_struct.get_cancelled_flag(), false);
+
+        let cancelled_error_struct = OperationError::new_cancelled(error_message);
+        assert_eq!(cancelled_error_struct.get_cancelled_flag(), true);
+        assert_eq!(cancelled_error_struct.get_help_flag(), false);
+        assert_eq!(cancelled_error_struct.to_string(), error_message);
+    }
+
     // Test implementation of the Display trait on the custom error struct.
     #[test]
     fn test_error_struct_display_trait() {
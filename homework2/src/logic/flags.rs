@@ -0,0 +1,351 @@
+use crate::logic::error::OperationError;
+
+// The named flags are the alternative of the positional arguments, e.g.
+//
+//     enc --cipher vigenere --mode encrypt --out both --key k --in msg.txt
+//
+// is the same as "enc vigenere encrypt both file:msg.txt k". The flags come in any order, every flag is given once,
+// they are translated into the positional arguments and the options of the positional form, which are parsed as before.
+// The positional form is used, when no flag of a value is given.
+
+// Kinds of the named flags.
+#[derive(Debug, PartialEq)]
+pub enum FlagKind {
+    // The value of the flag takes the place of a positional argument.
+    Positional,
+    // The value of the flag becomes the "<name>=<value>" option of the prefix.
+    Option(&'static str),
+    // The flag without a value, the same as the flag of the positional form.
+    Switch,
+}
+
+// Definition of a named flag for the parsing and the help message.
+pub struct FlagDefinition {
+    pub name: &'static str,
+    // Name of the value in the help message, empty for the switches.
+    pub value: &'static str,
+    pub kind: FlagKind,
+    pub help: &'static str,
+}
+
+// The flag of the help message.
+pub const HELP_FLAG: &str = "--help";
+
+// All of the named flags, in the order of the help message.
+pub const FLAG_DEFINITIONS: &[FlagDefinition] = &[
+    FlagDefinition { name: "--cipher", value: "<cipher type>", kind: FlagKind::Positional, help: "the cipher, required" },
+    FlagDefinition { name: "--mode", value: "<encryption mode>", kind: FlagKind::Positional, help: "the operation of the cipher, required" },
+    FlagDefinition { name: "--out", value: "<output mode>", kind: FlagKind::Positional, help: "the output mode, required" },
+    FlagDefinition { name: "--text", value: "<plaintext or ciphertext>", kind: FlagKind::Positional, help: "the text to process, the first ciphertext of the RSA common modulus attack" },
+    FlagDefinition { name: "--in", value: "<input file>", kind: FlagKind::Positional, help: "the file to process instead of the text, \"-\" for the standard input" },
+    FlagDefinition { name: "--key", value: "<key>", kind: FlagKind::Positional, help: "the key of Caesar, Vigenere, XOR, one-time pad or affine cipher, the amount of rails of rail fence cipher" },
+    FlagDefinition { name: "--out-path", value: "<path>", kind: FlagKind::Option("out="), help: "the output path of the symmetric ciphers, same as \"out=<path>\"" },
+    FlagDefinition { name: "--encoding", value: "<encoding>", kind: FlagKind::Option("encoding="), help: "the ciphertext encoding, same as \"encoding=<encoding>\"" },
+    FlagDefinition { name: "--hex-case", value: "<hex case>", kind: FlagKind::Option("hex-case="), help: "the hex case, same as \"hex-case=<hex case>\"" },
+    FlagDefinition { name: "--top", value: "<amount of candidates>", kind: FlagKind::Option("top="), help: "the amount of the Caesar bruteforce candidates, same as \"top=<amount>\"" },
+    FlagDefinition { name: "--prime", value: "<shared prime>", kind: FlagKind::Positional, help: "the Diffie-Hellman shared prime, \"none\" if omitted" },
+    FlagDefinition { name: "--base", value: "<shared base>", kind: FlagKind::Positional, help: "the Diffie-Hellman shared base, \"none\" if omitted" },
+    FlagDefinition { name: "--secret-a", value: "<secret A>", kind: FlagKind::Positional, help: "the Diffie-Hellman secret A, \"none\" if omitted" },
+    FlagDefinition { name: "--secret-b", value: "<secret B>", kind: FlagKind::Positional, help: "the Diffie-Hellman secret B, \"none\" if omitted" },
+    FlagDefinition { name: "--secrets", value: "<secret A>,<secret B>,...", kind: FlagKind::Positional, help: "the comma separated secrets of the multi-party Diffie-Hellman algorithm" },
+    FlagDefinition { name: "--group", value: "<group>", kind: FlagKind::Positional, help: "the MODP group of the Diffie-Hellman algorithm instead of the shared prime and base" },
+    FlagDefinition { name: "--public-value", value: "<public value>", kind: FlagKind::Positional, help: "the public value of the Diffie-Hellman discrete logarithm search" },
+    FlagDefinition { name: "--key-length", value: "<bytes>", kind: FlagKind::Option("key-length="), help: "the length of the derived Diffie-Hellman key, same as \"key-length=<bytes>\"" },
+    FlagDefinition { name: "--exponent", value: "<exponent>", kind: FlagKind::Positional, help: "the RSA public or private exponent, the first public exponent of the common modulus attack" },
+    FlagDefinition { name: "--modulus", value: "<public modulus>", kind: FlagKind::Positional, help: "the RSA public modulus" },
+    FlagDefinition { name: "--key-file", value: "<key file>", kind: FlagKind::Positional, help: "the RSA key file instead of the exponent and the modulus" },
+    FlagDefinition { name: "--output-file", value: "<output file>", kind: FlagKind::Positional, help: "the output file of the RSA encryption or decryption of a file of any size" },
+    FlagDefinition { name: "--padding", value: "<padding>", kind: FlagKind::Option("padding="), help: "the RSA padding, same as \"padding=<padding>\"" },
+    FlagDefinition { name: "--bits", value: "<key size>", kind: FlagKind::Positional, help: "the bit size of the generated RSA modulus" },
+    FlagDefinition { name: "--public-exponent", value: "<65537 or random>", kind: FlagKind::Option("e="), help: "the public exponent choice of the RSA key generation, same as \"e=<choice>\"" },
+    FlagDefinition { name: "--save", value: "<directory>", kind: FlagKind::Option("save="), help: "the directory of the generated RSA key files, same as \"save=<directory>\"" },
+    FlagDefinition { name: "--prime-p", value: "<prime p>", kind: FlagKind::Positional, help: "the prime p of the RSA key generation from the known primes" },
+    FlagDefinition { name: "--prime-q", value: "<prime q>", kind: FlagKind::Positional, help: "the prime q of the RSA key generation from the known primes" },
+    FlagDefinition { name: "--threads", value: "<amount of threads>", kind: FlagKind::Positional, help: "the amount of the threads of the RSA bruteforce" },
+    FlagDefinition { name: "--strategy", value: "<bruteforce strategy>", kind: FlagKind::Positional, help: "the strategy of the RSA bruteforce" },
+    FlagDefinition { name: "--timeout", value: "<seconds>", kind: FlagKind::Option("timeout="), help: "the timeout of the RSA key generation or bruteforce, same as \"timeout=<seconds>\"" },
+    FlagDefinition { name: "--second-text", value: "<second ciphertext>", kind: FlagKind::Positional, help: "the second ciphertext of the RSA common modulus attack" },
+    FlagDefinition { name: "--second-exponent", value: "<second public exponent>", kind: FlagKind::Positional, help: "the second public exponent of the RSA common modulus attack" },
+    FlagDefinition { name: "--alpha", value: "", kind: FlagKind::Switch, help: "the classic mode of Caesar, Vigenere or affine cipher" },
+    FlagDefinition { name: "--all", value: "", kind: FlagKind::Switch, help: "all of the Caesar bruteforce candidates" },
+    FlagDefinition { name: "--armor", value: "", kind: FlagKind::Switch, help: "the armored ciphertexts or RSA key files" },
+    FlagDefinition { name: "--binary", value: "", kind: FlagKind::Switch, help: "the RSA encryption or decryption of the raw bytes of the input file" },
+    FlagDefinition { name: "--blinding", value: "", kind: FlagKind::Switch, help: "the blinding of the RSA private key operations" },
+    FlagDefinition { name: HELP_FLAG, value: "", kind: FlagKind::Switch, help: "the help message" },
+];
+
+// The arguments of the positional form, translated from the named flags.
+#[derive(Debug, PartialEq)]
+pub struct NamedArgs {
+    // The positional arguments, starting with the cipher, the mode and the output mode.
+    pub positional: Vec<String>,
+    // The "<name>=<value>" options and the switches.
+    pub options: Vec<String>,
+}
+
+// The given named flags with their values, the positional flags are taken out one by one for the arguments of the operation.
+struct GivenFlags {
+    flags: Vec<(&'static FlagDefinition, Option<String>)>,
+    operation: String,
+}
+
+impl GivenFlags {
+    fn has(&self, name: &str) -> bool {
+        self.flags.iter().any(|(definition, _)| definition.name == name)
+    }
+
+    // Take out the value of the optional flag.
+    fn take(&mut self, name: &str) -> Option<String> {
+        let index = self.flags.iter().position(|(definition, _)| definition.name == name)?;
+        self.flags.remove(index).1
+    }
+
+    // Take out the value of the optional flag or "none".
+    fn take_or_none(&mut self, name: &str) -> String {
+        self.take(name).unwrap_or_else(|| String::from("none"))
+    }
+
+    // Take out the value of the required flag.
+    fn require(&mut self, name: &str) -> Result<String, OperationError> {
+        match self.take(name) {
+            Some(value) => Ok(value),
+            None => Err(OperationError::new(&format!("Missing the required flag \"{}\" of the \"{}\" operation.", name, self.operation))),
+        }
+    }
+}
+
+// Find the definition of the named flag.
+fn find_flag(name: &str) -> Option<&'static FlagDefinition> {
+    FLAG_DEFINITIONS.iter().find(|definition| definition.name == name)
+}
+
+// Check if the arguments use the named flags: any flag with a value or the help flag is given.
+// The switches alone are the flags of the positional form.
+pub fn uses_named_flags(args: &[String]) -> bool {
+    args.iter().any(|arg| match find_flag(arg) {
+        Some(definition) => definition.kind != FlagKind::Switch || definition.name == HELP_FLAG,
+        None => false,
+    })
+}
+
+// Find the named flag of the option or the switch of the positional form, for the errors of the unsupported options.
+pub fn option_flag_name(option: &str) -> &str {
+    for definition in FLAG_DEFINITIONS {
+        match definition.kind {
+            FlagKind::Option(prefix) if option.starts_with(prefix) => return definition.name,
+            FlagKind::Switch if option == definition.name => return definition.name,
+            _ => (),
+        }
+    }
+    option
+}
+
+// Translate the named flags into the arguments of the positional form. The unknown, the duplicate and the missing flags,
+// the flags without the values and the positional arguments among the flags are the errors. The flags, which are not used
+// by the operation, are the errors as well, except for the options, which are checked with the options of the operation.
+// The unknown cipher is passed on, so it is reported as for the positional form.
+pub fn named_flags_to_args(args: &[String]) -> Result<NamedArgs, OperationError> {
+    let mut flags: Vec<(&'static FlagDefinition, Option<String>)> = Vec::new();
+    let mut arg_iterator = args.iter();
+
+    while let Some(arg) = arg_iterator.next() {
+        let definition = match find_flag(arg) {
+            Some(definition) => definition,
+            None if arg.starts_with("--") => {
+                return Err(OperationError::new(&format!("Received the unknown flag \"{}\", see \"{}\" for the list of the flags.", arg, HELP_FLAG)));
+            }
+            None => {
+                return Err(OperationError::new(&format!(
+                    "Received the positional argument \"{}\" together with the named flags, use either the positional arguments or the named flags, e.g. \"--text <text>\".",
+                    arg
+                )));
+            }
+        };
+        if flags.iter().any(|(given, _)| given.name == definition.name) {
+            return Err(OperationError::new(&format!("Received the flag \"{}\" more than once.", definition.name)));
+        }
+
+        let value = match definition.kind {
+            FlagKind::Switch => None,
+            FlagKind::Positional | FlagKind::Option(_) => match arg_iterator.next() {
+                Some(value) if find_flag(value).is_none() => Some(value.clone()),
+                _ => return Err(OperationError::new(&format!("Did not receive a value of the flag \"{}\", expected \"{} {}\".", definition.name, definition.name, definition.value))),
+            },
+        };
+        flags.push((definition, value));
+    }
+
+    if flags.iter().any(|(definition, _)| definition.name == HELP_FLAG) {
+        return Ok(NamedArgs { positional: vec![String::from("help")], options: Vec::new() });
+    }
+
+    let mut given = GivenFlags { flags, operation: String::new() };
+    let cipher = given.require("--cipher")?;
+    let mode = given.require("--mode")?;
+    let output = given.require("--out")?;
+    given.operation = format!("{} {}", cipher, mode);
+
+    let mut positional = vec![cipher.clone(), mode.clone(), output];
+    let symmetric = ["caesar", "vigenere", "xor", "otp", "affine", "railfence"].contains(&cipher.as_str());
+    match (cipher.as_str(), mode.as_str()) {
+        ("caesar", "bruteforce") | ("vigenere", "analyze") | ("otp", "encrypt") => positional.push(symmetric_target(&mut given)?),
+        (_, "encrypt") | (_, "decrypt") if symmetric => {
+            positional.push(symmetric_target(&mut given)?);
+            positional.push(given.require("--key")?);
+        }
+        ("df", "generate") => {
+            if given.has("--group") {
+                if let Some(name) = ["--prime", "--base"].iter().find(|name| given.has(name)) {
+                    return Err(OperationError::new(&format!("Received the flag \"{}\" together with \"--group\", the MODP group replaces the shared prime and base.", name)));
+                }
+                positional.push(given.take_or_none("--secret-a"));
+                positional.push(given.take_or_none("--secret-b"));
+                positional.push(given.require("--group")?);
+            } else if ["--prime", "--base", "--secret-a", "--secret-b"].iter().any(|name| given.has(name)) {
+                for name in ["--prime", "--base", "--secret-a", "--secret-b"] {
+                    positional.push(given.take_or_none(name));
+                }
+            }
+        }
+        ("df", "crack") => {
+            for name in ["--prime", "--base", "--public-value"] {
+                positional.push(given.require(name)?);
+            }
+        }
+        ("df", "multi") => {
+            positional.push(given.take_or_none("--prime"));
+            positional.push(given.take_or_none("--base"));
+            positional.extend(given.require("--secrets")?.split(',').map(|secret| secret.trim().to_string()));
+        }
+        ("rsa", "generate") => positional.extend(given.take("--bits")),
+        ("rsa", "generate-from") => {
+            for name in ["--prime-p", "--prime-q", "--exponent"] {
+                positional.push(given.require(name)?);
+            }
+        }
+        ("rsa", "bruteforce") => {
+            positional.push(given.require("--exponent")?);
+            positional.push(given.require("--modulus")?);
+            let strategy = given.take("--strategy");
+            match given.take("--threads") {
+                Some(threads) => positional.push(threads),
+                None if strategy.is_some() => positional.push(String::from("none")),
+                None => (),
+            }
+            positional.extend(strategy);
+        }
+        ("rsa", "common-modulus") => {
+            for name in ["--text", "--second-text", "--exponent", "--second-exponent", "--modulus"] {
+                positional.push(given.require(name)?);
+            }
+        }
+        ("rsa", "encrypt") | ("rsa", "decrypt") => {
+            positional.push(rsa_target(&mut given)?);
+            if given.has("--output-file") {
+                positional.push(given.require("--output-file")?);
+            }
+            match given.take("--key-file") {
+                Some(key_file) => positional.push(format!("file:{}", key_file)),
+                None => {
+                    positional.push(given.require("--exponent")?);
+                    positional.push(given.require("--modulus")?);
+                }
+            }
+        }
+        (_, _) if symmetric || cipher == "df" || cipher == "rsa" => {
+            return Err(OperationError::new(&format!("The mode \"{}\" of the \"--mode\" flag is not supported by the \"{}\" cipher, see \"{}\" for the operations.", mode, cipher, HELP_FLAG)));
+        }
+        // The unknown cipher is reported by the parsing of the positional arguments.
+        _ => return Ok(NamedArgs { positional, options: Vec::new() }),
+    }
+
+    let mut options = Vec::new();
+    for (definition, value) in given.flags.iter() {
+        match (&definition.kind, value) {
+            (FlagKind::Option(prefix), Some(value)) => options.push(format!("{}{}", prefix, value)),
+            (FlagKind::Switch, _) => options.push(definition.name.to_string()),
+            _ => {
+                return Err(OperationError::new(&format!("The flag \"{}\" is not supported by the \"{}\" operation together with the given flags.", definition.name, given.operation)));
+            }
+        }
+    }
+
+    Ok(NamedArgs { positional, options })
+}
+
+// Take out the text or the input file of the operation, exactly one of them is required.
+fn take_target(given: &mut GivenFlags) -> Result<(Option<String>, Option<String>), OperationError> {
+    match (given.take("--text"), given.take("--in")) {
+        (Some(_), Some(_)) => Err(OperationError::new(&format!("Received both the \"--text\" and the \"--in\" flags of the \"{}\" operation, only one of them is allowed.", given.operation))),
+        (None, None) => Err(OperationError::new(&format!("Missing the required flag \"--text\" or \"--in\" of the \"{}\" operation.", given.operation))),
+        target => Ok(target),
+    }
+}
+
+// The target of the symmetric ciphers: the text, the "file:<path>" of the input file or "-" for the standard input.
+fn symmetric_target(given: &mut GivenFlags) -> Result<String, OperationError> {
+    match take_target(given)? {
+        (_, Some(path)) if path == "-" => Ok(path),
+        (_, Some(path)) => Ok(format!("file:{}", path)),
+        (text, None) => Ok(text.unwrap_or_default()),
+    }
+}
+
+// The target of the RSA encryption and decryption: the text or the path of the input file, which is read by the binary mode
+// or the streaming into the output file, "-" reads the text from the standard input.
+fn rsa_target(given: &mut GivenFlags) -> Result<String, OperationError> {
+    match take_target(given)? {
+        (_, Some(path)) if path != "-" && !given.has("--binary") && !given.has("--output-file") => Err(OperationError::new(&format!(
+            "The input file of the \"{}\" operation requires the \"--binary\" or the \"--output-file\" flag, use \"--text\" for the text.",
+            given.operation
+        ))),
+        (_, Some(path)) => Ok(path),
+        (text, None) => Ok(text.unwrap_or_default()),
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::logic::flags::{named_flags_to_args, option_flag_name, uses_named_flags, FlagKind, NamedArgs, FLAG_DEFINITIONS};
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    // Test the detection of the named flags, the switches alone keep the positional form.
+    #[test]
+    fn test_uses_named_flags() {
+        assert!(uses_named_flags(&to_args(&["--cipher", "caesar"])));
+        assert!(uses_named_flags(&to_args(&["caesar", "encrypt", "console", "hello", "3", "--key", "3"])));
+        assert!(uses_named_flags(&to_args(&["--help"])));
+        assert!(!uses_named_flags(&to_args(&["caesar", "encrypt", "console", "hello", "3", "--alpha"])));
+        assert!(!uses_named_flags(&to_args(&["rsa", "encrypt", "console", "--unknown", "3", "55"])));
+    }
+
+    // Test the translation of the flags in any order into the positional arguments and the options.
+    #[test]
+    fn test_named_flags_to_args() {
+        let expected = NamedArgs { positional: to_args(&["vigenere", "encrypt", "both", "file:msg.txt", "k"]), options: to_args(&["--alpha"]) };
+        assert_eq!(named_flags_to_args(&to_args(&["--cipher", "vigenere", "--mode", "encrypt", "--out", "both", "--key", "k", "--in", "msg.txt", "--alpha"])).unwrap(), expected);
+        assert_eq!(named_flags_to_args(&to_args(&["--alpha", "--in", "msg.txt", "--key", "k", "--out", "both", "--mode", "encrypt", "--cipher", "vigenere"])).unwrap(), expected);
+
+        let expected = NamedArgs { positional: to_args(&["df", "multi", "console", "none", "5", "6", "none", "7"]), options: Vec::new() };
+        assert_eq!(named_flags_to_args(&to_args(&["--cipher", "df", "--mode", "multi", "--out", "console", "--base", "5", "--secrets", "6, none,7"])).unwrap(), expected);
+
+        let expected = NamedArgs { positional: to_args(&["help"]), options: Vec::new() };
+        assert_eq!(named_flags_to_args(&to_args(&["--cipher", "caesar", "--help"])).unwrap(), expected);
+    }
+
+    // Test every flag of the help message has a value name exactly when it is not a switch, the names are unique.
+    #[test]
+    fn test_flag_definitions() {
+        for (index, definition) in FLAG_DEFINITIONS.iter().enumerate() {
+            assert!(definition.name.starts_with("--"), "{}", definition.name);
+            assert_eq!(definition.value.is_empty(), definition.kind == FlagKind::Switch, "{}", definition.name);
+            assert!(FLAG_DEFINITIONS[index + 1..].iter().all(|other| other.name != definition.name), "{}", definition.name);
+        }
+        assert_eq!(option_flag_name("padding=raw"), "--padding");
+        assert_eq!(option_flag_name("--armor"), "--armor");
+    }
+}
@@ -0,0 +1,204 @@
+use std::error::Error;
+
+use crate::crypto::caesar::CaesarCandidate;
+use crate::crypto::diffie_hellman::{DiffieHellmanResult, DiscreteLogResult, MultiDiffieHellmanResult};
+use crate::crypto::otp::OtpResult;
+use crate::crypto::rsa::{BruteforceResult, RsaKeyPair, RsaResult};
+use crate::crypto::vigenere::analysis::VigenereAnalysis;
+use crate::encoding::string_hex_encode;
+use crate::logic::config::{Cipher, Mode};
+#[cfg(not(feature = "serde"))]
+use crate::logic::error::OperationError;
+
+// The JSON output prints the result of the operation as a single JSON document on one line:
+//
+//     {"cipher":"caesar","mode":"encrypt","result":{"text":"4B4C4D"}}
+//
+// The cipher and the mode are their names on the command line. The result is the object of the result struct
+// with the field names of the struct, the big integers are the decimal strings. The texts are {"text": ...},
+// the raw bytes of the RSA binary mode are {"hex": ...}, the streamed RSA file is {"path": ..., "written_bytes": ...}
+// and the Caesar bruteforce candidates are {"candidates": [...]}. The errors are {"error": ..., "code": <exit code>}.
+
+// Error for the JSON output mode of the tool, built without the serde feature.
+pub const JSON_FEATURE_REQUIRED: &str = "The \"json\" output mode requires the tool built with the \"serde\" feature, e.g. \"cargo build --release --features serde\".";
+
+// The result of an operation in the JSON output, serialized as the "result" object of the JSON document.
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum JsonResult<'a> {
+    Text { text: String },
+    Hex { hex: String },
+    File { path: &'a str, written_bytes: u64 },
+    Otp(&'a OtpResult),
+    CaesarCandidates { candidates: &'a [CaesarCandidate] },
+    VigenereAnalysis(&'a VigenereAnalysis),
+    DiffieHellman(&'a DiffieHellmanResult),
+    DiffieHellmanMulti(&'a MultiDiffieHellmanResult),
+    DiffieHellmanCrack(&'a DiscreteLogResult),
+    RsaKeyPair(&'a RsaKeyPair),
+    RsaBruteforce(&'a BruteforceResult),
+}
+
+// Implement methods on the JSON result enum.
+impl<'a> JsonResult<'a> {
+    // The JSON result of the RSA calculations.
+    pub fn from_rsa(rsa_result: &'a RsaResult) -> Result<JsonResult<'a>, Box<dyn Error>> {
+        Ok(match rsa_result {
+            RsaResult::KeyPair(key_pair) => JsonResult::RsaKeyPair(key_pair),
+            RsaResult::StringResult(string_result) => JsonResult::Text { text: string_result.clone() },
+            RsaResult::BytesResult(bytes_result) => JsonResult::Hex { hex: string_hex_encode(bytes_result)? },
+            RsaResult::FileResult { path, written_bytes } => JsonResult::File { path, written_bytes: *written_bytes },
+            RsaResult::BruteforceRSAResult(bruteforce_result) => JsonResult::RsaBruteforce(bruteforce_result),
+        })
+    }
+}
+
+// The JSON document of the result.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonDocument<'a> {
+    cipher: &'a str,
+    mode: &'a str,
+    result: &'a JsonResult<'a>,
+}
+
+// The JSON document of the error.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonError<'a> {
+    error: &'a str,
+    code: i32,
+}
+
+// Serialize the result of the operation of the cipher and the mode into the JSON document.
+#[cfg(feature = "serde")]
+pub fn json_document(cipher: &Cipher, mode: &Mode, result: &JsonResult) -> Result<String, Box<dyn Error>> {
+    Ok(serde_json::to_string(&JsonDocument { cipher: cipher.command_name(), mode: mode.command_name(), result })?)
+}
+
+// The tool without the serde feature has no JSON output, the configuration denies it before any calculations.
+#[cfg(not(feature = "serde"))]
+pub fn json_document(cipher: &Cipher, mode: &Mode, result: &JsonResult) -> Result<String, Box<dyn Error>> {
+    Err(Box::new(OperationError::new(JSON_FEATURE_REQUIRED)))
+}
+
+// Print the error with the exit code of the tool as the JSON document into the standard output, so the consumer
+// of the JSON output never parses a text. The tool without the serde feature prints the message into the standard error.
+pub fn print_json_error(message: &str, code: i32) {
+    #[cfg(feature = "serde")]
+    match serde_json::to_string(&JsonError { error: message.trim(), code }) {
+        Ok(error_document) => return println!("{}", error_document),
+        Err(e) => eprintln!("Failed to serialize the error into JSON: {}.", e),
+    }
+
+    eprintln!("{}", message);
+}
+
+// Test module.
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::crypto::caesar::CaesarCandidate;
+    use crate::crypto::diffie_hellman::{DiffieHellmanResult, DiscreteLogResult, MultiDiffieHellmanResult};
+    use crate::crypto::otp::OtpResult;
+    use crate::crypto::rsa::{BruteforceResult, RsaKeyPair, RsaResult};
+    use crate::crypto::vigenere::analysis::{KeyLengthCandidate, VigenereAnalysis};
+    use crate::logic::bigint::ChonkerInt;
+    use crate::logic::config::{Cipher, Mode};
+    use crate::logic::json::{json_document, JsonResult};
+
+    // Parse the JSON document of the result back into a JSON value for the checks.
+    fn document_value(cipher: Cipher, mode: Mode, result: &JsonResult) -> Value {
+        let document = json_document(&cipher, &mode, result).unwrap();
+        assert!(!document.contains('\n'), "{}", document);
+        serde_json::from_str(&document).unwrap()
+    }
+
+    // Test the JSON documents of the symmetric ciphers: the text, the one-time pad, the Caesar candidates and the Vigenere analysis.
+    #[test]
+    fn test_json_symmetric_results() {
+        let value = document_value(Cipher::Caesar, Mode::Encode, &JsonResult::Text { text: String::from("4B4C4D") });
+        assert_eq!(value, json!({ "cipher": "caesar", "mode": "encrypt", "result": { "text": "4B4C4D" } }));
+
+        let otp_result = OtpResult { ciphertext: String::from("1F"), key: String::from("77") };
+        let value = document_value(Cipher::Otp, Mode::Encode, &JsonResult::Otp(&otp_result));
+        assert_eq!(value["result"], json!({ "ciphertext": "1F", "key": "77" }));
+
+        let candidates = vec![CaesarCandidate { shift: 3, score: 0.5, plaintext: String::from("hello") }];
+        let value = document_value(Cipher::Caesar, Mode::Bruteforce, &JsonResult::CaesarCandidates { candidates: &candidates });
+        assert_eq!(value["mode"], "bruteforce");
+        assert_eq!(value["result"], json!({ "candidates": [{ "shift": 3, "score": 0.5, "plaintext": "hello" }] }));
+
+        let analysis = VigenereAnalysis { key_lengths: vec![KeyLengthCandidate { length: 3, index_of_coincidence: 0.25 }], key: b"key".to_vec(), plaintext: String::from("text"), confidence: 0.75 };
+        let value = document_value(Cipher::Vigenere, Mode::Analyze, &JsonResult::VigenereAnalysis(&analysis));
+        assert_eq!(value["result"], json!({ "key_lengths": [{ "length": 3, "index_of_coincidence": 0.25 }], "key": [107, 101, 121], "plaintext": "text", "confidence": 0.75 }));
+    }
+
+    // Test the JSON documents of Diffie-Hellman results, the big integers are the decimal strings.
+    #[test]
+    fn test_json_diffie_hellman_results() {
+        let df_result = DiffieHellmanResult {
+            prime: ChonkerInt::from(23u64),
+            base: ChonkerInt::from(5u64),
+            secret_a: ChonkerInt::from(6u64),
+            secret_b: ChonkerInt::from(15u64),
+            public_a: ChonkerInt::from(8u64),
+            public_b: ChonkerInt::from(19u64),
+            shared_key: ChonkerInt::from(2u64),
+            derived_key: None,
+        };
+        let value = document_value(Cipher::DiffieHellman, Mode::Generate, &JsonResult::DiffieHellman(&df_result));
+        assert_eq!(value["cipher"], "df");
+        assert_eq!(
+            value["result"],
+            json!({ "prime": "23", "base": "5", "secret_a": "6", "secret_b": "15", "public_a": "8", "public_b": "19", "shared_key": "2", "derived_key": null })
+        );
+
+        let multi_result = MultiDiffieHellmanResult {
+            shared_prime: ChonkerInt::from(23u64),
+            shared_base: ChonkerInt::from(5u64),
+            secrets: vec![ChonkerInt::from(6u64), ChonkerInt::from(15u64)],
+            public_values: vec![ChonkerInt::from(8u64), ChonkerInt::from(19u64)],
+            intermediate_values: vec![vec![ChonkerInt::from(8u64)], vec![ChonkerInt::from(19u64)]],
+            results: vec![ChonkerInt::from(2u64), ChonkerInt::from(2u64)],
+            success: true,
+        };
+        let value = document_value(Cipher::DiffieHellman, Mode::MultiParty, &JsonResult::DiffieHellmanMulti(&multi_result));
+        assert_eq!(value["result"]["results"], json!(["2", "2"]));
+        assert_eq!(value["result"]["success"], true);
+
+        let crack_result = DiscreteLogResult { shared_prime: ChonkerInt::from(23u64), shared_base: ChonkerInt::from(5u64), public_value: ChonkerInt::from(8u64), secret: ChonkerInt::from(6u64) };
+        let value = document_value(Cipher::DiffieHellman, Mode::Crack, &JsonResult::DiffieHellmanCrack(&crack_result));
+        assert_eq!(value["result"], json!({ "shared_prime": "23", "shared_base": "5", "public_value": "8", "secret": "6" }));
+    }
+
+    // Test the JSON documents of the RSA results: the key pair, the bruteforce, the text, the raw bytes in hex and the streamed file.
+    #[test]
+    fn test_json_rsa_results() {
+        let key_pair = RsaKeyPair { public_key_n: ChonkerInt::from(3233u64), public_key_e: ChonkerInt::from(17u64), private_key_d: ChonkerInt::from(413u64), crt: None };
+        let rsa_result = RsaResult::KeyPair(key_pair);
+        let value = document_value(Cipher::RSA, Mode::Generate, &JsonResult::from_rsa(&rsa_result).unwrap());
+        assert_eq!(value, json!({ "cipher": "rsa", "mode": "generate", "result": { "public_key_n": "3233", "public_key_e": "17", "private_key_d": "413" } }));
+
+        let rsa_result = RsaResult::BruteforceRSAResult(BruteforceResult {
+            prime_q: ChonkerInt::from(53u64),
+            prime_p: ChonkerInt::from(61u64),
+            public_key_n: ChonkerInt::from(3233u64),
+            public_key_e: ChonkerInt::from(17u64),
+            private_key_d: ChonkerInt::from(413u64),
+            crt: None,
+        });
+        let value = document_value(Cipher::RSA, Mode::Bruteforce, &JsonResult::from_rsa(&rsa_result).unwrap());
+        assert_eq!(value["result"]["prime_p"], "61");
+        assert_eq!(value["result"]["private_key_d"], "413");
+
+        for (rsa_result, result) in [
+            (RsaResult::StringResult(String::from("secret")), json!({ "text": "secret" })),
+            (RsaResult::BytesResult(vec![0x00, 0x7F, 0xFF]), json!({ "hex": "007FFF" })),
+            (RsaResult::FileResult { path: String::from("video.mp4.enc"), written_bytes: 4096 }, json!({ "path": "video.mp4.enc", "written_bytes": 4096 })),
+        ] {
+            let value = document_value(Cipher::RSA, Mode::Encode, &JsonResult::from_rsa(&rsa_result).unwrap());
+            assert_eq!(value["result"], result);
+        }
+    }
+}
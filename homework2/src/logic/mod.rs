@@ -1,140 +1,467 @@
+use std::error::Error;
 use std::io;
-use std::io::BufWriter;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
 
-use crate::crypto::caesar::{caesar, check_caesar_key};
-use crate::crypto::diffie_hellman::diffie_hellman;
-use crate::crypto::rsa::rsa;
-use crate::crypto::vigenere::vigenere;
-use crate::logic::config::{Cipher, ConfigVariant, Output};
+use crate::crypto::affine::affine_classic;
+use crate::crypto::caesar::{caesar_bruteforce, caesar_classic, caesar_decrypt_stream, caesar_encrypt_stream, decrypted_bytes_to_string, Caesar};
+use crate::crypto::diffie_hellman::{diffie_hellman_crack, diffie_hellman_multi_from_strings, diffie_hellman_with_key_length};
+use crate::crypto::rsa::keyfile::{PRIVATE_KEY_FILE_NAME, PUBLIC_KEY_FILE_NAME};
+use crate::crypto::rsa::{rsa, BruteforceProgress, BruteforceProgressCallback, RsaRequest};
+use crate::crypto::vigenere::analysis::vigenere_analyze;
+use crate::crypto::vigenere::{vigenere_classic, vigenere_decrypt_stream, vigenere_encrypt_stream};
+use crate::crypto::otp::{otp_decrypt, otp_encrypt};
+use crate::crypto::symmetric::{run_symmetric_cipher_bytes, target_text, SymmetricCipher};
+use crate::encoding::string_hex_encode;
+use crate::logic::cancellation::CancellationToken;
+use crate::logic::config::{Cipher, ConfigVariant, Mode, Output};
 use crate::logic::error::OperationError;
-use crate::logic::output::{print_calculation_result, print_df_calculation_result, print_rsa_calculation_result, save_calculation_result, save_df_calculation_result, save_rsa_calculation_result};
+use crate::logic::json::JsonResult;
+use crate::logic::output::{
+    print_caesar_bruteforce_result, print_calculation_result, print_df_calculation_result, print_df_crack_result, print_df_multi_calculation_result, print_json_result, print_otp_calculation_result,
+    print_rsa_calculation_result, print_streamed_result, print_vigenere_analysis_result, save_caesar_bruteforce_result, save_calculation_result, save_df_calculation_result, save_df_crack_result,
+    save_df_multi_calculation_result, save_otp_calculation_result, save_rsa_calculation_result, save_vigenere_analysis_result, RESULT_FILE_NAME,
+};
+
+// The payloads of the results of execute(), re-exported next to RunOutcome to match on it.
+pub use crate::crypto::caesar::CaesarCandidate;
+pub use crate::crypto::diffie_hellman::{DiffieHellmanResult, DiscreteLogResult, MultiDiffieHellmanResult};
+pub use crate::crypto::otp::OtpResult;
+pub use crate::crypto::rsa::{BruteforceResult, RsaKeyPair, RsaResult};
+pub use crate::crypto::vigenere::analysis::VigenereAnalysis;
 
 mod output;
+// Module for the named flags of the command line.
+mod flags;
+
+// Module for the JSON output of the results and the errors.
+pub mod json;
 
 pub mod config;
 
 pub mod error;
 
+pub mod cancellation;
+
 pub mod bigint;
 
-// Function uniting encryption logic.
+// The result of the operation of a configuration, calculated by execute() without any output.
+// The symmetric result is the raw bytes of the ciphertext or of the decrypted text for any output mode,
+// only run() checks the decrypted text to be UTF-8 for the console and the JSON output.
+// The streamed result is the amount of the bytes streamed into the result file, the RSA result of the file streaming is its output file,
+// both are produced only by run(), since the streaming writes the result file.
+#[derive(Debug, PartialEq)]
+pub enum RunOutcome {
+    Symmetric(Vec<u8>),
+    SymmetricStream(u64),
+    Otp(OtpResult),
+    CaesarBruteforce(Vec<CaesarCandidate>),
+    VigenereAnalysis(VigenereAnalysis),
+    DiffieHellman(DiffieHellmanResult),
+    DiffieHellmanMulti(MultiDiffieHellmanResult),
+    DiffieHellmanCrack(DiscreteLogResult),
+    RSA(RsaResult),
+}
+
+// Function uniting encryption logic, the results are printed into the standard output and the progress into the standard error.
 // Tests for this function/tool logic can be found in the integration test under "tests" directory.
 pub fn run(config: ConfigVariant) -> Result<(), Box<dyn std::error::Error>> {
-    let mut symmetric_result = String::new();
-    let mut df_result = Default::default();
-    let mut rsa_result = Default::default();
-    let cipher_mode;
-    let mut output_mode = Output::File;
+    // Get a standard output handle and wrap into a buffer writer. The progress of the RSA bruteforce goes into the error writer,
+    // the thread pool prints nothing, so the output writer receives the whole output of the tool.
+    let mut handle = BufWriter::new(io::stdout());
+
+    run_with_output(config, &mut handle, &mut io::stderr())
+}
+
+// Run the encryption logic with the results printed into the output writer and the progress into the error writer,
+// the result files are saved at the location of the program.
+pub fn run_with_output(config: ConfigVariant, out: &mut impl Write, err: &mut impl Write) -> Result<(), Box<dyn std::error::Error>> {
+    run_in_directory(config, Path::new(""), out, err)
+}
+
+// Run the encryption logic with the results printed into the output writer and the progress into the error writer,
+// the result files, the streamed files and the key files are saved into the base directory, the absolute paths are kept as they are.
+pub fn run_in_directory(config: ConfigVariant, base_dir: &Path, out: &mut impl Write, err: &mut impl Write) -> Result<(), Box<dyn std::error::Error>> {
+    // Keep the output settings of the configuration, the configuration itself is consumed by the calculations.
+    let (cipher, mode, output) = config_operation(&config);
+    let output_path = match &config {
+        ConfigVariant::Symmetric(symmetric_config) => symmetric_config.output_path.as_deref().map(|output_path| base_dir.join(output_path)),
+        _ => None,
+    };
+    let key_files = match &config {
+        ConfigVariant::RSA(rsa_config) => rsa_config.key_dir.as_deref().map(|key_dir| (base_dir.join(key_dir), rsa_config.armored_keys)),
+        _ => None,
+    };
+
+    // Print the progress of the bruteforce on a single updating line of the error writer.
+    // The progress is only informational, a failed write does not stop the bruteforce.
+    let mut progress_reported = false;
+    let progress_callback = match mode {
+        Mode::Bruteforce => Some(Box::new(|bruteforce_progress: BruteforceProgress| {
+            progress_reported = true;
+            let _ = write!(
+                err,
+                "\rWorker {} tested {} candidates, current candidate: {}, elapsed: {:.1} s.",
+                bruteforce_progress.worker,
+                bruteforce_progress.candidates_tested,
+                bruteforce_progress.current_candidate,
+                bruteforce_progress.elapsed.as_secs_f64()
+            );
+            let _ = err.flush();
+        }) as BruteforceProgressCallback),
+        _ => None,
+    };
+
+    let calculation_result = calculate(config, base_dir, progress_callback);
+
+    // End the progress line before any other output.
+    if progress_reported {
+        writeln!(err)?;
+    }
+
+    // The decrypted bytes are saved into the file as they are, so the binary files are restored,
+    // the console and the JSON output accept only a valid UTF-8 text.
+    let outcome = match calculation_result? {
+        RunOutcome::Symmetric(result) if output == Output::Console || output == Output::Json => {
+            let cipher_name = cipher.symmetric_cipher().map_or(cipher.command_name(), |symmetric_cipher| symmetric_cipher.name());
+            RunOutcome::Symmetric(decrypted_bytes_to_string(result, cipher_name)?.into_bytes())
+        }
+        outcome => outcome,
+    };
+    let handle = out;
+
+    // Save the generated key pair into the key files of the requested directory.
+    if let (Some((key_dir, armored_keys)), RunOutcome::RSA(RsaResult::KeyPair(key_pair))) = (&key_files, &outcome) {
+        match armored_keys {
+            true => key_pair.save_to_armored_files(key_dir)?,
+            false => key_pair.save_to_files(key_dir)?,
+        }
+        // The JSON output prints only its document.
+        if output != Output::Json {
+            writeln!(handle, "Successfully saved the RSA key pair into \"{}\" and \"{}\" files at \"{}\".", PUBLIC_KEY_FILE_NAME, PRIVATE_KEY_FILE_NAME, key_dir.display())?;
+        }
+    }
+
+    // Print out calculation result into the console as the JSON document and save it into the requested file of the symmetric ciphers.
+    if output == Output::Json {
+        let json_result = match &outcome {
+            RunOutcome::Symmetric(symmetric_result) => JsonResult::Text { text: printable_result(symmetric_result)? },
+            RunOutcome::SymmetricStream(_) => return Err(Box::new(OperationError::new("The streaming of the input file supports only the file output."))),
+            RunOutcome::Otp(otp_result) => JsonResult::Otp(otp_result),
+            RunOutcome::CaesarBruteforce(caesar_candidates) => JsonResult::CaesarCandidates { candidates: caesar_candidates },
+            RunOutcome::VigenereAnalysis(vigenere_analysis) => JsonResult::VigenereAnalysis(vigenere_analysis),
+            RunOutcome::DiffieHellman(df_result) => JsonResult::DiffieHellman(df_result),
+            RunOutcome::DiffieHellmanMulti(df_multi_result) => JsonResult::DiffieHellmanMulti(df_multi_result),
+            RunOutcome::DiffieHellmanCrack(df_crack_result) => JsonResult::DiffieHellmanCrack(df_crack_result),
+            RunOutcome::RSA(rsa_result) => JsonResult::from_rsa(rsa_result)?,
+        };
+        print_json_result(handle, &cipher, &mode, &json_result, output_path.as_deref())?;
+
+        return Ok(());
+    }
+
+    // Print out calculation result into the console.
+    if output == Output::Console || output == Output::Both {
+        match &outcome {
+            RunOutcome::Symmetric(symmetric_result) => print_calculation_result(handle, &printable_result(symmetric_result)?)?,
+            RunOutcome::SymmetricStream(_) => (),
+            RunOutcome::Otp(otp_result) => print_otp_calculation_result(handle, otp_result)?,
+            RunOutcome::CaesarBruteforce(caesar_candidates) => print_caesar_bruteforce_result(handle, caesar_candidates)?,
+            RunOutcome::VigenereAnalysis(vigenere_analysis) => print_vigenere_analysis_result(handle, vigenere_analysis)?,
+            RunOutcome::DiffieHellman(df_result) => print_df_calculation_result(handle, df_result)?,
+            RunOutcome::DiffieHellmanMulti(df_multi_result) => print_df_multi_calculation_result(handle, df_multi_result)?,
+            RunOutcome::DiffieHellmanCrack(df_crack_result) => print_df_crack_result(handle, df_crack_result)?,
+            RunOutcome::RSA(rsa_result) => print_rsa_calculation_result(handle, rsa_result)?,
+        }
+    }
+
+    // Save calculation results into the file, the result file of the symmetric ciphers is saved at the requested path or at the default one.
+    if output == Output::File || output == Output::Both {
+        let output_path = &output_path.unwrap_or_else(|| base_dir.join(RESULT_FILE_NAME));
+        match &outcome {
+            RunOutcome::Symmetric(symmetric_result) => save_calculation_result(handle, symmetric_result, output_path)?,
+            RunOutcome::SymmetricStream(written_len) => print_streamed_result(handle, *written_len, output_path)?,
+            RunOutcome::Otp(otp_result) => save_otp_calculation_result(handle, otp_result, output_path)?,
+            RunOutcome::CaesarBruteforce(caesar_candidates) => save_caesar_bruteforce_result(handle, caesar_candidates, output_path)?,
+            RunOutcome::VigenereAnalysis(vigenere_analysis) => save_vigenere_analysis_result(handle, vigenere_analysis, output_path)?,
+            RunOutcome::DiffieHellman(df_result) => save_df_calculation_result(handle, df_result, base_dir)?,
+            RunOutcome::DiffieHellmanMulti(df_multi_result) => save_df_multi_calculation_result(handle, df_multi_result, base_dir)?,
+            RunOutcome::DiffieHellmanCrack(df_crack_result) => save_df_crack_result(handle, df_crack_result, base_dir)?,
+            RunOutcome::RSA(rsa_result) => save_rsa_calculation_result(handle, rsa_result, base_dir)?,
+        }
+    }
+
+    handle.flush()?;
+
+    Ok(())
+}
+
+// Calculate the result of the operation of the configuration without any output, the errors are the operation errors of the tool.
+// The streaming of the input files into the result files is denied, it is the output itself and is run only by run().
+pub fn execute(config: ConfigVariant) -> Result<RunOutcome, OperationError> {
+    if is_streamed(&config) {
+        return Err(OperationError::new("The streaming of the input file writes the result file, it is supported only by run() and not by execute()."));
+    }
+
+    match calculate(config, Path::new(""), None) {
+        Ok(outcome) => Ok(outcome),
+        Err(e) => match e.downcast::<OperationError>() {
+            Ok(operation_error) => Err(*operation_error),
+            Err(e) => Err(OperationError::new(&e.to_string())),
+        },
+    }
+}
+
+// The cipher, the mode and the output mode of the configuration.
+fn config_operation(config: &ConfigVariant) -> (Cipher, Mode, Output) {
+    match config {
+        ConfigVariant::Symmetric(symmetric_config) => (symmetric_config.cipher, symmetric_config.mode, symmetric_config.output),
+        ConfigVariant::DF(df_config) => (df_config.cipher, df_config.mode, df_config.output),
+        ConfigVariant::RSA(rsa_config) => (rsa_config.cipher, rsa_config.mode, rsa_config.output),
+    }
+}
 
-    // Determine the appropriate action according to the type of configuration and store the results of calculations.
+// Check if the configuration streams the input file into the result file, Caesar or Vigenere of the large input file or RSA of the input file.
+fn is_streamed(config: &ConfigVariant) -> bool {
     match config {
-        ConfigVariant::Symmetric(mut symmetric_config) => {
-            // Check the chosen cipher and calculate the result.
-            symmetric_result = if symmetric_config.cipher == Cipher::Caesar {
-                // Store cipher and output mode.
-                cipher_mode = Cipher::Caesar;
-                output_mode = symmetric_config.output;
-
-                // Check Caesar key, only numbers are accepted.
-                if !check_caesar_key(&symmetric_config.key) {
-                    return Err(Box::new(OperationError::new("Received incorrect key for Caesar processing, only a number value as a key is accepted.")));
+        ConfigVariant::Symmetric(symmetric_config) => symmetric_config.stream_path.is_some(),
+        ConfigVariant::RSA(rsa_config) => matches!(rsa_config.request, RsaRequest::EncryptStream { .. } | RsaRequest::DecryptStream { .. }),
+        ConfigVariant::DF(_) => false,
+    }
+}
+
+// Determine the appropriate action according to the type of configuration and calculate the result,
+// the streamed result files are created in the base directory, the progress of the RSA bruteforce is reported to the callback.
+fn calculate(config: ConfigVariant, base_dir: &Path, progress_callback: Option<BruteforceProgressCallback>) -> Result<RunOutcome, Box<dyn Error>> {
+    match config {
+        ConfigVariant::Symmetric(symmetric_config) => {
+            // Check the chosen cipher and mode and calculate the result. The key searches and the one-time pad produce their own results,
+            // the classic mode produces a plain text, the rest is driven through the symmetric cipher of the cipher type with the hex ciphertext.
+            if let Some(stream_path) = &symmetric_config.stream_path {
+                // The large input file is streamed into the result file, only the amount of the written bytes is kept.
+                let result_path = base_dir.join(symmetric_config.output_path.as_deref().unwrap_or(RESULT_FILE_NAME));
+                Ok(RunOutcome::SymmetricStream(stream_symmetric_file(&symmetric_config.cipher, &symmetric_config.mode, stream_path, &result_path, &symmetric_config.key)?))
+            } else if symmetric_config.cipher == Cipher::Caesar && symmetric_config.mode == Mode::Bruteforce {
+                // The bruteforce ranks the candidates of all shifts and keeps the requested amount of the best ones.
+                let mut candidates = caesar_bruteforce(target_text(&symmetric_config.target)?)?;
+                if let Some(amount) = symmetric_config.candidates {
+                    candidates.truncate(amount);
+                }
+                Ok(RunOutcome::CaesarBruteforce(candidates))
+            } else if symmetric_config.cipher == Cipher::Vigenere && symmetric_config.mode == Mode::Analyze {
+                // The analysis guesses the key and the plaintext.
+                Ok(RunOutcome::VigenereAnalysis(vigenere_analyze(target_text(&symmetric_config.target)?)?))
+            } else if symmetric_config.cipher == Cipher::Otp {
+                // The encryption produces the ciphertext and the generated key, the decryption produces the text.
+                match symmetric_config.mode {
+                    Mode::Encode => Ok(RunOutcome::Otp(otp_encrypt(&symmetric_config.target)?)),
+                    Mode::Decode => Ok(RunOutcome::Symmetric(otp_decrypt(target_text(&symmetric_config.target)?, &symmetric_config.key)?.into_bytes())),
+                    _ => Err(Box::new(OperationError::new("Received an incorrect argument for the encryption mode of the one-time pad. Correct values: \"encrypt\" or \"decrypt\"."))),
                 }
-                caesar(&symmetric_config.mode, &mut symmetric_config.target, &symmetric_config.key)?
+            } else if symmetric_config.classic {
+                let target = target_text(&symmetric_config.target)?;
+                let classic_result = match symmetric_config.cipher {
+                    Cipher::Caesar => {
+                        // Check Caesar key, only numbers are accepted.
+                        Caesar.validate_key(&symmetric_config.key)?;
+                        caesar_classic(&symmetric_config.mode, target, &symmetric_config.key)?
+                    }
+                    Cipher::Vigenere => vigenere_classic(&symmetric_config.mode, target, &symmetric_config.key)?,
+                    Cipher::Affine => affine_classic(&symmetric_config.mode, target, &symmetric_config.key)?,
+                    _ => return Err(Box::new(OperationError::new("The classic mode is supported only for Caesar, Vigenere or affine cipher."))),
+                };
+
+                Ok(RunOutcome::Symmetric(classic_result.into_bytes()))
             } else {
-                cipher_mode = Cipher::Vigenere;
-                vigenere(&symmetric_config.mode, &mut symmetric_config.target, &symmetric_config.key)?
-            };
+                match symmetric_config.cipher.symmetric_cipher() {
+                    Some(cipher) => Ok(RunOutcome::Symmetric(run_symmetric_cipher_bytes(cipher.as_ref(), &symmetric_config.mode, &symmetric_config.target, &symmetric_config.key, symmetric_config.encoding)?)),
+                    None => Err(Box::new(OperationError::new("The cipher type is not a symmetric cipher."))),
+                }
+            }
         }
         ConfigVariant::DF(df_config) => {
-            // Store cipher and output mode.
-            cipher_mode = Cipher::DiffieHellman;
-            output_mode = df_config.output;
-
             let shared_prime = df_config.shared_prime;
             let shared_base = df_config.shared_base;
-            let secret_a = df_config.secret_a;
-            let secret_b = df_config.secret_b;
 
-            df_result = diffie_hellman(shared_prime, shared_base, secret_a, secret_b)?
+            // The multi-party exchange produces its own result with the values of every participant.
+            // The discrete logarithm search produces the recovered secret.
+            match df_config.mode {
+                Mode::MultiParty => Ok(RunOutcome::DiffieHellmanMulti(diffie_hellman_multi_from_strings(shared_prime, shared_base, &df_config.secrets)?)),
+                Mode::Crack => {
+                    let public_value = df_config.public_value.unwrap_or_default();
+                    Ok(RunOutcome::DiffieHellmanCrack(diffie_hellman_crack(&shared_prime.unwrap_or_default(), &shared_base.unwrap_or_default(), &public_value)?))
+                }
+                _ => Ok(RunOutcome::DiffieHellman(diffie_hellman_with_key_length(shared_prime, shared_base, df_config.secret_a, df_config.secret_b, df_config.key_length)?)),
+            }
         }
         ConfigVariant::RSA(rsa_config) => {
-            // Store cipher and output mode.
-            cipher_mode = Cipher::RSA;
-            output_mode = rsa_config.output;
+            // Bound the key generation or the bruteforce with the requested timeout.
+            let cancellation = match rsa_config.timeout_secs {
+                Some(timeout_secs) => CancellationToken::with_timeout(Duration::from_secs(timeout_secs)),
+                None => CancellationToken::new(),
+            };
 
-            let target = rsa_config.target;
-            let key_exponent = rsa_config.key_exponent;
-            let key_modulus = rsa_config.key_modulus;
-            let thread_count = rsa_config.thread_count;
+            // The result file of the streamed RSA encryption or decryption is created in the base directory.
+            let mut request = rsa_config.request;
+            match &mut request {
+                RsaRequest::EncryptStream { output, .. } | RsaRequest::DecryptStream { output, .. } => *output = base_dir.join(output.as_str()).to_string_lossy().into_owned(),
+                _ => (),
+            }
 
-            rsa_result = rsa(&rsa_config.mode, target, key_exponent, key_modulus, thread_count)?;
+            Ok(RunOutcome::RSA(rsa(request, progress_callback, &cancellation)?))
         }
     }
+}
 
+// Stream the input file into the result file under Caesar or Vigenere cipher chunk by chunk, the input file of any size fits into the memory.
+// Returns the amount of the written bytes.
+fn stream_symmetric_file(cipher: &Cipher, mode: &Mode, input_path: &str, output_path: &Path, key: &str) -> Result<u64, Box<dyn Error>> {
+    let input_file = match File::open(input_path) {
+        Ok(file) => file,
+        Err(e) => return Err(Box::new(OperationError::new(&format!("Failed to open the input file \"{}\" for the streaming: {}.", input_path, e)))),
+    };
+    let output_file = match File::create(output_path) {
+        Ok(file) => file,
+        Err(e) => return Err(Box::new(OperationError::new(&format!("Failed to create the result file \"{}\" for the streaming: {}.", output_path.display(), e)))),
+    };
 
-    // Get a standard output handle, lock it, wrap into a buffer writer and allocate on heap.
-    let stdout = io::stdout();
-    let mut handle = Box::new(BufWriter::new(stdout.lock()));
+    match (cipher, mode) {
+        (Cipher::Caesar, Mode::Encode) => caesar_encrypt_stream(input_file, output_file, key),
+        (Cipher::Caesar, Mode::Decode) => caesar_decrypt_stream(input_file, output_file, key),
+        (Cipher::Vigenere, Mode::Encode) => vigenere_encrypt_stream(input_file, output_file, key),
+        (Cipher::Vigenere, Mode::Decode) => vigenere_decrypt_stream(input_file, output_file, key),
+        _ => Err(Box::new(OperationError::new("The streaming of the input file is supported only for the encryption and the decryption of Caesar or Vigenere cipher."))),
+    }
+}
 
-    // Output the produced result in a requested way.
-    // And match the output according to the result types.
-    match cipher_mode {
-        Cipher::DiffieHellman => {
-            // Produce an output for Diffie-Hellman algorithm.
-            match output_mode {
-                // Print out calculation result into the console.
-                Output::Console => {
-                    print_df_calculation_result(&mut handle, &df_result)?;
-                }
-                // Save calculation results into the file.
-                Output::File => {
-                    save_df_calculation_result(&df_result)?;
-                }
-                // Print out calculation result into the console and save it into the file.
-                Output::Both => {
-                    print_df_calculation_result(&mut handle, &df_result)?;
-                    save_df_calculation_result(&df_result)?;
-                }
-            }
+// Show the result of the symmetric ciphers in the console: a valid UTF-8 text as it is, the raw decrypted bytes of a binary file in hex.
+fn printable_result(result: &[u8]) -> Result<String, Box<dyn Error>> {
+    match std::str::from_utf8(result) {
+        Ok(text) => Ok(text.to_string()),
+        Err(_) => string_hex_encode(result),
+    }
+}
+
+// Test module.
+#[cfg(test)]
+mod tests {
+    use crate::crypto::rsa::RsaResult;
+    use crate::crypto::symmetric::{run_symmetric_cipher, run_symmetric_cipher_bytes};
+    use crate::encoding::Encoding;
+    use crate::logic::bigint::ChonkerInt;
+    use crate::logic::config::{Cipher, ConfigVariant, Mode};
+    use crate::logic::{execute, RunOutcome};
+
+    // Create the configuration of the arguments from the command line.
+    fn config_of(args: &[&str]) -> ConfigVariant {
+        ConfigVariant::new(args.iter().map(|s| s.to_string())).unwrap()
+    }
+
+    // Test the symmetric ciphers of the cipher types through the shared path on the known answers.
+    #[test]
+    fn test_cipher_symmetric_cipher() {
+        for (cipher, key, ciphertext) in [
+            (Cipher::Caesar, "1", "69666D6D70"),
+            (Cipher::Vigenere, "\u{1}\u{2}", "69676D6E70"),
+            (Cipher::Xor, "key", "030015070A"),
+            (Cipher::Affine, "1,1", "69666D6D70"),
+            (Cipher::RailFence, "2", "686C6F656C"),
+        ] {
+            let symmetric_cipher = match cipher.symmetric_cipher() {
+                Some(symmetric_cipher) => symmetric_cipher,
+                None => panic!("A symmetric cipher was expected for {:?} (test_cipher_symmetric_cipher)", cipher),
+            };
+
+            assert_eq!(run_symmetric_cipher(symmetric_cipher.as_ref(), &Mode::Encode, "hello", key).unwrap(), ciphertext);
+            assert_eq!(run_symmetric_cipher(symmetric_cipher.as_ref(), &Mode::Decode, ciphertext, key).unwrap(), "hello");
         }
-        Cipher::RSA => {
-            // Produce an output for RSA algorithm.
-            match output_mode {
-                // Print out calculation result into the console.
-                Output::Console => {
-                    print_rsa_calculation_result(&mut handle, &rsa_result)?;
-                }
-                // Save calculation results into the file.
-                Output::File => {
-                    save_rsa_calculation_result(&rsa_result)?;
-                }
-                // Print out calculation result into the console and save it into the file.
-                Output::Both => {
-                    print_rsa_calculation_result(&mut handle, &rsa_result)?;
-                    save_rsa_calculation_result(&rsa_result)?;
-                }
+
+        for cipher in [Cipher::Otp, Cipher::DiffieHellman, Cipher::RSA] {
+            assert!(cipher.symmetric_cipher().is_none());
+        }
+    }
+
+    // Test the base64 ciphertexts of Caesar and Vigenere: the known answers, the decryption with the selected encoding
+    // and the denied one with the default hex encoding, and the hint for the hex ciphertext under the base64 encoding.
+    #[test]
+    fn test_symmetric_cipher_base64() {
+        for (cipher, key, ciphertext, hex_ciphertext) in [(Cipher::Caesar, "1", "aWZtbXA=", "69666D6D70"), (Cipher::Vigenere, "\u{1}\u{2}", "aWdtbnA=", "69676D6E70")] {
+            let symmetric_cipher = cipher.symmetric_cipher().unwrap();
+
+            assert_eq!(run_symmetric_cipher_bytes(symmetric_cipher.as_ref(), &Mode::Encode, b"hello", key, Some(Encoding::Base64)).unwrap(), ciphertext.as_bytes());
+            assert_eq!(run_symmetric_cipher_bytes(symmetric_cipher.as_ref(), &Mode::Decode, ciphertext.as_bytes(), key, Some(Encoding::Base64)).unwrap(), b"hello");
+            assert!(run_symmetric_cipher_bytes(symmetric_cipher.as_ref(), &Mode::Decode, ciphertext.as_bytes(), key, None).is_err());
+
+            let error_message = run_symmetric_cipher_bytes(symmetric_cipher.as_ref(), &Mode::Decode, hex_ciphertext.as_bytes(), key, Some(Encoding::Base64)).unwrap_err().to_string();
+            assert!(error_message.contains("choose \"encoding=hex\""), "{}", error_message);
+        }
+    }
+
+    // Test the structured results of the symmetric configurations: the hex ciphertext, the decrypted text,
+    // the one-time pad result and the ranked Caesar candidates.
+    #[test]
+    fn test_execute_symmetric() {
+        assert_eq!(execute(config_of(&["caesar", "encrypt", "console", "HAL", "1"])).unwrap(), RunOutcome::Symmetric(b"49424D".to_vec()));
+        assert_eq!(execute(config_of(&["caesar", "decrypt", "file", "49424D", "1"])).unwrap(), RunOutcome::Symmetric(b"HAL".to_vec()));
+        assert_eq!(execute(config_of(&["otp", "decrypt", "console", "696B", "0102"])).unwrap(), RunOutcome::Symmetric(b"hi".to_vec()));
+        // The decrypted bytes are raw for any output mode, only run() denies the invalid UTF-8 text in the console.
+        assert_eq!(execute(config_of(&["xor", "decrypt", "console", "FF", "key"])).unwrap(), RunOutcome::Symmetric(vec![0xFF ^ b'k']));
+
+        match execute(config_of(&["otp", "encrypt", "console", "hi"])).unwrap() {
+            RunOutcome::Otp(otp_result) => assert_eq!((otp_result.ciphertext.len(), otp_result.key.len()), (4, 4)),
+            outcome => panic!("Expected the one-time pad result, received: {:?} (test_execute_symmetric)", outcome),
+        }
+        match execute(config_of(&["caesar", "bruteforce", "console", "64777764666E2364772367647A71", "top=3"])).unwrap() {
+            RunOutcome::CaesarBruteforce(candidates) => {
+                assert_eq!(candidates.len(), 3);
+                assert_eq!((candidates[0].shift, candidates[0].plaintext.as_str()), (3, "attack at dawn"));
             }
+            outcome => panic!("Expected the Caesar candidates, received: {:?} (test_execute_symmetric)", outcome),
         }
-        _ => {
-            // Produce an output for Caesar or Vigenere ciphers.
-            match output_mode {
-                // Print out calculation result into the console.
-                Output::Console => {
-                    print_calculation_result(&mut handle, &symmetric_result)?;
-                }
-                // Save calculation results into the file.
-                Output::File => {
-                    save_calculation_result(&symmetric_result)?;
-                }
-                // Print out calculation result into the console and save it into the file.
-                Output::Both => {
-                    print_calculation_result(&mut handle, &symmetric_result)?;
-                    save_calculation_result(&symmetric_result)?;
-                }
+    }
+
+    // Test the structured results of the Diffie-Hellman configurations: the exchange of two participants and the discrete logarithm search.
+    #[test]
+    fn test_execute_diffie_hellman() {
+        match execute(config_of(&["df", "generate", "console", "23", "2", "6", "15"])).unwrap() {
+            RunOutcome::DiffieHellman(df_result) => {
+                assert_eq!((df_result.public_a, df_result.public_b), (ChonkerInt::from(18u64), ChonkerInt::from(16u64)));
+                assert_eq!(df_result.shared_key, ChonkerInt::from(4u64));
             }
+            outcome => panic!("Expected the Diffie-Hellman result, received: {:?} (test_execute_diffie_hellman)", outcome),
+        }
+        match execute(config_of(&["df", "crack", "console", "23", "2", "18"])).unwrap() {
+            RunOutcome::DiffieHellmanCrack(df_crack_result) => assert_eq!(df_crack_result.secret, ChonkerInt::from(6u64)),
+            outcome => panic!("Expected the discrete logarithm result, received: {:?} (test_execute_diffie_hellman)", outcome),
         }
     }
 
+    // Test the structured result of the RSA key pair generation from the known primes, the operation error of an incorrect key
+    // and the denied streaming of the input file, which does not create the output file.
+    #[test]
+    fn test_execute_rsa_and_errors() {
+        match execute(config_of(&["rsa", "generate-from", "console", "61", "53", "17"])).unwrap() {
+            RunOutcome::RSA(RsaResult::KeyPair(key_pair)) => {
+                assert_eq!(key_pair.public_key_n, ChonkerInt::from(3233u64));
+                assert_eq!(key_pair.public_key_e, ChonkerInt::from(17u64));
+                assert_eq!(key_pair.private_key_d, ChonkerInt::from(413u64));
+            }
+            outcome => panic!("Expected the RSA key pair, received: {:?} (test_execute_rsa_and_errors)", outcome),
+        }
+
+        let error = execute(config_of(&["caesar", "encrypt", "console", "HAL", "key"])).unwrap_err();
+        assert!(!error.to_string().is_empty());
+        assert!(!error.get_cancelled_flag());
 
-    Ok(())
+        let input_file = std::env::temp_dir().join(format!("enc_execute_stream_{}.bin", std::process::id()));
+        let output_file = std::env::temp_dir().join(format!("enc_execute_stream_{}.out", std::process::id()));
+        std::fs::write(&input_file, b"Streamed data.").unwrap();
+        let config = config_of(&["rsa", "encrypt", "file", &input_file.display().to_string(), &output_file.display().to_string(), "9683922000451682283955009414215846271", "503389953040597954843496152539898795547523683"]);
+        let error = execute(config).unwrap_err();
+        assert!(error.to_string().contains("execute()"), "{}", error);
+        assert!(!output_file.exists());
+        std::fs::remove_file(&input_file).unwrap();
+    }
 }
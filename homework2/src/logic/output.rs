@@ -1,8 +1,21 @@
 use std::fs;
 use std::io::{BufWriter, Write};
+use std::path::Path;
 
-use crate::crypto::diffie_hellman::DiffieHellmanResult;
+use crate::crypto::caesar::CaesarCandidate;
+use crate::crypto::diffie_hellman::{df_participant_name, DiffieHellmanResult, DiscreteLogResult, MultiDiffieHellmanResult};
+use crate::crypto::otp::OtpResult;
 use crate::crypto::rsa::RsaResult;
+use crate::crypto::vigenere::analysis::VigenereAnalysis;
+use crate::encoding::string_hex_encode;
+use crate::logic::config::{Cipher, Mode};
+use crate::logic::flags::FLAG_DEFINITIONS;
+use crate::logic::json::{json_document, JsonResult};
+
+// Name of the file for the raw bytes of the RSA binary encryption/decryption result.
+pub const BINARY_RESULT_FILE_NAME: &str = "calculation_result.bin";
+// Name of the default result file of the symmetric ciphers.
+pub const RESULT_FILE_NAME: &str = "calculation_result.txt";
 
 // Print out calculation result into the console.
 pub fn print_calculation_result(
@@ -18,28 +31,162 @@ pub fn print_calculation_result(
     Ok(())
 }
 
+// Print out the ciphertext and the generated key of the one-time pad encryption into the console.
+pub fn print_otp_calculation_result(handle: &mut impl Write, otp_result: &OtpResult) -> Result<(), std::io::Error> {
+    writeln!(handle, "The result of the one-time pad encryption:")?;
+    write_otp_values(handle, otp_result)?;
+
+    // Print out buffer.
+    handle.flush()?;
+
+    Ok(())
+}
+
+// Write the ciphertext and the key of the one-time pad encryption, one value per line.
+fn write_otp_values(handle: &mut impl Write, otp_result: &OtpResult) -> Result<(), std::io::Error> {
+    writeln!(handle, "Ciphertext: {}", otp_result.ciphertext)?;
+    writeln!(handle, "Key: {}", otp_result.key)?;
+
+    Ok(())
+}
+
+// Print out the ranked candidates of the Caesar bruteforce into the console.
+pub fn print_caesar_bruteforce_result(handle: &mut impl Write, candidates: &[CaesarCandidate]) -> Result<(), std::io::Error> {
+    writeln!(handle, "The result of the Caesar bruteforce, from the most likely plaintext:")?;
+    write_caesar_candidates(handle, candidates)?;
+
+    // Print out buffer.
+    handle.flush()?;
+
+    Ok(())
+}
+
+// Write the rank, the shift, the score and the plaintext of every candidate, one candidate per line.
+// The plaintext is quoted and its control characters are escaped, since most of the candidates are not a text.
+fn write_caesar_candidates(handle: &mut impl Write, candidates: &[CaesarCandidate]) -> Result<(), std::io::Error> {
+    for (rank, candidate) in candidates.iter().enumerate() {
+        writeln!(handle, "{}. Shift: {}, score: {:.3}, plaintext: {:?}", rank + 1, candidate.shift, candidate.score, candidate.plaintext)?;
+    }
+
+    Ok(())
+}
+
+// Print out the guessed key and plaintext of the Vigenere analysis into the console.
+pub fn print_vigenere_analysis_result(handle: &mut impl Write, analysis: &VigenereAnalysis) -> Result<(), std::io::Error> {
+    writeln!(handle, "The result of the Vigenere analysis, the key and the plaintext are the guesses:")?;
+    write_vigenere_analysis(handle, analysis)?;
+
+    // Print out buffer.
+    handle.flush()?;
+
+    Ok(())
+}
+
+// Write the key length candidates with their index of coincidence, the guessed key in hex and as a quoted text,
+// the quoted plaintext and the confidence, one value per line.
+fn write_vigenere_analysis(handle: &mut impl Write, analysis: &VigenereAnalysis) -> Result<(), std::io::Error> {
+    let key_lengths: Vec<String> = analysis.key_lengths.iter().map(|candidate| format!("{} ({:.4})", candidate.length, candidate.index_of_coincidence)).collect();
+    let key_hex = match string_hex_encode(&analysis.key) {
+        Ok(key_hex) => key_hex,
+        Err(e) => return Err(std::io::Error::other(e.to_string())),
+    };
+
+    writeln!(handle, "Key length candidates (index of coincidence): {}", key_lengths.join(", "))?;
+    writeln!(handle, "Guessed key (hex): {}", key_hex)?;
+    writeln!(handle, "Guessed key: {:?}", String::from_utf8_lossy(&analysis.key))?;
+    writeln!(handle, "Guessed plaintext: {:?}", analysis.plaintext)?;
+    writeln!(handle, "Confidence: {:.2}", analysis.confidence)?;
+
+    Ok(())
+}
+
 // Print out calculation result for the Diffie-Hellman calculations into the console.
 pub fn print_df_calculation_result(
     handle: &mut impl Write,
     df_result: &DiffieHellmanResult,
 ) -> Result<(), std::io::Error> {
     writeln!(handle, "The result of the Diffie-Hellman calculations:")?;
-    writeln!(handle, "Shared prime: {}", df_result.shared_prime)?;
-    writeln!(handle, "Shared base: {}", df_result.shared_base)?;
+    writeln!(handle, "Shared prime: {}", df_result.prime)?;
+    writeln!(handle, "Shared base: {}", df_result.base)?;
     writeln!(handle, "Secret A: {}", df_result.secret_a)?;
     writeln!(handle, "Secret B: {}", df_result.secret_b)?;
-    writeln!(handle, "Package from A to B: {}", df_result.package_from_a_to_b)?;
-    writeln!(handle, "Package from B to A: {}", df_result.package_from_b_to_a)?;
-    writeln!(handle, "Result A: {}", df_result.result_a)?;
-    writeln!(handle, "Result B: {}", df_result.result_b)?;
+    writeln!(handle, "Package from A to B: {}", df_result.public_a)?;
+    writeln!(handle, "Package from B to A: {}", df_result.public_b)?;
+    writeln!(handle, "Shared key: {}", df_result.shared_key)?;
+    if let Some(derived_key) = &df_result.derived_key {
+        writeln!(handle, "Derived key: {}", derived_key)?;
+    }
+    writeln!(handle, "Was the operation successful?: {}", df_result.verify())?;
+
+    // Print out buffer.
+    handle.flush()?;
+
+    Ok(())
+}
+
+// Print out calculation result for the multi-party Diffie-Hellman calculations into the console.
+pub fn print_df_multi_calculation_result(
+    handle: &mut impl Write,
+    df_result: &MultiDiffieHellmanResult,
+) -> Result<(), std::io::Error> {
+    writeln!(handle, "The result of the multi-party Diffie-Hellman calculations:")?;
+    write_df_multi_values(handle, df_result)?;
+
+    // Print out buffer.
+    handle.flush()?;
+
+    Ok(())
+}
+
+// Write the values of every participant of the multi-party Diffie-Hellman calculations, one value per line:
+// the secrets, the public values, the intermediate values of each round, sent to the next participant, and the results.
+fn write_df_multi_values(handle: &mut impl Write, df_result: &MultiDiffieHellmanResult) -> Result<(), std::io::Error> {
+    let participants = df_result.secrets.len();
+
+    writeln!(handle, "Shared prime: {}", df_result.shared_prime)?;
+    writeln!(handle, "Shared base: {}", df_result.shared_base)?;
+    writeln!(handle, "Participants: {}", participants)?;
+    for (index, secret) in df_result.secrets.iter().enumerate() {
+        writeln!(handle, "Secret {}: {}", df_participant_name(index), secret)?;
+    }
+    for (index, public_value) in df_result.public_values.iter().enumerate() {
+        writeln!(handle, "Public value from {} to {}: {}", df_participant_name(index), df_participant_name((index + 1) % participants), public_value)?;
+    }
+    // The public values are sent in the first round.
+    for (round, values) in df_result.intermediate_values.iter().enumerate() {
+        for (index, value) in values.iter().enumerate() {
+            writeln!(handle, "Round {} package from {} to {}: {}", round + 2, df_participant_name(index), df_participant_name((index + 1) % participants), value)?;
+        }
+    }
+    for (index, result) in df_result.results.iter().enumerate() {
+        writeln!(handle, "Result {}: {}", df_participant_name(index), result)?;
+    }
     writeln!(handle, "Was the operation successful?: {}", df_result.success)?;
 
+    Ok(())
+}
+
+// Print out the secret, recovered with the discrete logarithm search, into the console.
+pub fn print_df_crack_result(handle: &mut impl Write, df_result: &DiscreteLogResult) -> Result<(), std::io::Error> {
+    writeln!(handle, "The result of the Diffie-Hellman discrete logarithm search:")?;
+    write_df_crack_values(handle, df_result)?;
+
     // Print out buffer.
     handle.flush()?;
 
     Ok(())
 }
 
+// Write the values of the discrete logarithm search, one value per line.
+fn write_df_crack_values(handle: &mut impl Write, df_result: &DiscreteLogResult) -> Result<(), std::io::Error> {
+    writeln!(handle, "Shared prime: {}", df_result.shared_prime)?;
+    writeln!(handle, "Shared base: {}", df_result.shared_base)?;
+    writeln!(handle, "Public value: {}", df_result.public_value)?;
+    writeln!(handle, "Recovered secret: {}", df_result.secret)?;
+
+    Ok(())
+}
+
 // Print out calculation result for the Diffie-Hellman calculations into the console.
 pub fn print_rsa_calculation_result(
     handle: &mut impl Write,
@@ -50,6 +197,7 @@ pub fn print_rsa_calculation_result(
     match rsa_result {
         RsaResult::KeyPair(generate_key_pair) => {
             writeln!(handle, "The result of the RSA key pair generation:")?;
+            writeln!(handle, "Key summary: {}", generate_key_pair.summary())?;
             writeln!(handle, "Key modulus n: {}", generate_key_pair.public_key_n)?;
             writeln!(handle, "Public key exponent e: {}", generate_key_pair.public_key_e)?;
             writeln!(handle, "Private key exponent d: {}", generate_key_pair.private_key_d)?;
@@ -58,8 +206,17 @@ pub fn print_rsa_calculation_result(
             writeln!(handle, "The result of the RSA encryption/decryption calculations:")?;
             writeln!(handle, "Encryption/decryption result: {}", *string_result)?;
         }
+        RsaResult::BytesResult(bytes_result) => {
+            writeln!(handle, "The result of the RSA encryption/decryption calculations of {} bytes:", bytes_result.len())?;
+            writeln!(handle, "Encryption/decryption result in hex: {}", bytes_hex(bytes_result))?;
+        }
+        RsaResult::FileResult { path, written_bytes } => {
+            writeln!(handle, "The result of the RSA encryption/decryption calculations of the file:")?;
+            writeln!(handle, "Encryption/decryption result: {} bytes written into \"{}\"", written_bytes, path)?;
+        }
         RsaResult::BruteforceRSAResult(bruteforce_result) => {
             writeln!(handle, "The result of the RSA bruteforce calculations:")?;
+            writeln!(handle, "Key summary: {}", bruteforce_result.summary())?;
             writeln!(handle, "Prime q: {}", bruteforce_result.prime_q)?;
             writeln!(handle, "Prime p: {}", bruteforce_result.prime_p)?;
             writeln!(handle, "Key modulus n: {}", bruteforce_result.public_key_n)?;
@@ -75,54 +232,180 @@ pub fn print_rsa_calculation_result(
 }
 
 // Save calculation result into the file.
-pub fn save_calculation_result(result: &str) -> Result<(), std::io::Error> {
-    fs::write("calculation_result.txt", result)?;
-    println!("Successfully saved the result of the calculations into \"calculation_result.txt\" file at the location of the program.");
+pub fn save_calculation_result(handle: &mut impl Write, result: &[u8], path: &Path) -> Result<(), std::io::Error> {
+    fs::write(path, result)?;
+    writeln!(handle, "Successfully saved the result of the calculations into {}.", result_file_location(path))?;
     Ok(())
 }
 
+// Report the result of the symmetric ciphers, which was streamed into the file chunk by chunk.
+pub fn print_streamed_result(handle: &mut impl Write, written_len: u64, path: &Path) -> Result<(), std::io::Error> {
+    writeln!(handle, "Successfully streamed {} bytes of the result of the calculations into {}.", written_len, result_file_location(path))?;
+    Ok(())
+}
+
+// Describe the location of the result file, the default files are saved at the location of the program.
+fn result_file_location(path: &Path) -> String {
+    if path == Path::new(RESULT_FILE_NAME) || path == Path::new(BINARY_RESULT_FILE_NAME) {
+        format!("\"{}\" file at the location of the program", path.display())
+    } else {
+        format!("\"{}\" file", path.display())
+    }
+}
+
 // A function that consumes the file handle and by dropping it, closes it.
 fn close_file(_file_handle: fs::File) {}
 
+// Save the ranked candidates of the Caesar bruteforce into the file.
+pub fn save_caesar_bruteforce_result(handle: &mut impl Write, candidates: &[CaesarCandidate], path: &Path) -> Result<(), std::io::Error> {
+    // Create a file or truncate it, write first line.
+    let mut file = fs::File::create(path)?;
+    file.write_fmt(format_args!("The result of the Caesar bruteforce, from the most likely plaintext:\n"))?;
+    close_file(file);
+
+    // Reopen file in appending mode and buffer the handle, after the lines are complete, flush it.
+    let mut file_buffer = BufWriter::new(fs::OpenOptions::new().append(true).open(path)?);
+    write_caesar_candidates(&mut file_buffer, candidates)?;
+    file_buffer.flush()?;
+
+    writeln!(handle, "Successfully saved the result of the Caesar bruteforce into {}.", result_file_location(path))?;
+
+    Ok(())
+}
+
+// Save the guessed key and plaintext of the Vigenere analysis into the file.
+pub fn save_vigenere_analysis_result(handle: &mut impl Write, analysis: &VigenereAnalysis, path: &Path) -> Result<(), std::io::Error> {
+    // Create a file or truncate it, write first line.
+    let mut file = fs::File::create(path)?;
+    file.write_fmt(format_args!("The result of the Vigenere analysis, the key and the plaintext are the guesses:\n"))?;
+    close_file(file);
+
+    // Reopen file in appending mode and buffer the handle, after the lines are complete, flush it.
+    let mut file_buffer = BufWriter::new(fs::OpenOptions::new().append(true).open(path)?);
+    write_vigenere_analysis(&mut file_buffer, analysis)?;
+    file_buffer.flush()?;
+
+    writeln!(handle, "Successfully saved the result of the Vigenere analysis into {}.", result_file_location(path))?;
+
+    Ok(())
+}
+
+// Save the ciphertext and the generated key of the one-time pad encryption into the file.
+pub fn save_otp_calculation_result(handle: &mut impl Write, otp_result: &OtpResult, path: &Path) -> Result<(), std::io::Error> {
+    // Create a file or truncate it, write first line.
+    let mut file = fs::File::create(path)?;
+    file.write_fmt(format_args!("The result of the one-time pad encryption:\n"))?;
+    close_file(file);
+
+    // Reopen file in appending mode and buffer the handle, after the lines are complete, flush it.
+    let mut file_buffer = BufWriter::new(fs::OpenOptions::new().append(true).open(path)?);
+    write_otp_values(&mut file_buffer, otp_result)?;
+    file_buffer.flush()?;
+
+    writeln!(handle, "Successfully saved the result of the one-time pad encryption into {}.", result_file_location(path))?;
+
+    Ok(())
+}
+
 // Save calculation result for the Diffie-Hellman calculations into the console.
-pub fn save_df_calculation_result(df_result: &DiffieHellmanResult) -> Result<(), std::io::Error> {
+pub fn save_df_calculation_result(handle: &mut impl Write, df_result: &DiffieHellmanResult, dir: &Path) -> Result<(), std::io::Error> {
+    let result_path = dir.join(RESULT_FILE_NAME);
+
     // Create a file or truncate it, write first line.
-    let mut file = fs::File::create("calculation_result.txt")?;
+    let mut file = fs::File::create(&result_path)?;
     file.write_fmt(format_args!("The result of the Diffie-Hellman calculations:\n"))?;
     close_file(file);
 
     // Reopen file in appending mode and buffer the handle, after the lines are complete, flush it.
-    let mut file_buffer = BufWriter::new(fs::OpenOptions::new().append(true).open("calculation_result.txt")?);
-    file_buffer.write_fmt(format_args!("Shared prime: {}\n", df_result.shared_prime))?;
-    file_buffer.write_fmt(format_args!("Shared base: {}\n", df_result.shared_base))?;
+    let mut file_buffer = BufWriter::new(fs::OpenOptions::new().append(true).open(&result_path)?);
+    file_buffer.write_fmt(format_args!("Shared prime: {}\n", df_result.prime))?;
+    file_buffer.write_fmt(format_args!("Shared base: {}\n", df_result.base))?;
     file_buffer.write_fmt(format_args!("Secret A: {}\n", df_result.secret_a))?;
     file_buffer.write_fmt(format_args!("Secret B: {}\n", df_result.secret_b))?;
-    file_buffer.write_fmt(format_args!("Package from A to B: {}\n", df_result.package_from_a_to_b))?;
-    file_buffer.write_fmt(format_args!("Package from B to A: {}\n", df_result.package_from_b_to_a))?;
-    file_buffer.write_fmt(format_args!("Result A: {}\n", df_result.result_a))?;
-    file_buffer.write_fmt(format_args!("Result B: {}\n", df_result.result_b))?;
-    file_buffer.write_fmt(format_args!("Was the operation successful?: {}\n", df_result.success))?;
+    file_buffer.write_fmt(format_args!("Package from A to B: {}\n", df_result.public_a))?;
+    file_buffer.write_fmt(format_args!("Package from B to A: {}\n", df_result.public_b))?;
+    file_buffer.write_fmt(format_args!("Shared key: {}\n", df_result.shared_key))?;
+    if let Some(derived_key) = &df_result.derived_key {
+        file_buffer.write_fmt(format_args!("Derived key: {}\n", derived_key))?;
+    }
+    file_buffer.write_fmt(format_args!("Was the operation successful?: {}\n", df_result.verify()))?;
     file_buffer.flush()?;
 
-    println!("Successfully saved the result of the Diffie-Hellman calculations into \"calculation_result.txt\" file at the location of the program.");
+    writeln!(handle, "Successfully saved the result of the Diffie-Hellman calculations into {}.", result_file_location(&result_path))?;
+
+    Ok(())
+}
+
+// Save calculation result for the multi-party Diffie-Hellman calculations into the file.
+pub fn save_df_multi_calculation_result(handle: &mut impl Write, df_result: &MultiDiffieHellmanResult, dir: &Path) -> Result<(), std::io::Error> {
+    let result_path = dir.join(RESULT_FILE_NAME);
+
+    // Create a file or truncate it, write first line.
+    let mut file = fs::File::create(&result_path)?;
+    file.write_fmt(format_args!("The result of the multi-party Diffie-Hellman calculations:\n"))?;
+    close_file(file);
+
+    // Reopen file in appending mode and buffer the handle, after the lines are complete, flush it.
+    let mut file_buffer = BufWriter::new(fs::OpenOptions::new().append(true).open(&result_path)?);
+    write_df_multi_values(&mut file_buffer, df_result)?;
+    file_buffer.flush()?;
+
+    writeln!(handle, "Successfully saved the result of the multi-party Diffie-Hellman calculations into {}.", result_file_location(&result_path))?;
+
+    Ok(())
+}
+
+// Save the secret, recovered with the discrete logarithm search, into the file.
+pub fn save_df_crack_result(handle: &mut impl Write, df_result: &DiscreteLogResult, dir: &Path) -> Result<(), std::io::Error> {
+    let result_path = dir.join(RESULT_FILE_NAME);
+
+    // Create a file or truncate it, write first line.
+    let mut file = fs::File::create(&result_path)?;
+    file.write_fmt(format_args!("The result of the Diffie-Hellman discrete logarithm search:\n"))?;
+    close_file(file);
+
+    // Reopen file in appending mode and buffer the handle, after the lines are complete, flush it.
+    let mut file_buffer = BufWriter::new(fs::OpenOptions::new().append(true).open(&result_path)?);
+    write_df_crack_values(&mut file_buffer, df_result)?;
+    file_buffer.flush()?;
+
+    writeln!(handle, "Successfully saved the result of the Diffie-Hellman discrete logarithm search into {}.", result_file_location(&result_path))?;
 
     Ok(())
 }
 
 // Save calculation result for the RSA calculations into the console.
-pub fn save_rsa_calculation_result(rsa_result: &RsaResult) -> Result<(), std::io::Error> {
+// The result of the binary encryption/decryption is saved as the raw bytes into the separate file,
+// the result of the file streaming is already written into its output file.
+pub fn save_rsa_calculation_result(handle: &mut impl Write, rsa_result: &RsaResult, dir: &Path) -> Result<(), std::io::Error> {
+    if let RsaResult::BytesResult(bytes_result) = rsa_result {
+        let binary_path = dir.join(BINARY_RESULT_FILE_NAME);
+        fs::write(&binary_path, bytes_result)?;
+        writeln!(handle, "Successfully saved the result of the RSA calculations into {}.", result_file_location(&binary_path))?;
+
+        return Ok(());
+    }
+
+    if let RsaResult::FileResult { path, written_bytes } = rsa_result {
+        writeln!(handle, "Successfully saved the result of the RSA calculations of {} bytes into \"{}\" file.", written_bytes, path)?;
+
+        return Ok(());
+    }
+
     // Create a file or truncate it, write first line.
-    let mut file = fs::File::create("calculation_result.txt")?;
+    let result_path = dir.join(RESULT_FILE_NAME);
+    let mut file = fs::File::create(&result_path)?;
     file.write_fmt(format_args!("The result of the RSA calculations.\n"))?;
     close_file(file);
 
     // Reopen file in appending mode and buffer the handle, after the lines are complete, flush it.
-    let mut file_buffer = BufWriter::new(fs::OpenOptions::new().append(true).open("calculation_result.txt")?);
+    let mut file_buffer = BufWriter::new(fs::OpenOptions::new().append(true).open(&result_path)?);
 
     // Generate an appropriate output, according to the type of RSA result.
     match rsa_result {
         RsaResult::KeyPair(generate_key_pair) => {
             file_buffer.write_fmt(format_args!("The result of the RSA key pair generation:\n"))?;
+            file_buffer.write_fmt(format_args!("Key summary: {}\n", generate_key_pair.summary()))?;
             file_buffer.write_fmt(format_args!("Key modulus n: {}\n", generate_key_pair.public_key_n))?;
             file_buffer.write_fmt(format_args!("Public key exponent e: {}\n", generate_key_pair.public_key_e))?;
             file_buffer.write_fmt(format_args!("Private key exponent d: {}\n", generate_key_pair.private_key_d))?;
@@ -131,8 +414,10 @@ pub fn save_rsa_calculation_result(rsa_result: &RsaResult) -> Result<(), std::io
             file_buffer.write_fmt(format_args!("The result of the RSA encryption/decryption calculations:\n"))?;
             file_buffer.write_fmt(format_args!("Encryption/decryption result: {}\n", *string_result))?;
         }
+        RsaResult::BytesResult(_) | RsaResult::FileResult { .. } => {}
         RsaResult::BruteforceRSAResult(bruteforce_result) => {
             file_buffer.write_fmt(format_args!("The result of the RSA bruteforce calculations:\n"))?;
+            file_buffer.write_fmt(format_args!("Key summary: {}\n", bruteforce_result.summary()))?;
             file_buffer.write_fmt(format_args!("Prime q: {}\n", bruteforce_result.prime_q))?;
             file_buffer.write_fmt(format_args!("Prime p: {}\n", bruteforce_result.prime_p))?;
             file_buffer.write_fmt(format_args!("Key modulus n: {}\n", bruteforce_result.public_key_n))?;
@@ -143,57 +428,199 @@ pub fn save_rsa_calculation_result(rsa_result: &RsaResult) -> Result<(), std::io
 
     file_buffer.flush()?;
 
-    println!("Successfully saved the result of the RSA calculations into \"calculation_result.txt\" file at the location of the program.");
+    writeln!(handle, "Successfully saved the result of the RSA calculations into {}.", result_file_location(&result_path))?;
+
+    Ok(())
+}
+
+// Print out the result as the JSON document into the console, the document is saved also into the file of the path, if any.
+pub fn print_json_result(handle: &mut impl Write, cipher: &Cipher, mode: &Mode, result: &JsonResult, path: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
+    let document = json_document(cipher, mode, result)?;
+    writeln!(handle, "{}", document)?;
+    handle.flush()?;
+
+    if let Some(path) = path {
+        fs::write(path, format!("{}\n", document))?;
+    }
 
     Ok(())
 }
 
+// Format the bytes as the uppercase hex string for the console.
+fn bytes_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
 // A function to print out help message to the console.
 pub fn print_help(handle: &mut impl Write) -> Result<(), Box<dyn std::error::Error>> {
-    writeln!(handle, "A command line tool to encrypt/decrypt strings with Caesar, Vigenere or RSA ciphers, or establish a key exchange with Diffie-Hellman algorithm.")?;
+    writeln!(handle, "A command line tool to encrypt/decrypt strings with Caesar, Vigenere, XOR, one-time pad, affine, rail fence or RSA ciphers, or establish a key exchange with Diffie-Hellman algorithm.")?;
     writeln!(handle, "Usage pattern:")?;
-    writeln!(handle, "    - For symmetric encryption with Caesar or Vigenere: enc(.exe) <cipher type> <encryption mode> <output mode> <plaintext or ciphertext> <key>")?;
-    writeln!(handle, "    - For Diffie-Hellman algorithm: enc(.exe) <cipher type> generate <output mode> <none or shared prime> <none or shared base> <none or secret A> <none or secret B>")?;
-    writeln!(handle, "    - For RSA encryption/decryption: enc(.exe) <cipher type> <encryption mode> <output mode> <plaintext or ciphertext> <public or private exponent> <public modulus>")?;
-    writeln!(handle, "    - For RSA key pair generation: enc(.exe) <cipher type> generate <output mode>")?;
-    writeln!(handle, "    - For RSA public key bruteforcing: enc(.exe) <cipher type> generate <output mode> <public or private exponent> <public modulus> <empty or a custom amount of threads>")?;
+    writeln!(handle, "    - For symmetric encryption with Caesar or Vigenere: enc(.exe) <cipher type> <encryption mode> <output mode> <plaintext or ciphertext> <key> <empty or a classic mode>")?;
+    writeln!(handle, "    - For symmetric encryption with XOR: enc(.exe) <cipher type> <encryption mode> <output mode> <plaintext or ciphertext> <key>")?;
+    writeln!(handle, "    - For symmetric encryption with affine cipher: enc(.exe) <cipher type> <encryption mode> <output mode> <plaintext or ciphertext> <affine key> <empty or a classic mode>")?;
+    writeln!(handle, "    - For bruteforce of Caesar cipher: enc(.exe) caesar bruteforce <output mode> <ciphertext> <optional: top=<amount of candidates> or --all>")?;
+    writeln!(handle, "    - For analysis of Vigenere cipher: enc(.exe) vigenere analyze <output mode> <ciphertext of at least 64 bytes>")?;
+    writeln!(handle, "    - For transposition with rail fence cipher: enc(.exe) <cipher type> <encryption mode> <output mode> <plaintext or ciphertext> <amount of rails>")?;
+    writeln!(handle, "    - For one-time pad encryption with a generated key: enc(.exe) <cipher type> encrypt <output mode> <plaintext>")?;
+    writeln!(handle, "    - For one-time pad decryption: enc(.exe) <cipher type> decrypt <output mode> <ciphertext> <key>")?;
+    writeln!(handle, "    - For Diffie-Hellman algorithm: enc(.exe) <cipher type> generate <output mode> <none or shared prime> <none or shared base> <none or secret A> <none or secret B> <empty or a derived key length>")?;
+    writeln!(handle, "    - For Diffie-Hellman algorithm over a MODP group of RFC 3526: enc(.exe) <cipher type> generate <output mode> <none or secret A> <none or secret B> <group> <empty or a derived key length>")?;
+    writeln!(handle, "    - For Diffie-Hellman discrete logarithm search of the secret: enc(.exe) <cipher type> crack <output mode> <shared prime> <shared base> <public value>")?;
+    writeln!(handle, "    - For multi-party Diffie-Hellman algorithm: enc(.exe) <cipher type> multi <output mode> <none or shared prime> <none or shared base> <none or secret A> <none or secret B> <none or secret C> ...")?;
+    writeln!(handle, "    - For RSA encryption/decryption: enc(.exe) <cipher type> <encryption mode> <output mode> <plaintext or ciphertext> <public or private exponent> <public modulus> <empty or a padding>")?;
+    writeln!(handle, "    - For RSA encryption/decryption with a key file: enc(.exe) <cipher type> <encryption mode> <output mode> <plaintext or ciphertext> <key file> <empty or a padding>")?;
+    writeln!(handle, "    - For RSA encryption/decryption of a binary file: enc(.exe) <cipher type> <encryption mode> <output mode> <input file> <exponent and modulus or key file> <empty or a padding> --binary")?;
+    writeln!(handle, "    - For RSA encryption/decryption of a file of any size: enc(.exe) <cipher type> <encryption mode> file <input file> <output file> <public or private exponent> <public modulus> <empty or a padding>")?;
+    writeln!(handle, "    - For RSA key pair generation: enc(.exe) <cipher type> generate <output mode> <empty or a key size> <empty or a public exponent choice> <empty or a timeout> <empty or a key directory>")?;
+    writeln!(handle, "    - For RSA key pair generation from the known primes: enc(.exe) <cipher type> generate-from <output mode> <prime p> <prime q> <public exponent> <empty or a key directory>")?;
+    writeln!(handle, "    - For RSA public key bruteforcing: enc(.exe) <cipher type> generate <output mode> <public or private exponent> <public modulus> <empty or a custom amount of threads> <empty or a bruteforce strategy> <empty or a timeout>")?;
+    writeln!(handle, "    - For RSA common modulus attack: enc(.exe) <cipher type> common-modulus <output mode> <first ciphertext> <second ciphertext> <first public exponent> <second public exponent> <public modulus>")?;
+    writeln!(handle, "    - For any operation with the named flags in any order: enc(.exe) --cipher <cipher type> --mode <encryption mode> --out <output mode> <flags of the operation>")?;
     writeln!(handle, "Note: you can use this tool with \"cargo run\" instead of tool's binary \"enc(.exe)\"")?;
     writeln!(handle)?;
     writeln!(handle, "Possible values for the listed arguments:")?;
-    writeln!(handle, "    - cipher type: caesar/vigenere/rsa/df,")?;
-    writeln!(handle, "    - encryption mode: encrypt/decrypt/generate/bruteforce,")?;
-    writeln!(handle, "    - output mode: console/file/both,")?;
+    writeln!(handle, "    - cipher type: caesar/vigenere/xor/otp/affine/railfence/rsa/df,")?;
+    writeln!(handle, "    - encryption mode: encrypt/decrypt/generate/generate-from/bruteforce/common-modulus/multi/crack/analyze,")?;
+    writeln!(handle, "    - output mode: console/file/both/json,")?;
+    writeln!(handle, "      \"json\" prints the result as a single JSON document {{\"cipher\": ..., \"mode\": ..., \"result\": {{...}}}} with the field names of the result, the big integers are decimal strings,")?;
+    writeln!(handle, "      the errors are printed as {{\"error\": ..., \"code\": <exit code>}} into the standard output, the symmetric ciphers save the document into the \"out=<path>\" file, it requires the tool built with \"--features serde\",")?;
     writeln!(handle, "    - plaintext or ciphertext: \"your text/string/phrase to encrypt or decrypt\",")?;
+    writeln!(handle, "    - standard input: \"-\" in place of the plaintext or ciphertext of the symmetric ciphers or the RSA text encryption/decryption to read all of the piped input, it counts as the plaintext or ciphertext argument,")?;
+    writeln!(handle, "      the input is processed as bytes by the ciphers over the bytes, the ciphertext may end with a line break, the empty input is denied,")?;
+    writeln!(handle, "    - input file of the symmetric ciphers: \"file:<path>\" in place of the plaintext or ciphertext to process the bytes of the file, the hex ciphertext of the file may end with a line break,")?;
+    writeln!(handle, "      the input file above 1 MiB is encrypted or decrypted chunk by chunk by Caesar or Vigenere with the \"file\" output mode, so the file of any size fits into the memory, the streamed ciphertext is plain hex unless another encoding is chosen,")?;
+    writeln!(handle, "    - output path of the symmetric ciphers: \"out=<path>\" for the \"file\", \"both\" or \"json\" output modes to save the result into the given file instead of \"{}\", the decrypted bytes are saved as they are, the console shows the bytes which are not a text in hex,", RESULT_FILE_NAME)?;
+    writeln!(handle, "    - ciphertext encoding: \"encoding=hex\", \"encoding=hex-colon\", \"encoding=hex-dash\", \"encoding=hex-space\", \"encoding=base32\" or \"encoding=base64\" for the ciphertexts of Caesar, Vigenere, XOR, affine, rail fence and RSA encryption or decryption, hex by default,")?;
+    writeln!(handle, "      the grouped hex splits the bytes by a colon, a dash or a space, e.g. \"DE:AD:BE:EF\", the hex ciphertexts are always accepted with these separators between the pairs of the hex digits,")?;
+    writeln!(handle, "      the decryption without the option accepts the hex and the armored ciphertexts, the base32 and base64 ciphertexts have to be chosen, the base32 and base64 outputs are padded with \"=\",")?;
+    writeln!(handle, "    - hex case: \"hex-case=upper\" or \"hex-case=lower\" for the letters of the hex ciphertexts of the encryption, e.g. \"deadbeef\" as openssl or xxd, the uppercase by default, the decryption accepts both cases,")?;
+    writeln!(handle, "    - armor: \"--armor\" for the ciphertexts of the encryption in base64 wrapped at 64 characters between the \"-----BEGIN CIPHERTEXT-----\" and \"-----END CIPHERTEXT-----\" lines, the decryption detects the armored ciphertexts,")?;
+    writeln!(handle, "      with \"save=<directory>\" of the RSA key generation the key files are saved armored as \"RSA PUBLIC KEY\" and \"RSA PRIVATE KEY\", the key files of both formats are loaded,")?;
+    writeln!(handle, "      NOTE: the armored key files are specific to this tool, they are NOT PKCS#1 files despite their labels and are not accepted by OpenSSL or other tools,")?;
     writeln!(handle, "    - key: \"your key to use for encryption or decryption\",")?;
+    writeln!(handle, "    - affine key: \"a,b\" of the affine cipher E(x) = (a * x + b) mod 256 over the bytes, or mod 26 in the classic mode, a has to be coprime to the modulus,")?;
+    writeln!(handle, "    - amount of rails: \"your whole number of at least 2\" for the zig-zag of the rail fence cipher over the bytes, the amount above the length of the text leaves it unchanged,")?;
+    writeln!(handle, "    - amount of candidates: \"top=<positive number>\" for the Caesar bruteforce to output the best candidates, 5 by default, or \"--all\" for all 256 shifts, ranked by the printable ASCII and the English letter frequencies of their plaintexts,")?;
+    writeln!(handle, "    - Vigenere analysis: estimates the key length 1-32 by the index of coincidence and every key byte by the chi-square against the English frequencies, the results are guesses, which may be wrong for the short or the non English texts, the 3 best key lengths are listed,")?;
+    writeln!(handle, "    - classic mode: \"--alpha\" for Caesar, Vigenere or affine cipher to shift only the letters A-Z and a-z within the alphabet, preserving the case, other characters are left unchanged,")?;
+    writeln!(handle, "      the Caesar key is taken modulo 26, the Vigenere key consists only of letters, the result is a plain text instead of hex,")?;
     writeln!(handle, "    - shared prime/shared base/secret A/secret B: \"your appropriate numeric values for Diffie-Hellman algorithm or \"none\" to generate a random value\",")?;
+    writeln!(handle, "    - shared prime/shared base: \"generate\" to generate a 128 bit safe prime p = 2q + 1 and a generator of its subgroup of the prime order q,")?;
+    writeln!(handle, "    - shared base/secrets: the base is in the range 2 - (p - 2), with g^q = 1 (modulo p) for the safe prime p = 2q + 1, the secrets are in the range 1 - (p - 2),")?;
+    writeln!(handle, "    - group: \"group5\", \"group14\", \"group15\" or \"group16\" for the 1536, 2048, 3072 or 4096 bit MODP group with the generator 2, also accepted in place of the shared prime,")?;
+    writeln!(handle, "    - public value: \"your public value g^a (modulo p) of a participant\", its secret is recovered with the baby-step giant-step search for the shared primes of at most 40 bits,")?;
+    writeln!(handle, "    - derived key length: \"key-length=<bytes>\" for the length of the key, derived from the Diffie-Hellman shared secret with HKDF-SHA-256, values from 1 to 8160 are allowed, or leave it empty to use a default value of 32,")?;
     writeln!(handle, "    - public or private exponent/public modulus: \"your appropriate numeric values for RSA cipher\",")?;
+    writeln!(handle, "    - prime p/prime q: \"your distinct primes for the RSA key pair\", the public exponent must be a coprime to lambda(n) = lcm(p - 1, q - 1),")?;
+    writeln!(handle, "    - first ciphertext/second ciphertext: \"your ciphertexts of the same message\", encrypted by this tool in the block mode with the same modulus and the coprime public exponents, the message is recovered without the private key,")?;
     writeln!(handle, "    - amount of threads: \"your custom of number of threads, values from 1 to 64 are allowed, or \"none\", or leave it empty to use a default value of 8\",")?;
+    writeln!(handle, "    - bruteforce strategy: \"trial\" for the trial division of moduli up to 10 digits, \"fermat\" for moduli of any length with close primes, or \"wiener\" for moduli of any length with a small private exponent, or leave it empty to use the trial division,")?;
+    writeln!(handle, "    - key size: \"your custom bit size of the RSA modulus, values from 128 to 4096 are allowed, or leave it empty to use a default value of 160\",")?;
+    writeln!(handle, "    - public exponent choice: \"e=65537\" for the usual fixed public exponent, or \"e=random\" for a random one, or leave it empty to use 65537,")?;
+    writeln!(handle, "    - padding: \"padding=raw\" for the block mode of this tool, \"padding=pkcs1\" for the randomised PKCS#1 v1.5 padding, or \"padding=oaep\" for the OAEP padding with SHA-256 and an empty label, the same padding must be used for the decryption, or leave it empty to use the block mode,")?;
+    writeln!(handle, "    - key file: \"file:<path>\" or the path to an existing file, the public key file \"rsa_public.key\" for encryption or the private key file \"rsa_private.key\" for decryption,")?;
+    writeln!(handle, "    - key directory: \"save=<directory>\" to save the generated key pair into the \"rsa_public.key\" and \"rsa_private.key\" files of the existing directory,")?;
+    writeln!(handle, "    - binary: \"--binary\" to encrypt or decrypt the raw bytes of the input file instead of a text, the raw result is saved into the \"{}\" file, the console shows it in hex,", BINARY_RESULT_FILE_NAME)?;
+    writeln!(handle, "    - blinding: \"--blinding\" to blind the private key operations of the decryption against the timing attacks, it requires the private key file with the primes,")?;
+    writeln!(handle, "    - input file/output file: \"your paths of the files\", the input file is encrypted or decrypted block by block into the output file, so the file of any size fits into the memory,")?;
+    writeln!(handle, "    - timeout: \"timeout=<seconds>\" to stop the RSA key generation or bruteforce after the given amount of seconds, the trial division accepts moduli longer than 10 digits with a timeout,")?;
+    writeln!(handle)?;
+    writeln!(handle, "Named flags, each given once, the values are the same as of the listed arguments, the positional arguments are not allowed together with them:")?;
+    for definition in FLAG_DEFINITIONS {
+        match definition.value.is_empty() {
+            true => writeln!(handle, "    - {}: {},", definition.name, definition.help)?,
+            false => writeln!(handle, "    - {} {}: {},", definition.name, definition.value, definition.help)?,
+        }
+    }
     writeln!(handle)?;
     writeln!(handle, "Notice:")?;
-    writeln!(handle, "    - This tool outputs encrypted information in a hexadecimal encoding format.")?;
-    writeln!(handle, "    - This tool only accepts ciphertexts for decryption, previously encrypted with this tool in hex format.")?;
+    writeln!(handle, "    - This tool outputs encrypted information in a hexadecimal encoding format, or in base32 or base64 with the \"encoding\" option, except for the classic mode of Caesar and Vigenere.")?;
+    writeln!(handle, "    - This tool only accepts ciphertexts for decryption, previously encrypted with this tool in hex, base32, base64 or armored format, or plain ciphertexts in the classic mode.")?;
     writeln!(handle, "    - If the \"file\" or \"both \" output mode were chosen then the ciphertext will be saved in the file, named \"ciphertext.txt\".")?;
     writeln!(handle, "    - If the \"file\" or \"both \" output mode were chosen then the ciphertext will be saved in the same location of the tool.")?;
     writeln!(handle, "    - Caesar mode encryption and decryption accept only whole numbers as a key (both positive and negative).")?;
     writeln!(handle, "    - Vigenere mode encryption and decryption accept any string as a key.")?;
+    writeln!(handle, "    - XOR mode encryption and decryption accept any non empty string as a key, the key is repeated over the bytes of the text.")?;
+    writeln!(handle, "    - One-time pad encryption generates a random key in hex, exactly as long as the bytes of the text, the decryption accepts only the key of the same length as the ciphertext, never reuse the key.")?;
+    writeln!(handle, "    - RSA key files are text files with the decimal values of the key as \"<name>: <value>\" lines between the \"-----BEGIN ENC RSA PUBLIC KEY-----\" and \"-----END ENC RSA PUBLIC KEY-----\" lines,")?;
+    writeln!(handle, "      the public key file contains \"n\" and \"e\", the private key file \"ENC RSA PRIVATE KEY\" contains \"n\", \"d\" and optionally the CRT values \"p\", \"q\", \"dp\", \"dq\" and \"qinv\".")?;
     writeln!(handle, "    - If you use \"cargo run\" command to run the program, it is advised to run int with \"--release\" parameter to speed up calculations.")?;
     writeln!(handle)?;
     writeln!(handle, "Examples of usage:")?;
     writeln!(handle, "    - To encrypt a string in Caesar cipher and output the result into the console:")?;
     writeln!(handle, "    enc(.exe) caesar encrypt console ThisIsAMessageToEncrypt 100")?;
+    writeln!(handle, "    - The same with the named flags:")?;
+    writeln!(handle, "    enc(.exe) --cipher caesar --mode encrypt --out console --text ThisIsAMessageToEncrypt --key 100")?;
     writeln!(handle, "    - To decrypt a string in Vigenere cipher and output the result into the file:")?;
     writeln!(handle, "    enc(.exe) vigenere decrypt file ThisIsAMessageToDecryptInHEX ThisIsTheUsedKey")?;
+    writeln!(handle, "    - To encrypt a string in XOR cipher and output the result into the console:")?;
+    writeln!(handle, "    enc(.exe) xor encrypt console hello key")?;
+    writeln!(handle, "    - To encrypt a string in affine cipher over the bytes or over the Latin alphabet:")?;
+    writeln!(handle, "    enc(.exe) affine encrypt console \"Target string!\" 171,42")?;
+    writeln!(handle, "    enc(.exe) affine encrypt console \"AFFINE CIPHER\" 5,8 --alpha")?;
+    writeln!(handle, "    - To bruteforce a Caesar ciphertext and output the 3 most likely plaintexts with their shifts:")?;
+    writeln!(handle, "    enc(.exe) caesar bruteforce console 4A4D4B top=3")?;
+    writeln!(handle, "    - To guess the key and the plaintext of a Vigenere ciphertext of an English text:")?;
+    writeln!(handle, "    enc(.exe) vigenere analyze console <hex ciphertext of a few hundred bytes>")?;
+    writeln!(handle, "    - To encrypt a string in rail fence cipher with 3 rails:")?;
+    writeln!(handle, "    enc(.exe) railfence encrypt console WEAREDISCOVEREDFLEEATONCE 3")?;
+    writeln!(handle, "    - To encrypt a file in Caesar cipher and decrypt it back into another file:")?;
+    writeln!(handle, "    enc(.exe) caesar encrypt file file:photo.png 7 out=photo.hex")?;
+    writeln!(handle, "    enc(.exe) caesar decrypt file file:photo.hex 7 out=photo_copy.png")?;
+    writeln!(handle, "    - To encrypt the piped text in Vigenere cipher:")?;
+    writeln!(handle, "    cat message.txt | enc(.exe) vigenere encrypt console - mykey")?;
+    writeln!(handle, "    - To encrypt a string in Caesar cipher into base64 and decrypt it back:")?;
+    writeln!(handle, "    enc(.exe) caesar encrypt console hello 3 encoding=base64")?;
+    writeln!(handle, "    enc(.exe) caesar decrypt console a2hvb3I= 3 encoding=base64")?;
+    writeln!(handle, "    - To encrypt a string in XOR cipher into the hex of the bytes split by colons or into base32:")?;
+    writeln!(handle, "    enc(.exe) xor encrypt console hello key encoding=hex-colon")?;
+    writeln!(handle, "    enc(.exe) xor encrypt console hello key encoding=base32")?;
+    writeln!(handle, "    - To encrypt a string in Vigenere cipher into the lowercase hex:")?;
+    writeln!(handle, "    enc(.exe) vigenere encrypt console hello key hex-case=lower")?;
+    writeln!(handle, "    enc(.exe) vigenere encrypt console hello key --armor")?;
+    writeln!(handle, "    - To encrypt a string with the one-time pad and decrypt it with the generated key:")?;
+    writeln!(handle, "    enc(.exe) otp encrypt console hi")?;
+    writeln!(handle, "    enc(.exe) otp decrypt console 696B 0102")?;
+    writeln!(handle, "    - To encrypt a string in the classic Caesar or Vigenere cipher of the Latin alphabet:")?;
+    writeln!(handle, "    enc(.exe) caesar encrypt console \"ATTACK AT DAWN\" 3 --alpha")?;
+    writeln!(handle, "    enc(.exe) vigenere encrypt console \"ATTACK AT DAWN\" LEMON --alpha")?;
     writeln!(handle, "    - To generate Diffie-Hellman values:")?;
     writeln!(handle, "    enc(.exe) df generate file")?;
     writeln!(handle, "    enc(.exe) df generate console none none none none")?;
     writeln!(handle, "    enc(.exe) df generate console none 123 none 12345")?;
+    writeln!(handle, "    enc(.exe) df generate console generate generate none none")?;
+    writeln!(handle, "    enc(.exe) df generate console none none group14")?;
+    writeln!(handle, "    enc(.exe) df generate console none none group14 key-length=16")?;
+    writeln!(handle, "    - To recover the Diffie-Hellman secret from the public value:")?;
+    writeln!(handle, "    enc(.exe) df crack console 10000000019 2 6560363120")?;
+    writeln!(handle, "    - To generate multi-party Diffie-Hellman values of 4 participants:")?;
+    writeln!(handle, "    enc(.exe) df multi console none none none none none none")?;
+    writeln!(handle, "    enc(.exe) df multi console generate generate 12 none 345 none")?;
     writeln!(handle, "    - To encrypt with RSA cipher:")?;
     writeln!(handle, "    enc(.exe) rsa encrypt console \"Target string!\" 12 19784619")?;
+    writeln!(handle, "    enc(.exe) rsa encrypt console \"Target string!\" 65537 503389953040597954843496152539898795547523683 padding=pkcs1")?;
+    writeln!(handle, "    enc(.exe) rsa encrypt console \"Target string!\" file:rsa_public.key padding=oaep")?;
+    writeln!(handle, "    enc(.exe) rsa decrypt console 00ADD3CE839F... rsa_private.key padding=oaep")?;
+    writeln!(handle, "    enc(.exe) rsa decrypt console 00ADD3CE839F... rsa_private.key padding=oaep --blinding")?;
+    writeln!(handle, "    enc(.exe) rsa encrypt file image.png rsa_public.key padding=oaep --binary")?;
+    writeln!(handle, "    enc(.exe) rsa encrypt file video.mp4 video.mp4.enc 65537 503389953040597954843496152539898795547523683")?;
+    writeln!(handle, "    enc(.exe) rsa decrypt file video.mp4.enc video.mp4 239227093839837965545527797083977554955436111 503389953040597954843496152539898795547523683")?;
     writeln!(handle, "    - To generate an RSA key pair:")?;
     writeln!(handle, "    enc(.exe) rsa generate console")?;
+    writeln!(handle, "    enc(.exe) rsa generate console 512")?;
+    writeln!(handle, "    enc(.exe) rsa generate console 256 e=random timeout=10")?;
+    writeln!(handle, "    enc(.exe) rsa generate console 512 save=.")?;
+    writeln!(handle, "    enc(.exe) rsa generate console 512 save=. --armor")?;
+    writeln!(handle, "    - To construct an RSA key pair from the known primes, e.g. the textbook example:")?;
+    writeln!(handle, "    enc(.exe) rsa generate-from console 61 53 17")?;
     writeln!(handle, "    - To bruteforce a public RSA key:")?;
     writeln!(handle, "    enc(.exe) rsa bruteforce both 12 19784619")?;
     writeln!(handle, "    enc(.exe) rsa bruteforce both 12 19784619 32")?;
+    writeln!(handle, "    enc(.exe) rsa bruteforce console 65537 1000000000000000000000000000000000003368000000000000000000000000000000000123247 none fermat")?;
+    writeln!(handle, "    enc(.exe) rsa bruteforce console 85495127941504911869640645070123075109 135852578890447093097260043048829602929 none wiener")?;
+    writeln!(handle, "    enc(.exe) rsa bruteforce console 65537 10000020999973 8 timeout=30")?;
+    writeln!(handle, "    - To recover a message from its two ciphertexts with the same modulus:")?;
+    writeln!(handle, "    enc(.exe) rsa common-modulus console 09A7F17BFD5A6EBA7644ED88C26F84B52B59F7 0F6FFACCF225260953EEFC868EA42642F3F282 3 5 503389953040597954843496152539898795547523683")?;
     writeln!(handle)?;
     writeln!(handle, "To trigger this help message pass \"help\" argument:")?;
     writeln!(handle, "    - enc(.exe) help")?;
@@ -209,15 +636,19 @@ pub fn print_help(handle: &mut impl Write) -> Result<(), Box<dyn std::error::Err
 mod tests {
     use std::{fs, io};
     use std::io::Write;
+    use std::path::Path;
     use std::str::from_utf8_unchecked;
 
     // Add a crate to run specific test sequentially, e.g. to run file modifying test in a sequence.
     use serial_test::serial;
 
-    use crate::crypto::diffie_hellman::DiffieHellmanResult;
+    use crate::crypto::diffie_hellman::{diffie_hellman_crack, diffie_hellman_multi, DiffieHellmanResult};
+    use crate::crypto::caesar::CaesarCandidate;
+    use crate::crypto::otp::OtpResult;
+    use crate::crypto::vigenere::analysis::{KeyLengthCandidate, VigenereAnalysis};
     use crate::crypto::rsa::{BruteforceResult, RsaKeyPair, RsaResult};
     use crate::logic::bigint::ChonkerInt;
-    use crate::logic::output::{print_calculation_result, print_df_calculation_result, print_help, print_rsa_calculation_result, save_calculation_result, save_df_calculation_result, save_rsa_calculation_result};
+    use crate::logic::output::{print_caesar_bruteforce_result, print_calculation_result, print_df_calculation_result, print_df_crack_result, print_df_multi_calculation_result, print_help, print_otp_calculation_result, print_rsa_calculation_result, print_vigenere_analysis_result, save_caesar_bruteforce_result, save_calculation_result, save_df_calculation_result, save_df_crack_result, save_df_multi_calculation_result, save_otp_calculation_result, save_rsa_calculation_result, save_vigenere_analysis_result, BINARY_RESULT_FILE_NAME, RESULT_FILE_NAME};
 
     // Test the function that outputs a computed result of the symmetric ciphers to the console.
     #[test]
@@ -247,8 +678,10 @@ mod tests {
     fn test_symmetric_calculation_result_file_output() {
         let test_result = "EncryptedOrDecryptedText";
 
+        let mut handle = Vec::new();
+
         // Panic if an error was encountered during output of a message the file.
-        if let Err(e) = save_calculation_result(test_result) {
+        if let Err(e) = save_calculation_result(&mut handle, test_result.as_bytes(), Path::new(RESULT_FILE_NAME)) {
             panic!(
                 "Failed to save the result message from a symmetric cipher: {}. (test_symmetric_calculation_result_file_output)",
                 e
@@ -272,14 +705,15 @@ mod tests {
         }
 
         assert_eq!(test_result, file_contents);
+        assert_eq!(String::from_utf8(handle).unwrap(), "Successfully saved the result of the calculations into \"calculation_result.txt\" file at the location of the program.\n");
     }
 
     // Test the function that outputs a computed result of the DF algorithm to the console.
     #[test]
     fn test_df_calculation_result_console_output() {
         let test_result = DiffieHellmanResult {
-            shared_prime: ChonkerInt::from(String::from("6331500943")),
-            shared_base: ChonkerInt::from(String::from("33530")),
+            prime: ChonkerInt::from(String::from("6331500943")),
+            base: ChonkerInt::from(String::from("33530")),
             secret_a: ChonkerInt::from(String::from("614842067695921615776914492033052920130845385386020346228402639809162015232875540829522077340269791144539698780492499495612027557332283006609256641139780780317628663030773355
         6411597370715491090659980583649982643141463339114832003654176356893821022100118556316754857822235268961392684184951595378194268980784826601317860609544840475618026842771887550684169201
         1644933575088826425638789591936961155246037386969900811911695403303334029607933251239069356595493592306160680788239826728901265888023256841658912969785025931515473724612482821378578162
@@ -291,11 +725,10 @@ mod tests {
         8582369984940245142332861319142161325034900101788309903947558216162780714077240019939769179574330406529607962254553152630303736908762532369516851693378879094120312722680132559767798388
         7442249484192894544229036742084494862478127381827437040902917773290943965301803822990563639650577978808164560843883186781741455231333101029906597150022872357610579172598559678444362442
         1428432")),
-            package_from_a_to_b: ChonkerInt::from(String::from("2985467518")),
-            package_from_b_to_a: ChonkerInt::from(String::from("2620722778")),
-            result_a: ChonkerInt::from(String::from("3828477390")),
-            result_b: ChonkerInt::from(String::from("3828477390")),
-            success: true,
+            public_a: ChonkerInt::from(String::from("2985467518")),
+            public_b: ChonkerInt::from(String::from("2620722778")),
+            shared_key: ChonkerInt::from(String::from("3828477390")),
+            derived_key: Some(String::from("6B1A7E0E43C9F1F2D8B2E6F7C6A29E3D")),
         };
         let mut handle = io::BufWriter::new(Vec::new());
 
@@ -313,6 +746,7 @@ mod tests {
         assert!(
             result_message.contains("The result of the Diffie-Hellman calculations:\nShared prime:")
         );
+        assert!(result_message.contains("\nDerived key: 6B1A7E0E43C9F1F2D8B2E6F7C6A29E3D\n"));
     }
 
     // Test the function that outputs a computed result of the DF algorithm to the file.
@@ -320,8 +754,8 @@ mod tests {
     #[serial]
     fn test_df_calculation_result_file_output() {
         let test_result = DiffieHellmanResult {
-            shared_prime: ChonkerInt::from(String::from("6331500943")),
-            shared_base: ChonkerInt::from(String::from("33530")),
+            prime: ChonkerInt::from(String::from("6331500943")),
+            base: ChonkerInt::from(String::from("33530")),
             secret_a: ChonkerInt::from(String::from("614842067695921615776914492033052920130845385386020346228402639809162015232875540829522077340269791144539698780492499495612027557332283006609256641139780780317628663030773355
         6411597370715491090659980583649982643141463339114832003654176356893821022100118556316754857822235268961392684184951595378194268980784826601317860609544840475618026842771887550684169201
         1644933575088826425638789591936961155246037386969900811911695403303334029607933251239069356595493592306160680788239826728901265888023256841658912969785025931515473724612482821378578162
@@ -333,15 +767,16 @@ mod tests {
         8582369984940245142332861319142161325034900101788309903947558216162780714077240019939769179574330406529607962254553152630303736908762532369516851693378879094120312722680132559767798388
         7442249484192894544229036742084494862478127381827437040902917773290943965301803822990563639650577978808164560843883186781741455231333101029906597150022872357610579172598559678444362442
         1428432")),
-            package_from_a_to_b: ChonkerInt::from(String::from("2985467518")),
-            package_from_b_to_a: ChonkerInt::from(String::from("2620722778")),
-            result_a: ChonkerInt::from(String::from("3828477390")),
-            result_b: ChonkerInt::from(String::from("3828477390")),
-            success: true,
+            public_a: ChonkerInt::from(String::from("2985467518")),
+            public_b: ChonkerInt::from(String::from("2620722778")),
+            shared_key: ChonkerInt::from(String::from("3828477390")),
+            derived_key: Some(String::from("6B1A7E0E43C9F1F2D8B2E6F7C6A29E3D")),
         };
 
+        let mut handle = Vec::new();
+
         // Panic if an error was encountered during output of a message the file.
-        if let Err(e) = save_df_calculation_result(&test_result) {
+        if let Err(e) = save_df_calculation_result(&mut handle, &test_result, Path::new("")) {
             panic!(
                 "Failed to save the DF result message to a file: {}. (test_df_calculation_result_file_output)",
                 e
@@ -369,6 +804,195 @@ mod tests {
         );
     }
 
+    // Test the functions that output a computed result of the multi-party DF algorithm to the console and to the file.
+    #[test]
+    #[serial]
+    fn test_df_multi_calculation_result_output() {
+        let test_result = diffie_hellman_multi(&ChonkerInt::from(2147483647u64), &ChonkerInt::from(7), &[ChonkerInt::from(3), ChonkerInt::from(5), ChonkerInt::from(7)]).unwrap();
+        let mut handle = io::BufWriter::new(Vec::new());
+
+        // Panic if an error was encountered during output of a message to the console.
+        if let Err(e) = print_df_multi_calculation_result(&mut handle, &test_result) {
+            panic!("Failed to output the multi-party DF result message: {}. (test_df_multi_calculation_result_output)", e);
+        }
+        let result_message = unsafe { from_utf8_unchecked(handle.get_ref()) }.to_string();
+
+        let mut handle = Vec::new();
+
+        // Panic if an error was encountered during output of a message the file.
+        if let Err(e) = save_df_multi_calculation_result(&mut handle, &test_result, Path::new("")) {
+            panic!("Failed to save the multi-party DF result message to a file: {}. (test_df_multi_calculation_result_output)", e);
+        }
+        let file_contents = match fs::read_to_string("calculation_result.txt") {
+            Ok(contents) => contents,
+            Err(e) => panic!("Failed to read the contents of the file: {}. (test_df_multi_calculation_result_output)", e),
+        };
+        if let Err(e) = fs::remove_file("calculation_result.txt") {
+            panic!("Failed to remove the produced file: {}. (test_df_multi_calculation_result_output)", e);
+        }
+
+        // 7^3, 7^5 and 7^7 are sent in the first round, 7^(7*3), 7^(3*5) and 7^(5*7) in the second one, the key is 7^105.
+        let shared_key = ChonkerInt::from(7).modpow(&ChonkerInt::from(105), &ChonkerInt::from(2147483647u64));
+        for contents in [result_message, file_contents] {
+            assert!(contents.starts_with("The result of the multi-party Diffie-Hellman calculations:\nShared prime: 2147483647\nShared base: 7\nParticipants: 3\nSecret A: 3\n"));
+            assert!(contents.contains("Public value from A to B: 343\nPublic value from B to C: 16807\nPublic value from C to A: 823543\n"));
+            assert!(contents.contains(&format!("Round 2 package from A to B: {}\n", ChonkerInt::from(7).modpow(&ChonkerInt::from(21), &ChonkerInt::from(2147483647u64)))));
+            assert!(contents.contains("Round 2 package from C to A: "));
+            assert!(!contents.contains("Round 3"));
+            assert!(contents.contains(&format!("Result A: {}\nResult B: {}\nResult C: {}\nWas the operation successful?: true\n", shared_key, shared_key, shared_key)));
+        }
+    }
+
+    // Test the functions that output the ciphertext and the key of the one-time pad encryption to the console and to the file.
+    #[test]
+    #[serial]
+    fn test_otp_calculation_result_output() {
+        let test_result = OtpResult { ciphertext: String::from("696B"), key: String::from("0102") };
+        let mut handle = io::BufWriter::new(Vec::new());
+
+        // Panic if an error was encountered during output of a message to the console.
+        if let Err(e) = print_otp_calculation_result(&mut handle, &test_result) {
+            panic!("Failed to output the one-time pad result message: {}. (test_otp_calculation_result_output)", e);
+        }
+        let result_message = unsafe { from_utf8_unchecked(handle.get_ref()) }.to_string();
+
+        let mut handle = Vec::new();
+
+        // Panic if an error was encountered during output of a message the file.
+        if let Err(e) = save_otp_calculation_result(&mut handle, &test_result, Path::new(RESULT_FILE_NAME)) {
+            panic!("Failed to save the one-time pad result message to a file: {}. (test_otp_calculation_result_output)", e);
+        }
+        let file_contents = match fs::read_to_string("calculation_result.txt") {
+            Ok(contents) => contents,
+            Err(e) => panic!("Failed to read the contents of the file: {}. (test_otp_calculation_result_output)", e),
+        };
+        if let Err(e) = fs::remove_file("calculation_result.txt") {
+            panic!("Failed to remove the produced file: {}. (test_otp_calculation_result_output)", e);
+        }
+
+        for contents in [result_message, file_contents] {
+            assert_eq!(contents, "The result of the one-time pad encryption:\nCiphertext: 696B\nKey: 0102\n");
+        }
+    }
+
+    // Test the functions that output the ranked candidates of the Caesar bruteforce to the console and to the file.
+    #[test]
+    #[serial]
+    fn test_caesar_bruteforce_result_output() {
+        let test_result = vec![
+            CaesarCandidate { shift: 3, score: 1.1234, plaintext: String::from("hello") },
+            CaesarCandidate { shift: 250, score: 0.5, plaintext: String::from("\u{1}\tx") },
+        ];
+        let mut handle = io::BufWriter::new(Vec::new());
+
+        // Panic if an error was encountered during output of a message to the console.
+        if let Err(e) = print_caesar_bruteforce_result(&mut handle, &test_result) {
+            panic!("Failed to output the Caesar bruteforce result message: {}. (test_caesar_bruteforce_result_output)", e);
+        }
+        let result_message = unsafe { from_utf8_unchecked(handle.get_ref()) }.to_string();
+
+        let mut handle = Vec::new();
+
+        // Panic if an error was encountered during output of a message the file.
+        if let Err(e) = save_caesar_bruteforce_result(&mut handle, &test_result, Path::new(RESULT_FILE_NAME)) {
+            panic!("Failed to save the Caesar bruteforce result message to a file: {}. (test_caesar_bruteforce_result_output)", e);
+        }
+        let file_contents = match fs::read_to_string("calculation_result.txt") {
+            Ok(contents) => contents,
+            Err(e) => panic!("Failed to read the contents of the file: {}. (test_caesar_bruteforce_result_output)", e),
+        };
+        if let Err(e) = fs::remove_file("calculation_result.txt") {
+            panic!("Failed to remove the produced file: {}. (test_caesar_bruteforce_result_output)", e);
+        }
+
+        for contents in [result_message, file_contents] {
+            assert_eq!(
+                contents,
+                "The result of the Caesar bruteforce, from the most likely plaintext:\n1. Shift: 3, score: 1.123, plaintext: \"hello\"\n2. Shift: 250, score: 0.500, plaintext: \"\\u{1}\\tx\"\n"
+            );
+        }
+    }
+
+    // Test the functions that output the guesses of the Vigenere analysis to the console and to the file.
+    #[test]
+    #[serial]
+    fn test_vigenere_analysis_result_output() {
+        let test_result = VigenereAnalysis {
+            key_lengths: vec![
+                KeyLengthCandidate { length: 5, index_of_coincidence: 0.06251 },
+                KeyLengthCandidate { length: 10, index_of_coincidence: 0.0649 },
+            ],
+            key: b"LEMO\n".to_vec(),
+            plaintext: String::from("Attack at dawn"),
+            confidence: 0.987,
+        };
+        let mut handle = io::BufWriter::new(Vec::new());
+
+        // Panic if an error was encountered during output of a message to the console.
+        if let Err(e) = print_vigenere_analysis_result(&mut handle, &test_result) {
+            panic!("Failed to output the Vigenere analysis result message: {}. (test_vigenere_analysis_result_output)", e);
+        }
+        let result_message = unsafe { from_utf8_unchecked(handle.get_ref()) }.to_string();
+
+        let mut handle = Vec::new();
+
+        // Panic if an error was encountered during output of a message the file.
+        if let Err(e) = save_vigenere_analysis_result(&mut handle, &test_result, Path::new(RESULT_FILE_NAME)) {
+            panic!("Failed to save the Vigenere analysis result message to a file: {}. (test_vigenere_analysis_result_output)", e);
+        }
+        let file_contents = match fs::read_to_string("calculation_result.txt") {
+            Ok(contents) => contents,
+            Err(e) => panic!("Failed to read the contents of the file: {}. (test_vigenere_analysis_result_output)", e),
+        };
+        if let Err(e) = fs::remove_file("calculation_result.txt") {
+            panic!("Failed to remove the produced file: {}. (test_vigenere_analysis_result_output)", e);
+        }
+
+        for contents in [result_message, file_contents] {
+            assert_eq!(
+                contents,
+                "The result of the Vigenere analysis, the key and the plaintext are the guesses:\n\
+                Key length candidates (index of coincidence): 5 (0.0625), 10 (0.0649)\n\
+                Guessed key (hex): 4C454D4F0A\n\
+                Guessed key: \"LEMO\\n\"\n\
+                Guessed plaintext: \"Attack at dawn\"\n\
+                Confidence: 0.99\n"
+            );
+        }
+    }
+
+    // Test the functions that output the secret of the DF discrete logarithm search to the console and to the file.
+    #[test]
+    #[serial]
+    fn test_df_crack_result_output() {
+        let test_result = diffie_hellman_crack("10000000019", "2", "6560363120").unwrap();
+        let mut handle = io::BufWriter::new(Vec::new());
+
+        // Panic if an error was encountered during output of a message to the console.
+        if let Err(e) = print_df_crack_result(&mut handle, &test_result) {
+            panic!("Failed to output the DF discrete logarithm result message: {}. (test_df_crack_result_output)", e);
+        }
+        let result_message = unsafe { from_utf8_unchecked(handle.get_ref()) }.to_string();
+
+        let mut handle = Vec::new();
+
+        // Panic if an error was encountered during output of a message the file.
+        if let Err(e) = save_df_crack_result(&mut handle, &test_result, Path::new("")) {
+            panic!("Failed to save the DF discrete logarithm result message to a file: {}. (test_df_crack_result_output)", e);
+        }
+        let file_contents = match fs::read_to_string("calculation_result.txt") {
+            Ok(contents) => contents,
+            Err(e) => panic!("Failed to read the contents of the file: {}. (test_df_crack_result_output)", e),
+        };
+        if let Err(e) = fs::remove_file("calculation_result.txt") {
+            panic!("Failed to remove the produced file: {}. (test_df_crack_result_output)", e);
+        }
+
+        for contents in [result_message, file_contents] {
+            assert_eq!(contents, "The result of the Diffie-Hellman discrete logarithm search:\nShared prime: 10000000019\nShared base: 2\nPublic value: 6560363120\nRecovered secret: 1234567891\n");
+        }
+    }
+
     // Test the function that outputs a computed keypair result of the RSA algorithm to the console.
     #[test]
     fn test_rsa_calculation_keypair_result_console_output() {
@@ -376,6 +1000,7 @@ mod tests {
             public_key_n: ChonkerInt::from(String::from("2877480840864488227166946252682128647397404497")),
             public_key_e: ChonkerInt::from(String::from("1")),
             private_key_d: ChonkerInt::from(String::from("1")),
+            crt: None,
         });
         let mut handle = io::BufWriter::new(Vec::new());
 
@@ -391,7 +1016,7 @@ mod tests {
         let result_message = unsafe { from_utf8_unchecked(result_message_ref) };
 
         assert!(
-            result_message.contains("The result of the RSA key pair generation:\nKey modulus n:")
+            result_message.contains("The result of the RSA key pair generation:\nKey summary: 152 bit modulus, public exponent 1, fingerprint ")
         );
     }
 
@@ -403,10 +1028,13 @@ mod tests {
             public_key_n: ChonkerInt::from(String::from("2877480840864488227166946252682128647397404497")),
             public_key_e: ChonkerInt::from(String::from("1")),
             private_key_d: ChonkerInt::from(String::from("1")),
+            crt: None,
         });
 
+        let mut handle = Vec::new();
+
         // Panic if an error was encountered during output of a message the file.
-        if let Err(e) = save_rsa_calculation_result(&test_result) {
+        if let Err(e) = save_rsa_calculation_result(&mut handle, &test_result, Path::new("")) {
             panic!(
                 "Failed to save the DF result message to a file: {}. (test_rsa_calculation_keypair_result_file_output)",
                 e
@@ -430,7 +1058,7 @@ mod tests {
         }
 
         assert!(
-            file_contents.contains("The result of the RSA key pair generation:\nKey modulus n:")
+            file_contents.contains("The result of the RSA key pair generation:\nKey summary: 152 bit modulus, public exponent 1, fingerprint ")
         );
     }
 
@@ -462,8 +1090,10 @@ mod tests {
     fn test_rsa_calculation_string_result_file_output() {
         let test_result = RsaResult::StringResult(String::from("Test string."));
 
+        let mut handle = Vec::new();
+
         // Panic if an error was encountered during output of a message the file.
-        if let Err(e) = save_rsa_calculation_result(&test_result) {
+        if let Err(e) = save_rsa_calculation_result(&mut handle, &test_result, Path::new("")) {
             panic!(
                 "Failed to save the DF result message to a file: {}. (test_rsa_calculation_string_result_file_output)",
                 e
@@ -491,6 +1121,85 @@ mod tests {
         );
     }
 
+    // Test the function that outputs a computed bytes result of the RSA algorithm to the console in hex.
+    #[test]
+    fn test_rsa_calculation_bytes_result_console_output() {
+        let test_result = RsaResult::BytesResult(vec![0x00, 0x7F, 0xFF]);
+        let mut handle = io::BufWriter::new(Vec::new());
+
+        // Panic if an error was encountered during output of a message to the console.
+        if let Err(e) = print_rsa_calculation_result(&mut handle, &test_result) {
+            panic!(
+                "Failed to output the RSA result message: {}. (test_rsa_calculation_bytes_result_console_output)",
+                e
+            );
+        }
+
+        let result_message_ref = handle.get_ref();
+        let result_message = unsafe { from_utf8_unchecked(result_message_ref) };
+
+        assert!(
+            result_message.contains("The result of the RSA encryption/decryption calculations of 3 bytes:\nEncryption/decryption result in hex: 007FFF")
+        );
+    }
+
+    // Test the function that outputs a computed file result of the RSA algorithm to the console.
+    #[test]
+    fn test_rsa_calculation_file_result_console_output() {
+        let test_result = RsaResult::FileResult { path: String::from("video.mp4.enc"), written_bytes: 4096 };
+        let mut handle = io::BufWriter::new(Vec::new());
+
+        // Panic if an error was encountered during output of a message to the console.
+        if let Err(e) = print_rsa_calculation_result(&mut handle, &test_result) {
+            panic!(
+                "Failed to output the RSA result message: {}. (test_rsa_calculation_file_result_console_output)",
+                e
+            );
+        }
+
+        let result_message_ref = handle.get_ref();
+        let result_message = unsafe { from_utf8_unchecked(result_message_ref) };
+
+        assert!(
+            result_message.contains("The result of the RSA encryption/decryption calculations of the file:\nEncryption/decryption result: 4096 bytes written into \"video.mp4.enc\"")
+        );
+    }
+
+    // Test the function that outputs a computed bytes result of the RSA algorithm to the binary file as the raw bytes.
+    #[test]
+    #[serial]
+    fn test_rsa_calculation_bytes_result_file_output() {
+        let test_result = RsaResult::BytesResult(vec![0x00, 0x7F, 0xFF]);
+
+        let mut handle = Vec::new();
+
+        // Panic if an error was encountered during output of a message the file.
+        if let Err(e) = save_rsa_calculation_result(&mut handle, &test_result, Path::new("")) {
+            panic!(
+                "Failed to save the RSA result to a file: {}. (test_rsa_calculation_bytes_result_file_output)",
+                e
+            );
+        }
+
+        let file_contents;
+
+        // Read the contents of the produced file, panic if the procedure fails.
+        match fs::read(BINARY_RESULT_FILE_NAME) {
+            Ok(contents) => file_contents = contents,
+            Err(e) => panic!("Failed to read the contents of the file: {}. (test_rsa_calculation_bytes_result_file_output)", e),
+        }
+
+        // Delete the produced file, panic if the procedure fails.
+        if let Err(e) = fs::remove_file(BINARY_RESULT_FILE_NAME) {
+            panic!(
+                "Failed to remove the produced file: {}. (test_rsa_calculation_bytes_result_file_output)",
+                e
+            );
+        }
+
+        assert_eq!(file_contents, vec![0x00, 0x7F, 0xFF]);
+    }
+
     // Test the function that outputs a computed bruteforce result of the RSA algorithm to the console.
     #[test]
     fn test_rsa_calculation_bruteforce_result_console_output() {
@@ -500,6 +1209,7 @@ mod tests {
             public_key_n: ChonkerInt::from(String::from("268970693")),
             public_key_e: ChonkerInt::from(String::from("85")),
             private_key_d: ChonkerInt::from(String::from("88590349")),
+            crt: None,
         });
         let mut handle = io::BufWriter::new(Vec::new());
 
@@ -515,7 +1225,7 @@ mod tests {
         let result_message = unsafe { from_utf8_unchecked(result_message_ref) };
 
         assert!(
-            result_message.contains("The result of the RSA bruteforce calculations:\nPrime q:")
+            result_message.contains("The result of the RSA bruteforce calculations:\nKey summary: 29 bit modulus, public exponent 85, fingerprint ")
         );
     }
 
@@ -529,10 +1239,13 @@ mod tests {
             public_key_n: ChonkerInt::from(String::from("268970693")),
             public_key_e: ChonkerInt::from(String::from("85")),
             private_key_d: ChonkerInt::from(String::from("88590349")),
+            crt: None,
         });
 
+        let mut handle = Vec::new();
+
         // Panic if an error was encountered during output of a message the file.
-        if let Err(e) = save_rsa_calculation_result(&test_result) {
+        if let Err(e) = save_rsa_calculation_result(&mut handle, &test_result, Path::new("")) {
             panic!(
                 "Failed to save the DF result message to a file: {}. (test_rsa_calculation_bruteforce_result_file_output)",
                 e
@@ -556,7 +1269,7 @@ mod tests {
         }
 
         assert!(
-            file_contents.contains("The result of the RSA bruteforce calculations:\nPrime q:")
+            file_contents.contains("The result of the RSA bruteforce calculations:\nKey summary: 29 bit modulus, public exponent 85, fingerprint ")
         );
     }
 
@@ -583,7 +1296,7 @@ mod tests {
 
         // Check inclusion of the first line.
         assert!(help_message.contains(
-            "A command line tool to encrypt/decrypt strings with Caesar, Vigenere or RSA ciphers, or establish a key exchange with Diffie-Hellman algorithm."
+            "A command line tool to encrypt/decrypt strings with Caesar, Vigenere, XOR, one-time pad, affine, rail fence or RSA ciphers, or establish a key exchange with Diffie-Hellman algorithm."
         ));
         // Check inclusion of some middle line.
         assert!(help_message.contains("    - If the \"file\" or \"both \" output mode were chosen then the ciphertext will be saved in the file, named \"ciphertext.txt\"."));
@@ -1,17 +1,23 @@
 use std::env;
+use std::io::{self, BufWriter};
 use std::process;
 
-use enc::logic::config::ConfigVariant;
+use enc::logic::config::{json_output_requested, ConfigVariant};
 use enc::logic::error::OperationError;
-use enc::logic::run;
+use enc::logic::json::print_json_error;
+use enc::logic::run_with_output;
 
 fn main() {
+    // Collect the input arguments from the command line, skip 1st argument, program name.
+    // The errors of the JSON output mode are printed as the JSON documents, so the consumer never parses a text.
+    let args: Vec<String> = env::args().skip(1).collect();
+    let json_output = json_output_requested(&args);
+
     // Create configuration for the encryption process,
     // based on the input arguments from the command line, and handle possible errors.
-    // Skip 1st argument, program name.
     // Check for the custom error types to, in turn, check for the value of the flag for the help message.
     // If it is set, output produced help message and exit process with the exit code 0.
-    let config = match ConfigVariant::new(env::args().skip(1)) {
+    let config = match ConfigVariant::new(args.into_iter()) {
         Ok(config) => config,
         Err(e) => {
             match e.downcast::<OperationError>() {
@@ -21,10 +27,18 @@ fn main() {
                         println!("{}", value);
                         process::exit(0);
                     }
+                    if json_output {
+                        print_json_error(&value.to_string(), 64);
+                        process::exit(64);
+                    }
                     eprintln!("Problem parsing arguments: {}\nEnter \"es(.exe) help\" or \"cargo run help\" to get a help message for more information about the tool.", value);
                     process::exit(64);
                 }
                 Err(e) => {
+                    if json_output {
+                        print_json_error(&e.to_string(), 64);
+                        process::exit(64);
+                    }
                     eprintln!("Problem parsing arguments: {}\nEnter \"es(.exe) help\" or \"cargo run help\" to get a help message for more information about the tool.", e);
                     process::exit(64);
                 }
@@ -32,8 +46,14 @@ fn main() {
         }
     };
 
-    // Pass the retrieved configuration settings to the main tool logic and handle possible errors.
-    if let Err(e) = run(config) {
+    // Pass the retrieved configuration settings to the main tool logic with the standard output and error and handle possible errors.
+    // The progress of the RSA bruteforce is reported into the standard error, the standard output is not locked for the whole run,
+    // since the workers of the thread pool print their state into it from their threads.
+    if let Err(e) = run_with_output(config, &mut BufWriter::new(io::stdout()), &mut io::stderr()) {
+        if json_output {
+            print_json_error(&e.to_string(), 70);
+            process::exit(70);
+        }
         eprintln!("Application error: {} Enter \"es(.exe) help\" to get a help message for more information about the tool.", e);
         process::exit(70);
     }
@@ -0,0 +1,70 @@
+// Crate with integration tests for the allocations of the in place BigInt arithmetic.
+// Tests run in a separate binary, which counts every allocation with a custom global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use enc::logic::bigint::ChonkerInt;
+
+// Allocator, which forwards to the system allocator and counts the allocations and reallocations.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// Count the allocations made by the passed closure.
+fn count_allocations<F: FnOnce()>(operation: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    operation();
+    ALLOCATIONS.load(Ordering::SeqCst) - before
+}
+
+// Test that the in place methods reuse the vectors of limbs or allocate only the vector of the result,
+// while the operators allocate temporary BigInts. A single test keeps the counter free of other test threads.
+#[test]
+fn test_bigint_in_place_allocations() {
+    let operand = ChonkerInt::from(String::from("123456789123456789123456789123456789123456789123456789"));
+
+    // Addition reuses the vector of limbs, if it has enough capacity.
+    let one = ChonkerInt::from(1);
+    let mut sum = operand.clone();
+    sum.add_assign_ref(&operand);
+    sum.add_assign_ref(&operand);
+    assert_eq!(count_allocations(|| sum.add_assign_ref(&one)), 0);
+    assert_eq!(count_allocations(|| sum.add_assign_ref(&operand)), 0);
+
+    // Halving never allocates.
+    let mut halved = operand.clone();
+    assert_eq!(count_allocations(|| halved.halve_in_place()), 0);
+    assert_eq!(halved, &operand / &ChonkerInt::from(2));
+
+    // Multiplication and squaring allocate only the vector of the product.
+    let mut product = operand.clone();
+    assert_eq!(count_allocations(|| product.mul_assign_ref(&operand)), 1);
+    let mut square = operand.clone();
+    assert_eq!(count_allocations(|| square.square_in_place()), 1);
+    assert_eq!(product, square);
+
+    // The operator allocates a temporary BigInt for every partial product and their sums.
+    let mut operator_product = ChonkerInt::new();
+    assert!(count_allocations(|| operator_product = &operand * &operand) > 1);
+    assert_eq!(operator_product, square);
+}
@@ -0,0 +1,81 @@
+// Crate with integration tests for the public API of the library.
+// Tests use the modules of the ciphers and the encodings as an external crate, without the command line configuration.
+
+use std::fs;
+use std::io::{Read, Write};
+
+use enc::crypto::diffie_hellman::{validate_peer_public, DhParty, DhPublicKey, MODP_GROUPS};
+use enc::crypto::rsa::{rsa_decrypt_stream, rsa_encrypt_stream, RsaKeyPair, RsaPadding};
+use enc::encoding::stream::{HexReader, HexWriter};
+use enc::encoding::{base32_decode, base32_encode, base64_decode, base64_encode, HexCase};
+use enc::logic::bigint::ChonkerInt;
+use enc::logic::config::ConfigVariant;
+use enc::logic::{execute, RsaResult, RunOutcome};
+
+// Test the Diffie-Hellman exchange of two parties over the MODP group, only the public keys are exchanged.
+#[test]
+fn test_public_api_diffie_hellman() {
+    let group = MODP_GROUPS.iter().find(|group| group.name == "group5").unwrap();
+    let party_a = DhParty::from_secret(&group.prime(), &group.generator(), &ChonkerInt::from(123456789u64)).unwrap();
+    let party_b = DhParty::from_secret(&group.prime(), &group.generator(), &ChonkerInt::from(987654321u64)).unwrap();
+
+    let public_key_a: DhPublicKey = party_a.public_key();
+    let public_key_b: DhPublicKey = party_b.public_key();
+    assert_eq!(public_key_a.shared_prime, group.prime());
+    validate_peer_public(&public_key_b.public_value, &group.prime()).unwrap();
+    assert!(validate_peer_public(&ChonkerInt::from(1), &group.prime()).is_err());
+
+    assert_eq!(party_a.agree(&public_key_b.public_value).unwrap(), party_b.agree(&public_key_a.public_value).unwrap());
+}
+
+// Test the RSA key pair from the primes: it is saved into the key files and loaded back,
+// the stream encrypted with the public key is decrypted with the private key.
+#[test]
+fn test_public_api_rsa() {
+    let key_pair = RsaKeyPair::from_primes(&ChonkerInt::from(1000000007u64), &ChonkerInt::from(998244353u64), &ChonkerInt::from(65537u64)).unwrap();
+
+    let dir = std::env::temp_dir().join(format!("enc_public_api_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    key_pair.save_to_files(&dir).unwrap();
+    let public_key = RsaKeyPair::load_public(&dir.join("rsa_public.key")).unwrap();
+    let private_key = RsaKeyPair::load_private(&dir.join("rsa_private.key")).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(public_key.public_key_n, key_pair.public_key_n);
+    assert_eq!(private_key.private_key_d, key_pair.private_key_d);
+
+    let plaintext = b"The public API of the library.";
+    let mut ciphertext = Vec::new();
+    rsa_encrypt_stream(&plaintext[..], &mut ciphertext, &public_key.public_key_e, &public_key.public_key_n, &RsaPadding::Raw).unwrap();
+    let mut decrypted = Vec::new();
+    rsa_decrypt_stream(&ciphertext[..], &mut decrypted, &private_key.private_key_d, &private_key.public_key_n, None, None, &RsaPadding::Raw).unwrap();
+    assert_eq!(decrypted, plaintext);
+
+    // The result of the operation of the configuration is matched on its payload.
+    let args = ["rsa", "generate-from", "console", "61", "53", "17"].iter().map(|arg| arg.to_string());
+    match execute(ConfigVariant::new(args).unwrap()).unwrap() {
+        RunOutcome::RSA(RsaResult::KeyPair(key_pair)) => assert_eq!(key_pair.public_key_n, ChonkerInt::from(3233u64)),
+        outcome => panic!("Expected the RSA key pair, received: {:?} (test_public_api_rsa)", outcome),
+    }
+}
+
+// Test the hex adapters: the bytes written into the hex writer are read back through the hex reader.
+#[test]
+fn test_public_api_hex_stream() {
+    let mut writer = HexWriter::new(Vec::new(), HexCase::Lower);
+    writer.write_all(&[0x00, 0xAB, 0xFF]).unwrap();
+    let hex_text = writer.into_inner();
+    assert_eq!(hex_text, b"00abff");
+
+    let mut bytes = Vec::new();
+    HexReader::new(&hex_text[..]).read_to_end(&mut bytes).unwrap();
+    assert_eq!(bytes, [0x00, 0xAB, 0xFF]);
+}
+
+// Test the base32 and base64 functions re-exported from the encoding module.
+#[test]
+fn test_public_api_base32_base64() {
+    assert_eq!(base32_encode(b"foobar", true), "MZXW6YTBOI======");
+    assert_eq!(base32_decode("MZXW6YTBOI======").unwrap(), b"foobar");
+    assert_eq!(base64_encode(b"foobar", true), "Zm9vYmFy");
+    assert_eq!(base64_decode("Zm9vYmFy").unwrap(), b"foobar");
+}
@@ -0,0 +1,106 @@
+// Crate with integration tests for the memory use of the streaming of the large input files by the symmetric ciphers.
+// Tests run in a separate binary, which tracks the peak of the allocated memory with a custom global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use enc::logic::config::ConfigVariant;
+use enc::logic::run;
+
+// Allocator, which forwards to the system allocator and tracks the currently allocated bytes and their peak.
+struct PeakAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// Add the allocated bytes to the current amount and raise the peak.
+fn track_allocation(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::SeqCst) + size;
+    PEAK.fetch_max(allocated, Ordering::SeqCst);
+}
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        track_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        track_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: PeakAllocator = PeakAllocator;
+
+// Length of the generated input file, far above the streaming threshold.
+const INPUT_FILE_LEN: usize = 50 * 1024 * 1024;
+// Limit of the memory, allocated by the streaming above the memory allocated before it.
+const STREAM_MEMORY_LIMIT: usize = 1024 * 1024;
+
+// Run the tool's logic with the arguments and return the peak of the memory, allocated above the memory allocated before it.
+fn run_peak_memory(args: Vec<String>) -> usize {
+    let config = ConfigVariant::new(args.into_iter()).unwrap();
+    let before = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(before, Ordering::SeqCst);
+    run(config).unwrap();
+    PEAK.load(Ordering::SeqCst) - before
+}
+
+// Check if the contents of the files are the same, comparing them by the blocks.
+fn same_file_contents(first_path: &Path, second_path: &Path) -> bool {
+    let (mut first_reader, mut second_reader) = (BufReader::new(File::open(first_path).unwrap()), BufReader::new(File::open(second_path).unwrap()));
+    let (mut first_block, mut second_block) = (vec![0u8; 64 * 1024], vec![0u8; 64 * 1024]);
+    loop {
+        let first_len = first_reader.read(&mut first_block).unwrap();
+        if first_len == 0 {
+            return second_reader.read(&mut second_block).unwrap() == 0;
+        }
+        if second_reader.read_exact(&mut second_block[..first_len]).is_err() || first_block[..first_len] != second_block[..first_len] {
+            return false;
+        }
+    }
+}
+
+// Test the streaming encryption and decryption of a large file with Caesar and Vigenere, the file is restored
+// and the memory use stays bounded by the chunks instead of the length of the file.
+// A single test keeps the peak free of other test threads.
+#[test]
+fn test_symmetric_stream_memory() {
+    let work_dir = std::env::temp_dir().join(format!("enc_stream_memory_{}", std::process::id()));
+    std::fs::create_dir_all(&work_dir).unwrap();
+    let (input_path, ciphertext_path, output_path) = (work_dir.join("input.bin"), work_dir.join("ciphertext.txt"), work_dir.join("output.bin"));
+
+    let mut input_writer = BufWriter::new(File::create(&input_path).unwrap());
+    let mut block = vec![0u8; 64 * 1024];
+    for block_index in 0..INPUT_FILE_LEN / block.len() {
+        for (index, byte) in block.iter_mut().enumerate() {
+            *byte = ((block_index * 64 * 1024 + index).wrapping_mul(2654435761) >> 13) as u8;
+        }
+        input_writer.write_all(&block).unwrap();
+    }
+    input_writer.flush().unwrap();
+    drop(input_writer);
+
+    for (cipher, key) in [("caesar", "-42"), ("vigenere", "Ключ7")] {
+        let args = vec![cipher.to_string(), "encrypt".to_string(), "file".to_string(), format!("file:{}", input_path.display()), key.to_string(), format!("out={}", ciphertext_path.display())];
+        assert!(run_peak_memory(args) < STREAM_MEMORY_LIMIT);
+        assert_eq!(std::fs::metadata(&ciphertext_path).unwrap().len(), 2 * INPUT_FILE_LEN as u64);
+
+        let args = vec![cipher.to_string(), "decrypt".to_string(), "file".to_string(), format!("file:{}", ciphertext_path.display()), key.to_string(), format!("out={}", output_path.display())];
+        assert!(run_peak_memory(args) < STREAM_MEMORY_LIMIT);
+        assert!(same_file_contents(&input_path, &output_path));
+    }
+
+    std::fs::remove_dir_all(&work_dir).unwrap();
+}